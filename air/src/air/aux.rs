@@ -5,16 +5,33 @@
 
 use alloc::vec::Vec;
 
+use math::FieldElement;
+
 /// Holds the randomly generated elements necessary to build the auxiliary trace.
+///
+/// When the auxiliary trace consists of several segments, random elements of all segments are
+/// concatenated in the order in which the segments are built.
+///
+/// For computations which are proven as one of several tables of a multi-table proof, this also
+/// holds the final value of the running sum of the [LogUp](crate::LogUp) argument of the table.
 #[derive(Debug, Clone)]
 pub struct AuxRandElements<E> {
     rand_elements: Vec<E>,
+    logup_sum: Option<E>,
 }
 
 impl<E> AuxRandElements<E> {
     /// Creates a new [`AuxRandElements`].
     pub fn new(rand_elements: Vec<E>) -> Self {
-        Self { rand_elements }
+        Self { rand_elements, logup_sum: None }
+    }
+
+    /// Sets the final value of the running sum of the LogUp argument.
+    ///
+    /// When not set, the running sum is expected to end at zero.
+    pub fn with_logup_sum(mut self, logup_sum: E) -> Self {
+        self.logup_sum = Some(logup_sum);
+        self
     }
 
     /// Returns the random elements needed to build all columns.
@@ -22,3 +39,10 @@ impl<E> AuxRandElements<E> {
         &self.rand_elements
     }
 }
+
+impl<E: FieldElement> AuxRandElements<E> {
+    /// Returns the final value of the running sum of the LogUp argument.
+    pub fn logup_sum(&self) -> E {
+        self.logup_sum.unwrap_or(E::ZERO)
+    }
+}
@@ -8,7 +8,20 @@ use core::cmp;
 
 use math::StarkField;
 
-use crate::{air::TransitionConstraintDegree, ProofOptions, TraceInfo};
+use crate::{
+    air::{
+        transition::{validate_frame_offsets, DEFAULT_FRAME_OFFSETS},
+        ConstraintDivisor, LogUp, TransitionConstraintDegree,
+    },
+    ProofOptions, TraceInfo,
+};
+
+// CONSTANTS
+// ================================================================================================
+
+/// Minimum number of bits of entropy in the salt of each leaf of trace and constraint commitments
+/// in zero-knowledge mode.
+const ZK_SALT_BITS: u32 = 128;
 
 // AIR CONTEXT
 // ================================================================================================
@@ -25,6 +38,10 @@ pub struct AirContext<B: StarkField> {
     pub(super) trace_domain_generator: B,
     pub(super) lde_domain_generator: B,
     pub(super) num_transition_exemptions: usize,
+    pub(super) custom_transition_divisors: Vec<ConstraintDivisor<B>>,
+    pub(super) transition_constraint_divisors: Vec<usize>,
+    pub(super) frame_offsets: Vec<isize>,
+    pub(super) logup: Option<LogUp>,
 }
 
 impl<B: StarkField> AirContext<B> {
@@ -95,6 +112,99 @@ impl<B: StarkField> AirContext<B> {
         num_aux_assertions: usize,
         options: ProofOptions,
     ) -> Self {
+        Self::build(
+            trace_info,
+            main_transition_constraint_degrees,
+            aux_transition_constraint_degrees,
+            num_main_assertions,
+            num_aux_assertions,
+            None,
+            options,
+        )
+    }
+
+    /// Returns a new instance of [AirContext] instantiated for computations which rely on a
+    /// [LogUp] lookup argument.
+    ///
+    /// The running-sum column of the LogUp argument must be the last column of the last auxiliary
+    /// trace segment, and the argument uses the last [LogUp::num_rand_elements()] random elements
+    /// drawn for the last auxiliary trace segment. The prover builds this column automatically, and
+    /// the transition constraint and assertions of the argument are appended to the ones
+    /// specified via `aux_transition_constraint_degrees` and `num_aux_assertions`. Thus, if the
+    /// LogUp argument is the only component of the auxiliary trace segment,
+    /// `aux_transition_constraint_degrees` should be empty and `num_aux_assertions` should be zero.
+    ///
+    /// # Panics
+    /// Panics if
+    /// * `main_transition_constraint_degrees` is an empty vector.
+    /// * `num_main_assertions` is zero.
+    /// * `trace_info` does not describe a multi-segment execution trace, or the last auxiliary
+    ///   trace segment is not wide enough or does not have enough random elements to accommodate the
+    ///   LogUp argument.
+    /// * The LogUp argument references columns outside of the main trace segment.
+    /// * Blowup factor specified by the provided `options` is too small to accommodate degrees of
+    ///   the specified transition constraints.
+    pub fn new_with_logup(
+        trace_info: TraceInfo,
+        main_transition_constraint_degrees: Vec<TransitionConstraintDegree>,
+        aux_transition_constraint_degrees: Vec<TransitionConstraintDegree>,
+        num_main_assertions: usize,
+        num_aux_assertions: usize,
+        logup: LogUp,
+        options: ProofOptions,
+    ) -> Self {
+        assert!(
+            trace_info.is_multi_segment(),
+            "LogUp argument requires a multi-segment execution trace"
+        );
+        let last_segment_idx = trace_info.num_aux_segments() - 1;
+        assert!(
+            trace_info.get_aux_segment_width_at(last_segment_idx) >= logup.aux_trace_width(),
+            "last auxiliary trace segment must contain at least {} columns, but was {}",
+            logup.aux_trace_width(),
+            trace_info.get_aux_segment_width_at(last_segment_idx)
+        );
+        assert!(
+            trace_info.get_num_aux_segment_rand_elements_at(last_segment_idx)
+                >= logup.num_rand_elements(),
+            "last auxiliary trace segment must have at least {} random elements, but had {}",
+            logup.num_rand_elements(),
+            trace_info.get_num_aux_segment_rand_elements_at(last_segment_idx)
+        );
+        assert!(
+            logup.max_column() < trace_info.main_trace_width(),
+            "LogUp argument references column {}, but main trace segment has only {} columns",
+            logup.max_column(),
+            trace_info.main_trace_width()
+        );
+
+        Self::build(
+            trace_info,
+            main_transition_constraint_degrees,
+            aux_transition_constraint_degrees,
+            num_main_assertions,
+            num_aux_assertions,
+            Some(logup),
+            options,
+        )
+    }
+
+    /// Builds a new [AirContext]; LogUp constraints (if any) are appended to the constraints
+    /// placed against the auxiliary trace segment.
+    fn build(
+        trace_info: TraceInfo,
+        main_transition_constraint_degrees: Vec<TransitionConstraintDegree>,
+        mut aux_transition_constraint_degrees: Vec<TransitionConstraintDegree>,
+        num_main_assertions: usize,
+        mut num_aux_assertions: usize,
+        logup: Option<LogUp>,
+        options: ProofOptions,
+    ) -> Self {
+        if let Some(logup) = logup.as_ref() {
+            aux_transition_constraint_degrees.push(logup.transition_constraint_degree());
+            num_aux_assertions += logup.num_assertions();
+        }
+
         assert!(
             !main_transition_constraint_degrees.is_empty(),
             "at least one transition constraint degree must be specified"
@@ -136,17 +246,13 @@ impl<B: StarkField> AirContext<B> {
             }
         }
 
-        assert!(
-            options.blowup_factor() >= ce_blowup_factor,
-            "blowup factor too small; expected at least {}, but was {}",
-            ce_blowup_factor,
-            options.blowup_factor()
-        );
-
         let trace_length = trace_info.length();
-        let lde_domain_size = trace_length * options.blowup_factor();
+        let lde_domain_size = options.trace_poly_size(trace_length, DEFAULT_FRAME_OFFSETS.len())
+            * options.blowup_factor();
+        let num_transition_constraints =
+            main_transition_constraint_degrees.len() + aux_transition_constraint_degrees.len();
 
-        AirContext {
+        let context = AirContext {
             options,
             trace_info,
             main_transition_constraint_degrees,
@@ -157,7 +263,14 @@ impl<B: StarkField> AirContext<B> {
             trace_domain_generator: B::get_root_of_unity(trace_length.ilog2()),
             lde_domain_generator: B::get_root_of_unity(lde_domain_size.ilog2()),
             num_transition_exemptions: 1,
-        }
+            custom_transition_divisors: Vec::new(),
+            transition_constraint_divisors: vec![0; num_transition_constraints],
+            frame_offsets: DEFAULT_FRAME_OFFSETS.to_vec(),
+            logup,
+        };
+        context.validate_ce_domain_size();
+
+        context
     }
 
     // PUBLIC ACCESSORS
@@ -177,23 +290,76 @@ impl<B: StarkField> AirContext<B> {
 
     /// Returns degree of trace polynomials for an instance of a computation.
     ///
-    /// The degree is always `trace_length` - 1.
+    /// The degree is always `trace_poly_size` - 1. Unless zero-knowledge mode is enabled, this is
+    /// the same as `trace_length` - 1.
     pub fn trace_poly_degree(&self) -> usize {
-        self.trace_info.length() - 1
+        self.trace_poly_size() - 1
+    }
+
+    /// Returns the number of coefficients of trace polynomials and of constraint composition
+    /// polynomial columns for an instance of a computation.
+    ///
+    /// Unless zero-knowledge mode is enabled, this is equal to the trace length. In zero-knowledge
+    /// mode, this is the smallest power of two which can fit randomized trace polynomials (see
+    /// [ProofOptions::trace_poly_size()]).
+    pub fn trace_poly_size(&self) -> usize {
+        self.options.trace_poly_size(self.trace_len(), self.frame_offsets.len())
+    }
+
+    /// Returns the number of coefficients of the random polynomial by which each trace polynomial
+    /// is randomized in zero-knowledge mode; for proofs which are not zero-knowledge this is zero.
+    pub fn num_trace_randomizer_coefficients(&self) -> usize {
+        self.options.num_trace_randomizer_coefficients(self.frame_offsets.len())
+    }
+
+    /// Returns the number of coefficients of the constraint composition polynomial placed into
+    /// each column of the composition polynomial.
+    ///
+    /// That is, the constraint composition polynomial is computed from its columns $H_i(x)$ as
+    /// $H(x) = \sum_{i} x^{i \cdot l} \cdot H_i(x)$, where $l$ is the value returned by this method.
+    /// Unless zero-knowledge mode is enabled, this is equal to the trace length. In zero-knowledge
+    /// mode, the columns are masked by random polynomials which cancel out in the above sum, and
+    /// thus, the segments are shorter than the columns.
+    pub fn composition_segment_len(&self) -> usize {
+        self.trace_poly_size() - self.options.num_composition_randomizer_coefficients()
+    }
+
+    /// Returns the number of random elements by which each leaf of trace and constraint
+    /// commitments is salted in zero-knowledge mode; for proofs which are not zero-knowledge this
+    /// is zero.
+    ///
+    /// Salts are revealed only for the queried leaves, and contain at least 128 bits of entropy.
+    pub fn num_salt_elements(&self) -> usize {
+        if self.options.is_zk() {
+            ZK_SALT_BITS.div_ceil(B::MODULUS_BITS) as usize
+        } else {
+            0
+        }
     }
 
     /// Returns size of the constraint evaluation domain.
     ///
-    /// This is guaranteed to be a power of two, and is equal to `trace_length * ce_blowup_factor`.
+    /// This is guaranteed to be a power of two. Unless zero-knowledge mode is enabled, this is
+    /// equal to `trace_length * ce_blowup_factor`. In zero-knowledge mode, this is the smallest
+    /// power of two which is greater than `trace_poly_size` and can accommodate degrees of all
+    /// transition constraints evaluated over randomized trace polynomials.
     pub fn ce_domain_size(&self) -> usize {
-        self.trace_info.length() * self.ce_blowup_factor
+        if self.options.is_zk() {
+            let max_degree =
+                self.max_transition_composition_degree(self.num_trace_randomizer_coefficients());
+            cmp::max(max_degree + 1, self.trace_poly_size() + 1).next_power_of_two()
+        } else {
+            self.trace_info.length() * self.ce_blowup_factor
+        }
     }
 
     /// Returns the size of the low-degree extension domain.
     ///
-    /// This is guaranteed to be a power of two, and is equal to `trace_length * lde_blowup_factor`.
+    /// This is guaranteed to be a power of two, and is equal to
+    /// `trace_poly_size * lde_blowup_factor`. Unless zero-knowledge mode is enabled, this is the
+    /// same as `trace_length * lde_blowup_factor`.
     pub fn lde_domain_size(&self) -> usize {
-        self.trace_info.length() * self.options.blowup_factor()
+        self.trace_poly_size() * self.options.blowup_factor()
     }
 
     /// Returns the number of transition constraints for a computation.
@@ -234,18 +400,106 @@ impl<B: StarkField> AirContext<B> {
         self.num_transition_exemptions
     }
 
+    /// Returns the number of rows at the start of an execution trace to which transition
+    /// constraints do not apply.
+    ///
+    /// This is zero unless the evaluation frame contains rows preceding the current row (see
+    /// [set_frame_offsets()](AirContext::set_frame_offsets)), in which case it is equal to the
+    /// number of steps the frame reaches back.
+    pub fn num_leading_transition_exemptions(&self) -> usize {
+        self.frame_offsets
+            .iter()
+            .map(|&offset| offset.min(0).unsigned_abs())
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Returns divisors of transition constraints.
+    ///
+    /// The first divisor is the default one: it specifies that transition constraints must hold
+    /// on all steps of the execution trace except for the first
+    /// [num_leading_transition_exemptions()](AirContext::num_leading_transition_exemptions) steps
+    /// and the last [num_transition_exemptions()](AirContext::num_transition_exemptions) steps.
+    /// The following divisors are the ones specified via
+    /// [set_transition_divisor()](AirContext::set_transition_divisor).
+    ///
+    /// The LogUp constraint (if any) references only the current and the next rows, and thus,
+    /// must hold on the first rows of the trace even if the evaluation frame reaches back. If it
+    /// relies on the default divisor and there are leading exemptions, the last divisor in the
+    /// list is the default divisor without leading exemptions.
+    pub fn transition_divisors(&self) -> Vec<ConstraintDivisor<B>> {
+        let mut result = Vec::with_capacity(self.custom_transition_divisors.len() + 2);
+        result.push(ConstraintDivisor::from_transition_with_leading_exemptions(
+            self.trace_len(),
+            self.num_leading_transition_exemptions(),
+            self.num_transition_exemptions,
+        ));
+        result.extend_from_slice(&self.custom_transition_divisors);
+        if self.has_separate_logup_divisor() {
+            result.push(ConstraintDivisor::from_transition(
+                self.trace_len(),
+                self.num_transition_exemptions,
+            ));
+        }
+        result
+    }
+
+    /// Returns the index of the divisor (in the list returned by
+    /// [transition_divisors()](AirContext::transition_divisors)) of each transition constraint.
+    ///
+    /// Transition constraints placed against the main trace segment come first, followed by the
+    /// constraints placed against the auxiliary trace segment.
+    pub fn transition_constraint_divisors(&self) -> Vec<usize> {
+        let mut result = self.transition_constraint_divisors.clone();
+        if self.has_separate_logup_divisor() {
+            let logup_idx = result.len() - 1;
+            result[logup_idx] = self.custom_transition_divisors.len() + 1;
+        }
+        result
+    }
+
+    /// Returns the row offsets of the evaluation frames over which transition constraints are
+    /// evaluated.
+    ///
+    /// By default, this is `[0, 1]` - i.e., transition constraints are evaluated over the current
+    /// and the next rows of the execution trace.
+    pub fn frame_offsets(&self) -> &[isize] {
+        &self.frame_offsets
+    }
+
+    /// Returns the LogUp lookup argument used by the computation, if any.
+    pub fn logup(&self) -> Option<&LogUp> {
+        self.logup.as_ref()
+    }
+
+    /// Returns the number of steps at the end of the execution trace on which the transition
+    /// constraint of the LogUp lookup argument is not enforced.
+    ///
+    /// The final value of the LogUp running sum is located at step `trace_length - n`, where `n`
+    /// is the value returned by this method. Unless a custom number of exemptions was set for the
+    /// LogUp constraint, this is the same as
+    /// [num_transition_exemptions()](AirContext::num_transition_exemptions).
+    pub fn num_logup_transition_exemptions(&self) -> usize {
+        let constraint_idx = self.num_transition_constraints() - 1;
+        match self.transition_constraint_divisors[constraint_idx] {
+            0 => self.num_transition_exemptions,
+            divisor_idx => self.custom_transition_divisors[divisor_idx - 1].exemptions().len(),
+        }
+    }
+
     /// Returns the number of columns needed to store the constraint composition polynomial.
     ///
     /// This is the maximum of:
-    /// 1. The maximum evaluation degree over all transition constraints minus the degree of the
-    ///    transition constraint divisor divided by trace length.
+    /// 1. The maximum over all transition constraints of the constraint's evaluation degree minus
+    ///    the degree of the constraint's divisor, divided by trace length.
     /// 2. `1`, because the constraint composition polynomial requires at least one column.
     ///
     /// Since the degree of a constraint `C(x)` can be computed as
     ///
     ///   `[constraint.base + constraint.cycles.len()] * [trace_length - 1]`
     ///
-    /// the degree of the constraint composition polynomial can be computed as:
+    /// the degree of the constraint composition polynomial for constraints with the default
+    /// divisor can be computed as:
     ///
     ///   `([constraint.base + constraint.cycles.len()] * [trace_length - 1] - [trace_length - n])`
     ///
@@ -262,24 +516,32 @@ impl<B: StarkField> AirContext<B> {
     /// if the highest constraint degree is equal to `5`, the constraint composition polynomial will
     /// require four columns and if the highest constraint degree is equal to `7`, it will require
     /// six columns to store.
+    ///
+    /// Constraints with custom divisors (see
+    /// [set_transition_divisor()](AirContext::set_transition_divisor)) are divided by polynomials
+    /// of smaller degree, and thus, may require more columns.
+    ///
+    /// In zero-knowledge mode, trace polynomials are randomized, and the constraint composition
+    /// polynomial is split into segments of
+    /// [composition_segment_len()](AirContext::composition_segment_len) coefficients, with one
+    /// column per segment. An additional random column is committed to together with these
+    /// columns; it is the last column of the composition polynomial.
     pub fn num_constraint_composition_columns(&self) -> usize {
-        let mut highest_constraint_degree = 0_usize;
-        for degree in self
-            .main_transition_constraint_degrees
-            .iter()
-            .chain(self.aux_transition_constraint_degrees.iter())
-        {
-            let eval_degree = degree.get_evaluation_degree(self.trace_len());
-            if eval_degree > highest_constraint_degree {
-                highest_constraint_degree = eval_degree
-            }
+        if self.options.is_zk() {
+            // boundary constraints are divided by polynomials of degree at least one
+            let num_randomizer_coefficients = self.num_trace_randomizer_coefficients();
+            let boundary_degree = self.trace_len() + num_randomizer_coefficients - 2;
+            let composition_degree = cmp::max(
+                self.max_transition_composition_degree(num_randomizer_coefficients),
+                boundary_degree,
+            );
+            return (composition_degree + 1).div_ceil(self.composition_segment_len()) + 1;
         }
-        let trace_length = self.trace_len();
-        let transition_divisior_degree = trace_length - self.num_transition_exemptions();
+
+        let highest_composition_degree = self.max_transition_composition_degree(0);
 
         // we use the identity: ceil(a/b) = (a + b - 1)/b
-        let num_constraint_col =
-            (highest_constraint_degree - transition_divisior_degree).div_ceil(trace_length);
+        let num_constraint_col = highest_composition_degree.div_ceil(self.trace_len());
 
         cmp::max(num_constraint_col, 1)
     }
@@ -289,15 +551,26 @@ impl<B: StarkField> AirContext<B> {
 
     /// Sets the number of transition exemptions for this context.
     ///
+    /// The number of exemptions applies to all transition constraints except for the ones with
+    /// custom divisors or custom numbers of exemptions (see
+    /// [set_transition_exemptions()](AirContext::set_transition_exemptions)).
+    ///
     /// # Panics
     /// Panics if:
     /// * The number of exemptions is zero.
+    /// * The number of exemptions is smaller than the largest frame offset.
     /// * The number of exemptions exceeds half of the trace length.
     /// * Given the combination of transition constraints degrees and the blowup factor in this
-    ///   context, the number of exemptions is too larger for a valid computation of the constraint
-    ///   composition polynomial.
+    ///   context, the number of exemptions (together with the number of leading exemptions) is
+    ///   too larger for a valid computation of the constraint composition polynomial.
     pub fn set_num_transition_exemptions(mut self, n: usize) -> Self {
         assert!(n > 0, "number of transition exemptions must be greater than zero");
+        // rows near the end of the trace cannot reference rows which wrap around to the start
+        let max_offset = self.max_frame_offset();
+        assert!(
+            n >= max_offset,
+            "number of transition exemptions must be at least {max_offset}, but was {n}"
+        );
         // exemptions which are for more than half the trace plus one are probably a mistake
         assert!(
             n <= self.trace_len() / 2 + 1,
@@ -312,21 +585,312 @@ impl<B: StarkField> AirContext<B> {
         // degree of the divisor which results in an increase of the resulting constraint
         // composition polynomial.Thus we need to check that the number of exemption points
         // is not too large given the above.
-        for degree in self
+        // In zero-knowledge mode, the constraint evaluation domain grows together with the degree
+        // of the constraint composition polynomial, and thus, we only need to check that it still
+        // fits into the low-degree extension domain.
+        let num_leading_exemptions = self.num_leading_transition_exemptions();
+        for (degree, _) in self
             .main_transition_constraint_degrees
             .iter()
             .chain(self.aux_transition_constraint_degrees.iter())
+            .zip(self.transition_constraint_divisors.iter())
+            .filter(|(_, &divisor_idx)| divisor_idx == 0 && !self.options.is_zk())
         {
             let eval_degree = degree.get_evaluation_degree(self.trace_len());
             let max_constraint_composition_degree = self.ce_domain_size() - 1;
             let max_exemptions = max_constraint_composition_degree + self.trace_len() - eval_degree;
             assert!(
-                n <= max_exemptions,
-                "number of transition exemptions cannot exceed: {max_exemptions}, but was {n}"
+                n + num_leading_exemptions <= max_exemptions,
+                "number of transition exemptions cannot exceed: {}, but was {}",
+                max_exemptions.saturating_sub(num_leading_exemptions),
+                n
             )
         }
 
         self.num_transition_exemptions = n;
+        self.validate_ce_domain_size();
+        self
+    }
+
+    /// Sets the number of transition exemptions for the specified transition constraints.
+    ///
+    /// This is similar to [set_num_transition_exemptions()](AirContext::set_num_transition_exemptions),
+    /// but applies only to the specified constraints: these constraints must hold on all steps of
+    /// the execution trace except for the last `n` steps, while all other constraints are not
+    /// affected. Thus, constraints which must hold on all rows except the last one can be combined
+    /// with constraints which must be skipped on more rows. Leading exemptions implied by the
+    /// frame offsets (see
+    /// [num_leading_transition_exemptions()](AirContext::num_leading_transition_exemptions))
+    /// still apply to the specified constraints, except for the LogUp constraint.
+    ///
+    /// Constraints are identified by their index in the list of all transition constraints, where
+    /// constraints placed against the main trace segment come first, followed by the constraints
+    /// placed against the auxiliary trace segment. The number of exemptions must be large enough
+    /// so that the specified constraints do not reference rows which wrap around the end of the
+    /// trace.
+    ///
+    /// # Panics
+    /// Panics if:
+    /// * The number of exemptions is zero, is smaller than the largest frame offset, or exceeds
+    ///   half of the trace length.
+    /// * Any of the constraint indexes is out of bounds, or refers to a constraint for which a
+    ///   custom divisor or custom number of exemptions has already been set.
+    /// * Given the combination of transition constraints degrees and the blowup factor in this
+    ///   context, the number of exemptions is too large for a valid computation of the constraint
+    ///   composition polynomial.
+    pub fn set_transition_exemptions(self, constraints: &[usize], n: usize) -> Self {
+        assert!(n > 0, "number of transition exemptions must be greater than zero");
+        // rows near the end of the trace cannot reference rows which wrap around to the start
+        let max_offset = self.max_frame_offset();
+        assert!(
+            n >= max_offset,
+            "number of transition exemptions must be at least {max_offset}, but was {n}"
+        );
+        assert!(
+            n <= self.trace_len() / 2 + 1,
+            "number of transition exemptions cannot exceed {}, but was {}",
+            self.trace_len() / 2 + 1,
+            n
+        );
+        // the LogUp constraint references only the current and the next rows, and thus, does not
+        // need leading exemptions
+        let trace_length = self.trace_len();
+        let logup_idx = self.logup.as_ref().map(|_| self.num_transition_constraints() - 1);
+        let (logup_constraints, constraints): (Vec<usize>, Vec<usize>) =
+            constraints.iter().partition(|&&idx| Some(idx) == logup_idx);
+
+        let num_leading_exemptions = self.num_leading_transition_exemptions();
+        let divisor = ConstraintDivisor::from_transition_with_leading_exemptions(
+            trace_length,
+            num_leading_exemptions,
+            n,
+        );
+        let mut result = self;
+        if !constraints.is_empty() {
+            result = result.set_transition_divisor(&constraints, divisor);
+        }
+        if !logup_constraints.is_empty() {
+            let divisor = ConstraintDivisor::from_transition(trace_length, n);
+            result = result.set_transition_divisor(&logup_constraints, divisor);
+        }
+        result
+    }
+
+    /// Sets the row offsets of the evaluation frames over which transition constraints are
+    /// evaluated.
+    ///
+    /// Each offset specifies how many steps after the current step a row of the evaluation frame
+    /// is located, with negative offsets referring to rows preceding the current row. For
+    /// example, offsets `[0, 1, -7, -2, 16]` describe a frame in which transition constraints can
+    /// access the current row, the next row, the rows 7 and 2 steps before the current row, as
+    /// well as the row 16 steps ahead of the current row. The first two offsets must always be 0
+    /// and 1, and the remaining offsets must be strictly increasing.
+    ///
+    /// If the number of transition exemptions is smaller than the largest offset, it is increased
+    /// to be equal to the largest offset, as transition constraints cannot be applied to rows
+    /// for which the frame would wrap around the end of the trace. Similarly, if the frame reaches
+    /// back by `k` rows, transition constraints are not applied to the first `k` rows of the
+    /// trace (see [num_leading_transition_exemptions()](AirContext::num_leading_transition_exemptions)).
+    ///
+    /// Frame offsets must be set before any custom transition divisors or custom numbers of
+    /// transition exemptions are specified.
+    ///
+    /// # Panics
+    /// Panics if:
+    /// * The offsets do not start with 0 and 1, or the remaining offsets are not strictly
+    ///   increasing or repeat 0 or 1.
+    /// * The number of offsets exceeds 255.
+    /// * The largest offset, or the absolute value of the smallest offset, exceeds half of the
+    ///   trace length.
+    /// * A custom divisor has already been set for any of the transition constraints.
+    /// * Given the combination of transition constraints degrees and the blowup factor in this
+    ///   context, the resulting number of transition exemptions is too large.
+    pub fn set_frame_offsets(mut self, offsets: Vec<isize>) -> Self {
+        validate_frame_offsets(&offsets);
+        let max_reach = offsets.iter().map(|offset| offset.unsigned_abs()).max().unwrap_or(0);
+        assert!(
+            max_reach <= self.trace_len() / 2,
+            "frame offsets cannot reach more than {} rows from the current row, but were {:?}",
+            self.trace_len() / 2,
+            offsets
+        );
+        assert!(
+            self.custom_transition_divisors.is_empty(),
+            "frame offsets must be set before custom transition divisors"
+        );
+
+        self.frame_offsets = offsets;
+        // in zero-knowledge mode, the size of trace polynomials depends on the number of rows in
+        // the evaluation frame, and so does the size of the low-degree extension domain
+        self.lde_domain_generator = B::get_root_of_unity(self.lde_domain_size().ilog2());
+        // re-validating the number of transition exemptions also makes sure that the leading
+        // exemptions implied by the new offsets do not make the divisor too small
+        let num_exemptions = cmp::max(self.num_transition_exemptions, self.max_frame_offset());
+        self.set_num_transition_exemptions(num_exemptions)
+    }
+
+    /// Sets the divisor of the specified transition constraints.
+    ///
+    /// By default, all transition constraints must hold on all steps of the execution trace except
+    /// for the last [num_transition_exemptions()](AirContext::num_transition_exemptions) steps.
+    /// This method makes it possible to enforce a group of constraints only on a sparse set of
+    /// steps - e.g., on every k-th step starting at some offset (see
+    /// [ConstraintDivisor::from_periodic_transition()]) or on a coset of the trace domain (see
+    /// [ConstraintDivisor::from_coset()]). Compared to multiplying such constraints by a periodic
+    /// selector column, this does not increase constraint degrees; however, since the divisor has
+    /// a smaller degree than the default one, the constraint composition polynomial may require
+    /// more columns.
+    ///
+    /// Constraints are identified by their index in the list of all transition constraints, where
+    /// constraints placed against the main trace segment come first, followed by the constraints
+    /// placed against the auxiliary trace segment. Number of transition exemptions does not apply
+    /// to constraints with custom divisors; exemption points (if any) must be a part of the
+    /// divisor.
+    ///
+    /// # Panics
+    /// Panics if:
+    /// * Any of the constraint indexes is out of bounds, or refers to a constraint for which a
+    ///   custom divisor has already been set.
+    /// * The LogUp constraint is assigned a divisor other than the one built by
+    ///   [ConstraintDivisor::from_transition()] with at least as many exemptions as the largest
+    ///   frame offset.
+    /// * The degree of the divisor numerator is zero or exceeds the trace length.
+    /// * The divisor numerator vanishes on points outside of the trace domain (e.g., the offset
+    ///   of a coset passed to [ConstraintDivisor::from_coset()] is not in the trace domain).
+    /// * The blowup factor is too small to accommodate the constraint composition polynomial.
+    pub fn set_transition_divisor(
+        mut self,
+        constraints: &[usize],
+        divisor: ConstraintDivisor<B>,
+    ) -> Self {
+        let trace_length = self.trace_len();
+        let numerator_degree: usize = divisor.numerator().iter().map(|term| term.0).sum();
+        assert!(
+            divisor.degree() > 0 && numerator_degree <= trace_length,
+            "divisor numerator degree must be between 1 and {trace_length}, but was {numerator_degree}"
+        );
+        // every term x^k - b of the numerator must vanish only on points of the trace domain;
+        // this is the case if k divides the trace length and b^(n / k) = 1
+        for &(degree, constant) in divisor.numerator() {
+            assert!(
+                trace_length % degree == 0
+                    && constant.exp(((trace_length / degree) as u64).into()) == B::ONE,
+                "divisor numerator must vanish only on the trace domain, but contains term \
+                x^{degree} - {constant}"
+            );
+        }
+
+        let divisor_idx = match self.custom_transition_divisors.iter().position(|d| d == &divisor) {
+            Some(idx) => idx + 1,
+            None => {
+                self.custom_transition_divisors.push(divisor.clone());
+                self.custom_transition_divisors.len()
+            },
+        };
+
+        for &constraint_idx in constraints {
+            assert!(
+                constraint_idx < self.num_transition_constraints(),
+                "constraint index must be smaller than {}, but was {}",
+                self.num_transition_constraints(),
+                constraint_idx
+            );
+            assert_eq!(
+                self.transition_constraint_divisors[constraint_idx], 0,
+                "custom divisor for constraint {constraint_idx} has already been set"
+            );
+            if self.logup.is_some() && constraint_idx == self.num_transition_constraints() - 1 {
+                let num_exemptions = divisor.exemptions().len();
+                assert!(
+                    num_exemptions >= self.max_frame_offset()
+                        && divisor
+                            == ConstraintDivisor::from_transition(trace_length, num_exemptions),
+                    "divisor of the LogUp constraint must enforce it on all steps of the trace \
+                    except for the last {} or more steps",
+                    self.max_frame_offset()
+                );
+            }
+            self.transition_constraint_divisors[constraint_idx] = divisor_idx;
+
+            // make sure the constraint evaluation domain is large enough to accommodate the
+            // degree of the constraint composition polynomial
+            let degree = self.get_transition_constraint_degree(constraint_idx);
+            let composition_degree =
+                degree.get_evaluation_degree(trace_length).saturating_sub(divisor.degree());
+            let ce_blowup_factor =
+                (composition_degree + 1).div_ceil(trace_length).next_power_of_two();
+            self.ce_blowup_factor = cmp::max(self.ce_blowup_factor, ce_blowup_factor);
+        }
+
+        self.validate_ce_domain_size();
+
         self
     }
+
+    // HELPER FUNCTIONS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the degree descriptor of the transition constraint at the specified index in the
+    /// list of all transition constraints.
+    fn get_transition_constraint_degree(
+        &self,
+        constraint_idx: usize,
+    ) -> &TransitionConstraintDegree {
+        let num_main_constraints = self.main_transition_constraint_degrees.len();
+        if constraint_idx < num_main_constraints {
+            &self.main_transition_constraint_degrees[constraint_idx]
+        } else {
+            &self.aux_transition_constraint_degrees[constraint_idx - num_main_constraints]
+        }
+    }
+
+    /// Returns the highest degree of the transition constraint composition polynomials, i.e.,
+    /// the highest difference between the evaluation degree of a transition constraint and the
+    /// degree of its divisor, when each trace polynomial has `num_randomizer_coefficients`
+    /// additional coefficients.
+    fn max_transition_composition_degree(&self, num_randomizer_coefficients: usize) -> usize {
+        let trace_length = self.trace_len();
+        let divisor_degrees: Vec<usize> =
+            self.transition_divisors().iter().map(|divisor| divisor.degree()).collect();
+
+        self.main_transition_constraint_degrees
+            .iter()
+            .chain(self.aux_transition_constraint_degrees.iter())
+            .zip(self.transition_constraint_divisors())
+            .map(|(degree, divisor_idx)| {
+                degree
+                    .get_randomized_evaluation_degree(trace_length, num_randomizer_coefficients)
+                    .saturating_sub(divisor_degrees[divisor_idx])
+            })
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Makes sure that the constraint evaluation domain fits into the low-degree extension
+    /// domain.
+    fn validate_ce_domain_size(&self) {
+        assert!(
+            self.ce_domain_size() <= self.lde_domain_size(),
+            "blowup factor too small; expected at least {}, but was {}",
+            self.ce_domain_size() / self.trace_poly_size(),
+            self.options.blowup_factor()
+        );
+    }
+
+    /// Returns the largest (non-negative) row offset of the evaluation frame.
+    fn max_frame_offset(&self) -> usize {
+        self.frame_offsets
+            .iter()
+            .map(|&offset| offset.max(0) as usize)
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Returns true if the LogUp constraint relies on the default divisor, but the default
+    /// divisor contains leading exemptions which must not apply to the LogUp constraint.
+    fn has_separate_logup_divisor(&self) -> bool {
+        self.logup.is_some()
+            && self.num_leading_transition_exemptions() > 0
+            && self.transition_constraint_divisors.last() == Some(&0)
+    }
 }
@@ -61,6 +61,100 @@ impl<B: StarkField> ConstraintDivisor<B> {
         Self::new(vec![(constraint_enforcement_domain_size, B::ONE)], exemptions)
     }
 
+    /// Builds a divisor for transition constraints which must hold on all steps of the constraint
+    /// enforcement domain except for the first $j$ and the last $k$ steps.
+    ///
+    /// The divisor polynomial $z(x)$ has the form:
+    ///
+    /// $$ z(x) = \frac{x^n - 1}{ \prod_{i=0}^{j-1} (x - g^i) \cdot \prod_{i=1}^k (x - g^{n-i})} $$
+    ///
+    /// where, $n$ is the length of the execution trace and $g$ is the generator of the trace
+    /// domain. Leading exemption points are needed when transition constraints reference rows
+    /// preceding the current row, as such constraints cannot be applied to the first $j$ rows
+    /// of the trace.
+    pub fn from_transition_with_leading_exemptions(
+        constraint_enforcement_domain_size: usize,
+        num_leading_exemptions: usize,
+        num_exemptions: usize,
+    ) -> Self {
+        let mut result = Self::from_transition(constraint_enforcement_domain_size, num_exemptions);
+        result.exemptions.extend(
+            (0..num_leading_exemptions).map(|step| {
+                get_trace_domain_value_at::<B>(constraint_enforcement_domain_size, step)
+            }),
+        );
+        result
+    }
+
+    /// Builds a divisor for transition constraints which must hold only on every `period`-th step
+    /// of the execution trace starting with `first_step`.
+    ///
+    /// The divisor polynomial $z(x)$ has the form:
+    ///
+    /// $$ z(x) = \frac{x^m - g^{s \cdot m}}{ \prod_{i=1}^k (x - g^{s + (m - i) \cdot p})} $$
+    ///
+    /// where $p$ is the period, $s$ is the first step, $m = n / p$ is the number of steps on which
+    /// the constraints are enforced, $g$ is the generator of the trace domain, and $k$ is the
+    /// number of exemption points. Exemption points remove the last $k$ of these steps; this is
+    /// needed, for example, when a constraint would otherwise reference rows which wrap around
+    /// the end of the trace.
+    ///
+    /// Setting `period` to 1 and `first_step` to 0 yields the same divisor as
+    /// [ConstraintDivisor::from_transition()].
+    ///
+    /// # Panics
+    /// Panics if:
+    /// * `period` is not a power of two or is greater than `trace_length`.
+    /// * `first_step` is not smaller than `period`.
+    /// * `num_exemptions` is not smaller than the number of steps on which the constraints are
+    ///   enforced.
+    pub fn from_periodic_transition(
+        trace_length: usize,
+        first_step: usize,
+        period: usize,
+        num_exemptions: usize,
+    ) -> Self {
+        assert!(
+            period.is_power_of_two() && period <= trace_length,
+            "period must be a power of two smaller than or equal to {trace_length}, but was {period}"
+        );
+        assert!(
+            first_step < period,
+            "first step must be smaller than {period}, but was {first_step}"
+        );
+        let num_steps = trace_length / period;
+        assert!(
+            num_exemptions < num_steps,
+            "number of exemptions must be smaller than {num_steps}, but was {num_exemptions}"
+        );
+
+        let offset =
+            get_trace_domain_value_at::<B>(trace_length, first_step).exp((num_steps as u64).into());
+        let exemptions = (num_steps - num_exemptions..num_steps)
+            .map(|i| get_trace_domain_value_at::<B>(trace_length, first_step + i * period))
+            .collect();
+        Self::new(vec![(num_steps, offset)], exemptions)
+    }
+
+    /// Builds a divisor for constraints which must hold on all points of the coset
+    /// $c \cdot H$, where $c$ is the `coset_offset` and $H$ is the multiplicative subgroup of
+    /// size `coset_size`.
+    ///
+    /// The divisor polynomial has the form $z(x) = x^m - c^m$, where $m$ is the size of the coset.
+    /// For the quotient of a constraint by this divisor to be a polynomial, the coset must be
+    /// contained in the trace domain; that is, `coset_size` must divide the trace length and
+    /// the offset must be an element of the trace domain.
+    ///
+    /// # Panics
+    /// Panics if `coset_size` is not a power of two.
+    pub fn from_coset(coset_size: usize, coset_offset: B) -> Self {
+        assert!(
+            coset_size.is_power_of_two(),
+            "coset size must be a power of two, but was {coset_size}"
+        );
+        Self::new(vec![(coset_size, coset_offset.exp((coset_size as u64).into()))], vec![])
+    }
+
     /// Builds a divisor for a boundary constraint described by the assertion.
     ///
     /// For boundary constraints, the divisor polynomial is defined as:
@@ -136,6 +230,18 @@ impl<B: StarkField> ConstraintDivisor<B> {
         numerator / denominator
     }
 
+    /// Returns true if constraints with this divisor are enforced at the provided `x` coordinate.
+    ///
+    /// This is the case when the numerator of the divisor evaluates to zero at `x`, and `x` is
+    /// not one of the exemption points.
+    pub fn is_enforced_at(&self, x: B) -> bool {
+        let numerator_is_zero = self
+            .numerator
+            .iter()
+            .any(|&(degree, constant)| x.exp((degree as u64).into()) == constant);
+        numerator_is_zero && !self.exemptions.contains(&x)
+    }
+
     /// Evaluates the denominator of this divisor (the exemption points) at the provided `x`
     /// coordinate.
     #[inline(always)]
@@ -224,6 +330,77 @@ mod tests {
         assert_eq!(expected, div.evaluate_at(BaseElement::new(4)));
     }
 
+    #[test]
+    fn constraint_divisor_periodic_transition() {
+        let n = 16_usize;
+        let g = BaseElement::get_root_of_unity(n.trailing_zeros());
+
+        // with period 1 and no offset, the divisor is the same as the default transition divisor
+        let divisor = ConstraintDivisor::<BaseElement>::from_periodic_transition(n, 0, 1, 2);
+        assert_eq!(ConstraintDivisor::from_transition(n, 2), divisor);
+
+        // divisor for constraints enforced on steps 3, 7, 11, but not on step 15
+        let divisor = ConstraintDivisor::<BaseElement>::from_periodic_transition(n, 3, 4, 1);
+        assert_eq!(3, divisor.degree());
+        assert_eq!(&[(4, g.exp(12))], divisor.numerator());
+        assert_eq!(&[g.exp(15)], divisor.exemptions());
+
+        for i in 0..n {
+            let x = g.exp((i as u32).into());
+            assert_eq!(i % 4 == 3 && i != 15, divisor.is_enforced_at(x));
+        }
+
+        // z(x) = (x - g^3) * (x - g^7) * (x - g^11)
+        let poly = polynom::mul(
+            &polynom::mul(&[-g.exp(3), BaseElement::ONE], &[-g.exp(7), BaseElement::ONE]),
+            &[-g.exp(11), BaseElement::ONE],
+        );
+        let x = BaseElement::new(5);
+        assert_eq!(polynom::eval(&poly, x), divisor.evaluate_at(x));
+    }
+
+    #[test]
+    fn constraint_divisor_leading_exemptions() {
+        let n = 16_usize;
+        let g = BaseElement::get_root_of_unity(n.trailing_zeros());
+
+        // without leading exemptions, the divisor is the same as the default transition divisor
+        let divisor =
+            ConstraintDivisor::<BaseElement>::from_transition_with_leading_exemptions(n, 0, 2);
+        assert_eq!(ConstraintDivisor::from_transition(n, 2), divisor);
+
+        // divisor for constraints enforced on steps 3 through 14
+        let divisor =
+            ConstraintDivisor::<BaseElement>::from_transition_with_leading_exemptions(n, 3, 1);
+        assert_eq!(12, divisor.degree());
+        for i in 0..n {
+            let x = g.exp((i as u32).into());
+            assert_eq!((3..15).contains(&i), divisor.is_enforced_at(x));
+        }
+
+        let poly = (3..15).fold(vec![BaseElement::ONE], |acc, i| {
+            polynom::mul(&acc, &[-g.exp((i as u32).into()), BaseElement::ONE])
+        });
+        let x = BaseElement::new(5);
+        assert_eq!(polynom::eval(&poly, x), divisor.evaluate_at(x));
+    }
+
+    #[test]
+    fn constraint_divisor_coset() {
+        let n = 16_usize;
+        let g = BaseElement::get_root_of_unity(n.trailing_zeros());
+
+        // coset {g^2, g^6, g^10, g^14} is the same as steps 2, 6, 10, 14 of the trace domain
+        let divisor = ConstraintDivisor::from_coset(4, g.exp(2));
+        assert_eq!(ConstraintDivisor::from_periodic_transition(n, 2, 4, 0), divisor);
+
+        for i in 0..n {
+            let x = g.exp((i as u32).into());
+            assert_eq!(i % 4 == 2, divisor.is_enforced_at(x));
+            assert_eq!(i % 4 == 2, divisor.evaluate_at(x) == BaseElement::ZERO);
+        }
+    }
+
     #[test]
     fn constraint_divisor_equivalence() {
         let n = 8_usize;
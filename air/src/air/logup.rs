@@ -0,0 +1,296 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use alloc::vec::Vec;
+
+use math::{ExtensionOf, FieldElement};
+
+use super::{Assertion, EvaluationFrame, TransitionConstraintDegree};
+
+// LOGUP LOOKUP ARGUMENT
+// ================================================================================================
+
+/// Describes a LogUp lookup argument over columns of the main trace segment.
+///
+/// A LogUp argument proves that every tuple of values read from a set of *looked-up* columns
+/// appears in a *table* defined by another set of columns of the main trace segment. The number
+/// of times each table row is looked up is recorded in a *multiplicity* column.
+///
+/// Given random elements $\alpha$ and $\beta$, each tuple $v$ is mapped to
+/// $d(v) = \alpha + \sum_j \beta^j \cdot v_j$, and the argument checks that:
+///
+/// $$
+/// \sum_i \sum_k \frac{1}{d(l_k(i))} = \sum_i \frac{m(i)}{d(t(i))}
+/// $$
+///
+/// where $l_k(i)$ is the $k$th looked-up tuple at row $i$, $t(i)$ is the table tuple at row $i$,
+/// and $m(i)$ is the multiplicity at row $i$.
+///
+/// This is done via a single running-sum column appended to the last auxiliary trace segment.
+/// When an [AirContext](crate::AirContext) is instantiated via
+/// [AirContext::new_with_logup()](crate::AirContext::new_with_logup), the prover builds this
+/// column, and the transition constraint and assertions enforcing it are added automatically.
+/// Specifically:
+/// * The running-sum column is the last column of the last auxiliary trace segment.
+/// * The argument uses the last [num_rand_elements()](LogUp::num_rand_elements) random elements
+///   drawn for the last auxiliary trace segment.
+///
+/// Rows of the trace which are exempt from transition constraints (by default, only the last
+/// row) do not participate in the lookup argument.
+///
+/// # One-sided arguments
+/// An argument may also consist of only the looked-up side (see [LogUp::new_lookups()]) or only
+/// the table side (see [LogUp::new_table()]). Such arguments are useful when a proof consists of
+/// several tables (e.g., a CPU table which performs lookups, and a separate table which defines
+/// the looked-up values): each table accumulates its side of the argument into its own running
+/// sum, and instead of ending at zero, the running sum of each table ends at the value specified
+/// via [AuxRandElements::with_logup_sum()](crate::AuxRandElements::with_logup_sum). The argument
+/// is valid when these final sums add up to zero across all tables.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LogUp {
+    lookups: Vec<Vec<usize>>,
+    table: Vec<usize>,
+    multiplicity: Option<usize>,
+}
+
+impl LogUp {
+    // CONSTRUCTORS
+    // --------------------------------------------------------------------------------------------
+    /// Returns a new LogUp argument instantiated from the specified columns of the main trace
+    /// segment.
+    ///
+    /// Each element of `lookups` is a tuple of columns read at every row of the trace; every
+    /// such tuple must be a row of the table defined by `table` columns. Number of times each
+    /// table row is looked up must be recorded in the `multiplicity` column.
+    ///
+    /// For example, a range check of columns 3 and 5 against a table in column 7 with
+    /// multiplicities in column 8 can be described as `LogUp::new(vec![vec![3], vec![5]], vec![7],
+    /// 8)`.
+    ///
+    /// # Panics
+    /// Panics if:
+    /// * `lookups` or `table` is empty.
+    /// * Width of any of the looked-up tuples is different from the number of table columns.
+    pub fn new(lookups: Vec<Vec<usize>>, table: Vec<usize>, multiplicity: usize) -> Self {
+        assert!(!lookups.is_empty(), "at least one looked-up tuple must be specified");
+        assert!(!table.is_empty(), "at least one table column must be specified");
+        for lookup in lookups.iter() {
+            assert_eq!(
+                table.len(),
+                lookup.len(),
+                "width of looked-up tuples must be equal to the number of table columns"
+            );
+        }
+
+        Self {
+            lookups,
+            table,
+            multiplicity: Some(multiplicity),
+        }
+    }
+
+    /// Returns a new LogUp argument which consists only of the looked-up side.
+    ///
+    /// The running sum of this argument accumulates `sum(1 / d(l_k))` at every row; the table
+    /// in which the tuples are looked up is expected to be defined elsewhere.
+    ///
+    /// # Panics
+    /// Panics if:
+    /// * `lookups` is empty or any of the looked-up tuples is empty.
+    /// * Looked-up tuples have different widths.
+    pub fn new_lookups(lookups: Vec<Vec<usize>>) -> Self {
+        assert!(!lookups.is_empty(), "at least one looked-up tuple must be specified");
+        let width = lookups[0].len();
+        assert!(width > 0, "looked-up tuples must contain at least one column");
+        for lookup in lookups.iter() {
+            assert_eq!(width, lookup.len(), "all looked-up tuples must have the same width");
+        }
+
+        Self {
+            lookups,
+            table: Vec::new(),
+            multiplicity: None,
+        }
+    }
+
+    /// Returns a new LogUp argument which consists only of the table side.
+    ///
+    /// The running sum of this argument accumulates `-m / d(t)` at every row; the tuples which
+    /// are looked up in this table are expected to be defined elsewhere.
+    ///
+    /// # Panics
+    /// Panics if `table` is empty.
+    pub fn new_table(table: Vec<usize>, multiplicity: usize) -> Self {
+        assert!(!table.is_empty(), "at least one table column must be specified");
+        Self {
+            lookups: Vec::new(),
+            table,
+            multiplicity: Some(multiplicity),
+        }
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the tuples of main trace columns which are looked up in the table.
+    ///
+    /// This is empty if the argument consists only of the table side.
+    pub fn lookups(&self) -> &[Vec<usize>] {
+        &self.lookups
+    }
+
+    /// Returns main trace columns which define the table.
+    ///
+    /// This is empty if the argument consists only of the looked-up side.
+    pub fn table(&self) -> &[usize] {
+        &self.table
+    }
+
+    /// Returns the main trace column which holds multiplicities of table rows, or `None` if the
+    /// argument consists only of the looked-up side.
+    pub fn multiplicity(&self) -> Option<usize> {
+        self.multiplicity
+    }
+
+    /// Returns the number of columns this argument adds to the auxiliary trace segment.
+    pub fn aux_trace_width(&self) -> usize {
+        1
+    }
+
+    /// Returns the number of random elements this argument requires.
+    ///
+    /// This is 1 when looked-up values are single columns, and 2 otherwise.
+    pub fn num_rand_elements(&self) -> usize {
+        if self.tuple_width() == 1 {
+            1
+        } else {
+            2
+        }
+    }
+
+    /// Returns the degree of the transition constraint enforced by this argument.
+    ///
+    /// The degree is the number of denominators (one per looked-up tuple, plus one for the table
+    /// if present) plus 1.
+    pub fn transition_constraint_degree(&self) -> TransitionConstraintDegree {
+        TransitionConstraintDegree::new(self.num_denominators() + 1)
+    }
+
+    /// Returns the number of assertions placed against the running-sum column.
+    pub fn num_assertions(&self) -> usize {
+        2
+    }
+
+    /// Returns the largest index of a main trace column referenced by this argument.
+    pub fn max_column(&self) -> usize {
+        self.lookups
+            .iter()
+            .flatten()
+            .chain(self.table.iter())
+            .chain(self.multiplicity.iter())
+            .fold(0, |acc, &column| acc.max(column))
+    }
+
+    /// Returns the number of denominators in the transition constraint of this argument.
+    pub fn num_denominators(&self) -> usize {
+        self.lookups.len() + usize::from(!self.table.is_empty())
+    }
+
+    // CONSTRAINT EVALUATION
+    // --------------------------------------------------------------------------------------------
+
+    /// Evaluates the transition constraint of this argument.
+    ///
+    /// The constraint enforces that `s' - s = sum(1 / d(l_k)) - m / d(t)`, where `s` is the
+    /// running sum located in the `aux_column` of the auxiliary trace segment. To keep the
+    /// constraint a polynomial, both sides of the equation are multiplied by the product of all
+    /// denominators.
+    ///
+    /// `rand_elements` must contain exactly [num_rand_elements()](LogUp::num_rand_elements)
+    /// elements.
+    pub fn evaluate_transition<F, E>(
+        &self,
+        main_frame: &EvaluationFrame<F>,
+        aux_frame: &EvaluationFrame<E>,
+        aux_column: usize,
+        rand_elements: &[E],
+    ) -> E
+    where
+        F: FieldElement,
+        E: FieldElement<BaseField = F::BaseField> + ExtensionOf<F>,
+    {
+        let row = main_frame.current();
+
+        // compute sum(1 / d(l_k)) - m / d(t) as a fraction with the product of all denominators
+        // in the denominator
+        let (mut numerator, mut denominator) = match self.multiplicity {
+            Some(multiplicity) => (
+                -E::from(row[multiplicity]),
+                self.get_denominator(row, &self.table, rand_elements),
+            ),
+            None => (E::ZERO, E::ONE),
+        };
+        for lookup in self.lookups.iter() {
+            let lookup_den = self.get_denominator(row, lookup, rand_elements);
+            numerator = numerator * lookup_den + denominator;
+            denominator *= lookup_den;
+        }
+
+        let delta = aux_frame.next()[aux_column] - aux_frame.current()[aux_column];
+        delta * denominator - numerator
+    }
+
+    /// Returns assertions placed against the running-sum column of this argument.
+    ///
+    /// The running sum must start at zero and must be equal to `final_sum` at the first step
+    /// which is exempt from transition constraints. For arguments which include both sides of
+    /// the lookup, `final_sum` is zero.
+    pub fn get_assertions<E: FieldElement>(
+        &self,
+        aux_column: usize,
+        trace_length: usize,
+        num_transition_exemptions: usize,
+        final_sum: E,
+    ) -> Vec<Assertion<E>> {
+        vec![
+            Assertion::single(aux_column, 0, E::ZERO),
+            Assertion::single(aux_column, trace_length - num_transition_exemptions, final_sum),
+        ]
+    }
+
+    /// Returns the value `d(v) = alpha + sum(beta^j * v_j)` for the tuple `v` located in the
+    /// specified columns of the provided row.
+    pub fn get_denominator<F, E>(&self, row: &[F], columns: &[usize], rand_elements: &[E]) -> E
+    where
+        F: FieldElement,
+        E: FieldElement<BaseField = F::BaseField> + ExtensionOf<F>,
+    {
+        let alpha = rand_elements[0];
+        match columns {
+            [column] => alpha + E::from(row[*column]),
+            _ => {
+                let beta = rand_elements[1];
+                let mut power = E::ONE;
+                let mut result = alpha;
+                for &column in columns {
+                    result += power * E::from(row[column]);
+                    power *= beta;
+                }
+                result
+            },
+        }
+    }
+
+    // HELPER METHODS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the number of columns in each looked-up tuple.
+    fn tuple_width(&self) -> usize {
+        match self.lookups.first() {
+            Some(lookup) => lookup.len(),
+            None => self.table.len(),
+        }
+    }
+}
@@ -3,7 +3,11 @@
 // This source code is licensed under the MIT license found in the
 // LICENSE file in the root directory of this source tree.
 
-use alloc::{collections::BTreeMap, vec::Vec};
+use alloc::{
+    collections::BTreeMap,
+    string::{String, ToString},
+    vec::Vec,
+};
 
 use crypto::{RandomCoin, RandomCoinError};
 use math::{fft, ExtensibleField, ExtensionOf, FieldElement, StarkField, ToElements};
@@ -26,6 +30,7 @@ mod boundary;
 pub use boundary::{BoundaryConstraint, BoundaryConstraintGroup, BoundaryConstraints};
 
 mod transition;
+pub(crate) use transition::DEFAULT_FRAME_OFFSETS;
 pub use transition::{EvaluationFrame, TransitionConstraintDegree, TransitionConstraints};
 
 mod coefficients;
@@ -34,6 +39,9 @@ pub use coefficients::{ConstraintCompositionCoefficients, DeepCompositionCoeffic
 mod divisor;
 pub use divisor::ConstraintDivisor;
 
+mod logup;
+pub use logup::LogUp;
+
 #[cfg(test)]
 mod tests;
 
@@ -171,11 +179,18 @@ const MIN_CYCLE_LENGTH: usize = 2;
 /// * Override [Air::get_aux_assertions()] method. This method is similar to the
 ///   [Air::get_assertions()] method, but it should return assertions against columns of the
 ///   auxiliary trace segment.
+///
+/// Lookups and range checks can be described without implementing these methods by declaring a
+/// [LogUp] argument via [AirContext::new_with_logup()] constructor. In this case, the column of
+/// the auxiliary trace segment, as well as the constraints and assertions needed for the
+/// argument are built automatically; if the LogUp argument is the only component of the
+/// auxiliary trace segment, neither [Air::evaluate_aux_transition()] nor
+/// [Air::get_aux_assertions()] need to be overridden.
 pub trait Air: Send + Sync {
     /// Base field for the computation described by this AIR. STARK protocol for this computation
     /// may be executed in the base field, or in an extension of the base fields as specified
     /// by [ProofOptions] struct.
-    type BaseField: StarkField + ExtensibleField<2> + ExtensibleField<3>;
+    type BaseField: StarkField + ExtensibleField<2> + ExtensibleField<3> + ExtensibleField<4>;
 
     /// A type defining shape of public inputs for the computation described by this protocol.
     /// This could be any type as long as it can be serialized into a sequence of field elements.
@@ -283,12 +298,107 @@ pub trait Air: Send + Sync {
         Vec::new()
     }
 
+    // MULTI-TABLE PROOFS
+    // --------------------------------------------------------------------------------------------
+
+    /// Checks that the tables of a multi-table proof match the tables expected for this
+    /// computation.
+    ///
+    /// This function is invoked by the multi-table verifier before an instance of this AIR is
+    /// created for any of the tables. `trace_infos` contains the trace info of every table of the
+    /// proof in the order in which the tables appear in the proof. The function must check that
+    /// the proof contains exactly the expected tables (i.e., the number of tables, their order,
+    /// and their shapes), and that [Air::new()] can be safely invoked for every one of them.
+    /// Otherwise, a prover could omit tables which balance the LogUp sums of the other tables.
+    ///
+    /// The default implementation of this function rejects all sets of tables. It must be
+    /// overridden for AIRs describing computations proven via multi-table proofs.
+    #[allow(unused_variables)]
+    fn validate_tables(
+        trace_infos: &[TraceInfo],
+        pub_inputs: &Self::PublicInputs,
+    ) -> Result<(), String> {
+        Err("multi-table proofs are not supported by this AIR".to_string())
+    }
+
     // PROVIDED METHODS
     // --------------------------------------------------------------------------------------------
 
-    /// Returns a vector of field elements required for construction of the auxiliary trace segment.
+    /// Evaluates all transition constraints placed against the auxiliary trace segment.
     ///
-    /// The elements are drawn uniformly at random from the provided public coin.
+    /// This evaluates constraints defined via
+    /// [evaluate_aux_transition()](Air::evaluate_aux_transition) method as well as the constraint
+    /// of the [LogUp] argument (if any). The length of the `result` slice must be equal to the
+    /// total number of auxiliary transition constraints.
+    fn evaluate_all_aux_transitions<F, E>(
+        &self,
+        main_frame: &EvaluationFrame<F>,
+        aux_frame: &EvaluationFrame<E>,
+        periodic_values: &[F],
+        aux_rand_elements: &AuxRandElements<E>,
+        result: &mut [E],
+    ) where
+        F: FieldElement<BaseField = Self::BaseField>,
+        E: FieldElement<BaseField = Self::BaseField> + ExtensionOf<F>,
+    {
+        match self.context().logup() {
+            Some(logup) => {
+                let (result, logup_result) = result.split_at_mut(result.len() - 1);
+                if !result.is_empty() {
+                    self.evaluate_aux_transition(
+                        main_frame,
+                        aux_frame,
+                        periodic_values,
+                        aux_rand_elements,
+                        result,
+                    );
+                }
+
+                let rand_elements = aux_rand_elements.rand_elements();
+                logup_result[0] = logup.evaluate_transition(
+                    main_frame,
+                    aux_frame,
+                    self.trace_info().aux_segment_width() - 1,
+                    &rand_elements[rand_elements.len() - logup.num_rand_elements()..],
+                );
+            },
+            None => self.evaluate_aux_transition(
+                main_frame,
+                aux_frame,
+                periodic_values,
+                aux_rand_elements,
+                result,
+            ),
+        }
+    }
+
+    /// Returns all assertions placed against the auxiliary trace segment.
+    ///
+    /// This includes assertions returned from [get_aux_assertions()](Air::get_aux_assertions)
+    /// method as well as the assertions of the [LogUp] argument (if any).
+    fn get_all_aux_assertions<E: FieldElement<BaseField = Self::BaseField>>(
+        &self,
+        aux_rand_elements: &AuxRandElements<E>,
+    ) -> Vec<Assertion<E>> {
+        let mut assertions = self.get_aux_assertions(aux_rand_elements);
+        if let Some(logup) = self.context().logup() {
+            assertions.extend(logup.get_assertions(
+                self.trace_info().aux_segment_width() - 1,
+                self.trace_length(),
+                self.context().num_logup_transition_exemptions(),
+                aux_rand_elements.logup_sum(),
+            ));
+        }
+        assertions
+    }
+
+    /// Returns a vector of field elements required for construction of the auxiliary trace
+    /// segment.
+    ///
+    /// The elements are drawn uniformly at random from the provided public coin. This method is
+    /// intended for computations with a single auxiliary trace segment, and is equivalent to
+    /// drawing the elements for the first auxiliary segment via
+    /// [get_aux_segment_rand_elements()](Air::get_aux_segment_rand_elements).
     fn get_aux_rand_elements<E, R>(
         &self,
         public_coin: &mut R,
@@ -297,12 +407,31 @@ pub trait Air: Send + Sync {
         E: FieldElement<BaseField = Self::BaseField>,
         R: RandomCoin<BaseField = Self::BaseField>,
     {
-        let num_elements = self.trace_info().get_num_aux_segment_rand_elements();
+        let rand_elements = self.get_aux_segment_rand_elements(0, public_coin)?;
+        Ok(AuxRandElements::new(rand_elements))
+    }
+
+    /// Returns a vector of field elements required for construction of the auxiliary trace
+    /// segment at the specified index.
+    ///
+    /// The elements are drawn uniformly at random from the provided public coin. This method is
+    /// invoked once per auxiliary segment, after the commitments to all previous trace segments
+    /// have been absorbed into the public coin.
+    fn get_aux_segment_rand_elements<E, R>(
+        &self,
+        segment_idx: usize,
+        public_coin: &mut R,
+    ) -> Result<Vec<E>, RandomCoinError>
+    where
+        E: FieldElement<BaseField = Self::BaseField>,
+        R: RandomCoin<BaseField = Self::BaseField>,
+    {
+        let num_elements = self.trace_info().get_num_aux_segment_rand_elements_at(segment_idx);
         let mut rand_elements = Vec::with_capacity(num_elements);
         for _ in 0..num_elements {
             rand_elements.push(public_coin.draw()?);
         }
-        Ok(AuxRandElements::new(rand_elements))
+        Ok(rand_elements)
     }
 
     /// Returns values for all periodic columns used in the computation.
@@ -369,7 +498,7 @@ pub trait Air: Send + Sync {
     }
 
     /// Convert assertions returned from [get_assertions()](Air::get_assertions) and
-    /// [get_aux_assertions()](Air::get_aux_assertions) methods into boundary constraints.
+    /// [get_all_aux_assertions()](Air::get_all_aux_assertions) methods into boundary constraints.
     ///
     /// This function also assigns composition coefficients to each constraint, and groups the
     /// constraints by their divisors. The coefficients will be used to compute random linear
@@ -383,7 +512,7 @@ pub trait Air: Send + Sync {
             self.context(),
             self.get_assertions(),
             aux_rand_elements
-                .map(|aux_rand_elements| self.get_aux_assertions(aux_rand_elements))
+                .map(|aux_rand_elements| self.get_all_aux_assertions(aux_rand_elements))
                 .unwrap_or_default(),
             composition_coefficients,
         )
@@ -415,7 +544,9 @@ pub trait Air: Send + Sync {
     /// Returns degree of trace polynomials for an instance of the computation described by
     /// this AIR.
     ///
-    /// The degree is always `trace_length` - 1.
+    /// The degree is always `trace_length` - 1, unless zero-knowledge mode is enabled, in which
+    /// case trace polynomials are randomized and their degree may be higher (see
+    /// [AirContext::trace_poly_degree()]).
     fn trace_poly_degree(&self) -> usize {
         self.context().trace_poly_degree()
     }
@@ -435,9 +566,11 @@ pub trait Air: Send + Sync {
     /// hightest transition constraint degree. For example, if the hightest transition
     /// constraint degree = 3, `ce_blowup_factor` will be set to 4.
     ///
-    /// `ce_blowup_factor` is guaranteed to be smaller than or equal to the `lde_blowup_factor`.
+    /// `ce_blowup_factor` is guaranteed to be smaller than or equal to the `lde_blowup_factor`,
+    /// unless zero-knowledge mode is enabled. In zero-knowledge mode, this is the ratio between
+    /// the size of the constraint evaluation domain and the trace length.
     fn ce_blowup_factor(&self) -> usize {
-        self.context().ce_blowup_factor
+        self.ce_domain_size() / self.trace_length()
     }
 
     /// Returns size of the constraint evaluation domain.
@@ -449,14 +582,15 @@ pub trait Air: Send + Sync {
 
     /// Returns low-degree extension domain blowup factor for the computation described by this
     /// AIR. This is guaranteed to be a power of two, and is always either equal to or greater
-    /// than ce_blowup_factor.
+    /// than ce_blowup_factor, unless zero-knowledge mode is enabled.
     fn lde_blowup_factor(&self) -> usize {
         self.context().options.blowup_factor()
     }
 
     /// Returns the size of the low-degree extension domain.
     ///
-    /// This is guaranteed to be a power of two, and is equal to `trace_length * lde_blowup_factor`.
+    /// This is guaranteed to be a power of two, and is equal to
+    /// `(trace_poly_degree + 1) * lde_blowup_factor`.
     fn lde_domain_size(&self) -> usize {
         self.context().lde_domain_size()
     }
@@ -9,8 +9,8 @@ use crypto::{hashers::Blake3_256, DefaultRandomCoin, RandomCoin};
 use math::{fields::f64::BaseElement, get_power_series, polynom, FieldElement, StarkField};
 
 use super::{
-    Air, AirContext, Assertion, EvaluationFrame, ProofOptions, TraceInfo,
-    TransitionConstraintDegree,
+    Air, AirContext, Assertion, AuxRandElements, ConstraintDivisor, EvaluationFrame, LogUp,
+    ProofOptions, TraceInfo, TransitionConstraintDegree, TransitionConstraints,
 };
 use crate::{options::BatchingMethod, FieldExtension};
 
@@ -55,7 +55,321 @@ fn get_periodic_column_polys_num_values_not_power_of_two() {
 // TRANSITION CONSTRAINTS
 // ================================================================================================
 
-// TODO
+#[test]
+fn set_frame_offsets_with_look_back() {
+    let trace_length = 16;
+    let context =
+        build_context::<BaseElement>(trace_length, 4, 1).set_frame_offsets(vec![0, 1, -3, 2]);
+
+    // the frame reaches 3 rows back and 2 rows ahead of the current row
+    assert_eq!(3, context.num_leading_transition_exemptions());
+    assert_eq!(2, context.num_transition_exemptions());
+
+    let divisors = context.transition_divisors();
+    assert_eq!(1, divisors.len());
+    assert_eq!(
+        ConstraintDivisor::from_transition_with_leading_exemptions(trace_length, 3, 2),
+        divisors[0]
+    );
+
+    let constraints = TransitionConstraints::new(&context, &[BaseElement::ONE]);
+    assert_eq!(&divisors[0], constraints.divisor());
+
+    // the divisor has degree 11, and thus the composition polynomial has degree 19
+    assert_eq!(2, context.num_constraint_composition_columns());
+}
+
+#[test]
+#[should_panic(expected = "frame offsets must start with 0 and 1")]
+fn set_frame_offsets_without_next_row() {
+    let _ = build_context::<BaseElement>(16, 4, 1).set_frame_offsets(vec![0, -1, 2]);
+}
+
+#[test]
+fn get_transition_constraints_with_custom_divisor() {
+    let trace_length = 16_usize;
+    let g = BaseElement::get_root_of_unity(trace_length.ilog2());
+    let options = ProofOptions::new(
+        32,
+        8,
+        0,
+        FieldExtension::None,
+        4,
+        31,
+        BatchingMethod::Linear,
+        BatchingMethod::Linear,
+    );
+    let degrees = vec![TransitionConstraintDegree::new(2); 3];
+    let divisor = ConstraintDivisor::from_periodic_transition(trace_length, 1, 4, 0);
+    let context = AirContext::new(TraceInfo::new(4, trace_length), degrees, 1, options.clone())
+        .set_transition_divisor(&[0, 2], divisor.clone());
+
+    // the default divisor has degree 15, and thus the composition polynomial of degree 2
+    // constraints has degree 15; the custom divisor has degree 4, and thus the composition
+    // polynomial has degree 26
+    assert_eq!(2, context.num_constraint_composition_columns());
+    assert_eq!(vec![1, 0, 1], context.transition_constraint_divisors());
+
+    let coefficients = vec![BaseElement::new(3), BaseElement::new(5), BaseElement::new(7)];
+    let constraints = TransitionConstraints::new(&context, &coefficients);
+    assert_eq!(2, constraints.divisors().len());
+    assert_eq!(&ConstraintDivisor::from_transition(trace_length, 1), constraints.divisor());
+    assert_eq!(&divisor, &constraints.divisors()[1]);
+    assert_eq!(&[1, 0, 1], constraints.main_constraint_divisors());
+
+    // evaluations of constraints are combined based on their divisors
+    let evaluations = vec![BaseElement::new(11), BaseElement::new(13), BaseElement::new(17)];
+    let x = BaseElement::new(19);
+    let expected = (coefficients[0] * evaluations[0] + coefficients[2] * evaluations[2])
+        / divisor.evaluate_at(x)
+        + coefficients[1] * evaluations[1] / constraints.divisor().evaluate_at(x);
+    assert_eq!(expected, constraints.combine_evaluations::<BaseElement>(&evaluations, &[], x));
+
+    // setting the same divisor for another constraint does not create a new group
+    let context = AirContext::<BaseElement>::new(
+        TraceInfo::new(4, trace_length),
+        vec![TransitionConstraintDegree::new(2); 3],
+        1,
+        options,
+    )
+    .set_transition_divisor(&[0], divisor.clone())
+    .set_transition_divisor(&[2], ConstraintDivisor::from_coset(4, g));
+    assert_eq!(2, context.transition_divisors().len());
+    assert_eq!(vec![1, 0, 1], context.transition_constraint_divisors());
+}
+
+#[test]
+fn get_zk_context_parameters() {
+    let trace_length = 16_usize;
+    let options = build_options();
+    let degrees = vec![TransitionConstraintDegree::new(2); 3];
+
+    // without zero-knowledge, sizes are derived from the trace length only
+    let context = AirContext::<BaseElement>::new(
+        TraceInfo::new(4, trace_length),
+        degrees.clone(),
+        1,
+        options.clone(),
+    );
+    assert_eq!(trace_length, context.trace_poly_size());
+    assert_eq!(trace_length, context.composition_segment_len());
+    assert_eq!(trace_length * 2, context.ce_domain_size());
+    assert_eq!(trace_length * 8, context.lde_domain_size());
+    assert_eq!(1, context.num_constraint_composition_columns());
+    assert_eq!(0, context.num_salt_elements());
+
+    // trace polynomials are randomized by (32 + 1) * 2 = 66 coefficients, and thus do not fit
+    // into 16 coefficients anymore; composition columns are masked by 32 + 2 = 34 coefficients
+    let context = AirContext::<BaseElement>::new(
+        TraceInfo::new(4, trace_length),
+        degrees,
+        1,
+        options.with_zk(),
+    );
+    assert_eq!(66, context.num_trace_randomizer_coefficients());
+    assert_eq!(128, context.trace_poly_size());
+    assert_eq!(127, context.trace_poly_degree());
+    assert_eq!(94, context.composition_segment_len());
+    assert_eq!(128 * 8, context.lde_domain_size());
+
+    // composition polynomial has degree 2 * (15 + 66) - 15 = 147, and thus requires 2 segments
+    // plus one random column
+    assert_eq!(256, context.ce_domain_size());
+    assert_eq!(3, context.num_constraint_composition_columns());
+    assert_eq!(2, context.num_salt_elements());
+}
+
+#[test]
+#[should_panic(expected = "custom divisor for constraint 0 has already been set")]
+fn set_transition_divisor_twice() {
+    let trace_length = 16;
+    let divisor = ConstraintDivisor::from_periodic_transition(trace_length, 1, 4, 0);
+    let _ = build_context::<BaseElement>(trace_length, 4, 1)
+        .set_transition_divisor(&[0], divisor.clone())
+        .set_transition_divisor(&[0], divisor);
+}
+
+#[test]
+#[should_panic(expected = "divisor numerator must vanish only on the trace domain")]
+fn set_coset_divisor_outside_of_trace_domain() {
+    let trace_length = 16;
+    let divisor = ConstraintDivisor::from_coset(4, BaseElement::GENERATOR);
+    let _ = build_context::<BaseElement>(trace_length, 4, 1).set_transition_divisor(&[0], divisor);
+}
+
+#[test]
+fn set_frame_offsets_with_look_back_and_logup() {
+    let trace_length = 16;
+    let options = ProofOptions::new(
+        32,
+        8,
+        0,
+        FieldExtension::None,
+        4,
+        31,
+        BatchingMethod::Linear,
+        BatchingMethod::Linear,
+    );
+    let trace_info = TraceInfo::new_multi_segment(4, 1, 1, trace_length, vec![]);
+    let logup = LogUp::new(vec![vec![0], vec![1]], vec![2], 3);
+    let context = AirContext::<BaseElement>::new_with_logup(
+        trace_info,
+        vec![TransitionConstraintDegree::new(1)],
+        vec![],
+        1,
+        0,
+        logup,
+        options,
+    )
+    .set_frame_offsets(vec![0, 1, -2]);
+
+    // the LogUp constraint must hold on the first rows of the trace, and thus, it gets its own
+    // divisor without leading exemptions
+    let divisors = context.transition_divisors();
+    assert_eq!(2, divisors.len());
+    assert_eq!(
+        ConstraintDivisor::from_transition_with_leading_exemptions(trace_length, 2, 1),
+        divisors[0]
+    );
+    assert_eq!(ConstraintDivisor::from_transition(trace_length, 1), divisors[1]);
+    assert_eq!(vec![0, 1], context.transition_constraint_divisors());
+    assert_eq!(1, context.num_logup_transition_exemptions());
+
+    // custom exemptions for the LogUp constraint also do not include leading exemptions
+    let context = context.set_transition_exemptions(&[0, 1], 2);
+    let divisors = context.transition_divisors();
+    assert_eq!(3, divisors.len());
+    assert_eq!(
+        ConstraintDivisor::from_transition_with_leading_exemptions(trace_length, 2, 2),
+        divisors[1]
+    );
+    assert_eq!(ConstraintDivisor::from_transition(trace_length, 2), divisors[2]);
+    assert_eq!(vec![1, 2], context.transition_constraint_divisors());
+    assert_eq!(2, context.num_logup_transition_exemptions());
+}
+
+#[test]
+#[should_panic(expected = "frame offsets must be set before custom transition divisors")]
+fn set_frame_offsets_after_custom_divisor() {
+    let trace_length = 16;
+    let divisor = ConstraintDivisor::from_periodic_transition(trace_length, 1, 4, 0);
+    let _ = build_context::<BaseElement>(trace_length, 4, 1)
+        .set_transition_divisor(&[0], divisor)
+        .set_frame_offsets(vec![0, 1, -1]);
+}
+
+#[test]
+fn get_transition_constraints_with_custom_exemptions() {
+    let trace_length = 16;
+    let degrees = vec![TransitionConstraintDegree::new(2); 3];
+    let context = AirContext::<BaseElement>::new(
+        TraceInfo::new(4, trace_length),
+        degrees,
+        1,
+        build_options(),
+    )
+    .set_num_transition_exemptions(2)
+    .set_transition_exemptions(&[1], 4);
+
+    // the default divisor now has degree 14, and the divisor of the second constraint has
+    // degree 12; thus, the composition polynomial has degree 18
+    assert_eq!(2, context.num_constraint_composition_columns());
+    assert_eq!(vec![0, 1, 0], context.transition_constraint_divisors());
+    assert_eq!(2, context.num_transition_exemptions());
+
+    let coefficients = vec![BaseElement::new(3), BaseElement::new(5), BaseElement::new(7)];
+    let constraints = TransitionConstraints::new(&context, &coefficients);
+    assert_eq!(&ConstraintDivisor::from_transition(trace_length, 2), constraints.divisor());
+    assert_eq!(&ConstraintDivisor::from_transition(trace_length, 4), &constraints.divisors()[1]);
+
+    let evaluations = vec![BaseElement::new(11), BaseElement::new(13), BaseElement::new(17)];
+    let x = BaseElement::new(19);
+    let expected = (coefficients[0] * evaluations[0] + coefficients[2] * evaluations[2])
+        / constraints.divisor().evaluate_at(x)
+        + coefficients[1] * evaluations[1] / constraints.divisors()[1].evaluate_at(x);
+    assert_eq!(expected, constraints.combine_evaluations::<BaseElement>(&evaluations, &[], x));
+}
+
+#[test]
+fn get_logup_assertions_with_custom_exemptions() {
+    let trace_length = 16;
+    let trace_info = TraceInfo::new_multi_segment(4, 1, 1, trace_length, vec![]);
+    let logup = LogUp::new(vec![vec![0], vec![1]], vec![2], 3);
+    let context = AirContext::<BaseElement>::new_with_logup(
+        trace_info,
+        vec![TransitionConstraintDegree::new(1)],
+        vec![],
+        1,
+        0,
+        logup,
+        build_options(),
+    );
+    assert_eq!(1, context.num_logup_transition_exemptions());
+
+    // the LogUp constraint is the last transition constraint
+    let context = context.set_transition_exemptions(&[1], 3);
+    assert_eq!(1, context.num_transition_exemptions());
+    assert_eq!(3, context.num_logup_transition_exemptions());
+
+    let logup_sum = BaseElement::new(7);
+    let aux_rand_elements =
+        AuxRandElements::new(vec![BaseElement::new(5)]).with_logup_sum(logup_sum);
+    let air = MockAir {
+        context,
+        assertions: vec![],
+        periodic_columns: vec![],
+    };
+    let assertions = air.get_all_aux_assertions(&aux_rand_elements);
+    assert!(assertions.contains(&Assertion::single(0, trace_length - 3, logup_sum)));
+}
+
+#[test]
+#[should_panic(expected = "divisor of the LogUp constraint must enforce it on all steps")]
+fn set_periodic_divisor_for_logup_constraint() {
+    let trace_length = 16;
+    let trace_info = TraceInfo::new_multi_segment(4, 1, 1, trace_length, vec![]);
+    let logup = LogUp::new(vec![vec![0], vec![1]], vec![2], 3);
+    let _ = AirContext::<BaseElement>::new_with_logup(
+        trace_info,
+        vec![TransitionConstraintDegree::new(1)],
+        vec![],
+        1,
+        0,
+        logup,
+        build_options(),
+    )
+    .set_transition_divisor(
+        &[1],
+        ConstraintDivisor::from_periodic_transition(trace_length, 0, 2, 1),
+    );
+}
+
+#[test]
+#[should_panic(expected = "divisor of the LogUp constraint must enforce it on all steps")]
+fn set_coset_divisor_for_logup_constraint() {
+    let trace_length = 16_usize;
+    let g = BaseElement::get_root_of_unity(trace_length.ilog2());
+    let trace_info = TraceInfo::new_multi_segment(4, 1, 1, trace_length, vec![]);
+    let logup = LogUp::new(vec![vec![0], vec![1]], vec![2], 3);
+    let _ = AirContext::<BaseElement>::new_with_logup(
+        trace_info,
+        vec![TransitionConstraintDegree::new(1)],
+        vec![],
+        1,
+        0,
+        logup,
+        build_options(),
+    )
+    .set_transition_divisor(&[1], ConstraintDivisor::from_coset(trace_length, g));
+}
+
+#[test]
+#[should_panic(expected = "number of transition exemptions must be at least 4, but was 2")]
+fn set_transition_exemptions_below_frame_offset() {
+    let _ = build_context::<BaseElement>(16, 4, 1)
+        .set_frame_offsets(vec![0, 1, 4])
+        .set_transition_exemptions(&[0], 2);
+}
 
 // BOUNDARY CONSTRAINTS
 // ================================================================================================
@@ -283,7 +597,14 @@ pub fn build_context<B: StarkField>(
     trace_width: usize,
     num_assertions: usize,
 ) -> AirContext<B> {
-    let options = ProofOptions::new(
+    let options = build_options();
+    let t_degrees = vec![TransitionConstraintDegree::new(2)];
+    let trace_info = TraceInfo::new(trace_width, trace_length);
+    AirContext::new(trace_info, t_degrees, num_assertions, options)
+}
+
+pub fn build_options() -> ProofOptions {
+    ProofOptions::new(
         32,
         8,
         0,
@@ -292,10 +613,7 @@ pub fn build_context<B: StarkField>(
         31,
         BatchingMethod::Linear,
         BatchingMethod::Linear,
-    );
-    let t_degrees = vec![TransitionConstraintDegree::new(2)];
-    let trace_info = TraceInfo::new(trace_width, trace_length);
-    AirContext::new(trace_info, t_degrees, num_assertions, options)
+    )
 }
 
 pub fn build_prng() -> DefaultRandomCoin<Blake3_256<BaseElement>> {
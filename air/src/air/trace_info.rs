@@ -16,15 +16,17 @@ use utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serial
 /// Information about a specific execution trace.
 ///
 /// Trace info consists of the number of columns for all trace segments, trace length, the number of
-/// random elements needed to generate the auxiliary segment and optional custom metadata.
+/// random elements needed to generate each of the auxiliary segments and optional custom metadata.
 ///
-/// Currently, a trace can consist of at most two segments: the main segment and one auxiliary
-/// segment. Metadata is just a vector of bytes and can store any values up to 64KB in size.
+/// A trace consists of the main segment and zero or more auxiliary segments. Auxiliary segments
+/// are built one after another: random elements for an auxiliary segment are drawn after the
+/// prover commits to all previous segments. Metadata is just a vector of bytes and can store any
+/// values up to 64KB in size.
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub struct TraceInfo {
     main_segment_width: usize,
-    aux_segment_width: usize,
-    num_aux_segment_rands: usize,
+    aux_segment_widths: Vec<usize>,
+    aux_segment_rands: Vec<usize>,
     trace_length: usize,
     trace_meta: Vec<u8>,
 }
@@ -36,7 +38,7 @@ impl TraceInfo {
     pub const MAX_TRACE_WIDTH: usize = 255;
     /// Maximum number of bytes in trace metadata; currently set at 65535.
     pub const MAX_META_LENGTH: usize = 65535;
-    /// Maximum number of random elements in the auxiliary trace segment; currently set to 255.
+    /// Maximum number of random elements in an auxiliary trace segment; currently set to 255.
     pub const MAX_RAND_SEGMENT_ELEMENTS: usize = 255;
 
     // CONSTRUCTORS
@@ -68,14 +70,15 @@ impl TraceInfo {
         Self::new_multi_segment(width, 0, 0, length, meta)
     }
 
-    /// Creates a new [TraceInfo] with main and auxiliary segments.
+    /// Creates a new [TraceInfo] with the main segment and at most one auxiliary segment.
+    ///
+    /// If `aux_segment_width` is zero, the trace consists of the main segment only.
     ///
     /// # Panics
     /// Panics if:
     /// * The width of the first trace segment is zero.
     /// * Total width of all trace segments is greater than 255.
     /// * Trace length is smaller than 8 or is not a power of two.
-    /// * A zero entry in auxiliary segment width array is followed by a non-zero entry.
     /// * Number of random elements for the auxiliary trace segment of non-zero width is set to
     ///   zero.
     /// * Number of random elements for the auxiliary trace segment of zero width is set to
@@ -87,6 +90,45 @@ impl TraceInfo {
         num_aux_segment_rands: usize,
         trace_length: usize,
         trace_meta: Vec<u8>,
+    ) -> Self {
+        if aux_segment_width == 0 {
+            assert!(
+                num_aux_segment_rands == 0,
+                "number of random elements for an empty auxiliary trace segment must be zero"
+            );
+            Self::new_with_aux_segments(main_segment_width, &[], &[], trace_length, trace_meta)
+        } else {
+            Self::new_with_aux_segments(
+                main_segment_width,
+                &[aux_segment_width],
+                &[num_aux_segment_rands],
+                trace_length,
+                trace_meta,
+            )
+        }
+    }
+
+    /// Creates a new [TraceInfo] with the main segment and the specified auxiliary segments.
+    ///
+    /// Width of the auxiliary segment at index `i` is specified by `aux_segment_widths[i]`, and
+    /// the number of random elements needed to build it is specified by `aux_segment_rands[i]`.
+    /// Random elements for a segment are drawn only after the prover has committed to all
+    /// previous segments.
+    ///
+    /// # Panics
+    /// Panics if:
+    /// * The width of the first trace segment is zero.
+    /// * Total width of all trace segments is greater than 255.
+    /// * Trace length is smaller than 8 or is not a power of two.
+    /// * Lengths of `aux_segment_widths` and `aux_segment_rands` are not the same.
+    /// * Width of any of the auxiliary trace segments is zero.
+    /// * Number of random elements for any auxiliary trace segment is zero or greater than 255.
+    pub fn new_with_aux_segments(
+        main_segment_width: usize,
+        aux_segment_widths: &[usize],
+        aux_segment_rands: &[usize],
+        trace_length: usize,
+        trace_meta: Vec<u8>,
     ) -> Self {
         assert!(
             trace_length >= Self::MIN_TRACE_LENGTH,
@@ -107,7 +149,15 @@ impl TraceInfo {
 
         // validate trace segment widths
         assert!(main_segment_width > 0, "main trace segment must consist of at least one column");
-        let full_width = main_segment_width + aux_segment_width;
+        assert_eq!(
+            aux_segment_widths.len(),
+            aux_segment_rands.len(),
+            "number of auxiliary segment widths must be equal to the number of random element counts"
+        );
+        for &width in aux_segment_widths {
+            assert!(width > 0, "auxiliary trace segment must consist of at least one column");
+        }
+        let full_width = main_segment_width + aux_segment_widths.iter().sum::<usize>();
         assert!(
             full_width <= TraceInfo::MAX_TRACE_WIDTH,
             "total number of columns in the trace cannot be greater than {}, but was {}",
@@ -115,24 +165,24 @@ impl TraceInfo {
             full_width
         );
 
-        // validate number of random elements required by the auxiliary segment
-        if aux_segment_width == 0 {
+        // validate number of random elements required by each of the auxiliary segments
+        for &num_rands in aux_segment_rands {
             assert!(
-                num_aux_segment_rands == 0,
-                "number of random elements for an empty auxiliary trace segment must be zero"
+                num_rands > 0,
+                "number of random elements for a non-empty auxiliary trace segment must be greater than zero"
+            );
+            assert!(
+                num_rands <= TraceInfo::MAX_RAND_SEGMENT_ELEMENTS,
+                "number of random elements required by a segment cannot exceed {}, but was {}",
+                TraceInfo::MAX_RAND_SEGMENT_ELEMENTS,
+                num_rands
             );
         }
-        assert!(
-            num_aux_segment_rands <= TraceInfo::MAX_RAND_SEGMENT_ELEMENTS,
-            "number of random elements required by a segment cannot exceed {}, but was {}",
-            TraceInfo::MAX_RAND_SEGMENT_ELEMENTS,
-            num_aux_segment_rands
-        );
 
         TraceInfo {
             main_segment_width,
-            aux_segment_width,
-            num_aux_segment_rands,
+            aux_segment_widths: aux_segment_widths.to_vec(),
+            aux_segment_rands: aux_segment_rands.to_vec(),
             trace_length,
             trace_meta,
         }
@@ -145,7 +195,7 @@ impl TraceInfo {
     ///
     /// This is guaranteed to be between 1 and 255.
     pub fn width(&self) -> usize {
-        self.main_segment_width + self.aux_segment_width
+        self.main_segment_width + self.aux_segment_width()
     }
 
     /// Returns execution trace length.
@@ -160,9 +210,9 @@ impl TraceInfo {
         &self.trace_meta
     }
 
-    /// Returns true if an execution trace contains the auxiliary trace segment.
+    /// Returns true if an execution trace contains at least one auxiliary trace segment.
     pub fn is_multi_segment(&self) -> bool {
-        self.aux_segment_width > 0
+        !self.aux_segment_widths.is_empty()
     }
 
     /// Returns the number of columns in the main segment of an execution trace.
@@ -172,37 +222,58 @@ impl TraceInfo {
         self.main_segment_width
     }
 
-    /// Returns the number of columns in the auxiliary segment of an execution trace.
+    /// Returns the total number of columns in all auxiliary segments of an execution trace.
+    ///
+    /// Columns of the auxiliary segments are indexed consecutively: the first column of an
+    /// auxiliary segment immediately follows the last column of the previous auxiliary segment.
     pub fn aux_segment_width(&self) -> usize {
-        self.aux_segment_width
+        self.aux_segment_widths.iter().sum()
     }
 
     /// Returns the total number of segments in an execution trace.
     pub fn num_segments(&self) -> usize {
-        if self.is_multi_segment() {
-            2
-        } else {
-            1
-        }
+        1 + self.num_aux_segments()
     }
 
     /// Returns the number of auxiliary trace segments in an execution trace.
     pub fn num_aux_segments(&self) -> usize {
-        if self.is_multi_segment() {
-            1
-        } else {
-            0
-        }
+        self.aux_segment_widths.len()
     }
 
     /// Returns the number of columns in the auxiliary trace segment.
+    ///
+    /// For execution traces with multiple auxiliary segments, this is the total number of columns
+    /// in all auxiliary segments (i.e., the same as
+    /// [aux_segment_width()](TraceInfo::aux_segment_width)); widths of individual segments can be
+    /// retrieved via [get_aux_segment_width_at()](TraceInfo::get_aux_segment_width_at).
     pub fn get_aux_segment_width(&self) -> usize {
-        self.aux_segment_width
+        self.aux_segment_width()
     }
 
     /// Returns the number of random elements needed to build all auxiliary columns.
+    ///
+    /// For execution traces with multiple auxiliary segments, random elements needed to build
+    /// individual segments can be retrieved via
+    /// [get_num_aux_segment_rand_elements_at()](TraceInfo::get_num_aux_segment_rand_elements_at).
     pub fn get_num_aux_segment_rand_elements(&self) -> usize {
-        self.num_aux_segment_rands
+        self.aux_segment_rands.iter().sum()
+    }
+
+    /// Returns the number of columns in the auxiliary trace segment at the specified index.
+    ///
+    /// # Panics
+    /// Panics if `segment_idx` is greater than or equal to the number of auxiliary segments.
+    pub fn get_aux_segment_width_at(&self, segment_idx: usize) -> usize {
+        self.aux_segment_widths[segment_idx]
+    }
+
+    /// Returns the number of random elements needed to build the auxiliary trace segment at the
+    /// specified index.
+    ///
+    /// # Panics
+    /// Panics if `segment_idx` is greater than or equal to the number of auxiliary segments.
+    pub fn get_num_aux_segment_rand_elements_at(&self, segment_idx: usize) -> usize {
+        self.aux_segment_rands[segment_idx]
     }
 }
 
@@ -215,12 +286,19 @@ impl<E: StarkField> ToElements<E> for TraceInfo {
         // be encoded in 8 bits (which is enforced by the constructor)
         let mut buf = self.main_segment_width as u32;
         buf = (buf << 8) | self.num_aux_segments() as u32;
-        if self.num_aux_segments() == 1 {
-            buf = (buf << 8) | self.aux_segment_width as u32;
-            buf = (buf << 8) | self.num_aux_segment_rands as u32;
+        if self.is_multi_segment() {
+            buf = (buf << 8) | self.aux_segment_widths[0] as u32;
+            buf = (buf << 8) | self.aux_segment_rands[0] as u32;
         }
         result.push(E::from(buf));
 
+        // parameters of each of the remaining auxiliary segments go into a separate field element
+        for (&width, &num_rands) in
+            self.aux_segment_widths.iter().zip(self.aux_segment_rands.iter()).skip(1)
+        {
+            result.push(E::from(((width as u32) << 8) | num_rands as u32));
+        }
+
         // We assume here that the trace length is never greater than 2^32.
         result.push(E::from(self.trace_length as u32));
 
@@ -243,16 +321,30 @@ impl Serializable for TraceInfo {
         // store segments
         target.write_u8(self.main_segment_width as u8);
 
-        debug_assert!(
-            self.aux_segment_width <= u8::MAX as usize,
-            "aux segment width does not fit into u8 value"
-        );
-        target.write_u8(self.aux_segment_width as u8);
-        debug_assert!(
-            self.num_aux_segment_rands <= u8::MAX as usize,
-            "aux segment random element count does not fit into u8 value"
-        );
-        target.write_u8(self.num_aux_segment_rands as u8);
+        // traces with at most one auxiliary segment are encoded as the width of the auxiliary
+        // segment followed by the number of its random elements (which is zero for traces without
+        // auxiliary segments). Traces with more auxiliary segments are encoded as a zero width
+        // followed by the number of auxiliary segments and the parameters of each segment; since
+        // a zero-width segment never requires random elements, the two encodings cannot be
+        // confused. The total trace width is at most 255, and thus, the number of auxiliary
+        // segments and their widths fit into u8 values.
+        if self.num_aux_segments() > 1 {
+            target.write_u8(0);
+            target.write_u8(self.num_aux_segments() as u8);
+        }
+        let aux_segments = self.aux_segment_widths.iter().zip(self.aux_segment_rands.iter());
+        for (&width, &num_rands) in aux_segments {
+            target.write_u8(width as u8);
+            debug_assert!(
+                num_rands <= u8::MAX as usize,
+                "aux segment random element count does not fit into u8 value"
+            );
+            target.write_u8(num_rands as u8);
+        }
+        if !self.is_multi_segment() {
+            target.write_u8(0);
+            target.write_u8(0);
+        }
 
         // store trace length as power of two
         target.write_u8(self.trace_length.ilog2() as u8);
@@ -277,10 +369,50 @@ impl Deserializable for TraceInfo {
             ));
         }
 
-        // read auxiliary trace segment width
-        let aux_segment_width = source.read_u8()? as usize;
+        // read auxiliary trace segment widths and numbers of random elements; see the comment in
+        // `write_into()` for the description of the encoding
+        let (num_aux_segments, first_segment) = match (source.read_u8()?, source.read_u8()?) {
+            (0, 0) => (0, None),
+            (0, num_aux_segments) if num_aux_segments < 2 => {
+                return Err(DeserializationError::InvalidValue(
+                    "an empty auxiliary trace segment cannot require random elements".to_string(),
+                ));
+            },
+            (0, num_aux_segments) => (num_aux_segments as usize, None),
+            (width, num_rands) => (1, Some((width, num_rands))),
+        };
+
+        let mut aux_segment_widths = Vec::with_capacity(num_aux_segments);
+        let mut aux_segment_rands = Vec::with_capacity(num_aux_segments);
+        for _ in 0..num_aux_segments {
+            let (aux_segment_width, num_aux_segment_rands) = match first_segment {
+                Some((width, num_rands)) => (width as usize, num_rands as usize),
+                None => (source.read_u8()? as usize, source.read_u8()? as usize),
+            };
+            if aux_segment_width == 0 {
+                return Err(DeserializationError::InvalidValue(
+                    "auxiliary trace segment width must be greater than zero".to_string(),
+                ));
+            }
+
+            if num_aux_segment_rands == 0 {
+                return Err(DeserializationError::InvalidValue(
+                    "a non-empty trace segment must require at least one random element"
+                        .to_string(),
+                ));
+            } else if num_aux_segment_rands > TraceInfo::MAX_RAND_SEGMENT_ELEMENTS {
+                return Err(DeserializationError::InvalidValue(format!(
+                    "number of random elements required by a segment cannot exceed {}, but was {}",
+                    TraceInfo::MAX_RAND_SEGMENT_ELEMENTS,
+                    num_aux_segment_rands
+                )));
+            }
 
-        let full_trace_width = main_segment_width + aux_segment_width;
+            aux_segment_widths.push(aux_segment_width);
+            aux_segment_rands.push(num_aux_segment_rands);
+        }
+
+        let full_trace_width = main_segment_width + aux_segment_widths.iter().sum::<usize>();
         if full_trace_width >= TraceInfo::MAX_TRACE_WIDTH {
             return Err(DeserializationError::InvalidValue(format!(
                 "full trace width cannot be greater than {}, but was {}",
@@ -289,20 +421,6 @@ impl Deserializable for TraceInfo {
             )));
         }
 
-        // read and validate number of random elements for the auxiliary trace segment
-        let num_aux_segment_rands = source.read_u8()? as usize;
-        if aux_segment_width != 0 && num_aux_segment_rands == 0 {
-            return Err(DeserializationError::InvalidValue(
-                "a non-empty trace segment must require at least one random element".to_string(),
-            ));
-        } else if num_aux_segment_rands > TraceInfo::MAX_RAND_SEGMENT_ELEMENTS {
-            return Err(DeserializationError::InvalidValue(format!(
-                "number of random elements required by a segment cannot exceed {}, but was {}",
-                TraceInfo::MAX_RAND_SEGMENT_ELEMENTS,
-                num_aux_segment_rands
-            )));
-        }
-
         // read and validate trace length (which was stored as a power of two)
         let trace_length = source.read_u8()?;
         if trace_length < TraceInfo::MIN_TRACE_LENGTH.ilog2() as u8 {
@@ -322,10 +440,10 @@ impl Deserializable for TraceInfo {
             vec![]
         };
 
-        Ok(Self::new_multi_segment(
+        Ok(Self::new_with_aux_segments(
             main_segment_width,
-            aux_segment_width,
-            num_aux_segment_rands,
+            &aux_segment_widths,
+            &aux_segment_rands,
             trace_length,
             trace_meta,
         ))
@@ -338,6 +456,7 @@ impl Deserializable for TraceInfo {
 #[cfg(test)]
 mod tests {
     use math::{fields::f64::BaseElement, FieldElement};
+    use utils::{Deserializable, Serializable};
 
     use super::{ToElements, TraceInfo};
 
@@ -386,5 +505,78 @@ mod tests {
         );
 
         assert_eq!(expected, info.to_elements());
+
+        // --- test trace with two auxiliary segments -------------------------
+        let main_width = 20;
+        let trace_length = 64_u32;
+        let num_aux_segments = 2;
+        let aux_widths = [9_u8, 3];
+        let aux_rands = [12_u8, 4];
+
+        let expected = {
+            let first_ele =
+                u32::from_le_bytes([aux_rands[0], aux_widths[0], num_aux_segments, main_width]);
+            let second_ele = u32::from_le_bytes([aux_rands[1], aux_widths[1], 0, 0]);
+
+            vec![
+                BaseElement::from(first_ele),
+                BaseElement::from(second_ele),
+                BaseElement::from(trace_length),
+            ]
+        };
+
+        let info = TraceInfo::new_with_aux_segments(
+            main_width as usize,
+            &[aux_widths[0] as usize, aux_widths[1] as usize],
+            &[aux_rands[0] as usize, aux_rands[1] as usize],
+            trace_length as usize,
+            vec![],
+        );
+
+        assert_eq!(expected, info.to_elements());
+    }
+
+    #[test]
+    fn trace_info_with_aux_segments() {
+        let info = TraceInfo::new_with_aux_segments(20, &[9, 3], &[12, 4], 64, vec![1, 2, 3]);
+
+        assert_eq!(3, info.num_segments());
+        assert_eq!(2, info.num_aux_segments());
+        assert_eq!(12, info.aux_segment_width());
+        assert_eq!(32, info.width());
+        assert_eq!(3, info.get_aux_segment_width_at(1));
+        assert_eq!(4, info.get_num_aux_segment_rand_elements_at(1));
+        assert_eq!(16, info.get_num_aux_segment_rand_elements());
+
+        let bytes = info.to_bytes();
+        assert_eq!(&[20, 0, 2, 9, 12, 3, 4, 6, 3, 0, 1, 2, 3], bytes.as_slice());
+        assert_eq!(info, TraceInfo::read_from_bytes(&bytes).unwrap());
+    }
+
+    #[test]
+    fn trace_info_serialization() {
+        // traces with at most one auxiliary segment use the same encoding as before support for
+        // multiple auxiliary segments was added
+        let info = TraceInfo::new(20, 64);
+        let bytes = info.to_bytes();
+        assert_eq!(&[20, 0, 0, 6, 0, 0], bytes.as_slice());
+        assert_eq!(info, TraceInfo::read_from_bytes(&bytes).unwrap());
+
+        let info = TraceInfo::new_multi_segment(20, 9, 12, 64, vec![1]);
+        let bytes = info.to_bytes();
+        assert_eq!(&[20, 9, 12, 6, 1, 0, 1], bytes.as_slice());
+        assert_eq!(info, TraceInfo::read_from_bytes(&bytes).unwrap());
+
+        // an empty auxiliary segment cannot require random elements
+        assert!(TraceInfo::read_from_bytes(&[20, 0, 1, 6, 0, 0]).is_err());
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "number of random elements for a non-empty auxiliary trace segment must be greater than zero"
+    )]
+    fn trace_info_aux_segment_without_rands() {
+        // an auxiliary segment of non-zero width must require at least one random element
+        let _ = TraceInfo::new_multi_segment(20, 9, 0, 64, vec![]);
     }
 }
@@ -95,6 +95,22 @@ impl TransitionConstraintDegree {
         result
     }
 
+    /// Computes a degree to which this degree description expands in the context of execution
+    /// trace of the specified length when trace polynomials are randomized in zero-knowledge mode
+    /// (see [ProofOptions::with_zk()]).
+    ///
+    /// A randomized trace polynomial has `num_randomizer_coefficients` more coefficients than the
+    /// trace polynomial, and thus, the expanded degree is greater than the one computed by
+    /// [get_evaluation_degree()](TransitionConstraintDegree::get_evaluation_degree) by
+    /// $b \cdot r$, where $b$ is the base degree and $r$ is `num_randomizer_coefficients`.
+    pub fn get_randomized_evaluation_degree(
+        &self,
+        trace_length: usize,
+        num_randomizer_coefficients: usize,
+    ) -> usize {
+        self.get_evaluation_degree(trace_length) + self.base * num_randomizer_coefficients
+    }
+
     /// Returns a minimum blowup factor needed to evaluate constraint of this degree.
     ///
     /// This is guaranteed to be a power of two, greater than one.
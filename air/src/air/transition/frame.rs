@@ -7,46 +7,92 @@ use alloc::vec::Vec;
 
 use super::FieldElement;
 
+// CONSTANTS
+// ================================================================================================
+
+/// Row offsets of the default evaluation frame: the current row and the next row.
+pub(crate) const DEFAULT_FRAME_OFFSETS: [isize; 2] = [0, 1];
+
 // EVALUATION FRAME
 // ================================================================================================
 
 /// A set of execution trace rows required for evaluation of transition constraints.
 ///
-/// In the current implementation, an evaluation frame always contains two consecutive rows of the
-/// execution trace. It is passed in as one of the parameters into
+/// By default, an evaluation frame contains two consecutive rows of the execution trace: the
+/// current row and the next row. An AIR can request additional rows via
+/// [AirContext::set_frame_offsets()](crate::AirContext::set_frame_offsets); in this case, the
+/// frame contains one row for each of the declared offsets, and the row at index `i` holds the
+/// state of the computation `offsets[i]` steps after the current step (or, for negative offsets,
+/// `-offsets[i]` steps before the current step). The first two offsets are always 0 and 1, and
+/// thus [current()](EvaluationFrame::current) and [next()](EvaluationFrame::next) always refer to
+/// the current and the next rows.
+///
+/// An evaluation frame is passed in as one of the parameters into
 /// [Air::evaluate_transition()](crate::Air::evaluate_transition) function.
 #[derive(Debug, Clone)]
 pub struct EvaluationFrame<E: FieldElement> {
-    current: Vec<E>,
-    next: Vec<E>,
+    rows: Vec<Vec<E>>,
+    offsets: Vec<isize>,
 }
 
 impl<E: FieldElement> EvaluationFrame<E> {
     // CONSTRUCTORS
     // --------------------------------------------------------------------------------------------
 
-    /// Returns a new evaluation frame instantiated with the specified number of columns.
+    /// Returns a new two-row evaluation frame instantiated with the specified number of columns.
     ///
     /// # Panics
     /// Panics if `num_columns` is zero.
     pub fn new(num_columns: usize) -> Self {
+        Self::with_offsets(num_columns, &DEFAULT_FRAME_OFFSETS)
+    }
+
+    /// Returns a new evaluation frame instantiated with the specified number of columns and one
+    /// row for each of the specified row offsets.
+    ///
+    /// # Panics
+    /// Panics if:
+    /// * `num_columns` is zero.
+    /// * `offsets` are not valid frame offsets (see
+    ///   [AirContext::set_frame_offsets()](crate::AirContext::set_frame_offsets)).
+    pub fn with_offsets(num_columns: usize, offsets: &[isize]) -> Self {
         assert!(num_columns > 0, "number of columns must be greater than zero");
+        validate_frame_offsets(offsets);
         EvaluationFrame {
-            current: vec![E::ZERO; num_columns],
-            next: vec![E::ZERO; num_columns],
+            rows: vec![vec![E::ZERO; num_columns]; offsets.len()],
+            offsets: offsets.to_vec(),
         }
     }
 
-    /// Returns a new evaluation frame instantiated from the provided rows.
+    /// Returns a new two-row evaluation frame instantiated from the provided rows.
     ///
     /// # Panics
     /// Panics if:
     /// * Lengths of the provided rows are zero.
     /// * Lengths of the provided rows are not the same.
     pub fn from_rows(current: Vec<E>, next: Vec<E>) -> Self {
-        assert!(!current.is_empty(), "a row must contain at least one value");
-        assert_eq!(current.len(), next.len(), "number of values in the rows must be the same");
-        Self { current, next }
+        Self::from_rows_with_offsets(vec![current, next], &DEFAULT_FRAME_OFFSETS)
+    }
+
+    /// Returns a new evaluation frame instantiated from the provided rows, where the row at
+    /// index `i` is the row located `offsets[i]` steps after (or, for negative offsets, before)
+    /// the current row.
+    ///
+    /// # Panics
+    /// Panics if:
+    /// * Lengths of the provided rows are zero.
+    /// * Lengths of the provided rows are not the same.
+    /// * The number of rows is not the same as the number of offsets.
+    /// * `offsets` are not valid frame offsets (see
+    ///   [AirContext::set_frame_offsets()](crate::AirContext::set_frame_offsets)).
+    pub fn from_rows_with_offsets(rows: Vec<Vec<E>>, offsets: &[isize]) -> Self {
+        validate_frame_offsets(offsets);
+        assert_eq!(rows.len(), offsets.len(), "number of rows must match the number of offsets");
+        assert!(!rows[0].is_empty(), "a row must contain at least one value");
+        for row in rows.iter().skip(1) {
+            assert_eq!(rows[0].len(), row.len(), "number of values in the rows must be the same");
+        }
+        Self { rows, offsets: offsets.to_vec() }
     }
 
     // ROW ACCESSORS
@@ -55,13 +101,35 @@ impl<E: FieldElement> EvaluationFrame<E> {
     /// Returns a reference to the current row.
     #[inline(always)]
     pub fn current(&self) -> &[E] {
-        &self.current
+        &self.rows[0]
     }
 
     /// Returns a reference to the next row.
     #[inline(always)]
     pub fn next(&self) -> &[E] {
-        &self.next
+        &self.rows[1]
+    }
+
+    /// Returns a reference to the row at the specified index of this frame.
+    ///
+    /// The returned row is located `self.offsets()[idx]` steps after the current row; negative
+    /// offsets refer to rows preceding the current row.
+    ///
+    /// # Panics
+    /// Panics if `idx` is greater than or equal to the number of rows in this frame.
+    #[inline(always)]
+    pub fn row(&self, idx: usize) -> &[E] {
+        &self.rows[idx]
+    }
+
+    /// Returns the number of rows in this frame.
+    pub fn num_rows(&self) -> usize {
+        self.rows.len()
+    }
+
+    /// Returns the row offsets of this frame.
+    pub fn offsets(&self) -> &[isize] {
+        &self.offsets
     }
 
     // DATA MUTATORS
@@ -70,12 +138,49 @@ impl<E: FieldElement> EvaluationFrame<E> {
     /// Returns a mutable reference to the current row.
     #[inline(always)]
     pub fn current_mut(&mut self) -> &mut [E] {
-        &mut self.current
+        &mut self.rows[0]
     }
 
     /// Returns a mutable reference to the next row.
     #[inline(always)]
     pub fn next_mut(&mut self) -> &mut [E] {
-        &mut self.next
+        &mut self.rows[1]
+    }
+
+    /// Returns a mutable reference to the row at the specified index of this frame.
+    ///
+    /// # Panics
+    /// Panics if `idx` is greater than or equal to the number of rows in this frame.
+    #[inline(always)]
+    pub fn row_mut(&mut self, idx: usize) -> &mut [E] {
+        &mut self.rows[idx]
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Makes sure the provided offsets start with 0 and 1, and that the remaining offsets are strictly
+/// increasing and do not repeat the first two.
+pub(crate) fn validate_frame_offsets(offsets: &[isize]) {
+    assert!(
+        offsets.len() >= 2 && offsets[0] == 0 && offsets[1] == 1,
+        "frame offsets must start with 0 and 1, but were {offsets:?}"
+    );
+    assert!(
+        offsets.len() <= u8::MAX as usize,
+        "number of frame offsets cannot exceed {}, but was {}",
+        u8::MAX,
+        offsets.len()
+    );
+    for window in offsets[2..].windows(2) {
+        assert!(
+            window[0] < window[1],
+            "frame offsets following 0 and 1 must be strictly increasing, but were {offsets:?}"
+        );
     }
+    assert!(
+        offsets[2..].iter().all(|&offset| offset != 0 && offset != 1),
+        "frame offsets must not contain duplicates, but were {offsets:?}"
+    );
 }
@@ -3,12 +3,13 @@
 // This source code is licensed under the MIT license found in the
 // LICENSE file in the root directory of this source tree.
 
-use alloc::vec::Vec;
+use alloc::{vec, vec::Vec};
 
 use super::{AirContext, ConstraintDivisor, ExtensionOf, FieldElement};
 
 mod frame;
 pub use frame::EvaluationFrame;
+pub(crate) use frame::{validate_frame_offsets, DEFAULT_FRAME_OFFSETS};
 
 mod degree;
 pub use degree::TransitionConstraintDegree;
@@ -28,13 +29,16 @@ const MIN_CYCLE_LENGTH: usize = 2;
 ///   trace segments (if any).
 /// - Groupings of random composition constraint coefficients separately for the main trace segment
 ///   and for auxiliary tace segment.
-/// - Divisor of transition constraints for a computation.
+/// - Divisors of transition constraints for a computation, as well as the index of the divisor
+///   of each constraint.
 pub struct TransitionConstraints<E: FieldElement> {
     main_constraint_coef: Vec<E>,
     main_constraint_degrees: Vec<TransitionConstraintDegree>,
+    main_constraint_divisors: Vec<usize>,
     aux_constraint_coef: Vec<E>,
     aux_constraint_degrees: Vec<TransitionConstraintDegree>,
-    divisor: ConstraintDivisor<E::BaseField>,
+    aux_constraint_divisors: Vec<usize>,
+    divisors: Vec<ConstraintDivisor<E::BaseField>>,
 }
 
 impl<E: FieldElement> TransitionConstraints<E> {
@@ -53,23 +57,27 @@ impl<E: FieldElement> TransitionConstraints<E> {
             "number of transition constraints must match the number of composition coefficient tuples"
         );
 
-        // build constraint divisor; the same divisor applies to all transition constraints
-        let divisor = ConstraintDivisor::from_transition(
-            context.trace_len(),
-            context.num_transition_exemptions(),
-        );
+        // build constraint divisors; the first divisor is the default one, and it applies to all
+        // transition constraints for which a custom divisor has not been specified
+        let divisors = context.transition_divisors();
 
         let main_constraint_degrees = context.main_transition_constraint_degrees.clone();
         let aux_constraint_degrees = context.aux_transition_constraint_degrees.clone();
 
+        let num_main_constraints = context.main_transition_constraint_degrees.len();
         let (main_constraint_coef, aux_constraint_coef) =
-            composition_coefficients.split_at(context.main_transition_constraint_degrees.len());
+            composition_coefficients.split_at(num_main_constraints);
+        let constraint_divisors = context.transition_constraint_divisors();
+        let (main_constraint_divisors, aux_constraint_divisors) =
+            constraint_divisors.split_at(num_main_constraints);
         Self {
             main_constraint_coef: main_constraint_coef.to_vec(),
             main_constraint_degrees,
+            main_constraint_divisors: main_constraint_divisors.to_vec(),
             aux_constraint_coef: aux_constraint_coef.to_vec(),
             aux_constraint_degrees,
-            divisor,
+            aux_constraint_divisors: aux_constraint_divisors.to_vec(),
+            divisors,
         }
     }
 
@@ -118,9 +126,10 @@ impl<E: FieldElement> TransitionConstraints<E> {
         self.aux_constraint_coef.clone()
     }
 
-    /// Returns a divisor for transition constraints.
+    /// Returns the default divisor for transition constraints.
     ///
-    /// All transition constraints have the same divisor which has the form:
+    /// Unless a custom divisor is specified for a constraint, transition constraints have a
+    /// divisor of the form:
     /// $$
     /// z(x) = \frac{x^n - 1}{x - g^{n - 1}}
     /// $$
@@ -130,14 +139,75 @@ impl<E: FieldElement> TransitionConstraints<E> {
     /// This divisor specifies that transition constraints must hold on all steps of the
     /// execution trace except for the last one.
     pub fn divisor(&self) -> &ConstraintDivisor<E::BaseField> {
-        &self.divisor
+        &self.divisors[0]
+    }
+
+    /// Returns all distinct divisors of transition constraints.
+    ///
+    /// The first divisor is always the default one (see [TransitionConstraints::divisor()]),
+    /// and the remaining ones are custom divisors specified via
+    /// [AirContext::set_transition_divisor()].
+    pub fn divisors(&self) -> &[ConstraintDivisor<E::BaseField>] {
+        &self.divisors
+    }
+
+    /// Returns the index of the divisor (in the list returned by
+    /// [TransitionConstraints::divisors()]) of each transition constraint placed against the
+    /// main trace segment.
+    pub fn main_constraint_divisors(&self) -> &[usize] {
+        &self.main_constraint_divisors
+    }
+
+    /// Returns the index of the divisor (in the list returned by
+    /// [TransitionConstraints::divisors()]) of each transition constraint placed against the
+    /// auxiliary trace segment.
+    pub fn aux_constraint_divisors(&self) -> &[usize] {
+        &self.aux_constraint_divisors
     }
 
     // CONSTRAINT COMPOSITION
     // --------------------------------------------------------------------------------------------
 
+    /// Computes random linear combinations of transition constraint evaluations for the main
+    /// trace segment, and adds them to `result`.
+    ///
+    /// Evaluations of constraints which share the same divisor are combined together; thus,
+    /// `result` must contain a slot for each of the divisors returned by
+    /// [TransitionConstraints::divisors()].
+    #[inline(always)]
+    pub fn merge_main_evaluations<F>(&self, evaluations: &[F], result: &mut [E])
+    where
+        F: FieldElement<BaseField = E::BaseField>,
+        E: ExtensionOf<F>,
+    {
+        for ((&const_eval, &coef), &divisor_idx) in evaluations
+            .iter()
+            .zip(self.main_constraint_coef.iter())
+            .zip(self.main_constraint_divisors.iter())
+        {
+            result[divisor_idx] += coef.mul_base(const_eval);
+        }
+    }
+
+    /// Computes random linear combinations of transition constraint evaluations for the
+    /// auxiliary trace segment, and adds them to `result`.
+    ///
+    /// Evaluations of constraints which share the same divisor are combined together; thus,
+    /// `result` must contain a slot for each of the divisors returned by
+    /// [TransitionConstraints::divisors()].
+    #[inline(always)]
+    pub fn merge_aux_evaluations(&self, evaluations: &[E], result: &mut [E]) {
+        for ((&const_eval, &coef), &divisor_idx) in evaluations
+            .iter()
+            .zip(self.aux_constraint_coef.iter())
+            .zip(self.aux_constraint_divisors.iter())
+        {
+            result[divisor_idx] += coef * const_eval;
+        }
+    }
+
     /// Computes a linear combination of all transition constraint evaluations and divides the
-    /// result by transition constraint divisor.
+    /// result by transition constraint divisors.
     ///
     /// A transition constraint is described by a rational function of the form $\frac{C(x)}{z(x)}$,
     /// where:
@@ -146,30 +216,29 @@ impl<E: FieldElement> TransitionConstraints<E> {
     ///
     /// Thus, this function computes a linear combination of $C(x)$ evaluations.
     ///
-    /// Since, the divisor polynomial is the same for all transition constraints (see
-    /// [ConstraintDivisor::from_transition]), we can divide the linear combination by the
-    /// divisor rather than dividing each individual $C(x)$ evaluation. This requires executing only
-    /// one division at the end.
+    /// Since most transition constraints usually share the same divisor (see
+    /// [ConstraintDivisor::from_transition]), we first compute a linear combination of
+    /// constraint evaluations for each distinct divisor, and then divide each combination by its
+    /// divisor. This requires executing only one division per distinct divisor.
     pub fn combine_evaluations<F>(&self, main_evaluations: &[F], aux_evaluations: &[E], x: F) -> E
     where
         F: FieldElement<BaseField = E::BaseField>,
         E: ExtensionOf<F>,
     {
-        // merge constraint evaluations for the main trace segment
-        let mut result = main_evaluations
-            .iter()
-            .zip(self.main_constraint_coef.iter())
-            .fold(E::ZERO, |acc, (&const_eval, &coef)| acc + coef.mul_base(const_eval));
-
+        // merge constraint evaluations for all trace segments
+        let mut merged = vec![E::ZERO; self.divisors.len()];
+        self.merge_main_evaluations(main_evaluations, &mut merged);
         if !self.aux_constraint_coef.is_empty() {
-            result += aux_evaluations
-                .iter()
-                .zip(self.aux_constraint_coef.iter())
-                .fold(E::ZERO, |acc, (&const_eval, &coef)| acc + coef * const_eval);
-        };
-        // divide out the evaluation of divisor at x and return the result
-        let z = E::from(self.divisor.evaluate_at(x));
-
-        result / z
+            self.merge_aux_evaluations(aux_evaluations, &mut merged);
+        }
+
+        // divide out the evaluation of each divisor at x and return the sum of the results
+        merged
+            .into_iter()
+            .zip(self.divisors.iter())
+            .fold(E::ZERO, |acc, (value, divisor)| {
+                let z = E::from(divisor.evaluate_at(x));
+                acc + value / z
+            })
     }
 }
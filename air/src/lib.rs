@@ -44,6 +44,6 @@ mod air;
 pub use air::{
     Air, AirContext, Assertion, AuxRandElements, BoundaryConstraint, BoundaryConstraintGroup,
     BoundaryConstraints, ConstraintCompositionCoefficients, ConstraintDivisor,
-    DeepCompositionCoefficients, EvaluationFrame, TraceInfo, TransitionConstraintDegree,
+    DeepCompositionCoefficients, EvaluationFrame, LogUp, TraceInfo, TransitionConstraintDegree,
     TransitionConstraints,
 };
@@ -38,7 +38,8 @@ const FRI_MAX_REMAINDER_DEGREE: usize = 255;
 ///
 /// For example, if the size of base field is ~64-bits, a quadratic extension must be use to
 /// achieve ~100 bits of soundness, and a cubic extension must be used to achieve 128+ bits
-/// of soundness.
+/// of soundness. For ~32-bit fields (e.g., BabyBear), a quartic extension is required to achieve
+/// ~100 bits of soundness.
 ///
 /// However, increasing extension degree will increase proof generation time and proof size by
 /// as much as 50%.
@@ -51,6 +52,8 @@ pub enum FieldExtension {
     Quadratic = 2,
     /// Composition polynomial is constructed in the cubic extension of the base field.
     Cubic = 3,
+    /// Composition polynomial is constructed in the quartic extension of the base field.
+    Quartic = 4,
 }
 
 /// STARK protocol parameters.
@@ -77,6 +80,10 @@ pub enum FieldExtension {
 /// 6. Batching method for DEEP polynomial - either independent random values per multi-point
 ///    quotient are used in the computation of the DEEP polynomial or powers of a single random
 ///    value are used instead.
+/// 7. Zero-knowledge - when enabled (see [ProofOptions::with_zk()]), the prover randomizes trace
+///    and constraint composition polynomials and salts the leaves of trace and constraint
+///    commitments, so that the proof does not reveal anything about the execution trace beyond
+///    the validity of the computation. This increases proof generation time and proof size.
 ///
 /// Another important parameter in defining STARK security level, which is not a part of
 /// [ProofOptions] is the hash function used in the protocol. The soundness of a STARK proof is
@@ -102,6 +109,7 @@ pub struct ProofOptions {
     batching_constraints: BatchingMethod,
     batching_deep: BatchingMethod,
     partition_options: PartitionOptions,
+    is_zk: bool,
 }
 
 // PROOF OPTIONS IMPLEMENTATION
@@ -181,6 +189,7 @@ impl ProofOptions {
             partition_options: PartitionOptions::new(1, 1),
             batching_constraints,
             batching_deep,
+            is_zk: false,
         }
     }
 
@@ -200,6 +209,26 @@ impl ProofOptions {
         self
     }
 
+    /// Updates the provided [ProofOptions] instance to generate zero-knowledge proofs.
+    ///
+    /// In zero-knowledge mode, the prover:
+    /// - Adds a random multiple of the trace domain vanishing polynomial to each trace polynomial.
+    ///   This does not change the values of the polynomial over the trace domain, but makes its
+    ///   evaluations at the queried and out-of-domain points random.
+    /// - Adds random masks to the columns of the constraint composition polynomial (the masks
+    ///   cancel out when the columns are combined), and commits to an additional random column
+    ///   which makes the DEEP composition polynomial random.
+    /// - Salts each leaf of trace and constraint commitments with random values, which are revealed
+    ///   for the queried leaves only.
+    ///
+    /// As a result, trace polynomials have degree greater than `trace_length - 1`, which may
+    /// require a larger constraint evaluation domain and a larger low-degree extension domain.
+    pub const fn with_zk(mut self) -> ProofOptions {
+        self.is_zk = true;
+
+        self
+    }
+
     // PUBLIC ACCESSORS
     // --------------------------------------------------------------------------------------------
 
@@ -289,6 +318,55 @@ impl ProofOptions {
     pub fn deep_poly_batching_method(&self) -> BatchingMethod {
         self.batching_deep
     }
+
+    /// Returns true if these proof options describe zero-knowledge proofs.
+    pub const fn is_zk(&self) -> bool {
+        self.is_zk
+    }
+
+    /// Returns the number of coefficients of the random polynomial by which each trace polynomial
+    /// is randomized in zero-knowledge mode, given the number of rows in the evaluation frame.
+    ///
+    /// Each trace polynomial is opened at the queried points, at the points on which the constraint
+    /// composition polynomial depends at the queried points (i.e., at `num_frame_rows` points per
+    /// query), and at `num_frame_rows` out-of-domain points, each of which reveals as many base
+    /// field elements as the degree of the field extension. The randomizer must be large enough to
+    /// hide all of these openings.
+    ///
+    /// For proofs which are not zero-knowledge, this is zero.
+    pub const fn num_trace_randomizer_coefficients(&self, num_frame_rows: usize) -> usize {
+        if self.is_zk {
+            (self.num_queries() + self.field_extension.degree() as usize) * num_frame_rows
+        } else {
+            0
+        }
+    }
+
+    /// Returns the number of coefficients of the random polynomials by which the columns of the
+    /// constraint composition polynomial are masked in zero-knowledge mode.
+    ///
+    /// The columns are opened at the queried points and at two out-of-domain points, each of which
+    /// reveals as many base field elements as the degree of the field extension.
+    ///
+    /// For proofs which are not zero-knowledge, this is zero.
+    pub const fn num_composition_randomizer_coefficients(&self) -> usize {
+        if self.is_zk {
+            self.num_queries() + 2 * self.field_extension.degree() as usize
+        } else {
+            0
+        }
+    }
+
+    /// Returns the number of coefficients of trace polynomials for an execution trace of the
+    /// specified length, given the number of rows in the evaluation frame.
+    ///
+    /// For proofs which are not zero-knowledge, this is equal to `trace_length`. Otherwise, this is
+    /// the smallest power of two which can fit the randomized trace polynomials; constraint
+    /// composition polynomial columns are of the same size, and the size of the low-degree
+    /// extension domain is equal to this value times the blowup factor.
+    pub const fn trace_poly_size(&self, trace_length: usize, num_frame_rows: usize) -> usize {
+        (trace_length + self.num_trace_randomizer_coefficients(num_frame_rows)).next_power_of_two()
+    }
 }
 
 impl<E: StarkField> ToElements<E> for ProofOptions {
@@ -300,7 +378,11 @@ impl<E: StarkField> ToElements<E> for ProofOptions {
         buf = (buf << 8) | self.fri_remainder_max_degree as u32;
         buf = (buf << 8) | self.blowup_factor as u32;
 
-        vec![E::from(buf), E::from(self.grinding_factor), E::from(self.num_queries)]
+        // the zero-knowledge flag is encoded together with the grinding factor so that encoding of
+        // options for proofs which are not zero-knowledge stays the same
+        let grinding_factor = self.grinding_factor as u32 | (self.is_zk as u32) << 8;
+
+        vec![E::from(buf), E::from(grinding_factor), E::from(self.num_queries)]
     }
 }
 
@@ -317,6 +399,7 @@ impl Serializable for ProofOptions {
         target.write(self.batching_deep);
         target.write_u8(self.partition_options.num_partitions);
         target.write_u8(self.partition_options.hash_rate);
+        target.write_bool(self.is_zk);
     }
 }
 
@@ -336,7 +419,12 @@ impl Deserializable for ProofOptions {
             BatchingMethod::read_from(source)?,
             BatchingMethod::read_from(source)?,
         );
-        Ok(result.with_partitions(source.read_u8()? as usize, source.read_u8()? as usize))
+        let result = result.with_partitions(source.read_u8()? as usize, source.read_u8()? as usize);
+        if source.read_bool()? {
+            Ok(result.with_zk())
+        } else {
+            Ok(result)
+        }
     }
 }
 
@@ -355,6 +443,7 @@ impl FieldExtension {
             Self::None => 1,
             Self::Quadratic => 2,
             Self::Cubic => 3,
+            Self::Quartic => 4,
         }
     }
 }
@@ -378,6 +467,7 @@ impl Deserializable for FieldExtension {
             1 => Ok(FieldExtension::None),
             2 => Ok(FieldExtension::Quadratic),
             3 => Ok(FieldExtension::Cubic),
+            4 => Ok(FieldExtension::Quartic),
             value => Err(DeserializationError::InvalidValue(format!(
                 "value {value} cannot be deserialized as FieldExtension enum"
             ))),
@@ -549,6 +639,14 @@ mod tests {
             BatchingMethod::Linear,
         );
         assert_eq!(expected, options.to_elements());
+
+        // the zero-knowledge flag is encoded together with the grinding factor
+        let expected = vec![
+            BaseElement::from(ext_fri),
+            BaseElement::from(grinding_factor | 1 << 8),
+            BaseElement::from(num_queries as u32),
+        ];
+        assert_eq!(expected, options.with_zk().to_elements());
     }
 
     #[test]
@@ -607,6 +705,13 @@ mod tests {
         let options_serialized = options.to_bytes();
         let options_deserialized = ProofOptions::read_from_bytes(&options_serialized).unwrap();
 
-        assert_eq!(options, options_deserialized)
+        assert_eq!(options, options_deserialized);
+
+        let options = options.with_zk();
+        let options_serialized = options.to_bytes();
+        let options_deserialized = ProofOptions::read_from_bytes(&options_serialized).unwrap();
+
+        assert_eq!(options, options_deserialized);
+        assert!(options_deserialized.is_zk());
     }
 }
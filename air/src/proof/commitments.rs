@@ -5,7 +5,7 @@
 
 use alloc::vec::Vec;
 
-use crypto::Hasher;
+use crypto::{Hasher, VectorCommitment};
 use utils::{
     ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable, SliceReader,
 };
@@ -19,6 +19,7 @@ use utils::{
 ///   execution trace segments.
 /// * Commitment to the evaluations of constraint composition polynomial over LDE domain.
 /// * Commitments to the evaluations of polynomials at all FRI layers.
+/// * Commitment to the FRI remainder polynomial.
 ///
 /// Internally, the commitments are stored as a sequence of bytes. Thus, to retrieve the
 /// commitments, [parse()](Commitments::parse) function should be used.
@@ -29,15 +30,17 @@ impl Commitments {
     // CONSTRUCTOR
     // --------------------------------------------------------------------------------------------
     /// Returns a new Commitments struct initialized with the provided commitments.
-    pub fn new<H: Hasher>(
-        trace_roots: Vec<H::Digest>,
-        constraint_root: H::Digest,
-        fri_roots: Vec<H::Digest>,
+    pub fn new<H: Hasher, V: VectorCommitment<H>>(
+        trace_commitments: Vec<V::Commitment>,
+        constraint_commitment: V::Commitment,
+        fri_commitments: Vec<V::Commitment>,
+        fri_remainder_commitment: H::Digest,
     ) -> Self {
         let mut bytes = Vec::new();
-        bytes.write_many(&trace_roots);
-        bytes.write(constraint_root);
-        bytes.write_many(&fri_roots);
+        bytes.write_many(&trace_commitments);
+        bytes.write(constraint_commitment);
+        bytes.write_many(&fri_commitments);
+        bytes.write(fri_remainder_commitment);
         Commitments(bytes)
     }
 
@@ -45,7 +48,7 @@ impl Commitments {
     // --------------------------------------------------------------------------------------------
 
     /// Adds the specified commitment to the list of commitments.
-    pub fn add<H: Hasher>(&mut self, commitment: &H::Digest) {
+    pub fn add<C: Serializable>(&mut self, commitment: &C) {
         commitment.write_into(&mut self.0);
     }
 
@@ -58,16 +61,20 @@ impl Commitments {
     /// 1. Extended execution trace commitments.
     /// 2. Constraint composition polynomial evaluation commitment.
     /// 3. FRI layer commitments.
+    /// 4. FRI remainder polynomial commitment.
     ///
     /// # Errors
     /// Returns an error if the bytes stored in self could not be parsed into the requested number
     /// of commitments, or if there are any unconsumed bytes remaining after the parsing completes.
     #[allow(clippy::type_complexity)]
-    pub fn parse<H: Hasher>(
+    pub fn parse<H: Hasher, V: VectorCommitment<H>>(
         self,
         num_trace_segments: usize,
         num_fri_layers: usize,
-    ) -> Result<(Vec<H::Digest>, H::Digest, Vec<H::Digest>), DeserializationError> {
+    ) -> Result<
+        (Vec<V::Commitment>, V::Commitment, Vec<V::Commitment>, H::Digest),
+        DeserializationError,
+    > {
         let mut reader = SliceReader::new(&self.0);
 
         // parse trace commitments
@@ -76,14 +83,61 @@ impl Commitments {
         // parse constraint evaluation commitment:
         let constraint_commitment = reader.read()?;
 
-        // read FRI commitments (+ 1 for remainder polynomial commitment)
-        let fri_commitments = reader.read_many(num_fri_layers + 1)?;
+        // read FRI layer commitments and the remainder polynomial commitment
+        let fri_commitments = reader.read_many(num_fri_layers)?;
+        let fri_remainder_commitment = reader.read()?;
 
         // make sure we consumed all available commitment bytes
         if reader.has_more_bytes() {
             return Err(DeserializationError::UnconsumedBytes);
         }
-        Ok((trace_commitments, constraint_commitment, fri_commitments))
+        Ok((
+            trace_commitments,
+            constraint_commitment,
+            fri_commitments,
+            fri_remainder_commitment,
+        ))
+    }
+
+    /// Parses the serialized commitments of a single table of a multi-table proof.
+    ///
+    /// The commitments of a table consist of extended execution trace commitments followed by
+    /// the constraint composition polynomial evaluation commitment; FRI layer commitments are
+    /// shared by all tables and are parsed via [parse_fri_layers()](Commitments::parse_fri_layers).
+    ///
+    /// # Errors
+    /// Returns an error if the bytes stored in self could not be parsed into the requested number
+    /// of commitments, or if there are any unconsumed bytes remaining after the parsing completes.
+    pub fn parse_table<H: Hasher, V: VectorCommitment<H>>(
+        self,
+        num_trace_segments: usize,
+    ) -> Result<(Vec<V::Commitment>, V::Commitment), DeserializationError> {
+        let mut reader = SliceReader::new(&self.0);
+        let trace_commitments = reader.read_many(num_trace_segments)?;
+        let constraint_commitment = reader.read()?;
+        if reader.has_more_bytes() {
+            return Err(DeserializationError::UnconsumedBytes);
+        }
+        Ok((trace_commitments, constraint_commitment))
+    }
+
+    /// Parses the serialized FRI layer commitments and the FRI remainder polynomial commitment of
+    /// a multi-table proof.
+    ///
+    /// # Errors
+    /// Returns an error if the bytes stored in self could not be parsed into the requested number
+    /// of commitments, or if there are any unconsumed bytes remaining after the parsing completes.
+    pub fn parse_fri_layers<H: Hasher, V: VectorCommitment<H>>(
+        self,
+        num_fri_layers: usize,
+    ) -> Result<(Vec<V::Commitment>, H::Digest), DeserializationError> {
+        let mut reader = SliceReader::new(&self.0);
+        let fri_commitments = reader.read_many(num_fri_layers)?;
+        let fri_remainder_commitment = reader.read()?;
+        if reader.has_more_bytes() {
+            return Err(DeserializationError::UnconsumedBytes);
+        }
+        Ok((fri_commitments, fri_remainder_commitment))
     }
 }
 
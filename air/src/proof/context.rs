@@ -65,6 +65,10 @@ impl Context {
     }
 
     /// Returns the size of the LDE domain for the computation described by this context.
+    ///
+    /// This does not account for randomization of trace polynomials in zero-knowledge mode, in
+    /// which case the size of the LDE domain also depends on the evaluation frame of the
+    /// computation (see [Proof::lde_domain_size()](crate::proof::Proof::lde_domain_size)).
     pub fn lde_domain_size(&self) -> usize {
         self.trace_info.length() * self.options.blowup_factor()
     }
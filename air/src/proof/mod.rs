@@ -27,6 +27,9 @@ pub use queries::Queries;
 mod ood_frame;
 pub use ood_frame::{merge_ood_evaluations, OodFrame, QuotientOodFrame, TraceOodFrame};
 
+mod multi;
+pub use multi::{MultiProof, TableProof};
+
 mod security;
 
 mod table;
@@ -83,8 +86,13 @@ impl Proof {
     }
 
     /// Returns the size of the LDE domain for the computation described by this proof.
+    ///
+    /// In zero-knowledge mode, trace polynomials are randomized, and thus, the LDE domain may be
+    /// larger than `trace_length * blowup_factor`.
     pub fn lde_domain_size(&self) -> usize {
-        self.context.lde_domain_size()
+        let num_frame_rows = self.ood_frame.num_trace_rows();
+        self.options().trace_poly_size(self.trace_info().length(), num_frame_rows)
+            * self.options().blowup_factor()
     }
 
     // SECURITY LEVEL
@@ -116,13 +124,17 @@ impl Proof {
         let num_trace_polys = self.context.trace_info().width();
         let num_constraint_composition_polys = self.options().blowup_factor();
         let num_committed_polys = num_trace_polys + num_constraint_composition_polys;
-        ProvenSecurity::compute(
+        // trace polynomials are opened at one out-of-domain point for each row of the
+        // evaluation frame
+        let num_ood_openings = self.ood_frame.num_trace_rows();
+        ProvenSecurity::compute_with_ood_openings(
             self.context.options(),
             self.context.num_modulus_bits(),
             self.trace_info().length(),
             H::COLLISION_RESISTANCE,
             num_constraints,
             num_committed_polys,
+            num_ood_openings,
         )
     }
 
@@ -0,0 +1,209 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use alloc::vec::Vec;
+
+use crypto::Hasher;
+use fri::FriProof;
+use utils::{ByteReader, Deserializable, DeserializationError, Serializable};
+
+use super::{
+    security::{ConjecturedSecurity, ProvenSecurity},
+    Commitments, Context, OodFrame, Queries,
+};
+use crate::{ProofOptions, TraceInfo};
+
+// MULTI-TABLE PROOF
+// ================================================================================================
+/// A proof attesting to a correct execution of a computation described by several tables.
+///
+/// Each table is an execution trace of its own length (a power of two), with its own low-degree
+/// extension, trace and constraint commitments, and out-of-domain evaluations. All tables share
+/// the random elements used to build auxiliary trace segments, which makes it possible to enforce
+/// consistency between the tables via a shared bus (e.g., a one-sided [LogUp](crate::LogUp)
+/// argument in each table). Low degree of the DEEP composition polynomials of all tables is
+/// proven via a single batched FRI proof.
+///
+/// Tables are sorted by trace length in non-increasing order; the first table defines the domain
+/// over which FRI is executed and the query positions are drawn.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct MultiProof {
+    /// Proof components specific to each of the tables.
+    pub tables: Vec<TableProof>,
+    /// Commitments to FRI layers made by the prover.
+    pub fri_commitments: Commitments,
+    /// Low-degree proof for the DEEP composition polynomials of all tables.
+    pub fri_proof: FriProof,
+    /// Proof-of-work nonce for query seed grinding.
+    pub pow_nonce: u64,
+}
+
+/// Components of a [MultiProof] specific to a single table.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct TableProof {
+    /// Basic metadata about the table.
+    pub context: Context,
+    /// Number of unique queries made against this table. Query positions drawn over the LDE
+    /// domain of the first table are folded into the (possibly smaller) LDE domain of this table.
+    pub num_unique_queries: u8,
+    /// Commitments to trace segments and constraint evaluations of this table.
+    pub commitments: Commitments,
+    /// Decommitments of extended execution trace values (for all trace segments) at positions
+    /// queried by the verifier.
+    pub trace_queries: Vec<Queries>,
+    /// Decommitments of constraint composition polynomial evaluations at positions queried by
+    /// the verifier.
+    pub constraint_queries: Queries,
+    /// Trace and constraint polynomial evaluations at an out-of-domain point.
+    pub ood_frame: OodFrame,
+    /// Serialized final value of the running sum of the table's LogUp argument; this is empty if
+    /// the table does not have a LogUp argument.
+    pub logup_sum: Vec<u8>,
+}
+
+impl MultiProof {
+    /// Returns STARK protocol parameters used to generate this proof.
+    ///
+    /// # Panics
+    /// Panics if the proof does not contain any tables.
+    pub fn options(&self) -> &ProofOptions {
+        self.tables[0].context.options()
+    }
+
+    /// Returns trace info for each of the tables described by this proof.
+    pub fn trace_infos(&self) -> impl Iterator<Item = &TraceInfo> {
+        self.tables.iter().map(|table| table.context.trace_info())
+    }
+
+    /// Returns the size of the LDE domain of the largest table described by this proof.
+    ///
+    /// # Panics
+    /// Panics if the proof does not contain any tables.
+    pub fn lde_domain_size(&self) -> usize {
+        self.tables[0].context.lde_domain_size()
+    }
+
+    // SECURITY LEVEL
+    // --------------------------------------------------------------------------------------------
+    /// Returns security level of this proof (in bits) using conjectured security.
+    ///
+    /// # Panics
+    /// Panics if the proof does not contain any tables.
+    pub fn conjectured_security<H: Hasher>(&self) -> ConjecturedSecurity {
+        let context = &self.tables[0].context;
+        ConjecturedSecurity::compute(
+            context.options(),
+            context.num_modulus_bits(),
+            H::COLLISION_RESISTANCE,
+        )
+    }
+
+    /// Returns security level of this proof (in bits) using proven security.
+    ///
+    /// The security level is estimated separately for each table, using the table's own trace
+    /// length, number of constraints, number of committed polynomials, and number of out-of-domain
+    /// openings; the security level of the proof is the lowest of these estimates.
+    ///
+    /// # Panics
+    /// Panics if the proof does not contain any tables.
+    pub fn proven_security<H: Hasher>(&self) -> ProvenSecurity {
+        self.tables
+            .iter()
+            .map(|table| {
+                let context = &table.context;
+                ProvenSecurity::compute_with_ood_openings(
+                    context.options(),
+                    context.num_modulus_bits(),
+                    context.trace_info().length(),
+                    H::COLLISION_RESISTANCE,
+                    context.num_constraints(),
+                    context.trace_info().width() + context.options().blowup_factor(),
+                    table.ood_frame.num_trace_rows(),
+                )
+            })
+            .reduce(ProvenSecurity::min)
+            .expect("multi-table proof must contain at least one table")
+    }
+
+    // SERIALIZATION / DESERIALIZATION
+    // --------------------------------------------------------------------------------------------
+
+    /// Serializes this proof into a vector of bytes.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        Serializable::to_bytes(self)
+    }
+
+    /// Returns a multi-table STARK proof read from the specified `source`.
+    ///
+    /// # Errors
+    /// Returns an error of a valid proof could not be read from the specified `source`.
+    pub fn from_bytes(source: &[u8]) -> Result<Self, DeserializationError> {
+        Deserializable::read_from_bytes(source)
+    }
+}
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for MultiProof {
+    fn write_into<W: utils::ByteWriter>(&self, target: &mut W) {
+        assert!(self.tables.len() <= u8::MAX as usize, "too many tables");
+        target.write_u8(self.tables.len() as u8);
+        target.write_many(&self.tables);
+        self.fri_commitments.write_into(target);
+        self.fri_proof.write_into(target);
+        self.pow_nonce.write_into(target);
+    }
+}
+
+impl Deserializable for MultiProof {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let num_tables = source.read_u8()? as usize;
+        Ok(MultiProof {
+            tables: source.read_many(num_tables)?,
+            fri_commitments: Commitments::read_from(source)?,
+            fri_proof: FriProof::read_from(source)?,
+            pow_nonce: source.read_u64()?,
+        })
+    }
+}
+
+impl Serializable for TableProof {
+    fn write_into<W: utils::ByteWriter>(&self, target: &mut W) {
+        self.context.write_into(target);
+        target.write_u8(self.num_unique_queries);
+        self.commitments.write_into(target);
+        target.write_many(&self.trace_queries);
+        self.constraint_queries.write_into(target);
+        self.ood_frame.write_into(target);
+        assert!(self.logup_sum.len() <= u8::MAX as usize, "LogUp sum is too big");
+        target.write_u8(self.logup_sum.len() as u8);
+        target.write_bytes(&self.logup_sum);
+    }
+}
+
+impl Deserializable for TableProof {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let context = Context::read_from(source)?;
+        let num_unique_queries = source.read_u8()?;
+        let commitments = Commitments::read_from(source)?;
+        let num_trace_segments = context.trace_info().num_segments();
+        let trace_queries = source.read_many(num_trace_segments)?;
+        let constraint_queries = Queries::read_from(source)?;
+        let ood_frame = OodFrame::read_from(source)?;
+        let num_logup_sum_bytes = source.read_u8()? as usize;
+        let logup_sum = source.read_vec(num_logup_sum_bytes)?;
+
+        Ok(TableProof {
+            context,
+            num_unique_queries,
+            commitments,
+            trace_queries,
+            constraint_queries,
+            ood_frame,
+            logup_sum,
+        })
+    }
+}
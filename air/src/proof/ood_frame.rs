@@ -10,7 +10,7 @@ use utils::{
     ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable, SliceReader,
 };
 
-use crate::EvaluationFrame;
+use crate::{air::DEFAULT_FRAME_OFFSETS, EvaluationFrame};
 
 // OUT-OF-DOMAIN FRAME
 // ================================================================================================
@@ -20,6 +20,8 @@ use crate::EvaluationFrame;
 /// This struct contains the following evaluations:
 /// * Evaluations of all trace polynomials at *z*.
 /// * Evaluations of all trace polynomials at *z * g*.
+/// * Evaluations of all trace polynomials at *z * g^k* for any additional frame offsets *k*
+///   declared by the AIR.
 /// * Evaluations of constraint composition column polynomials at *z*.
 /// * Evaluations of constraint composition column polynomials at *z * g*.
 ///
@@ -40,7 +42,9 @@ impl OodFrame {
     /// Updates the trace state portion of this out-of-domain frame.
     ///
     /// The out-of-domain frame is stored as one vector built from the concatenation of values of
-    /// two vectors, the current row vector and the next row vector. Given the input frame
+    /// all rows of the trace frame, starting with the current row vector and followed by the next
+    /// row vector (and, if the AIR declares additional frame offsets, the remaining rows in the
+    /// order of their offsets). Given the input frame
     ///
     ///    +-------+-------+-------+-------+-------+-------+-------+-------+
     ///    |   a1  |   a2  |  ...  |  an   |  c1   |  c2   |  ...  |  cm   |
@@ -52,7 +56,7 @@ impl OodFrame {
     ///
     /// [a1, ..., an, c1, ..., cm, b1, ..., bn, d1, ..., dm]
     ///
-    /// into `Self::trace_states` (as byte values).
+    /// into `Self::trace_states` (as byte values), preceded by the number of rows in the frame.
     ///
     /// # Panics
     /// Panics if evaluation frame has already been set.
@@ -65,8 +69,9 @@ impl OodFrame {
         // save the evaluations of the current and then next evaluations for each polynomial
         let main_and_aux_trace_states = trace_ood_frame.to_trace_states();
 
-        // there are 2 frames: current and next
-        let frame_size: u8 = 2;
+        // there is one row for each frame offset; the number of offsets is guaranteed to fit
+        // into a single byte
+        let frame_size = trace_ood_frame.num_rows() as u8;
         self.trace_states.write_u8(frame_size);
         self.trace_states.write_many(&main_and_aux_trace_states);
     }
@@ -107,6 +112,20 @@ impl OodFrame {
         self.quotient_states.write_many(&quotient_states);
     }
 
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the number of rows in the trace portion of this out-of-domain frame.
+    ///
+    /// This is the number of points at which each trace polynomial is opened. If the trace states
+    /// have not been set yet, the default of 2 (the current and the next rows) is returned.
+    pub fn num_trace_rows(&self) -> usize {
+        self.trace_states
+            .first()
+            .map(|&size| size as usize)
+            .unwrap_or(DEFAULT_FRAME_OFFSETS.len())
+    }
+
     // PARSER
     // --------------------------------------------------------------------------------------------
     /// Returns an out-of-domain trace frame and an out-of-domain constraints evaluations frame.
@@ -117,6 +136,7 @@ impl OodFrame {
     ///
     /// # Errors
     /// Returns an error if:
+    /// * The number of rows in the trace frame is not the same as the number of `frame_offsets`.
     /// * Valid [`TraceOodFrame`]s for the specified `main_trace_width` and `aux_trace_width`
     ///   could not be parsed from the internal bytes.
     /// * Valid [`QuotientOodFrame`]s for the specified `num_quotients` could not be parsed
@@ -127,25 +147,31 @@ impl OodFrame {
         main_trace_width: usize,
         aux_trace_width: usize,
         num_quotients: usize,
+        frame_offsets: &[isize],
     ) -> Result<(TraceOodFrame<E>, QuotientOodFrame<E>), DeserializationError> {
         assert!(main_trace_width > 0, "trace width cannot be zero");
         assert!(num_quotients > 0, "number of evaluations cannot be zero");
 
         // parse main and auxiliary trace evaluation frames. This does the reverse operation done in
         // `set_trace_states()`.
-        let (trace_current_row, trace_next_row) = {
+        let trace_rows = {
             let mut reader = SliceReader::new(&self.trace_states);
             let frame_size = reader.read_u8()? as usize;
-            assert_eq!(frame_size, 2);
-            let mut trace = reader.read_many((main_trace_width + aux_trace_width) * frame_size)?;
+            if frame_size != frame_offsets.len() {
+                return Err(DeserializationError::InvalidValue(format!(
+                    "expected {} rows in the out-of-domain trace frame, but was {}",
+                    frame_offsets.len(),
+                    frame_size
+                )));
+            }
+            let trace_width = main_trace_width + aux_trace_width;
+            let trace: Vec<E> = reader.read_many(trace_width * frame_size)?;
 
             if reader.has_more_bytes() {
                 return Err(DeserializationError::UnconsumedBytes);
             }
 
-            let next_row = trace.split_off(main_trace_width + aux_trace_width);
-            let current_row = trace;
-            (current_row, next_row)
+            trace.chunks(trace_width).map(|row| row.to_vec()).collect::<Vec<_>>()
         };
 
         // parse the constraint evaluations. This does the reverse operation done in
@@ -153,7 +179,11 @@ impl OodFrame {
         let (quotients_current_row, quotients_next_row) = {
             let mut reader = SliceReader::new(&self.quotient_states);
             let frame_size = reader.read_u8()? as usize;
-            assert_eq!(frame_size, 2);
+            if frame_size != 2 {
+                return Err(DeserializationError::InvalidValue(format!(
+                    "expected 2 rows in the out-of-domain quotient frame, but was {frame_size}"
+                )));
+            }
             let mut quotients_evaluations = reader.read_many(num_quotients * frame_size)?;
 
             if reader.has_more_bytes() {
@@ -166,7 +196,7 @@ impl OodFrame {
         };
 
         Ok((
-            TraceOodFrame::new(trace_current_row, trace_next_row, main_trace_width),
+            TraceOodFrame::from_rows(trace_rows, frame_offsets, main_trace_width),
             QuotientOodFrame::new(quotients_current_row, quotients_next_row),
         ))
     }
@@ -219,52 +249,84 @@ impl Deserializable for OodFrame {
 
 /// Trace evaluation frame at the out-of-domain point.
 ///
-/// Stores the trace evaluations at `z` and `gz`, where `z` is a random Field element in
-/// `current_row` and `next_row`, respectively.
+/// Stores the trace evaluations at `z * g^k` for each frame offset `k`, where `z` is a random
+/// field element and `g` is the generator of the trace domain. For the default frame offsets
+/// `[0, 1]`, these are the evaluations at `z` and `gz` in the current and the next rows,
+/// respectively.
 pub struct TraceOodFrame<E: FieldElement> {
-    current_row: Vec<E>,
-    next_row: Vec<E>,
+    rows: Vec<Vec<E>>,
+    offsets: Vec<isize>,
     main_trace_width: usize,
 }
 
 impl<E: FieldElement> TraceOodFrame<E> {
     /// Creates a new [`TraceOodFrame`] from current, next.
     pub fn new(current_row: Vec<E>, next_row: Vec<E>, main_trace_width: usize) -> Self {
-        assert_eq!(current_row.len(), next_row.len());
+        Self::from_rows(vec![current_row, next_row], &DEFAULT_FRAME_OFFSETS, main_trace_width)
+    }
 
-        Self { current_row, next_row, main_trace_width }
+    /// Creates a new [`TraceOodFrame`] from a list of rows, one row for each of the specified
+    /// frame offsets.
+    ///
+    /// # Panics
+    /// Panics if the number of rows is not the same as the number of offsets, or if the rows are
+    /// not all of the same length.
+    pub fn from_rows(rows: Vec<Vec<E>>, offsets: &[isize], main_trace_width: usize) -> Self {
+        assert_eq!(rows.len(), offsets.len());
+        for row in rows.iter().skip(1) {
+            assert_eq!(rows[0].len(), row.len());
+        }
+
+        Self {
+            rows,
+            offsets: offsets.to_vec(),
+            main_trace_width,
+        }
     }
 
     /// Returns the number of columns for the current and next frames.
     pub fn num_columns(&self) -> usize {
-        self.current_row.len()
+        self.rows[0].len()
+    }
+
+    /// Returns the number of rows in this frame.
+    pub fn num_rows(&self) -> usize {
+        self.rows.len()
+    }
+
+    /// Returns the row offsets of this frame.
+    pub fn offsets(&self) -> &[isize] {
+        &self.offsets
     }
 
     /// Returns the current row, consisting of both main and auxiliary columns.
     pub fn current_row(&self) -> &[E] {
-        &self.current_row
+        &self.rows[0]
     }
 
     /// Returns the next frame, consisting of both main and auxiliary columns.
     pub fn next_row(&self) -> &[E] {
-        &self.next_row
+        &self.rows[1]
+    }
+
+    /// Returns the row at the specified index, consisting of both main and auxiliary columns.
+    pub fn row(&self, idx: usize) -> &[E] {
+        &self.rows[idx]
     }
 
     /// Returns the evaluation frame for the main trace
     pub fn main_frame(&self) -> EvaluationFrame<E> {
-        let current = self.current_row[0..self.main_trace_width].to_vec();
-        let next = self.next_row[0..self.main_trace_width].to_vec();
+        let rows = self.rows.iter().map(|row| row[..self.main_trace_width].to_vec()).collect();
 
-        EvaluationFrame::from_rows(current, next)
+        EvaluationFrame::from_rows_with_offsets(rows, &self.offsets)
     }
 
     /// Returns the evaluation frame for the auxiliary trace
     pub fn aux_frame(&self) -> Option<EvaluationFrame<E>> {
         if self.has_aux_frame() {
-            let current = self.current_row[self.main_trace_width..].to_vec();
-            let next = self.next_row[self.main_trace_width..].to_vec();
+            let rows = self.rows.iter().map(|row| row[self.main_trace_width..].to_vec()).collect();
 
-            Some(EvaluationFrame::from_rows(current, next))
+            Some(EvaluationFrame::from_rows_with_offsets(rows, &self.offsets))
         } else {
             None
         }
@@ -272,15 +334,16 @@ impl<E: FieldElement> TraceOodFrame<E> {
 
     /// Returns true if an auxiliary frame is present
     fn has_aux_frame(&self) -> bool {
-        self.current_row.len() > self.main_trace_width
+        self.num_columns() > self.main_trace_width
     }
 
     /// Returns the main/aux frames as a vector of elements described in
     /// [`OodFrame::set_trace_states`].
     pub fn to_trace_states(&self) -> Vec<E> {
         let mut main_and_aux_frame_states = Vec::new();
-        main_and_aux_frame_states.extend_from_slice(&self.current_row);
-        main_and_aux_frame_states.extend_from_slice(&self.next_row);
+        for row in self.rows.iter() {
+            main_and_aux_frame_states.extend_from_slice(row);
+        }
 
         main_and_aux_frame_states
     }
@@ -331,7 +394,8 @@ impl<E: FieldElement> QuotientOodFrame<E> {
 
 /// Given trace and constraints polynomials OOD evaluations, returns the vector containing their
 /// concatenation, with the evaluations at `z` grouped together and coming first and followed
-/// by the evaluations at `z * g`.
+/// by the evaluations at `z * g`, and then by the trace evaluations at the remaining frame
+/// offsets (if any).
 pub fn merge_ood_evaluations<E>(
     trace_ood_frame: &TraceOodFrame<E>,
     constraints_ood_frame: &QuotientOodFrame<E>,
@@ -346,6 +410,9 @@ where
 
     let mut ood_evals = current_row;
     ood_evals.extend_from_slice(&next_row);
+    for i in 2..trace_ood_frame.num_rows() {
+        ood_evals.extend_from_slice(trace_ood_frame.row(i));
+    }
 
     ood_evals
 }
@@ -73,6 +73,9 @@ impl ProvenSecurity {
     /// Computes the proven security level (in bits) of the protocol using Theorem 2 and Theorem 3
     /// in [1].
     ///
+    /// This assumes that trace polynomials are opened at two out-of-domain points (i.e., that
+    /// transition constraints are evaluated over the default two-row evaluation frame).
+    ///
     /// [1]: https://eprint.iacr.org/2024/1553
     pub fn compute(
         options: &ProofOptions,
@@ -82,6 +85,44 @@ impl ProvenSecurity {
         num_constraints: usize,
         num_committed_polys: usize,
     ) -> Self {
+        Self::compute_with_ood_openings(
+            options,
+            base_field_bits,
+            trace_domain_size,
+            collision_resistance,
+            num_constraints,
+            num_committed_polys,
+            2,
+        )
+    }
+
+    /// Computes the proven security level (in bits) of the protocol using Theorem 2 and Theorem 3
+    /// in [1] for a protocol in which trace polynomials are opened at `num_ood_openings`
+    /// out-of-domain points (i.e., one point for each row of the evaluation frame).
+    ///
+    /// If zero-knowledge mode is enabled in `options`, the estimate accounts for the degree of
+    /// randomized trace polynomials (which are committed to over a correspondingly larger domain),
+    /// as well as for the additional random column of the constraint composition polynomial.
+    ///
+    /// [1]: https://eprint.iacr.org/2024/1553
+    pub fn compute_with_ood_openings(
+        options: &ProofOptions,
+        base_field_bits: u32,
+        trace_domain_size: usize,
+        collision_resistance: u32,
+        num_constraints: usize,
+        num_committed_polys: usize,
+        num_ood_openings: usize,
+    ) -> Self {
+        let (trace_domain_size, num_committed_polys) = if options.is_zk() {
+            (
+                options.trace_poly_size(trace_domain_size, num_ood_openings),
+                num_committed_polys + 1,
+            )
+        } else {
+            (trace_domain_size, num_committed_polys)
+        };
+
         let unique_decoding = cmp::min(
             proven_security_protocol_unique_decoding(
                 options,
@@ -89,6 +130,7 @@ impl ProvenSecurity {
                 trace_domain_size,
                 num_constraints,
                 num_committed_polys,
+                num_ood_openings,
             ),
             collision_resistance as u64,
         ) as u32;
@@ -107,6 +149,7 @@ impl ProvenSecurity {
                 a as usize,
                 num_constraints,
                 num_committed_polys,
+                num_ood_openings,
             )
         })
         .expect(
@@ -121,6 +164,7 @@ impl ProvenSecurity {
                 m_optimal as usize,
                 num_constraints,
                 num_committed_polys,
+                num_ood_openings,
             ),
             collision_resistance as u64,
         ) as u32;
@@ -143,6 +187,14 @@ impl ProvenSecurity {
     pub fn is_at_least(&self, bits: u32) -> bool {
         self.list_decoding >= bits || self.unique_decoding >= bits
     }
+
+    /// Returns the lower of the two security levels in each of the decoding regimes.
+    pub(crate) fn min(self, other: Self) -> Self {
+        Self {
+            unique_decoding: cmp::min(self.unique_decoding, other.unique_decoding),
+            list_decoding: cmp::min(self.list_decoding, other.list_decoding),
+        }
+    }
 }
 
 /// Computes proven security level for the specified proof parameters for a fixed value of the
@@ -154,6 +206,7 @@ fn proven_security_protocol_for_given_proximity_parameter(
     m: usize,
     num_constraints: usize,
     num_committed_polys: usize,
+    num_ood_openings: usize,
 ) -> u64 {
     let extension_field_bits = (base_field_bits * options.field_extension().degree()) as f64;
     let num_fri_queries = options.num_queries() as f64;
@@ -164,7 +217,7 @@ fn proven_security_protocol_for_given_proximity_parameter(
     let max_deg = options.blowup_factor() as f64 + 1.0;
     let lde_domain_size = (trace_domain_size * options.blowup_factor()) as f64;
     let trace_domain_size = trace_domain_size as f64;
-    let num_openings = 2.0;
+    let num_openings = num_ood_openings as f64;
 
     // we apply Theorem 2 in https://eprint.iacr.org/2024/1553, which is based on Theorem 8 in
     // https://eprint.iacr.org/2022/1216.pdf and Theorem 5 in https://eprint.iacr.org/2021/582
@@ -226,12 +279,13 @@ fn proven_security_protocol_unique_decoding(
     trace_domain_size: usize,
     num_constraints: usize,
     num_committed_polys: usize,
+    num_ood_openings: usize,
 ) -> u64 {
     let extension_field_bits = (base_field_bits * options.field_extension().degree()) as f64;
     let num_fri_queries = options.num_queries() as f64;
     let lde_domain_size = (trace_domain_size * options.blowup_factor()) as f64;
     let trace_domain_size = trace_domain_size as f64;
-    let num_openings = 2.0;
+    let num_openings = num_ood_openings as f64;
     let rho_plus = (trace_domain_size + num_openings) / lde_domain_size;
     let alpha = (1.0 + rho_plus) * 0.5;
     // we use the blowup factor in order to bound the max degree
@@ -1388,4 +1442,61 @@ mod tests {
 
         assert_eq!(security_3, 125);
     }
+
+    #[test]
+    fn zero_knowledge() {
+        let field_extension = FieldExtension::Quadratic;
+        let base_field_bits = BaseElement::MODULUS_BITS;
+        let fri_folding_factor = 8;
+        let fri_remainder_max_degree = 127;
+        let grinding_factor = 20;
+        let blowup_factor = 8;
+        let num_queries = 80;
+        let collision_resistance = 128;
+        let trace_length = 2_usize.pow(20);
+        let num_committed_polys = 2;
+        let num_constraints = 100;
+
+        let options = ProofOptions::new(
+            num_queries,
+            blowup_factor,
+            grinding_factor,
+            field_extension,
+            fri_folding_factor as usize,
+            fri_remainder_max_degree as usize,
+            BatchingMethod::Linear,
+            BatchingMethod::Linear,
+        );
+        let non_zk = ProvenSecurity::compute(
+            &options,
+            base_field_bits,
+            trace_length,
+            collision_resistance,
+            num_constraints,
+            num_committed_polys,
+        );
+        let zk = ProvenSecurity::compute(
+            &options.clone().with_zk(),
+            base_field_bits,
+            trace_length,
+            collision_resistance,
+            num_constraints,
+            num_committed_polys,
+        );
+
+        // randomized trace polynomials do not fit into the trace domain anymore, and thus, the
+        // protocol behaves as if the trace was twice as long and had one more committed column
+        let expected = ProvenSecurity::compute(
+            &options,
+            base_field_bits,
+            trace_length * 2,
+            collision_resistance,
+            num_constraints,
+            num_committed_polys + 1,
+        );
+
+        assert!(zk.ldr_bits() <= non_zk.ldr_bits());
+        assert_eq!(expected.ldr_bits(), zk.ldr_bits());
+        assert_eq!(expected.udr_bits(), zk.udr_bits());
+    }
 }
@@ -95,17 +95,59 @@ impl<E: FieldElement> Table<E> {
     /// Combines multiple tables together into a single table by stacking tables column-wise (e.g.
     /// the number of rows remains the same but the number of columns changes).
     ///
-    /// Currently, this method does not support inputs containing more than one table.
-    ///
     /// # Panics
-    /// Panics if the list of tables is empty.
+    /// Panics if the list of tables is empty, or if the tables have different numbers of rows.
     pub fn merge(mut tables: Vec<Table<E>>) -> Table<E> {
         assert!(!tables.is_empty(), "cannot merge an empty set of tables");
         if tables.len() == 1 {
-            tables.remove(0)
-        } else {
-            unimplemented!("merging of multiple tables is not yet implemented")
+            return tables.remove(0);
+        }
+
+        let num_rows = tables[0].num_rows();
+        for table in tables.iter().skip(1) {
+            assert_eq!(num_rows, table.num_rows(), "all tables must have the same number of rows");
+        }
+
+        let row_width = tables.iter().map(|table| table.row_width).sum();
+        let mut data = Vec::with_capacity(num_rows * row_width);
+        for row_idx in 0..num_rows {
+            for table in tables.iter() {
+                data.extend_from_slice(table.get_row(row_idx));
+            }
+        }
+
+        Table { data, row_width }
+    }
+
+    /// Splits this table column-wise into two tables such that the first table contains the
+    /// first `num_columns` columns of this table, and the second table contains the remaining
+    /// columns.
+    ///
+    /// # Panics
+    /// Panics if `num_columns` is zero or is not smaller than the number of columns in this table.
+    pub fn split(self, num_columns: usize) -> (Table<E>, Table<E>) {
+        assert!(num_columns > 0, "number of columns must be greater than 0");
+        assert!(
+            num_columns < self.row_width,
+            "number of columns must be smaller than {}, but was {num_columns}",
+            self.row_width
+        );
+
+        let num_rows = self.num_rows();
+        let mut left = Vec::with_capacity(num_rows * num_columns);
+        let mut right = Vec::with_capacity(num_rows * (self.row_width - num_columns));
+        for row in self.rows() {
+            left.extend_from_slice(&row[..num_columns]);
+            right.extend_from_slice(&row[num_columns..]);
         }
+
+        (
+            Table { data: left, row_width: num_columns },
+            Table {
+                data: right,
+                row_width: self.row_width - num_columns,
+            },
+        )
     }
 }
 
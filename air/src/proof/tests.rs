@@ -20,3 +20,58 @@ fn dummy_proof_serialization() {
 
     assert_eq!(proof, proof_copy);
 }
+
+#[test]
+fn multi_row_ood_frame_parsing() {
+    use alloc::vec::Vec;
+
+    use math::{fields::f64::BaseElement, FieldElement};
+
+    use super::{OodFrame, QuotientOodFrame, TraceOodFrame};
+
+    let offsets = [0, 1, 2];
+    let rows = (0..3u64)
+        .map(|i| vec![BaseElement::new(i), BaseElement::new(i + 10)])
+        .collect::<Vec<_>>();
+    let trace_frame = TraceOodFrame::from_rows(rows.clone(), &offsets, 2);
+    let quotient_frame = QuotientOodFrame::new(vec![BaseElement::ONE], vec![BaseElement::ZERO]);
+
+    let mut ood_frame = OodFrame::default();
+    ood_frame.set_trace_states(&trace_frame);
+    ood_frame.set_quotient_states(&quotient_frame);
+    assert_eq!(3, ood_frame.num_trace_rows());
+
+    let (parsed_trace, parsed_quotients) =
+        ood_frame.clone().parse::<BaseElement>(2, 0, 1, &offsets).unwrap();
+    assert_eq!(3, parsed_trace.num_rows());
+    for (i, row) in rows.iter().enumerate() {
+        assert_eq!(row.as_slice(), parsed_trace.row(i));
+    }
+    assert_eq!(parsed_trace.main_frame().row(2), rows[2].as_slice());
+    assert_eq!(quotient_frame.current_row(), parsed_quotients.current_row());
+    assert_eq!(quotient_frame.next_row(), parsed_quotients.next_row());
+
+    // parsing the frame with a different set of offsets should fail
+    assert!(ood_frame.parse::<BaseElement>(2, 0, 1, &[0, 1]).is_err());
+}
+
+#[test]
+fn table_merge_multiple_tables() {
+    use alloc::vec::Vec;
+
+    use math::fields::f64::BaseElement;
+    use utils::Serializable;
+
+    use super::Table;
+
+    let left = (0..4u64).flat_map(|i| BaseElement::new(i).to_bytes()).collect::<Vec<_>>();
+    let right = (10..16u64).flat_map(|i| BaseElement::new(i).to_bytes()).collect::<Vec<_>>();
+    let left = Table::<BaseElement>::from_bytes(&left, 2, 2).unwrap();
+    let right = Table::<BaseElement>::from_bytes(&right, 2, 3).unwrap();
+
+    let merged = Table::merge(vec![left, right]);
+    assert_eq!(2, merged.num_rows());
+    assert_eq!(5, merged.num_columns());
+    assert_eq!(&[0, 1, 10, 11, 12].map(BaseElement::new), merged.get_row(0));
+    assert_eq!(&[2, 3, 13, 14, 15].map(BaseElement::new), merged.get_row(1));
+}
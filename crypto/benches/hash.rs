@@ -9,7 +9,9 @@ use criterion::{criterion_group, criterion_main, BatchSize, Criterion};
 use math::fields::f128;
 use rand_utils::rand_value;
 use winter_crypto::{
-    hashers::{Blake3_256, Rp62_248, Rp64_256, RpJive64_256, Sha3_256},
+    hashers::{
+        Blake3_256, Poseidon2_64_256, Rp128_256, Rp62_248, Rp64_256, RpJive64_256, Sha3_256,
+    },
     Hasher,
 };
 
@@ -21,7 +23,9 @@ type Sha3Digest = <Sha3 as Hasher>::Digest;
 
 type Rp62_248Digest = <Rp62_248 as Hasher>::Digest;
 type Rp64_256Digest = <Rp64_256 as Hasher>::Digest;
+type Rp128_256Digest = <Rp128_256 as Hasher>::Digest;
 type RpJive64_256Digest = <RpJive64_256 as Hasher>::Digest;
+type Poseidon2_64_256Digest = <Poseidon2_64_256 as Hasher>::Digest;
 
 fn blake3(c: &mut Criterion) {
     let v: [Blake3Digest; 2] = [Blake3::hash(&[1u8]), Blake3::hash(&[2u8])];
@@ -119,5 +123,55 @@ fn rescue_jive256(c: &mut Criterion) {
     });
 }
 
-criterion_group!(hash_group, blake3, sha3, rescue248, rescue256, rescue_jive256);
+fn rescue128_256(c: &mut Criterion) {
+    let v: [Rp128_256Digest; 2] = [Rp128_256::hash(&[1u8]), Rp128_256::hash(&[2u8])];
+    c.bench_function("hash_rp128_256 (cached)", |bench| {
+        bench.iter(|| Rp128_256::merge(black_box(&v)))
+    });
+
+    c.bench_function("hash_rp128_256 (random)", |b| {
+        b.iter_batched(
+            || {
+                [
+                    Rp128_256::hash(&rand_value::<u64>().to_le_bytes()),
+                    Rp128_256::hash(&rand_value::<u64>().to_le_bytes()),
+                ]
+            },
+            |state| Rp128_256::merge(&state),
+            BatchSize::SmallInput,
+        )
+    });
+}
+
+fn poseidon2_256(c: &mut Criterion) {
+    let v: [Poseidon2_64_256Digest; 2] =
+        [Poseidon2_64_256::hash(&[1u8]), Poseidon2_64_256::hash(&[2u8])];
+    c.bench_function("hash_poseidon2_64_256 (cached)", |bench| {
+        bench.iter(|| Poseidon2_64_256::merge(black_box(&v)))
+    });
+
+    c.bench_function("hash_poseidon2_64_256 (random)", |b| {
+        b.iter_batched(
+            || {
+                [
+                    Poseidon2_64_256::hash(&rand_value::<u64>().to_le_bytes()),
+                    Poseidon2_64_256::hash(&rand_value::<u64>().to_le_bytes()),
+                ]
+            },
+            |state| Poseidon2_64_256::merge(&state),
+            BatchSize::SmallInput,
+        )
+    });
+}
+
+criterion_group!(
+    hash_group,
+    blake3,
+    sha3,
+    rescue248,
+    rescue256,
+    rescue_jive256,
+    rescue128_256,
+    poseidon2_256
+);
 criterion_main!(hash_group);
@@ -7,10 +7,15 @@ use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
 use math::fields::f128::BaseElement;
 use rand_utils::rand_value;
 use utils::uninit_vector;
-use winter_crypto::{build_merkle_nodes, concurrent, hashers::Blake3_256, Hasher};
+use winter_crypto::{
+    build_merkle_nodes, concurrent,
+    hashers::{Blake3_256, Rp64_256},
+    ArityMerkleTree, Hasher,
+};
 
 type Blake3 = Blake3_256<BaseElement>;
 type Blake3Digest = <Blake3 as Hasher>::Digest;
+type RpDigest = <Rp64_256 as Hasher>::Digest;
 
 #[allow(clippy::needless_range_loop)]
 pub fn merkle_tree_construction(c: &mut Criterion) {
@@ -35,5 +40,26 @@ pub fn merkle_tree_construction(c: &mut Criterion) {
     }
 }
 
-criterion_group!(merkle_group, merkle_tree_construction,);
+pub fn arity_merkle_tree_construction(c: &mut Criterion) {
+    let mut merkle_group = c.benchmark_group("arity merkle tree construction");
+
+    static BATCH_SIZES: [usize; 2] = [65536, 262144];
+
+    for size in &BATCH_SIZES {
+        let data: Vec<RpDigest> = (0..*size)
+            .map(|_| Rp64_256::hash(&rand_value::<u128>().to_le_bytes()))
+            .collect();
+        merkle_group.bench_with_input(BenchmarkId::new("rp64_256/2", size), &data, |b, i| {
+            b.iter(|| ArityMerkleTree::<Rp64_256, 2>::new(i.clone()).unwrap())
+        });
+        merkle_group.bench_with_input(BenchmarkId::new("rp64_256/4", size), &data, |b, i| {
+            b.iter(|| ArityMerkleTree::<Rp64_256, 4>::new(i.clone()).unwrap())
+        });
+        merkle_group.bench_with_input(BenchmarkId::new("rp64_256/8", size), &data, |b, i| {
+            b.iter(|| ArityMerkleTree::<Rp64_256, 8>::new(i.clone()).unwrap())
+        });
+    }
+}
+
+criterion_group!(merkle_group, merkle_tree_construction, arity_merkle_tree_construction);
 criterion_main!(merkle_group);
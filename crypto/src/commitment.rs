@@ -23,11 +23,16 @@ use crate::Hasher;
 /// proofs for a number of `(i, v_i)` can be batched together into one batch opening proof in order
 /// to optimize both the proof size as well as the verification time.
 ///
-/// The current implementation restricts both of the commitment string as well as the leaf values
-/// to be `H::Digest` where `H` is a type parameter such that `H: Hasher`.
+/// The current implementation restricts the leaf values to be `H::Digest` where `H` is a type
+/// parameter such that `H: Hasher`. The commitment string may consist of one or more digests; a
+/// single digest computed from the commitment string via
+/// [commitment_digest()](VectorCommitment::commitment_digest) is what gets absorbed into a public
+/// coin.
 pub trait VectorCommitment<H: Hasher>: Sized {
     /// Options defining the VC i.e., public parameters.
     type Options: Default;
+    /// Commitment string to the committed values.
+    type Commitment: Clone + Debug + Eq + Serializable + Deserializable;
     /// Opening proof of some value at some position index.
     type Proof: Clone + Serializable + Deserializable;
     /// Batch opening proof of a number of {(i, v_i)}_{i ∈ S} for an index set.
@@ -46,7 +51,10 @@ pub trait VectorCommitment<H: Hasher>: Sized {
     fn with_options(items: Vec<H::Digest>, options: Self::Options) -> Result<Self, Self::Error>;
 
     /// Returns the commitment string to the committed values.
-    fn commitment(&self) -> H::Digest;
+    fn commitment(&self) -> Self::Commitment;
+
+    /// Returns a digest of the specified commitment string.
+    fn commitment_digest(commitment: &Self::Commitment) -> H::Digest;
 
     /// Returns the length of the vector committed to for `Self`.
     fn domain_len(&self) -> usize;
@@ -70,7 +78,7 @@ pub trait VectorCommitment<H: Hasher>: Sized {
 
     /// Verifies that the claimed value is at the given index using a proof.
     fn verify(
-        commitment: H::Digest,
+        commitment: Self::Commitment,
         index: usize,
         item: H::Digest,
         proof: &Self::Proof,
@@ -78,7 +86,7 @@ pub trait VectorCommitment<H: Hasher>: Sized {
 
     /// Verifies that the claimed values are at the given set of indices using a batch proof.
     fn verify_many(
-        commitment: H::Digest,
+        commitment: Self::Commitment,
         indexes: &[usize],
         items: &[H::Digest],
         proof: &Self::MultiProof,
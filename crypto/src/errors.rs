@@ -25,6 +25,8 @@ pub enum MerkleTreeError {
     TooManyLeafIndexes(usize, usize),
     /// Merkle proof is not valid for the specified position(s).
     InvalidProof,
+    /// A seed for leaf salts was not specified and could not be drawn from the operating system.
+    SeedUnavailable,
 }
 
 impl fmt::Display for MerkleTreeError {
@@ -57,6 +59,9 @@ impl fmt::Display for MerkleTreeError {
             Self::InvalidProof => {
                 write!(f, "Merkle proof is invalid")
             },
+            Self::SeedUnavailable => {
+                write!(f, "failed to draw a random seed for leaf salts")
+            },
         }
     }
 }
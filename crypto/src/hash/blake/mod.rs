@@ -65,45 +65,63 @@ impl<B: StarkField> ElementHasher for Blake3_256<B> {
     }
 }
 
-// BLAKE3 192-BIT OUTPUT
+// BLAKE3 TRUNCATED OUTPUT
 // ================================================================================================
 
 /// Implementation of the [Hasher](super::Hasher) trait for BLAKE3 hash function with 192-bit
 /// output.
+pub type Blake3_192<B> = TruncatedBlake3<B, 24>;
+
+/// Implementation of the [Hasher](super::Hasher) trait for BLAKE3 hash function with 160-bit
+/// output.
+pub type Blake3_160<B> = TruncatedBlake3<B, 20>;
+
+/// Implementation of the [Hasher](super::Hasher) trait for BLAKE3 hash function with 224-bit
+/// output.
+pub type Blake3_224<B> = TruncatedBlake3<B, 28>;
+
+/// Implementation of the [Hasher](super::Hasher) trait for BLAKE3 hash function with the output
+/// truncated to `N` bytes.
+///
+/// Collision resistance of the hash function is set to `4 * N` bits (i.e., half of the output
+/// size). `N` must be between 16 and 32 (both inclusive).
 #[derive(Debug, PartialEq, Eq)]
-pub struct Blake3_192<B: StarkField>(PhantomData<B>);
+pub struct TruncatedBlake3<B: StarkField, const N: usize>(PhantomData<B>);
 
-impl<B: StarkField> Hasher for Blake3_192<B> {
-    type Digest = ByteDigest<24>;
+impl<B: StarkField, const N: usize> Hasher for TruncatedBlake3<B, N> {
+    type Digest = ByteDigest<N>;
 
-    const COLLISION_RESISTANCE: u32 = 96;
+    const COLLISION_RESISTANCE: u32 = {
+        assert!(N >= 16 && N <= 32, "digest size must be between 16 and 32 bytes");
+        N as u32 * 4
+    };
 
     fn hash(bytes: &[u8]) -> Self::Digest {
         let result = blake3::hash(bytes);
-        ByteDigest(result.as_bytes()[..24].try_into().unwrap())
+        ByteDigest(result.as_bytes()[..N].try_into().unwrap())
     }
 
     fn merge(values: &[Self::Digest; 2]) -> Self::Digest {
         let result = blake3::hash(ByteDigest::digests_as_bytes(values));
-        ByteDigest(result.as_bytes()[..24].try_into().unwrap())
+        ByteDigest(result.as_bytes()[..N].try_into().unwrap())
     }
 
     fn merge_many(values: &[Self::Digest]) -> Self::Digest {
         let result = blake3::hash(ByteDigest::digests_as_bytes(values));
-        ByteDigest(result.as_bytes()[..24].try_into().unwrap())
+        ByteDigest(result.as_bytes()[..N].try_into().unwrap())
     }
 
     fn merge_with_int(seed: Self::Digest, value: u64) -> Self::Digest {
-        let mut data = [0; 32];
-        data[..24].copy_from_slice(&seed.0);
-        data[24..].copy_from_slice(&value.to_le_bytes());
+        let mut data = [0; 40];
+        data[..N].copy_from_slice(&seed.0);
+        data[N..N + 8].copy_from_slice(&value.to_le_bytes());
 
-        let result = blake3::hash(&data);
-        ByteDigest(result.as_bytes()[..24].try_into().unwrap())
+        let result = blake3::hash(&data[..N + 8]);
+        ByteDigest(result.as_bytes()[..N].try_into().unwrap())
     }
 }
 
-impl<B: StarkField> ElementHasher for Blake3_192<B> {
+impl<B: StarkField, const N: usize> ElementHasher for TruncatedBlake3<B, N> {
     type BaseField = B;
 
     fn hash_elements<E: FieldElement<BaseField = Self::BaseField>>(elements: &[E]) -> Self::Digest {
@@ -112,14 +130,14 @@ impl<B: StarkField> ElementHasher for Blake3_192<B> {
             // element bytes directly
             let bytes = E::elements_as_bytes(elements);
             let result = blake3::hash(bytes);
-            ByteDigest(result.as_bytes()[..24].try_into().unwrap())
+            ByteDigest(result.as_bytes()[..N].try_into().unwrap())
         } else {
             // when elements' internal and canonical representations differ, we need to serialize
             // them before hashing
             let mut hasher = BlakeHasher::new();
             hasher.write_many(elements);
             let result = hasher.finalize();
-            ByteDigest(result[..24].try_into().unwrap())
+            ByteDigest(result[..N].try_into().unwrap())
         }
     }
 }
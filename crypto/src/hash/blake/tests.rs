@@ -8,7 +8,7 @@ use rand_utils::rand_array;
 use utils::Deserializable;
 
 use super::{Blake3_256, ElementHasher, Hasher};
-use crate::hash::{Blake3_192, ByteDigest};
+use crate::hash::{Blake3_160, Blake3_192, Blake3_224, ByteDigest, Digest};
 
 #[test]
 fn hash_padding() {
@@ -53,3 +53,33 @@ fn merge_vs_merge_many_192() {
 
     assert_eq!(r1, r2)
 }
+
+#[test]
+fn truncated_output() {
+    let e: [BaseElement; 4] = rand_array();
+    let full = Blake3_256::hash_elements(&e).as_bytes();
+
+    // truncated digests are prefixes of the full digest
+    assert_eq!(full[..20], Blake3_160::hash_elements(&e).as_bytes()[..20]);
+    assert_eq!(full[..24], Blake3_192::hash_elements(&e).as_bytes()[..24]);
+    assert_eq!(full[..28], Blake3_224::hash_elements(&e).as_bytes()[..28]);
+
+    // unused bytes are set to 0
+    assert_eq!([0; 12], Blake3_160::hash_elements(&e).as_bytes()[20..]);
+
+    assert_eq!(80, Blake3_160::<BaseElement>::COLLISION_RESISTANCE);
+    assert_eq!(96, Blake3_192::<BaseElement>::COLLISION_RESISTANCE);
+    assert_eq!(112, Blake3_224::<BaseElement>::COLLISION_RESISTANCE);
+}
+
+#[test]
+fn truncated_merge_with_int() {
+    let seed = Blake3_160::<BaseElement>::hash(&[1_u8, 2, 3]);
+
+    let mut data = seed.as_bytes()[..20].to_vec();
+    data.extend_from_slice(&42_u64.to_le_bytes());
+
+    let r1 = Blake3_160::<BaseElement>::merge_with_int(seed, 42);
+    let r2 = Blake3_160::<BaseElement>::hash(&data);
+    assert_eq!(r1, r2);
+}
@@ -8,3 +8,5 @@
 pub mod mds_f64_8x8;
 
 pub mod mds_f64_12x12;
+
+pub mod poseidon2_f64_12x12;
@@ -0,0 +1,92 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+//! This module contains the linear layers of the Poseidon2 permutation over the 64-bit field with
+//! a state of 12 elements, as described in section 5 of <https://eprint.iacr.org/2023/323.pdf>.
+//!
+//! The external layer multiplies the state by circ(2·M4, M4, M4), where M4 is the 4x4 MDS matrix
+//! from the paper. This is computed by multiplying each 4-element chunk of the state by M4 and
+//! then adding the sum of all chunks to each of the chunks.
+//!
+//! The internal layer multiplies the state by a matrix of the form 1 + diag(d_0, ..., d_11),
+//! i.e., each element is multiplied by its diagonal entry and the sum of all elements is added to
+//! the result. The diagonal entries are the ones used by the reference implementation in
+//! [HorizenLabs/poseidon2](https://github.com/HorizenLabs/poseidon2).
+
+use math::{fields::f64::BaseElement, FieldElement};
+
+// CONSTANTS
+// ================================================================================================
+
+/// Diagonal of the internal matrix minus the identity matrix.
+pub(crate) const MAT_DIAG12_M_1: [BaseElement; 12] = [
+    BaseElement::new(0xc3b6c08e23ba9300),
+    BaseElement::new(0xd84b5de94a324fb6),
+    BaseElement::new(0x0d0c371c5b35b84f),
+    BaseElement::new(0x7964f570e7188037),
+    BaseElement::new(0x5daf18bbd996604b),
+    BaseElement::new(0x6743bc47b9595257),
+    BaseElement::new(0x5528b9362c59bb70),
+    BaseElement::new(0xac45e25b7127b68b),
+    BaseElement::new(0xa2077d7dfbb606b5),
+    BaseElement::new(0xf3faac6faee378ae),
+    BaseElement::new(0x0c6388b51545e883),
+    BaseElement::new(0xd27dbb6944917b60),
+];
+
+// LINEAR LAYERS
+// ================================================================================================
+
+/// Multiplies the state by the external matrix circ(2·M4, M4, M4).
+#[inline(always)]
+pub(crate) fn matmul_external(state: &mut [BaseElement; 12]) {
+    for chunk in state.chunks_exact_mut(4) {
+        matmul_m4(chunk.try_into().unwrap());
+    }
+
+    let mut sums = [BaseElement::ZERO; 4];
+    for chunk in state.chunks_exact(4) {
+        sums.iter_mut().zip(chunk).for_each(|(s, &c)| *s += c);
+    }
+    for chunk in state.chunks_exact_mut(4) {
+        chunk.iter_mut().zip(sums).for_each(|(c, s)| *c += s);
+    }
+}
+
+/// Multiplies the state by the internal matrix 1 + diag(MAT_DIAG12_M_1).
+#[inline(always)]
+pub(crate) fn matmul_internal(state: &mut [BaseElement; 12]) {
+    let sum = state.iter().fold(BaseElement::ZERO, |acc, &s| acc + s);
+    state.iter_mut().zip(MAT_DIAG12_M_1).for_each(|(s, d)| *s = *s * d + sum);
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Multiplies a 4-element chunk of the state by the matrix
+///
+/// ```text
+/// [5 7 1 3]
+/// [4 6 1 1]
+/// [1 3 5 7]
+/// [1 1 4 6]
+/// ```
+///
+/// using 8 additions and 4 doublings, as described in appendix B of the Poseidon2 paper.
+#[inline(always)]
+fn matmul_m4(chunk: &mut [BaseElement; 4]) {
+    let [x0, x1, x2, x3] = *chunk;
+
+    let t0 = x0 + x1;
+    let t1 = x2 + x3;
+    let t2 = x1.double() + t1;
+    let t3 = x3.double() + t0;
+    let t4 = t1.double().double() + t3;
+    let t5 = t0.double().double() + t2;
+    let t6 = t3 + t5;
+    let t7 = t2 + t4;
+
+    *chunk = [t6, t5, t7, t4];
+}
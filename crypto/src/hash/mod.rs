@@ -9,15 +9,18 @@ use math::{FieldElement, StarkField};
 use utils::{ByteReader, Deserializable, DeserializationError, Serializable};
 
 mod blake;
-pub use blake::{Blake3_192, Blake3_256};
+pub use blake::{Blake3_160, Blake3_192, Blake3_224, Blake3_256, TruncatedBlake3};
 
 mod sha;
-pub use sha::Sha3_256;
+pub use sha::{Keccak256, Sha2_160, Sha2_192, Sha2_224, Sha2_256, Sha3_256, TruncatedSha2};
 
 mod mds;
 
+mod poseidon2;
+pub use poseidon2::Poseidon2_64_256;
+
 mod rescue;
-pub use rescue::{Rp62_248, Rp64_256, RpJive64_256};
+pub use rescue::{Rp128_256, Rp62_248, Rp64_256, RpJive64_256};
 
 // HASHER TRAITS
 // ================================================================================================
@@ -70,6 +73,10 @@ pub trait ElementHasher: Hasher {
 pub trait Digest:
     Debug + Default + Copy + Clone + Eq + PartialEq + Send + Sync + Serializable + Deserializable
 {
+    /// Number of bytes in this digest; this is the number of meaningful bytes in the array
+    /// returned by [Digest::as_bytes()].
+    const NUM_BYTES: usize = 32;
+
     /// Returns this digest serialized into an array of bytes.
     ///
     /// Ideally, the length of the returned array should be defined by an associated constant, but
@@ -106,6 +113,8 @@ impl<const N: usize> ByteDigest<N> {
 }
 
 impl<const N: usize> Digest for ByteDigest<N> {
+    const NUM_BYTES: usize = N;
+
     fn as_bytes(&self) -> [u8; 32] {
         let mut result = [0; 32];
         result[..N].copy_from_slice(&self.0);
@@ -0,0 +1,485 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use core::ops::Range;
+
+use math::{fields::f64::BaseElement, FieldElement, StarkField};
+
+use super::{
+    super::mds::poseidon2_f64_12x12::{matmul_external, matmul_internal},
+    Digest, ElementHasher, Hasher,
+};
+
+mod digest;
+pub use digest::ElementDigest;
+
+#[cfg(test)]
+mod tests;
+
+// CONSTANTS
+// ================================================================================================
+
+/// Sponge state is set to 12 field elements or 96 bytes; 8 elements are reserved for rate and
+/// the remaining 4 elements are reserved for capacity.
+const STATE_WIDTH: usize = 12;
+
+/// The rate portion of the state is located in elements 4 through 11.
+const RATE_RANGE: Range<usize> = 4..12;
+const RATE_WIDTH: usize = RATE_RANGE.end - RATE_RANGE.start;
+
+const INPUT1_RANGE: Range<usize> = 4..8;
+const INPUT2_RANGE: Range<usize> = 8..12;
+
+/// The capacity portion of the state is located in elements 0, 1, 2, and 3.
+const CAPACITY_RANGE: Range<usize> = 0..4;
+
+/// The output of the hash function is a digest which consists of 4 field elements or 32 bytes.
+///
+/// The digest is returned from state elements 4, 5, 6, and 7 (the first four elements of the
+/// rate portion).
+const DIGEST_RANGE: Range<usize> = 4..8;
+const DIGEST_SIZE: usize = DIGEST_RANGE.end - DIGEST_RANGE.start;
+
+/// The number of full rounds is set to 8 (4 at the beginning and 4 at the end of the permutation)
+/// and the number of partial rounds is set to 22 to target 128-bit security level; these are the
+/// parameters recommended in table 1 of <https://eprint.iacr.org/2023/323.pdf>.
+const NUM_FULL_ROUNDS: usize = 8;
+const NUM_HALF_FULL_ROUNDS: usize = NUM_FULL_ROUNDS / 2;
+const NUM_PARTIAL_ROUNDS: usize = 22;
+
+/// S-Box power; the smallest integer d such that gcd(d, p - 1) = 1.
+///
+/// The constant is defined for tests only because the exponentiation in the code is unrolled for
+/// efficiency reasons.
+#[cfg(test)]
+const ALPHA: u64 = 7;
+
+// HASHER IMPLEMENTATION
+// ================================================================================================
+
+/// Implementation of [Hasher] trait for Poseidon2 hash function with 256-bit output.
+///
+/// The permutation is implemented according to the Poseidon2
+/// [specifications](https://eprint.iacr.org/2023/323.pdf) and is compatible with the reference
+/// implementation in [HorizenLabs/poseidon2](https://github.com/HorizenLabs/poseidon2) (round
+/// constants are generated using the Grain LFSR as described in the specifications, and the
+/// diagonal of the internal matrix is the one used by the reference implementation).
+///
+/// The sponge construction built on top of the permutation is the same as the one used by
+/// [Rp64_256](crate::hashers::Rp64_256):
+/// * When hashing a sequence of elements, we do not append any padding to the sequence. Instead,
+///   we initialize the first capacity element to the number of elements to be hashed, and pad the
+///   sequence with Fp(0) elements only. This ensures consistency of hash outputs between different
+///   hashing methods (see section below), but means that the number of elements to be hashed must
+///   be known upfront.
+/// * We use the first 4 elements of the state for capacity and the remaining 8 elements for rate.
+///   The output of the hash function comes from the first four elements of the rate portion of the
+///   state (elements 4, 5, 6, and 7).
+///
+/// The parameters used to instantiate the function are:
+/// * Field: 64-bit prime field with modulus 2^64 - 2^32 + 1.
+/// * State width: 12 field elements.
+/// * Capacity size: 4 field elements.
+/// * Number of full rounds: 8.
+/// * Number of partial rounds: 22.
+/// * S-Box degree: 7.
+///
+/// The above parameters target 128-bit security level. The digest consists of four field elements
+/// and it can be serialized into 32 bytes (256 bits).
+///
+/// ## Hash output consistency
+/// Functions [hash_elements()](Poseidon2_64_256::hash_elements), [merge()](Poseidon2_64_256::merge),
+/// and [merge_with_int()](Poseidon2_64_256::merge_with_int) are internally consistent. That is,
+/// computing a hash for the same set of elements using these functions will always produce the
+/// same result.
+///
+/// However, [hash()](Poseidon2_64_256::hash) function is not consistent with functions mentioned
+/// above because it needs to be able to handle arbitrary binary strings, which may or may not
+/// encode valid field elements. See [Rp64_256](crate::hashers::Rp64_256) for more details.
+pub struct Poseidon2_64_256();
+
+impl Hasher for Poseidon2_64_256 {
+    type Digest = ElementDigest;
+
+    const COLLISION_RESISTANCE: u32 = 128;
+
+    fn hash(bytes: &[u8]) -> Self::Digest {
+        // compute the number of elements required to represent the string; we will be processing
+        // the string in 7-byte chunks, thus the number of elements will be equal to the number
+        // of such chunks (including a potential partial chunk at the end).
+        let num_elements = if bytes.len().is_multiple_of(7) {
+            bytes.len() / 7
+        } else {
+            bytes.len() / 7 + 1
+        };
+
+        // initialize state to all zeros, except for the first element of the capacity part, which
+        // is set to the number of elements to be hashed. this is done so that adding zero elements
+        // at the end of the list always results in a different hash.
+        let mut state = [BaseElement::ZERO; STATE_WIDTH];
+        state[CAPACITY_RANGE.start] = BaseElement::new(num_elements as u64);
+
+        // break the string into 7-byte chunks, convert each chunk into a field element, and
+        // absorb the element into the rate portion of the state. we use 7-byte chunks because
+        // every 7-byte chunk is guaranteed to map to some field element.
+        let mut i = 0;
+        let mut buf = [0_u8; 8];
+        for chunk in bytes.chunks(7) {
+            if i < num_elements - 1 {
+                buf[..7].copy_from_slice(chunk);
+            } else {
+                // if we are dealing with the last chunk, it may be smaller than 7 bytes long, so
+                // we need to handle it slightly differently. we also append a byte with value 1
+                // to the end of the string; this pads the string in such a way that adding
+                // trailing zeros results in different hash
+                let chunk_len = chunk.len();
+                buf = [0_u8; 8];
+                buf[..chunk_len].copy_from_slice(chunk);
+                buf[chunk_len] = 1;
+            }
+
+            // convert the bytes into a field element and absorb it into the rate portion of the
+            // state; if the rate is filled up, apply the Poseidon2 permutation and start absorbing
+            // again from zero index.
+            state[RATE_RANGE.start + i] += BaseElement::new(u64::from_le_bytes(buf));
+            i += 1;
+            if i.is_multiple_of(RATE_WIDTH) {
+                Self::apply_permutation(&mut state);
+                i = 0;
+            }
+        }
+
+        // if we absorbed some elements but didn't apply a permutation to them (would happen when
+        // the number of elements is not a multiple of RATE_WIDTH), apply the Poseidon2 permutation.
+        // we don't need to apply any extra padding because we injected total number of elements
+        // in the input list into the capacity portion of the state during initialization.
+        if i > 0 {
+            Self::apply_permutation(&mut state);
+        }
+
+        // return the first 4 elements of the state as hash result
+        ElementDigest::new(state[DIGEST_RANGE].try_into().unwrap())
+    }
+
+    fn merge(values: &[Self::Digest; 2]) -> Self::Digest {
+        // initialize the state by copying the digest elements into the rate portion of the state
+        // (8 total elements), and set the first capacity element to 8 (the number of elements to
+        // be hashed).
+        let mut state = [BaseElement::ZERO; STATE_WIDTH];
+        state[RATE_RANGE].copy_from_slice(Self::Digest::digests_as_elements(values));
+        state[CAPACITY_RANGE.start] = BaseElement::new(RATE_WIDTH as u64);
+
+        // apply the Poseidon2 permutation and return the first four elements of the state
+        Self::apply_permutation(&mut state);
+        ElementDigest::new(state[DIGEST_RANGE].try_into().unwrap())
+    }
+
+    fn merge_many(values: &[Self::Digest]) -> Self::Digest {
+        Self::hash_elements(ElementDigest::digests_as_elements(values))
+    }
+
+    fn merge_with_int(seed: Self::Digest, value: u64) -> Self::Digest {
+        // initialize the state as follows:
+        // - seed is copied into the first 4 elements of the rate portion of the state.
+        // - if the value fits into a single field element, copy it into the fifth rate element and
+        //   set the first capacity element to 5 (the number of elements to be hashed).
+        // - if the value doesn't fit into a single field element, split it into two field elements,
+        //   copy them into rate elements 5 and 6, and set the first capacity element to 6.
+        let mut state = [BaseElement::ZERO; STATE_WIDTH];
+        state[INPUT1_RANGE].copy_from_slice(seed.as_elements());
+        state[INPUT2_RANGE.start] = BaseElement::new(value);
+        if value < BaseElement::MODULUS {
+            state[CAPACITY_RANGE.start] = BaseElement::new(DIGEST_SIZE as u64 + 1);
+        } else {
+            state[INPUT2_RANGE.start + 1] = BaseElement::new(value / BaseElement::MODULUS);
+            state[CAPACITY_RANGE.start] = BaseElement::new(DIGEST_SIZE as u64 + 2);
+        }
+
+        // apply the Poseidon2 permutation and return the first four elements of the state
+        Self::apply_permutation(&mut state);
+        ElementDigest::new(state[DIGEST_RANGE].try_into().unwrap())
+    }
+}
+
+impl ElementHasher for Poseidon2_64_256 {
+    type BaseField = BaseElement;
+
+    fn hash_elements<E: FieldElement<BaseField = Self::BaseField>>(elements: &[E]) -> Self::Digest {
+        // convert the elements into a list of base field elements
+        let elements = E::slice_as_base_elements(elements);
+
+        // initialize state to all zeros, except for the last element of the capacity part, which
+        // is set to the number of elements to be hashed. this is done so that adding zero elements
+        // at the end of the list always results in a different hash.
+        let mut state = [BaseElement::ZERO; STATE_WIDTH];
+        state[CAPACITY_RANGE.start] = BaseElement::new(elements.len() as u64);
+
+        // absorb elements into the state one by one until the rate portion of the state is filled
+        // up; then apply the Poseidon2 permutation and start absorbing again; repeat until all
+        // elements have been absorbed
+        let mut i = 0;
+        for &element in elements.iter() {
+            state[RATE_RANGE.start + i] += element;
+            i += 1;
+            if i.is_multiple_of(RATE_WIDTH) {
+                Self::apply_permutation(&mut state);
+                i = 0;
+            }
+        }
+
+        // if we absorbed some elements but didn't apply a permutation to them (would happen when
+        // the number of elements is not a multiple of RATE_WIDTH), apply the Poseidon2 permutation.
+        // we don't need to apply any extra padding because we injected total number of elements
+        // in the input list into the capacity portion of the state during initialization.
+        if i > 0 {
+            Self::apply_permutation(&mut state);
+        }
+
+        // return the first 4 elements of the state as hash result
+        ElementDigest::new(state[DIGEST_RANGE].try_into().unwrap())
+    }
+}
+
+// HASH FUNCTION IMPLEMENTATION
+// ================================================================================================
+
+impl Poseidon2_64_256 {
+    // CONSTANTS
+    // --------------------------------------------------------------------------------------------
+
+    /// The number of full rounds (half of them at the beginning and half at the end of the
+    /// permutation).
+    pub const NUM_FULL_ROUNDS: usize = NUM_FULL_ROUNDS;
+
+    /// The number of partial rounds.
+    pub const NUM_PARTIAL_ROUNDS: usize = NUM_PARTIAL_ROUNDS;
+
+    /// Sponge state is set to 12 field elements or 96 bytes; 8 elements are reserved for rate and
+    /// the remaining 4 elements are reserved for capacity.
+    pub const STATE_WIDTH: usize = STATE_WIDTH;
+
+    /// The rate portion of the state is located in elements 4 through 11 (inclusive).
+    pub const RATE_RANGE: Range<usize> = RATE_RANGE;
+
+    /// The capacity portion of the state is located in elements 0, 1, 2, and 3.
+    pub const CAPACITY_RANGE: Range<usize> = CAPACITY_RANGE;
+
+    /// The output of the hash function can be read from state elements 4, 5, 6, and 7.
+    pub const DIGEST_RANGE: Range<usize> = DIGEST_RANGE;
+
+    /// Round constants added to the hasher state in the first half of the full rounds.
+    pub const ARK_EXTERNAL_INITIAL: [[BaseElement; STATE_WIDTH]; NUM_HALF_FULL_ROUNDS] =
+        ARK_EXTERNAL_INITIAL;
+
+    /// Round constants added to the first state element in the partial rounds.
+    pub const ARK_INTERNAL: [BaseElement; NUM_PARTIAL_ROUNDS] = ARK_INTERNAL;
+
+    /// Round constants added to the hasher state in the second half of the full rounds.
+    pub const ARK_EXTERNAL_TERMINAL: [[BaseElement; STATE_WIDTH]; NUM_HALF_FULL_ROUNDS] =
+        ARK_EXTERNAL_TERMINAL;
+
+    // POSEIDON2 PERMUTATION
+    // --------------------------------------------------------------------------------------------
+
+    /// Applies Poseidon2 permutation to the provided state.
+    pub fn apply_permutation(state: &mut [BaseElement; STATE_WIDTH]) {
+        // the external linear layer is applied to the input before the first round
+        matmul_external(state);
+
+        for ark in ARK_EXTERNAL_INITIAL.iter() {
+            Self::apply_full_round(state, ark);
+        }
+        for &ark in ARK_INTERNAL.iter() {
+            Self::apply_partial_round(state, ark);
+        }
+        for ark in ARK_EXTERNAL_TERMINAL.iter() {
+            Self::apply_full_round(state, ark);
+        }
+    }
+
+    /// Poseidon2 full round function; the S-Box is applied to all state elements.
+    #[inline(always)]
+    pub fn apply_full_round(
+        state: &mut [BaseElement; STATE_WIDTH],
+        ark: &[BaseElement; STATE_WIDTH],
+    ) {
+        Self::add_constants(state, ark);
+        Self::apply_sbox(state);
+        matmul_external(state);
+    }
+
+    /// Poseidon2 partial round function; the S-Box is applied to the first state element only.
+    #[inline(always)]
+    pub fn apply_partial_round(state: &mut [BaseElement; STATE_WIDTH], ark: BaseElement) {
+        state[0] = (state[0] + ark).exp7();
+        matmul_internal(state);
+    }
+
+    // HELPER FUNCTIONS
+    // --------------------------------------------------------------------------------------------
+
+    #[inline(always)]
+    fn add_constants(state: &mut [BaseElement; STATE_WIDTH], ark: &[BaseElement; STATE_WIDTH]) {
+        state.iter_mut().zip(ark).for_each(|(s, &k)| *s += k);
+    }
+
+    #[inline(always)]
+    fn apply_sbox(state: &mut [BaseElement; STATE_WIDTH]) {
+        state.iter_mut().for_each(|s| *s = s.exp7());
+    }
+}
+
+// ROUND CONSTANTS
+// ================================================================================================
+
+// Round constants are generated using the Grain LFSR as described in appendix F of
+// <https://eprint.iacr.org/2019/458.pdf> (with parameters field = 1, sbox = 0, n = 64, t = 12,
+// R_F = 8, R_P = 22). Unlike in Poseidon, a single constant is generated for each partial round.
+
+/// Round constants added to the state in the first four full rounds.
+const ARK_EXTERNAL_INITIAL: [[BaseElement; STATE_WIDTH]; NUM_HALF_FULL_ROUNDS] = [
+    [
+        BaseElement::new(0x13dcf33aba214f46),
+        BaseElement::new(0x30b3b654a1da6d83),
+        BaseElement::new(0x1fc634ada6159b56),
+        BaseElement::new(0x937459964dc03466),
+        BaseElement::new(0xedd2ef2ca7949924),
+        BaseElement::new(0xede9affde0e22f68),
+        BaseElement::new(0x8515b9d6bac9282d),
+        BaseElement::new(0x6b5c07b4e9e900d8),
+        BaseElement::new(0x1ec66368838c8a08),
+        BaseElement::new(0x9042367d80d1fbab),
+        BaseElement::new(0x400283564a3c3799),
+        BaseElement::new(0x4a00be0466bca75e),
+    ],
+    [
+        BaseElement::new(0x7913beee58e3817f),
+        BaseElement::new(0xf545e88532237d90),
+        BaseElement::new(0x22f8cb8736042005),
+        BaseElement::new(0x6f04990e247a2623),
+        BaseElement::new(0xfe22e87ba37c38cd),
+        BaseElement::new(0xd20e32c85ffe2815),
+        BaseElement::new(0x117227674048fe73),
+        BaseElement::new(0x4e9fb7ea98a6b145),
+        BaseElement::new(0xe0866c232b8af08b),
+        BaseElement::new(0x00bbc77916884964),
+        BaseElement::new(0x7031c0fb990d7116),
+        BaseElement::new(0x240a9e87cf35108f),
+    ],
+    [
+        BaseElement::new(0x2e6363a5a12244b3),
+        BaseElement::new(0x5e1c3787d1b5011c),
+        BaseElement::new(0x4132660e2a196e8b),
+        BaseElement::new(0x3a013b648d3d4327),
+        BaseElement::new(0xf79839f49888ea43),
+        BaseElement::new(0xfe85658ebafe1439),
+        BaseElement::new(0xb6889825a14240bd),
+        BaseElement::new(0x578453605541382b),
+        BaseElement::new(0x4508cda8f6b63ce9),
+        BaseElement::new(0x9c3ef35848684c91),
+        BaseElement::new(0x0812bde23c87178c),
+        BaseElement::new(0xfe49638f7f722c14),
+    ],
+    [
+        BaseElement::new(0x8e3f688ce885cbf5),
+        BaseElement::new(0xb8e110acf746a87d),
+        BaseElement::new(0xb4b2e8973a6dabef),
+        BaseElement::new(0x9e714c5da3d462ec),
+        BaseElement::new(0x6438f9033d3d0c15),
+        BaseElement::new(0x24312f7cf1a27199),
+        BaseElement::new(0x23f843bb47acbf71),
+        BaseElement::new(0x9183f11a34be9f01),
+        BaseElement::new(0x839062fbb9d45dbf),
+        BaseElement::new(0x24b56e7e6c2e43fa),
+        BaseElement::new(0xe1683da61c962a72),
+        BaseElement::new(0xa95c63971a19bfa7),
+    ],
+];
+
+/// Round constants added to the first state element in each partial round.
+const ARK_INTERNAL: [BaseElement; NUM_PARTIAL_ROUNDS] = [
+    BaseElement::new(0x4adf842aa75d4316),
+    BaseElement::new(0xf8fbb871aa4ab4eb),
+    BaseElement::new(0x68e85b6eb2dd6aeb),
+    BaseElement::new(0x07a0b06b2d270380),
+    BaseElement::new(0xd94e0228bd282de4),
+    BaseElement::new(0x8bdd91d3250c5278),
+    BaseElement::new(0x209c68b88bba778f),
+    BaseElement::new(0xb5e18cdab77f3877),
+    BaseElement::new(0xb296a3e808da93fa),
+    BaseElement::new(0x8370ecbda11a327e),
+    BaseElement::new(0x3f9075283775dad8),
+    BaseElement::new(0xb78095bb23c6aa84),
+    BaseElement::new(0x3f36b9fe72ad4e5f),
+    BaseElement::new(0x69bc96780b10b553),
+    BaseElement::new(0x3f1d341f2eb7b881),
+    BaseElement::new(0x4e939e9815838818),
+    BaseElement::new(0xda366b3ae2a31604),
+    BaseElement::new(0xbc89db1e7287d509),
+    BaseElement::new(0x6102f411f9ef5659),
+    BaseElement::new(0x58725c5e7ac1f0ab),
+    BaseElement::new(0x0df5856c798883e7),
+    BaseElement::new(0xf7bb62a8da4c961b),
+];
+
+/// Round constants added to the state in the last four full rounds.
+const ARK_EXTERNAL_TERMINAL: [[BaseElement; STATE_WIDTH]; NUM_HALF_FULL_ROUNDS] = [
+    [
+        BaseElement::new(0xc68be7c94882a24d),
+        BaseElement::new(0xaf996d5d5cdaedd9),
+        BaseElement::new(0x9717f025e7daf6a5),
+        BaseElement::new(0x6436679e6e7216f4),
+        BaseElement::new(0x8a223d99047af267),
+        BaseElement::new(0xbb512e35a133ba9a),
+        BaseElement::new(0xfbbf44097671aa03),
+        BaseElement::new(0xf04058ebf6811e61),
+        BaseElement::new(0x5cca84703fac7ffb),
+        BaseElement::new(0x9b55c7945de6469f),
+        BaseElement::new(0x8e05bf09808e934f),
+        BaseElement::new(0x2ea900de876307d7),
+    ],
+    [
+        BaseElement::new(0x7748fff2b38dfb89),
+        BaseElement::new(0x6b99a676dd3b5d81),
+        BaseElement::new(0xac4bb7c627cf7c13),
+        BaseElement::new(0xadb6ebe5e9e2f5ba),
+        BaseElement::new(0x2d33378cafa24ae3),
+        BaseElement::new(0x1e5b73807543f8c2),
+        BaseElement::new(0x09208814bfebb10f),
+        BaseElement::new(0x782e64b6bb5b93dd),
+        BaseElement::new(0xadd5a48eac90b50f),
+        BaseElement::new(0xadd4c54c736ea4b1),
+        BaseElement::new(0xd58dbb86ed817fd8),
+        BaseElement::new(0x6d5ed1a533f34ddd),
+    ],
+    [
+        BaseElement::new(0x28686aa3e36b7cb9),
+        BaseElement::new(0x591abd3476689f36),
+        BaseElement::new(0x047d766678f13875),
+        BaseElement::new(0xa2a11112625f5b49),
+        BaseElement::new(0x21fd10a3f8304958),
+        BaseElement::new(0xf9b40711443b0280),
+        BaseElement::new(0xd2697eb8b2bde88e),
+        BaseElement::new(0x3493790b51731b3f),
+        BaseElement::new(0x11caf9dd73764023),
+        BaseElement::new(0x7acfb8f72878164e),
+        BaseElement::new(0x744ec4db23cefc26),
+        BaseElement::new(0x1e00e58f422c6340),
+    ],
+    [
+        BaseElement::new(0x21dd28d906a62dda),
+        BaseElement::new(0xf32a46ab5f465b5f),
+        BaseElement::new(0xbfce13201f3f7e6b),
+        BaseElement::new(0xf30d2e7adb5304e2),
+        BaseElement::new(0xecdf4ee4abad48e9),
+        BaseElement::new(0xf94e82182d395019),
+        BaseElement::new(0x4ee52e3744d887c5),
+        BaseElement::new(0xa1341c7cac0083b2),
+        BaseElement::new(0x2302fb26c30c834a),
+        BaseElement::new(0xaea3c587273bf7d3),
+        BaseElement::new(0xf798e24961823ec7),
+        BaseElement::new(0x962deba3e9a2cd94),
+    ],
+];
@@ -0,0 +1,201 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use proptest::prelude::*;
+use rand_utils::{rand_array, rand_value};
+
+use super::{
+    super::super::mds::poseidon2_f64_12x12::{matmul_external, matmul_internal, MAT_DIAG12_M_1},
+    BaseElement, ElementDigest, ElementHasher, FieldElement, Hasher, Poseidon2_64_256, StarkField,
+    ALPHA, STATE_WIDTH,
+};
+
+#[test]
+fn test_sbox() {
+    let state: [BaseElement; STATE_WIDTH] = rand_array();
+
+    let mut expected = state;
+    expected.iter_mut().for_each(|v| *v = v.exp(ALPHA));
+
+    let mut actual = state;
+    Poseidon2_64_256::apply_sbox(&mut actual);
+
+    assert_eq!(expected, actual);
+}
+
+#[test]
+fn apply_permutation() {
+    let mut state: [BaseElement; STATE_WIDTH] = [
+        BaseElement::new(0),
+        BaseElement::new(1),
+        BaseElement::new(2),
+        BaseElement::new(3),
+        BaseElement::new(4),
+        BaseElement::new(5),
+        BaseElement::new(6),
+        BaseElement::new(7),
+        BaseElement::new(8),
+        BaseElement::new(9),
+        BaseElement::new(10),
+        BaseElement::new(11),
+    ];
+
+    Poseidon2_64_256::apply_permutation(&mut state);
+
+    // expected values are the test vector of the reference implementation of Poseidon2
+    let expected = vec![
+        BaseElement::new(0x01eaef96bdf1c0c1),
+        BaseElement::new(0x1f0d2cc525b2540c),
+        BaseElement::new(0x6282c1dfe1e0358d),
+        BaseElement::new(0xe780d721f698e1e6),
+        BaseElement::new(0x280c0b6f753d833b),
+        BaseElement::new(0x1b942dd5023156ab),
+        BaseElement::new(0x43f0df3fcccb8398),
+        BaseElement::new(0xe8e8190585489025),
+        BaseElement::new(0x56bdbf72f77ada22),
+        BaseElement::new(0x7911c32bf9dcd705),
+        BaseElement::new(0xec467926508fbe67),
+        BaseElement::new(0x6a50450ddf85a6ed),
+    ];
+
+    assert_eq!(expected, state);
+}
+
+#[test]
+fn hash_elements_vs_merge() {
+    let elements: [BaseElement; 8] = rand_array();
+
+    let digests: [ElementDigest; 2] = [
+        ElementDigest::new(elements[..4].try_into().unwrap()),
+        ElementDigest::new(elements[4..].try_into().unwrap()),
+    ];
+
+    let m_result = Poseidon2_64_256::merge(&digests);
+    let h_result = Poseidon2_64_256::hash_elements(&elements);
+    assert_eq!(m_result, h_result);
+}
+
+#[test]
+fn merge_vs_merge_many() {
+    let elements: [BaseElement; 8] = rand_array();
+
+    let digests: [ElementDigest; 2] = [
+        ElementDigest::new(elements[..4].try_into().unwrap()),
+        ElementDigest::new(elements[4..].try_into().unwrap()),
+    ];
+
+    let m_result = Poseidon2_64_256::merge(&digests);
+    let h_result = Poseidon2_64_256::merge_many(&digests);
+    assert_eq!(m_result, h_result);
+}
+
+#[test]
+fn hash_elements_vs_merge_with_int() {
+    let seed = ElementDigest::new(rand_array());
+
+    // ----- value fits into a field element ------------------------------------------------------
+    let val: BaseElement = rand_value();
+    let m_result = Poseidon2_64_256::merge_with_int(seed, val.as_int());
+
+    let mut elements = seed.as_elements().to_vec();
+    elements.push(val);
+    let h_result = Poseidon2_64_256::hash_elements(&elements);
+
+    assert_eq!(m_result, h_result);
+
+    // ----- value does not fit into a field element ----------------------------------------------
+    let val = BaseElement::MODULUS + 2;
+    let m_result = Poseidon2_64_256::merge_with_int(seed, val);
+
+    let mut elements = seed.as_elements().to_vec();
+    elements.push(BaseElement::new(val));
+    elements.push(BaseElement::new(1));
+    let h_result = Poseidon2_64_256::hash_elements(&elements);
+
+    assert_eq!(m_result, h_result);
+}
+
+#[test]
+fn hash_padding() {
+    // adding a zero bytes at the end of a byte string should result in a different hash
+    let r1 = Poseidon2_64_256::hash(&[1_u8, 2, 3]);
+    let r2 = Poseidon2_64_256::hash(&[1_u8, 2, 3, 0]);
+    assert_ne!(r1, r2);
+
+    // same as above but with bigger inputs
+    let r1 = Poseidon2_64_256::hash(&[1_u8, 2, 3, 4, 5, 6]);
+    let r2 = Poseidon2_64_256::hash(&[1_u8, 2, 3, 4, 5, 6, 0]);
+    assert_ne!(r1, r2);
+
+    // same as above but with input splitting over two elements
+    let r1 = Poseidon2_64_256::hash(&[1_u8, 2, 3, 4, 5, 6, 7]);
+    let r2 = Poseidon2_64_256::hash(&[1_u8, 2, 3, 4, 5, 6, 7, 0]);
+    assert_ne!(r1, r2);
+
+    // same as above but with multiple zeros
+    let r1 = Poseidon2_64_256::hash(&[1_u8, 2, 3, 4, 5, 6, 7, 0, 0]);
+    let r2 = Poseidon2_64_256::hash(&[1_u8, 2, 3, 4, 5, 6, 7, 0, 0, 0, 0]);
+    assert_ne!(r1, r2);
+}
+
+#[test]
+fn hash_elements_padding() {
+    let e1: [BaseElement; 2] = rand_array();
+    let e2 = [e1[0], e1[1], BaseElement::ZERO];
+
+    let r1 = Poseidon2_64_256::hash_elements(&e1);
+    let r2 = Poseidon2_64_256::hash_elements(&e2);
+    assert_ne!(r1, r2);
+}
+
+/// Multiplies the state by the external matrix computed explicitly as circ(2·M4, M4, M4).
+fn matmul_external_naive(state: &mut [BaseElement; STATE_WIDTH]) {
+    const M4: [[u64; 4]; 4] = [[5, 7, 1, 3], [4, 6, 1, 1], [1, 3, 5, 7], [1, 1, 4, 6]];
+
+    let mut result = [BaseElement::ZERO; STATE_WIDTH];
+    for (i, r) in result.iter_mut().enumerate() {
+        for (j, &s) in state.iter().enumerate() {
+            let block = if i / 4 == j / 4 { 2 } else { 1 };
+            *r += BaseElement::new(block * M4[i % 4][j % 4]) * s;
+        }
+    }
+    *state = result;
+}
+
+/// Multiplies the state by the internal matrix computed explicitly as 1 + diag(MAT_DIAG12_M_1).
+fn matmul_internal_naive(state: &mut [BaseElement; STATE_WIDTH]) {
+    let mut result = [BaseElement::ZERO; STATE_WIDTH];
+    for (i, r) in result.iter_mut().enumerate() {
+        for (j, &s) in state.iter().enumerate() {
+            let diag = if i == j { MAT_DIAG12_M_1[i] } else { BaseElement::ZERO };
+            *r += (BaseElement::ONE + diag) * s;
+        }
+    }
+    *state = result;
+}
+
+proptest! {
+    #[test]
+    fn matmul_external_proptest(a in any::<[u64; STATE_WIDTH]>()) {
+        let mut v1 = a.map(BaseElement::new);
+        let mut v2 = v1;
+
+        matmul_external_naive(&mut v1);
+        matmul_external(&mut v2);
+
+        prop_assert_eq!(v1, v2);
+    }
+
+    #[test]
+    fn matmul_internal_proptest(a in any::<[u64; STATE_WIDTH]>()) {
+        let mut v1 = a.map(BaseElement::new);
+        let mut v2 = v1;
+
+        matmul_internal_naive(&mut v1);
+        matmul_internal(&mut v2);
+
+        prop_assert_eq!(v1, v2);
+    }
+}
@@ -14,6 +14,9 @@ pub use rp64_256::Rp64_256;
 mod rp64_256_jive;
 pub use rp64_256_jive::RpJive64_256;
 
+mod rp128_256;
+pub use rp128_256::Rp128_256;
+
 // HELPER FUNCTIONS
 // ================================================================================================
 
@@ -0,0 +1,679 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use core::ops::Range;
+
+use math::{fields::f128::BaseElement, FieldElement};
+
+use super::{exp_acc, Digest, ElementHasher, Hasher};
+
+mod digest;
+pub use digest::ElementDigest;
+
+#[cfg(test)]
+mod tests;
+
+// CONSTANTS
+// ================================================================================================
+
+/// Sponge state is set to 6 field elements or 96 bytes; 4 elements are reserved for rate and
+/// the remaining 2 elements are reserved for capacity.
+const STATE_WIDTH: usize = 6;
+
+/// The rate portion of the state is located in elements 2 through 5.
+const RATE_RANGE: Range<usize> = 2..6;
+const RATE_WIDTH: usize = RATE_RANGE.end - RATE_RANGE.start;
+
+const INPUT1_RANGE: Range<usize> = 2..4;
+const INPUT2_RANGE: Range<usize> = 4..6;
+
+/// The capacity portion of the state is located in elements 0 and 1.
+const CAPACITY_RANGE: Range<usize> = 0..2;
+
+/// The output of the hash function is a digest which consists of 2 field elements or 32 bytes.
+///
+/// The digest is returned from state elements 2 and 3 (the first two elements of the rate
+/// portion).
+const DIGEST_RANGE: Range<usize> = 2..4;
+const DIGEST_SIZE: usize = DIGEST_RANGE.end - DIGEST_RANGE.start;
+
+/// The number of rounds is set to 11 to target 128-bit security level with 50% security margin;
+/// computed using algorithm 7 from <https://eprint.iacr.org/2020/1143.pdf>
+const NUM_ROUNDS: usize = 11;
+
+/// S-Box and Inverse S-Box powers;
+/// computed using algorithm 6 from <https://eprint.iacr.org/2020/1143.pdf>
+///
+/// The constants are defined for tests only because the exponentiations in the code are unrolled
+/// for efficiency reasons.
+#[cfg(test)]
+const ALPHA: u128 = 3;
+#[cfg(test)]
+const INV_ALPHA: u128 = 226854911280625642308916371969163307691;
+
+// HASHER IMPLEMENTATION
+// ================================================================================================
+
+/// Implementation of [Hasher] trait for Rescue Prime hash function over the 128-bit field with
+/// 256-bit output.
+///
+/// The hash function is implemented according to the Rescue Prime
+/// [specifications](https://eprint.iacr.org/2020/1143.pdf) with the following exceptions:
+/// * When hashing a sequence of elements, we do not append Fp(1) followed by Fp(0) elements to the
+///   end of the sequence as padding. Instead, we initialize the first capacity element to the
+///   number of elements to be hashed, and pad the sequence with Fp(0) elements only. This ensures
+///   consistency of hash outputs between different hashing methods (see section below). However, it
+///   also means that our instantiation of Rescue Prime cannot be used in a stream mode as the
+///   number of elements to be hashed must be known upfront.
+/// * We use the first 2 elements of the state (rather than the last 2 elements of the state) for
+///   capacity and the remaining 4 elements for rate. The output of the hash function comes from the
+///   first two elements of the rate portion of the state (elements 2 and 3). This effectively
+///   applies a fixed bit permutation before and after XLIX permutation. We assert without proof
+///   that this does not affect security of the construction.
+///
+/// The parameters used to instantiate the function are:
+/// * Field: 128-bit prime field with modulus 2^128 - 45 * 2^40 + 1.
+/// * State width: 6 field elements.
+/// * Capacity size: 2 field elements.
+/// * Number of rounds: 11.
+/// * S-Box degree: 3.
+///
+/// The above parameters target 128-bit security level. The digest consists of two field elements
+/// and it can be serialized into 32 bytes (256 bits).
+///
+/// Unlike the 64-bit instantiations, the number of rounds is not one less than a power of two;
+/// an AIR for this function can fit a permutation into a 16-step cycle and disable the round
+/// constraints for the last 5 steps of the cycle.
+///
+/// ## Hash output consistency
+/// Functions [hash_elements()](Rp128_256::hash_elements), [merge()](Rp128_256::merge), and
+/// [merge_with_int()](Rp128_256::merge_with_int) are internally consistent. That is, computing
+/// a hash for the same set of elements using these functions will always produce the same
+/// result. For example, merging two digests using [merge()](Rp128_256::merge) will produce the
+/// same result as hashing 4 elements which make up these digests using
+/// [hash_elements()](Rp128_256::hash_elements) function.
+///
+/// However, [hash()](Rp128_256::hash) function is not consistent with functions mentioned above.
+/// For example, if we take two field elements, serialize them to bytes and hash them using
+/// [hash()](Rp128_256::hash), the result will differ from the result obtained by hashing these
+/// elements directly using [hash_elements()](Rp128_256::hash_elements) function. The reason for
+/// this difference is that [hash()](Rp128_256::hash) function needs to be able to handle
+/// arbitrary binary strings, which may or may not encode valid field elements - and thus,
+/// deserialization procedure used by this function is different from the procedure used to
+/// deserialize valid field elements.
+///
+/// Thus, if the underlying data consists of valid field elements, it might make more sense
+/// to deserialize them into field elements and then hash them using
+/// [hash_elements()](Rp128_256::hash_elements) function rather then hashing the serialized bytes
+/// using [hash()](Rp128_256::hash) function.
+pub struct Rp128_256();
+
+impl Hasher for Rp128_256 {
+    type Digest = ElementDigest;
+
+    const COLLISION_RESISTANCE: u32 = 128;
+
+    fn hash(bytes: &[u8]) -> Self::Digest {
+        // compute the number of elements required to represent the string; we will be processing
+        // the string in 15-byte chunks, thus the number of elements will be equal to the number
+        // of such chunks (including a potential partial chunk at the end).
+        let num_elements = if bytes.len().is_multiple_of(15) {
+            bytes.len() / 15
+        } else {
+            bytes.len() / 15 + 1
+        };
+
+        // initialize state to all zeros, except for the first element of the capacity part, which
+        // is set to the number of elements to be hashed. this is done so that adding zero elements
+        // at the end of the list always results in a different hash.
+        let mut state = [BaseElement::ZERO; STATE_WIDTH];
+        state[CAPACITY_RANGE.start] = BaseElement::new(num_elements as u128);
+
+        // break the string into 15-byte chunks, convert each chunk into a field element, and
+        // absorb the element into the rate portion of the state. we use 15-byte chunks because
+        // every 15-byte chunk is guaranteed to map to some field element.
+        let mut i = 0;
+        let mut buf = [0_u8; 16];
+        for chunk in bytes.chunks(15) {
+            if i < num_elements - 1 {
+                buf[..15].copy_from_slice(chunk);
+            } else {
+                // if we are dealing with the last chunk, it may be smaller than 15 bytes long, so
+                // we need to handle it slightly differently. we also append a byte with value 1
+                // to the end of the string; this pads the string in such a way that adding
+                // trailing zeros results in different hash
+                let chunk_len = chunk.len();
+                buf = [0_u8; 16];
+                buf[..chunk_len].copy_from_slice(chunk);
+                buf[chunk_len] = 1;
+            }
+
+            // convert the bytes into a field element and absorb it into the rate portion of the
+            // state; if the rate is filled up, apply the Rescue permutation and start absorbing
+            // again from zero index.
+            state[RATE_RANGE.start + i] += BaseElement::new(u128::from_le_bytes(buf));
+            i += 1;
+            if i.is_multiple_of(RATE_WIDTH) {
+                Self::apply_permutation(&mut state);
+                i = 0;
+            }
+        }
+
+        // if we absorbed some elements but didn't apply a permutation to them (would happen when
+        // the number of elements is not a multiple of RATE_WIDTH), apply the Rescue permutation.
+        // we don't need to apply any extra padding because we injected total number of elements
+        // in the input list into the capacity portion of the state during initialization.
+        if i > 0 {
+            Self::apply_permutation(&mut state);
+        }
+
+        // return the first 2 elements of the rate portion of the state as hash result
+        ElementDigest::new(state[DIGEST_RANGE].try_into().unwrap())
+    }
+
+    fn merge(values: &[Self::Digest; 2]) -> Self::Digest {
+        // initialize the state by copying the digest elements into the rate portion of the state
+        // (4 total elements), and set the first capacity element to 4 (the number of elements to
+        // be hashed).
+        let mut state = [BaseElement::ZERO; STATE_WIDTH];
+        state[RATE_RANGE].copy_from_slice(Self::Digest::digests_as_elements(values));
+        state[CAPACITY_RANGE.start] = BaseElement::new(RATE_WIDTH as u128);
+
+        // apply the Rescue permutation and return the first two elements of the rate portion of
+        // the state
+        Self::apply_permutation(&mut state);
+        ElementDigest::new(state[DIGEST_RANGE].try_into().unwrap())
+    }
+
+    fn merge_many(values: &[Self::Digest]) -> Self::Digest {
+        Self::hash_elements(ElementDigest::digests_as_elements(values))
+    }
+
+    fn merge_with_int(seed: Self::Digest, value: u64) -> Self::Digest {
+        // initialize the state as follows:
+        // - seed is copied into the first 2 elements of the rate portion of the state.
+        // - the value always fits into a single field element, so copy it into the third rate
+        //   element and set the first capacity element to 3 (the number of elements to be hashed).
+        let mut state = [BaseElement::ZERO; STATE_WIDTH];
+        state[INPUT1_RANGE].copy_from_slice(seed.as_elements());
+        state[INPUT2_RANGE.start] = BaseElement::new(value as u128);
+        state[CAPACITY_RANGE.start] = BaseElement::new(DIGEST_SIZE as u128 + 1);
+
+        // apply the Rescue permutation and return the first two elements of the rate portion of
+        // the state
+        Self::apply_permutation(&mut state);
+        ElementDigest::new(state[DIGEST_RANGE].try_into().unwrap())
+    }
+}
+
+impl ElementHasher for Rp128_256 {
+    type BaseField = BaseElement;
+
+    fn hash_elements<E: FieldElement<BaseField = Self::BaseField>>(elements: &[E]) -> Self::Digest {
+        // convert the elements into a list of base field elements
+        let elements = E::slice_as_base_elements(elements);
+
+        // initialize state to all zeros, except for the first element of the capacity part, which
+        // is set to the number of elements to be hashed. this is done so that adding zero elements
+        // at the end of the list always results in a different hash.
+        let mut state = [BaseElement::ZERO; STATE_WIDTH];
+        state[CAPACITY_RANGE.start] = BaseElement::new(elements.len() as u128);
+
+        // absorb elements into the state one by one until the rate portion of the state is filled
+        // up; then apply the Rescue permutation and start absorbing again; repeat until all
+        // elements have been absorbed
+        let mut i = 0;
+        for &element in elements.iter() {
+            state[RATE_RANGE.start + i] += element;
+            i += 1;
+            if i.is_multiple_of(RATE_WIDTH) {
+                Self::apply_permutation(&mut state);
+                i = 0;
+            }
+        }
+
+        // if we absorbed some elements but didn't apply a permutation to them (would happen when
+        // the number of elements is not a multiple of RATE_WIDTH), apply the Rescue permutation.
+        // we don't need to apply any extra padding because we injected total number of elements
+        // in the input list into the capacity portion of the state during initialization.
+        if i > 0 {
+            Self::apply_permutation(&mut state);
+        }
+
+        // return the first 2 elements of the rate portion of the state as hash result
+        ElementDigest::new(state[DIGEST_RANGE].try_into().unwrap())
+    }
+}
+
+// HASH FUNCTION IMPLEMENTATION
+// ================================================================================================
+
+impl Rp128_256 {
+    // CONSTANTS
+    // --------------------------------------------------------------------------------------------
+
+    /// The number of rounds is set to 11 to target 128-bit security level with 50% security margin.
+    pub const NUM_ROUNDS: usize = NUM_ROUNDS;
+
+    /// Sponge state is set to 6 field elements or 96 bytes; 4 elements are reserved for rate and
+    /// the remaining 2 elements are reserved for capacity.
+    pub const STATE_WIDTH: usize = STATE_WIDTH;
+
+    /// The rate portion of the state is located in elements 2 through 5 (inclusive).
+    pub const RATE_RANGE: Range<usize> = RATE_RANGE;
+
+    /// The capacity portion of the state is located in elements 0 and 1.
+    pub const CAPACITY_RANGE: Range<usize> = CAPACITY_RANGE;
+
+    /// The output of the hash function can be read from state elements 2 and 3.
+    pub const DIGEST_RANGE: Range<usize> = DIGEST_RANGE;
+
+    /// MDS matrix used for computing the linear layer in a Rescue Prime round.
+    pub const MDS: [[BaseElement; STATE_WIDTH]; STATE_WIDTH] = MDS;
+
+    /// Inverse of the MDS matrix.
+    pub const INV_MDS: [[BaseElement; STATE_WIDTH]; STATE_WIDTH] = INV_MDS;
+
+    /// Round constants added to the hasher state in the first half of the Rescue Prime round.
+    pub const ARK1: [[BaseElement; STATE_WIDTH]; NUM_ROUNDS] = ARK1;
+
+    /// Round constants added to the hasher state in the second half of the Rescue Prime round.
+    pub const ARK2: [[BaseElement; STATE_WIDTH]; NUM_ROUNDS] = ARK2;
+
+    // RESCUE PERMUTATION
+    // --------------------------------------------------------------------------------------------
+
+    /// Applies Rescue-XLIX permutation to the provided state.
+    pub fn apply_permutation(state: &mut [BaseElement; STATE_WIDTH]) {
+        // implementation is based on algorithm 3 from <https://eprint.iacr.org/2020/1143.pdf>
+        // apply round function 11 times; this provides 128-bit security with 50% security margin
+        for i in 0..NUM_ROUNDS {
+            Self::apply_round(state, i);
+        }
+    }
+
+    /// Rescue-XLIX round function.
+    #[inline(always)]
+    pub fn apply_round(state: &mut [BaseElement; STATE_WIDTH], round: usize) {
+        // apply first half of Rescue round
+        Self::apply_sbox(state);
+        Self::apply_mds(state);
+        Self::add_constants(state, &ARK1[round]);
+
+        // apply second half of Rescue round
+        Self::apply_inv_sbox(state);
+        Self::apply_mds(state);
+        Self::add_constants(state, &ARK2[round]);
+    }
+
+    // HELPER FUNCTIONS
+    // --------------------------------------------------------------------------------------------
+
+    #[inline(always)]
+    fn apply_mds(state: &mut [BaseElement; STATE_WIDTH]) {
+        let mut result = [BaseElement::ZERO; STATE_WIDTH];
+        result.iter_mut().zip(MDS).for_each(|(r, mds_row)| {
+            state.iter().zip(mds_row).for_each(|(&s, m)| {
+                *r += m * s;
+            });
+        });
+        *state = result
+    }
+
+    #[inline(always)]
+    fn add_constants(state: &mut [BaseElement; STATE_WIDTH], ark: &[BaseElement; STATE_WIDTH]) {
+        state.iter_mut().zip(ark).for_each(|(s, &k)| *s += k);
+    }
+
+    #[inline(always)]
+    fn apply_sbox(state: &mut [BaseElement; STATE_WIDTH]) {
+        state.iter_mut().for_each(|v| *v = v.cube())
+    }
+
+    #[inline(always)]
+    fn apply_inv_sbox(state: &mut [BaseElement; STATE_WIDTH]) {
+        // compute base^226854911280625642308916371969163307691 using 147 multiplications per
+        // array element
+        // 226854911280625642308916371969163307691 =
+        //    b1010101010101010101010101010101010101010101010101010101010101010
+        //     1010101010101010100011001010101010101010101010101010101010101011
+
+        // compute base^10
+        let mut t1 = *state;
+        t1.iter_mut().for_each(|t| *t = t.square());
+
+        // compute base^11
+        let mut t3 = t1;
+        t3.iter_mut().zip(state.iter()).for_each(|(t, &s)| *t *= s);
+
+        // compute base^1010
+        let t2 = exp_acc::<BaseElement, STATE_WIDTH, 2>(t1, t1);
+
+        // compute base^10101010
+        let t4 = exp_acc::<BaseElement, STATE_WIDTH, 4>(t2, t2);
+
+        // compute base^1010101010101010
+        let t8 = exp_acc::<BaseElement, STATE_WIDTH, 8>(t4, t4);
+
+        // compute base^10 repeated 16 times
+        let t16 = exp_acc::<BaseElement, STATE_WIDTH, 16>(t8, t8);
+
+        // compute base^10 repeated 20 times
+        let t20 = exp_acc::<BaseElement, STATE_WIDTH, 8>(t16, t4);
+
+        // compute base^10 repeated 32 times
+        let t32 = exp_acc::<BaseElement, STATE_WIDTH, 32>(t16, t16);
+
+        // compute base^10 repeated 41 times
+        let acc = exp_acc::<BaseElement, STATE_WIDTH, 16>(t32, t8);
+        let acc = exp_acc::<BaseElement, STATE_WIDTH, 2>(acc, t1);
+
+        // append 0011 to the exponent
+        let acc = exp_acc::<BaseElement, STATE_WIDTH, 4>(acc, t3);
+
+        // append 00 followed by 10 repeated 20 times to the exponent
+        let acc = exp_acc::<BaseElement, STATE_WIDTH, 42>(acc, t20);
+
+        // change the last bit of the exponent to 1
+        state.iter_mut().zip(acc).for_each(|(s, a)| *s *= a);
+    }
+}
+
+// MDS
+// ================================================================================================
+/// Rescue MDS matrix
+/// Computed using algorithm 4 from <https://eprint.iacr.org/2020/1143.pdf>
+const MDS: [[BaseElement; STATE_WIDTH]; STATE_WIDTH] = [
+    [
+        BaseElement::new(340282366920938463463374557953730612630),
+        BaseElement::new(21493836),
+        BaseElement::new(340282366920938463463374557953736934518),
+        BaseElement::new(914760),
+        BaseElement::new(340282366920938463463374557953744928504),
+        BaseElement::new(364),
+    ],
+    [
+        BaseElement::new(340282366920938463463374557948521959389),
+        BaseElement::new(7809407397),
+        BaseElement::new(340282366920938463463374557950844620457),
+        BaseElement::new(324945621),
+        BaseElement::new(340282366920938463463374557953733852285),
+        BaseElement::new(99463),
+    ],
+    [
+        BaseElement::new(340282366920938463463374556526559624596),
+        BaseElement::new(2132618407920),
+        BaseElement::new(340282366920938463463374557163162978137),
+        BaseElement::new(88084432800),
+        BaseElement::new(340282366920938463463374557950784345879),
+        BaseElement::new(25095280),
+    ],
+    [
+        BaseElement::new(340282366920938463463374197863906102577),
+        BaseElement::new(537966647357139),
+        BaseElement::new(340282366920938463463374358646073999137),
+        BaseElement::new(22165576349400),
+        BaseElement::new(340282366920938463463374557212857010097),
+        BaseElement::new(6174066262),
+    ],
+    [
+        BaseElement::new(340282366920938463463285966851139685903),
+        BaseElement::new(132344277849702072),
+        BaseElement::new(340282366920938463463325536573199985698),
+        BaseElement::new(5448481182864720),
+        BaseElement::new(340282366920938463463374376171390478291),
+        BaseElement::new(1506472167928),
+    ],
+    [
+        BaseElement::new(340282366920938463441758328918057706841),
+        BaseElement::new(32291274613403616174),
+        BaseElement::new(340282366920938463451414421516665416977),
+        BaseElement::new(1329039099788841441),
+        BaseElement::new(340282366920938463463330243139804660633),
+        BaseElement::new(366573514642546),
+    ],
+];
+
+/// Rescue Inverse MDS matrix
+const INV_MDS: [[BaseElement; STATE_WIDTH]; STATE_WIDTH] = [
+    [
+        BaseElement::new(133202720344903784697302507504318451498),
+        BaseElement::new(9109562341901685402869515497167051415),
+        BaseElement::new(187114562320006661061623258692072377978),
+        BaseElement::new(217977550980311337650875125151512141987),
+        BaseElement::new(274535269264332978809051716514493438195),
+        BaseElement::new(198907435511358942768401550501671423539),
+    ],
+    [
+        BaseElement::new(107211340690419935719675873160429442610),
+        BaseElement::new(93035459208639798096019355873148696692),
+        BaseElement::new(34612840942819361370119536876515785819),
+        BaseElement::new(28124271099756519590702162721340502811),
+        BaseElement::new(220883180661145883341796932300840696133),
+        BaseElement::new(196697641239095428808435254975214799010),
+    ],
+    [
+        BaseElement::new(48198755643822249649260269442324041679),
+        BaseElement::new(64419499747985404280993270855996080557),
+        BaseElement::new(280207800449835933431237404716657540948),
+        BaseElement::new(61755931245950637038951462642746253929),
+        BaseElement::new(206737575380416523686108693210925354496),
+        BaseElement::new(19245171373866178840198015038840651466),
+    ],
+    [
+        BaseElement::new(133290479635000282395391929030461304726),
+        BaseElement::new(256035933367497105928763702353648605000),
+        BaseElement::new(97077987470620839632334052346344687963),
+        BaseElement::new(144638736603246051821344039641662500876),
+        BaseElement::new(323753713558453221824969500168839490204),
+        BaseElement::new(66050250127997888787320450320278295843),
+    ],
+    [
+        BaseElement::new(107416947271017171483976049725783774207),
+        BaseElement::new(29799978553141132526384006297614595309),
+        BaseElement::new(112991183841517485419461727429810868869),
+        BaseElement::new(27096959906733835564333321118624482460),
+        BaseElement::new(197262955506413467422574209301409294301),
+        BaseElement::new(205996708763053834510019802034246907929),
+    ],
+    [
+        BaseElement::new(114827794598835201662537916675749328586),
+        BaseElement::new(22232541983454090535685600849896663137),
+        BaseElement::new(84718265936029339288536427868493390350),
+        BaseElement::new(176534200716138685131361645691447579493),
+        BaseElement::new(304590074876810806644622682832255680729),
+        BaseElement::new(317944222651547267127379399943392242317),
+    ],
+];
+
+// ROUND CONSTANTS
+// ================================================================================================
+
+/// Rescue round constants;
+/// computed using algorithm 5 from <https://eprint.iacr.org/2020/1143.pdf>
+///
+/// The constants are broken up into two arrays ARK1 and ARK2; ARK1 contains the constants for the
+/// first half of Rescue round, and ARK2 contains constants for the second half of Rescue round.
+const ARK1: [[BaseElement; STATE_WIDTH]; NUM_ROUNDS] = [
+    [
+        BaseElement::new(227618694779039058697910448522513791698),
+        BaseElement::new(196659986316371352722752785377494389359),
+        BaseElement::new(260860213514749798590452409809289797223),
+        BaseElement::new(141306463468204754351930684896040177838),
+        BaseElement::new(33922178645880839194126660867839402968),
+        BaseElement::new(34241651161817452903158894243886470014),
+    ],
+    [
+        BaseElement::new(249916113281091914581621661910900870304),
+        BaseElement::new(179618476531661703918528326422340381621),
+        BaseElement::new(162296537168595944476187931221903013839),
+        BaseElement::new(104061057184552281235952017433051670407),
+        BaseElement::new(299923464356381055601343295792407295728),
+        BaseElement::new(231391173527189457242760808107973251612),
+    ],
+    [
+        BaseElement::new(128826358544240941212386346710689105786),
+        BaseElement::new(217582339112609692663529183891839320971),
+        BaseElement::new(87388562096341452691831696892902550775),
+        BaseElement::new(103814332508828008586465554060468874868),
+        BaseElement::new(225923359623958488935254279610119925939),
+        BaseElement::new(304404410915325696597460110764440104239),
+    ],
+    [
+        BaseElement::new(260933928876342429202787174412338043865),
+        BaseElement::new(111937547969018396750140576045611359159),
+        BaseElement::new(231513525547282342806662825613556659686),
+        BaseElement::new(41387522037481418304383071315578054482),
+        BaseElement::new(70384292719081005725913189157969769704),
+        BaseElement::new(329556630036616443759545406971408770241),
+    ],
+    [
+        BaseElement::new(301375678500273999134466876256889433924),
+        BaseElement::new(102054753869665663647069935762005124923),
+        BaseElement::new(49774549091165733337233280004162429702),
+        BaseElement::new(327373437818502651134703933892482093719),
+        BaseElement::new(286367133714811421206039247077329401472),
+        BaseElement::new(156780927381188171743703609168450825021),
+    ],
+    [
+        BaseElement::new(304213653077009081532632755498134427287),
+        BaseElement::new(24189264092407285338907416353187415123),
+        BaseElement::new(265920669733591960916688077997715961401),
+        BaseElement::new(118940988768344067870410337350444998853),
+        BaseElement::new(280031751150197179739739826950543560864),
+        BaseElement::new(123237383339352344664226047893274009645),
+    ],
+    [
+        BaseElement::new(206807251956517713982429447762137227387),
+        BaseElement::new(191768223482572304446235803537571821928),
+        BaseElement::new(134456657507853248872740809207579762668),
+        BaseElement::new(263118833476185666732056921820391492112),
+        BaseElement::new(50345261777123126081646985956989043964),
+        BaseElement::new(193501886157804332457416321216678762320),
+    ],
+    [
+        BaseElement::new(88169634186552758537242363993985977455),
+        BaseElement::new(84049665447513970034315409859225141936),
+        BaseElement::new(254373199563435246271354654121515700140),
+        BaseElement::new(66186572482460596709832990092672313979),
+        BaseElement::new(207555645241227776377917325888476058800),
+        BaseElement::new(92213355999260009597707094727941223405),
+    ],
+    [
+        BaseElement::new(305284456478702405964575099932895699313),
+        BaseElement::new(225645450546818388491932375159983117055),
+        BaseElement::new(187868516406525934738498344417616892299),
+        BaseElement::new(269172070801524069570194159187451336976),
+        BaseElement::new(211999735252348718001305579218693891071),
+        BaseElement::new(7675251988603645827572348152989840635),
+    ],
+    [
+        BaseElement::new(288004627492043700972481988163457256281),
+        BaseElement::new(203277208315963161277624339787078352775),
+        BaseElement::new(174063232279132613089963686574195586696),
+        BaseElement::new(222382554350910702401781101746589309708),
+        BaseElement::new(287619776387026813278963698629550602405),
+        BaseElement::new(149292410014197279516658224218812975900),
+    ],
+    [
+        BaseElement::new(67198171767005951649615301006590555946),
+        BaseElement::new(280624590542612646760223957176147508017),
+        BaseElement::new(327929191718445986085545882666674913114),
+        BaseElement::new(247316633860359927259690964697623528052),
+        BaseElement::new(218716677495295484469714865132596901112),
+        BaseElement::new(261961300622486426040957027151827295238),
+    ],
+];
+
+const ARK2: [[BaseElement; STATE_WIDTH]; NUM_ROUNDS] = [
+    [
+        BaseElement::new(112190438022032279105005030683208049670),
+        BaseElement::new(52687399179696024076709761976954353278),
+        BaseElement::new(150044243541339753570634526612571606500),
+        BaseElement::new(254916533887940128228637624135785757505),
+        BaseElement::new(154758139682741051489745736889314710124),
+        BaseElement::new(302463074910239772663396457768054285929),
+    ],
+    [
+        BaseElement::new(126713429690955155930956808041712154667),
+        BaseElement::new(229650169468671931941459929689299000546),
+        BaseElement::new(288877934213418003534510133656155693618),
+        BaseElement::new(165877634883867680790749743788479515270),
+        BaseElement::new(294778360053082389208949343674359074515),
+        BaseElement::new(65625665917658962225180865057866594380),
+    ],
+    [
+        BaseElement::new(191588507285093849537546086009066579383),
+        BaseElement::new(112341596895234483881378034031698334133),
+        BaseElement::new(123150572896196312338745430731529332383),
+        BaseElement::new(59365080114962367274116495456243147959),
+        BaseElement::new(188434169040916662168730973460161009385),
+        BaseElement::new(217702455308119033515841194434724153485),
+    ],
+    [
+        BaseElement::new(81155733899993623711158234945493555335),
+        BaseElement::new(174226127532760487150049969090944545794),
+        BaseElement::new(29707687460966582601802348877061588821),
+        BaseElement::new(280546648853580378048842094233339534044),
+        BaseElement::new(319404427698255303563704688038396180437),
+        BaseElement::new(4822330431085641031512405186622961756),
+    ],
+    [
+        BaseElement::new(272267165409011454405102984291691617471),
+        BaseElement::new(154436186679227213659968357881605226272),
+        BaseElement::new(217545833058637509204494239903421857157),
+        BaseElement::new(147204219360371568253333690789942637816),
+        BaseElement::new(315264383528640192631638534454591838419),
+        BaseElement::new(4893573504394174007371763851494743565),
+    ],
+    [
+        BaseElement::new(214653030151709052349379895764539757760),
+        BaseElement::new(4170056072470362550390266782265809511),
+        BaseElement::new(313501767604794881200233054924392904311),
+        BaseElement::new(211372474755911410890218884367156097455),
+        BaseElement::new(86728340769449857394834573989264942775),
+        BaseElement::new(293626057588985360301490754643579006378),
+    ],
+    [
+        BaseElement::new(109576967997871255495921139507871288720),
+        BaseElement::new(174169039935924661485469031364751004361),
+        BaseElement::new(315571578643335047104577107112755605269),
+        BaseElement::new(336268243059596190890025099811623766142),
+        BaseElement::new(95328050058463456348731284969802537358),
+        BaseElement::new(333894244072973092150918192339829473171),
+    ],
+    [
+        BaseElement::new(126359398890521868408589460763901524075),
+        BaseElement::new(222535417594859843820401235708418903880),
+        BaseElement::new(204618585670528623918895471429308789874),
+        BaseElement::new(38885489290339221046504828569998219678),
+        BaseElement::new(193083660650016763907552787106071212155),
+        BaseElement::new(166109615004958339998848966972225255430),
+    ],
+    [
+        BaseElement::new(288237442401208891187374000033187702528),
+        BaseElement::new(286313002788492904402221118839572702376),
+        BaseElement::new(174096771382451694443690161685358228240),
+        BaseElement::new(285817286738267086249917254628221700202),
+        BaseElement::new(169585134651395848769196260552142572654),
+        BaseElement::new(319324417951700761151782642102218322500),
+    ],
+    [
+        BaseElement::new(209519343219550788801407091722786672343),
+        BaseElement::new(155004282119544731273188902076407719936),
+        BaseElement::new(320476591457045576012089453393330235346),
+        BaseElement::new(177773231176087515475360035259748311763),
+        BaseElement::new(287145123115793347568557249025341465796),
+        BaseElement::new(97814174966584118360497484968557640626),
+    ],
+    [
+        BaseElement::new(84795844612423190346765618825011109939),
+        BaseElement::new(122767938363477244397208693529281512419),
+        BaseElement::new(89372039629522992092463512208905918146),
+        BaseElement::new(80086589695099095607551487462536936643),
+        BaseElement::new(60862193175226347771996233364139575466),
+        BaseElement::new(149799995988255102003369212289073189760),
+    ],
+];
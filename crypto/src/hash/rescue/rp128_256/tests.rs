@@ -0,0 +1,181 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use rand_utils::{rand_array, rand_value};
+
+use super::{
+    BaseElement, ElementDigest, ElementHasher, FieldElement, Hasher, Rp128_256, ALPHA, INV_ALPHA,
+    INV_MDS, MDS, STATE_WIDTH,
+};
+
+#[test]
+fn mds_inv_test() {
+    for (i, mds_row) in MDS.iter().enumerate() {
+        for j in 0..STATE_WIDTH {
+            let result = mds_row
+                .iter()
+                .zip(INV_MDS.iter())
+                .fold(BaseElement::ZERO, |acc, (&m, inv_mds_row)| acc + m * inv_mds_row[j]);
+            if i == j {
+                assert_eq!(result, BaseElement::ONE);
+            } else {
+                assert_eq!(result, BaseElement::ZERO);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_alphas() {
+    let e: BaseElement = rand_value();
+    let e_exp = e.exp(ALPHA);
+    assert_eq!(e, e_exp.exp(INV_ALPHA));
+}
+
+#[test]
+fn test_sbox() {
+    let state: [BaseElement; STATE_WIDTH] = rand_array();
+
+    let mut expected = state;
+    expected.iter_mut().for_each(|v| *v = v.exp(ALPHA));
+
+    let mut actual = state;
+    Rp128_256::apply_sbox(&mut actual);
+
+    assert_eq!(expected, actual);
+}
+
+#[test]
+fn test_inv_sbox() {
+    let state: [BaseElement; STATE_WIDTH] = rand_array();
+
+    let mut expected = state;
+    expected.iter_mut().for_each(|v| *v = v.exp(INV_ALPHA));
+
+    let mut actual = state;
+    Rp128_256::apply_inv_sbox(&mut actual);
+
+    assert_eq!(expected, actual);
+}
+
+#[test]
+fn apply_permutation() {
+    let mut state: [BaseElement; STATE_WIDTH] = [
+        BaseElement::new(0),
+        BaseElement::new(1),
+        BaseElement::new(2),
+        BaseElement::new(3),
+        BaseElement::new(4),
+        BaseElement::new(5),
+    ];
+
+    Rp128_256::apply_permutation(&mut state);
+
+    // expected values are obtained by executing a port of the sage reference implementation code
+    let expected = vec![
+        BaseElement::new(46771070154415290902977165906428028899),
+        BaseElement::new(82358041222807699750401724986334429820),
+        BaseElement::new(331235926383655389914858194204981381412),
+        BaseElement::new(115579754731942467536123113107138729325),
+        BaseElement::new(84344847878459772670179217956834571038),
+        BaseElement::new(150752519819353466869456741557530059053),
+    ];
+
+    assert_eq!(expected, state);
+}
+
+#[test]
+fn hash_elements() {
+    // expected values are obtained by executing a port of the sage reference implementation code
+    let elements = [BaseElement::new(1), BaseElement::new(2), BaseElement::new(3)];
+    let expected = ElementDigest::new([
+        BaseElement::new(38338923671568163429875929992689497835),
+        BaseElement::new(209361843627857444932943814405083574275),
+    ]);
+    assert_eq!(expected, Rp128_256::hash_elements(&elements));
+
+    // same as above but with input spanning two permutations
+    let elements: [BaseElement; 8] = core::array::from_fn(|i| BaseElement::new(i as u128 + 1));
+    let expected = ElementDigest::new([
+        BaseElement::new(189567856668949886548887223208812455127),
+        BaseElement::new(336920609152508616393103687474328978730),
+    ]);
+    assert_eq!(expected, Rp128_256::hash_elements(&elements));
+}
+
+#[test]
+fn hash_elements_vs_merge() {
+    let elements: [BaseElement; 4] = rand_array();
+
+    let digests: [ElementDigest; 2] = [
+        ElementDigest::new(elements[..2].try_into().unwrap()),
+        ElementDigest::new(elements[2..].try_into().unwrap()),
+    ];
+
+    let m_result = Rp128_256::merge(&digests);
+    let h_result = Rp128_256::hash_elements(&elements);
+    assert_eq!(m_result, h_result);
+}
+
+#[test]
+fn merge_vs_merge_many() {
+    let elements: [BaseElement; 4] = rand_array();
+
+    let digests: [ElementDigest; 2] = [
+        ElementDigest::new(elements[..2].try_into().unwrap()),
+        ElementDigest::new(elements[2..].try_into().unwrap()),
+    ];
+
+    let m_result = Rp128_256::merge(&digests);
+    let h_result = Rp128_256::merge_many(&digests);
+    assert_eq!(m_result, h_result);
+}
+
+#[test]
+fn hash_elements_vs_merge_with_int() {
+    let seed = ElementDigest::new(rand_array());
+
+    let val: u64 = rand_value();
+    let m_result = Rp128_256::merge_with_int(seed, val);
+
+    let mut elements = seed.as_elements().to_vec();
+    elements.push(BaseElement::new(val as u128));
+    let h_result = Rp128_256::hash_elements(&elements);
+
+    assert_eq!(m_result, h_result);
+}
+
+#[test]
+fn hash_padding() {
+    // adding a zero bytes at the end of a byte string should result in a different hash
+    let r1 = Rp128_256::hash(&[1_u8, 2, 3]);
+    let r2 = Rp128_256::hash(&[1_u8, 2, 3, 0]);
+    assert_ne!(r1, r2);
+
+    // same as above but with bigger inputs
+    let r1 = Rp128_256::hash(&[1_u8, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14]);
+    let r2 = Rp128_256::hash(&[1_u8, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 0]);
+    assert_ne!(r1, r2);
+
+    // same as above but with input splitting over two elements
+    let r1 = Rp128_256::hash(&[1_u8, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15]);
+    let r2 = Rp128_256::hash(&[1_u8, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 0]);
+    assert_ne!(r1, r2);
+
+    // same as above but with multiple zeros
+    let r1 = Rp128_256::hash(&[1_u8, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 0, 0]);
+    let r2 = Rp128_256::hash(&[1_u8, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 0, 0, 0, 0]);
+    assert_ne!(r1, r2);
+}
+
+#[test]
+fn hash_elements_padding() {
+    let e1: [BaseElement; 2] = rand_array();
+    let e2 = [e1[0], e1[1], BaseElement::ZERO];
+
+    let r1 = Rp128_256::hash_elements(&e1);
+    let r2 = Rp128_256::hash_elements(&e2);
+    assert_ne!(r1, r2);
+}
@@ -33,6 +33,8 @@ impl ElementDigest {
 }
 
 impl Digest for ElementDigest {
+    const NUM_BYTES: usize = 31;
+
     fn as_bytes(&self) -> [u8; 32] {
         let v1 = self.0[0].as_int();
         let v2 = self.0[1].as_int();
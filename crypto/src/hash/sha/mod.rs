@@ -6,11 +6,14 @@
 use core::marker::PhantomData;
 
 use math::{FieldElement, StarkField};
-use sha3::Digest;
+use sha3::digest::{Digest, Output};
 use utils::ByteWriter;
 
 use super::{ByteDigest, ElementHasher, Hasher};
 
+#[cfg(test)]
+mod tests;
+
 // SHA3 WITH 256-BIT OUTPUT
 // ================================================================================================
 
@@ -55,30 +58,211 @@ impl<B: StarkField> ElementHasher for Sha3_256<B> {
         } else {
             // when elements' internal and canonical representations differ, we need to serialize
             // them before hashing
-            let mut hasher = ShaHasher::new();
+            let mut hasher = ShaHasher::<sha3::Sha3_256>::new();
             hasher.write_many(elements);
             ByteDigest(hasher.finalize())
         }
     }
 }
 
+// KECCAK WITH 256-BIT OUTPUT
+// ================================================================================================
+
+/// Implementation of the [Hasher](super::Hasher) trait for Keccak hash function with 256-bit
+/// output.
+///
+/// This is the original Keccak-256 function (i.e., the one computed by the `KECCAK256` opcode of
+/// the Ethereum Virtual Machine), which differs from [Sha3_256] in its padding rule.
+pub struct Keccak256<B: StarkField>(PhantomData<B>);
+
+impl<B: StarkField> Hasher for Keccak256<B> {
+    type Digest = ByteDigest<32>;
+
+    const COLLISION_RESISTANCE: u32 = 128;
+
+    fn hash(bytes: &[u8]) -> Self::Digest {
+        ByteDigest(sha3::Keccak256::digest(bytes).into())
+    }
+
+    fn merge(values: &[Self::Digest; 2]) -> Self::Digest {
+        ByteDigest(sha3::Keccak256::digest(ByteDigest::digests_as_bytes(values)).into())
+    }
+
+    fn merge_many(values: &[Self::Digest]) -> Self::Digest {
+        ByteDigest(sha3::Keccak256::digest(ByteDigest::digests_as_bytes(values)).into())
+    }
+
+    fn merge_with_int(seed: Self::Digest, value: u64) -> Self::Digest {
+        let mut data = [0; 40];
+        data[..32].copy_from_slice(&seed.0);
+        data[32..].copy_from_slice(&value.to_le_bytes());
+        ByteDigest(sha3::Keccak256::digest(data).into())
+    }
+}
+
+impl<B: StarkField> ElementHasher for Keccak256<B> {
+    type BaseField = B;
+
+    fn hash_elements<E: FieldElement<BaseField = Self::BaseField>>(elements: &[E]) -> Self::Digest {
+        if B::IS_CANONICAL {
+            // when element's internal and canonical representations are the same, we can hash
+            // element bytes directly
+            let bytes = E::elements_as_bytes(elements);
+            ByteDigest(sha3::Keccak256::digest(bytes).into())
+        } else {
+            // when elements' internal and canonical representations differ, we need to serialize
+            // them before hashing
+            let mut hasher = ShaHasher::<sha3::Keccak256>::new();
+            hasher.write_many(elements);
+            ByteDigest(hasher.finalize())
+        }
+    }
+}
+
+// SHA2 WITH 256-BIT OUTPUT
+// ================================================================================================
+
+/// Implementation of the [Hasher](super::Hasher) trait for SHA2 hash function with 256-bit
+/// output (i.e., SHA-256).
+pub struct Sha2_256<B: StarkField>(PhantomData<B>);
+
+impl<B: StarkField> Hasher for Sha2_256<B> {
+    type Digest = ByteDigest<32>;
+
+    const COLLISION_RESISTANCE: u32 = 128;
+
+    fn hash(bytes: &[u8]) -> Self::Digest {
+        ByteDigest(sha2::Sha256::digest(bytes).into())
+    }
+
+    fn merge(values: &[Self::Digest; 2]) -> Self::Digest {
+        ByteDigest(sha2::Sha256::digest(ByteDigest::digests_as_bytes(values)).into())
+    }
+
+    fn merge_many(values: &[Self::Digest]) -> Self::Digest {
+        ByteDigest(sha2::Sha256::digest(ByteDigest::digests_as_bytes(values)).into())
+    }
+
+    fn merge_with_int(seed: Self::Digest, value: u64) -> Self::Digest {
+        let mut data = [0; 40];
+        data[..32].copy_from_slice(&seed.0);
+        data[32..].copy_from_slice(&value.to_le_bytes());
+        ByteDigest(sha2::Sha256::digest(data).into())
+    }
+}
+
+impl<B: StarkField> ElementHasher for Sha2_256<B> {
+    type BaseField = B;
+
+    fn hash_elements<E: FieldElement<BaseField = Self::BaseField>>(elements: &[E]) -> Self::Digest {
+        if B::IS_CANONICAL {
+            // when element's internal and canonical representations are the same, we can hash
+            // element bytes directly
+            let bytes = E::elements_as_bytes(elements);
+            ByteDigest(sha2::Sha256::digest(bytes).into())
+        } else {
+            // when elements' internal and canonical representations differ, we need to serialize
+            // them before hashing
+            let mut hasher = ShaHasher::<sha2::Sha256>::new();
+            hasher.write_many(elements);
+            ByteDigest(hasher.finalize())
+        }
+    }
+}
+
+// SHA2 TRUNCATED OUTPUT
+// ================================================================================================
+
+/// Implementation of the [Hasher](super::Hasher) trait for SHA-256 hash function with the output
+/// truncated to 160 bits.
+pub type Sha2_160<B> = TruncatedSha2<B, 20>;
+
+/// Implementation of the [Hasher](super::Hasher) trait for SHA-256 hash function with the output
+/// truncated to 192 bits.
+pub type Sha2_192<B> = TruncatedSha2<B, 24>;
+
+/// Implementation of the [Hasher](super::Hasher) trait for SHA-256 hash function with the output
+/// truncated to 224 bits.
+///
+/// Note that this is not the same as SHA-224, which uses different initial hash values.
+pub type Sha2_224<B> = TruncatedSha2<B, 28>;
+
+/// Implementation of the [Hasher](super::Hasher) trait for SHA-256 hash function with the output
+/// truncated to `N` bytes.
+///
+/// Collision resistance of the hash function is set to `4 * N` bits (i.e., half of the output
+/// size). `N` must be between 16 and 32 (both inclusive).
+pub struct TruncatedSha2<B: StarkField, const N: usize>(PhantomData<B>);
+
+impl<B: StarkField, const N: usize> Hasher for TruncatedSha2<B, N> {
+    type Digest = ByteDigest<N>;
+
+    const COLLISION_RESISTANCE: u32 = {
+        assert!(N >= 16 && N <= 32, "digest size must be between 16 and 32 bytes");
+        N as u32 * 4
+    };
+
+    fn hash(bytes: &[u8]) -> Self::Digest {
+        ByteDigest(sha2::Sha256::digest(bytes)[..N].try_into().unwrap())
+    }
+
+    fn merge(values: &[Self::Digest; 2]) -> Self::Digest {
+        let result = sha2::Sha256::digest(ByteDigest::digests_as_bytes(values));
+        ByteDigest(result[..N].try_into().unwrap())
+    }
+
+    fn merge_many(values: &[Self::Digest]) -> Self::Digest {
+        let result = sha2::Sha256::digest(ByteDigest::digests_as_bytes(values));
+        ByteDigest(result[..N].try_into().unwrap())
+    }
+
+    fn merge_with_int(seed: Self::Digest, value: u64) -> Self::Digest {
+        let mut data = [0; 40];
+        data[..N].copy_from_slice(&seed.0);
+        data[N..N + 8].copy_from_slice(&value.to_le_bytes());
+        ByteDigest(sha2::Sha256::digest(&data[..N + 8])[..N].try_into().unwrap())
+    }
+}
+
+impl<B: StarkField, const N: usize> ElementHasher for TruncatedSha2<B, N> {
+    type BaseField = B;
+
+    fn hash_elements<E: FieldElement<BaseField = Self::BaseField>>(elements: &[E]) -> Self::Digest {
+        if B::IS_CANONICAL {
+            // when element's internal and canonical representations are the same, we can hash
+            // element bytes directly
+            let bytes = E::elements_as_bytes(elements);
+            ByteDigest(sha2::Sha256::digest(bytes)[..N].try_into().unwrap())
+        } else {
+            // when elements' internal and canonical representations differ, we need to serialize
+            // them before hashing
+            let mut hasher = ShaHasher::<sha2::Sha256>::new();
+            hasher.write_many(elements);
+            ByteDigest(hasher.finalize()[..N].try_into().unwrap())
+        }
+    }
+}
+
 // SHA HASHER
 // ================================================================================================
 
-/// Wrapper around SHA3 hasher to implement [ByteWriter] trait for it.
-struct ShaHasher(sha3::Sha3_256);
+/// Wrapper around SHA2 and SHA3 hashers to implement [ByteWriter] trait for them.
+struct ShaHasher<D: Digest>(D);
 
-impl ShaHasher {
+impl<D: Digest> ShaHasher<D> {
     pub fn new() -> Self {
-        Self(sha3::Sha3_256::new())
+        Self(D::new())
     }
 
-    pub fn finalize(self) -> [u8; 32] {
+    pub fn finalize(self) -> [u8; 32]
+    where
+        Output<D>: Into<[u8; 32]>,
+    {
         self.0.finalize().into()
     }
 }
 
-impl ByteWriter for ShaHasher {
+impl<D: Digest> ByteWriter for ShaHasher<D> {
     fn write_u8(&mut self, value: u8) {
         self.0.update([value]);
     }
@@ -0,0 +1,103 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use math::{
+    fields::{f128, f62},
+    FieldElement,
+};
+use rand_utils::rand_array;
+use utils::{Deserializable, Serializable};
+
+use super::{ElementHasher, Hasher, Keccak256, Sha2_160, Sha2_224, Sha2_256, Sha3_256};
+use crate::hash::{ByteDigest, Digest};
+
+#[test]
+fn sha2_hash() {
+    // expected value is the "abc" test vector from FIPS 180-2
+    let expected = [
+        0xba, 0x78, 0x16, 0xbf, 0x8f, 0x01, 0xcf, 0xea, 0x41, 0x41, 0x40, 0xde, 0x5d, 0xae, 0x22,
+        0x23, 0xb0, 0x03, 0x61, 0xa3, 0x96, 0x17, 0x7a, 0x9c, 0xb4, 0x10, 0xff, 0x61, 0xf2, 0x00,
+        0x15, 0xad,
+    ];
+    assert_eq!(expected, Sha2_256::<f62::BaseElement>::hash(b"abc").as_bytes());
+}
+
+#[test]
+fn sha2_merge_vs_merge_many() {
+    let digest_0 = ByteDigest::read_from_bytes(&[1_u8; 32]).unwrap();
+    let digest_1 = ByteDigest::read_from_bytes(&[2_u8; 32]).unwrap();
+
+    let r1 = Sha2_256::<f62::BaseElement>::merge(&[digest_0, digest_1]);
+    let r2 = Sha2_256::<f62::BaseElement>::merge_many(&[digest_0, digest_1]);
+
+    assert_eq!(r1, r2)
+}
+
+#[test]
+fn sha2_hash_elements() {
+    // for fields with canonical element representation, elements are hashed as raw bytes
+    let e: [f128::BaseElement; 4] = rand_array();
+    let r1 = Sha2_256::hash_elements(&e);
+    let r2 = Sha2_256::<f128::BaseElement>::hash(&e.to_bytes());
+    assert_eq!(r1, r2);
+
+    // for other fields, elements are serialized before they are hashed
+    let e: [f62::BaseElement; 4] = rand_array();
+    let r1 = Sha2_256::hash_elements(&e);
+    let r2 = Sha2_256::<f62::BaseElement>::hash(&e.to_bytes());
+    assert_eq!(r1, r2);
+
+    // SHA2 and SHA3 produce different digests
+    assert_ne!(r1, Sha3_256::hash_elements(&e));
+}
+
+#[test]
+fn sha2_truncated_output() {
+    let e: [f62::BaseElement; 4] = rand_array();
+    let full = Sha2_256::hash_elements(&e).as_bytes();
+
+    // truncated digests are prefixes of the full digest
+    assert_eq!(full[..20], Sha2_160::hash_elements(&e).as_bytes()[..20]);
+    assert_eq!(full[..28], Sha2_224::hash_elements(&e).as_bytes()[..28]);
+
+    assert_eq!(80, Sha2_160::<f62::BaseElement>::COLLISION_RESISTANCE);
+    assert_eq!(112, Sha2_224::<f62::BaseElement>::COLLISION_RESISTANCE);
+}
+
+#[test]
+fn sha2_hash_elements_padding() {
+    let e1: [f62::BaseElement; 2] = rand_array();
+    let e2 = [e1[0], e1[1], f62::BaseElement::ZERO];
+
+    // adding a zero element at the end of a list of elements should result in a different hash
+    let r1 = Sha2_256::hash_elements(&e1);
+    let r2 = Sha2_256::hash_elements(&e2);
+    assert_ne!(r1, r2);
+}
+
+#[test]
+fn keccak_hash() {
+    // expected value is Keccak-256 of an empty string (as computed by the EVM)
+    let expected = [
+        0xc5, 0xd2, 0x46, 0x01, 0x86, 0xf7, 0x23, 0x3c, 0x92, 0x7e, 0x7d, 0xb2, 0xdc, 0xc7, 0x03,
+        0xc0, 0xe5, 0x00, 0xb6, 0x53, 0xca, 0x82, 0x27, 0x3b, 0x7b, 0xfa, 0xd8, 0x04, 0x5d, 0x85,
+        0xa4, 0x70,
+    ];
+    assert_eq!(expected, Keccak256::<f62::BaseElement>::hash(&[]).as_bytes());
+
+    // Keccak-256 and SHA3-256 differ only in padding
+    assert_ne!(
+        Keccak256::<f62::BaseElement>::hash(b"abc"),
+        Sha3_256::<f62::BaseElement>::hash(b"abc")
+    );
+}
+
+#[test]
+fn keccak_hash_elements() {
+    let e: [f62::BaseElement; 4] = rand_array();
+    let r1 = Keccak256::hash_elements(&e);
+    let r2 = Keccak256::<f62::BaseElement>::hash(&e.to_bytes());
+    assert_eq!(r1, r2);
+}
@@ -15,6 +15,9 @@
 //! * **PRNG** - which is used to generate pseudo-random elements in a finite field. The
 //!   [RandomCoin] implementation uses a cryptographic hash function to generate pseudo-random
 //!   elements form a seed.
+//! * **Sum-check protocol** - non-interactive versions of the sum-check protocol for multilinear
+//!   polynomials defined in `winter-math`, which draw round challenges from a [RandomCoin]. See
+//!   [prove_sumcheck()] and [verify_sumcheck()].
 
 #![no_std]
 
@@ -26,16 +29,26 @@ pub use hash::{Digest, ElementHasher, Hasher};
 pub mod hashers {
     //! Contains implementations of currently supported hash functions.
 
-    pub use super::hash::{Blake3_192, Blake3_256, Rp62_248, Rp64_256, RpJive64_256, Sha3_256};
+    pub use super::hash::{
+        Blake3_160, Blake3_192, Blake3_224, Blake3_256, Keccak256, Poseidon2_64_256, Rp128_256,
+        Rp62_248, Rp64_256, RpJive64_256, Sha2_160, Sha2_192, Sha2_224, Sha2_256, Sha3_256,
+        TruncatedBlake3, TruncatedSha2,
+    };
 }
 
 mod merkle;
 #[cfg(feature = "concurrent")]
 pub use merkle::concurrent;
-pub use merkle::{build_merkle_nodes, BatchMerkleProof, MerkleTree};
+pub use merkle::{
+    build_merkle_nodes, ArityMerkleProof, ArityMerkleTree, BatchMerkleProof, MerkleCapOptions,
+    MerkleCapProof, MerkleCapTree, MerkleTree, SaltedBatchMerkleProof, SaltedMerkleTree,
+    SaltedMerkleTreeOptions, SaltedMerkleTreeProof,
+};
 
 mod random;
-pub use random::{DefaultRandomCoin, RandomCoin};
+pub use random::{
+    prove_sumcheck, verify_sumcheck, DefaultRandomCoin, KeccakRandomCoin, RandomCoin,
+};
 
 mod errors;
 pub use errors::{MerkleTreeError, RandomCoinError};
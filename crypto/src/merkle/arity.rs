@@ -0,0 +1,344 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use alloc::{collections::BTreeMap, vec::Vec};
+
+#[cfg(feature = "concurrent")]
+use utils::iterators::*;
+use utils::{
+    iter_mut, uninit_vector, ByteReader, ByteWriter, Deserializable, DeserializationError,
+    Serializable,
+};
+
+use super::map_indexes;
+use crate::{Hasher, MerkleTreeError, VectorCommitment};
+
+// ARITY MERKLE TREE
+// ================================================================================================
+
+/// A fully-balanced Merkle tree in which every internal node has `N` children.
+///
+/// Internal nodes are computed by hashing `N` child nodes at a time using [Hasher::merge_many()].
+/// For algebraic hash functions whose state can absorb more than two digests per permutation,
+/// trees of higher arity have fewer levels, and thus, their paths are cheaper to verify (e.g.,
+/// inside a recursive verifier).
+///
+/// `N` must be a power of two greater than one, and the number of leaves must be a power of two
+/// greater than one. If the number of leaves is not a power of `N`, the root has fewer than `N`
+/// children (but still a power of two), while all other internal nodes have exactly `N` children.
+///
+/// # Examples
+/// ```
+/// # use winter_crypto::{ArityMerkleTree, Hasher, hashers::Rp64_256};
+/// let leaves = (0..32u8).map(|i| Rp64_256::hash(&[i])).collect::<Vec<_>>();
+/// let tree = ArityMerkleTree::<Rp64_256, 4>::new(leaves.clone()).unwrap();
+///
+/// // levels of 4-to-1 hashes are followed by a 2-to-1 hash at the root
+/// assert_eq!(5, tree.depth());
+/// assert_eq!(3, tree.num_levels());
+///
+/// let proof = tree.prove_batch(&[3, 17]).unwrap();
+/// let opened = [leaves[3], leaves[17]];
+/// assert!(
+///     ArityMerkleTree::<Rp64_256, 4>::verify_batch(tree.root(), &[3, 17], &opened, &proof)
+///         .is_ok()
+/// );
+/// ```
+#[derive(Debug)]
+pub struct ArityMerkleTree<H: Hasher, const N: usize> {
+    /// Tree nodes level by level, starting with the leaves and ending with the root.
+    levels: Vec<Vec<H::Digest>>,
+}
+
+/// Opening proof for a set of leaves of an [ArityMerkleTree].
+///
+/// The proof contains the sibling nodes needed to compute the root of the tree from the opened
+/// leaves. Siblings are listed level by level starting from the leaf level, and within each level
+/// in the ascending order of their positions; siblings which can be computed from the opened
+/// leaves are omitted.
+#[derive(Debug, PartialEq, Eq)]
+pub struct ArityMerkleProof<H: Hasher> {
+    /// Sibling nodes between the opened leaves and the root.
+    pub nodes: Vec<H::Digest>,
+    /// Depth of the leaves in a binary tree with the same number of leaves, i.e., the base 2
+    /// logarithm of the number of leaves.
+    pub depth: u8,
+}
+
+impl<H: Hasher, const N: usize> ArityMerkleTree<H, N> {
+    // CONSTRUCTORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new Merkle tree of arity `N` built from the provided leaves.
+    ///
+    /// When `concurrent` feature is enabled, each level of the tree is built using multiple
+    /// threads.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// * Fewer than two leaves were provided.
+    /// * Number of leaves is not a power of two.
+    pub fn new(leaves: Vec<H::Digest>) -> Result<Self, MerkleTreeError> {
+        const { assert!(N >= 2 && N.is_power_of_two(), "arity must be a power of two") };
+
+        if leaves.len() < 2 {
+            return Err(MerkleTreeError::TooFewLeaves(2, leaves.len()));
+        }
+        if !leaves.len().is_power_of_two() {
+            return Err(MerkleTreeError::NumberOfLeavesNotPowerOfTwo(leaves.len()));
+        }
+
+        let depth = leaves.len().ilog2() as usize;
+        let mut levels = vec![leaves];
+        for arity in level_arities::<N>(depth) {
+            let children = levels.last().expect("no tree levels");
+            let mut parents = unsafe { uninit_vector::<H::Digest>(children.len() / arity) };
+            iter_mut!(parents, 1024).enumerate().for_each(|(i, parent)| {
+                *parent = H::merge_many(&children[i * arity..(i + 1) * arity]);
+            });
+            levels.push(parents);
+        }
+
+        Ok(Self { levels })
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the root of the tree.
+    pub fn root(&self) -> &H::Digest {
+        &self.levels[self.levels.len() - 1][0]
+    }
+
+    /// Returns the base 2 logarithm of the number of leaves in the tree.
+    pub fn depth(&self) -> usize {
+        self.leaves().len().ilog2() as usize
+    }
+
+    /// Returns the number of levels of internal nodes in the tree (including the root).
+    pub fn num_levels(&self) -> usize {
+        self.levels.len() - 1
+    }
+
+    /// Returns leaf nodes of the tree.
+    pub fn leaves(&self) -> &[H::Digest] {
+        &self.levels[0]
+    }
+
+    // PROVING METHODS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a proof for the leaves at the specified `indexes`.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// * No indexes were provided (i.e., `indexes` is an empty slice).
+    /// * Any of the provided indexes are greater than or equal to the number of leaves in the tree.
+    /// * List of indexes contains duplicates.
+    pub fn prove_batch(&self, indexes: &[usize]) -> Result<ArityMerkleProof<H>, MerkleTreeError> {
+        if indexes.is_empty() {
+            return Err(MerkleTreeError::TooFewLeafIndexes);
+        }
+        let depth = self.depth();
+        map_indexes(indexes, depth)?;
+
+        let mut positions = indexes.to_vec();
+        positions.sort_unstable();
+
+        let mut nodes = Vec::new();
+        for (level, arity) in self.levels.iter().zip(level_arities::<N>(depth)) {
+            let mut parents = Vec::new();
+            let mut i = 0;
+            while i < positions.len() {
+                let parent = positions[i] / arity;
+                let start = parent * arity;
+                for (position, &node) in level[start..start + arity].iter().enumerate() {
+                    if i < positions.len() && positions[i] == start + position {
+                        i += 1;
+                    } else {
+                        nodes.push(node);
+                    }
+                }
+                parents.push(parent);
+            }
+            positions = parents;
+        }
+
+        Ok(ArityMerkleProof { nodes, depth: depth as u8 })
+    }
+
+    // VERIFICATION METHODS
+    // --------------------------------------------------------------------------------------------
+
+    /// Checks whether the `proof` resolves to the specified `root` for the provided `leaves` at
+    /// the specified `indexes`.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// * No indexes were provided (i.e., `indexes` is an empty slice).
+    /// * The number of leaves is different from the number of indexes.
+    /// * Any of the specified `indexes` is greater than or equal to the number of leaves in the
+    ///   tree from which the proof was generated.
+    /// * List of indexes contains duplicates.
+    /// * The proof does not resolve to the specified `root`.
+    pub fn verify_batch(
+        root: &H::Digest,
+        indexes: &[usize],
+        leaves: &[H::Digest],
+        proof: &ArityMerkleProof<H>,
+    ) -> Result<(), MerkleTreeError> {
+        if indexes.is_empty() {
+            return Err(MerkleTreeError::TooFewLeafIndexes);
+        }
+        if indexes.len() != leaves.len() {
+            return Err(MerkleTreeError::InvalidProof);
+        }
+        let depth = proof.depth as usize;
+        if depth == 0 || depth >= usize::BITS as usize {
+            return Err(MerkleTreeError::InvalidProof);
+        }
+        map_indexes(indexes, depth)?;
+
+        let mut level: BTreeMap<usize, H::Digest> =
+            indexes.iter().copied().zip(leaves.iter().copied()).collect();
+        let mut siblings = proof.nodes.iter();
+        let mut children = Vec::with_capacity(N);
+        for arity in level_arities::<N>(depth) {
+            let mut parents = BTreeMap::new();
+            let mut known = level.iter().peekable();
+            while let Some(&(&position, _)) = known.peek() {
+                let parent = position / arity;
+                children.clear();
+                for position in parent * arity..(parent + 1) * arity {
+                    match known.next_if(|&(&p, _)| p == position) {
+                        Some((_, &node)) => children.push(node),
+                        None => {
+                            children.push(*siblings.next().ok_or(MerkleTreeError::InvalidProof)?)
+                        },
+                    }
+                }
+                parents.insert(parent, H::merge_many(&children));
+            }
+            level = parents;
+        }
+
+        if siblings.next().is_some() || level.get(&0) != Some(root) {
+            return Err(MerkleTreeError::InvalidProof);
+        }
+        Ok(())
+    }
+}
+
+// VECTOR COMMITMENT IMPLEMENTATION
+// ================================================================================================
+
+impl<H: Hasher, const N: usize> VectorCommitment<H> for ArityMerkleTree<H, N> {
+    type Options = ();
+
+    type Commitment = H::Digest;
+
+    type Proof = ArityMerkleProof<H>;
+
+    type MultiProof = ArityMerkleProof<H>;
+
+    type Error = MerkleTreeError;
+
+    fn with_options(items: Vec<H::Digest>, _options: Self::Options) -> Result<Self, Self::Error> {
+        ArityMerkleTree::new(items)
+    }
+
+    fn commitment(&self) -> H::Digest {
+        *self.root()
+    }
+
+    fn commitment_digest(commitment: &H::Digest) -> H::Digest {
+        *commitment
+    }
+
+    fn domain_len(&self) -> usize {
+        self.leaves().len()
+    }
+
+    fn get_proof_domain_len(proof: &Self::Proof) -> usize {
+        1 << proof.depth
+    }
+
+    fn get_multiproof_domain_len(proof: &Self::MultiProof) -> usize {
+        1 << proof.depth
+    }
+
+    fn open(&self, index: usize) -> Result<(H::Digest, Self::Proof), Self::Error> {
+        let proof = self.prove_batch(&[index])?;
+        Ok((self.leaves()[index], proof))
+    }
+
+    fn open_many(
+        &self,
+        indexes: &[usize],
+    ) -> Result<(Vec<H::Digest>, Self::MultiProof), Self::Error> {
+        let proof = self.prove_batch(indexes)?;
+        let leaves = indexes.iter().map(|&i| self.leaves()[i]).collect();
+        Ok((leaves, proof))
+    }
+
+    fn verify(
+        commitment: H::Digest,
+        index: usize,
+        item: H::Digest,
+        proof: &Self::Proof,
+    ) -> Result<(), Self::Error> {
+        ArityMerkleTree::<H, N>::verify_batch(&commitment, &[index], &[item], proof)
+    }
+
+    fn verify_many(
+        commitment: H::Digest,
+        indexes: &[usize],
+        items: &[H::Digest],
+        proof: &Self::MultiProof,
+    ) -> Result<(), Self::Error> {
+        ArityMerkleTree::<H, N>::verify_batch(&commitment, indexes, items, proof)
+    }
+}
+
+impl<H: Hasher> Clone for ArityMerkleProof<H> {
+    fn clone(&self) -> Self {
+        Self {
+            nodes: self.nodes.clone(),
+            depth: self.depth,
+        }
+    }
+}
+
+// SERIALIZATION / DESERIALIZATION
+// ================================================================================================
+
+impl<H: Hasher> Serializable for ArityMerkleProof<H> {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_u8(self.depth);
+        self.nodes.write_into(target);
+    }
+}
+
+impl<H: Hasher> Deserializable for ArityMerkleProof<H> {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let depth = source.read_u8()?;
+        let nodes = Vec::<_>::read_from(source)?;
+        Ok(ArityMerkleProof { nodes, depth })
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the number of children of the nodes at each level of internal nodes of an `N`-ary tree
+/// with 2^`depth` leaves, starting from the level right above the leaves.
+fn level_arities<const N: usize>(depth: usize) -> impl Iterator<Item = usize> {
+    let log_arity = N.ilog2() as usize;
+    let num_full_levels = depth / log_arity;
+    let root_log_arity = depth % log_arity;
+
+    let root_arity = (root_log_arity > 0).then_some(1 << root_log_arity);
+    core::iter::repeat_n(N, num_full_levels).chain(root_arity)
+}
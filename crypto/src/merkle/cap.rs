@@ -0,0 +1,373 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use alloc::{collections::BTreeMap, vec::Vec};
+
+use utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable};
+
+use super::{map_indexes, MerkleTree};
+use crate::{Hasher, MerkleTreeError, VectorCommitment};
+
+// MERKLE CAP TREE
+// ================================================================================================
+
+/// A fully-balanced Merkle tree which commits to its cap rather than to its root.
+///
+/// The cap of height `k` consists of the 2^k tree nodes located `k` levels below the root; a cap
+/// of height 0 consists of the root only. The commitment to the tree is the cap itself, and
+/// opening proofs contain Merkle paths which stop at the cap rather than at the root. Thus, each
+/// path is `k` nodes shorter than a path in a regular [MerkleTree], while the cap is sent only
+/// once as part of the commitment. When the commitment is absorbed into a public coin, the hash
+/// of all cap nodes (computed via [Hasher::merge_many()]) is used.
+///
+/// Shorter paths pay off when paths are verified independently of each other (e.g., inside a
+/// recursive verifier). For batch proofs, however, the nodes close to the root are shared by many
+/// paths and are already included only once; thus, the cap usually costs more than the siblings
+/// it saves, unless the number of openings is large compared to the size of the cap.
+///
+/// The cap height is specified via [MerkleCapOptions]; if the height exceeds the depth of the
+/// tree, the cap consists of the leaves of the tree.
+///
+/// # Examples
+/// ```
+/// # use winter_crypto::{MerkleCapTree, MerkleCapOptions, Hasher, hashers::Blake3_256};
+/// # use math::fields::f128::BaseElement;
+/// type Blake3 = Blake3_256<BaseElement>;
+///
+/// let leaves = (0..8u8).map(|i| Blake3::hash(&[i])).collect::<Vec<_>>();
+/// let options = MerkleCapOptions::new(2);
+/// let tree = MerkleCapTree::<Blake3>::with_options(leaves.clone(), options).unwrap();
+/// assert_eq!(4, tree.cap().len());
+///
+/// // the proof contains one sibling for each of the opened leaves
+/// let proof = tree.prove_batch(&[3, 5]).unwrap();
+/// assert_eq!(2, proof.nodes.len());
+///
+/// let opened = [leaves[3], leaves[5]];
+/// assert!(MerkleCapTree::<Blake3>::verify_batch(tree.cap(), &[3, 5], &opened, &proof).is_ok());
+/// assert!(MerkleCapTree::<Blake3>::verify_batch(tree.cap(), &[3, 4], &opened, &proof).is_err());
+/// ```
+#[derive(Debug)]
+pub struct MerkleCapTree<H: Hasher> {
+    tree: MerkleTree<H>,
+    cap_height: usize,
+}
+
+/// Options of a [MerkleCapTree] specifying the height of the cap.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MerkleCapOptions {
+    cap_height: usize,
+}
+
+impl MerkleCapOptions {
+    /// Height of the cap used by default; the corresponding cap consists of 16 nodes.
+    pub const DEFAULT_CAP_HEIGHT: usize = 4;
+
+    /// Returns options for a cap consisting of 2^`cap_height` nodes.
+    pub fn new(cap_height: usize) -> Self {
+        Self { cap_height }
+    }
+
+    /// Returns the height of the cap.
+    pub fn cap_height(&self) -> usize {
+        self.cap_height
+    }
+}
+
+impl Default for MerkleCapOptions {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_CAP_HEIGHT)
+    }
+}
+
+/// Opening proof for a set of leaves of a [MerkleCapTree].
+///
+/// The proof contains the sibling nodes needed to compute the cap nodes from the opened leaves.
+/// Siblings are listed level by level starting from the leaf level, and within each level in the
+/// ascending order of their positions; siblings which can be computed from the opened leaves are
+/// omitted.
+#[derive(Debug, PartialEq, Eq)]
+pub struct MerkleCapProof<H: Hasher> {
+    /// Sibling nodes between the opened leaves and the cap.
+    pub nodes: Vec<H::Digest>,
+    /// Depth of the leaves.
+    pub depth: u8,
+}
+
+impl<H: Hasher> MerkleCapTree<H> {
+    // CONSTRUCTORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new Merkle cap tree built from the provided leaves with the cap height specified
+    /// by `options`.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// * Fewer than two leaves were provided.
+    /// * Number of leaves is not a power of two.
+    pub fn with_options(
+        leaves: Vec<H::Digest>,
+        options: MerkleCapOptions,
+    ) -> Result<Self, MerkleTreeError> {
+        let tree = MerkleTree::new(leaves)?;
+        let cap_height = options.cap_height.min(tree.depth());
+        Ok(Self { tree, cap_height })
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the nodes of the cap of the tree; the cap is the commitment to the tree.
+    pub fn cap(&self) -> &[H::Digest] {
+        let start = 1 << self.cap_height;
+        if self.cap_height == self.tree.depth() {
+            self.tree.leaves()
+        } else {
+            &self.tree.nodes[start..start * 2]
+        }
+    }
+
+    /// Returns the height of the cap, i.e., the base 2 logarithm of the number of cap nodes.
+    pub fn cap_height(&self) -> usize {
+        self.cap_height
+    }
+
+    /// Returns depth of the tree.
+    pub fn depth(&self) -> usize {
+        self.tree.depth()
+    }
+
+    /// Returns leaf nodes of the tree.
+    pub fn leaves(&self) -> &[H::Digest] {
+        self.tree.leaves()
+    }
+
+    // PROVING METHODS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a proof for the leaves at the specified `indexes`.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// * No indexes were provided (i.e., `indexes` is an empty slice).
+    /// * Any of the provided indexes are greater than or equal to the number of leaves in the tree.
+    /// * List of indexes contains duplicates.
+    pub fn prove_batch(&self, indexes: &[usize]) -> Result<MerkleCapProof<H>, MerkleTreeError> {
+        if indexes.is_empty() {
+            return Err(MerkleTreeError::TooFewLeafIndexes);
+        }
+        let depth = self.depth();
+        map_indexes(indexes, depth)?;
+
+        // walk up from the leaves to the cap, recording siblings which cannot be computed
+        let num_leaves = self.tree.leaves.len();
+        let mut level: Vec<usize> = indexes.iter().map(|&i| i + num_leaves).collect();
+        level.sort_unstable();
+
+        let mut nodes = Vec::new();
+        for _ in self.cap_height..depth {
+            let mut i = 0;
+            while i < level.len() {
+                let index = level[i];
+                if i + 1 < level.len() && level[i + 1] == index ^ 1 {
+                    i += 1;
+                } else {
+                    nodes.push(self.get_node(index ^ 1));
+                }
+                i += 1;
+            }
+            level = level.into_iter().map(|index| index >> 1).collect();
+            level.dedup();
+        }
+
+        Ok(MerkleCapProof { nodes, depth: depth as u8 })
+    }
+
+    // VERIFICATION METHODS
+    // --------------------------------------------------------------------------------------------
+
+    /// Checks whether the `proof` resolves to the specified `cap` for the provided `leaves` at the
+    /// specified `indexes`.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// * No indexes were provided (i.e., `indexes` is an empty slice).
+    /// * The number of leaves is different from the number of indexes.
+    /// * Any of the specified `indexes` is greater than or equal to the number of leaves in the
+    ///   tree from which the proof was generated.
+    /// * List of indexes contains duplicates.
+    /// * The number of cap nodes is not a power of two or exceeds the number of leaves in the
+    ///   tree.
+    /// * The proof does not resolve to the cap.
+    pub fn verify_batch(
+        cap: &[H::Digest],
+        indexes: &[usize],
+        leaves: &[H::Digest],
+        proof: &MerkleCapProof<H>,
+    ) -> Result<(), MerkleTreeError> {
+        if indexes.is_empty() {
+            return Err(MerkleTreeError::TooFewLeafIndexes);
+        }
+        if indexes.len() != leaves.len() {
+            return Err(MerkleTreeError::InvalidProof);
+        }
+
+        let depth = proof.depth as usize;
+        if depth >= usize::BITS as usize {
+            return Err(MerkleTreeError::InvalidProof);
+        }
+        if !cap.len().is_power_of_two() || cap.len() > 1 << depth {
+            return Err(MerkleTreeError::InvalidProof);
+        }
+        map_indexes(indexes, depth)?;
+
+        // compute nodes level by level until the cap is reached
+        let mut level: BTreeMap<usize, H::Digest> =
+            indexes.iter().map(|&i| i + (1 << depth)).zip(leaves.iter().copied()).collect();
+        let mut siblings = proof.nodes.iter();
+        let cap_height = cap.len().ilog2() as usize;
+        for _ in cap_height..depth {
+            let mut next_level = BTreeMap::new();
+            for (&index, &node) in level.iter() {
+                if index & 1 == 1 && level.contains_key(&(index ^ 1)) {
+                    continue;
+                }
+                let sibling = match level.get(&(index ^ 1)) {
+                    Some(&sibling) => sibling,
+                    None => *siblings.next().ok_or(MerkleTreeError::InvalidProof)?,
+                };
+                let parent = if index & 1 == 0 {
+                    H::merge(&[node, sibling])
+                } else {
+                    H::merge(&[sibling, node])
+                };
+                next_level.insert(index >> 1, parent);
+            }
+            level = next_level;
+        }
+
+        if siblings.next().is_some() {
+            return Err(MerkleTreeError::InvalidProof);
+        }
+        for (index, node) in level {
+            if cap[index - cap.len()] != node {
+                return Err(MerkleTreeError::InvalidProof);
+            }
+        }
+        Ok(())
+    }
+
+    // HELPER METHODS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the node at the specified position, where internal nodes are at positions
+    /// [1, n) and leaves are at positions [n, 2n).
+    fn get_node(&self, index: usize) -> H::Digest {
+        let num_leaves = self.tree.leaves.len();
+        if index < num_leaves {
+            self.tree.nodes[index]
+        } else {
+            self.tree.leaves[index - num_leaves]
+        }
+    }
+}
+
+// VECTOR COMMITMENT IMPLEMENTATION
+// ================================================================================================
+
+impl<H: Hasher> VectorCommitment<H> for MerkleCapTree<H> {
+    type Options = MerkleCapOptions;
+
+    type Commitment = Vec<H::Digest>;
+
+    type Proof = MerkleCapProof<H>;
+
+    type MultiProof = MerkleCapProof<H>;
+
+    type Error = MerkleTreeError;
+
+    fn with_options(items: Vec<H::Digest>, options: Self::Options) -> Result<Self, Self::Error> {
+        MerkleCapTree::with_options(items, options)
+    }
+
+    fn commitment(&self) -> Vec<H::Digest> {
+        self.cap().to_vec()
+    }
+
+    fn commitment_digest(commitment: &Vec<H::Digest>) -> H::Digest {
+        H::merge_many(commitment)
+    }
+
+    fn domain_len(&self) -> usize {
+        1 << self.depth()
+    }
+
+    fn get_proof_domain_len(proof: &Self::Proof) -> usize {
+        1 << proof.depth
+    }
+
+    fn get_multiproof_domain_len(proof: &Self::MultiProof) -> usize {
+        1 << proof.depth
+    }
+
+    fn open(&self, index: usize) -> Result<(H::Digest, Self::Proof), Self::Error> {
+        let proof = self.prove_batch(&[index])?;
+        Ok((self.leaves()[index], proof))
+    }
+
+    fn open_many(
+        &self,
+        indexes: &[usize],
+    ) -> Result<(Vec<H::Digest>, Self::MultiProof), Self::Error> {
+        let proof = self.prove_batch(indexes)?;
+        let leaves = indexes.iter().map(|&i| self.leaves()[i]).collect();
+        Ok((leaves, proof))
+    }
+
+    fn verify(
+        commitment: Vec<H::Digest>,
+        index: usize,
+        item: H::Digest,
+        proof: &Self::Proof,
+    ) -> Result<(), Self::Error> {
+        MerkleCapTree::<H>::verify_batch(&commitment, &[index], &[item], proof)
+    }
+
+    fn verify_many(
+        commitment: Vec<H::Digest>,
+        indexes: &[usize],
+        items: &[H::Digest],
+        proof: &Self::MultiProof,
+    ) -> Result<(), Self::Error> {
+        MerkleCapTree::<H>::verify_batch(&commitment, indexes, items, proof)
+    }
+}
+
+impl<H: Hasher> Clone for MerkleCapProof<H> {
+    fn clone(&self) -> Self {
+        Self {
+            nodes: self.nodes.clone(),
+            depth: self.depth,
+        }
+    }
+}
+
+// SERIALIZATION / DESERIALIZATION
+// ================================================================================================
+
+impl<H: Hasher> Serializable for MerkleCapProof<H> {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_u8(self.depth);
+        self.nodes.write_into(target);
+    }
+}
+
+impl<H: Hasher> Deserializable for MerkleCapProof<H> {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let depth = source.read_u8()?;
+        let nodes = Vec::<_>::read_from(source)?;
+        Ok(MerkleCapProof { nodes, depth })
+    }
+}
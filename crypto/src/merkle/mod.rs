@@ -12,6 +12,17 @@ use core::slice;
 mod proofs;
 pub use proofs::BatchMerkleProof;
 
+mod arity;
+pub use arity::{ArityMerkleProof, ArityMerkleTree};
+
+mod cap;
+pub use cap::{MerkleCapOptions, MerkleCapProof, MerkleCapTree};
+
+mod salted;
+pub use salted::{
+    SaltedBatchMerkleProof, SaltedMerkleTree, SaltedMerkleTreeOptions, SaltedMerkleTreeProof,
+};
+
 use crate::{Hasher, MerkleTreeError, VectorCommitment};
 
 #[cfg(feature = "concurrent")]
@@ -401,6 +412,8 @@ fn normalize_indexes(indexes: &[usize]) -> Vec<usize> {
 impl<H: Hasher> VectorCommitment<H> for MerkleTree<H> {
     type Options = ();
 
+    type Commitment = H::Digest;
+
     type Proof = Vec<H::Digest>;
 
     type MultiProof = BatchMerkleProof<H>;
@@ -415,6 +428,10 @@ impl<H: Hasher> VectorCommitment<H> for MerkleTree<H> {
         *self.root()
     }
 
+    fn commitment_digest(commitment: &H::Digest) -> H::Digest {
+        *commitment
+    }
+
     fn domain_len(&self) -> usize {
         1 << self.depth()
     }
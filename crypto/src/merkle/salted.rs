@@ -0,0 +1,312 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use alloc::vec::Vec;
+
+#[cfg(feature = "concurrent")]
+use utils::iterators::*;
+use utils::{iter_mut, uninit_vector};
+
+use super::{BatchMerkleProof, MerkleTree};
+use crate::{Hasher, MerkleTreeError, VectorCommitment};
+
+// SALTED MERKLE TREE
+// ================================================================================================
+
+/// A fully-balanced Merkle tree in which every leaf is salted with a pseudo-random salt.
+///
+/// Each leaf of the underlying [MerkleTree] is computed as `H::merge(value, salt)`, where the salt
+/// of the leaf at position `i` is derived from a secret 32-byte seed as
+/// `H::merge_with_int(H::hash(seed), i)`. Salts of opened leaves are included in opening proofs,
+/// while the remaining salts (and the seed itself) are never revealed. Thus, authentication paths
+/// leak no information about the values at unopened positions, even if these values come from a
+/// small domain.
+///
+/// The seed is specified via [SaltedMerkleTreeOptions]. When the tree is built with the default
+/// options (e.g., via [VectorCommitment::new()]), a fresh seed is drawn from the operating
+/// system's source of randomness; this requires the `std` feature.
+///
+/// # Examples
+/// ```
+/// # use winter_crypto::{SaltedMerkleTree, SaltedMerkleTreeOptions, Hasher, hashers::Blake3_256};
+/// # use math::fields::f128::BaseElement;
+/// type Blake3 = Blake3_256<BaseElement>;
+///
+/// let values = [
+///     Blake3::hash(&[1u8]),
+///     Blake3::hash(&[2u8]),
+///     Blake3::hash(&[3u8]),
+///     Blake3::hash(&[4u8]),
+/// ];
+/// let options = SaltedMerkleTreeOptions::with_seed([7u8; 32]);
+/// let tree = SaltedMerkleTree::<Blake3>::with_options(values.to_vec(), options).unwrap();
+///
+/// // open a value and verify the opening
+/// let (value, proof) = tree.prove(2).unwrap();
+/// assert_eq!(values[2], value);
+/// assert!(SaltedMerkleTree::<Blake3>::verify(*tree.root(), 2, value, &proof).is_ok());
+/// assert!(SaltedMerkleTree::<Blake3>::verify(*tree.root(), 1, value, &proof).is_err());
+/// ```
+#[derive(Debug)]
+pub struct SaltedMerkleTree<H: Hasher> {
+    tree: MerkleTree<H>,
+    values: Vec<H::Digest>,
+    salt_seed: H::Digest,
+}
+
+/// Options of a [SaltedMerkleTree] specifying the seed from which leaf salts are derived.
+///
+/// If no seed is specified (which is the case for the default options), a fresh seed is drawn
+/// from the operating system's source of randomness when the tree is built.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SaltedMerkleTreeOptions {
+    seed: Option<[u8; 32]>,
+}
+
+impl SaltedMerkleTreeOptions {
+    /// Returns options which derive leaf salts from the specified `seed`.
+    ///
+    /// The seed must be kept secret and must not be reused across trees.
+    pub fn with_seed(seed: [u8; 32]) -> Self {
+        Self { seed: Some(seed) }
+    }
+}
+
+/// Opening of a single value of a [SaltedMerkleTree] consisting of the salt of the opened leaf
+/// and a Merkle path leading from this leaf up to the root (excluding the root itself).
+pub type SaltedMerkleTreeProof<H> = (<H as Hasher>::Digest, Vec<<H as Hasher>::Digest>);
+
+/// Batch opening of values of a [SaltedMerkleTree] consisting of the salts of the opened leaves
+/// (in the order in which the values were opened) and a batch Merkle proof for these leaves.
+pub type SaltedBatchMerkleProof<H> = (Vec<<H as Hasher>::Digest>, BatchMerkleProof<H>);
+
+impl<H: Hasher> SaltedMerkleTree<H> {
+    // CONSTRUCTORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new salted Merkle tree committing to the provided values, with leaf salts derived
+    /// from the seed specified in `options`.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// * Fewer than two values were provided.
+    /// * Number of values is not a power of two.
+    /// * No seed was specified in `options`, and a seed could not be drawn from the operating
+    ///   system (this is always the case when the `std` feature is disabled).
+    pub fn with_options(
+        values: Vec<H::Digest>,
+        options: SaltedMerkleTreeOptions,
+    ) -> Result<Self, MerkleTreeError> {
+        let seed = match options.seed {
+            Some(seed) => seed,
+            None => draw_seed()?,
+        };
+
+        let salt_seed = H::hash(&seed);
+        let mut leaves: Vec<H::Digest> = unsafe { uninit_vector(values.len()) };
+        iter_mut!(leaves, 1024)
+            .zip(&values)
+            .enumerate()
+            .for_each(|(i, (leaf, &value))| {
+                *leaf = H::merge(&[value, H::merge_with_int(salt_seed, i as u64)]);
+            });
+        let tree = MerkleTree::new(leaves)?;
+
+        Ok(Self { tree, values, salt_seed })
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the root of the tree.
+    pub fn root(&self) -> &H::Digest {
+        self.tree.root()
+    }
+
+    /// Returns depth of the tree.
+    pub fn depth(&self) -> usize {
+        self.tree.depth()
+    }
+
+    /// Returns the values committed to by the tree.
+    pub fn values(&self) -> &[H::Digest] {
+        &self.values
+    }
+
+    // PROVING METHODS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the value at the specified `index` together with a proof of its inclusion in the
+    /// tree.
+    ///
+    /// # Errors
+    /// Returns an error if the specified index is greater than or equal to the number of leaves
+    /// in the tree.
+    pub fn prove(
+        &self,
+        index: usize,
+    ) -> Result<(H::Digest, SaltedMerkleTreeProof<H>), MerkleTreeError> {
+        let (_, path) = self.tree.prove(index)?;
+        Ok((self.values[index], (self.get_salt(index), path)))
+    }
+
+    /// Returns the values at the specified `indexes` together with a batch proof of their
+    /// inclusion in the tree.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// * No indexes were provided (i.e., `indexes` is an empty slice).
+    /// * Any of the provided indexes are greater than or equal to the number of leaves in the tree.
+    /// * List of indexes contains duplicates.
+    pub fn prove_batch(
+        &self,
+        indexes: &[usize],
+    ) -> Result<(Vec<H::Digest>, SaltedBatchMerkleProof<H>), MerkleTreeError> {
+        let (_, proof) = self.tree.prove_batch(indexes)?;
+        let values = indexes.iter().map(|&i| self.values[i]).collect();
+        let salts = indexes.iter().map(|&i| self.get_salt(i)).collect();
+        Ok((values, (salts, proof)))
+    }
+
+    // VERIFICATION METHODS
+    // --------------------------------------------------------------------------------------------
+
+    /// Checks whether the `proof` for the given `value` at the specified `index` is valid.
+    ///
+    /// # Errors
+    /// Returns an error if the specified `proof` does not resolve to the specified `root`.
+    pub fn verify(
+        root: H::Digest,
+        index: usize,
+        value: H::Digest,
+        proof: &SaltedMerkleTreeProof<H>,
+    ) -> Result<(), MerkleTreeError> {
+        let (salt, path) = proof;
+        if path.is_empty() {
+            return Err(MerkleTreeError::InvalidProof);
+        }
+        MerkleTree::<H>::verify(root, index, H::merge(&[value, *salt]), path)
+    }
+
+    /// Checks whether the batch `proof` contains Merkle proofs resolving to `root` for the
+    /// provided `values` at the specified `indexes`.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// * No indexes were provided (i.e., `indexes` is an empty slice).
+    /// * The number of values or salts is different from the number of indexes.
+    /// * Any of the specified `indexes` is greater than or equal to the number of leaves in the
+    ///   tree from which the batch proof was generated.
+    /// * List of indexes contains duplicates.
+    /// * Any of the proofs in the batch proof does not resolve to the specified `root`.
+    pub fn verify_batch(
+        root: &H::Digest,
+        indexes: &[usize],
+        values: &[H::Digest],
+        proof: &SaltedBatchMerkleProof<H>,
+    ) -> Result<(), MerkleTreeError> {
+        let (salts, proof) = proof;
+        if values.len() != indexes.len() || salts.len() != indexes.len() {
+            return Err(MerkleTreeError::InvalidProof);
+        }
+        let leaves: Vec<H::Digest> =
+            values.iter().zip(salts.iter()).map(|(&v, &s)| H::merge(&[v, s])).collect();
+        MerkleTree::<H>::verify_batch(root, indexes, &leaves, proof)
+    }
+
+    // HELPER METHODS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the salt of the leaf at the specified `index`.
+    fn get_salt(&self, index: usize) -> H::Digest {
+        H::merge_with_int(self.salt_seed, index as u64)
+    }
+}
+
+// VECTOR COMMITMENT IMPLEMENTATION
+// ================================================================================================
+
+impl<H: Hasher> VectorCommitment<H> for SaltedMerkleTree<H> {
+    type Options = SaltedMerkleTreeOptions;
+
+    type Commitment = H::Digest;
+
+    type Proof = SaltedMerkleTreeProof<H>;
+
+    type MultiProof = SaltedBatchMerkleProof<H>;
+
+    type Error = MerkleTreeError;
+
+    fn with_options(items: Vec<H::Digest>, options: Self::Options) -> Result<Self, Self::Error> {
+        SaltedMerkleTree::with_options(items, options)
+    }
+
+    fn commitment(&self) -> H::Digest {
+        *self.root()
+    }
+
+    fn commitment_digest(commitment: &H::Digest) -> H::Digest {
+        *commitment
+    }
+
+    fn domain_len(&self) -> usize {
+        1 << self.depth()
+    }
+
+    fn get_proof_domain_len(proof: &Self::Proof) -> usize {
+        1 << proof.1.len()
+    }
+
+    fn get_multiproof_domain_len(proof: &Self::MultiProof) -> usize {
+        1 << proof.1.depth
+    }
+
+    fn open(&self, index: usize) -> Result<(H::Digest, Self::Proof), Self::Error> {
+        self.prove(index)
+    }
+
+    fn open_many(
+        &self,
+        indexes: &[usize],
+    ) -> Result<(Vec<H::Digest>, Self::MultiProof), Self::Error> {
+        self.prove_batch(indexes)
+    }
+
+    fn verify(
+        commitment: H::Digest,
+        index: usize,
+        item: H::Digest,
+        proof: &Self::Proof,
+    ) -> Result<(), Self::Error> {
+        SaltedMerkleTree::<H>::verify(commitment, index, item, proof)
+    }
+
+    fn verify_many(
+        commitment: H::Digest,
+        indexes: &[usize],
+        items: &[H::Digest],
+        proof: &Self::MultiProof,
+    ) -> Result<(), Self::Error> {
+        SaltedMerkleTree::<H>::verify_batch(&commitment, indexes, items, proof)
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Draws a fresh seed from the operating system's source of randomness.
+#[cfg(feature = "std")]
+fn draw_seed() -> Result<[u8; 32], MerkleTreeError> {
+    let mut seed = [0u8; 32];
+    getrandom::fill(&mut seed).map_err(|_| MerkleTreeError::SeedUnavailable)?;
+    Ok(seed)
+}
+
+/// Without the standard library there is no source of randomness, and thus, the seed must always
+/// be specified explicitly.
+#[cfg(not(feature = "std"))]
+fn draw_seed() -> Result<[u8; 32], MerkleTreeError> {
+    Err(MerkleTreeError::SeedUnavailable)
+}
@@ -5,6 +5,7 @@
 
 use math::fields::f128::BaseElement;
 use proptest::prelude::*;
+use utils::{Deserializable, Serializable};
 
 use super::*;
 
@@ -254,6 +255,325 @@ fn from_proofs() {
     assert_eq!(proof1.depth, proof2.depth);
 }
 
+#[test]
+fn salted_tree_prove_n_verify() {
+    let values = Digest256::bytes_as_digests(&LEAVES8).to_vec();
+    let options = SaltedMerkleTreeOptions::with_seed([1; 32]);
+    let tree = SaltedMerkleTree::<Blake3_256>::with_options(values.clone(), options).unwrap();
+    assert_eq!(3, tree.depth());
+    assert_eq!(values, tree.values());
+
+    // leaves are salted, and thus, the root is different from the root of a regular tree
+    let plain_tree = MerkleTree::<Blake3_256>::new(values.clone()).unwrap();
+    assert_ne!(plain_tree.root(), tree.root());
+
+    let (value, proof) = tree.prove(5).unwrap();
+    assert_eq!(values[5], value);
+    assert!(SaltedMerkleTree::<Blake3_256>::verify(*tree.root(), 5, value, &proof).is_ok());
+    assert!(SaltedMerkleTree::<Blake3_256>::verify(*tree.root(), 4, value, &proof).is_err());
+    assert!(SaltedMerkleTree::<Blake3_256>::verify(*tree.root(), 5, values[4], &proof).is_err());
+
+    let (_, wrong_proof) = tree.prove(4).unwrap();
+    let forged_proof = (wrong_proof.0, proof.1.clone());
+    assert!(SaltedMerkleTree::<Blake3_256>::verify(*tree.root(), 5, value, &forged_proof).is_err());
+
+    let (opened, proof) = tree.prove_batch(&[6, 1, 3]).unwrap();
+    assert_eq!(vec![values[6], values[1], values[3]], opened);
+    assert!(SaltedMerkleTree::verify_batch(tree.root(), &[6, 1, 3], &opened, &proof).is_ok());
+    assert!(SaltedMerkleTree::verify_batch(tree.root(), &[1, 6, 3], &opened, &proof).is_err());
+    assert!(SaltedMerkleTree::verify_batch(tree.root(), &[6, 1], &opened[..2], &proof).is_err());
+}
+
+#[test]
+fn salted_tree_seeds() {
+    let values = Digest256::bytes_as_digests(&LEAVES4).to_vec();
+
+    // the same seed always results in the same tree
+    let options = SaltedMerkleTreeOptions::with_seed([1; 32]);
+    let tree1 = SaltedMerkleTree::<Blake3_256>::with_options(values.clone(), options).unwrap();
+    let tree2 = SaltedMerkleTree::<Blake3_256>::with_options(values.clone(), options).unwrap();
+    assert_eq!(tree1.root(), tree2.root());
+
+    // different seeds result in different trees
+    let options = SaltedMerkleTreeOptions::with_seed([2; 32]);
+    let tree3 = SaltedMerkleTree::<Blake3_256>::with_options(values.clone(), options).unwrap();
+    assert_ne!(tree1.root(), tree3.root());
+
+    // with default options, a fresh seed is drawn for every tree
+    let tree4 = <SaltedMerkleTree<Blake3_256> as VectorCommitment<_>>::new(values.clone()).unwrap();
+    let tree5 = <SaltedMerkleTree<Blake3_256> as VectorCommitment<_>>::new(values).unwrap();
+    assert_ne!(tree4.root(), tree5.root());
+}
+
+#[test]
+fn salted_tree_salts() {
+    let values = Digest256::bytes_as_digests(&LEAVES8).to_vec();
+    let plain_tree = MerkleTree::<Blake3_256>::new(values.clone()).unwrap();
+
+    // the same seed always results in the same salts
+    let options = SaltedMerkleTreeOptions::with_seed([1; 32]);
+    let tree1 = SaltedMerkleTree::<Blake3_256>::with_options(values.clone(), options).unwrap();
+    let tree2 = SaltedMerkleTree::<Blake3_256>::with_options(values.clone(), options).unwrap();
+    assert_eq!(tree1.prove(5).unwrap(), tree2.prove(5).unwrap());
+
+    // every leaf gets its own salt
+    let (_, (salt3, _)) = tree1.prove(3).unwrap();
+    let (_, (salt5, path5)) = tree1.prove(5).unwrap();
+    assert_ne!(salt3, salt5);
+
+    // paths are built from the salted leaves, and thus, they differ from the paths of a regular
+    // tree
+    let (_, plain_path5) = plain_tree.prove(5).unwrap();
+    assert_eq!(plain_path5.len(), path5.len());
+    assert_ne!(plain_path5[0], path5[0]);
+
+    // trees with fresh seeds open the same leaf with different salts and under different roots
+    let tree3 = <SaltedMerkleTree<Blake3_256> as VectorCommitment<_>>::new(values.clone()).unwrap();
+    let tree4 = <SaltedMerkleTree<Blake3_256> as VectorCommitment<_>>::new(values).unwrap();
+    let (value3, (salt3, _)) = tree3.prove(5).unwrap();
+    let (value4, (salt4, _)) = tree4.prove(5).unwrap();
+    assert_eq!(value3, value4);
+    assert_ne!(salt3, salt4);
+    assert_ne!(tree3.root(), tree4.root());
+
+    // the same holds for batch openings
+    let (_, (salts3, _)) = tree3.prove_batch(&[1, 6]).unwrap();
+    let (_, (salts4, _)) = tree4.prove_batch(&[1, 6]).unwrap();
+    assert_eq!(2, salts3.len());
+    assert_ne!(salts3[0], salts4[0]);
+    assert_ne!(salts3[1], salts4[1]);
+}
+
+#[test]
+fn salted_tree_proof_serialization() {
+    let values = Digest256::bytes_as_digests(&LEAVES8).to_vec();
+    let tree = <SaltedMerkleTree<Blake3_256> as VectorCommitment<_>>::new(values).unwrap();
+    let indexes = [0, 2, 7];
+
+    let (opened, proof) = tree.open_many(&indexes).unwrap();
+    assert_eq!(8, SaltedMerkleTree::<Blake3_256>::get_multiproof_domain_len(&proof));
+
+    let bytes = proof.to_bytes();
+    let proof = SaltedBatchMerkleProof::<Blake3_256>::read_from_bytes(&bytes).unwrap();
+    assert!(<SaltedMerkleTree<Blake3_256> as VectorCommitment<_>>::verify_many(
+        tree.commitment(),
+        &indexes,
+        &opened,
+        &proof
+    )
+    .is_ok());
+}
+
+#[test]
+fn cap_tree_prove_n_verify() {
+    let leaves = Digest256::bytes_as_digests(&LEAVES8).to_vec();
+    let plain_tree = MerkleTree::<Blake3_256>::new(leaves.clone()).unwrap();
+
+    for cap_height in 0..=4 {
+        let options = MerkleCapOptions::new(cap_height);
+        let tree = MerkleCapTree::<Blake3_256>::with_options(leaves.clone(), options).unwrap();
+
+        // the cap height is capped at the depth of the tree
+        let cap_height = cap_height.min(3);
+        assert_eq!(cap_height, tree.cap_height());
+        assert_eq!(1 << cap_height, tree.cap().len());
+        assert_eq!(tree.cap(), &tree.commitment()[..]);
+        assert_eq!(
+            Blake3_256::merge_many(tree.cap()),
+            MerkleCapTree::<Blake3_256>::commitment_digest(&tree.commitment())
+        );
+        if cap_height == 0 {
+            assert_eq!(plain_tree.root(), &tree.cap()[0]);
+        }
+
+        for indexes in [vec![1], vec![1, 2], vec![6, 1, 3], vec![0, 1, 2, 3, 4, 5, 6, 7]] {
+            let opened: Vec<_> = indexes.iter().map(|&i| leaves[i]).collect();
+            let proof = tree.prove_batch(&indexes).unwrap();
+            assert_eq!(3, proof.depth);
+            assert!(MerkleCapTree::verify_batch(tree.cap(), &indexes, &opened, &proof).is_ok());
+
+            // paths stop at the cap
+            let (_, plain_proof) = plain_tree.prove_batch(&indexes).unwrap();
+            let num_plain_nodes: usize = plain_proof.nodes.iter().map(|n| n.len()).sum();
+            assert!(proof.nodes.len() <= num_plain_nodes);
+        }
+
+        // paths of individual openings are shortened by the height of the cap
+        let (leaf, proof) = tree.open(5).unwrap();
+        assert_eq!(3 - cap_height, proof.nodes.len());
+        assert!(MerkleCapTree::<Blake3_256>::verify(tree.commitment(), 5, leaf, &proof).is_ok());
+        assert!(MerkleCapTree::<Blake3_256>::verify(tree.commitment(), 4, leaf, &proof).is_err());
+
+        let proof = tree.prove_batch(&[1, 2]).unwrap();
+        let opened = [leaves[1], leaves[2]];
+        assert!(MerkleCapTree::verify_batch(tree.cap(), &[1, 3], &opened, &proof).is_err());
+        assert!(MerkleCapTree::verify_batch(tree.cap(), &[2, 1], &opened, &proof).is_err());
+        if cap_height < 3 {
+            // unused proof nodes are rejected (when the cap consists of the leaves, the proof
+            // contains no nodes)
+            assert!(MerkleCapTree::verify_batch(tree.cap(), &[1], &opened[..1], &proof).is_err());
+        }
+        assert!(
+            MerkleCapTree::verify_batch(tree.cap(), &[1, 2], &[leaves[1], leaves[1]], &proof)
+                .is_err()
+        );
+
+        // a cap which does not match the tree is rejected
+        let mut wrong_cap = tree.cap().to_vec();
+        wrong_cap[1 >> (3 - cap_height)] = leaves[7];
+        assert!(MerkleCapTree::verify_batch(&wrong_cap, &[1, 2], &opened, &proof).is_err());
+
+        // a malformed cap is rejected
+        assert!(MerkleCapTree::verify_batch(&wrong_cap[1..], &[1, 2], &opened, &proof).is_err());
+    }
+}
+
+#[test]
+fn cap_tree_paths_stop_at_cap() {
+    let leaves: Vec<_> = (0..32u8).map(|i| Blake3_256::hash(&[i])).collect();
+    let plain_tree = MerkleTree::<Blake3_256>::new(leaves.clone()).unwrap();
+
+    for cap_height in 0..=5 {
+        let options = MerkleCapOptions::new(cap_height);
+        let tree = MerkleCapTree::<Blake3_256>::with_options(leaves.clone(), options).unwrap();
+
+        // a path in a cap tree is a path in the regular tree without its top `cap_height` nodes
+        for index in 0..32 {
+            let (_, plain_path) = plain_tree.prove(index).unwrap();
+            let (_, proof) = tree.open(index).unwrap();
+            assert_eq!(&plain_path[..5 - cap_height], &proof.nodes[..]);
+        }
+
+        // in batch proofs, the siblings at the top `cap_height` levels are omitted as well; the
+        // opened leaves below are spread across the tree so that every level has 2 siblings
+        let indexes = [0, 31];
+        let (_, plain_proof) = plain_tree.prove_batch(&indexes).unwrap();
+        let num_plain_nodes: usize = plain_proof.nodes.iter().map(|n| n.len()).sum();
+        assert_eq!(2 * 4, num_plain_nodes);
+        let proof = tree.prove_batch(&indexes).unwrap();
+        assert_eq!(2 * (5 - cap_height.max(1)), proof.nodes.len());
+    }
+}
+
+#[test]
+fn cap_tree_proof_serialization() {
+    let leaves = Digest256::bytes_as_digests(&LEAVES8).to_vec();
+    let tree = <MerkleCapTree<Blake3_256> as VectorCommitment<_>>::new(leaves).unwrap();
+    assert_eq!(3, tree.cap_height());
+
+    let (opened, proof) = tree.open_many(&[0, 5]).unwrap();
+    assert_eq!(8, MerkleCapTree::<Blake3_256>::get_multiproof_domain_len(&proof));
+
+    let bytes = proof.to_bytes();
+    let proof = MerkleCapProof::<Blake3_256>::read_from_bytes(&bytes).unwrap();
+    assert!(<MerkleCapTree<Blake3_256> as VectorCommitment<_>>::verify_many(
+        tree.commitment(),
+        &[0, 5],
+        &opened,
+        &proof
+    )
+    .is_ok());
+}
+
+#[test]
+fn arity_tree_prove_n_verify() {
+    let leaves = Digest256::bytes_as_digests(&LEAVES8).to_vec();
+
+    // a binary tree has the same root as a regular Merkle tree
+    let binary_tree = ArityMerkleTree::<Blake3_256, 2>::new(leaves.clone()).unwrap();
+    let plain_tree = MerkleTree::<Blake3_256>::new(leaves.clone()).unwrap();
+    assert_eq!(plain_tree.root(), binary_tree.root());
+    assert_eq!(3, binary_tree.num_levels());
+
+    // in a 4-ary tree with 8 leaves, the root has 2 children
+    let tree = ArityMerkleTree::<Blake3_256, 4>::new(leaves.clone()).unwrap();
+    assert_eq!(3, tree.depth());
+    assert_eq!(2, tree.num_levels());
+    let left = Blake3_256::merge_many(&leaves[..4]);
+    let right = Blake3_256::merge_many(&leaves[4..]);
+    assert_eq!(Blake3_256::merge_many(&[left, right]), *tree.root());
+
+    // in an 8-ary tree with 8 leaves, the root has 8 children
+    let wide_tree = ArityMerkleTree::<Blake3_256, 8>::new(leaves.clone()).unwrap();
+    assert_eq!(1, wide_tree.num_levels());
+    assert_eq!(Blake3_256::merge_many(&leaves), *wide_tree.root());
+
+    // an individual opening contains 3 siblings at the first level and 1 sibling at the root
+    let (leaf, proof) = tree.open(5).unwrap();
+    assert_eq!(leaves[5], leaf);
+    assert_eq!(vec![leaves[4], leaves[6], leaves[7], left], proof.nodes);
+    assert!(ArityMerkleTree::<Blake3_256, 4>::verify(*tree.root(), 5, leaf, &proof).is_ok());
+    assert!(ArityMerkleTree::<Blake3_256, 4>::verify(*tree.root(), 4, leaf, &proof).is_err());
+
+    for indexes in [vec![1], vec![1, 2], vec![6, 1, 3], vec![0, 1, 2, 3, 4, 5, 6, 7]] {
+        let opened: Vec<_> = indexes.iter().map(|&i| leaves[i]).collect();
+        let proof = tree.prove_batch(&indexes).unwrap();
+        assert!(
+            ArityMerkleTree::<_, 4>::verify_batch(tree.root(), &indexes, &opened, &proof).is_ok()
+        );
+    }
+
+    let proof = tree.prove_batch(&[1, 2]).unwrap();
+    let opened = [leaves[1], leaves[2]];
+    assert!(ArityMerkleTree::<_, 4>::verify_batch(tree.root(), &[1, 3], &opened, &proof).is_err());
+    assert!(ArityMerkleTree::<_, 4>::verify_batch(tree.root(), &[2, 1], &opened, &proof).is_err());
+    assert!(ArityMerkleTree::<_, 4>::verify_batch(tree.root(), &[1], &opened[..1], &proof).is_err());
+    assert!(
+        ArityMerkleTree::<_, 4>::verify_batch(tree.root(), &[1, 2, 9], &opened, &proof).is_err()
+    );
+
+    assert_eq!(
+        Err(MerkleTreeError::NumberOfLeavesNotPowerOfTwo(6)),
+        ArityMerkleTree::<Blake3_256, 4>::new(leaves[..6].to_vec()).map(|_| ())
+    );
+}
+
+#[test]
+fn arity_tree_levels() {
+    let leaves: Vec<_> = (0..64u8).map(|i| Blake3_256::hash(&[i])).collect();
+    let binary_tree = ArityMerkleTree::<Blake3_256, 2>::new(leaves.clone()).unwrap();
+    let tree = ArityMerkleTree::<Blake3_256, 4>::new(leaves.clone()).unwrap();
+
+    // a 4-ary tree has half as many levels as a binary tree with the same leaves
+    assert_eq!(6, binary_tree.num_levels());
+    assert_eq!(3, tree.num_levels());
+    assert_eq!(6, tree.depth());
+
+    // an individual path has one node per level in a binary tree, and 3 nodes per level in a
+    // 4-ary tree
+    for index in 0..64 {
+        let (_, binary_proof) = binary_tree.open(index).unwrap();
+        let (_, proof) = tree.open(index).unwrap();
+        assert_eq!(6, binary_proof.nodes.len());
+        assert_eq!(3 * 3, proof.nodes.len());
+    }
+
+    // when the opened leaves fill a node at the first level, no siblings are needed at that level
+    let (_, binary_proof) = binary_tree.open_many(&[4, 5, 6, 7]).unwrap();
+    let (_, proof) = tree.open_many(&[4, 5, 6, 7]).unwrap();
+    assert_eq!(6 - 2, binary_proof.nodes.len());
+    assert_eq!(3 * 2, proof.nodes.len());
+}
+
+#[test]
+fn arity_tree_proof_serialization() {
+    let leaves = Digest256::bytes_as_digests(&LEAVES8).to_vec();
+    let tree = <ArityMerkleTree<Blake3_256, 4> as VectorCommitment<_>>::new(leaves).unwrap();
+
+    let (opened, proof) = tree.open_many(&[0, 5]).unwrap();
+    assert_eq!(8, ArityMerkleTree::<Blake3_256, 4>::get_multiproof_domain_len(&proof));
+
+    let bytes = proof.to_bytes();
+    let proof = ArityMerkleProof::<Blake3_256>::read_from_bytes(&bytes).unwrap();
+    assert!(<ArityMerkleTree<Blake3_256, 4> as VectorCommitment<_>>::verify_many(
+        tree.commitment(),
+        &[0, 5],
+        &opened,
+        &proof
+    )
+    .is_ok());
+}
+
 proptest! {
     #[test]
     fn prove_n_verify(tree in random_blake3_merkle_tree(128),
@@ -275,6 +595,37 @@ proptest! {
         prop_assert!(MerkleTree::verify_batch(tree.root(), &indices[..], &leaves,  &proof).is_ok());
     }
 
+    #[test]
+    fn cap_tree_prove_batch_n_verify(tree in random_blake3_merkle_tree(128),
+                      cap_height in 0..8usize,
+                      proof_indices in prop::collection::vec(any::<prop::sample::Index>(), 10..20)
+    )  {
+        let options = MerkleCapOptions::new(cap_height);
+        let tree = MerkleCapTree::<Blake3_256>::with_options(tree.leaves().to_vec(), options).unwrap();
+        let mut indices: Vec<usize> = proof_indices.iter().map(|idx| idx.index(128)).collect();
+        indices.sort_unstable(); indices.dedup();
+        let leaves: Vec<_> = indices.iter().map(|&i| tree.leaves()[i]).collect();
+        let proof = tree.prove_batch(&indices[..]).unwrap();
+        prop_assert!(MerkleCapTree::verify_batch(tree.cap(), &indices[..], &leaves, &proof).is_ok());
+    }
+
+    #[test]
+    fn arity_tree_prove_batch_n_verify(tree in random_blake3_merkle_tree(128),
+                      proof_indices in prop::collection::vec(any::<prop::sample::Index>(), 10..20)
+    )  {
+        let mut indices: Vec<usize> = proof_indices.iter().map(|idx| idx.index(128)).collect();
+        indices.sort_unstable(); indices.dedup();
+        let leaves: Vec<_> = indices.iter().map(|&i| tree.leaves()[i]).collect();
+
+        let tree4 = ArityMerkleTree::<Blake3_256, 4>::new(tree.leaves().to_vec()).unwrap();
+        let proof = tree4.prove_batch(&indices[..]).unwrap();
+        prop_assert!(ArityMerkleTree::<_, 4>::verify_batch(tree4.root(), &indices[..], &leaves, &proof).is_ok());
+
+        let tree8 = ArityMerkleTree::<Blake3_256, 8>::new(tree.leaves().to_vec()).unwrap();
+        let proof = tree8.prove_batch(&indices[..]).unwrap();
+        prop_assert!(ArityMerkleTree::<_, 8>::verify_batch(tree8.root(), &indices[..], &leaves, &proof).is_ok());
+    }
+
     #[test]
     fn batch_proof_from_proofs(tree in random_blake3_merkle_tree(128),
                       proof_indices in prop::collection::vec(any::<prop::sample::Index>(), 10..20)
@@ -155,13 +155,16 @@ impl<B: StarkField, H: ElementHasher<BaseField = B>> RandomCoin for DefaultRando
     /// PRNG.
     fn draw<E: FieldElement>(&mut self) -> Result<E, RandomCoinError> {
         for _ in 0..1000 {
-            // get the next pseudo-random value and take the first ELEMENT_BYTES from it
-            let value = self.next();
-            let bytes = &value.as_bytes()[..E::ELEMENT_BYTES];
+            // get the next pseudo-random value and take the first ELEMENT_BYTES from it; if the
+            // digest is shorter than an element, keep appending the next pseudo-random values
+            let mut bytes = Vec::with_capacity(E::ELEMENT_BYTES + H::Digest::NUM_BYTES);
+            while bytes.len() < E::ELEMENT_BYTES {
+                bytes.extend_from_slice(&self.next().as_bytes()[..H::Digest::NUM_BYTES]);
+            }
 
             // check if the bytes can be converted into a valid field element; if they can,
             // return; otherwise try again
-            if let Some(element) = E::from_random_bytes(bytes) {
+            if let Some(element) = E::from_random_bytes(&bytes[..E::ELEMENT_BYTES]) {
                 return Ok(element);
             }
         }
@@ -246,3 +249,32 @@ impl<B: StarkField, H: ElementHasher<BaseField = B>> RandomCoin for DefaultRando
         Ok(values)
     }
 }
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use math::{
+        fields::{f128::BaseElement, QuadExtension},
+        FieldElement,
+    };
+    use utils::Randomizable;
+
+    use super::{DefaultRandomCoin, RandomCoin};
+    use crate::{hash::Blake3_160, Digest, ElementHasher, Hasher};
+
+    #[test]
+    fn draw_with_short_digest() {
+        type Blake3 = Blake3_160<BaseElement>;
+        let mut coin = DefaultRandomCoin::<Blake3>::new(&[BaseElement::ONE]);
+
+        // an element which is wider than the digest is read from consecutive hashes
+        let seed = Blake3::hash_elements(&[BaseElement::ONE]);
+        let mut bytes = Blake3::merge_with_int(seed, 1).as_bytes()[..20].to_vec();
+        bytes.extend_from_slice(&Blake3::merge_with_int(seed, 2).as_bytes()[..12]);
+
+        let element = coin.draw::<QuadExtension<BaseElement>>().unwrap();
+        assert_eq!(QuadExtension::<BaseElement>::from_random_bytes(&bytes), Some(element));
+    }
+}
@@ -0,0 +1,290 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+
+use math::{FieldElement, StarkField};
+use sha3::Digest as _;
+
+use crate::{
+    errors::RandomCoinError, hash::ByteDigest, hashers::Keccak256, Digest, ElementHasher, Hasher,
+    RandomCoin,
+};
+
+// KECCAK RANDOM COIN IMPLEMENTATION
+// ================================================================================================
+
+/// Pseudo-random element generator for finite fields based on Keccak-256, designed to be cheaply
+/// replayed by a verifier running in the Ethereum Virtual Machine.
+///
+/// The coin follows the same construction as [DefaultRandomCoin](crate::DefaultRandomCoin)
+/// instantiated with [Keccak256](crate::hashers::Keccak256), but encodes its inputs and decodes
+/// its outputs the way EVM code would. That is, counters and nonces are absorbed as 8-byte
+/// big-endian integers (as in `abi.encodePacked(seed, uint64(counter))`), and hash outputs are
+/// interpreted as big-endian 256-bit integers:
+/// - The seed is set to a hash of the provided elements; on reseeding, it is set to
+///   keccak256(`seed` || `data`).
+/// - To draw the next element, we increment the `counter` and compute keccak256(`seed` ||
+///   `counter`). Coefficients of the element are read from the low-order bits of the resulting
+///   integer (e.g., a 64-bit base field element is read as `uint64(uint256(h))`). Elements wider
+///   than 32 bytes are read from several consecutive hashes: once the bytes of keccak256(`seed`
+///   || `counter`) are exhausted, the counter is incremented and the remaining coefficients are
+///   read the same way from the next hash. If any of the coefficients is not a valid field
+///   element, we try again until a valid element is found or the number of allowed tries is
+///   exceeded.
+/// - Integers for query positions are read as `uint256(h) & (domain_size - 1)`.
+/// - Proof-of-work is checked by counting the leading zeros of `uint256(h)`.
+///
+/// # Examples
+/// ```
+/// # use winter_crypto::{RandomCoin, KeccakRandomCoin};
+/// # use math::fields::f64::BaseElement;
+/// let seed = &[BaseElement::new(1), BaseElement::new(2)];
+///
+/// // should draw the same elements for the same seed
+/// let mut coin1 = KeccakRandomCoin::new(seed);
+/// let mut coin2 = KeccakRandomCoin::new(seed);
+/// let e1 = coin1.draw::<BaseElement>().unwrap();
+/// let e2 = coin2.draw::<BaseElement>().unwrap();
+/// assert_eq!(e1, e2);
+///
+/// // should draw different elements each time
+/// let e3 = coin1.draw::<BaseElement>().unwrap();
+/// assert_ne!(e1, e3);
+/// ```
+pub struct KeccakRandomCoin<B: StarkField> {
+    seed: ByteDigest<32>,
+    counter: u64,
+    _base_field: PhantomData<B>,
+}
+
+impl<B: StarkField> KeccakRandomCoin<B> {
+    /// Updates the state by incrementing the counter and returns keccak256(seed || counter)
+    fn next(&mut self) -> [u8; 32] {
+        self.counter += 1;
+        hash_with_int(&self.seed, self.counter).as_bytes()
+    }
+}
+
+impl<B: StarkField> RandomCoin for KeccakRandomCoin<B> {
+    type BaseField = B;
+    type Hasher = Keccak256<B>;
+
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new random coin instantiated with the provided `seed`.
+    fn new(seed: &[Self::BaseField]) -> Self {
+        let seed = Keccak256::<B>::hash_elements(seed);
+        Self {
+            seed,
+            counter: 0,
+            _base_field: PhantomData,
+        }
+    }
+
+    // RESEEDING
+    // --------------------------------------------------------------------------------------------
+
+    /// Reseeds the coin with the specified data by setting the new seed to
+    /// keccak256(`seed` || `data`).
+    fn reseed(&mut self, data: ByteDigest<32>) {
+        self.seed = Keccak256::<B>::merge(&[self.seed, data]);
+        self.counter = 0;
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Computes keccak256(`seed` || `value`) and returns the number of leading zeros in the
+    /// resulting value if it is interpreted as an integer in big-endian byte order.
+    fn check_leading_zeros(&self, value: u64) -> u32 {
+        let bytes = hash_with_int(&self.seed, value).as_bytes();
+        let seed_head = u64::from_be_bytes(bytes[..8].try_into().unwrap());
+        seed_head.leading_zeros()
+    }
+
+    // DRAW METHODS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the next pseudo-random field element.
+    ///
+    /// # Errors
+    /// Returns an error if a valid field element could not be generated after 1000 calls to the
+    /// PRNG.
+    fn draw<E: FieldElement>(&mut self) -> Result<E, RandomCoinError> {
+        for _ in 0..1000 {
+            // take the low-order ELEMENT_BYTES of the big-endian value, and convert them into
+            // little-endian order expected by the field element; if the element is wider than
+            // the hash, keep appending the next pseudo-random values in the same way
+            let mut bytes = Vec::with_capacity(E::ELEMENT_BYTES + 32);
+            while bytes.len() < E::ELEMENT_BYTES {
+                let mut value = self.next();
+                value.reverse();
+                bytes.extend_from_slice(&value);
+            }
+
+            if let Some(element) = E::from_random_bytes(&bytes[..E::ELEMENT_BYTES]) {
+                return Ok(element);
+            }
+        }
+
+        Err(RandomCoinError::FailedToDrawFieldElement(1000))
+    }
+
+    /// Returns a vector of integers selected from the range [0, domain_size) after reseeding
+    /// the PRNG with the specified `nonce` by setting the new seed to keccak256(`seed` ||
+    /// `nonce`).
+    ///
+    /// # Errors
+    /// Returns an error if the specified number of integers could not be generated after 1000
+    /// calls to the PRNG.
+    ///
+    /// # Panics
+    /// Panics if:
+    /// - `domain_size` is not a power of two.
+    /// - `num_values` is greater than or equal to `domain_size`.
+    fn draw_integers(
+        &mut self,
+        num_values: usize,
+        domain_size: usize,
+        nonce: u64,
+    ) -> Result<Vec<usize>, RandomCoinError> {
+        assert!(domain_size.is_power_of_two(), "domain size must be a power of two");
+        assert!(num_values < domain_size, "number of values must be smaller than domain size");
+
+        // reseed with nonce
+        self.seed = hash_with_int(&self.seed, nonce);
+        self.counter = 0;
+
+        // determine how many bits are needed to represent valid values in the domain
+        let v_mask = (domain_size - 1) as u64;
+
+        // draw values from PRNG until we get as many unique values as specified by num_queries
+        let mut values = Vec::new();
+        for _ in 0..1000 {
+            // read the low-order 8 bytes of the big-endian value
+            let bytes: [u8; 8] = self.next()[24..].try_into().unwrap();
+
+            // convert to integer and limit the integer to the number of bits which can fit
+            // into the specified domain
+            let value = (u64::from_be_bytes(bytes) & v_mask) as usize;
+
+            values.push(value);
+            if values.len() == num_values {
+                break;
+            }
+        }
+
+        if values.len() < num_values {
+            return Err(RandomCoinError::FailedToDrawIntegers(num_values, values.len(), 1000));
+        }
+
+        Ok(values)
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns keccak256(`seed` || `value`), where `value` is encoded as an 8-byte big-endian integer.
+fn hash_with_int(seed: &ByteDigest<32>, value: u64) -> ByteDigest<32> {
+    let mut data = [0; 40];
+    data[..32].copy_from_slice(&seed.as_bytes());
+    data[32..].copy_from_slice(&value.to_be_bytes());
+    ByteDigest::new(sha3::Keccak256::digest(data).into())
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use math::{
+        fields::{f64::BaseElement, QuintExtension},
+        StarkField,
+    };
+    use sha3::Digest as _;
+
+    use super::{ElementHasher, Keccak256, KeccakRandomCoin, RandomCoin};
+    use crate::Digest;
+
+    /// Computes keccak256(`seed` || `value`) the way `abi.encodePacked(bytes32, uint64)` encodes
+    /// its inputs.
+    fn keccak_packed(seed: &[u8; 32], value: u64) -> [u8; 32] {
+        let mut hasher = sha3::Keccak256::new();
+        hasher.update(seed);
+        hasher.update(value.to_be_bytes());
+        hasher.finalize().into()
+    }
+
+    #[test]
+    fn keccak_coin_draw() {
+        let seed = [BaseElement::new(1), BaseElement::new(2)];
+        let mut coin = KeccakRandomCoin::new(&seed);
+
+        // the element is read from the low-order 64 bits of keccak256(seed || 1)
+        let seed = Keccak256::hash_elements(&seed).as_bytes();
+        let h = keccak_packed(&seed, 1);
+        let value = u64::from_be_bytes(h[24..].try_into().unwrap());
+        assert!(value < BaseElement::MODULUS);
+        assert_eq!(BaseElement::new(value), coin.draw::<BaseElement>().unwrap());
+    }
+
+    #[test]
+    fn keccak_coin_draw_wide_element() {
+        let seed = [BaseElement::new(1), BaseElement::new(2)];
+        let mut coin = KeccakRandomCoin::new(&seed);
+
+        // the element is 40 bytes wide: the first four coefficients are read from the low-order
+        // 256 bits of keccak256(seed || 1), and the last one from keccak256(seed || 2)
+        let seed = Keccak256::hash_elements(&seed).as_bytes();
+        let h1 = keccak_packed(&seed, 1);
+        let h2 = keccak_packed(&seed, 2);
+        let coefficients = [&h1[24..], &h1[16..24], &h1[8..16], &h1[..8], &h2[24..]].map(|bytes| {
+            let value = u64::from_be_bytes(bytes.try_into().unwrap());
+            assert!(value < BaseElement::MODULUS);
+            BaseElement::new(value)
+        });
+        let [c0, c1, c2, c3, c4] = coefficients;
+        let expected = QuintExtension::new(c0, c1, c2, c3, c4);
+        assert_eq!(expected, coin.draw::<QuintExtension<BaseElement>>().unwrap());
+
+        // the next element is drawn after both hashes
+        let h3 = keccak_packed(&seed, 3);
+        let value = u64::from_be_bytes(h3[24..].try_into().unwrap());
+        assert!(value < BaseElement::MODULUS);
+        assert_eq!(BaseElement::new(value), coin.draw::<BaseElement>().unwrap());
+    }
+
+    #[test]
+    fn keccak_coin_draw_integers() {
+        let seed = [BaseElement::new(3)];
+        let mut coin = KeccakRandomCoin::new(&seed);
+        let values = coin.draw_integers(2, 1 << 10, 42).unwrap();
+
+        // integers are read from the low-order bits of keccak256(keccak256(seed || nonce) || i)
+        let seed = keccak_packed(&Keccak256::hash_elements(&seed).as_bytes(), 42);
+        for (i, value) in values.into_iter().enumerate() {
+            let h = keccak_packed(&seed, i as u64 + 1);
+            let expected = u64::from_be_bytes(h[24..].try_into().unwrap()) & 0x3ff;
+            assert_eq!(expected as usize, value);
+        }
+    }
+
+    #[test]
+    fn keccak_coin_leading_zeros() {
+        let seed = [BaseElement::new(4)];
+        let coin = KeccakRandomCoin::new(&seed);
+
+        let seed = Keccak256::hash_elements(&seed).as_bytes();
+        for nonce in 0..16 {
+            let h = keccak_packed(&seed, nonce);
+            let expected = u64::from_be_bytes(h[..8].try_into().unwrap()).leading_zeros();
+            assert_eq!(expected, coin.check_leading_zeros(nonce));
+        }
+    }
+}
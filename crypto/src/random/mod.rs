@@ -12,6 +12,12 @@ use crate::{errors::RandomCoinError, ElementHasher, Hasher};
 mod default;
 pub use default::DefaultRandomCoin;
 
+mod keccak;
+pub use keccak::KeccakRandomCoin;
+
+mod sumcheck;
+pub use sumcheck::{prove_sumcheck, verify_sumcheck};
+
 // RANDOM COIN TRAIT
 // ================================================================================================
 
@@ -0,0 +1,181 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use alloc::vec::Vec;
+
+use math::{
+    multilinear::{
+        CompositionPolynomial, MultiLinearPoly, SumCheckError, SumCheckProof, SumCheckProver,
+        SumCheckVerifier,
+    },
+    FieldElement,
+};
+
+use super::RandomCoin;
+use crate::ElementHasher;
+
+// SUM-CHECK PROTOCOL
+// ================================================================================================
+
+/// Generates a non-interactive proof that the sum of the `composition` polynomial applied to the
+/// specified multilinear polynomials over the boolean hypercube is equal to the returned claim.
+///
+/// Round challenges are drawn from the `public_coin`, which is reseeded with the claim and with
+/// every round polynomial before the challenge for the round is drawn. The point defined by the
+/// round challenges is returned together with the proof.
+///
+/// # Panics
+/// Panics if:
+/// * `mles` is empty or the polynomials have different numbers of variables.
+/// * Maximum degree of the `composition` polynomial is zero.
+/// * A round challenge could not be drawn from the `public_coin`.
+pub fn prove_sumcheck<E, P, C>(
+    composition: P,
+    mles: Vec<MultiLinearPoly<E>>,
+    public_coin: &mut C,
+) -> (E, SumCheckProof<E>, Vec<E>)
+where
+    E: FieldElement,
+    P: CompositionPolynomial<E>,
+    C: RandomCoin<BaseField = E::BaseField>,
+{
+    let mut prover = SumCheckProver::new(composition, mles);
+    let claim = prover.claim();
+    public_coin.reseed(C::Hasher::hash_elements(&[claim]));
+
+    let num_rounds = prover.num_rounds();
+    let mut round_polys = Vec::with_capacity(num_rounds);
+    let mut round_challenges = Vec::with_capacity(num_rounds);
+    for _ in 0..num_rounds {
+        let round_poly = prover.round_poly();
+        public_coin.reseed(C::Hasher::hash_elements(round_poly.evaluations()));
+        let round_challenge = public_coin.draw().expect("failed to draw round challenge");
+
+        prover.bind(round_challenge);
+        round_polys.push(round_poly);
+        round_challenges.push(round_challenge);
+    }
+
+    let proof = SumCheckProof {
+        round_polys,
+        final_evaluations: prover.final_evaluations(),
+    };
+    (claim, proof, round_challenges)
+}
+
+/// Verifies a non-interactive proof that the sum of the `composition` polynomial applied to
+/// `num_polys` multilinear polynomials in `num_variables` variables over the boolean hypercube is
+/// equal to `claim`, and returns the point defined by the round challenges.
+///
+/// The `public_coin` must be in the same state as the coin used to generate the proof.
+///
+/// A successful verification means that the sum is correct provided that final evaluations in
+/// the proof are evaluations of the multilinear polynomials at the returned point; this must be
+/// checked by the caller.
+///
+/// # Errors
+/// Returns an error if the proof has an unexpected number of rounds or round polynomials of an
+/// unexpected degree, if a round challenge could not be drawn from the `public_coin`, if the
+/// proof does not contain exactly `num_polys` final evaluations, or if the final claim does not
+/// match the `composition` polynomial applied to the final evaluations.
+///
+/// # Panics
+/// Panics if maximum degree of the `composition` polynomial is zero.
+pub fn verify_sumcheck<E, P, C>(
+    composition: &P,
+    num_polys: usize,
+    num_variables: usize,
+    claim: E,
+    proof: &SumCheckProof<E>,
+    public_coin: &mut C,
+) -> Result<Vec<E>, SumCheckError>
+where
+    E: FieldElement,
+    P: CompositionPolynomial<E>,
+    C: RandomCoin<BaseField = E::BaseField>,
+{
+    let mut verifier =
+        SumCheckVerifier::new(num_variables, num_polys, composition.max_degree(), claim);
+    public_coin.reseed(C::Hasher::hash_elements(&[claim]));
+
+    for (round, round_poly) in proof.round_polys.iter().enumerate() {
+        public_coin.reseed(C::Hasher::hash_elements(round_poly.evaluations()));
+        let round_challenge =
+            public_coin.draw().map_err(|_| SumCheckError::FailedToDrawChallenge(round))?;
+        verifier.process_round(round_poly, round_challenge)?;
+    }
+
+    verifier.finalize(composition, &proof.final_evaluations)
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use math::{
+        fields::{f64::BaseElement, QuadExtension},
+        multilinear::{CompositionPolynomial, MultiLinearPoly, SumCheckError},
+        FieldElement,
+    };
+    use rand_utils::rand_vector;
+
+    use super::{prove_sumcheck, verify_sumcheck};
+    use crate::{hash::Rp64_256, DefaultRandomCoin, RandomCoin};
+
+    type E = QuadExtension<BaseElement>;
+
+    /// f(a, b) = a * b
+    struct InnerProduct;
+
+    impl CompositionPolynomial<E> for InnerProduct {
+        fn max_degree(&self) -> usize {
+            2
+        }
+
+        fn evaluate(&self, query: &[E]) -> E {
+            query[0] * query[1]
+        }
+    }
+
+    #[test]
+    fn sumcheck_inner_product() {
+        let num_variables = 8;
+        let a: Vec<E> = rand_vector(1 << num_variables);
+        let b: Vec<E> = rand_vector(1 << num_variables);
+        let expected = a.iter().zip(b.iter()).fold(E::ZERO, |acc, (&a, &b)| acc + a * b);
+        let mles = vec![
+            MultiLinearPoly::from_evaluations(a.clone()),
+            MultiLinearPoly::from_evaluations(b.clone()),
+        ];
+
+        let seed = [BaseElement::new(42)];
+        let mut coin = DefaultRandomCoin::<Rp64_256>::new(&seed);
+        let (claim, proof, point) = prove_sumcheck(InnerProduct, mles.clone(), &mut coin);
+        assert_eq!(expected, claim);
+
+        let mut coin = DefaultRandomCoin::<Rp64_256>::new(&seed);
+        let result = verify_sumcheck(&InnerProduct, 2, num_variables, claim, &proof, &mut coin);
+        assert_eq!(Ok(point.clone()), result);
+        for (mle, &value) in mles.iter().zip(proof.final_evaluations.iter()) {
+            assert_eq!(mle.evaluate(&point), value);
+        }
+
+        // a wrong claim is rejected
+        let mut coin = DefaultRandomCoin::<Rp64_256>::new(&seed);
+        let result =
+            verify_sumcheck(&InnerProduct, 2, num_variables, claim + E::ONE, &proof, &mut coin);
+        assert_eq!(Err(SumCheckError::FinalEvaluationMismatch), result);
+
+        // a proof with a missing final evaluation is rejected
+        let mut bad_proof = proof.clone();
+        bad_proof.final_evaluations.pop();
+        let mut coin = DefaultRandomCoin::<Rp64_256>::new(&seed);
+        let result = verify_sumcheck(&InnerProduct, 2, num_variables, claim, &bad_proof, &mut coin);
+        assert_eq!(Err(SumCheckError::InvalidNumFinalEvaluations(2, 1)), result);
+    }
+}
@@ -8,7 +8,8 @@ use winterfell::{
 };
 
 
-use super::{BaseElement, FieldElement, ProofOptions};
+use super::{FieldElement, ProofOptions, StarkField};
+use crate::ExampleField;
 use crate::experiment_sha::assertions::{push_input_assertions, push_result_assertions, push_static_assertions, ASSERTIONS_LEN};
 use crate::experiment_sha::transitions::{generate_transitions_degrees, setup_add_transitions, setup_and_transitions, setup_bit_info_transitions, setup_check_bit_transitions, setup_copy_memory_transitions, setup_not_transitions, setup_ror_transitions, setup_setb_transitions, setup_shr_transitions, setup_xor_transitions};
 use crate::experiment_sha::table_constants::{BIT_REGISTERS_LEN, IV_INDICES, REGISTERS_INDICES, VARIABLES_COUNT};
@@ -19,14 +20,14 @@ use crate::experiment_sha::table_constants::TABLE_WIDTH;
 
 // const NUM_ASSERTIONS: usize = 24;
 
-pub struct PublicInputs {
-    pub data: Vec<[BaseElement; 16]>,
-    pub result: Vec<BaseElement>
+pub struct PublicInputs<B: StarkField> {
+    pub data: Vec<[B; 16]>,
+    pub result: Vec<B>
 }
 
-impl ToElements<BaseElement> for PublicInputs {
-    fn to_elements(&self) -> Vec<BaseElement> {
-        let mut elements: Vec<BaseElement> = self.data.clone().into_iter().flatten().collect();
+impl<B: StarkField> ToElements<B> for PublicInputs<B> {
+    fn to_elements(&self) -> Vec<B> {
+        let mut elements: Vec<B> = self.data.clone().into_iter().flatten().collect();
         elements.extend(self.result.clone());
         elements
     }
@@ -37,11 +38,11 @@ impl ToElements<BaseElement> for PublicInputs {
 
 /// Генерирует периодические столбцы для copy_memory_transitions и битовой информации.
 /// Анализирует программу и генерирует соответствующие флаги для каждой переменной.
-fn generate_periodic_columns() -> Vec<Vec<BaseElement>> {
+fn generate_periodic_columns<B: StarkField>() -> Vec<Vec<B>> {
     let program = get_program();
     let mut columns = Vec::new();
 
-    let mut setb2_column = vec![BaseElement::ONE; program.len()];
+    let mut setb2_column = vec![B::ONE; program.len()];
     for step in 0..program.len() {
         for [command, b1] in program[step].clone() {
             if command == SetB::num() {
@@ -53,7 +54,7 @@ fn generate_periodic_columns() -> Vec<Vec<BaseElement>> {
 
     // Для каждой переменной создаем один столбец
     for variable_idx in 0..VARIABLES_COUNT {
-        let mut column = vec![BaseElement::ONE; program.len()];
+        let mut column = vec![B::ONE; program.len()];
         
         // Анализируем каждый шаг программы
         for step in 0..program.len() {
@@ -62,68 +63,68 @@ fn generate_periodic_columns() -> Vec<Vec<BaseElement>> {
                     // ToBin команда
                     cmd if cmd == ToBin::num() => {
                         // Для ToBin: копируем все переменные, кроме тех, которые преобразуются в биты
-                        BaseElement::ONE
+                        B::ONE
                     },
                     cmd if cmd == FromBin::num() => {
                         // Для FromBin: копируем все переменные, кроме тех, которые восстанавливаются из битов
                         if variable_idx == element_to_u32(b1) as usize {
-                            BaseElement::ZERO // Эти переменные изменяются (восстанавливаются из битов)
+                            B::ZERO // Эти переменные изменяются (восстанавливаются из битов)
                         } else {
-                            BaseElement::ONE // Остальные переменные копируются
+                            B::ONE // Остальные переменные копируются
                         }
                     },
                     cmd if cmd == ResetHardMemory::num() => {
                         if IV_INDICES.contains(&variable_idx) {
-                            BaseElement::ONE
+                            B::ONE
                         } else {
-                            BaseElement::ZERO
+                            B::ZERO
                         }
                     },
                     cmd if cmd == XOR::num() || cmd == AND::num() => {
                         if variable_idx == element_to_u32(b1) as usize {
-                            BaseElement::ZERO
+                            B::ZERO
                         } else {
-                            BaseElement::ONE
+                            B::ONE
                         }
                     },
                     cmd if cmd == AddStep1::num() => {
                         if variable_idx == REGISTERS_INDICES[10] || variable_idx == REGISTERS_INDICES[11] {
-                            BaseElement::ZERO
+                            B::ZERO
                         } else {
-                            BaseElement::ONE
+                            B::ONE
                         }
                     },
                     cmd if cmd == AddStep2::num() => {
                         if variable_idx == REGISTERS_INDICES[10] || variable_idx == REGISTERS_INDICES[11] || variable_idx == element_to_u32(b1) as usize {
-                            BaseElement::ZERO
+                            B::ZERO
                         } else {
-                            BaseElement::ONE
+                            B::ONE
                         }
                     },
                     cmd if cmd == SetR10::num() => {
                         if variable_idx == REGISTERS_INDICES[10] {
-                            BaseElement::ZERO
+                            B::ZERO
                         } else {
-                            BaseElement::ONE
+                            B::ONE
                         }
                     },
                     cmd if cmd == SetR11::num() => {
                         if variable_idx == REGISTERS_INDICES[11] {
-                            BaseElement::ZERO
+                            B::ZERO
                         } else {
-                            BaseElement::ONE
+                            B::ONE
                         }
                     },
                     cmd if cmd == SetR11Value::num() => {
                         if variable_idx == REGISTERS_INDICES[11] {
-                            BaseElement::ZERO
+                            B::ZERO
                         } else {
-                            BaseElement::ONE
+                            B::ONE
                         }
                     },
-                    _ => BaseElement::ONE,
+                    _ => B::ONE,
                 };
-                if flag == BaseElement::ZERO {
+                if flag == B::ZERO {
                     column[step] = flag;
                 }
             }
@@ -134,43 +135,43 @@ fn generate_periodic_columns() -> Vec<Vec<BaseElement>> {
     
     // Добавляем флаги для копирования битовых столбцов
     for _ in 0..BIT_REGISTERS_LEN {
-        let mut column = vec![BaseElement::ONE; program.len()];
+        let mut column = vec![B::ONE; program.len()];
         
         // Анализируем каждый шаг программы
         for step in 0..program.len() {
             for [command, _] in program[step].clone() {
                 let flag = match command {
                     cmd if cmd == ToBin::num() => {
-                        BaseElement::ZERO
+                        B::ZERO
                     },
                     cmd if cmd == FromBin::num() => {
-                        BaseElement::ONE
+                        B::ONE
                     },
                     cmd if cmd == XOR::num() => {
-                        BaseElement::ONE
+                        B::ONE
                     },
                     cmd if cmd == AND::num() => {
-                        BaseElement::ONE
+                        B::ONE
                     },
                     cmd if cmd == NOT::num() => {
-                        BaseElement::ZERO
+                        B::ZERO
                     },
                     cmd if cmd == ROR::num() => {
-                        BaseElement::ZERO
+                        B::ZERO
                     },
                     cmd if cmd == SHR::num() => {
-                        BaseElement::ZERO
+                        B::ZERO
                     },
                     cmd if cmd == AddStep1::num() => {
-                        BaseElement::ONE
+                        B::ONE
                     },
                     cmd if cmd == SetB::num() => {
-                        BaseElement::ZERO
+                        B::ZERO
                     },
-                    _ => BaseElement::ONE,
+                    _ => B::ONE,
                 };
             
-                if flag == BaseElement::ZERO {
+                if flag == B::ZERO {
                     column[step] = flag;
                 }
             }
@@ -181,7 +182,7 @@ fn generate_periodic_columns() -> Vec<Vec<BaseElement>> {
     
     // Добавляем битовую информацию для ToBin (разложение в биты)
     for variable_idx in 0..VARIABLES_COUNT {
-        let mut bit_info_column = vec![BaseElement::ZERO; program.len()];
+        let mut bit_info_column = vec![B::ZERO; program.len()];
         
         // Анализируем каждый шаг программы
         for step in 0..program.len() {
@@ -191,14 +192,14 @@ fn generate_periodic_columns() -> Vec<Vec<BaseElement>> {
                     cmd if cmd == ToBin::num() => {
                         // Для ToBin: переменная b1 будет разложена в биты
                         if variable_idx == element_to_u32(b1) as usize {
-                            BaseElement::ONE // Эта переменная будет разложена в биты
+                            B::ONE // Эта переменная будет разложена в биты
                         } else {
-                            BaseElement::ZERO // Эта переменная не будет разложена в биты
+                            B::ZERO // Эта переменная не будет разложена в биты
                         }
                     },
-                    _ => BaseElement::ZERO, // Для других команд не разлагаем в биты
+                    _ => B::ZERO, // Для других команд не разлагаем в биты
                 };
-                if bit_flag == BaseElement::ONE {
+                if bit_flag == B::ONE {
                     bit_info_column[step] = bit_flag;
                 }
             }
@@ -209,7 +210,7 @@ fn generate_periodic_columns() -> Vec<Vec<BaseElement>> {
 
     // Добавляем битовую информацию для FromBin (восстановление из битов)
     for variable_idx in 0..VARIABLES_COUNT {
-        let mut bit_info_column = vec![BaseElement::ZERO; program.len()];
+        let mut bit_info_column = vec![B::ZERO; program.len()];
         
         // Анализируем каждый шаг программы
         for step in 0..program.len() {
@@ -219,15 +220,15 @@ fn generate_periodic_columns() -> Vec<Vec<BaseElement>> {
                     cmd if cmd == FromBin::num() => {
                         // Для FromBin: переменная b1 будет восстановлена из битов
                         if variable_idx == element_to_u32(b1) as usize {
-                            BaseElement::ONE // Эта переменная будет восстановлена из битов
+                            B::ONE // Эта переменная будет восстановлена из битов
                         } else {
-                            BaseElement::ZERO // Эта переменная не будет восстановлена из битов
+                            B::ZERO // Эта переменная не будет восстановлена из битов
                         }
                     },
-                    _ => BaseElement::ZERO, // Для других команд не восстанавливаем из битов
+                    _ => B::ZERO, // Для других команд не восстанавливаем из битов
                 };
             
-                if bit_flag == BaseElement::ONE {
+                if bit_flag == B::ONE {
                     bit_info_column[step] = bit_flag;
                 }
             }
@@ -238,7 +239,7 @@ fn generate_periodic_columns() -> Vec<Vec<BaseElement>> {
     
     for register_idx in REGISTERS_INDICES {
         // Добавляем флаги для XOR переходов
-        let mut xor_column = vec![BaseElement::ZERO; program.len()];
+        let mut xor_column = vec![B::ZERO; program.len()];
         
         // Анализируем каждый шаг программы
         for step in 0..program.len() {
@@ -246,11 +247,11 @@ fn generate_periodic_columns() -> Vec<Vec<BaseElement>> {
                 let xor_flag = match command {
                     cmd if cmd == XOR::num() && register_idx == element_to_u32(b1) as usize => {
                         // Для XOR: активируем переходы для всех битов B1
-                        BaseElement::ONE
+                        B::ONE
                     },
-                    _ => BaseElement::ZERO, // Для других команд не выполняем XOR
+                    _ => B::ZERO, // Для других команд не выполняем XOR
                 };
-                if xor_flag == BaseElement::ONE {
+                if xor_flag == B::ONE {
                     xor_column[step] = xor_flag;
                 }
             }        
@@ -260,7 +261,7 @@ fn generate_periodic_columns() -> Vec<Vec<BaseElement>> {
     
     for register_idx in REGISTERS_INDICES {
         // Добавляем флаги для AND переходов
-        let mut and_column = vec![BaseElement::ZERO; program.len()];
+        let mut and_column = vec![B::ZERO; program.len()];
         
         // Анализируем каждый шаг программы
         for step in 0..program.len() {
@@ -268,12 +269,12 @@ fn generate_periodic_columns() -> Vec<Vec<BaseElement>> {
                 let and_flag = match command {
                     cmd if cmd == AND::num() && register_idx == element_to_u32(b1) as usize => {
                         // Для AND: активируем переходы для всех битов B1
-                        BaseElement::ONE
+                        B::ONE
                     },
-                    _ => BaseElement::ZERO, // Для других команд не выполняем AND
+                    _ => B::ZERO, // Для других команд не выполняем AND
                 };
             
-                if and_flag == BaseElement::ONE {
+                if and_flag == B::ONE {
                     and_column[step] = and_flag;
                 }
             }
@@ -282,7 +283,7 @@ fn generate_periodic_columns() -> Vec<Vec<BaseElement>> {
     }
     
     // Добавляем флаги для NOT переходов
-    let mut not_column = vec![BaseElement::ZERO; program.len()];
+    let mut not_column = vec![B::ZERO; program.len()];
     
     // Анализируем каждый шаг программы
     for step in 0..program.len() {
@@ -290,11 +291,11 @@ fn generate_periodic_columns() -> Vec<Vec<BaseElement>> {
             let not_flag = match command {
                 cmd if cmd == NOT::num() => {
                     // Для NOT: активируем переходы для всех битов B1
-                    BaseElement::ONE
+                    B::ONE
                 },
-                _ => BaseElement::ZERO, // Для других команд не выполняем NOT
+                _ => B::ZERO, // Для других команд не выполняем NOT
             };
-            if not_flag == BaseElement::ONE {
+            if not_flag == B::ONE {
                 not_column[step] = not_flag;
             }
         }
@@ -303,7 +304,7 @@ fn generate_periodic_columns() -> Vec<Vec<BaseElement>> {
     
     for shift in ROR_TRANSITIONS_SHIFTS {
         // Добавляем флаги для ROR переходов
-        let mut ror_column = vec![BaseElement::ZERO; program.len()];
+        let mut ror_column = vec![B::ZERO; program.len()];
         
         // Анализируем каждый шаг программы
         for step in 0..program.len() {
@@ -311,12 +312,12 @@ fn generate_periodic_columns() -> Vec<Vec<BaseElement>> {
                 let ror_flag = match command {
                     cmd if cmd == ROR::num() && element_to_u32(b1) as usize == shift => {
                         // Для ROR: активируем переходы для всех битов B1
-                        BaseElement::ONE
+                        B::ONE
                     },
-                    _ => BaseElement::ZERO, // Для других команд не выполняем ROR
+                    _ => B::ZERO, // Для других команд не выполняем ROR
                 };
             
-                if ror_flag == BaseElement::ONE {
+                if ror_flag == B::ONE {
                     ror_column[step] = ror_flag;
                 }
             }
@@ -325,7 +326,7 @@ fn generate_periodic_columns() -> Vec<Vec<BaseElement>> {
     }
     
     // Добавляем флаги для SHR переходов
-    let mut shr_column = vec![BaseElement::ZERO; program.len()];
+    let mut shr_column = vec![B::ZERO; program.len()];
     
     // Анализируем каждый шаг программы
     for step in 0..program.len() {
@@ -333,30 +334,30 @@ fn generate_periodic_columns() -> Vec<Vec<BaseElement>> {
             let shr_flag = match command {
                 cmd if cmd == SHR::num() => {
                     // Для SHR: активируем переходы для всех битов B1
-                    BaseElement::ONE
+                    B::ONE
                 },
-                _ => BaseElement::ZERO, // Для других команд не выполняем SHR
+                _ => B::ZERO, // Для других команд не выполняем SHR
             };
         
-            if shr_flag == BaseElement::ONE {
+            if shr_flag == B::ONE {
                 shr_column[step] = shr_flag;
             }
         }
     }        
     columns.push(shr_column);
     
-    let mut add_1_column = vec![BaseElement::ZERO; program.len()];
+    let mut add_1_column = vec![B::ZERO; program.len()];
     for step in 0..program.len() {
         for [command, _] in program[step].clone() {
         
             let add_flag = match command {
                 cmd if cmd == AddStep1::num() => {
-                    BaseElement::ONE
+                    B::ONE
                 },
-                _ => BaseElement::ZERO,
+                _ => B::ZERO,
             };
             
-            if add_flag == BaseElement::ONE {
+            if add_flag == B::ONE {
                 add_1_column[step] = add_flag;
             }
         }
@@ -365,17 +366,17 @@ fn generate_periodic_columns() -> Vec<Vec<BaseElement>> {
 
 
     for i in REGISTERS_INDICES {
-        let mut add_2_column = vec![BaseElement::ZERO; program.len()];
+        let mut add_2_column = vec![B::ZERO; program.len()];
         for step in 0..program.len() {
             for [command, b1] in program[step].clone() {
                 let add_flag = match command {
                     cmd if cmd == AddStep2::num() && i == element_to_u32(b1) as usize => {
-                        BaseElement::ONE
+                        B::ONE
                     },
-                    _ => BaseElement::ZERO,
+                    _ => B::ZERO,
                 };
                 
-                if add_flag == BaseElement::ONE {
+                if add_flag == B::ONE {
                     add_2_column[step] = add_flag;
                 }
             }
@@ -384,7 +385,7 @@ fn generate_periodic_columns() -> Vec<Vec<BaseElement>> {
     }
     
     // Добавляем флаги для SetB2 переходов
-    let mut setb2_column = vec![BaseElement::ZERO; program.len()];
+    let mut setb2_column = vec![B::ZERO; program.len()];
     
     // Анализируем каждый шаг программы
     for step in 0..program.len() {
@@ -392,12 +393,12 @@ fn generate_periodic_columns() -> Vec<Vec<BaseElement>> {
             let setb2_flag = match command {
                 cmd if cmd == SetB::num() => {
                     // Для SetB2: активируем переходы для проверки B2
-                    BaseElement::ONE
+                    B::ONE
                 },
-                _ => BaseElement::ZERO, // Для других команд не выполняем SetB2
+                _ => B::ZERO, // Для других команд не выполняем SetB2
             };
             
-            if setb2_flag == BaseElement::ONE {
+            if setb2_flag == B::ONE {
                 setb2_column[step] = setb2_flag;
             }
         }
@@ -410,19 +411,19 @@ fn generate_periodic_columns() -> Vec<Vec<BaseElement>> {
 // EXPERIMENT SHA AIR
 // ================================================================================================
 
-pub struct ExperimentShaAir {
-    context: AirContext<BaseElement>,
-    data: Vec<[BaseElement; 16]>,
-    result: Vec<BaseElement>,
+pub struct ExperimentShaAir<B: ExampleField> {
+    context: AirContext<B>,
+    data: Vec<[B; 16]>,
+    result: Vec<B>,
 }
 
-impl Air for ExperimentShaAir {
-    type BaseField = BaseElement;
-    type PublicInputs = PublicInputs;
+impl<B: ExampleField> Air for ExperimentShaAir<B> {
+    type BaseField = B;
+    type PublicInputs = PublicInputs<B>;
 
     // CONSTRUCTOR
     // --------------------------------------------------------------------------------------------
-    fn new(trace_info: TraceInfo, pub_inputs: PublicInputs, options: ProofOptions) -> Self {
+    fn new(trace_info: TraceInfo, pub_inputs: PublicInputs<B>, options: ProofOptions) -> Self {
         let degrees = generate_transitions_degrees();
         assert_eq!(TABLE_WIDTH, trace_info.width());
         ExperimentShaAir {
@@ -470,7 +471,7 @@ impl Air for ExperimentShaAir {
 
     fn get_periodic_column_values(&self) -> Vec<Vec<Self::BaseField>> {
 
-        debug_assert_eq!(get_program().len(), PROGRAM_LEN);
+        debug_assert_eq!(get_program::<B>().len(), PROGRAM_LEN);
         let mut periodic_columns = Vec::new();
         
         // Добавляем столбцы для команд программы
@@ -2,7 +2,7 @@ use winterfell::Assertion;
 
 use crate::experiment_sha::{table_constants::IV_INDICES, utis::get_iv, vm_program::PROGRAM_LEN};
 
-use super::BaseElement;
+use super::StarkField;
 
 pub const INPUT_ASSERTIONS_LEN: usize = 16;
 pub const RESULT_ASSERTIONS_LEN: usize = 8;
@@ -10,15 +10,15 @@ pub const IV_ASSERTIONS_LEN: usize = 8;
 
 pub const ASSERTIONS_LEN: usize = INPUT_ASSERTIONS_LEN + RESULT_ASSERTIONS_LEN + IV_ASSERTIONS_LEN;
 
-pub fn push_static_assertions(assertions: &mut Vec<Assertion<BaseElement>>) {
+pub fn push_static_assertions<B: StarkField>(assertions: &mut Vec<Assertion<B>>) {
     let iv = get_iv();
     for i in 0..iv.len() {
         assertions.push(Assertion::single(IV_INDICES[i], 0, iv[i]));
     }
 }
 
-pub fn push_input_assertions(input: Vec<[BaseElement; 16]>, assertions: &mut Vec<Assertion<BaseElement>>) {
-    let mut input_assertions: Vec<Vec<BaseElement>> = vec![vec![]; INPUT_ASSERTIONS_LEN];
+pub fn push_input_assertions<B: StarkField>(input: Vec<[B; 16]>, assertions: &mut Vec<Assertion<B>>) {
+    let mut input_assertions: Vec<Vec<B>> = vec![vec![]; INPUT_ASSERTIONS_LEN];
     for i in input {
         for j in 0..i.len() {
             input_assertions[j].push(i[j]);
@@ -29,7 +29,7 @@ pub fn push_input_assertions(input: Vec<[BaseElement; 16]>, assertions: &mut Vec
     }
 }
 
-pub fn push_result_assertions(result: Vec<BaseElement>, assertions: &mut Vec<Assertion<BaseElement>>, last_step: usize) {
+pub fn push_result_assertions<B: StarkField>(result: Vec<B>, assertions: &mut Vec<Assertion<B>>, last_step: usize) {
     for i in 0..RESULT_ASSERTIONS_LEN {
         assertions.push(Assertion::single(IV_INDICES[i], last_step, result[i]));
     }
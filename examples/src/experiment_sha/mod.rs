@@ -7,12 +7,21 @@ use core::marker::PhantomData;
 
 use tracing::{field, info_span};
 use winterfell::{
-    crypto::{DefaultRandomCoin, ElementHasher, MerkleTree},
-    math::{fields::f64::BaseElement, FieldElement},
+    crypto::{
+        hashers::{Blake3_160, Blake3_192, Blake3_224, Blake3_256, Sha2_160, Sha2_192, Sha2_224, Sha2_256, Sha3_256},
+        DefaultRandomCoin, ElementHasher, MerkleCapTree, MerkleTree, VectorCommitment,
+    },
+    math::{
+        fields::{
+            f128::BaseElement as F128BaseElement, f62::BaseElement as F62BaseElement,
+            f64::BaseElement as F64BaseElement,
+        },
+        FieldElement, StarkField,
+    },
     Proof, ProofOptions, Prover, Trace, VerifierError,
 };
 
-use crate::{experiment_sha::{air::PublicInputs, table_constants::TABLE_WIDTH, utis::{bytes_to_elements, prepare_sha_256_block}}, Blake3_192F64, Blake3_256F64, Example, ExampleOptions, HashFunction, Sha3_256F64};
+use crate::{experiment_sha::{air::PublicInputs, table_constants::TABLE_WIDTH, utis::{bytes_to_elements, prepare_sha_256_block}}, Example, ExampleField, ExampleOptions, FieldType, HashFunction};
 
 mod air;
 mod assertions;
@@ -162,44 +171,109 @@ pub fn get_example(
     options: &ExampleOptions,
     string_length: usize,
 ) -> Result<Box<dyn Example>, String> {
+    let field = options.field(FieldType::F64);
+    let (options, hash_fn) = options.to_proof_options(28, 8);
+
+    match field {
+        FieldType::F62 => build_example::<F62BaseElement>(string_length, options, hash_fn),
+        FieldType::F64 => build_example::<F64BaseElement>(string_length, options, hash_fn),
+        FieldType::F128 => build_example::<F128BaseElement>(string_length, options, hash_fn),
+    }
+}
+
+/// Returns the example which commits to the execution trace, constraint evaluations and FRI
+/// layers via [MerkleCapTree] with the default cap height rather than via [MerkleTree].
+///
+/// Only BLAKE3 with 256-bit output is supported.
+pub fn get_merkle_cap_example(
+    options: &ExampleOptions,
+    string_length: usize,
+) -> Result<Box<dyn Example>, String> {
+    let field = options.field(FieldType::F64);
     let (options, hash_fn) = options.to_proof_options(28, 8);
+    if hash_fn != HashFunction::Blake3_256 {
+        return Err("The specified hash function cannot be used with Merkle cap commitments.".to_string());
+    }
 
+    match field {
+        FieldType::F62 => Ok(Box::new(ExperimentShaExample::<
+            Blake3_256<F62BaseElement>,
+            MerkleCapTree<Blake3_256<F62BaseElement>>,
+        >::new(string_length, options))),
+        FieldType::F64 => Ok(Box::new(ExperimentShaExample::<
+            Blake3_256<F64BaseElement>,
+            MerkleCapTree<Blake3_256<F64BaseElement>>,
+        >::new(string_length, options))),
+        FieldType::F128 => Ok(Box::new(ExperimentShaExample::<
+            Blake3_256<F128BaseElement>,
+            MerkleCapTree<Blake3_256<F128BaseElement>>,
+        >::new(string_length, options))),
+    }
+}
+
+fn build_example<B: ExampleField>(
+    string_length: usize,
+    options: ProofOptions,
+    hash_fn: HashFunction,
+) -> Result<Box<dyn Example>, String> {
     match hash_fn {
         HashFunction::Blake3_192 => {
-            Ok(Box::new(ExperimentShaExample::<Blake3_192F64>::new(string_length, options)))
+            Ok(Box::new(ExperimentShaExample::<Blake3_192<B>>::new(string_length, options)))
         },
         HashFunction::Blake3_256 => {
-            Ok(Box::new(ExperimentShaExample::<Blake3_256F64>::new(string_length, options)))
+            Ok(Box::new(ExperimentShaExample::<Blake3_256<B>>::new(string_length, options)))
         },
         HashFunction::Sha3_256 => {
-            Ok(Box::new(ExperimentShaExample::<Sha3_256F64>::new(string_length, options)))
+            Ok(Box::new(ExperimentShaExample::<Sha3_256<B>>::new(string_length, options)))
+        },
+        HashFunction::Sha2_256 => {
+            Ok(Box::new(ExperimentShaExample::<Sha2_256<B>>::new(string_length, options)))
+        },
+        HashFunction::Blake3_160 => {
+            Ok(Box::new(ExperimentShaExample::<Blake3_160<B>>::new(string_length, options)))
+        },
+        HashFunction::Blake3_224 => {
+            Ok(Box::new(ExperimentShaExample::<Blake3_224<B>>::new(string_length, options)))
+        },
+        HashFunction::Sha2_160 => {
+            Ok(Box::new(ExperimentShaExample::<Sha2_160<B>>::new(string_length, options)))
+        },
+        HashFunction::Sha2_192 => {
+            Ok(Box::new(ExperimentShaExample::<Sha2_192<B>>::new(string_length, options)))
+        },
+        HashFunction::Sha2_224 => {
+            Ok(Box::new(ExperimentShaExample::<Sha2_224<B>>::new(string_length, options)))
+        },
+        hash_fn if hash_fn == B::RESCUE_PRIME => {
+            Ok(Box::new(ExperimentShaExample::<B::RescuePrime>::new(string_length, options)))
         },
         _ => Err("The specified hash function cannot be used with this example.".to_string()),
     }
 }
 
-pub struct ExperimentShaExample<H: ElementHasher> {
+pub struct ExperimentShaExample<H: ElementHasher, V = MerkleTree<H>> {
     options: ProofOptions,
-    input_data: Vec<[BaseElement; 16]>,
-    result: Vec<BaseElement>,
+    input_data: Vec<[H::BaseField; 16]>,
+    result: Vec<H::BaseField>,
     _hasher: PhantomData<H>,
+    _vector_commitment: PhantomData<V>,
 }
 
-impl<H: ElementHasher> ExperimentShaExample<H> {
+impl<H: ElementHasher, V> ExperimentShaExample<H, V> {
     pub fn new(string_length: usize, options: ProofOptions) -> Self {
         let input_string = "a".repeat(string_length).to_string();
         let input_data = prepare_sha_256_block(&input_string)
             .chunks(16)
             .map(|chunk| {
-                let arr: [BaseElement; 16] = chunk
+                let arr: [H::BaseField; 16] = chunk
                     .iter()
                     .cloned()
-                    .collect::<Vec<BaseElement>>()
+                    .collect::<Vec<H::BaseField>>()
                     .try_into()
                     .expect("Chunk should have exactly 16 elements");
                 arr
             })
-            .collect::<Vec<[BaseElement; 16]>>();
+            .collect::<Vec<[H::BaseField; 16]>>();
         use sha2::{Sha256, Digest};
         let mut hasher = Sha256::new();
         hasher.update(input_string.as_bytes());
@@ -211,6 +285,7 @@ impl<H: ElementHasher> ExperimentShaExample<H> {
             input_data,
             result: bytes_to_elements(hash_result.as_slice()),
             _hasher: PhantomData,
+            _vector_commitment: PhantomData,
         }
     }
 }
@@ -218,13 +293,15 @@ impl<H: ElementHasher> ExperimentShaExample<H> {
 // EXAMPLE IMPLEMENTATION
 // ================================================================================================
 
-impl<H: ElementHasher> Example for ExperimentShaExample<H>
+impl<H: ElementHasher, V> Example for ExperimentShaExample<H, V>
 where
-    H: ElementHasher<BaseField = BaseElement> + Sync,
+    H: ElementHasher + Sync,
+    H::BaseField: ExampleField,
+    V: VectorCommitment<H> + Sync,
 {
     fn prove(&self) -> Proof {
         // create a prover
-        let prover = ExperimentShaProver::<H>::new(self.options.clone());
+        let prover = ExperimentShaProver::<H, V>::new(self.options.clone());
 
         // generate execution trace
         let trace =
@@ -243,7 +320,7 @@ where
         let acceptable_options =
             winterfell::AcceptableOptions::OptionSet(vec![proof.options().clone()]);
 
-        winterfell::verify::<ExperimentShaAir, H, DefaultRandomCoin<H>, MerkleTree<H>>(
+        winterfell::verify::<ExperimentShaAir<H::BaseField>, H, DefaultRandomCoin<H>, V>(
             proof,
             PublicInputs { data: self.input_data.clone(), result: self.result.clone() },
             &acceptable_options,
@@ -257,17 +334,17 @@ where
         let input_data = prepare_sha_256_block(&input_string)
             .chunks(16)
             .map(|chunk| {
-                let arr: [BaseElement; 16] = chunk
+                let arr: [H::BaseField; 16] = chunk
                     .iter()
                     .cloned()
-                    .collect::<Vec<BaseElement>>()
+                    .collect::<Vec<H::BaseField>>()
                     .try_into()
                     .expect("Chunk should have exactly 16 elements");
                 arr
             })
-            .collect::<Vec<[BaseElement; 16]>>();
+            .collect::<Vec<[H::BaseField; 16]>>();
 
-        winterfell::verify::<ExperimentShaAir, H, DefaultRandomCoin<H>, MerkleTree<H>>(
+        winterfell::verify::<ExperimentShaAir<H::BaseField>, H, DefaultRandomCoin<H>, V>(
             proof,
             PublicInputs { data: input_data, result: self.result.clone() },
             &acceptable_options,
@@ -4,7 +4,7 @@
 // LICENSE file in the root directory of this source tree.
 
 use winterfell::{
-    crypto::MerkleTree, matrix::ColMatrix, AuxRandElements, CompositionPoly, CompositionPolyTrace, ConstraintCompositionCoefficients, DefaultConstraintCommitment, DefaultConstraintEvaluator, DefaultTraceLde, PartitionOptions, StarkDomain, Trace, TraceInfo, TracePolyTable, TraceTable
+    crypto::{MerkleTree, VectorCommitment}, matrix::ColMatrix, AuxRandElements, CompositionPoly, CompositionPolyTrace, ConstraintCompositionCoefficients, DefaultConstraintCommitment, DefaultConstraintEvaluator, DefaultTraceLde, PartitionOptions, StarkDomain, Trace, TraceInfo, TracePolyTable, TraceTable
 };
 
 use crate::experiment_sha::table_constants::{INPUT_BASE_ELEMENTS, IV_INDICES};
@@ -14,7 +14,7 @@ use crate::experiment_sha::{air::PublicInputs, table::set_iv, utis::{element_to_
 use crate::experiment_sha::table_constants::TABLE_WIDTH;
 
 use super::{
-    BaseElement, DefaultRandomCoin, ElementHasher, ExperimentShaAir, FieldElement, PhantomData,
+    DefaultRandomCoin, ElementHasher, ExampleField, ExperimentShaAir, FieldElement, PhantomData,
     ProofOptions, Prover,
 };
 
@@ -23,19 +23,20 @@ use super::{
 // EXPERIMENT SHA PROVER
 // ================================================================================================
 
-pub struct ExperimentShaProver<H: ElementHasher> {
+pub struct ExperimentShaProver<H: ElementHasher, V = MerkleTree<H>> {
     options: ProofOptions,
     _hasher: PhantomData<H>,
+    _vector_commitment: PhantomData<V>,
 }
 
-impl<H: ElementHasher> ExperimentShaProver<H> {
+impl<H: ElementHasher, V> ExperimentShaProver<H, V> {
     pub fn new(options: ProofOptions) -> Self {
-        Self { options, _hasher: PhantomData }
+        Self { options, _hasher: PhantomData, _vector_commitment: PhantomData }
     }
 
     /// Builds an execution trace for computing a sequence of the specified length
-    pub fn build_trace(&self, input_data: PublicInputs) -> TraceTable<BaseElement> {
-        let program = get_program();
+    pub fn build_trace(&self, input_data: PublicInputs<H::BaseField>) -> TraceTable<H::BaseField> {
+        let program = get_program::<H::BaseField>();
         assert_eq!(program.len(), PROGRAM_LEN);
         assert_eq!(input_data.result.len(), 8);
         println!("input_data.data.len() = {}", input_data.data.len());
@@ -54,51 +55,51 @@ impl<H: ElementHasher> ExperimentShaProver<H> {
                 // println!("initial h");
                 // println!("{:?}", state[64..72].iter().map(|x| format!("{:x}", element_to_u32(*x))).collect::<Vec<String>>().join(" "));
             },
-            |step: usize, state: &mut [BaseElement]| {
+            |step: usize, state: &mut [H::BaseField]| {
                 if step % program.len() == program.len() - 1 {
                     for i in 0..input_data.data[(step + 1) / program.len()].len() {
                         state[i] = input_data.data[(step + 1) / program.len()][i];
                     }
                 } else {
-                    let mut initial_state = vec![BaseElement::new(0); state.len()];
+                    let mut initial_state = vec![H::BaseField::from(0u32); state.len()];
                     for i in 0..state.len() {
                         initial_state[i] = state[i];
                     }
                     for [command, b1] in program[step % program.len()].clone() {
-                        if ToBin::num() == command {
+                        if command == ToBin::num() {
                             ToBin::prove(&initial_state, state, element_to_u32(b1) as usize);
-                        } else if FromBin::num() == command {
+                        } else if command == FromBin::num() {
                             FromBin::prove(&initial_state, state, element_to_u32(b1) as usize);
                             /*
-                            if b1 == BaseElement::new(0) {
+                            if b1 == H::BaseField::from(0u32) {
                                 println!("w[0] = {:x}", element_to_u32(state[0]));
                             }
                             */
-                        } else if XOR::num() == command {
+                        } else if command == XOR::num() {
                             XOR::prove(&initial_state, state, element_to_u32(b1) as usize);
-                        } else if AND::num() == command {
+                        } else if command == AND::num() {
                             AND::prove(&initial_state, state, element_to_u32(b1) as usize);
-                        } else if NOT::num() == command {
+                        } else if command == NOT::num() {
                             NOT::prove(&initial_state, state, element_to_u32(b1) as usize);
-                        } else if ROR::num() == command {
+                        } else if command == ROR::num() {
                             ROR::prove(&initial_state, state, element_to_u32(b1) as usize);
-                        } else if SHR::num() == command {
+                        } else if command == SHR::num() {
                             SHR::prove(&initial_state, state, element_to_u32(b1) as usize);
-                        } else if AddStep1::num() == command {
+                        } else if command == AddStep1::num() {
                             AddStep1::prove(&initial_state, state, element_to_u32(b1) as usize);
-                        } else if AddStep2::num() == command {
+                        } else if command == AddStep2::num() {
                             AddStep2::prove(&initial_state, state, element_to_u32(b1) as usize);
-                        } else if SetB::num() == command {
+                        } else if command == SetB::num() {
                             SetB::prove(&initial_state, state, element_to_u32(b1) as usize);
-                        } else if NOP::num() == command {
+                        } else if command == NOP::num() {
                             NOP::prove(&initial_state, state, element_to_u32(b1) as usize);
-                        } else if ResetHardMemory::num() == command {
+                        } else if command == ResetHardMemory::num() {
                             ResetHardMemory::prove(&initial_state, state, element_to_u32(b1) as usize);
-                        } else if SetR10::num() == command {
+                        } else if command == SetR10::num() {
                             SetR10::prove(&initial_state, state, element_to_u32(b1) as usize);
-                        } else if SetR11::num() == command {
+                        } else if command == SetR11::num() {
                             SetR11::prove(&initial_state, state, element_to_u32(b1) as usize);
-                        } else if SetR11Value::num() == command {
+                        } else if command == SetR11Value::num() {
                             SetR11Value::prove(&initial_state, state, element_to_u32(b1) as usize);
                         } else {
                             todo!();
@@ -115,15 +116,17 @@ impl<H: ElementHasher> ExperimentShaProver<H> {
     }
 }
 
-impl<H: ElementHasher> Prover for ExperimentShaProver<H>
+impl<H: ElementHasher, V> Prover for ExperimentShaProver<H, V>
 where
-    H: ElementHasher<BaseField = BaseElement> + Sync,
+    H: ElementHasher + Sync,
+    H::BaseField: ExampleField,
+    V: VectorCommitment<H> + Sync,
 {
-    type BaseField = BaseElement;
-    type Air = ExperimentShaAir;
-    type Trace = TraceTable<BaseElement>;
+    type BaseField = H::BaseField;
+    type Air = ExperimentShaAir<H::BaseField>;
+    type Trace = TraceTable<H::BaseField>;
     type HashFn = H;
-    type VC = MerkleTree<H>;
+    type VC = V;
     type RandomCoin = DefaultRandomCoin<Self::HashFn>;
     type TraceLde<E: FieldElement<BaseField = Self::BaseField>> =
         DefaultTraceLde<E, Self::HashFn, Self::VC>;
@@ -132,8 +135,8 @@ where
     type ConstraintEvaluator<'a, E: FieldElement<BaseField = Self::BaseField>> =
         DefaultConstraintEvaluator<'a, Self::Air, E>;
 
-    fn get_pub_inputs(&self, trace: &Self::Trace) -> PublicInputs {
-        let mut input_data_elements: Vec<[BaseElement; 16]> = vec![[BaseElement::new(0); 16]; trace.length() / PROGRAM_LEN];
+    fn get_pub_inputs(&self, trace: &Self::Trace) -> PublicInputs<H::BaseField> {
+        let mut input_data_elements: Vec<[H::BaseField; 16]> = vec![[H::BaseField::from(0u32); 16]; trace.length() / PROGRAM_LEN];
         for j in 0..trace.length() / PROGRAM_LEN {
             for i in 0..INPUT_BASE_ELEMENTS {
                 input_data_elements[j][i] = trace.get(i, j * PROGRAM_LEN);
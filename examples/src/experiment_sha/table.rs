@@ -1,21 +1,21 @@
 use crate::experiment_sha::utis::element_to_u32;
 use crate::experiment_sha::table_constants::*;
 
-use super::BaseElement;
+use super::StarkField;
 
-pub fn set_to_bit_register(state: &mut [BaseElement], u: BaseElement, b: [usize; BIT_REGISTERS_SIZE]) {
+pub fn set_to_bit_register<B: StarkField>(state: &mut [B], u: B, b: [usize; BIT_REGISTERS_SIZE]) {
     let mut a = element_to_u32(u);
     for i in b {
-        state[i] = BaseElement::new((a & 1) as u64);
+        state[i] = B::from(a & 1);
         a /= 2;
     }
 }
 
 
-pub fn set_from_bit_register(state: &mut [BaseElement], u: usize, b: [usize; BIT_REGISTERS_SIZE]) {
-    let two = BaseElement::new(2);
-    let mut p = BaseElement::new(1);
-    let mut in_register = BaseElement::new(0);
+pub fn set_from_bit_register<B: StarkField>(state: &mut [B], u: usize, b: [usize; BIT_REGISTERS_SIZE]) {
+    let two = B::from(2u32);
+    let mut p = B::from(1u32);
+    let mut in_register = B::from(0u32);
     for i in b {
         in_register += state[i] * p;
         p = two * p;
@@ -23,35 +23,35 @@ pub fn set_from_bit_register(state: &mut [BaseElement], u: usize, b: [usize; BIT
     state[u] = in_register;
 }
 
-pub fn xor_bit_registers(initial_state: &[BaseElement], final_state: &mut [BaseElement], idx: usize) {
-    let two = BaseElement::new(2);
-    let mut p = BaseElement::new(1);
-    let mut in_register = BaseElement::new(0);
+pub fn xor_bit_registers<B: StarkField>(initial_state: &[B], final_state: &mut [B], idx: usize) {
+    let two = B::from(2u32);
+    let mut p = B::from(1u32);
+    let mut in_register = B::from(0u32);
     for i in B1 {
-        in_register += BaseElement::new((element_to_u32(initial_state[i]) ^ element_to_u32(final_state[i])) as u64) * p;
+        in_register += B::from(element_to_u32(initial_state[i]) ^ element_to_u32(final_state[i])) * p;
         p = two * p;
     }
     final_state[idx] = in_register;
 }
 
-pub fn and_bit_registers(initial_state: &[BaseElement], final_state: &mut [BaseElement], idx: usize) {
-    let two = BaseElement::new(2);
-    let mut p = BaseElement::new(1);
-    let mut in_register = BaseElement::new(0);
+pub fn and_bit_registers<B: StarkField>(initial_state: &[B], final_state: &mut [B], idx: usize) {
+    let two = B::from(2u32);
+    let mut p = B::from(1u32);
+    let mut in_register = B::from(0u32);
     for i in B1 {
-        in_register += BaseElement::new((element_to_u32(initial_state[i]) & element_to_u32(final_state[i])) as u64) * p;
+        in_register += B::from(element_to_u32(initial_state[i]) & element_to_u32(final_state[i])) * p;
         p = two * p;
     }
     final_state[idx] = in_register;
 }
 
-pub fn not_bit_registers(state: &mut [BaseElement]) {
+pub fn not_bit_registers<B: StarkField>(state: &mut [B]) {
     for i in B1 {
-        state[i] = BaseElement::new((1 - element_to_u32(state[i])) as u64);
+        state[i] = B::from(1 - element_to_u32(state[i]));
     }
 }
 
-pub fn ror_bit_registers(state: &mut [BaseElement], shift: usize) {
+pub fn ror_bit_registers<B: StarkField>(state: &mut [B], shift: usize) {
     // Циклический сдвиг вправо: младший бит становится старшим
     let mut bits = [0u32; BIT_REGISTERS_SIZE];
     
@@ -67,11 +67,11 @@ pub fn ror_bit_registers(state: &mut [BaseElement], shift: usize) {
     
     // Записываем результат обратно в B1
     for (i, &idx) in B1.iter().enumerate() {
-        state[idx] = BaseElement::new(new_bits[i] as u64);
+        state[idx] = B::from(new_bits[i]);
     }
 }
 
-pub fn shr_bit_registers(state: &mut [BaseElement]) {
+pub fn shr_bit_registers<B: StarkField>(state: &mut [B]) {
     // Сдвиг вправо (деление на 2): старший бит становится 0
     let mut bits = [0u32; BIT_REGISTERS_SIZE];
     
@@ -88,11 +88,11 @@ pub fn shr_bit_registers(state: &mut [BaseElement]) {
     
     // Записываем результат обратно в B1
     for (i, &idx) in B1.iter().enumerate() {
-        state[idx] = BaseElement::new(bits[i] as u64);
+        state[idx] = B::from(bits[i]);
     }
 }
 
-pub fn set_iv(state: &mut [BaseElement], iv: [BaseElement; IV_LEN]) {
+pub fn set_iv<B: StarkField>(state: &mut [B], iv: [B; IV_LEN]) {
     for i in 0..iv.len() {
         state[IV_INDICES[i]] = iv[i];
     }
@@ -7,7 +7,6 @@ use crate::experiment_sha::vm_program::SetB;
 use crate::experiment_sha::vm_program::{Command, FromBin, ToBin, PROGRAM_LEN, XOR, AND, NOT, ROR, SHR, AddStep1};
 use crate::utils::{is_binary, are_equal, EvaluationResult};
 
-use super::BaseElement;
 use super::FieldElement;
 
 
@@ -37,7 +36,7 @@ pub fn generate_transitions_degrees() -> Vec<TransitionConstraintDegree> {
     degrees
 }
 
-pub fn setup_copy_memory_transitions<E: FieldElement + From<BaseElement>>(transitions: &mut [E], current_frame: &[E], next_frame: &[E], periodic_values: &[E]) {
+pub fn setup_copy_memory_transitions<E: FieldElement>(transitions: &mut [E], current_frame: &[E], next_frame: &[E], periodic_values: &[E]) {
     // Используем периодические значения как флаги для активации переходов
     let mut periodic_idx = SET_B2_VALUES;
     
@@ -68,42 +67,42 @@ pub fn setup_copy_memory_transitions<E: FieldElement + From<BaseElement>>(transi
 }
 
  // TODO uncomment https://github.com/facebook/winterfell/blob/2f78ee9bf667a561bdfcdfa68668d0f9b18b8315/prover/src/constraints/evaluation_table.rs#L214. 
-pub fn setup_check_bit_transitions<E: FieldElement + From<BaseElement>>(transitions: &mut [E], _: &[E], next_frame: &[E]) {
+pub fn setup_check_bit_transitions<E: FieldElement>(transitions: &mut [E], _: &[E], next_frame: &[E]) {
     for i in 0..CHECK_BIT_TRANSITIONS_LEN {
         transitions[CHECK_BIT_TRANSITIONS_INDICES[i]] = is_binary(next_frame[BIT_REGISTERS_START + i]); // TODO Think next or current?
     }
 }
 
-pub fn setup_bit_info_transitions<E: FieldElement + From<BaseElement>>(transitions: &mut [E], current_frame: &[E], next_frame: &[E], periodic_values: &[E]) {
+pub fn setup_bit_info_transitions<E: FieldElement>(transitions: &mut [E], current_frame: &[E], next_frame: &[E], periodic_values: &[E]) {
     ToBin::eval_transitions(transitions, current_frame, next_frame, periodic_values);
     FromBin::eval_transitions(transitions, current_frame, next_frame, periodic_values);
 }
 
-pub fn setup_xor_transitions<E: FieldElement + From<BaseElement>>(transitions: &mut [E], current_frame: &[E], next_frame: &[E], periodic_values: &[E]) {
+pub fn setup_xor_transitions<E: FieldElement>(transitions: &mut [E], current_frame: &[E], next_frame: &[E], periodic_values: &[E]) {
     XOR::eval_transitions(transitions, current_frame, next_frame, periodic_values)
 }
 
-pub fn setup_and_transitions<E: FieldElement + From<BaseElement>>(transitions: &mut [E], current_frame: &[E], next_frame: &[E], periodic_values: &[E]) {
+pub fn setup_and_transitions<E: FieldElement>(transitions: &mut [E], current_frame: &[E], next_frame: &[E], periodic_values: &[E]) {
     AND::eval_transitions(transitions, current_frame, next_frame, periodic_values);
 }
 
-pub fn setup_not_transitions<E: FieldElement + From<BaseElement>>(transitions: &mut [E], current_frame: &[E], next_frame: &[E], periodic_values: &[E]) {
+pub fn setup_not_transitions<E: FieldElement>(transitions: &mut [E], current_frame: &[E], next_frame: &[E], periodic_values: &[E]) {
     NOT::eval_transitions(transitions, current_frame, next_frame, periodic_values);
 }
 
-pub fn setup_ror_transitions<E: FieldElement + From<BaseElement>>(transitions: &mut [E], current_frame: &[E], next_frame: &[E], periodic_values: &[E]) {
+pub fn setup_ror_transitions<E: FieldElement>(transitions: &mut [E], current_frame: &[E], next_frame: &[E], periodic_values: &[E]) {
     ROR::eval_transitions(transitions, current_frame, next_frame, periodic_values);
 }
 
-pub fn setup_shr_transitions<E: FieldElement + From<BaseElement>>(transitions: &mut [E], current_frame: &[E], next_frame: &[E], periodic_values: &[E]) {
+pub fn setup_shr_transitions<E: FieldElement>(transitions: &mut [E], current_frame: &[E], next_frame: &[E], periodic_values: &[E]) {
     SHR::eval_transitions(transitions, current_frame, next_frame, periodic_values);
 }
 
-pub fn setup_add_transitions<E: FieldElement + From<BaseElement>>(transitions: &mut [E], current_frame: &[E], next_frame: &[E], periodic_values: &[E]) {
+pub fn setup_add_transitions<E: FieldElement>(transitions: &mut [E], current_frame: &[E], next_frame: &[E], periodic_values: &[E]) {
     AddStep1::eval_transitions(transitions, current_frame, next_frame, periodic_values);
     AddStep2::eval_transitions(transitions, current_frame, next_frame, periodic_values);
 }
 
-pub fn setup_setb_transitions<E: FieldElement + From<BaseElement>>(transitions: &mut [E], current_frame: &[E], next_frame: &[E], periodic_values: &[E]) {
+pub fn setup_setb_transitions<E: FieldElement>(transitions: &mut [E], current_frame: &[E], next_frame: &[E], periodic_values: &[E]) {
     SetB::eval_transitions(transitions, current_frame, next_frame, periodic_values);
 }
@@ -1,9 +1,9 @@
 use crate::experiment_sha::table_constants::IV_INDICES;
 
-use super::BaseElement;
+use super::StarkField;
 use super::FieldElement;
 
-pub fn extend_sha256_block(data: Vec<BaseElement>) -> Vec<BaseElement> {
+pub fn extend_sha256_block<B: StarkField>(data: Vec<B>) -> Vec<B> {
     let mut w = data.iter().map(|e| element_to_u32(*e)).collect::<Vec<_>>();
     w.append(&mut vec![0u32; 48]);
 
@@ -13,10 +13,10 @@ pub fn extend_sha256_block(data: Vec<BaseElement>) -> Vec<BaseElement> {
         let s1 = (w[i - 2].rotate_right(17)) ^ (w[i - 2].rotate_right(19)) ^ (w[i - 2] >> 10);
         w[i] = w[i - 16].wrapping_add(s0).wrapping_add(w[i - 7]).wrapping_add(s1);
     }
-    w.iter().map(|e| BaseElement::new(*e as u64)).collect()
+    w.iter().map(|e| B::from(*e)).collect()
 }
 
-pub fn prepare_sha_256_block(s: &String) -> Vec<BaseElement> {
+pub fn prepare_sha_256_block<B: StarkField>(s: &String) -> Vec<B> {
     let mut m = s.as_bytes().to_vec();
     m.push(0x80);
     if 64 - m.len() % 64 < 8 {
@@ -28,30 +28,30 @@ pub fn prepare_sha_256_block(s: &String) -> Vec<BaseElement> {
     bytes_to_elements(&m)
 }
 
-pub fn bytes_to_elements(s: &[u8]) -> Vec<BaseElement> {
-    s.chunks_exact(4).map(|chunk| BaseElement::new(u32::from_be_bytes(chunk.try_into().unwrap()) as u64)).collect()
+pub fn bytes_to_elements<B: StarkField>(s: &[u8]) -> Vec<B> {
+    s.chunks_exact(4).map(|chunk| B::from(u32::from_be_bytes(chunk.try_into().unwrap()))).collect()
 }
 
-pub fn string_to_elements(s: &String) -> Vec<BaseElement> {
+pub fn string_to_elements<B: StarkField>(s: &String) -> Vec<B> {
     bytes_to_elements(s.as_bytes())
 }
 
-pub fn element_to_u32<E: FieldElement + From<BaseElement>>(e: E) -> u32 {
+pub fn element_to_u32<E: FieldElement>(e: E) -> u32 {
     u32::from_le_bytes(e.to_bytes()[..4].try_into().unwrap())
 }
 
-pub fn elements_to_string(v: Vec<BaseElement>) -> String {
+pub fn elements_to_string<B: StarkField>(v: Vec<B>) -> String {
     String::from_utf8(v.iter().map(|e| element_to_u32(*e).to_le_bytes()).collect::<Vec<_>>().concat()).unwrap()
 }
 
-pub fn get_iv() -> [BaseElement; 8] {
+pub fn get_iv<B: StarkField>() -> [B; 8] {
     [
         0x6a09e667u32, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a,
         0x510e527f, 0x9b05688c, 0x1f83d9ab, 0x5be0cd19,
-    ].map(|u| BaseElement::new(u as u64)).to_vec().try_into().unwrap()
+    ].map(|u| B::from(u)).to_vec().try_into().unwrap()
 }
 
-pub fn transpose<const M: usize, const N: usize>(input: [[BaseElement; N]; M]) -> [[BaseElement; M]; N] {
+pub fn transpose<B: StarkField, const M: usize, const N: usize>(input: [[B; N]; M]) -> [[B; M]; N] {
     let mut output = [[input[0][0]; M]; N];
     for i in 0..N {
         for j in 0..M {
@@ -62,7 +62,7 @@ pub fn transpose<const M: usize, const N: usize>(input: [[BaseElement; N]; M]) -
     output
 }
 
-pub fn transpose_vec(input: Vec<[BaseElement; 2]>) -> [Vec<BaseElement>; 2] {
+pub fn transpose_vec<B: StarkField>(input: Vec<[B; 2]>) -> [Vec<B>; 2] {
     let mut output = [Vec::new(), Vec::new()];
     for row in input {
         output[0].push(row[0]);
@@ -71,7 +71,7 @@ pub fn transpose_vec(input: Vec<[BaseElement; 2]>) -> [Vec<BaseElement>; 2] {
     output
 }
 
-pub fn extract_hash(state: &[BaseElement]) -> Vec<u8> {
+pub fn extract_hash<B: StarkField>(state: &[B]) -> Vec<u8> {
     let mut hash = Vec::with_capacity(32);
     for i in IV_INDICES {
         hash.extend_from_slice(&element_to_u32(state[i]).to_be_bytes());
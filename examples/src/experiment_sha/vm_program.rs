@@ -3,20 +3,20 @@ use crate::{experiment_sha::{table::{and_bit_registers, not_bit_registers, ror_b
 use crate::experiment_sha::transitions_constants::*;
 use crate::experiment_sha::table_constants::*;
 
-use super::{BaseElement, FieldElement};
+use super::{FieldElement, StarkField};
 
 pub trait Command {
-    fn num() -> BaseElement;
-    fn eval_transitions<E: FieldElement + From<BaseElement>>(transitions: &mut [E], current_frame: &[E], next_frame: &[E], periodic_values: &[E]);
-    fn prove(initial_state: &[BaseElement], final_state: &mut [BaseElement], b1: usize);
+    fn num<B: StarkField>() -> B;
+    fn eval_transitions<E: FieldElement>(transitions: &mut [E], current_frame: &[E], next_frame: &[E], periodic_values: &[E]);
+    fn prove<B: StarkField>(initial_state: &[B], final_state: &mut [B], b1: usize);
 }
 
 pub struct ToBin;
 impl ToBin {
-    fn eval_single_transition<E: FieldElement + From<BaseElement>>(current_frame: &[E], next_frame: &[E], idx: usize) -> E{
-        let two = E::from(BaseElement::new(2));
-        let mut p = E::from(BaseElement::new(1));
-        let mut in_register = E::from(BaseElement::new(0));
+    fn eval_single_transition<E: FieldElement>(current_frame: &[E], next_frame: &[E], idx: usize) -> E{
+        let two = E::from(2u32);
+        let mut p = E::from(1u32);
+        let mut in_register = E::from(0u32);
         for i in B1 {
             in_register += next_frame[i] * p;
             p = two * p;
@@ -26,10 +26,10 @@ impl ToBin {
 }
 
 impl Command for ToBin {
-    fn num() -> BaseElement {
-        BaseElement::new(0)
+    fn num<B: StarkField>() -> B {
+        B::from(0u32)
     }
-    fn eval_transitions<E: FieldElement + From<BaseElement>>(transitions: &mut [E], current_frame: &[E], next_frame: &[E], periodic_values: &[E]){
+    fn eval_transitions<E: FieldElement>(transitions: &mut [E], current_frame: &[E], next_frame: &[E], periodic_values: &[E]){
         for i in 0..TO_B1_TRANSITIONS_LEN {
             let flag = periodic_values[PERIODIC_TO_B1_COLUMNS_INDICES[i]];
             let constraint_value = ToBin::eval_single_transition(current_frame, next_frame, i);            
@@ -38,7 +38,7 @@ impl Command for ToBin {
         
     }
     
-    fn prove(_: &[BaseElement], final_state: &mut [BaseElement], b1: usize) {
+    fn prove<B: StarkField>(_: &[B], final_state: &mut [B], b1: usize) {
         set_to_bit_register(final_state, final_state[b1], B1);
     }
 }
@@ -46,10 +46,10 @@ impl Command for ToBin {
 pub struct FromBin;
 
 impl FromBin {
-    fn eval_single_transition<E: FieldElement + From<BaseElement>>(next_frame: &[E], idx: usize) -> E {
-        let two = E::from(BaseElement::new(2));
-        let mut p = E::from(BaseElement::new(1));
-        let mut in_register = E::from(BaseElement::new(0));
+    fn eval_single_transition<E: FieldElement>(next_frame: &[E], idx: usize) -> E {
+        let two = E::from(2u32);
+        let mut p = E::from(1u32);
+        let mut in_register = E::from(0u32);
         for i in B1 {
             in_register += next_frame[i] * p;
             p = two * p;
@@ -60,10 +60,10 @@ impl FromBin {
 
 
 impl Command for FromBin {
-    fn num() -> BaseElement {
-        BaseElement::new(1)
+    fn num<B: StarkField>() -> B {
+        B::from(1u32)
     }
-    fn eval_transitions<E: FieldElement + From<BaseElement>>(transitions: &mut [E], _: &[E], next_frame: &[E], periodic_values: &[E]) {
+    fn eval_transitions<E: FieldElement>(transitions: &mut [E], _: &[E], next_frame: &[E], periodic_values: &[E]) {
         for i in 0..FROM_BIN_TRANSITIONS_LEN {            
             let flag = periodic_values[PERIODIC_FROM_BIN_COLUMNS_INDICES[i]];
             let constraint_value = FromBin::eval_single_transition(next_frame, i);
@@ -71,7 +71,7 @@ impl Command for FromBin {
         }
     }
     
-    fn prove(_: &[BaseElement], final_state: &mut [BaseElement], b1: usize) {
+    fn prove<B: StarkField>(_: &[B], final_state: &mut [B], b1: usize) {
         set_from_bit_register(final_state, b1, B1);
     }
 }
@@ -79,12 +79,12 @@ impl Command for FromBin {
 pub struct XOR;
 
 impl XOR {
-    fn eval_single_transition<E: FieldElement + From<BaseElement>>(current_frame: &[E], next_frame: &[E], idx: usize) -> E {
-        let two = E::from(BaseElement::new(2));
-        let mut p = E::from(BaseElement::new(1));
-        let mut in_register = E::from(BaseElement::new(0));
+    fn eval_single_transition<E: FieldElement>(current_frame: &[E], next_frame: &[E], idx: usize) -> E {
+        let two = E::from(2u32);
+        let mut p = E::from(1u32);
+        let mut in_register = E::from(0u32);
         for i in B1 {
-            in_register += (current_frame[i] + next_frame[i] - E::from(BaseElement::new(2)) * current_frame[i] * next_frame[i]) * p;
+            in_register += (current_frame[i] + next_frame[i] - E::from(2u32) * current_frame[i] * next_frame[i]) * p;
             p = two * p;
         }
         in_register - next_frame[idx]
@@ -92,11 +92,11 @@ impl XOR {
 }
 
 impl Command for XOR {
-    fn num() -> BaseElement {
-        BaseElement::new(2)
+    fn num<B: StarkField>() -> B {
+        B::from(2u32)
     }
     
-    fn eval_transitions<E: FieldElement + From<BaseElement>>(transitions: &mut [E], current_frame: &[E], next_frame: &[E], periodic_values: &[E]) {
+    fn eval_transitions<E: FieldElement>(transitions: &mut [E], current_frame: &[E], next_frame: &[E], periodic_values: &[E]) {
         for i in 0..XOR_TRANSITIONS_LEN {
             let flag = periodic_values[PERIODIC_XOR_COLUMNS_INDICES[i]];
             let constraint_value = XOR::eval_single_transition(current_frame, next_frame, REGISTERS_INDICES[i]);
@@ -104,7 +104,7 @@ impl Command for XOR {
         }
     }
     
-    fn prove(initial_state: &[BaseElement], final_state: &mut [BaseElement], idx: usize) {
+    fn prove<B: StarkField>(initial_state: &[B], final_state: &mut [B], idx: usize) {
         xor_bit_registers(initial_state, final_state, idx);
     }
 }
@@ -112,10 +112,10 @@ impl Command for XOR {
 pub struct AND;
 
 impl AND {
-    fn eval_single_transition<E: FieldElement + From<BaseElement>>(current_frame: &[E], next_frame: &[E], idx: usize) -> E {
-        let two = E::from(BaseElement::new(2));
-        let mut p = E::from(BaseElement::new(1));
-        let mut in_register = E::from(BaseElement::new(0));
+    fn eval_single_transition<E: FieldElement>(current_frame: &[E], next_frame: &[E], idx: usize) -> E {
+        let two = E::from(2u32);
+        let mut p = E::from(1u32);
+        let mut in_register = E::from(0u32);
         for i in B1 {
             in_register += (current_frame[i] * next_frame[i]) * p;
             p = two * p;
@@ -125,11 +125,11 @@ impl AND {
 }
 
 impl Command for AND {
-    fn num() -> BaseElement {
-        BaseElement::new(3)
+    fn num<B: StarkField>() -> B {
+        B::from(3u32)
     }
     
-    fn eval_transitions<E: FieldElement + From<BaseElement>>(transitions: &mut [E], current_frame: &[E], next_frame: &[E], periodic_values: &[E]) {
+    fn eval_transitions<E: FieldElement>(transitions: &mut [E], current_frame: &[E], next_frame: &[E], periodic_values: &[E]) {
         for i in 0..AND_TRANSITIONS_LEN {
             let flag = periodic_values[PERIODIC_AND_COLUMNS_INDICES[i]];
             let constraint_value = AND::eval_single_transition(current_frame, next_frame, REGISTERS_INDICES[i]);
@@ -137,7 +137,7 @@ impl Command for AND {
         }
     }
     
-    fn prove(initial_state: &[BaseElement], final_state: &mut [BaseElement], idx: usize) {
+    fn prove<B: StarkField>(initial_state: &[B], final_state: &mut [B], idx: usize) {
         and_bit_registers(initial_state, final_state, idx);
     }
 }
@@ -145,21 +145,21 @@ impl Command for AND {
 pub struct NOT;
 
 impl NOT {
-    fn eval_single_transition<E: FieldElement + From<BaseElement>>(current_frame: &[E], next_frame: &[E], idx: usize) -> E {
+    fn eval_single_transition<E: FieldElement>(current_frame: &[E], next_frame: &[E], idx: usize) -> E {
         let b1_current = current_frame[B1[idx]];
         let b1_next = next_frame[B1[idx]];
-        let not_result = E::from(BaseElement::new(1)) - b1_current;
+        let not_result = E::from(1u32) - b1_current;
         
         b1_next - not_result
     }
 }
 
 impl Command for NOT {
-    fn num() -> BaseElement {
-        BaseElement::new(4)
+    fn num<B: StarkField>() -> B {
+        B::from(4u32)
     }
     
-    fn eval_transitions<E: FieldElement + From<BaseElement>>(transitions: &mut [E], current_frame: &[E], next_frame: &[E], periodic_values: &[E]) {
+    fn eval_transitions<E: FieldElement>(transitions: &mut [E], current_frame: &[E], next_frame: &[E], periodic_values: &[E]) {
         let flag = periodic_values[PERIODIC_NOT_COLUMNS_INDICES[0]];
         for i in 0..NOT_TRANSITIONS_LEN {
             let constraint_value = NOT::eval_single_transition(current_frame, next_frame, i);
@@ -167,7 +167,7 @@ impl Command for NOT {
         }
     }
     
-    fn prove(_: &[BaseElement], final_state: &mut [BaseElement], _: usize) {
+    fn prove<B: StarkField>(_: &[B], final_state: &mut [B], _: usize) {
         // Выполняем NOT для B1, результат сохраняем в B1
         not_bit_registers(final_state);
     }
@@ -176,17 +176,17 @@ impl Command for NOT {
 pub struct ROR;
 
 impl ROR {
-    fn eval_single_transition<E: FieldElement + From<BaseElement>>(current_frame: &[E], next_frame: &[E], idx: usize) -> E {
-        let two = E::from(BaseElement::new(2));
-        let mut p = E::from(BaseElement::new(1));
-        let mut current_register = E::from(BaseElement::new(0));
+    fn eval_single_transition<E: FieldElement>(current_frame: &[E], next_frame: &[E], idx: usize) -> E {
+        let two = E::from(2u32);
+        let mut p = E::from(1u32);
+        let mut current_register = E::from(0u32);
         for i in 0..B1.len() {
             current_register += current_frame[B1[(i + idx) % B1.len()]] * p;
             p = two * p;
         }
 
-        p = E::from(BaseElement::new(1));
-        let mut next_register = E::from(BaseElement::new(0));
+        p = E::from(1u32);
+        let mut next_register = E::from(0u32);
         for i in B1 {
             next_register += next_frame[i] * p;
             p = two * p;
@@ -196,11 +196,11 @@ impl ROR {
 }
 
 impl Command for ROR {
-    fn num() -> BaseElement {
-        BaseElement::new(5)
+    fn num<B: StarkField>() -> B {
+        B::from(5u32)
     }
     
-    fn eval_transitions<E: FieldElement + From<BaseElement>>(transitions: &mut [E], current_frame: &[E], next_frame: &[E], periodic_values: &[E]) {
+    fn eval_transitions<E: FieldElement>(transitions: &mut [E], current_frame: &[E], next_frame: &[E], periodic_values: &[E]) {
         for i in 0..ROR_TRANSITIONS_LEN {
             let flag = periodic_values[PERIODIC_ROR_COLUMNS_INDICES[i]];
             let constraint_value = ROR::eval_single_transition(current_frame, next_frame, ROR_TRANSITIONS_SHIFTS[i]);
@@ -208,7 +208,7 @@ impl Command for ROR {
         }
     }
     
-    fn prove(_: &[BaseElement], final_state: &mut [BaseElement], shift: usize) {
+    fn prove<B: StarkField>(_: &[B], final_state: &mut [B], shift: usize) {
         // Выполняем циклический сдвиг вправо для B1, результат сохраняем в B1
         ror_bit_registers(final_state, shift);
     }
@@ -217,10 +217,10 @@ impl Command for ROR {
 pub struct SHR;
 
 impl SHR {
-    fn eval_single_transition<E: FieldElement + From<BaseElement>>(current_frame: &[E], next_frame: &[E], idx: usize) -> E {
+    fn eval_single_transition<E: FieldElement>(current_frame: &[E], next_frame: &[E], idx: usize) -> E {
         let b1_next = next_frame[B1[idx]];
         let expected_value = if idx == 31 {
-            E::from(BaseElement::new(0))
+            E::from(0u32)
         } else {
             current_frame[B1[idx + 1]]
         };
@@ -230,11 +230,11 @@ impl SHR {
 }
 
 impl Command for SHR {
-    fn num() -> BaseElement {
-        BaseElement::new(6)
+    fn num<B: StarkField>() -> B {
+        B::from(6u32)
     }
     
-    fn eval_transitions<E: FieldElement + From<BaseElement>>(transitions: &mut [E], current_frame: &[E], next_frame: &[E], periodic_values: &[E]) {
+    fn eval_transitions<E: FieldElement>(transitions: &mut [E], current_frame: &[E], next_frame: &[E], periodic_values: &[E]) {
         let flag = periodic_values[PERIODIC_SHR_COLUMNS_INDICES[0]];
         
         // Обрабатываем SHR transitions
@@ -244,7 +244,7 @@ impl Command for SHR {
         }
     }
     
-    fn prove(_: &[BaseElement], final_state: &mut [BaseElement], _: usize) {
+    fn prove<B: StarkField>(_: &[B], final_state: &mut [B], _: usize) {
         // Выполняем сдвиг вправо (деление на 2) для B1, результат сохраняем в B1
         shr_bit_registers(final_state);
     }
@@ -253,66 +253,66 @@ impl Command for SHR {
 pub struct AddStep1;
 
 impl Command for AddStep1 {
-    fn num() -> BaseElement {
-        BaseElement::new(7)
+    fn num<B: StarkField>() -> B {
+        B::from(7u32)
     }
     
-    fn eval_transitions<E: FieldElement + From<BaseElement>>(transitions: &mut [E], current_frame: &[E], next_frame: &[E], periodic_values: &[E]) {
+    fn eval_transitions<E: FieldElement>(transitions: &mut [E], current_frame: &[E], next_frame: &[E], periodic_values: &[E]) {
         let flag = periodic_values[PERIODIC_ADD_1_COLUMNS_INDICES[0]];
-        let two = E::from(BaseElement::new(2));
-        let mut p = E::from(BaseElement::new(1));
-        let mut in_register = E::from(BaseElement::new(0));
+        let two = E::from(2u32);
+        let mut p = E::from(1u32);
+        let mut in_register = E::from(0u32);
         for i in 0..B1.len()-1 {
             in_register += current_frame[B1[i]] * p;
             p = two * p;
         }
         transitions.agg_constraint(ADD_1_TRANSITIONS_INDICES[0], flag, in_register - current_frame[REGISTERS_INDICES[10]]);
         
-        p = E::from(BaseElement::new(1));
-        in_register = E::from(BaseElement::new(0));
+        p = E::from(1u32);
+        in_register = E::from(0u32);
         for i in 0..B1.len()-1 {
             in_register += next_frame[B1[i]] * p;
             p = two * p;
         }
         transitions.agg_constraint(ADD_1_TRANSITIONS_INDICES[1], flag, in_register - current_frame[REGISTERS_INDICES[11]]);
         transitions.agg_constraint(ADD_1_TRANSITIONS_INDICES[2], flag, next_frame[REGISTERS_INDICES[10]] - (current_frame[REGISTERS_INDICES[10]] + current_frame[REGISTERS_INDICES[11]]));
-        transitions.agg_constraint(ADD_1_TRANSITIONS_INDICES[3], flag, are_equal(next_frame[REGISTERS_INDICES[11]], current_frame[B1[B1.len() - 1]] + next_frame[B1[B1.len() - 1]] - E::from(BaseElement::new(2)) * current_frame[B1[B1.len() - 1]] * next_frame[B1[B1.len() - 1]]));
+        transitions.agg_constraint(ADD_1_TRANSITIONS_INDICES[3], flag, are_equal(next_frame[REGISTERS_INDICES[11]], current_frame[B1[B1.len() - 1]] + next_frame[B1[B1.len() - 1]] - E::from(2u32) * current_frame[B1[B1.len() - 1]] * next_frame[B1[B1.len() - 1]]));
     }
     
-    fn prove(initial_state: &[BaseElement], final_state: &mut [BaseElement], _: usize) {
-        final_state[REGISTERS_INDICES[10]] = BaseElement::new((element_to_u32(initial_state[REGISTERS_INDICES[10]]) as u64 + element_to_u32(initial_state[REGISTERS_INDICES[11]]) as u64) as u64);
-        final_state[REGISTERS_INDICES[11]] = BaseElement::new((element_to_u32(initial_state[B1[BIT_REGISTERS_LEN - 1]]) ^ element_to_u32(final_state[B1[BIT_REGISTERS_LEN - 1]])) as u64);    
+    fn prove<B: StarkField>(initial_state: &[B], final_state: &mut [B], _: usize) {
+        final_state[REGISTERS_INDICES[10]] = B::from(element_to_u32(initial_state[REGISTERS_INDICES[10]])) + B::from(element_to_u32(initial_state[REGISTERS_INDICES[11]]));
+        final_state[REGISTERS_INDICES[11]] = B::from(element_to_u32(initial_state[B1[BIT_REGISTERS_LEN - 1]]) ^ element_to_u32(final_state[B1[BIT_REGISTERS_LEN - 1]]));    
     }
 }
 
 pub struct AddStep2;
 
 impl Command for AddStep2 {
-    fn num() -> BaseElement {
-        BaseElement::new(8)
+    fn num<B: StarkField>() -> B {
+        B::from(8u32)
     }
     
-    fn eval_transitions<E: FieldElement + From<BaseElement>>(transitions: &mut [E], current_frame: &[E], next_frame: &[E], periodic_values: &[E]) {
+    fn eval_transitions<E: FieldElement>(transitions: &mut [E], current_frame: &[E], next_frame: &[E], periodic_values: &[E]) {
         for i in 0..PERIODIC_ADD_2_COLUMNS_LEN {
             let flag = periodic_values[PERIODIC_ADD_2_COLUMNS_INDICES[i]];
-            let two = E::from(BaseElement::new(2));
-            let mut p = E::from(BaseElement::new(1));
-            let mut in_register = E::from(BaseElement::new(0));
+            let two = E::from(2u32);
+            let mut p = E::from(1u32);
+            let mut in_register = E::from(0u32);
             for i in 0..B1.len()-1 {
                 in_register += next_frame[B1[i]] * p;
                 p = two * p;
             }
             transitions.agg_constraint(ADD_2_TRANSITIONS_INDICES[0], flag, in_register - next_frame[REGISTERS_INDICES[10]]);
 
-            transitions.agg_constraint(ADD_2_TRANSITIONS_INDICES[1], flag, are_equal(next_frame[REGISTERS_INDICES[11]],E::from(BaseElement::new(1 << 31)) * (next_frame[B1[B1.len() - 1]] + current_frame[REGISTERS_INDICES[11]] - E::from(BaseElement::new(2)) * current_frame[REGISTERS_INDICES[11]] * next_frame[B1[B1.len() - 1]])));
+            transitions.agg_constraint(ADD_2_TRANSITIONS_INDICES[1], flag, are_equal(next_frame[REGISTERS_INDICES[11]],E::from(1u32 << 31) * (next_frame[B1[B1.len() - 1]] + current_frame[REGISTERS_INDICES[11]] - E::from(2u32) * current_frame[REGISTERS_INDICES[11]] * next_frame[B1[B1.len() - 1]])));
 
             transitions.agg_constraint(ADD_2_TRANSITIONS_INDICES[2 + i], flag, are_equal(next_frame[REGISTERS_INDICES[i]], next_frame[REGISTERS_INDICES[10]] + next_frame[REGISTERS_INDICES[11]]));
         }
     }
     
-    fn prove(initial_state: &[BaseElement], final_state: &mut [BaseElement], idx: usize) {
-        final_state[REGISTERS_INDICES[10]] = BaseElement::new((element_to_u32(initial_state[REGISTERS_INDICES[10]]) & ((1u32 << 31) - 1)) as u64);
-        final_state[REGISTERS_INDICES[11]] = BaseElement::new(((element_to_u32(final_state[B1[BIT_REGISTERS_LEN - 1]]) ^ element_to_u32(initial_state[REGISTERS_INDICES[11]])) << 31) as u64);
+    fn prove<B: StarkField>(initial_state: &[B], final_state: &mut [B], idx: usize) {
+        final_state[REGISTERS_INDICES[10]] = B::from(element_to_u32(initial_state[REGISTERS_INDICES[10]]) & ((1u32 << 31) - 1));
+        final_state[REGISTERS_INDICES[11]] = B::from((element_to_u32(final_state[B1[BIT_REGISTERS_LEN - 1]]) ^ element_to_u32(initial_state[REGISTERS_INDICES[11]])) << 31);
         final_state[idx] = final_state[REGISTERS_INDICES[10]] + final_state[REGISTERS_INDICES[11]]; 
     }
 }
@@ -320,10 +320,10 @@ impl Command for AddStep2 {
 pub struct SetB;
 
 impl SetB {
-    fn eval_single_transition<E: FieldElement + From<BaseElement>>(next_frame: &[E], periodic_values: &[E]) -> E {
-        let two = E::from(BaseElement::new(2));
-        let mut p = E::from(BaseElement::new(1));
-        let mut in_register = E::from(BaseElement::new(0));
+    fn eval_single_transition<E: FieldElement>(next_frame: &[E], periodic_values: &[E]) -> E {
+        let two = E::from(2u32);
+        let mut p = E::from(1u32);
+        let mut in_register = E::from(0u32);
         for i in B1 {
             in_register += next_frame[i] * p;
             p = two * p;
@@ -334,19 +334,19 @@ impl SetB {
 
 
 impl Command for SetB {
-    fn num() -> BaseElement {
-        BaseElement::new(9)
+    fn num<B: StarkField>() -> B {
+        B::from(9u32)
     }
     
-    fn eval_transitions<E: FieldElement + From<BaseElement>>(transitions: &mut [E], _: &[E], next_frame: &[E], periodic_values: &[E]) {
+    fn eval_transitions<E: FieldElement>(transitions: &mut [E], _: &[E], next_frame: &[E], periodic_values: &[E]) {
         let flag = periodic_values[PERIODIC_SETB2_COLUMNS_INDICES[0]];
     
         let constraint_value = SetB::eval_single_transition(next_frame, periodic_values);
         transitions.agg_constraint(SETB2_TRANSITIONS_INDICES[0], flag, constraint_value);
     }
     
-    fn prove(_: &[BaseElement], final_state: &mut [BaseElement], value: usize) {
-        let base_element_value = BaseElement::new(value as u64);
+    fn prove<B: StarkField>(_: &[B], final_state: &mut [B], value: usize) {
+        let base_element_value = B::from(value as u32);
         set_to_bit_register(final_state, base_element_value, B1);
     }
 }
@@ -354,73 +354,73 @@ impl Command for SetB {
 pub struct NOP;
 
 impl Command for NOP {
-    fn num() -> BaseElement {
-        BaseElement::new(10)
+    fn num<B: StarkField>() -> B {
+        B::from(10u32)
     }
     
-    fn eval_transitions<E: FieldElement + From<BaseElement>>(_: &mut[E], _: &[E], _: &[E], _: &[E]) {
+    fn eval_transitions<E: FieldElement>(_: &mut[E], _: &[E], _: &[E], _: &[E]) {
     }
     
-    fn prove(_: &[BaseElement], _: &mut [BaseElement], _: usize) {
+    fn prove<B: StarkField>(_: &[B], _: &mut [B], _: usize) {
     }
 }
 
 pub struct ResetHardMemory;
 
 impl Command for ResetHardMemory {
-    fn num() -> BaseElement {
-        BaseElement::new(11)
+    fn num<B: StarkField>() -> B {
+        B::from(11u32)
     }
     
-    fn eval_transitions<E: FieldElement + From<BaseElement>>(_: &mut[E], _: &[E], _: &[E], _: &[E]) {
+    fn eval_transitions<E: FieldElement>(_: &mut[E], _: &[E], _: &[E], _: &[E]) {
     }
     
-    fn prove(_: &[BaseElement], _: &mut [BaseElement], _: usize) {
+    fn prove<B: StarkField>(_: &[B], _: &mut [B], _: usize) {
     }
 }
 
 pub struct SetR10;
 
 impl Command for SetR10 {
-    fn num() -> BaseElement {
-        BaseElement::new(12)
+    fn num<B: StarkField>() -> B {
+        B::from(12u32)
     }
     
-    fn eval_transitions<E: FieldElement + From<BaseElement>>(_: &mut[E], _: &[E], _: &[E], _: &[E]) {
+    fn eval_transitions<E: FieldElement>(_: &mut[E], _: &[E], _: &[E], _: &[E]) {
     }
     
-    fn prove(initial_state: &[BaseElement], final_state: &mut [BaseElement], idx: usize) {
-        final_state[REGISTERS_INDICES[10]] = BaseElement::new((element_to_u32(initial_state[idx]) & ((1u32 << 31) - 1)) as u64);
+    fn prove<B: StarkField>(initial_state: &[B], final_state: &mut [B], idx: usize) {
+        final_state[REGISTERS_INDICES[10]] = B::from(element_to_u32(initial_state[idx]) & ((1u32 << 31) - 1));
     }
 }
 
 pub struct SetR11;
 
 impl Command for SetR11 {
-    fn num() -> BaseElement {
-        BaseElement::new(13)
+    fn num<B: StarkField>() -> B {
+        B::from(13u32)
     }
     
-    fn eval_transitions<E: FieldElement + From<BaseElement>>(_: &mut[E], _: &[E], _: &[E], _: &[E]) {
+    fn eval_transitions<E: FieldElement>(_: &mut[E], _: &[E], _: &[E], _: &[E]) {
     }
     
-    fn prove(initial_state: &[BaseElement], final_state: &mut [BaseElement], idx: usize) {
-        final_state[REGISTERS_INDICES[11]] = BaseElement::new((element_to_u32(initial_state[idx]) & ((1u32 << 31) - 1)) as u64);
+    fn prove<B: StarkField>(initial_state: &[B], final_state: &mut [B], idx: usize) {
+        final_state[REGISTERS_INDICES[11]] = B::from(element_to_u32(initial_state[idx]) & ((1u32 << 31) - 1));
     }
 }
 
 pub struct SetR11Value;
 
 impl Command for SetR11Value {
-    fn num() -> BaseElement {
-        BaseElement::new(14)
+    fn num<B: StarkField>() -> B {
+        B::from(14u32)
     }
     
-    fn eval_transitions<E: FieldElement + From<BaseElement>>(_: &mut[E], _: &[E], _: &[E], _: &[E]) {
+    fn eval_transitions<E: FieldElement>(_: &mut[E], _: &[E], _: &[E], _: &[E]) {
     }
     
-    fn prove(_: &[BaseElement], final_state: &mut [BaseElement], value: usize) {
-        final_state[REGISTERS_INDICES[11]] = BaseElement::new((value as u32 & ((1u32 << 31) - 1)) as u64);
+    fn prove<B: StarkField>(_: &[B], final_state: &mut [B], value: usize) {
+        final_state[REGISTERS_INDICES[11]] = B::from(value as u32 & ((1u32 << 31) - 1));
     }
 }
 
@@ -446,345 +446,345 @@ const K: [u32; 64] = [
 
 pub const PROGRAM_LEN: usize = 8192;
 
-fn create_tmp_iv() -> Vec<Vec<[BaseElement; 2]>> {
+fn create_tmp_iv<B: StarkField>() -> Vec<Vec<[B; 2]>> {
     vec![
-        vec![[ToBin::num(), BaseElement::new(IV_INDICES[0] as u64)],
-             [FromBin::num(), BaseElement::new(REGISTERS_INDICES[0] as u64)]], // tmp_h[0]
-        vec![[ToBin::num(), BaseElement::new(IV_INDICES[1] as u64)],
-             [FromBin::num(), BaseElement::new(REGISTERS_INDICES[1] as u64)]], // tmp_h[1]
-        vec![[ToBin::num(), BaseElement::new(IV_INDICES[2] as u64)],
-             [FromBin::num(), BaseElement::new(REGISTERS_INDICES[2] as u64)]], // tmp_h[2]
-        vec![[ToBin::num(), BaseElement::new(IV_INDICES[3] as u64)],
-             [FromBin::num(), BaseElement::new(REGISTERS_INDICES[3] as u64)]], // tmp_h[3]
-        vec![[ToBin::num(), BaseElement::new(IV_INDICES[4] as u64)],
-             [FromBin::num(), BaseElement::new(REGISTERS_INDICES[4] as u64)]], // tmp_h[4]
-        vec![[ToBin::num(), BaseElement::new(IV_INDICES[5] as u64)],
-             [FromBin::num(), BaseElement::new(REGISTERS_INDICES[5] as u64)]], // tmp_h[5]
-        vec![[ToBin::num(), BaseElement::new(IV_INDICES[6] as u64)],
-             [FromBin::num(), BaseElement::new(REGISTERS_INDICES[6] as u64)]], // tmp_h[6]
-        vec![[ToBin::num(), BaseElement::new(IV_INDICES[7] as u64)],
-             [FromBin::num(), BaseElement::new(REGISTERS_INDICES[7] as u64)]]  // tmp_h[7]
+        vec![[ToBin::num(), B::from(IV_INDICES[0] as u32)],
+             [FromBin::num(), B::from(REGISTERS_INDICES[0] as u32)]], // tmp_h[0]
+        vec![[ToBin::num(), B::from(IV_INDICES[1] as u32)],
+             [FromBin::num(), B::from(REGISTERS_INDICES[1] as u32)]], // tmp_h[1]
+        vec![[ToBin::num(), B::from(IV_INDICES[2] as u32)],
+             [FromBin::num(), B::from(REGISTERS_INDICES[2] as u32)]], // tmp_h[2]
+        vec![[ToBin::num(), B::from(IV_INDICES[3] as u32)],
+             [FromBin::num(), B::from(REGISTERS_INDICES[3] as u32)]], // tmp_h[3]
+        vec![[ToBin::num(), B::from(IV_INDICES[4] as u32)],
+             [FromBin::num(), B::from(REGISTERS_INDICES[4] as u32)]], // tmp_h[4]
+        vec![[ToBin::num(), B::from(IV_INDICES[5] as u32)],
+             [FromBin::num(), B::from(REGISTERS_INDICES[5] as u32)]], // tmp_h[5]
+        vec![[ToBin::num(), B::from(IV_INDICES[6] as u32)],
+             [FromBin::num(), B::from(REGISTERS_INDICES[6] as u32)]], // tmp_h[6]
+        vec![[ToBin::num(), B::from(IV_INDICES[7] as u32)],
+             [FromBin::num(), B::from(REGISTERS_INDICES[7] as u32)]]  // tmp_h[7]
     ]
 }
 
 // Set s1 to r8 register
-fn calc_s1() -> Vec<Vec<[BaseElement; 2]>> {
+fn calc_s1<B: StarkField>() -> Vec<Vec<[B; 2]>> {
     vec![
-        vec![[ToBin::num(), BaseElement::new(REGISTERS_INDICES[4] as u64)]],
-        vec![[ROR::num(), BaseElement::new(6)],
-             [FromBin::num(), BaseElement::new(REGISTERS_INDICES[8] as u64)]],
-        vec![[ToBin::num(), BaseElement::new(REGISTERS_INDICES[4] as u64)]],
-        vec![[ROR::num(), BaseElement::new(11)],
-             [FromBin::num(), BaseElement::new(REGISTERS_INDICES[9] as u64)]],
-        vec![[ToBin::num(), BaseElement::new(REGISTERS_INDICES[4] as u64)]],
-        vec![[ROR::num(), BaseElement::new(25)]],
-        vec![[ToBin::num(), BaseElement::new(REGISTERS_INDICES[9] as u64)],
-             [XOR::num(), BaseElement::new(REGISTERS_INDICES[9] as u64)]],
-        vec![[ToBin::num(), BaseElement::new(REGISTERS_INDICES[9] as u64)]],
-        vec![[ToBin::num(), BaseElement::new(REGISTERS_INDICES[8] as u64)],
-             [XOR::num(), BaseElement::new(REGISTERS_INDICES[8] as u64)]],
+        vec![[ToBin::num(), B::from(REGISTERS_INDICES[4] as u32)]],
+        vec![[ROR::num(), B::from(6u32)],
+             [FromBin::num(), B::from(REGISTERS_INDICES[8] as u32)]],
+        vec![[ToBin::num(), B::from(REGISTERS_INDICES[4] as u32)]],
+        vec![[ROR::num(), B::from(11u32)],
+             [FromBin::num(), B::from(REGISTERS_INDICES[9] as u32)]],
+        vec![[ToBin::num(), B::from(REGISTERS_INDICES[4] as u32)]],
+        vec![[ROR::num(), B::from(25u32)]],
+        vec![[ToBin::num(), B::from(REGISTERS_INDICES[9] as u32)],
+             [XOR::num(), B::from(REGISTERS_INDICES[9] as u32)]],
+        vec![[ToBin::num(), B::from(REGISTERS_INDICES[9] as u32)]],
+        vec![[ToBin::num(), B::from(REGISTERS_INDICES[8] as u32)],
+             [XOR::num(), B::from(REGISTERS_INDICES[8] as u32)]],
     ]
 }
 
 // Set ch to r9 register
-fn calc_ch() -> Vec<Vec<[BaseElement; 2]>> {
+fn calc_ch<B: StarkField>() -> Vec<Vec<[B; 2]>> {
     vec![
-        vec![[ToBin::num(), BaseElement::new(REGISTERS_INDICES[4] as u64)]],
-        vec![[ToBin::num(), BaseElement::new(REGISTERS_INDICES[5] as u64)],
-             [AND::num(), BaseElement::new(REGISTERS_INDICES[9] as u64)]],
-        vec![[ToBin::num(), BaseElement::new(REGISTERS_INDICES[4] as u64)]],
-        vec![[NOT::num(), BaseElement::new(0)]],
-        vec![[ToBin::num(), BaseElement::new(REGISTERS_INDICES[6] as u64)],
-             [AND::num(), BaseElement::new(REGISTERS_INDICES[10] as u64)]],
-        vec![[ToBin::num(), BaseElement::new(REGISTERS_INDICES[9] as u64)]],
-        vec![[ToBin::num(), BaseElement::new(REGISTERS_INDICES[10] as u64)],
-             [XOR::num(), BaseElement::new(REGISTERS_INDICES[9] as u64)]],
+        vec![[ToBin::num(), B::from(REGISTERS_INDICES[4] as u32)]],
+        vec![[ToBin::num(), B::from(REGISTERS_INDICES[5] as u32)],
+             [AND::num(), B::from(REGISTERS_INDICES[9] as u32)]],
+        vec![[ToBin::num(), B::from(REGISTERS_INDICES[4] as u32)]],
+        vec![[NOT::num(), B::from(0u32)]],
+        vec![[ToBin::num(), B::from(REGISTERS_INDICES[6] as u32)],
+             [AND::num(), B::from(REGISTERS_INDICES[10] as u32)]],
+        vec![[ToBin::num(), B::from(REGISTERS_INDICES[9] as u32)]],
+        vec![[ToBin::num(), B::from(REGISTERS_INDICES[10] as u32)],
+             [XOR::num(), B::from(REGISTERS_INDICES[9] as u32)]],
     ]
 }
 
 // Set temp1 to r8 register
-fn calc_temp1(i: usize) -> Vec<Vec<[BaseElement; 2]>> {
+fn calc_temp1<B: StarkField>(i: usize) -> Vec<Vec<[B; 2]>> {
     vec![
-        vec![[ToBin::num(), BaseElement::new(REGISTERS_INDICES[7] as u64)],
-             [SetR10::num(), BaseElement::new(REGISTERS_INDICES[7] as u64)],
-             [SetR11::num(), BaseElement::new(REGISTERS_INDICES[8] as u64)]],
-        vec![[ToBin::num(), BaseElement::new(REGISTERS_INDICES[8] as u64)],
-             [AddStep1::num(), BaseElement::new(0)]],
-        vec![[ToBin::num(), BaseElement::new(REGISTERS_INDICES[10] as u64)],
-             [AddStep2::num(), BaseElement::new(REGISTERS_INDICES[8] as u64)]],
-
-        vec![[ToBin::num(), BaseElement::new(REGISTERS_INDICES[8] as u64)],
-             [SetR10::num(), BaseElement::new(REGISTERS_INDICES[8] as u64)],
-             [SetR11::num(), BaseElement::new(REGISTERS_INDICES[9] as u64)]],
-        vec![[ToBin::num(), BaseElement::new(REGISTERS_INDICES[9] as u64)],
-             [AddStep1::num(), BaseElement::new(0)]],
-        vec![[ToBin::num(), BaseElement::new(REGISTERS_INDICES[10] as u64)],
-             [AddStep2::num(), BaseElement::new(REGISTERS_INDICES[8] as u64)]],
-
-        vec![[ToBin::num(), BaseElement::new(REGISTERS_INDICES[8] as u64)],
-             [SetR10::num(), BaseElement::new(REGISTERS_INDICES[8] as u64)],
-             [SetR11Value::num(), BaseElement::new(K[i] as u64)]],
-        vec![[SetB::num(), BaseElement::new(K[i] as u64)],
-             [AddStep1::num(), BaseElement::new(0)]],
-        vec![[ToBin::num(), BaseElement::new(REGISTERS_INDICES[10] as u64)],
-             [AddStep2::num(), BaseElement::new(REGISTERS_INDICES[8] as u64)]],
-
-        vec![[ToBin::num(), BaseElement::new(REGISTERS_INDICES[8] as u64)],
-             [SetR10::num(), BaseElement::new(REGISTERS_INDICES[8] as u64)],
-             [SetR11::num(), BaseElement::new(HARD_MEMORY_INDICES[i % 16] as u64)]],
-        vec![[ToBin::num(), BaseElement::new(HARD_MEMORY_INDICES[i % 16] as u64)],
-             [AddStep1::num(), BaseElement::new(0)]],
-        vec![[ToBin::num(), BaseElement::new(REGISTERS_INDICES[10] as u64)],
-             [AddStep2::num(), BaseElement::new(REGISTERS_INDICES[8] as u64)]],
+        vec![[ToBin::num(), B::from(REGISTERS_INDICES[7] as u32)],
+             [SetR10::num(), B::from(REGISTERS_INDICES[7] as u32)],
+             [SetR11::num(), B::from(REGISTERS_INDICES[8] as u32)]],
+        vec![[ToBin::num(), B::from(REGISTERS_INDICES[8] as u32)],
+             [AddStep1::num(), B::from(0u32)]],
+        vec![[ToBin::num(), B::from(REGISTERS_INDICES[10] as u32)],
+             [AddStep2::num(), B::from(REGISTERS_INDICES[8] as u32)]],
+
+        vec![[ToBin::num(), B::from(REGISTERS_INDICES[8] as u32)],
+             [SetR10::num(), B::from(REGISTERS_INDICES[8] as u32)],
+             [SetR11::num(), B::from(REGISTERS_INDICES[9] as u32)]],
+        vec![[ToBin::num(), B::from(REGISTERS_INDICES[9] as u32)],
+             [AddStep1::num(), B::from(0u32)]],
+        vec![[ToBin::num(), B::from(REGISTERS_INDICES[10] as u32)],
+             [AddStep2::num(), B::from(REGISTERS_INDICES[8] as u32)]],
+
+        vec![[ToBin::num(), B::from(REGISTERS_INDICES[8] as u32)],
+             [SetR10::num(), B::from(REGISTERS_INDICES[8] as u32)],
+             [SetR11Value::num(), B::from(K[i])]],
+        vec![[SetB::num(), B::from(K[i])],
+             [AddStep1::num(), B::from(0u32)]],
+        vec![[ToBin::num(), B::from(REGISTERS_INDICES[10] as u32)],
+             [AddStep2::num(), B::from(REGISTERS_INDICES[8] as u32)]],
+
+        vec![[ToBin::num(), B::from(REGISTERS_INDICES[8] as u32)],
+             [SetR10::num(), B::from(REGISTERS_INDICES[8] as u32)],
+             [SetR11::num(), B::from(HARD_MEMORY_INDICES[i % 16] as u32)]],
+        vec![[ToBin::num(), B::from(HARD_MEMORY_INDICES[i % 16] as u32)],
+             [AddStep1::num(), B::from(0u32)]],
+        vec![[ToBin::num(), B::from(REGISTERS_INDICES[10] as u32)],
+             [AddStep2::num(), B::from(REGISTERS_INDICES[8] as u32)]],
     ]
 }
 
 // Set s0 to r9 register
-fn calc_s0() -> Vec<Vec<[BaseElement; 2]>> {
+fn calc_s0<B: StarkField>() -> Vec<Vec<[B; 2]>> {
     vec![
-        vec![[ToBin::num(), BaseElement::new(REGISTERS_INDICES[0] as u64)]],
-        vec![[ROR::num(), BaseElement::new(2)],
-             [FromBin::num(), BaseElement::new(REGISTERS_INDICES[9] as u64)]],
-        vec![[ToBin::num(), BaseElement::new(REGISTERS_INDICES[0] as u64)]],
-        vec![[ROR::num(), BaseElement::new(13)],
-             [FromBin::num(), BaseElement::new(REGISTERS_INDICES[10] as u64)]],
-        vec![[ToBin::num(), BaseElement::new(REGISTERS_INDICES[0] as u64)]],
-        vec![[ROR::num(), BaseElement::new(22)]],
-        vec![[ToBin::num(), BaseElement::new(REGISTERS_INDICES[10] as u64)],
-             [XOR::num(), BaseElement::new(REGISTERS_INDICES[10] as u64)]],
-        vec![[ToBin::num(), BaseElement::new(REGISTERS_INDICES[9] as u64)]],
-        vec![[ToBin::num(), BaseElement::new(REGISTERS_INDICES[10] as u64)],
-             [XOR::num(), BaseElement::new(REGISTERS_INDICES[9] as u64)]],
+        vec![[ToBin::num(), B::from(REGISTERS_INDICES[0] as u32)]],
+        vec![[ROR::num(), B::from(2u32)],
+             [FromBin::num(), B::from(REGISTERS_INDICES[9] as u32)]],
+        vec![[ToBin::num(), B::from(REGISTERS_INDICES[0] as u32)]],
+        vec![[ROR::num(), B::from(13u32)],
+             [FromBin::num(), B::from(REGISTERS_INDICES[10] as u32)]],
+        vec![[ToBin::num(), B::from(REGISTERS_INDICES[0] as u32)]],
+        vec![[ROR::num(), B::from(22u32)]],
+        vec![[ToBin::num(), B::from(REGISTERS_INDICES[10] as u32)],
+             [XOR::num(), B::from(REGISTERS_INDICES[10] as u32)]],
+        vec![[ToBin::num(), B::from(REGISTERS_INDICES[9] as u32)]],
+        vec![[ToBin::num(), B::from(REGISTERS_INDICES[10] as u32)],
+             [XOR::num(), B::from(REGISTERS_INDICES[9] as u32)]],
     ]
 }
 
 // Set maj to r10 register
-fn calc_maj() -> Vec<Vec<[BaseElement; 2]>> {
+fn calc_maj<B: StarkField>() -> Vec<Vec<[B; 2]>> {
     vec![
-        vec![[ToBin::num(), BaseElement::new(REGISTERS_INDICES[0] as u64)]],
-        vec![[ToBin::num(), BaseElement::new(REGISTERS_INDICES[1] as u64)],
-             [AND::num(), BaseElement::new(REGISTERS_INDICES[10] as u64)]],
-        vec![[ToBin::num(), BaseElement::new(REGISTERS_INDICES[0] as u64)]],
-        vec![[ToBin::num(), BaseElement::new(REGISTERS_INDICES[2] as u64)],
-             [AND::num(), BaseElement::new(REGISTERS_INDICES[11] as u64)]],
-        vec![[ToBin::num(), BaseElement::new(REGISTERS_INDICES[10] as u64)]],
-        vec![[ToBin::num(), BaseElement::new(REGISTERS_INDICES[11] as u64)],
-             [XOR::num(), BaseElement::new(REGISTERS_INDICES[10] as u64)]],
-        vec![[ToBin::num(), BaseElement::new(REGISTERS_INDICES[1] as u64)]],
-        vec![[ToBin::num(), BaseElement::new(REGISTERS_INDICES[2] as u64)],
-             [AND::num(), BaseElement::new(REGISTERS_INDICES[11] as u64)]],
-        vec![[ToBin::num(), BaseElement::new(REGISTERS_INDICES[11] as u64)]],
-        vec![[ToBin::num(), BaseElement::new(REGISTERS_INDICES[10] as u64)],
-             [XOR::num(), BaseElement::new(REGISTERS_INDICES[10] as u64)]],
+        vec![[ToBin::num(), B::from(REGISTERS_INDICES[0] as u32)]],
+        vec![[ToBin::num(), B::from(REGISTERS_INDICES[1] as u32)],
+             [AND::num(), B::from(REGISTERS_INDICES[10] as u32)]],
+        vec![[ToBin::num(), B::from(REGISTERS_INDICES[0] as u32)]],
+        vec![[ToBin::num(), B::from(REGISTERS_INDICES[2] as u32)],
+             [AND::num(), B::from(REGISTERS_INDICES[11] as u32)]],
+        vec![[ToBin::num(), B::from(REGISTERS_INDICES[10] as u32)]],
+        vec![[ToBin::num(), B::from(REGISTERS_INDICES[11] as u32)],
+             [XOR::num(), B::from(REGISTERS_INDICES[10] as u32)]],
+        vec![[ToBin::num(), B::from(REGISTERS_INDICES[1] as u32)]],
+        vec![[ToBin::num(), B::from(REGISTERS_INDICES[2] as u32)],
+             [AND::num(), B::from(REGISTERS_INDICES[11] as u32)]],
+        vec![[ToBin::num(), B::from(REGISTERS_INDICES[11] as u32)]],
+        vec![[ToBin::num(), B::from(REGISTERS_INDICES[10] as u32)],
+             [XOR::num(), B::from(REGISTERS_INDICES[10] as u32)]],
     ]
 }
 
 // Set temp2 to r9 register
-fn calc_temp2() -> Vec<Vec<[BaseElement; 2]>> {
+fn calc_temp2<B: StarkField>() -> Vec<Vec<[B; 2]>> {
     vec![
-        vec![[ToBin::num(), BaseElement::new(REGISTERS_INDICES[10] as u64)],
-             [SetR10::num(), BaseElement::new(REGISTERS_INDICES[10] as u64)],
-             [SetR11::num(), BaseElement::new(REGISTERS_INDICES[9] as u64)]],
-        vec![[ToBin::num(), BaseElement::new(REGISTERS_INDICES[9] as u64)],
-             [AddStep1::num(), BaseElement::new(0)]],
-        vec![[ToBin::num(), BaseElement::new(REGISTERS_INDICES[10] as u64)],
-             [AddStep2::num(), BaseElement::new(REGISTERS_INDICES[9] as u64)]],
+        vec![[ToBin::num(), B::from(REGISTERS_INDICES[10] as u32)],
+             [SetR10::num(), B::from(REGISTERS_INDICES[10] as u32)],
+             [SetR11::num(), B::from(REGISTERS_INDICES[9] as u32)]],
+        vec![[ToBin::num(), B::from(REGISTERS_INDICES[9] as u32)],
+             [AddStep1::num(), B::from(0u32)]],
+        vec![[ToBin::num(), B::from(REGISTERS_INDICES[10] as u32)],
+             [AddStep2::num(), B::from(REGISTERS_INDICES[9] as u32)]],
     ]
 }
 
-fn update_w_i(i: usize) -> Vec<Vec<[BaseElement; 2]>> {
+fn update_w_i<B: StarkField>(i: usize) -> Vec<Vec<[B; 2]>> {
     /*
         let s0 = (w[i - 15].rotate_right(7)) ^ (w[i - 15].rotate_right(18)) ^ (w[i - 15] >> 3);
         let s1 = (w[i - 2].rotate_right(17)) ^ (w[i - 2].rotate_right(19)) ^ (w[i - 2] >> 10);
         w[i] = w[i - 16].wrapping_add(s0).wrapping_add(w[i - 7]).wrapping_add(s1);
     */
     vec![
-        vec![[ToBin::num(), BaseElement::new(HARD_MEMORY_INDICES[(i + 1) % 16] as u64)]],
-        vec![[ROR::num(), BaseElement::new(7)],
-             [FromBin::num(), BaseElement::new(REGISTERS_INDICES[8] as u64)]],
-        vec![[ToBin::num(), BaseElement::new(HARD_MEMORY_INDICES[(i + 1) % 16] as u64)]],
-        vec![[ROR::num(), BaseElement::new(18)],
-             [FromBin::num(), BaseElement::new(REGISTERS_INDICES[9] as u64)]],
-        vec![[ToBin::num(), BaseElement::new(HARD_MEMORY_INDICES[(i + 1) % 16] as u64)]],
-        vec![[SHR::num(), BaseElement::new(0)]],
-        vec![[SHR::num(), BaseElement::new(0)]],
-        vec![[SHR::num(), BaseElement::new(0)]],
-        vec![[ToBin::num(), BaseElement::new(REGISTERS_INDICES[9] as u64)],
-             [XOR::num(), BaseElement::new(REGISTERS_INDICES[9] as u64)]],
-        vec![[ToBin::num(), BaseElement::new(REGISTERS_INDICES[9] as u64)]],
-        vec![[ToBin::num(), BaseElement::new(REGISTERS_INDICES[8] as u64)],
-             [XOR::num(), BaseElement::new(REGISTERS_INDICES[8] as u64)]], // save s0 to r8
-        vec![[ToBin::num(), BaseElement::new(HARD_MEMORY_INDICES[(i + 14) % 16] as u64)]],
-        vec![[ROR::num(), BaseElement::new(17)],
-             [FromBin::num(), BaseElement::new(REGISTERS_INDICES[9] as u64)]],
-        vec![[ToBin::num(), BaseElement::new(HARD_MEMORY_INDICES[(i + 14) % 16] as u64)]],
-        vec![[ROR::num(), BaseElement::new(19)],
-             [FromBin::num(), BaseElement::new(REGISTERS_INDICES[10] as u64)]],
-        vec![[ToBin::num(), BaseElement::new(HARD_MEMORY_INDICES[(i + 14) % 16] as u64)]],
-        vec![[SHR::num(), BaseElement::new(0)]],
-        vec![[SHR::num(), BaseElement::new(0)]],
-        vec![[SHR::num(), BaseElement::new(0)]],
-        vec![[SHR::num(), BaseElement::new(0)]],
-        vec![[SHR::num(), BaseElement::new(0)]],
-        vec![[SHR::num(), BaseElement::new(0)]],
-        vec![[SHR::num(), BaseElement::new(0)]],
-        vec![[SHR::num(), BaseElement::new(0)]],
-        vec![[SHR::num(), BaseElement::new(0)]],
-        vec![[SHR::num(), BaseElement::new(0)]],
-        vec![[ToBin::num(), BaseElement::new(REGISTERS_INDICES[9] as u64)],
-             [XOR::num(), BaseElement::new(REGISTERS_INDICES[9] as u64)]],
-        vec![[ToBin::num(), BaseElement::new(REGISTERS_INDICES[9] as u64)]],
-        vec![[ToBin::num(), BaseElement::new(REGISTERS_INDICES[10] as u64)],
-             [XOR::num(), BaseElement::new(REGISTERS_INDICES[9] as u64)]],// save s1 to r9
+        vec![[ToBin::num(), B::from(HARD_MEMORY_INDICES[(i + 1) % 16] as u32)]],
+        vec![[ROR::num(), B::from(7u32)],
+             [FromBin::num(), B::from(REGISTERS_INDICES[8] as u32)]],
+        vec![[ToBin::num(), B::from(HARD_MEMORY_INDICES[(i + 1) % 16] as u32)]],
+        vec![[ROR::num(), B::from(18u32)],
+             [FromBin::num(), B::from(REGISTERS_INDICES[9] as u32)]],
+        vec![[ToBin::num(), B::from(HARD_MEMORY_INDICES[(i + 1) % 16] as u32)]],
+        vec![[SHR::num(), B::from(0u32)]],
+        vec![[SHR::num(), B::from(0u32)]],
+        vec![[SHR::num(), B::from(0u32)]],
+        vec![[ToBin::num(), B::from(REGISTERS_INDICES[9] as u32)],
+             [XOR::num(), B::from(REGISTERS_INDICES[9] as u32)]],
+        vec![[ToBin::num(), B::from(REGISTERS_INDICES[9] as u32)]],
+        vec![[ToBin::num(), B::from(REGISTERS_INDICES[8] as u32)],
+             [XOR::num(), B::from(REGISTERS_INDICES[8] as u32)]], // save s0 to r8
+        vec![[ToBin::num(), B::from(HARD_MEMORY_INDICES[(i + 14) % 16] as u32)]],
+        vec![[ROR::num(), B::from(17u32)],
+             [FromBin::num(), B::from(REGISTERS_INDICES[9] as u32)]],
+        vec![[ToBin::num(), B::from(HARD_MEMORY_INDICES[(i + 14) % 16] as u32)]],
+        vec![[ROR::num(), B::from(19u32)],
+             [FromBin::num(), B::from(REGISTERS_INDICES[10] as u32)]],
+        vec![[ToBin::num(), B::from(HARD_MEMORY_INDICES[(i + 14) % 16] as u32)]],
+        vec![[SHR::num(), B::from(0u32)]],
+        vec![[SHR::num(), B::from(0u32)]],
+        vec![[SHR::num(), B::from(0u32)]],
+        vec![[SHR::num(), B::from(0u32)]],
+        vec![[SHR::num(), B::from(0u32)]],
+        vec![[SHR::num(), B::from(0u32)]],
+        vec![[SHR::num(), B::from(0u32)]],
+        vec![[SHR::num(), B::from(0u32)]],
+        vec![[SHR::num(), B::from(0u32)]],
+        vec![[SHR::num(), B::from(0u32)]],
+        vec![[ToBin::num(), B::from(REGISTERS_INDICES[9] as u32)],
+             [XOR::num(), B::from(REGISTERS_INDICES[9] as u32)]],
+        vec![[ToBin::num(), B::from(REGISTERS_INDICES[9] as u32)]],
+        vec![[ToBin::num(), B::from(REGISTERS_INDICES[10] as u32)],
+             [XOR::num(), B::from(REGISTERS_INDICES[9] as u32)]],// save s1 to r9
         
-        vec![[ToBin::num(), BaseElement::new(HARD_MEMORY_INDICES[i] as u64)],
-             [SetR10::num(), BaseElement::new(HARD_MEMORY_INDICES[i] as u64)],
-             [SetR11::num(), BaseElement::new(REGISTERS_INDICES[8] as u64)]],
-        vec![[ToBin::num(), BaseElement::new(REGISTERS_INDICES[8] as u64)],
-             [AddStep1::num(), BaseElement::new(0)]],
-        vec![[ToBin::num(), BaseElement::new(REGISTERS_INDICES[10] as u64)],
-             [AddStep2::num(), BaseElement::new(REGISTERS_INDICES[8] as u64)]],
-
-        vec![[ToBin::num(), BaseElement::new(HARD_MEMORY_INDICES[(i + 9) % 16] as u64)],
-             [SetR10::num(), BaseElement::new(HARD_MEMORY_INDICES[(i + 9) % 16] as u64)],
-             [SetR11::num(), BaseElement::new(REGISTERS_INDICES[8] as u64)]],
-        vec![[ToBin::num(), BaseElement::new(REGISTERS_INDICES[8] as u64)],
-             [AddStep1::num(), BaseElement::new(0)]],
-        vec![[ToBin::num(), BaseElement::new(REGISTERS_INDICES[10] as u64)],
-             [AddStep2::num(), BaseElement::new(REGISTERS_INDICES[8] as u64)]],
-
-        vec![[ToBin::num(), BaseElement::new(REGISTERS_INDICES[8] as u64)],
-             [SetR10::num(), BaseElement::new(REGISTERS_INDICES[8] as u64)],
-             [SetR11::num(), BaseElement::new(REGISTERS_INDICES[9] as u64)]],
-        vec![[ToBin::num(), BaseElement::new(REGISTERS_INDICES[9] as u64)],
-             [AddStep1::num(), BaseElement::new(0)]],
-        vec![[ToBin::num(), BaseElement::new(REGISTERS_INDICES[10] as u64)],
-             [AddStep2::num(), BaseElement::new(REGISTERS_INDICES[8] as u64)]],
+        vec![[ToBin::num(), B::from(HARD_MEMORY_INDICES[i] as u32)],
+             [SetR10::num(), B::from(HARD_MEMORY_INDICES[i] as u32)],
+             [SetR11::num(), B::from(REGISTERS_INDICES[8] as u32)]],
+        vec![[ToBin::num(), B::from(REGISTERS_INDICES[8] as u32)],
+             [AddStep1::num(), B::from(0u32)]],
+        vec![[ToBin::num(), B::from(REGISTERS_INDICES[10] as u32)],
+             [AddStep2::num(), B::from(REGISTERS_INDICES[8] as u32)]],
+
+        vec![[ToBin::num(), B::from(HARD_MEMORY_INDICES[(i + 9) % 16] as u32)],
+             [SetR10::num(), B::from(HARD_MEMORY_INDICES[(i + 9) % 16] as u32)],
+             [SetR11::num(), B::from(REGISTERS_INDICES[8] as u32)]],
+        vec![[ToBin::num(), B::from(REGISTERS_INDICES[8] as u32)],
+             [AddStep1::num(), B::from(0u32)]],
+        vec![[ToBin::num(), B::from(REGISTERS_INDICES[10] as u32)],
+             [AddStep2::num(), B::from(REGISTERS_INDICES[8] as u32)]],
+
+        vec![[ToBin::num(), B::from(REGISTERS_INDICES[8] as u32)],
+             [SetR10::num(), B::from(REGISTERS_INDICES[8] as u32)],
+             [SetR11::num(), B::from(REGISTERS_INDICES[9] as u32)]],
+        vec![[ToBin::num(), B::from(REGISTERS_INDICES[9] as u32)],
+             [AddStep1::num(), B::from(0u32)]],
+        vec![[ToBin::num(), B::from(REGISTERS_INDICES[10] as u32)],
+             [AddStep2::num(), B::from(REGISTERS_INDICES[8] as u32)]],
         
-        vec![[ToBin::num(), BaseElement::new(REGISTERS_INDICES[8] as u64)],
-             [FromBin::num(), BaseElement::new(HARD_MEMORY_INDICES[i] as u64)]],
+        vec![[ToBin::num(), B::from(REGISTERS_INDICES[8] as u32)],
+             [FromBin::num(), B::from(HARD_MEMORY_INDICES[i] as u32)]],
     ]
 }
 
-fn update_tmp_h() -> Vec<Vec<[BaseElement; 2]>> {
+fn update_tmp_h<B: StarkField>() -> Vec<Vec<[B; 2]>> {
     vec![
-        vec![[ToBin::num(), BaseElement::new(REGISTERS_INDICES[6] as u64)],
-             [FromBin::num(), BaseElement::new(REGISTERS_INDICES[7] as u64)]],
-        vec![[ToBin::num(), BaseElement::new(REGISTERS_INDICES[5] as u64)],
-             [FromBin::num(), BaseElement::new(REGISTERS_INDICES[6] as u64)]],
-        vec![[ToBin::num(), BaseElement::new(REGISTERS_INDICES[4] as u64)],
-             [FromBin::num(), BaseElement::new(REGISTERS_INDICES[5] as u64)]],
-
-        vec![[ToBin::num(), BaseElement::new(REGISTERS_INDICES[3] as u64)],
-             [SetR10::num(), BaseElement::new(REGISTERS_INDICES[3] as u64)],
-             [SetR11::num(), BaseElement::new(REGISTERS_INDICES[8] as u64)]],
-        vec![[ToBin::num(), BaseElement::new(REGISTERS_INDICES[8] as u64)],
-             [AddStep1::num(), BaseElement::new(0)]],
-        vec![[ToBin::num(), BaseElement::new(REGISTERS_INDICES[10] as u64)],
-             [AddStep2::num(), BaseElement::new(REGISTERS_INDICES[4] as u64)]],
+        vec![[ToBin::num(), B::from(REGISTERS_INDICES[6] as u32)],
+             [FromBin::num(), B::from(REGISTERS_INDICES[7] as u32)]],
+        vec![[ToBin::num(), B::from(REGISTERS_INDICES[5] as u32)],
+             [FromBin::num(), B::from(REGISTERS_INDICES[6] as u32)]],
+        vec![[ToBin::num(), B::from(REGISTERS_INDICES[4] as u32)],
+             [FromBin::num(), B::from(REGISTERS_INDICES[5] as u32)]],
+
+        vec![[ToBin::num(), B::from(REGISTERS_INDICES[3] as u32)],
+             [SetR10::num(), B::from(REGISTERS_INDICES[3] as u32)],
+             [SetR11::num(), B::from(REGISTERS_INDICES[8] as u32)]],
+        vec![[ToBin::num(), B::from(REGISTERS_INDICES[8] as u32)],
+             [AddStep1::num(), B::from(0u32)]],
+        vec![[ToBin::num(), B::from(REGISTERS_INDICES[10] as u32)],
+             [AddStep2::num(), B::from(REGISTERS_INDICES[4] as u32)]],
         
-        vec![[ToBin::num(), BaseElement::new(REGISTERS_INDICES[2] as u64)],
-             [FromBin::num(), BaseElement::new(REGISTERS_INDICES[3] as u64)]],
-        vec![[ToBin::num(), BaseElement::new(REGISTERS_INDICES[1] as u64)],
-             [FromBin::num(), BaseElement::new(REGISTERS_INDICES[2] as u64)]],
-        vec![[ToBin::num(), BaseElement::new(REGISTERS_INDICES[0] as u64)],
-             [FromBin::num(), BaseElement::new(REGISTERS_INDICES[1] as u64)]],
-
-        vec![[ToBin::num(), BaseElement::new(REGISTERS_INDICES[8] as u64)],
-             [SetR10::num(), BaseElement::new(REGISTERS_INDICES[8] as u64)],
-             [SetR11::num(), BaseElement::new(REGISTERS_INDICES[9] as u64)]],
-        vec![[ToBin::num(), BaseElement::new(REGISTERS_INDICES[9] as u64)],
-             [AddStep1::num(), BaseElement::new(0)]],
-        vec![[ToBin::num(), BaseElement::new(REGISTERS_INDICES[10] as u64)],
-             [AddStep2::num(), BaseElement::new(REGISTERS_INDICES[0] as u64)]],
+        vec![[ToBin::num(), B::from(REGISTERS_INDICES[2] as u32)],
+             [FromBin::num(), B::from(REGISTERS_INDICES[3] as u32)]],
+        vec![[ToBin::num(), B::from(REGISTERS_INDICES[1] as u32)],
+             [FromBin::num(), B::from(REGISTERS_INDICES[2] as u32)]],
+        vec![[ToBin::num(), B::from(REGISTERS_INDICES[0] as u32)],
+             [FromBin::num(), B::from(REGISTERS_INDICES[1] as u32)]],
+
+        vec![[ToBin::num(), B::from(REGISTERS_INDICES[8] as u32)],
+             [SetR10::num(), B::from(REGISTERS_INDICES[8] as u32)],
+             [SetR11::num(), B::from(REGISTERS_INDICES[9] as u32)]],
+        vec![[ToBin::num(), B::from(REGISTERS_INDICES[9] as u32)],
+             [AddStep1::num(), B::from(0u32)]],
+        vec![[ToBin::num(), B::from(REGISTERS_INDICES[10] as u32)],
+             [AddStep2::num(), B::from(REGISTERS_INDICES[0] as u32)]],
     ]
 }
 
-fn update_h() -> Vec<Vec<[BaseElement; 2]>> {
+fn update_h<B: StarkField>() -> Vec<Vec<[B; 2]>> {
     vec![
-        vec![[ToBin::num(), BaseElement::new(IV_INDICES[0] as u64)],
-             [SetR10::num(), BaseElement::new(IV_INDICES[0] as u64)],
-             [SetR11::num(), BaseElement::new(REGISTERS_INDICES[0] as u64)]],
-        vec![[ToBin::num(), BaseElement::new(REGISTERS_INDICES[0] as u64)],
-             [AddStep1::num(), BaseElement::new(0)]],
-        vec![[ToBin::num(), BaseElement::new(REGISTERS_INDICES[10] as u64)],
-             [AddStep2::num(), BaseElement::new(REGISTERS_INDICES[8] as u64)]],
-        vec![[ToBin::num(), BaseElement::new(REGISTERS_INDICES[8] as u64)],
-             [FromBin::num(), BaseElement::new(IV_INDICES[0] as u64)]],
-
-        vec![[ToBin::num(), BaseElement::new(IV_INDICES[1] as u64)],
-             [SetR10::num(), BaseElement::new(IV_INDICES[1] as u64)],
-             [SetR11::num(), BaseElement::new(REGISTERS_INDICES[1] as u64)]],
-        vec![[ToBin::num(), BaseElement::new(REGISTERS_INDICES[1] as u64)],
-             [AddStep1::num(), BaseElement::new(0)]],
-        vec![[ToBin::num(), BaseElement::new(REGISTERS_INDICES[10] as u64)],
-             [AddStep2::num(), BaseElement::new(REGISTERS_INDICES[8] as u64)]],
-        vec![[ToBin::num(), BaseElement::new(REGISTERS_INDICES[8] as u64)],
-             [FromBin::num(), BaseElement::new(IV_INDICES[1] as u64)]],
-
-        vec![[ToBin::num(), BaseElement::new(IV_INDICES[2] as u64)],
-             [SetR10::num(), BaseElement::new(IV_INDICES[2] as u64)],
-             [SetR11::num(), BaseElement::new(REGISTERS_INDICES[2] as u64)]],
-        vec![[ToBin::num(), BaseElement::new(REGISTERS_INDICES[2] as u64)],
-             [AddStep1::num(), BaseElement::new(0)]],
-        vec![[ToBin::num(), BaseElement::new(REGISTERS_INDICES[10] as u64)],
-             [AddStep2::num(), BaseElement::new(REGISTERS_INDICES[8] as u64)]],
-        vec![[ToBin::num(), BaseElement::new(REGISTERS_INDICES[8] as u64)],
-             [FromBin::num(), BaseElement::new(IV_INDICES[2] as u64)]],
-
-        vec![[ToBin::num(), BaseElement::new(IV_INDICES[3] as u64)],
-             [SetR10::num(), BaseElement::new(IV_INDICES[3] as u64)],
-             [SetR11::num(), BaseElement::new(REGISTERS_INDICES[3] as u64)]],
-        vec![[ToBin::num(), BaseElement::new(REGISTERS_INDICES[3] as u64)],
-             [AddStep1::num(), BaseElement::new(0)]],
-        vec![[ToBin::num(), BaseElement::new(REGISTERS_INDICES[10] as u64)],
-             [AddStep2::num(), BaseElement::new(REGISTERS_INDICES[8] as u64)]],
-        vec![[ToBin::num(), BaseElement::new(REGISTERS_INDICES[8] as u64)],
-             [FromBin::num(), BaseElement::new(IV_INDICES[3] as u64)]],
-
-        vec![[ToBin::num(), BaseElement::new(IV_INDICES[4] as u64)],
-             [SetR10::num(), BaseElement::new(IV_INDICES[4] as u64)],
-             [SetR11::num(), BaseElement::new(REGISTERS_INDICES[4] as u64)]],
-        vec![[ToBin::num(), BaseElement::new(REGISTERS_INDICES[4] as u64)],
-             [AddStep1::num(), BaseElement::new(0)]],
-        vec![[ToBin::num(), BaseElement::new(REGISTERS_INDICES[10] as u64)],
-             [AddStep2::num(), BaseElement::new(REGISTERS_INDICES[8] as u64)]],
-        vec![[ToBin::num(), BaseElement::new(REGISTERS_INDICES[8] as u64)],
-             [FromBin::num(), BaseElement::new(IV_INDICES[4] as u64)]],
-
-        vec![[ToBin::num(), BaseElement::new(IV_INDICES[5] as u64)],
-             [SetR10::num(), BaseElement::new(IV_INDICES[5] as u64)],
-             [SetR11::num(), BaseElement::new(REGISTERS_INDICES[5] as u64)]],
-        vec![[ToBin::num(), BaseElement::new(REGISTERS_INDICES[5] as u64)],
-             [AddStep1::num(), BaseElement::new(0)]],
-        vec![[ToBin::num(), BaseElement::new(REGISTERS_INDICES[10] as u64)],
-             [AddStep2::num(), BaseElement::new(REGISTERS_INDICES[8] as u64)]],
-        vec![[ToBin::num(), BaseElement::new(REGISTERS_INDICES[8] as u64)],
-             [FromBin::num(), BaseElement::new(IV_INDICES[5] as u64)]],
-
-        vec![[ToBin::num(), BaseElement::new(IV_INDICES[6] as u64)],
-             [SetR10::num(), BaseElement::new(IV_INDICES[6] as u64)],
-             [SetR11::num(), BaseElement::new(REGISTERS_INDICES[6] as u64)]],
-        vec![[ToBin::num(), BaseElement::new(REGISTERS_INDICES[6] as u64)],
-             [AddStep1::num(), BaseElement::new(0)]],
-        vec![[ToBin::num(), BaseElement::new(REGISTERS_INDICES[10] as u64)],
-             [AddStep2::num(), BaseElement::new(REGISTERS_INDICES[8] as u64)]],
-        vec![[ToBin::num(), BaseElement::new(REGISTERS_INDICES[8] as u64)],
-             [FromBin::num(), BaseElement::new(IV_INDICES[6] as u64)]],
-
-        vec![[ToBin::num(), BaseElement::new(IV_INDICES[7] as u64)],
-             [SetR10::num(), BaseElement::new(IV_INDICES[7] as u64)],
-             [SetR11::num(), BaseElement::new(REGISTERS_INDICES[7] as u64)]],
-        vec![[ToBin::num(), BaseElement::new(REGISTERS_INDICES[7] as u64)],
-             [AddStep1::num(), BaseElement::new(0)]],
-        vec![[ToBin::num(), BaseElement::new(REGISTERS_INDICES[10] as u64)],
-             [AddStep2::num(), BaseElement::new(REGISTERS_INDICES[8] as u64)]],
-        vec![[ToBin::num(), BaseElement::new(REGISTERS_INDICES[8] as u64)],
-             [FromBin::num(), BaseElement::new(IV_INDICES[7] as u64)]],
+        vec![[ToBin::num(), B::from(IV_INDICES[0] as u32)],
+             [SetR10::num(), B::from(IV_INDICES[0] as u32)],
+             [SetR11::num(), B::from(REGISTERS_INDICES[0] as u32)]],
+        vec![[ToBin::num(), B::from(REGISTERS_INDICES[0] as u32)],
+             [AddStep1::num(), B::from(0u32)]],
+        vec![[ToBin::num(), B::from(REGISTERS_INDICES[10] as u32)],
+             [AddStep2::num(), B::from(REGISTERS_INDICES[8] as u32)]],
+        vec![[ToBin::num(), B::from(REGISTERS_INDICES[8] as u32)],
+             [FromBin::num(), B::from(IV_INDICES[0] as u32)]],
+
+        vec![[ToBin::num(), B::from(IV_INDICES[1] as u32)],
+             [SetR10::num(), B::from(IV_INDICES[1] as u32)],
+             [SetR11::num(), B::from(REGISTERS_INDICES[1] as u32)]],
+        vec![[ToBin::num(), B::from(REGISTERS_INDICES[1] as u32)],
+             [AddStep1::num(), B::from(0u32)]],
+        vec![[ToBin::num(), B::from(REGISTERS_INDICES[10] as u32)],
+             [AddStep2::num(), B::from(REGISTERS_INDICES[8] as u32)]],
+        vec![[ToBin::num(), B::from(REGISTERS_INDICES[8] as u32)],
+             [FromBin::num(), B::from(IV_INDICES[1] as u32)]],
+
+        vec![[ToBin::num(), B::from(IV_INDICES[2] as u32)],
+             [SetR10::num(), B::from(IV_INDICES[2] as u32)],
+             [SetR11::num(), B::from(REGISTERS_INDICES[2] as u32)]],
+        vec![[ToBin::num(), B::from(REGISTERS_INDICES[2] as u32)],
+             [AddStep1::num(), B::from(0u32)]],
+        vec![[ToBin::num(), B::from(REGISTERS_INDICES[10] as u32)],
+             [AddStep2::num(), B::from(REGISTERS_INDICES[8] as u32)]],
+        vec![[ToBin::num(), B::from(REGISTERS_INDICES[8] as u32)],
+             [FromBin::num(), B::from(IV_INDICES[2] as u32)]],
+
+        vec![[ToBin::num(), B::from(IV_INDICES[3] as u32)],
+             [SetR10::num(), B::from(IV_INDICES[3] as u32)],
+             [SetR11::num(), B::from(REGISTERS_INDICES[3] as u32)]],
+        vec![[ToBin::num(), B::from(REGISTERS_INDICES[3] as u32)],
+             [AddStep1::num(), B::from(0u32)]],
+        vec![[ToBin::num(), B::from(REGISTERS_INDICES[10] as u32)],
+             [AddStep2::num(), B::from(REGISTERS_INDICES[8] as u32)]],
+        vec![[ToBin::num(), B::from(REGISTERS_INDICES[8] as u32)],
+             [FromBin::num(), B::from(IV_INDICES[3] as u32)]],
+
+        vec![[ToBin::num(), B::from(IV_INDICES[4] as u32)],
+             [SetR10::num(), B::from(IV_INDICES[4] as u32)],
+             [SetR11::num(), B::from(REGISTERS_INDICES[4] as u32)]],
+        vec![[ToBin::num(), B::from(REGISTERS_INDICES[4] as u32)],
+             [AddStep1::num(), B::from(0u32)]],
+        vec![[ToBin::num(), B::from(REGISTERS_INDICES[10] as u32)],
+             [AddStep2::num(), B::from(REGISTERS_INDICES[8] as u32)]],
+        vec![[ToBin::num(), B::from(REGISTERS_INDICES[8] as u32)],
+             [FromBin::num(), B::from(IV_INDICES[4] as u32)]],
+
+        vec![[ToBin::num(), B::from(IV_INDICES[5] as u32)],
+             [SetR10::num(), B::from(IV_INDICES[5] as u32)],
+             [SetR11::num(), B::from(REGISTERS_INDICES[5] as u32)]],
+        vec![[ToBin::num(), B::from(REGISTERS_INDICES[5] as u32)],
+             [AddStep1::num(), B::from(0u32)]],
+        vec![[ToBin::num(), B::from(REGISTERS_INDICES[10] as u32)],
+             [AddStep2::num(), B::from(REGISTERS_INDICES[8] as u32)]],
+        vec![[ToBin::num(), B::from(REGISTERS_INDICES[8] as u32)],
+             [FromBin::num(), B::from(IV_INDICES[5] as u32)]],
+
+        vec![[ToBin::num(), B::from(IV_INDICES[6] as u32)],
+             [SetR10::num(), B::from(IV_INDICES[6] as u32)],
+             [SetR11::num(), B::from(REGISTERS_INDICES[6] as u32)]],
+        vec![[ToBin::num(), B::from(REGISTERS_INDICES[6] as u32)],
+             [AddStep1::num(), B::from(0u32)]],
+        vec![[ToBin::num(), B::from(REGISTERS_INDICES[10] as u32)],
+             [AddStep2::num(), B::from(REGISTERS_INDICES[8] as u32)]],
+        vec![[ToBin::num(), B::from(REGISTERS_INDICES[8] as u32)],
+             [FromBin::num(), B::from(IV_INDICES[6] as u32)]],
+
+        vec![[ToBin::num(), B::from(IV_INDICES[7] as u32)],
+             [SetR10::num(), B::from(IV_INDICES[7] as u32)],
+             [SetR11::num(), B::from(REGISTERS_INDICES[7] as u32)]],
+        vec![[ToBin::num(), B::from(REGISTERS_INDICES[7] as u32)],
+             [AddStep1::num(), B::from(0u32)]],
+        vec![[ToBin::num(), B::from(REGISTERS_INDICES[10] as u32)],
+             [AddStep2::num(), B::from(REGISTERS_INDICES[8] as u32)]],
+        vec![[ToBin::num(), B::from(REGISTERS_INDICES[8] as u32)],
+             [FromBin::num(), B::from(IV_INDICES[7] as u32)]],
     ]
 }
 
-fn hash_one_round(i: usize) -> Vec<Vec<[BaseElement; 2]>> {
+fn hash_one_round<B: StarkField>(i: usize) -> Vec<Vec<[B; 2]>> {
     let mut program = Vec::new();
     program.extend(calc_s1());
     program.extend(calc_ch());
@@ -796,9 +796,9 @@ fn hash_one_round(i: usize) -> Vec<Vec<[BaseElement; 2]>> {
     program
 }
 
-pub fn get_program() -> Vec<Vec<[BaseElement; 2]>> {
+pub fn get_program<B: StarkField>() -> Vec<Vec<[B; 2]>> {
     let mut program = Vec::new();
-    program.push(vec![[NOP::num(), BaseElement::new(0)]]);
+    program.push(vec![[NOP::num(), B::from(0u32)]]);
     // program.extend(sample_program());
     program.extend(create_tmp_iv());
     for i in 0..64 {
@@ -811,7 +811,7 @@ pub fn get_program() -> Vec<Vec<[BaseElement; 2]>> {
     println!("initial program.len() = {}", program.len());
     let padding_len = program.len().next_power_of_two() - program.len();
     for _ in 0..padding_len {
-        program.push(vec![[ResetHardMemory::num(), BaseElement::new(0)]]);
+        program.push(vec![[ResetHardMemory::num(), B::from(0u32)]]);
     }
     program
 }
@@ -7,20 +7,20 @@ use winterfell::{
     Air, AirContext, Assertion, EvaluationFrame, TraceInfo, TransitionConstraintDegree,
 };
 
-use super::{BaseElement, FieldElement, ProofOptions, TRACE_WIDTH};
-use crate::utils::are_equal;
+use super::{FieldElement, ProofOptions, TRACE_WIDTH};
+use crate::{utils::are_equal, ExampleField};
 
 // FIBONACCI AIR
 // ================================================================================================
 
-pub struct FibAir {
-    context: AirContext<BaseElement>,
-    result: BaseElement,
+pub struct FibAir<B: ExampleField> {
+    context: AirContext<B>,
+    result: B,
 }
 
-impl Air for FibAir {
-    type BaseField = BaseElement;
-    type PublicInputs = BaseElement;
+impl<B: ExampleField> Air for FibAir<B> {
+    type BaseField = B;
+    type PublicInputs = B;
 
     // CONSTRUCTOR
     // --------------------------------------------------------------------------------------------
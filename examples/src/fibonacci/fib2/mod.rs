@@ -8,13 +8,22 @@ use std::time::Instant;
 
 use tracing::{field, info_span};
 use winterfell::{
-    crypto::{DefaultRandomCoin, ElementHasher, MerkleTree},
-    math::{fields::f128::BaseElement, FieldElement},
+    crypto::{
+        hashers::{Blake3_160, Blake3_192, Blake3_224, Blake3_256, Sha2_256, Sha3_256},
+        DefaultRandomCoin, ElementHasher, MerkleTree, RandomCoin, VectorCommitment,
+    },
+    math::{
+        fields::{
+            f128::BaseElement as F128BaseElement, f62::BaseElement as F62BaseElement,
+            f64::BaseElement as F64BaseElement,
+        },
+        FieldElement,
+    },
     Proof, ProofOptions, Prover, Trace, VerifierError,
 };
 
 use super::utils::compute_fib_term;
-use crate::{Blake3_192, Blake3_256, Example, ExampleOptions, HashFunction, Sha3_256};
+use crate::{Example, ExampleField, ExampleOptions, FieldType, HashFunction};
 
 mod air;
 use air::FibAir;
@@ -37,36 +46,63 @@ pub fn get_example(
     options: &ExampleOptions,
     sequence_length: usize,
 ) -> Result<Box<dyn Example>, String> {
+    let field = options.field(FieldType::F128);
     let (options, hash_fn) = options.to_proof_options(28, 8);
 
+    match field {
+        FieldType::F62 => build_example::<F62BaseElement>(sequence_length, options, hash_fn),
+        FieldType::F64 => build_example::<F64BaseElement>(sequence_length, options, hash_fn),
+        FieldType::F128 => build_example::<F128BaseElement>(sequence_length, options, hash_fn),
+    }
+}
+
+fn build_example<B: ExampleField>(
+    sequence_length: usize,
+    options: ProofOptions,
+    hash_fn: HashFunction,
+) -> Result<Box<dyn Example>, String> {
     match hash_fn {
+        HashFunction::Blake3_160 => {
+            Ok(Box::new(FibExample::<Blake3_160<B>>::new(sequence_length, options)))
+        },
         HashFunction::Blake3_192 => {
-            Ok(Box::new(FibExample::<Blake3_192>::new(sequence_length, options)))
+            Ok(Box::new(FibExample::<Blake3_192<B>>::new(sequence_length, options)))
+        },
+        HashFunction::Blake3_224 => {
+            Ok(Box::new(FibExample::<Blake3_224<B>>::new(sequence_length, options)))
         },
         HashFunction::Blake3_256 => {
-            Ok(Box::new(FibExample::<Blake3_256>::new(sequence_length, options)))
+            Ok(Box::new(FibExample::<Blake3_256<B>>::new(sequence_length, options)))
         },
         HashFunction::Sha3_256 => {
-            Ok(Box::new(FibExample::<Sha3_256>::new(sequence_length, options)))
+            Ok(Box::new(FibExample::<Sha3_256<B>>::new(sequence_length, options)))
+        },
+        HashFunction::Sha2_256 => {
+            Ok(Box::new(FibExample::<Sha2_256<B>>::new(sequence_length, options)))
+        },
+        hash_fn if hash_fn == B::RESCUE_PRIME => {
+            Ok(Box::new(FibExample::<B::RescuePrime>::new(sequence_length, options)))
         },
         _ => Err("The specified hash function cannot be used with this example.".to_string()),
     }
 }
 
-pub struct FibExample<H: ElementHasher> {
+pub struct FibExample<H: ElementHasher, V = MerkleTree<H>, R = DefaultRandomCoin<H>> {
     options: ProofOptions,
     sequence_length: usize,
-    result: BaseElement,
+    result: H::BaseField,
     _hasher: PhantomData<H>,
+    _vector_commitment: PhantomData<V>,
+    _random_coin: PhantomData<R>,
 }
 
-impl<H: ElementHasher> FibExample<H> {
+impl<H: ElementHasher, V, R> FibExample<H, V, R> {
     pub fn new(sequence_length: usize, options: ProofOptions) -> Self {
         assert!(sequence_length.is_power_of_two(), "sequence length must be a power of 2");
 
         // compute Fibonacci sequence
         let now = Instant::now();
-        let result = compute_fib_term(sequence_length);
+        let result = compute_fib_term::<H::BaseField>(sequence_length);
         println!(
             "Computed Fibonacci sequence up to {}th term in {} ms",
             sequence_length,
@@ -78,6 +114,8 @@ impl<H: ElementHasher> FibExample<H> {
             sequence_length,
             result,
             _hasher: PhantomData,
+            _vector_commitment: PhantomData,
+            _random_coin: PhantomData,
         }
     }
 }
@@ -85,9 +123,12 @@ impl<H: ElementHasher> FibExample<H> {
 // EXAMPLE IMPLEMENTATION
 // ================================================================================================
 
-impl<H: ElementHasher> Example for FibExample<H>
+impl<H: ElementHasher, V, R> Example for FibExample<H, V, R>
 where
-    H: ElementHasher<BaseField = BaseElement> + Sync,
+    H: ElementHasher + Sync,
+    H::BaseField: ExampleField,
+    V: VectorCommitment<H> + Sync,
+    R: RandomCoin<BaseField = H::BaseField, Hasher = H>,
 {
     fn prove(&self) -> Proof {
         println!(
@@ -96,7 +137,7 @@ where
         );
 
         // create a prover
-        let prover = FibProver::<H>::new(self.options.clone());
+        let prover = FibProver::<H, V, R>::new(self.options.clone());
 
         // generate execution trace
         let trace =
@@ -115,19 +156,15 @@ where
         let acceptable_options =
             winterfell::AcceptableOptions::OptionSet(vec![proof.options().clone()]);
 
-        winterfell::verify::<FibAir, H, DefaultRandomCoin<H>, MerkleTree<H>>(
-            proof,
-            self.result,
-            &acceptable_options,
-        )
+        winterfell::verify::<FibAir<H::BaseField>, H, R, V>(proof, self.result, &acceptable_options)
     }
 
     fn verify_with_wrong_inputs(&self, proof: Proof) -> Result<(), VerifierError> {
         let acceptable_options =
             winterfell::AcceptableOptions::OptionSet(vec![proof.options().clone()]);
-        winterfell::verify::<FibAir, H, DefaultRandomCoin<H>, MerkleTree<H>>(
+        winterfell::verify::<FibAir<H::BaseField>, H, R, V>(
             proof,
-            self.result + BaseElement::ONE,
+            self.result + H::BaseField::ONE,
             &acceptable_options,
         )
     }
@@ -4,39 +4,48 @@
 // LICENSE file in the root directory of this source tree.
 
 use winterfell::{
-    crypto::MerkleTree, matrix::ColMatrix, AuxRandElements, CompositionPoly, CompositionPolyTrace,
-    ConstraintCompositionCoefficients, DefaultConstraintCommitment, DefaultConstraintEvaluator,
-    DefaultTraceLde, PartitionOptions, StarkDomain, Trace, TraceInfo, TracePolyTable, TraceTable,
+    crypto::{MerkleTree, RandomCoin, VectorCommitment},
+    matrix::ColMatrix,
+    AuxRandElements, CompositionPoly, CompositionPolyTrace, ConstraintCompositionCoefficients,
+    DefaultConstraintCommitment, DefaultConstraintEvaluator, DefaultTraceLde, PartitionOptions,
+    StarkDomain, Trace, TraceInfo, TracePolyTable, TraceTable,
 };
 
 use super::{
-    BaseElement, DefaultRandomCoin, ElementHasher, FibAir, FieldElement, PhantomData, ProofOptions,
-    Prover, TRACE_WIDTH,
+    DefaultRandomCoin, ElementHasher, ExampleField, FibAir, FieldElement, PhantomData,
+    ProofOptions, Prover, TRACE_WIDTH,
 };
 
 // FIBONACCI PROVER
 // ================================================================================================
 
-pub struct FibProver<H: ElementHasher> {
+pub struct FibProver<H: ElementHasher, V = MerkleTree<H>, R = DefaultRandomCoin<H>> {
     options: ProofOptions,
     _hasher: PhantomData<H>,
+    _vector_commitment: PhantomData<V>,
+    _random_coin: PhantomData<R>,
 }
 
-impl<H: ElementHasher> FibProver<H> {
+impl<H: ElementHasher, V, R> FibProver<H, V, R> {
     pub fn new(options: ProofOptions) -> Self {
-        Self { options, _hasher: PhantomData }
+        Self {
+            options,
+            _hasher: PhantomData,
+            _vector_commitment: PhantomData,
+            _random_coin: PhantomData,
+        }
     }
 
     /// Builds an execution trace for computing a Fibonacci sequence of the specified length such
     /// that each row advances the sequence by 2 terms.
-    pub fn build_trace(&self, sequence_length: usize) -> TraceTable<BaseElement> {
+    pub fn build_trace(&self, sequence_length: usize) -> TraceTable<H::BaseField> {
         assert!(sequence_length.is_power_of_two(), "sequence length must be a power of 2");
 
         let mut trace = TraceTable::new(TRACE_WIDTH, sequence_length / 2);
         trace.fill(
             |state| {
-                state[0] = BaseElement::ONE;
-                state[1] = BaseElement::ONE;
+                state[0] = H::BaseField::ONE;
+                state[1] = H::BaseField::ONE;
             },
             |_, state| {
                 state[0] += state[1];
@@ -48,16 +57,19 @@ impl<H: ElementHasher> FibProver<H> {
     }
 }
 
-impl<H: ElementHasher> Prover for FibProver<H>
+impl<H: ElementHasher, V, R> Prover for FibProver<H, V, R>
 where
-    H: ElementHasher<BaseField = BaseElement> + Sync,
+    H: ElementHasher + Sync,
+    H::BaseField: ExampleField,
+    V: VectorCommitment<H> + Sync,
+    R: RandomCoin<BaseField = H::BaseField, Hasher = H>,
 {
-    type BaseField = BaseElement;
-    type Air = FibAir;
-    type Trace = TraceTable<BaseElement>;
+    type BaseField = H::BaseField;
+    type Air = FibAir<H::BaseField>;
+    type Trace = TraceTable<H::BaseField>;
     type HashFn = H;
-    type VC = MerkleTree<H>;
-    type RandomCoin = DefaultRandomCoin<Self::HashFn>;
+    type VC = V;
+    type RandomCoin = R;
     type TraceLde<E: FieldElement<BaseField = Self::BaseField>> =
         DefaultTraceLde<E, Self::HashFn, Self::VC>;
     type ConstraintCommitment<E: FieldElement<BaseField = Self::BaseField>> =
@@ -65,7 +77,7 @@ where
     type ConstraintEvaluator<'a, E: FieldElement<BaseField = Self::BaseField>> =
         DefaultConstraintEvaluator<'a, Self::Air, E>;
 
-    fn get_pub_inputs(&self, trace: &Self::Trace) -> BaseElement {
+    fn get_pub_inputs(&self, trace: &Self::Trace) -> H::BaseField {
         let last_step = trace.length() - 1;
         trace.get(1, last_step)
     }
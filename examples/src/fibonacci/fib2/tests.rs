@@ -3,7 +3,16 @@
 // This source code is licensed under the MIT license found in the
 // LICENSE file in the root directory of this source tree.
 
-use super::{super::utils::build_proof_options, Blake3_256};
+use winterfell::{
+    crypto::{
+        hashers::{Keccak256, Rp62_248},
+        ArityMerkleTree, KeccakRandomCoin, MerkleCapTree, MerkleTree, SaltedMerkleTree,
+    },
+    math::fields::f128::BaseElement,
+};
+
+use super::super::utils::build_proof_options;
+use crate::{Blake3_160, Blake3_256, Blake3_256F64, Sha2_256};
 
 #[test]
 fn fib2_test_basic_proof_verification() {
@@ -22,3 +31,92 @@ fn fib2_test_basic_proof_verification_fail() {
     let fib = Box::new(super::FibExample::<Blake3_256>::new(16, build_proof_options(false)));
     crate::tests::test_basic_proof_verification_fail(fib);
 }
+
+#[test]
+fn fib2_test_f62_proof_verification() {
+    let fib = Box::new(super::FibExample::<Rp62_248>::new(16, build_proof_options(true)));
+    crate::tests::test_basic_proof_verification(fib);
+}
+
+#[test]
+fn fib2_test_f62_proof_verification_fail() {
+    let fib = Box::new(super::FibExample::<Rp62_248>::new(16, build_proof_options(false)));
+    crate::tests::test_basic_proof_verification_fail(fib);
+}
+
+#[test]
+fn fib2_test_f64_proof_verification() {
+    let fib = Box::new(super::FibExample::<Blake3_256F64>::new(16, build_proof_options(true)));
+    crate::tests::test_basic_proof_verification(fib);
+}
+
+#[test]
+fn fib2_test_sha2_proof_verification() {
+    let fib = Box::new(super::FibExample::<Sha2_256>::new(16, build_proof_options(true)));
+    crate::tests::test_basic_proof_verification(fib);
+}
+
+#[test]
+fn fib2_test_truncated_hash_proof_verification() {
+    let fib = Box::new(super::FibExample::<Blake3_160>::new(16, build_proof_options(true)));
+    crate::tests::test_basic_proof_verification(fib);
+}
+
+#[test]
+fn fib2_test_zk_proof_verification() {
+    let options = build_proof_options(false).with_zk();
+    let fib = Box::new(super::FibExample::<Blake3_256>::new(16, options));
+    crate::tests::test_basic_proof_verification(fib);
+}
+
+#[test]
+fn fib2_test_zk_proof_verification_extension() {
+    let options = build_proof_options(true).with_zk();
+    let fib = Box::new(super::FibExample::<Blake3_256>::new(64, options));
+    crate::tests::test_basic_proof_verification(fib);
+}
+
+#[test]
+fn fib2_test_zk_proof_verification_fail() {
+    let options = build_proof_options(false).with_zk();
+    let fib = Box::new(super::FibExample::<Blake3_256>::new(16, options));
+    crate::tests::test_basic_proof_verification_fail(fib);
+}
+
+#[test]
+fn fib2_test_salted_merkle_tree_proof_verification() {
+    let fib = Box::new(super::FibExample::<Blake3_256, SaltedMerkleTree<Blake3_256>>::new(
+        16,
+        build_proof_options(false),
+    ));
+    crate::tests::test_basic_proof_verification(fib);
+}
+
+#[test]
+fn fib2_test_merkle_cap_proof_verification() {
+    let fib = Box::new(super::FibExample::<Blake3_256, MerkleCapTree<Blake3_256>>::new(
+        64,
+        build_proof_options(false),
+    ));
+    crate::tests::test_basic_proof_verification(fib);
+}
+
+#[test]
+fn fib2_test_arity_merkle_tree_proof_verification() {
+    let fib = Box::new(super::FibExample::<Blake3_256, ArityMerkleTree<Blake3_256, 4>>::new(
+        64,
+        build_proof_options(false),
+    ));
+    crate::tests::test_basic_proof_verification(fib);
+}
+
+#[test]
+fn fib2_test_keccak_proof_verification() {
+    type Keccak = Keccak256<BaseElement>;
+    let fib = Box::new(super::FibExample::<
+        Keccak,
+        MerkleTree<Keccak>,
+        KeccakRandomCoin<BaseElement>,
+    >::new(16, build_proof_options(true)));
+    crate::tests::test_basic_proof_verification(fib);
+}
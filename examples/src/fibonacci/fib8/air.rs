@@ -8,20 +8,20 @@ use winterfell::{
     TransitionConstraintDegree,
 };
 
-use super::{BaseElement, FieldElement, TRACE_WIDTH};
-use crate::utils::are_equal;
+use super::{FieldElement, TRACE_WIDTH};
+use crate::{utils::are_equal, ExampleField};
 
 // FIBONACCI AIR
 // ================================================================================================
 
-pub struct Fib8Air {
-    context: AirContext<BaseElement>,
-    result: BaseElement,
+pub struct Fib8Air<B: ExampleField> {
+    context: AirContext<B>,
+    result: B,
 }
 
-impl Air for Fib8Air {
-    type BaseField = BaseElement;
-    type PublicInputs = BaseElement;
+impl<B: ExampleField> Air for Fib8Air<B> {
+    type BaseField = B;
+    type PublicInputs = B;
 
     // CONSTRUCTOR
     // --------------------------------------------------------------------------------------------
@@ -69,8 +69,8 @@ impl Air for Fib8Air {
         // 6 terms are not recorded in the trace), and ends with the expected result
         let last_step = self.trace_length() - 1;
         vec![
-            Assertion::single(0, 0, BaseElement::new(13)),
-            Assertion::single(1, 0, BaseElement::new(21)),
+            Assertion::single(0, 0, B::from(13u32)),
+            Assertion::single(1, 0, B::from(21u32)),
             Assertion::single(1, last_step, self.result),
         ]
     }
@@ -8,13 +8,22 @@ use std::time::Instant;
 
 use tracing::{field, info_span};
 use winterfell::{
-    crypto::{DefaultRandomCoin, ElementHasher, MerkleTree},
-    math::{fields::f128::BaseElement, FieldElement},
+    crypto::{
+        hashers::{Blake3_192, Blake3_256, Sha2_256, Sha3_256},
+        DefaultRandomCoin, ElementHasher, MerkleTree,
+    },
+    math::{
+        fields::{
+            f128::BaseElement as F128BaseElement, f62::BaseElement as F62BaseElement,
+            f64::BaseElement as F64BaseElement,
+        },
+        FieldElement,
+    },
     Proof, ProofOptions, Prover, Trace, VerifierError,
 };
 
 use super::utils::compute_fib_term;
-use crate::{Blake3_192, Blake3_256, Example, ExampleOptions, HashFunction, Sha3_256};
+use crate::{Example, ExampleField, ExampleOptions, FieldType, HashFunction};
 
 mod air;
 use air::Fib8Air;
@@ -37,17 +46,36 @@ pub fn get_example(
     options: &ExampleOptions,
     sequence_length: usize,
 ) -> Result<Box<dyn Example>, String> {
+    let field = options.field(FieldType::F128);
     let (options, hash_fn) = options.to_proof_options(28, 8);
 
+    match field {
+        FieldType::F62 => build_example::<F62BaseElement>(sequence_length, options, hash_fn),
+        FieldType::F64 => build_example::<F64BaseElement>(sequence_length, options, hash_fn),
+        FieldType::F128 => build_example::<F128BaseElement>(sequence_length, options, hash_fn),
+    }
+}
+
+fn build_example<B: ExampleField>(
+    sequence_length: usize,
+    options: ProofOptions,
+    hash_fn: HashFunction,
+) -> Result<Box<dyn Example>, String> {
     match hash_fn {
         HashFunction::Blake3_192 => {
-            Ok(Box::new(Fib8Example::<Blake3_192>::new(sequence_length, options)))
+            Ok(Box::new(Fib8Example::<Blake3_192<B>>::new(sequence_length, options)))
         },
         HashFunction::Blake3_256 => {
-            Ok(Box::new(Fib8Example::<Blake3_256>::new(sequence_length, options)))
+            Ok(Box::new(Fib8Example::<Blake3_256<B>>::new(sequence_length, options)))
         },
         HashFunction::Sha3_256 => {
-            Ok(Box::new(Fib8Example::<Sha3_256>::new(sequence_length, options)))
+            Ok(Box::new(Fib8Example::<Sha3_256<B>>::new(sequence_length, options)))
+        },
+        HashFunction::Sha2_256 => {
+            Ok(Box::new(Fib8Example::<Sha2_256<B>>::new(sequence_length, options)))
+        },
+        hash_fn if hash_fn == B::RESCUE_PRIME => {
+            Ok(Box::new(Fib8Example::<B::RescuePrime>::new(sequence_length, options)))
         },
         _ => Err("The specified hash function cannot be used with this example.".to_string()),
     }
@@ -56,7 +84,7 @@ pub fn get_example(
 pub struct Fib8Example<H: ElementHasher> {
     options: ProofOptions,
     sequence_length: usize,
-    result: BaseElement,
+    result: H::BaseField,
     _hasher: PhantomData<H>,
 }
 
@@ -66,7 +94,7 @@ impl<H: ElementHasher> Fib8Example<H> {
 
         // compute Fibonacci sequence
         let now = Instant::now();
-        let result = compute_fib_term(sequence_length);
+        let result = compute_fib_term::<H::BaseField>(sequence_length);
         println!(
             "Computed Fibonacci sequence up to {}th term in {} ms",
             sequence_length,
@@ -87,7 +115,8 @@ impl<H: ElementHasher> Fib8Example<H> {
 
 impl<H: ElementHasher> Example for Fib8Example<H>
 where
-    H: ElementHasher<BaseField = BaseElement> + Sync,
+    H: ElementHasher + Sync,
+    H::BaseField: ExampleField,
 {
     fn prove(&self) -> Proof {
         println!(
@@ -114,7 +143,7 @@ where
     fn verify(&self, proof: Proof) -> Result<(), VerifierError> {
         let acceptable_options =
             winterfell::AcceptableOptions::OptionSet(vec![proof.options().clone()]);
-        winterfell::verify::<Fib8Air, H, DefaultRandomCoin<H>, MerkleTree<H>>(
+        winterfell::verify::<Fib8Air<H::BaseField>, H, DefaultRandomCoin<H>, MerkleTree<H>>(
             proof,
             self.result,
             &acceptable_options,
@@ -124,9 +153,9 @@ where
     fn verify_with_wrong_inputs(&self, proof: Proof) -> Result<(), VerifierError> {
         let acceptable_options =
             winterfell::AcceptableOptions::OptionSet(vec![proof.options().clone()]);
-        winterfell::verify::<Fib8Air, H, DefaultRandomCoin<H>, MerkleTree<H>>(
+        winterfell::verify::<Fib8Air<H::BaseField>, H, DefaultRandomCoin<H>, MerkleTree<H>>(
             proof,
-            self.result + BaseElement::ONE,
+            self.result + H::BaseField::ONE,
             &acceptable_options,
         )
     }
@@ -10,7 +10,7 @@ use winterfell::{
 };
 
 use super::{
-    BaseElement, DefaultRandomCoin, ElementHasher, Fib8Air, FieldElement, PhantomData,
+    DefaultRandomCoin, ElementHasher, ExampleField, Fib8Air, FieldElement, PhantomData,
     ProofOptions, Prover,
 };
 
@@ -29,12 +29,12 @@ impl<H: ElementHasher> Fib8Prover<H> {
 
     /// Builds an execution trace for computing a Fibonacci sequence of the specified length such
     /// that each row advances the sequence by 8 terms.
-    pub fn build_trace(&self, length: usize) -> TraceTable<BaseElement> {
+    pub fn build_trace(&self, length: usize) -> TraceTable<H::BaseField> {
         assert!(length.is_power_of_two(), "sequence length must be a power of 2");
 
         // initialize the trace with 7th and 8th terms of Fibonacci sequence (skipping the first 6)
-        let n0 = BaseElement::ONE;
-        let n1 = BaseElement::ONE;
+        let n0 = H::BaseField::ONE;
+        let n1 = H::BaseField::ONE;
         let n2 = n0 + n1;
         let n3 = n1 + n2;
         let n4 = n2 + n3;
@@ -65,11 +65,12 @@ impl<H: ElementHasher> Fib8Prover<H> {
 
 impl<H: ElementHasher> Prover for Fib8Prover<H>
 where
-    H: ElementHasher<BaseField = BaseElement> + Sync,
+    H: ElementHasher + Sync,
+    H::BaseField: ExampleField,
 {
-    type BaseField = BaseElement;
-    type Air = Fib8Air;
-    type Trace = TraceTable<BaseElement>;
+    type BaseField = H::BaseField;
+    type Air = Fib8Air<H::BaseField>;
+    type Trace = TraceTable<H::BaseField>;
     type HashFn = H;
     type VC = MerkleTree<H>;
     type RandomCoin = DefaultRandomCoin<Self::HashFn>;
@@ -80,7 +81,7 @@ where
     type ConstraintEvaluator<'a, E: FieldElement<BaseField = Self::BaseField>> =
         DefaultConstraintEvaluator<'a, Self::Air, E>;
 
-    fn get_pub_inputs(&self, trace: &Self::Trace) -> BaseElement {
+    fn get_pub_inputs(&self, trace: &Self::Trace) -> H::BaseField {
         let last_step = trace.length() - 1;
         trace.get(1, last_step)
     }
@@ -0,0 +1,67 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use winterfell::{
+    Air, AirContext, Assertion, EvaluationFrame, TraceInfo, TransitionConstraintDegree,
+};
+
+use super::{BaseElement, FieldElement, ProofOptions, TRACE_WIDTH};
+use crate::utils::are_equal;
+
+// FIBONACCI AIR
+// ================================================================================================
+
+pub struct FibFrameAir {
+    context: AirContext<BaseElement>,
+    result: BaseElement,
+}
+
+impl Air for FibFrameAir {
+    type BaseField = BaseElement;
+    type PublicInputs = BaseElement;
+
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+    fn new(trace_info: TraceInfo, pub_inputs: Self::BaseField, options: ProofOptions) -> Self {
+        let degrees = vec![TransitionConstraintDegree::new(1)];
+        assert_eq!(TRACE_WIDTH, trace_info.width());
+
+        // transition constraints need to look 2 rows ahead of the current row; this also
+        // exempts the last 2 rows of the trace from transition constraints
+        let context =
+            AirContext::new(trace_info, degrees, 3, options).set_frame_offsets(vec![0, 1, 2]);
+        FibFrameAir { context, result: pub_inputs }
+    }
+
+    fn context(&self) -> &AirContext<Self::BaseField> {
+        &self.context
+    }
+
+    fn evaluate_transition<E: FieldElement + From<Self::BaseField>>(
+        &self,
+        frame: &EvaluationFrame<E>,
+        _periodic_values: &[E],
+        result: &mut [E],
+    ) {
+        // expected frame is 3 rows of 1 field element each
+        debug_assert_eq!(3, frame.num_rows());
+        debug_assert_eq!(TRACE_WIDTH, frame.current().len());
+
+        // constraint of Fibonacci sequence (1 term per step):
+        // s_{i+2} = s_{i+1} + s_{i}
+        result[0] = are_equal(frame.row(2)[0], frame.next()[0] + frame.current()[0]);
+    }
+
+    fn get_assertions(&self) -> Vec<Assertion<Self::BaseField>> {
+        // a valid Fibonacci sequence should start with two ones and terminate with
+        // the expected result
+        let last_step = self.trace_length() - 1;
+        vec![
+            Assertion::single(0, 0, Self::BaseField::ONE),
+            Assertion::single(0, 1, Self::BaseField::ONE),
+            Assertion::single(0, last_step, self.result),
+        ]
+    }
+}
@@ -0,0 +1,69 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use winterfell::{
+    Air, AirContext, Assertion, EvaluationFrame, TraceInfo, TransitionConstraintDegree,
+};
+
+use super::{BaseElement, FieldElement, ProofOptions, LONG_LAG, SHORT_LAG, TRACE_WIDTH};
+use crate::utils::are_equal;
+
+// LAGGED FIBONACCI AIR
+// ================================================================================================
+
+pub struct LaggedFibAir {
+    context: AirContext<BaseElement>,
+    result: BaseElement,
+}
+
+impl Air for LaggedFibAir {
+    type BaseField = BaseElement;
+    type PublicInputs = BaseElement;
+
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+    fn new(trace_info: TraceInfo, pub_inputs: Self::BaseField, options: ProofOptions) -> Self {
+        let degrees = vec![TransitionConstraintDegree::new(1)];
+        assert_eq!(TRACE_WIDTH, trace_info.width());
+
+        // the transition constraint defines the next term using the terms SHORT_LAG and LONG_LAG
+        // steps before it, and thus, needs to look back LONG_LAG - 1 rows from the current row;
+        // this exempts the first LONG_LAG - 1 rows of the trace from transition constraints
+        let frame_offsets = vec![0, 1, 1 - LONG_LAG as isize, 1 - SHORT_LAG as isize];
+        let context = AirContext::new(trace_info, degrees, LONG_LAG + 1, options)
+            .set_frame_offsets(frame_offsets);
+        LaggedFibAir { context, result: pub_inputs }
+    }
+
+    fn context(&self) -> &AirContext<Self::BaseField> {
+        &self.context
+    }
+
+    fn evaluate_transition<E: FieldElement + From<Self::BaseField>>(
+        &self,
+        frame: &EvaluationFrame<E>,
+        _periodic_values: &[E],
+        result: &mut [E],
+    ) {
+        // expected frame is 4 rows of 1 field element each
+        debug_assert_eq!(4, frame.num_rows());
+        debug_assert_eq!(TRACE_WIDTH, frame.current().len());
+
+        // constraint of lagged Fibonacci sequence (1 term per step):
+        // s_{i+1} = s_{i+1-7} + s_{i+1-2}
+        result[0] = are_equal(frame.next()[0], frame.row(2)[0] + frame.row(3)[0]);
+    }
+
+    fn get_assertions(&self) -> Vec<Assertion<Self::BaseField>> {
+        // a valid lagged Fibonacci sequence should start with LONG_LAG ones and terminate with
+        // the expected result
+        let last_step = self.trace_length() - 1;
+        let mut assertions: Vec<_> = (0..LONG_LAG)
+            .map(|step| Assertion::single(0, step, Self::BaseField::ONE))
+            .collect();
+        assertions.push(Assertion::single(0, last_step, self.result));
+        assertions
+    }
+}
@@ -4,23 +4,27 @@
 // LICENSE file in the root directory of this source tree.
 
 use winterfell::{
+    math::{ExtensibleField, StarkField},
     Air, AirContext, Assertion, EvaluationFrame, TraceInfo, TransitionConstraintDegree,
 };
 
-use super::{BaseElement, FieldElement, ProofOptions, TRACE_WIDTH};
+use super::{FieldElement, ProofOptions, TRACE_WIDTH};
 use crate::utils::are_equal;
 
 // FIBONACCI AIR
 // ================================================================================================
 
-pub struct FibSmall {
-    context: AirContext<BaseElement>,
-    result: BaseElement,
+pub struct FibSmall<B: StarkField> {
+    context: AirContext<B>,
+    result: B,
 }
 
-impl Air for FibSmall {
-    type BaseField = BaseElement;
-    type PublicInputs = BaseElement;
+impl<B> Air for FibSmall<B>
+where
+    B: StarkField + ExtensibleField<2> + ExtensibleField<3> + ExtensibleField<4>,
+{
+    type BaseField = B;
+    type PublicInputs = B;
 
     // CONSTRUCTOR
     // --------------------------------------------------------------------------------------------
@@ -9,7 +9,7 @@ use std::time::Instant;
 use tracing::{field, info_span};
 use winterfell::{
     crypto::{DefaultRandomCoin, ElementHasher, MerkleTree},
-    math::{fields::f64::BaseElement, FieldElement},
+    math::{fields::f64::BaseElement, ExtensibleField, FieldElement},
     Proof, ProofOptions, Prover, Trace, VerifierError,
 };
 
@@ -32,6 +32,7 @@ const TRACE_WIDTH: usize = 2;
 
 type Blake3_192 = winterfell::crypto::hashers::Blake3_192<BaseElement>;
 type Blake3_256 = winterfell::crypto::hashers::Blake3_256<BaseElement>;
+type Sha2_256 = winterfell::crypto::hashers::Sha2_256<BaseElement>;
 type Sha3_256 = winterfell::crypto::hashers::Sha3_256<BaseElement>;
 type Rp64_256 = winterfell::crypto::hashers::Rp64_256;
 type RpJive64_256 = winterfell::crypto::hashers::RpJive64_256;
@@ -55,19 +56,23 @@ pub fn get_example(
         HashFunction::Sha3_256 => {
             Ok(Box::new(FibExample::<Sha3_256>::new(sequence_length, options)))
         },
+        HashFunction::Sha2_256 => {
+            Ok(Box::new(FibExample::<Sha2_256>::new(sequence_length, options)))
+        },
         HashFunction::Rp64_256 => {
             Ok(Box::new(FibExample::<Rp64_256>::new(sequence_length, options)))
         },
         HashFunction::RpJive64_256 => {
             Ok(Box::new(FibExample::<RpJive64_256>::new(sequence_length, options)))
         },
+        _ => Err("The specified hash function cannot be used with this example.".to_string()),
     }
 }
 
 pub struct FibExample<H: ElementHasher> {
     options: ProofOptions,
     sequence_length: usize,
-    result: BaseElement,
+    result: H::BaseField,
     _hasher: PhantomData<H>,
 }
 
@@ -77,7 +82,7 @@ impl<H: ElementHasher> FibExample<H> {
 
         // compute Fibonacci sequence
         let now = Instant::now();
-        let result = compute_fib_term::<BaseElement>(sequence_length);
+        let result = compute_fib_term::<H::BaseField>(sequence_length);
         println!(
             "Computed Fibonacci sequence up to {}th term in {} ms",
             sequence_length,
@@ -98,7 +103,8 @@ impl<H: ElementHasher> FibExample<H> {
 
 impl<H: ElementHasher> Example for FibExample<H>
 where
-    H: ElementHasher<BaseField = BaseElement> + Sync,
+    H: Sync,
+    H::BaseField: ExtensibleField<2> + ExtensibleField<3> + ExtensibleField<4> + 'static,
 {
     fn prove(&self) -> Proof {
         println!(
@@ -126,7 +132,7 @@ where
         let acceptable_options =
             winterfell::AcceptableOptions::OptionSet(vec![proof.options().clone()]);
 
-        winterfell::verify::<FibSmall, H, DefaultRandomCoin<H>, MerkleTree<H>>(
+        winterfell::verify::<FibSmall<H::BaseField>, H, DefaultRandomCoin<H>, MerkleTree<H>>(
             proof,
             self.result,
             &acceptable_options,
@@ -136,9 +142,9 @@ where
     fn verify_with_wrong_inputs(&self, proof: Proof) -> Result<(), VerifierError> {
         let acceptable_options =
             winterfell::AcceptableOptions::OptionSet(vec![proof.options().clone()]);
-        winterfell::verify::<FibSmall, H, DefaultRandomCoin<H>, MerkleTree<H>>(
+        winterfell::verify::<FibSmall<H::BaseField>, H, DefaultRandomCoin<H>, MerkleTree<H>>(
             proof,
-            self.result + BaseElement::ONE,
+            self.result + H::BaseField::ONE,
             &acceptable_options,
         )
     }
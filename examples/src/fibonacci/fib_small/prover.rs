@@ -9,7 +9,7 @@ use winterfell::{
 };
 
 use super::{
-    air::FibSmall, BaseElement, DefaultRandomCoin, ElementHasher, FieldElement, PhantomData,
+    air::FibSmall, DefaultRandomCoin, ElementHasher, ExtensibleField, FieldElement, PhantomData,
     ProofOptions, Prover, TRACE_WIDTH,
 };
 
@@ -34,14 +34,14 @@ where
 
     /// Builds an execution trace for computing a Fibonacci sequence of the specified length such
     /// that each row advances the sequence by 2 terms.
-    pub fn build_trace(&self, sequence_length: usize) -> TraceTable<BaseElement> {
+    pub fn build_trace(&self, sequence_length: usize) -> TraceTable<H::BaseField> {
         assert!(sequence_length.is_power_of_two(), "sequence length must be a power of 2");
 
         let mut trace = TraceTable::new(TRACE_WIDTH, sequence_length / 2);
         trace.fill(
             |state| {
-                state[0] = BaseElement::ONE;
-                state[1] = BaseElement::ONE;
+                state[0] = H::BaseField::ONE;
+                state[1] = H::BaseField::ONE;
             },
             |_, state| {
                 state[0] += state[1];
@@ -55,11 +55,11 @@ where
 
 impl<H: ElementHasher + Sync> Prover for FibSmallProver<H>
 where
-    H: ElementHasher<BaseField = BaseElement>,
+    H::BaseField: ExtensibleField<2> + ExtensibleField<3> + ExtensibleField<4> + 'static,
 {
-    type BaseField = BaseElement;
-    type Air = FibSmall;
-    type Trace = TraceTable<BaseElement>;
+    type BaseField = H::BaseField;
+    type Air = FibSmall<H::BaseField>;
+    type Trace = TraceTable<H::BaseField>;
     type HashFn = H;
     type VC = MerkleTree<Self::HashFn>;
     type RandomCoin = DefaultRandomCoin<Self::HashFn>;
@@ -70,7 +70,7 @@ where
     type ConstraintEvaluator<'a, E: FieldElement<BaseField = Self::BaseField>> =
         DefaultConstraintEvaluator<'a, Self::Air, E>;
 
-    fn get_pub_inputs(&self, trace: &Self::Trace) -> BaseElement {
+    fn get_pub_inputs(&self, trace: &Self::Trace) -> H::BaseField {
         let last_step = trace.length() - 1;
         trace.get(1, last_step)
     }
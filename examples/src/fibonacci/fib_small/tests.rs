@@ -3,6 +3,10 @@
 // This source code is licensed under the MIT license found in the
 // LICENSE file in the root directory of this source tree.
 
+use winterfell::{
+    crypto::hashers::Blake3_256, math::fields::f31, BatchingMethod, FieldExtension, ProofOptions,
+};
+
 use super::{super::utils::build_proof_options, Rp64_256};
 
 #[test]
@@ -22,3 +26,34 @@ fn fib_small_test_basic_proof_verification_fail() {
     let fib = Box::new(super::FibExample::<Rp64_256>::new(128, build_proof_options(false)));
     crate::tests::test_basic_proof_verification_fail(fib);
 }
+
+#[test]
+fn fib_small_test_babybear_proof_verification() {
+    let fib = Box::new(super::FibExample::<Blake3_256<f31::BaseElement>>::new(
+        128,
+        build_babybear_proof_options(),
+    ));
+    crate::tests::test_basic_proof_verification(fib);
+}
+
+#[test]
+fn fib_small_test_babybear_proof_verification_fail() {
+    let fib = Box::new(super::FibExample::<Blake3_256<f31::BaseElement>>::new(
+        128,
+        build_babybear_proof_options(),
+    ));
+    crate::tests::test_basic_proof_verification_fail(fib);
+}
+
+fn build_babybear_proof_options() -> ProofOptions {
+    ProofOptions::new(
+        28,
+        8,
+        0,
+        FieldExtension::Quartic,
+        4,
+        7,
+        BatchingMethod::Linear,
+        BatchingMethod::Linear,
+    )
+}
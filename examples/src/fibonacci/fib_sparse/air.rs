@@ -0,0 +1,85 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use winterfell::{
+    Air, AirContext, Assertion, ConstraintDivisor, EvaluationFrame, TraceInfo,
+    TransitionConstraintDegree,
+};
+
+use super::{BaseElement, FieldElement, ProofOptions, SAMPLE_PERIOD, TRACE_WIDTH};
+use crate::utils::are_equal;
+
+// FIBONACCI AIR
+// ================================================================================================
+
+pub struct FibSparseAir {
+    context: AirContext<BaseElement>,
+    result: BaseElement,
+}
+
+impl Air for FibSparseAir {
+    type BaseField = BaseElement;
+    type PublicInputs = BaseElement;
+
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+    fn new(trace_info: TraceInfo, pub_inputs: Self::BaseField, options: ProofOptions) -> Self {
+        let degrees = vec![TransitionConstraintDegree::new(1); 3];
+        assert_eq!(TRACE_WIDTH, trace_info.width());
+
+        // the sum constraint needs to look SAMPLE_PERIOD rows ahead of the current row, and is
+        // enforced only on every SAMPLE_PERIOD-th row; the last sampled row is exempt because
+        // the frame would wrap around the end of the trace
+        let trace_length = trace_info.length();
+        let sum_divisor =
+            ConstraintDivisor::from_periodic_transition(trace_length, 0, SAMPLE_PERIOD, 1);
+
+        // Fibonacci constraints look only one row ahead, and thus, need to be skipped only on
+        // the last row of the trace; since the frame reaches SAMPLE_PERIOD rows ahead, this
+        // requires an explicit divisor for these constraints
+        let fib_divisor = ConstraintDivisor::from_transition(trace_length, 1);
+        let context = AirContext::new(trace_info, degrees, 4, options)
+            .set_frame_offsets(vec![0, 1, SAMPLE_PERIOD as isize])
+            .set_transition_divisor(&[2], sum_divisor)
+            .set_transition_divisor(&[0, 1], fib_divisor);
+        FibSparseAir { context, result: pub_inputs }
+    }
+
+    fn context(&self) -> &AirContext<Self::BaseField> {
+        &self.context
+    }
+
+    fn evaluate_transition<E: FieldElement + From<Self::BaseField>>(
+        &self,
+        frame: &EvaluationFrame<E>,
+        _periodic_values: &[E],
+        result: &mut [E],
+    ) {
+        let current = frame.current();
+        let next = frame.next();
+        let sample_next = frame.row(2);
+        debug_assert_eq!(TRACE_WIDTH, current.len());
+
+        // constraints of Fibonacci sequence (1 term per step)
+        result[0] = are_equal(next[0], current[1]);
+        result[1] = are_equal(next[1], current[0] + current[1]);
+
+        // the sum of sampled terms is incremented by the current term; this constraint is
+        // enforced only on sampled rows, and thus, does not need to be multiplied by a selector
+        result[2] = are_equal(sample_next[2], current[2] + current[0]);
+    }
+
+    fn get_assertions(&self) -> Vec<Assertion<Self::BaseField>> {
+        // a valid Fibonacci sequence should start with two ones, the sum should start with zero
+        // and end (at the last sampled row) with the expected result
+        let last_sample = self.trace_length() - SAMPLE_PERIOD;
+        vec![
+            Assertion::single(0, 0, Self::BaseField::ONE),
+            Assertion::single(1, 0, Self::BaseField::ONE),
+            Assertion::single(2, 0, Self::BaseField::ZERO),
+            Assertion::single(2, last_sample, self.result),
+        ]
+    }
+}
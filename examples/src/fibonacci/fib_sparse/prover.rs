@@ -0,0 +1,123 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+use winterfell::{
+    crypto::MerkleTree, matrix::ColMatrix, AuxRandElements, CompositionPoly, CompositionPolyTrace,
+    ConstraintCompositionCoefficients, DefaultConstraintCommitment, DefaultConstraintEvaluator,
+    DefaultTraceLde, PartitionOptions, StarkDomain, Trace, TraceInfo, TracePolyTable, TraceTable,
+};
+
+use super::{
+    air::FibSparseAir, BaseElement, DefaultRandomCoin, ElementHasher, FieldElement, PhantomData,
+    ProofOptions, Prover, SAMPLE_PERIOD, TRACE_WIDTH,
+};
+
+// FIBONACCI PROVER
+// ================================================================================================
+
+pub struct FibSparseProver<H: ElementHasher>
+where
+    H: Sync,
+{
+    options: ProofOptions,
+    _hasher: PhantomData<H>,
+}
+
+impl<H: ElementHasher> FibSparseProver<H>
+where
+    H: Sync,
+{
+    pub fn new(options: ProofOptions) -> Self {
+        Self { options, _hasher: PhantomData }
+    }
+
+    /// Builds an execution trace for computing a Fibonacci sequence of the specified length
+    /// together with a sum of every [SAMPLE_PERIOD]-th term of the sequence.
+    ///
+    /// The first two columns advance the sequence by 1 term per row. The third column holds the
+    /// sum of the terms sampled before the current block of [SAMPLE_PERIOD] rows; its values are
+    /// constrained only on sampled rows.
+    pub fn build_trace(&self, sequence_length: usize) -> TraceTable<BaseElement> {
+        assert!(sequence_length.is_power_of_two(), "sequence length must be a power of 2");
+
+        let mut columns = vec![vec![BaseElement::ZERO; sequence_length]; TRACE_WIDTH];
+        columns[0][0] = BaseElement::ONE;
+        columns[1][0] = BaseElement::ONE;
+        for i in 1..sequence_length {
+            columns[0][i] = columns[1][i - 1];
+            columns[1][i] = columns[0][i - 1] + columns[1][i - 1];
+
+            // the sum is updated at the start of each block with the term sampled at the start
+            // of the previous block
+            columns[2][i] = if i % SAMPLE_PERIOD == 0 {
+                columns[2][i - 1] + columns[0][i - SAMPLE_PERIOD]
+            } else {
+                columns[2][i - 1]
+            };
+        }
+
+        TraceTable::init(columns)
+    }
+}
+
+impl<H: ElementHasher + Sync> Prover for FibSparseProver<H>
+where
+    H: ElementHasher<BaseField = BaseElement>,
+{
+    type BaseField = BaseElement;
+    type Air = FibSparseAir;
+    type Trace = TraceTable<BaseElement>;
+    type HashFn = H;
+    type VC = MerkleTree<Self::HashFn>;
+    type RandomCoin = DefaultRandomCoin<Self::HashFn>;
+    type TraceLde<E: FieldElement<BaseField = Self::BaseField>> =
+        DefaultTraceLde<E, Self::HashFn, Self::VC>;
+    type ConstraintCommitment<E: FieldElement<BaseField = Self::BaseField>> =
+        DefaultConstraintCommitment<E, H, Self::VC>;
+    type ConstraintEvaluator<'a, E: FieldElement<BaseField = Self::BaseField>> =
+        DefaultConstraintEvaluator<'a, Self::Air, E>;
+
+    fn get_pub_inputs(&self, trace: &Self::Trace) -> BaseElement {
+        let last_sample = trace.length() - SAMPLE_PERIOD;
+        trace.get(2, last_sample)
+    }
+
+    fn options(&self) -> &ProofOptions {
+        &self.options
+    }
+
+    fn new_trace_lde<E: FieldElement<BaseField = Self::BaseField>>(
+        &self,
+        trace_info: &TraceInfo,
+        main_trace: &ColMatrix<Self::BaseField>,
+        domain: &StarkDomain<Self::BaseField>,
+        partition_option: PartitionOptions,
+    ) -> (Self::TraceLde<E>, TracePolyTable<E>) {
+        DefaultTraceLde::new(trace_info, main_trace, domain, partition_option)
+    }
+
+    fn new_evaluator<'a, E: FieldElement<BaseField = Self::BaseField>>(
+        &self,
+        air: &'a Self::Air,
+        aux_rand_elements: Option<AuxRandElements<E>>,
+        composition_coefficients: ConstraintCompositionCoefficients<E>,
+    ) -> Self::ConstraintEvaluator<'a, E> {
+        DefaultConstraintEvaluator::new(air, aux_rand_elements, composition_coefficients)
+    }
+
+    fn build_constraint_commitment<E: FieldElement<BaseField = Self::BaseField>>(
+        &self,
+        composition_poly_trace: CompositionPolyTrace<E>,
+        num_constraint_composition_columns: usize,
+        domain: &StarkDomain<Self::BaseField>,
+        partition_options: PartitionOptions,
+    ) -> (Self::ConstraintCommitment<E>, CompositionPoly<E>) {
+        DefaultConstraintCommitment::new(
+            composition_poly_trace,
+            num_constraint_composition_columns,
+            domain,
+            partition_options,
+        )
+    }
+}
@@ -5,7 +5,10 @@
 
 pub mod fib2;
 pub mod fib8;
+pub mod fib_frame;
+pub mod fib_lagged;
 pub mod fib_small;
+pub mod fib_sparse;
 pub mod mulfib2;
 pub mod mulfib8;
 
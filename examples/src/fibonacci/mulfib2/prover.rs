@@ -10,7 +10,7 @@ use winterfell::{
 };
 
 use super::{
-    BaseElement, DefaultRandomCoin, ElementHasher, FieldElement, MulFib2Air, PhantomData,
+    DefaultRandomCoin, ElementHasher, ExampleField, FieldElement, MulFib2Air, PhantomData,
     ProofOptions, Prover,
 };
 
@@ -29,11 +29,11 @@ impl<H: ElementHasher> MulFib2Prover<H> {
 
     /// Builds an execution trace for computing a multiplicative version of a Fibonacci sequence of
     /// the specified length such that each row advances the sequence by 2 terms.
-    pub fn build_trace(&self, length: usize) -> TraceTable<BaseElement> {
+    pub fn build_trace(&self, length: usize) -> TraceTable<H::BaseField> {
         assert!(length.is_power_of_two(), "sequence length must be a power of 2");
 
-        let mut reg0 = vec![BaseElement::new(1)];
-        let mut reg1 = vec![BaseElement::new(2)];
+        let mut reg0 = vec![H::BaseField::ONE];
+        let mut reg1 = vec![H::BaseField::from(2u32)];
 
         for i in 0..(length / 2 - 1) {
             reg0.push(reg0[i] * reg1[i]);
@@ -46,11 +46,12 @@ impl<H: ElementHasher> MulFib2Prover<H> {
 
 impl<H: ElementHasher> Prover for MulFib2Prover<H>
 where
-    H: ElementHasher<BaseField = BaseElement> + Sync,
+    H: ElementHasher + Sync,
+    H::BaseField: ExampleField,
 {
-    type BaseField = BaseElement;
-    type Air = MulFib2Air;
-    type Trace = TraceTable<BaseElement>;
+    type BaseField = H::BaseField;
+    type Air = MulFib2Air<H::BaseField>;
+    type Trace = TraceTable<H::BaseField>;
     type HashFn = H;
     type VC = MerkleTree<H>;
     type RandomCoin = DefaultRandomCoin<Self::HashFn>;
@@ -61,7 +62,7 @@ where
     type ConstraintEvaluator<'a, E: FieldElement<BaseField = Self::BaseField>> =
         DefaultConstraintEvaluator<'a, Self::Air, E>;
 
-    fn get_pub_inputs(&self, trace: &Self::Trace) -> BaseElement {
+    fn get_pub_inputs(&self, trace: &Self::Trace) -> H::BaseField {
         let last_step = trace.length() - 1;
         trace.get(0, last_step)
     }
@@ -4,25 +4,24 @@
 // LICENSE file in the root directory of this source tree.
 
 use winterfell::{
-    math::{fields::f128::BaseElement, FieldElement},
-    Air, AirContext, Assertion, EvaluationFrame, ProofOptions, TraceInfo,
+    math::FieldElement, Air, AirContext, Assertion, EvaluationFrame, ProofOptions, TraceInfo,
     TransitionConstraintDegree,
 };
 
 use super::TRACE_WIDTH;
-use crate::utils::are_equal;
+use crate::{utils::are_equal, ExampleField};
 
 // FIBONACCI AIR
 // ================================================================================================
 
-pub struct MulFib8Air {
-    context: AirContext<BaseElement>,
-    result: BaseElement,
+pub struct MulFib8Air<B: ExampleField> {
+    context: AirContext<B>,
+    result: B,
 }
 
-impl Air for MulFib8Air {
-    type BaseField = BaseElement;
-    type PublicInputs = BaseElement;
+impl<B: ExampleField> Air for MulFib8Air<B> {
+    type BaseField = B;
+    type PublicInputs = B;
 
     // CONSTRUCTOR
     // --------------------------------------------------------------------------------------------
@@ -84,8 +83,8 @@ impl Air for MulFib8Air {
         // with the expected result
         let last_step = self.trace_length() - 1;
         vec![
-            Assertion::single(0, 0, BaseElement::new(1)),
-            Assertion::single(1, 0, BaseElement::new(2)),
+            Assertion::single(0, 0, B::ONE),
+            Assertion::single(1, 0, B::from(2u32)),
             Assertion::single(6, last_step, self.result),
         ]
     }
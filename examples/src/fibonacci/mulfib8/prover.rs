@@ -10,7 +10,7 @@ use winterfell::{
 };
 
 use super::{
-    BaseElement, DefaultRandomCoin, ElementHasher, FieldElement, MulFib8Air, PhantomData,
+    DefaultRandomCoin, ElementHasher, ExampleField, FieldElement, MulFib8Air, PhantomData,
     ProofOptions, Prover,
 };
 
@@ -29,11 +29,11 @@ impl<H: ElementHasher> MulFib8Prover<H> {
 
     /// Builds an execution trace for computing a multiplicative version of a Fibonacci sequence of
     /// the specified length such that each row advances the sequence by 8 terms.
-    pub fn build_trace(&self, length: usize) -> TraceTable<BaseElement> {
+    pub fn build_trace(&self, length: usize) -> TraceTable<H::BaseField> {
         assert!(length.is_power_of_two(), "sequence length must be a power of 2");
 
-        let mut reg0 = vec![BaseElement::new(1)];
-        let mut reg1 = vec![BaseElement::new(2)];
+        let mut reg0 = vec![H::BaseField::ONE];
+        let mut reg1 = vec![H::BaseField::from(2u32)];
         let mut reg2 = vec![reg0[0] * reg1[0]];
         let mut reg3 = vec![reg1[0] * reg2[0]];
         let mut reg4 = vec![reg2[0] * reg3[0]];
@@ -58,11 +58,12 @@ impl<H: ElementHasher> MulFib8Prover<H> {
 
 impl<H: ElementHasher> Prover for MulFib8Prover<H>
 where
-    H: ElementHasher<BaseField = BaseElement> + Sync,
+    H: ElementHasher + Sync,
+    H::BaseField: ExampleField,
 {
-    type BaseField = BaseElement;
-    type Air = MulFib8Air;
-    type Trace = TraceTable<BaseElement>;
+    type BaseField = H::BaseField;
+    type Air = MulFib8Air<H::BaseField>;
+    type Trace = TraceTable<H::BaseField>;
     type HashFn = H;
     type VC = MerkleTree<H>;
     type RandomCoin = DefaultRandomCoin<Self::HashFn>;
@@ -73,7 +74,7 @@ where
     type ConstraintEvaluator<'a, E: FieldElement<BaseField = Self::BaseField>> =
         DefaultConstraintEvaluator<'a, Self::Air, E>;
 
-    fn get_pub_inputs(&self, trace: &Self::Trace) -> BaseElement {
+    fn get_pub_inputs(&self, trace: &Self::Trace) -> H::BaseField {
         let last_step = trace.length() - 1;
         trace.get(6, last_step)
     }
@@ -3,7 +3,7 @@
 // This source code is licensed under the MIT license found in the
 // LICENSE file in the root directory of this source tree.
 
-use winterfell::math::{fields::f128::BaseElement, FieldElement};
+use winterfell::math::FieldElement;
 
 pub fn compute_fib_term<E: FieldElement>(n: usize) -> E {
     let mut t0 = E::ONE;
@@ -17,9 +17,9 @@ pub fn compute_fib_term<E: FieldElement>(n: usize) -> E {
     t1
 }
 
-pub fn compute_mulfib_term(n: usize) -> BaseElement {
-    let mut t0 = BaseElement::ONE;
-    let mut t1 = BaseElement::new(2);
+pub fn compute_mulfib_term<E: FieldElement>(n: usize) -> E {
+    let mut t0 = E::ONE;
+    let mut t1 = E::from(2u32);
 
     for _ in 0..(n - 1) {
         t1 = t0 * t1;
@@ -16,7 +16,7 @@ use winterfell::{
 use super::{
     message_to_elements, rescue, Example, PrivateKey, Signature, CYCLE_LENGTH, NUM_HASH_ROUNDS,
 };
-use crate::{Blake3_192, Blake3_256, ExampleOptions, HashFunction, Sha3_256};
+use crate::{Blake3_192, Blake3_256, ExampleOptions, HashFunction, Sha2_256, Sha3_256};
 
 mod air;
 use air::{LamportAggregateAir, PublicInputs};
@@ -48,6 +48,9 @@ pub fn get_example(
         HashFunction::Sha3_256 => {
             Ok(Box::new(LamportAggregateExample::<Sha3_256>::new(num_signatures, options)))
         },
+        HashFunction::Sha2_256 => {
+            Ok(Box::new(LamportAggregateExample::<Sha2_256>::new(num_signatures, options)))
+        },
         _ => Err("The specified hash function cannot be used with this example.".to_string()),
     }
 }
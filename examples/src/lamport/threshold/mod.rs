@@ -17,7 +17,7 @@ use super::{
     message_to_elements, rescue, Example, PrivateKey, Signature, CYCLE_LENGTH as HASH_CYCLE_LENGTH,
     NUM_HASH_ROUNDS,
 };
-use crate::{Blake3_192, Blake3_256, ExampleOptions, HashFunction, Sha3_256};
+use crate::{Blake3_192, Blake3_256, ExampleOptions, HashFunction, Sha2_256, Sha3_256};
 
 mod signature;
 use signature::AggPublicKey;
@@ -53,6 +53,9 @@ pub fn get_example(
         HashFunction::Sha3_256 => {
             Ok(Box::new(LamportThresholdExample::<Sha3_256>::new(num_signers, options)))
         },
+        HashFunction::Sha2_256 => {
+            Ok(Box::new(LamportThresholdExample::<Sha2_256>::new(num_signers, options)))
+        },
         _ => Err("The specified hash function cannot be used with this example.".to_string()),
     }
 }
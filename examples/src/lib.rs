@@ -3,11 +3,23 @@
 // This source code is licensed under the MIT license found in the
 // LICENSE file in the root directory of this source tree.
 
+use core::{fmt::Debug, str::FromStr};
+
+use core_utils::DeserializationError;
 use structopt::StructOpt;
 use winterfell::{
-    crypto::hashers::{Rp64_256, RpJive64_256},
-    math::fields::{f128::BaseElement as F128BaseElement, f64::BaseElement as F64BaseElement},
-    BatchingMethod, FieldExtension, Proof, ProofOptions, VerifierError,
+    crypto::{
+        hashers::{Rp128_256, Rp62_248, Rp64_256, RpJive64_256},
+        ElementHasher, Hasher,
+    },
+    math::{
+        fields::{
+            f128::BaseElement as F128BaseElement, f62::BaseElement as F62BaseElement,
+            f64::BaseElement as F64BaseElement,
+        },
+        ExtensibleField, StarkField,
+    },
+    BatchingMethod, FieldExtension, MultiProof, Proof, ProofOptions, VerifierError,
 };
 
 pub mod experiment_sha;
@@ -16,10 +28,19 @@ pub mod fibonacci;
 pub mod lamport;
 #[cfg(feature = "std")]
 pub mod merkle;
+#[cfg(feature = "std")]
+pub mod multi_table;
+#[cfg(feature = "std")]
+pub mod permutation;
 pub mod proof_size_benchmark;
+#[cfg(feature = "std")]
+pub mod range_check;
 pub mod rescue;
+pub mod rescue_prime;
 #[cfg(feature = "std")]
 pub mod rescue_raps;
+#[cfg(feature = "std")]
+pub mod schnorr;
 pub mod utils;
 pub mod vdf;
 
@@ -29,19 +50,111 @@ mod tests;
 // TYPES AND INTERFACES
 // ================================================================================================
 
+pub type Blake3_160 = winterfell::crypto::hashers::Blake3_160<F128BaseElement>;
 pub type Blake3_192 = winterfell::crypto::hashers::Blake3_192<F128BaseElement>;
+pub type Blake3_224 = winterfell::crypto::hashers::Blake3_224<F128BaseElement>;
 pub type Blake3_256 = winterfell::crypto::hashers::Blake3_256<F128BaseElement>;
+pub type Sha2_160 = winterfell::crypto::hashers::Sha2_160<F128BaseElement>;
+pub type Sha2_192 = winterfell::crypto::hashers::Sha2_192<F128BaseElement>;
+pub type Sha2_224 = winterfell::crypto::hashers::Sha2_224<F128BaseElement>;
+pub type Sha2_256 = winterfell::crypto::hashers::Sha2_256<F128BaseElement>;
 pub type Sha3_256 = winterfell::crypto::hashers::Sha3_256<F128BaseElement>;
 
 // Hash functions for f64 field
+pub type Blake3_160F64 = winterfell::crypto::hashers::Blake3_160<F64BaseElement>;
 pub type Blake3_192F64 = winterfell::crypto::hashers::Blake3_192<F64BaseElement>;
+pub type Blake3_224F64 = winterfell::crypto::hashers::Blake3_224<F64BaseElement>;
 pub type Blake3_256F64 = winterfell::crypto::hashers::Blake3_256<F64BaseElement>;
+pub type Sha2_160F64 = winterfell::crypto::hashers::Sha2_160<F64BaseElement>;
+pub type Sha2_192F64 = winterfell::crypto::hashers::Sha2_192<F64BaseElement>;
+pub type Sha2_224F64 = winterfell::crypto::hashers::Sha2_224<F64BaseElement>;
+pub type Sha2_256F64 = winterfell::crypto::hashers::Sha2_256<F64BaseElement>;
 pub type Sha3_256F64 = winterfell::crypto::hashers::Sha3_256<F64BaseElement>;
 
-pub trait Example {
-    fn prove(&self) -> Proof;
-    fn verify(&self, proof: Proof) -> Result<(), VerifierError>;
-    fn verify_with_wrong_inputs(&self, proof: Proof) -> Result<(), VerifierError>;
+/// A base field which can be used with examples that are generic over the base field.
+pub trait ExampleField:
+    StarkField + ExtensibleField<2> + ExtensibleField<3> + ExtensibleField<4> + 'static
+{
+    /// Instance of Rescue Prime hash function over this field.
+    type RescuePrime: ElementHasher<BaseField = Self> + Sync;
+
+    /// Hash function option which selects [ExampleField::RescuePrime].
+    const RESCUE_PRIME: HashFunction;
+}
+
+impl ExampleField for F62BaseElement {
+    type RescuePrime = Rp62_248;
+    const RESCUE_PRIME: HashFunction = HashFunction::Rp62_248;
+}
+
+impl ExampleField for F64BaseElement {
+    type RescuePrime = Rp64_256;
+    const RESCUE_PRIME: HashFunction = HashFunction::Rp64_256;
+}
+
+impl ExampleField for F128BaseElement {
+    type RescuePrime = Rp128_256;
+    const RESCUE_PRIME: HashFunction = HashFunction::Rp128_256;
+}
+
+pub trait Example<P = Proof> {
+    fn prove(&self) -> P;
+    fn verify(&self, proof: P) -> Result<(), VerifierError>;
+    fn verify_with_wrong_inputs(&self, proof: P) -> Result<(), VerifierError>;
+}
+
+/// A proof generated by an example; this is either a regular [Proof] or a [MultiProof].
+pub trait ExampleProof: Sized + PartialEq + Debug {
+    /// Serializes this proof into a vector of bytes.
+    fn to_bytes(&self) -> Vec<u8>;
+
+    /// Returns a proof read from the specified `source`.
+    fn from_bytes(source: &[u8]) -> Result<Self, DeserializationError>;
+
+    /// Returns the conjectured security level of this proof in bits.
+    fn conjectured_security_bits<H: Hasher>(&self) -> u32;
+
+    /// Returns the proven security level of this proof in bits in the list decoding and the
+    /// unique decoding regimes.
+    fn proven_security_bits<H: Hasher>(&self) -> (u32, u32);
+}
+
+impl ExampleProof for Proof {
+    fn to_bytes(&self) -> Vec<u8> {
+        Proof::to_bytes(self)
+    }
+
+    fn from_bytes(source: &[u8]) -> Result<Self, DeserializationError> {
+        Proof::from_bytes(source)
+    }
+
+    fn conjectured_security_bits<H: Hasher>(&self) -> u32 {
+        self.conjectured_security::<H>().bits()
+    }
+
+    fn proven_security_bits<H: Hasher>(&self) -> (u32, u32) {
+        let security_level = self.proven_security::<H>();
+        (security_level.ldr_bits(), security_level.udr_bits())
+    }
+}
+
+impl ExampleProof for MultiProof {
+    fn to_bytes(&self) -> Vec<u8> {
+        MultiProof::to_bytes(self)
+    }
+
+    fn from_bytes(source: &[u8]) -> Result<Self, DeserializationError> {
+        MultiProof::from_bytes(source)
+    }
+
+    fn conjectured_security_bits<H: Hasher>(&self) -> u32 {
+        self.conjectured_security::<H>().bits()
+    }
+
+    fn proven_security_bits<H: Hasher>(&self) -> (u32, u32) {
+        let security_level = self.proven_security::<H>();
+        (security_level.ldr_bits(), security_level.udr_bits())
+    }
 }
 
 // EXAMPLE OPTIONS
@@ -76,6 +189,10 @@ pub struct ExampleOptions {
     /// Folding factor for FRI protocol
     #[structopt(short = "f", long = "folding", default_value = "8")]
     folding_factor: usize,
+
+    /// Base field for examples which support more than one field: f62, f64, or f128
+    #[structopt(long = "field")]
+    field: Option<FieldType>,
 }
 
 impl ExampleOptions {
@@ -86,15 +203,24 @@ impl ExampleOptions {
             1 => FieldExtension::None,
             2 => FieldExtension::Quadratic,
             3 => FieldExtension::Cubic,
+            4 => FieldExtension::Quartic,
             val => panic!("'{val}' is not a valid field extension option"),
         };
 
         let hash_fn = match self.hash_fn.as_str() {
+            "blake3_160" => HashFunction::Blake3_160,
             "blake3_192" => HashFunction::Blake3_192,
+            "blake3_224" => HashFunction::Blake3_224,
             "blake3_256" => HashFunction::Blake3_256,
+            "sha2_160" => HashFunction::Sha2_160,
+            "sha2_192" => HashFunction::Sha2_192,
+            "sha2_224" => HashFunction::Sha2_224,
+            "sha2_256" => HashFunction::Sha2_256,
             "sha3_256" => HashFunction::Sha3_256,
+            "rp62_248" => HashFunction::Rp62_248,
             "rp64_256" => HashFunction::Rp64_256,
             "rp_jive64_256" => HashFunction::RpJive64_256,
+            "rp128_256" => HashFunction::Rp128_256,
             val => panic!("'{val}' is not a valid hash function option"),
         };
 
@@ -113,32 +239,49 @@ impl ExampleOptions {
         )
     }
 
+    /// Returns the base field selected for the example, or `default` if no field was specified.
+    pub fn field(&self, default: FieldType) -> FieldType {
+        self.field.unwrap_or(default)
+    }
+
     /// Returns the conjectured security level of the input proof in bits.
-    pub fn get_proof_security_level_conjectured(&self, proof: &Proof) -> u32 {
-        let security_level = match self.hash_fn.as_str() {
-            "blake3_192" => proof.conjectured_security::<Blake3_192>(),
-            "blake3_256" => proof.conjectured_security::<Blake3_256>(),
-            "sha3_256" => proof.conjectured_security::<Sha3_256>(),
-            "rp64_256" => proof.conjectured_security::<Rp64_256>(),
-            "rp_jive64_256" => proof.conjectured_security::<RpJive64_256>(),
+    pub fn get_proof_security_level_conjectured<P: ExampleProof>(&self, proof: &P) -> u32 {
+        match self.hash_fn.as_str() {
+            "blake3_160" => proof.conjectured_security_bits::<Blake3_160>(),
+            "blake3_192" => proof.conjectured_security_bits::<Blake3_192>(),
+            "blake3_224" => proof.conjectured_security_bits::<Blake3_224>(),
+            "blake3_256" => proof.conjectured_security_bits::<Blake3_256>(),
+            "sha2_160" => proof.conjectured_security_bits::<Sha2_160>(),
+            "sha2_192" => proof.conjectured_security_bits::<Sha2_192>(),
+            "sha2_224" => proof.conjectured_security_bits::<Sha2_224>(),
+            "sha2_256" => proof.conjectured_security_bits::<Sha2_256>(),
+            "sha3_256" => proof.conjectured_security_bits::<Sha3_256>(),
+            "rp62_248" => proof.conjectured_security_bits::<Rp62_248>(),
+            "rp64_256" => proof.conjectured_security_bits::<Rp64_256>(),
+            "rp_jive64_256" => proof.conjectured_security_bits::<RpJive64_256>(),
+            "rp128_256" => proof.conjectured_security_bits::<Rp128_256>(),
             val => panic!("'{val}' is not a valid hash function option"),
-        };
-
-        security_level.bits()
+        }
     }
 
     /// Returns the proven security level of the input proof in bits.
-    pub fn get_proof_security_level_proven(&self, proof: &Proof) -> (u32, u32) {
-        let security_level = match self.hash_fn.as_str() {
-            "blake3_192" => proof.proven_security::<Blake3_192>(),
-            "blake3_256" => proof.proven_security::<Blake3_256>(),
-            "sha3_256" => proof.proven_security::<Sha3_256>(),
-            "rp64_256" => proof.proven_security::<Rp64_256>(),
-            "rp_jive64_256" => proof.proven_security::<RpJive64_256>(),
+    pub fn get_proof_security_level_proven<P: ExampleProof>(&self, proof: &P) -> (u32, u32) {
+        match self.hash_fn.as_str() {
+            "blake3_160" => proof.proven_security_bits::<Blake3_160>(),
+            "blake3_192" => proof.proven_security_bits::<Blake3_192>(),
+            "blake3_224" => proof.proven_security_bits::<Blake3_224>(),
+            "blake3_256" => proof.proven_security_bits::<Blake3_256>(),
+            "sha2_160" => proof.proven_security_bits::<Sha2_160>(),
+            "sha2_192" => proof.proven_security_bits::<Sha2_192>(),
+            "sha2_224" => proof.proven_security_bits::<Sha2_224>(),
+            "sha2_256" => proof.proven_security_bits::<Sha2_256>(),
+            "sha3_256" => proof.proven_security_bits::<Sha3_256>(),
+            "rp62_248" => proof.proven_security_bits::<Rp62_248>(),
+            "rp64_256" => proof.proven_security_bits::<Rp64_256>(),
+            "rp_jive64_256" => proof.proven_security_bits::<RpJive64_256>(),
+            "rp128_256" => proof.proven_security_bits::<Rp128_256>(),
             val => panic!("'{val}' is not a valid hash function option"),
-        };
-
-        (security_level.ldr_bits(), security_level.udr_bits())
+        }
     }
 }
 
@@ -175,6 +318,26 @@ pub enum ExampleType {
         #[structopt(short = "n", default_value = "65536")]
         sequence_length: usize,
     },
+    /// Compute a Fibonacci sequence using a single register and a 3-row evaluation frame
+    FibFrame {
+        /// Length of Fibonacci sequence; must be a power of two
+        #[structopt(short = "n", default_value = "1048576")]
+        sequence_length: usize,
+    },
+    /// Compute a lagged Fibonacci sequence using a single register and an evaluation frame which
+    /// reaches back 6 rows
+    FibLagged {
+        /// Length of the sequence; must be a power of two
+        #[structopt(short = "n", default_value = "1048576")]
+        sequence_length: usize,
+    },
+    /// Compute a sum of every 8th term of a Fibonacci sequence using a constraint which is
+    /// enforced only on every 8th step
+    FibSparse {
+        /// Length of Fibonacci sequence; must be a power of two
+        #[structopt(short = "n", default_value = "1048576")]
+        sequence_length: usize,
+    },
     /// Execute a simple VDF function
     Vdf {
         /// Number of steps in the VDF function; must be a power of two
@@ -193,6 +356,12 @@ pub enum ExampleType {
         #[structopt(short = "n", default_value = "1024")]
         chain_length: usize,
     },
+    /// Compute a hash chain using Rescue Prime hash function over the 128-bit field
+    RescuePrime {
+        /// Length of the hash chain; must be a power of two
+        #[structopt(short = "n", default_value = "1024")]
+        chain_length: usize,
+    },
     /// Compute two hash chains absorbing sequences that are a permutation of each other
     #[cfg(feature = "std")]
     RescueRaps {
@@ -200,6 +369,30 @@ pub enum ExampleType {
         #[structopt(short = "n", default_value = "1024")]
         chain_length: usize,
     },
+    /// Range check two sequences of values using a LogUp lookup argument
+    #[cfg(feature = "std")]
+    RangeCheck {
+        /// Number of values in each sequence; must be a power of two
+        #[structopt(short = "n", default_value = "1024")]
+        num_values: usize,
+    },
+    /// Compute a sum of 16-bit values using a sum table and a range table of different lengths
+    /// connected via a LogUp bus
+    #[cfg(feature = "std")]
+    MultiTable {
+        /// Number of values; must be a power of two greater than 256, and its ratio to 256 must
+        /// be a power of the FRI folding factor
+        #[structopt(short = "n", default_value = "2048")]
+        num_values: usize,
+    },
+    /// Prove that a sequence of pairs is a permutation of Fibonacci pairs using two auxiliary
+    /// trace segments
+    #[cfg(feature = "std")]
+    Permutation {
+        /// Length of the sequence; must be a power of two
+        #[structopt(short = "n", default_value = "1024")]
+        sequence_length: usize,
+    },
     /// Compute a root of a Merkle path using Rescue hash function
     #[cfg(feature = "std")]
     Merkle {
@@ -221,6 +414,13 @@ pub enum ExampleType {
         #[structopt(short = "n", default_value = "3")]
         num_signers: usize,
     },
+    /// Verify a set of Schnorr signatures over the EcGFp5 elliptic curve
+    #[cfg(feature = "std")]
+    Schnorr {
+        /// Number of signatures to verify; must be a power of two
+        #[structopt(short = "n", default_value = "4")]
+        num_signatures: usize,
+    },
     // Compute a SHA256 hash of a string
     ExperimentSha {
         /// Length of string; number of blocks after padding must be power of two
@@ -235,6 +435,32 @@ pub enum ExampleType {
     },
 }
 
+/// Defines a set of base fields available for the examples which are generic over the base field.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum FieldType {
+    /// 62-bit prime field with modulus 2^62 - 111 * 2^39 + 1.
+    F62,
+
+    /// 64-bit prime field with modulus 2^64 - 2^32 + 1.
+    F64,
+
+    /// 128-bit prime field with modulus 2^128 - 45 * 2^40 + 1.
+    F128,
+}
+
+impl FromStr for FieldType {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "f62" => Ok(FieldType::F62),
+            "f64" => Ok(FieldType::F64),
+            "f128" => Ok(FieldType::F128),
+            val => Err(format!("'{val}' is not a valid field option")),
+        }
+    }
+}
+
 /// Defines a set of hash functions available for the provided examples. Some examples may not
 /// support all listed hash functions.
 ///
@@ -244,21 +470,56 @@ pub enum ExampleType {
 #[repr(u8)]
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub enum HashFunction {
+    /// BLAKE3 hash function with output truncated to 160 bits.
+    ///
+    /// When this function is used in the STARK protocol, proof security cannot exceed 80 bits.
+    Blake3_160,
+
     /// BLAKE3 hash function with 192 bit output.
     ///
     /// When this function is used in the STARK protocol, proof security cannot exceed 96 bits.
     Blake3_192,
 
+    /// BLAKE3 hash function with output truncated to 224 bits.
+    ///
+    /// When this function is used in the STARK protocol, proof security cannot exceed 112 bits.
+    Blake3_224,
+
     /// BLAKE3 hash function with 256 bit output.
     ///
     /// When this function is used in the STARK protocol, proof security cannot exceed 128 bits.
     Blake3_256,
 
+    /// SHA-256 hash function with output truncated to 160 bits.
+    ///
+    /// When this function is used in the STARK protocol, proof security cannot exceed 80 bits.
+    Sha2_160,
+
+    /// SHA-256 hash function with output truncated to 192 bits.
+    ///
+    /// When this function is used in the STARK protocol, proof security cannot exceed 96 bits.
+    Sha2_192,
+
+    /// SHA-256 hash function with output truncated to 224 bits.
+    ///
+    /// When this function is used in the STARK protocol, proof security cannot exceed 112 bits.
+    Sha2_224,
+
+    /// SHA2 hash function with 256 bit output (i.e., SHA-256).
+    ///
+    /// When this function is used in the STARK protocol, proof security cannot exceed 128 bits.
+    Sha2_256,
+
     /// SHA3 hash function with 256 bit output.
     ///
     /// When this function is used in the STARK protocol, proof security cannot exceed 128 bits.
     Sha3_256,
 
+    /// Rescue Prime hash function with 248 bit output. It only works in `f62` field.
+    ///
+    /// When this function is used in the STARK protocol, proof security cannot exceed 124 bits.
+    Rp62_248,
+
     /// Rescue Prime hash function with 256 bit output. It only works in `f64` field.
     ///
     /// When this function is used in the STARK protocol, proof security cannot exceed 128 bits.
@@ -269,4 +530,9 @@ pub enum HashFunction {
     ///
     /// When this function is used in the STARK protocol, proof security cannot exceed 128 bits.
     RpJive64_256,
+
+    /// Rescue Prime hash function with 256 bit output. It only works in `f128` field.
+    ///
+    /// When this function is used in the STARK protocol, proof security cannot exceed 128 bits.
+    Rp128_256,
 }
@@ -5,9 +5,14 @@
 
 use std::time::Instant;
 
-use examples::{experiment_sha, fibonacci, rescue, vdf, ExampleOptions, ExampleType};
+use examples::{
+    experiment_sha, fibonacci, rescue, rescue_prime, vdf, Example, ExampleOptions, ExampleProof,
+    ExampleType,
+};
 #[cfg(feature = "std")]
-use examples::{lamport, merkle, rescue_raps};
+use examples::{
+    lamport, merkle, multi_table, permutation, range_check, rescue_raps, schnorr,
+};
 use examples::proof_size_benchmark::ProofSizeBenchmark;
 use structopt::StructOpt;
 use tracing::info_span;
@@ -16,7 +21,6 @@ use tracing_forest::ForestLayer;
 #[cfg(not(feature = "tracing-forest"))]
 use tracing_subscriber::fmt::format::FmtSpan;
 use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt, EnvFilter};
-use winterfell::Proof;
 
 // BENCHMARK RUNNER
 // ================================================================================================
@@ -97,14 +101,39 @@ fn main() {
         ExampleType::FibSmall { sequence_length } => {
             fibonacci::fib_small::get_example(&options, sequence_length)
         },
+        ExampleType::FibFrame { sequence_length } => {
+            fibonacci::fib_frame::get_example(&options, sequence_length)
+        },
+        ExampleType::FibLagged { sequence_length } => {
+            fibonacci::fib_lagged::get_example(&options, sequence_length)
+        },
+        ExampleType::FibSparse { sequence_length } => {
+            fibonacci::fib_sparse::get_example(&options, sequence_length)
+        },
         ExampleType::Vdf { num_steps } => vdf::regular::get_example(&options, num_steps),
         ExampleType::VdfExempt { num_steps } => vdf::exempt::get_example(&options, num_steps),
         ExampleType::Rescue { chain_length } => rescue::get_example(&options, chain_length),
+        ExampleType::RescuePrime { chain_length } => {
+            rescue_prime::get_example(&options, chain_length)
+        },
         #[cfg(feature = "std")]
         ExampleType::RescueRaps { chain_length } => {
             rescue_raps::get_example(&options, chain_length)
         },
         #[cfg(feature = "std")]
+        ExampleType::RangeCheck { num_values } => range_check::get_example(&options, num_values),
+        #[cfg(feature = "std")]
+        ExampleType::MultiTable { num_values } => {
+            let example = multi_table::get_example(&options, num_values)
+                .expect("The example failed to initialize.");
+            run_example(&options, example);
+            return;
+        },
+        #[cfg(feature = "std")]
+        ExampleType::Permutation { sequence_length } => {
+            permutation::get_example(&options, sequence_length)
+        },
+        #[cfg(feature = "std")]
         ExampleType::Merkle { tree_depth } => merkle::get_example(&options, tree_depth),
         #[cfg(feature = "std")]
         ExampleType::LamportA { num_signatures } => {
@@ -115,6 +144,8 @@ fn main() {
             lamport::threshold::get_example(&options, num_signers)
         },
         #[cfg(feature = "std")]
+        ExampleType::Schnorr { num_signatures } => schnorr::get_example(&options, num_signatures),
+        #[cfg(feature = "std")]
         ExampleType::ExperimentSha { string_length } => {
             experiment_sha::get_example(&options, string_length)
         },
@@ -125,6 +156,11 @@ fn main() {
     }
     .expect("The example failed to initialize.");
 
+    run_example(&options, example);
+}
+
+/// Generates a proof for the specified example, and then verifies it.
+fn run_example<P: ExampleProof>(options: &ExampleOptions, example: Box<dyn Example<P>>) {
     // generate proof
     let now = Instant::now();
     let proof = info_span!("generate_proof").in_scope(|| example.as_ref().prove());
@@ -157,7 +193,7 @@ fn main() {
 
     // verify the proof
     println!("---------------------");
-    let parsed_proof = Proof::from_bytes(&proof_bytes).unwrap();
+    let parsed_proof = P::from_bytes(&proof_bytes).unwrap();
     assert_eq!(proof, parsed_proof);
     let now = Instant::now();
     match example.verify(proof) {
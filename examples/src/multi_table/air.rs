@@ -0,0 +1,157 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use winterfell::{
+    math::ToElements, Air, AirContext, Assertion, EvaluationFrame, LogUp, TraceInfo,
+    TransitionConstraintDegree,
+};
+
+use super::{
+    BaseElement, FieldElement, ProofOptions, RANGE_TABLE_ID, RANGE_TABLE_SIZE, RANGE_TABLE_WIDTH,
+    SUM_TABLE_ID, SUM_TABLE_WIDTH,
+};
+use crate::utils::are_equal;
+
+// PUBLIC INPUTS
+// ================================================================================================
+
+#[derive(Clone)]
+pub struct PublicInputs {
+    pub result: BaseElement,
+}
+
+impl ToElements<BaseElement> for PublicInputs {
+    fn to_elements(&self) -> Vec<BaseElement> {
+        vec![self.result]
+    }
+}
+
+// MULTI-TABLE AIR
+// ================================================================================================
+
+/// AIR shared by both tables of the example; the table is identified by the trace metadata.
+pub struct MultiTableAir {
+    context: AirContext<BaseElement>,
+    table_id: u8,
+    result: BaseElement,
+}
+
+impl MultiTableAir {
+    /// Returns the LogUp argument of the specified table.
+    ///
+    /// In the sum table, limbs in columns 1 and 2 are looked up; in the range table, column 0
+    /// holds the table values and column 1 holds the number of times each value is looked up.
+    pub fn logup(table_id: u8) -> LogUp {
+        match table_id {
+            SUM_TABLE_ID => LogUp::new_lookups(vec![vec![1], vec![2]]),
+            RANGE_TABLE_ID => LogUp::new_table(vec![0], 1),
+            _ => panic!("invalid table ID: {table_id}"),
+        }
+    }
+
+    /// Returns the trace info of the specified table with the specified number of rows.
+    pub fn trace_info(table_id: u8, trace_length: usize) -> TraceInfo {
+        let logup = Self::logup(table_id);
+        let width = match table_id {
+            SUM_TABLE_ID => SUM_TABLE_WIDTH,
+            _ => RANGE_TABLE_WIDTH,
+        };
+        TraceInfo::new_multi_segment(
+            width,
+            logup.aux_trace_width(),
+            logup.num_rand_elements(),
+            trace_length,
+            vec![table_id],
+        )
+    }
+}
+
+impl Air for MultiTableAir {
+    type BaseField = BaseElement;
+    type PublicInputs = PublicInputs;
+
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+    fn new(trace_info: TraceInfo, pub_inputs: PublicInputs, options: ProofOptions) -> Self {
+        let table_id = trace_info.meta()[0];
+        let (width, num_assertions) = match table_id {
+            SUM_TABLE_ID => (SUM_TABLE_WIDTH, 2),
+            RANGE_TABLE_ID => (RANGE_TABLE_WIDTH, 1),
+            _ => panic!("invalid table ID: {table_id}"),
+        };
+        assert_eq!(width, trace_info.main_trace_width());
+
+        let degrees = vec![TransitionConstraintDegree::new(1)];
+        let logup = Self::logup(table_id);
+        MultiTableAir {
+            context: AirContext::new_with_logup(
+                trace_info,
+                degrees,
+                vec![],
+                num_assertions,
+                0,
+                logup,
+                options,
+            ),
+            table_id,
+            result: pub_inputs.result,
+        }
+    }
+
+    fn validate_tables(
+        trace_infos: &[TraceInfo],
+        _pub_inputs: &PublicInputs,
+    ) -> Result<(), String> {
+        // the proof must contain the sum table followed by the range table; the length of the sum
+        // table depends on the number of summed values, while the range table has a fixed length
+        let [sum_table, range_table] = trace_infos else {
+            return Err(format!("expected 2 tables, but the proof contains {}", trace_infos.len()));
+        };
+        if *sum_table != Self::trace_info(SUM_TABLE_ID, sum_table.length()) {
+            return Err("the first table is not a valid sum table".to_string());
+        }
+        if *range_table != Self::trace_info(RANGE_TABLE_ID, RANGE_TABLE_SIZE) {
+            return Err("the second table is not a valid range table".to_string());
+        }
+        Ok(())
+    }
+
+    fn context(&self) -> &AirContext<Self::BaseField> {
+        &self.context
+    }
+
+    fn evaluate_transition<E: FieldElement + From<Self::BaseField>>(
+        &self,
+        frame: &EvaluationFrame<E>,
+        _periodic_values: &[E],
+        result: &mut [E],
+    ) {
+        let current = frame.current();
+        let next = frame.next();
+
+        result[0] = match self.table_id {
+            // the accumulator must be incremented by the value composed from the two limbs
+            SUM_TABLE_ID => {
+                let value = current[1] + current[2] * E::from(256u32);
+                are_equal(next[0], current[0] + value)
+            },
+            // the table column must be incremented by one at every step
+            _ => are_equal(next[0], current[0] + E::ONE),
+        };
+    }
+
+    fn get_assertions(&self) -> Vec<Assertion<Self::BaseField>> {
+        let last_step = self.trace_length() - 1;
+        match self.table_id {
+            // the accumulator must start at zero and end with the claimed result
+            SUM_TABLE_ID => vec![
+                Assertion::single(0, 0, BaseElement::ZERO),
+                Assertion::single(0, last_step, self.result),
+            ],
+            // the range table must start at zero
+            _ => vec![Assertion::single(0, 0, BaseElement::ZERO)],
+        }
+    }
+}
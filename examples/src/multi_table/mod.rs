@@ -0,0 +1,174 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use core::marker::PhantomData;
+use std::time::Instant;
+
+use rand_utils::rand_value;
+use tracing::{field, info_span};
+use winterfell::{
+    crypto::{DefaultRandomCoin, ElementHasher, MerkleTree},
+    math::{fields::f128::BaseElement, FieldElement},
+    MultiProof, ProofOptions, Prover, Trace, VerifierError,
+};
+
+use crate::{Blake3_192, Blake3_256, Example, ExampleOptions, HashFunction, Sha2_256, Sha3_256};
+
+mod air;
+use air::{MultiTableAir, PublicInputs};
+
+mod prover;
+use prover::MultiTableProver;
+
+#[cfg(test)]
+mod tests;
+
+// CONSTANTS
+// ================================================================================================
+
+/// Width of the main trace segment of the sum table.
+const SUM_TABLE_WIDTH: usize = 3;
+
+/// Width of the main trace segment of the range table.
+const RANGE_TABLE_WIDTH: usize = 2;
+
+/// Number of rows in the range table; limbs of summed values must be in [0, RANGE_TABLE_SIZE - 1).
+const RANGE_TABLE_SIZE: usize = 256;
+
+/// Trace metadata identifying the sum table.
+const SUM_TABLE_ID: u8 = 0;
+
+/// Trace metadata identifying the range table.
+const RANGE_TABLE_ID: u8 = 1;
+
+// MULTI-TABLE EXAMPLE
+// ================================================================================================
+
+pub fn get_example(
+    options: &ExampleOptions,
+    num_values: usize,
+) -> Result<Box<dyn Example<MultiProof>>, String> {
+    let (options, hash_fn) = options.to_proof_options(42, 8);
+
+    match hash_fn {
+        HashFunction::Blake3_192 => {
+            Ok(Box::new(MultiTableExample::<Blake3_192>::new(num_values, options)))
+        },
+        HashFunction::Blake3_256 => {
+            Ok(Box::new(MultiTableExample::<Blake3_256>::new(num_values, options)))
+        },
+        HashFunction::Sha3_256 => {
+            Ok(Box::new(MultiTableExample::<Sha3_256>::new(num_values, options)))
+        },
+        HashFunction::Sha2_256 => {
+            Ok(Box::new(MultiTableExample::<Sha2_256>::new(num_values, options)))
+        },
+        _ => Err("The specified hash function cannot be used with this example.".to_string()),
+    }
+}
+
+/// Computes a sum of 16-bit values using two tables of different lengths.
+///
+/// The sum table accumulates values, each of which is split into two 8-bit limbs; the limbs are
+/// looked up in a separate range table of [RANGE_TABLE_SIZE] rows. The tables are connected via
+/// a LogUp bus: the sum table includes only the lookup side of the argument, while the range
+/// table includes only the table side.
+///
+/// Since this example produces a [MultiProof] rather than a regular proof, it implements
+/// `Example<MultiProof>`.
+pub struct MultiTableExample<H: ElementHasher> {
+    options: ProofOptions,
+    limbs: Vec<[u64; 2]>,
+    result: BaseElement,
+    _hasher: PhantomData<H>,
+}
+
+impl<H: ElementHasher> MultiTableExample<H> {
+    pub fn new(num_values: usize, options: ProofOptions) -> Self {
+        assert!(num_values.is_power_of_two(), "number of values must be a power of 2");
+        assert!(
+            num_values > RANGE_TABLE_SIZE,
+            "number of values must be greater than {RANGE_TABLE_SIZE}"
+        );
+
+        // generate random limbs in range [0, RANGE_TABLE_SIZE - 1); the last row of the sum table
+        // does not participate in the lookup, and thus only the first num_values - 1 values are
+        // summed up
+        let now = Instant::now();
+        let range = RANGE_TABLE_SIZE as u64 - 1;
+        let limbs: Vec<[u64; 2]> =
+            (0..num_values).map(|_| [0, 1].map(|_| rand_value::<u64>() % range)).collect();
+        let result = limbs
+            .iter()
+            .take(num_values - 1)
+            .map(|&[lo, hi]| BaseElement::from(lo + (hi << 8)))
+            .fold(BaseElement::ZERO, |acc, value| acc + value);
+        println!(
+            "Generated {} values with limbs in range [0, {}) in {} ms",
+            num_values,
+            range,
+            now.elapsed().as_millis()
+        );
+
+        MultiTableExample {
+            options,
+            limbs,
+            result,
+            _hasher: PhantomData,
+        }
+    }
+}
+
+// EXAMPLE IMPLEMENTATION
+// ================================================================================================
+
+impl<H: ElementHasher> Example<MultiProof> for MultiTableExample<H>
+where
+    H: ElementHasher<BaseField = BaseElement> + Sync,
+{
+    fn prove(&self) -> MultiProof {
+        println!("Generating proof for summing {} values", self.limbs.len() - 1);
+
+        // create a prover
+        let prover = MultiTableProver::<H>::new(self.options.clone());
+
+        // generate execution traces for both tables
+        let traces = info_span!(
+            "generate_execution_trace",
+            num_cols = SUM_TABLE_WIDTH,
+            steps = field::Empty
+        )
+        .in_scope(|| {
+            let traces = prover.build_traces(&self.limbs);
+            tracing::Span::current().record("steps", traces[0].length());
+            traces
+        });
+
+        // generate the proof
+        prover.prove_multi(traces).unwrap()
+    }
+
+    fn verify(&self, proof: MultiProof) -> Result<(), VerifierError> {
+        let acceptable_options =
+            winterfell::AcceptableOptions::OptionSet(vec![proof.options().clone()]);
+
+        winterfell::verify_multi::<MultiTableAir, H, DefaultRandomCoin<H>, MerkleTree<H>>(
+            proof,
+            PublicInputs { result: self.result },
+            &acceptable_options,
+        )
+    }
+
+    fn verify_with_wrong_inputs(&self, proof: MultiProof) -> Result<(), VerifierError> {
+        let acceptable_options =
+            winterfell::AcceptableOptions::OptionSet(vec![proof.options().clone()]);
+
+        winterfell::verify_multi::<MultiTableAir, H, DefaultRandomCoin<H>, MerkleTree<H>>(
+            proof,
+            PublicInputs { result: self.result + BaseElement::ONE },
+            &acceptable_options,
+        )
+    }
+}
@@ -0,0 +1,172 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use winterfell::{
+    crypto::MerkleTree, matrix::ColMatrix, AuxRandElements, CompositionPoly, CompositionPolyTrace,
+    ConstraintCompositionCoefficients, DefaultConstraintCommitment, DefaultConstraintEvaluator,
+    DefaultTraceLde, EvaluationFrame, PartitionOptions, StarkDomain, Trace, TraceInfo,
+    TracePolyTable,
+};
+
+use super::{
+    BaseElement, DefaultRandomCoin, ElementHasher, FieldElement, MultiTableAir, PhantomData,
+    ProofOptions, Prover, PublicInputs, RANGE_TABLE_ID, RANGE_TABLE_SIZE, SUM_TABLE_ID,
+};
+
+// MULTI-TABLE TRACE
+// ================================================================================================
+
+/// Execution trace of a single table of the multi-table example.
+///
+/// The main trace segment of the sum table consists of an accumulator column and two limb
+/// columns; the main trace segment of the range table consists of a table column and a
+/// multiplicity column. In both tables, the auxiliary trace segment consists of a single
+/// running-sum column of the LogUp argument, which is built by the prover automatically.
+pub struct MultiTableTrace {
+    info: TraceInfo,
+    trace: ColMatrix<BaseElement>,
+}
+
+impl MultiTableTrace {
+    fn new(table_id: u8, columns: Vec<Vec<BaseElement>>) -> Self {
+        let trace = ColMatrix::new(columns);
+        MultiTableTrace {
+            info: MultiTableAir::trace_info(table_id, trace.num_rows()),
+            trace,
+        }
+    }
+}
+
+impl Trace for MultiTableTrace {
+    type BaseField = BaseElement;
+
+    fn info(&self) -> &TraceInfo {
+        &self.info
+    }
+
+    fn read_main_frame(&self, row_idx: usize, frame: &mut EvaluationFrame<Self::BaseField>) {
+        for i in 0..frame.num_rows() {
+            let frame_row_idx =
+                (row_idx as isize + frame.offsets()[i]).rem_euclid(self.info.length() as isize);
+            self.trace.read_row_into(frame_row_idx as usize, frame.row_mut(i));
+        }
+    }
+
+    fn main_segment(&self) -> &ColMatrix<BaseElement> {
+        &self.trace
+    }
+}
+
+// MULTI-TABLE PROVER
+// ================================================================================================
+
+pub struct MultiTableProver<H: ElementHasher> {
+    options: ProofOptions,
+    _hasher: PhantomData<H>,
+}
+
+impl<H: ElementHasher> MultiTableProver<H> {
+    pub fn new(options: ProofOptions) -> Self {
+        Self { options, _hasher: PhantomData }
+    }
+
+    /// Builds execution traces of the sum table and the range table for the provided limbs.
+    ///
+    /// The last row of the sum table does not participate in the lookup, and thus the value
+    /// composed from the limbs in the last row is not added to the sum.
+    pub fn build_traces(&self, limbs: &[[u64; 2]]) -> Vec<MultiTableTrace> {
+        let trace_length = limbs.len();
+
+        let mut accumulator = Vec::with_capacity(trace_length);
+        let mut acc = BaseElement::ZERO;
+        let mut multiplicities = vec![0u64; RANGE_TABLE_SIZE];
+        for &[lo, hi] in limbs.iter() {
+            accumulator.push(acc);
+            acc += BaseElement::from(lo + (hi << 8));
+        }
+        for &[lo, hi] in limbs.iter().take(trace_length - 1) {
+            multiplicities[lo as usize] += 1;
+            multiplicities[hi as usize] += 1;
+        }
+
+        let sum_table = vec![
+            accumulator,
+            limbs.iter().map(|&[lo, _]| BaseElement::from(lo)).collect(),
+            limbs.iter().map(|&[_, hi]| BaseElement::from(hi)).collect(),
+        ];
+        let range_table = vec![
+            (0..RANGE_TABLE_SIZE as u64).map(BaseElement::from).collect(),
+            multiplicities.into_iter().map(BaseElement::from).collect(),
+        ];
+
+        vec![
+            MultiTableTrace::new(SUM_TABLE_ID, sum_table),
+            MultiTableTrace::new(RANGE_TABLE_ID, range_table),
+        ]
+    }
+}
+
+impl<H: ElementHasher> Prover for MultiTableProver<H>
+where
+    H: ElementHasher<BaseField = BaseElement> + Sync,
+{
+    type BaseField = BaseElement;
+    type Air = MultiTableAir;
+    type Trace = MultiTableTrace;
+    type HashFn = H;
+    type VC = MerkleTree<H>;
+    type RandomCoin = DefaultRandomCoin<Self::HashFn>;
+    type TraceLde<E: FieldElement<BaseField = Self::BaseField>> =
+        DefaultTraceLde<E, Self::HashFn, Self::VC>;
+    type ConstraintCommitment<E: FieldElement<BaseField = Self::BaseField>> =
+        DefaultConstraintCommitment<E, H, Self::VC>;
+    type ConstraintEvaluator<'a, E: FieldElement<BaseField = Self::BaseField>> =
+        DefaultConstraintEvaluator<'a, Self::Air, E>;
+
+    fn get_pub_inputs(&self, trace: &Self::Trace) -> PublicInputs {
+        let last_step = trace.length() - 1;
+        PublicInputs {
+            result: trace.main_segment().get(0, last_step),
+        }
+    }
+
+    fn options(&self) -> &ProofOptions {
+        &self.options
+    }
+
+    fn new_trace_lde<E: FieldElement<BaseField = Self::BaseField>>(
+        &self,
+        trace_info: &TraceInfo,
+        main_trace: &ColMatrix<Self::BaseField>,
+        domain: &StarkDomain<Self::BaseField>,
+        partition_options: PartitionOptions,
+    ) -> (Self::TraceLde<E>, TracePolyTable<E>) {
+        DefaultTraceLde::new(trace_info, main_trace, domain, partition_options)
+    }
+
+    fn new_evaluator<'a, E: FieldElement<BaseField = Self::BaseField>>(
+        &self,
+        air: &'a Self::Air,
+        aux_rand_elements: Option<AuxRandElements<E>>,
+        composition_coefficients: ConstraintCompositionCoefficients<E>,
+    ) -> Self::ConstraintEvaluator<'a, E> {
+        DefaultConstraintEvaluator::new(air, aux_rand_elements, composition_coefficients)
+    }
+
+    fn build_constraint_commitment<E: FieldElement<BaseField = Self::BaseField>>(
+        &self,
+        composition_poly_trace: CompositionPolyTrace<E>,
+        num_constraint_composition_columns: usize,
+        domain: &StarkDomain<Self::BaseField>,
+        partition_options: PartitionOptions,
+    ) -> (Self::ConstraintCommitment<E>, CompositionPoly<E>) {
+        DefaultConstraintCommitment::new(
+            composition_poly_trace,
+            num_constraint_composition_columns,
+            domain,
+            partition_options,
+        )
+    }
+}
@@ -0,0 +1,89 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use winterfell::{
+    BatchingMethod, FieldExtension, MultiProof, ProofOptions, Prover, ProverError, VerifierError,
+};
+
+use crate::{Blake3_256, Example};
+
+#[test]
+fn multi_table_test_basic_proof_verification() {
+    let multi_table = super::MultiTableExample::<Blake3_256>::new(1024, build_options(false));
+    let proof = multi_table.prove();
+    assert!(multi_table.verify(proof).is_ok());
+}
+
+#[test]
+fn multi_table_test_basic_proof_verification_extension() {
+    let multi_table = super::MultiTableExample::<Blake3_256>::new(1024, build_options(true));
+    let proof = multi_table.prove();
+    assert!(multi_table.verify(proof).is_ok());
+}
+
+#[test]
+fn multi_table_test_basic_proof_verification_fail() {
+    let multi_table = super::MultiTableExample::<Blake3_256>::new(1024, build_options(false));
+    let proof = multi_table.prove();
+    assert!(multi_table.verify_with_wrong_inputs(proof).is_err());
+}
+
+#[test]
+fn multi_table_test_proof_serialization() {
+    let multi_table = super::MultiTableExample::<Blake3_256>::new(1024, build_options(false));
+    let proof = multi_table.prove();
+    let parsed_proof = MultiProof::from_bytes(&proof.to_bytes()).unwrap();
+    assert_eq!(proof, parsed_proof);
+    assert!(multi_table.verify(parsed_proof).is_ok());
+}
+
+#[test]
+fn multi_table_test_unbalanced_bus_fail() {
+    let multi_table = super::MultiTableExample::<Blake3_256>::new(1024, build_options(false));
+    let mut proof = multi_table.prove();
+
+    // replace the final LogUp sum of the range table with the one from the sum table
+    proof.tables[1].logup_sum = proof.tables[0].logup_sum.clone();
+    assert!(multi_table.verify(proof).is_err());
+}
+
+#[test]
+fn multi_table_test_missing_table_fail() {
+    let multi_table = super::MultiTableExample::<Blake3_256>::new(1024, build_options(false));
+    let mut proof = multi_table.prove();
+
+    // a proof of the range table alone must not be accepted, even though its LogUp sum may be
+    // balanced if all multiplicities are zero
+    proof.tables.remove(0);
+    assert!(matches!(multi_table.verify(proof), Err(VerifierError::UnexpectedTables(_))));
+}
+
+#[test]
+fn multi_table_test_reordered_tables_fail() {
+    let multi_table = super::MultiTableExample::<Blake3_256>::new(1024, build_options(false));
+    let mut proof = multi_table.prove();
+
+    proof.tables.swap(0, 1);
+    assert!(matches!(multi_table.verify(proof), Err(VerifierError::UnexpectedTables(_))));
+}
+
+#[test]
+fn multi_table_test_zk_mode_fail() {
+    let options = build_options(false).with_zk();
+    let multi_table = super::MultiTableExample::<Blake3_256>::new(1024, options.clone());
+    let prover = super::MultiTableProver::<Blake3_256>::new(options);
+    let traces = prover.build_traces(&multi_table.limbs);
+    assert!(matches!(prover.prove_multi(traces), Err(ProverError::UnsupportedZkMode)));
+}
+
+fn build_options(use_extension_field: bool) -> ProofOptions {
+    let extension = if use_extension_field {
+        FieldExtension::Quadratic
+    } else {
+        FieldExtension::None
+    };
+    // the sum table is 4 times longer than the range table, and thus the folding factor must be 4
+    ProofOptions::new(28, 8, 0, extension, 4, 31, BatchingMethod::Horner, BatchingMethod::Horner)
+}
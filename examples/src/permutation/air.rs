@@ -0,0 +1,159 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use winterfell::{
+    math::{ExtensionOf, ToElements},
+    Air, AirContext, Assertion, AuxRandElements, EvaluationFrame, TraceInfo,
+    TransitionConstraintDegree,
+};
+
+use super::{BaseElement, FieldElement, ProofOptions, TRACE_WIDTH};
+use crate::utils::are_equal;
+
+// CONSTANTS
+// ================================================================================================
+
+/// Width of the first auxiliary trace segment which holds fingerprints of the pairs.
+pub const FINGERPRINT_SEGMENT_WIDTH: usize = 2;
+
+/// Width of the second auxiliary trace segment which holds the grand product.
+pub const PRODUCT_SEGMENT_WIDTH: usize = 1;
+
+// PERMUTATION AIR
+// ================================================================================================
+
+#[derive(Clone)]
+pub struct PublicInputs {
+    pub result: BaseElement,
+}
+
+impl ToElements<BaseElement> for PublicInputs {
+    fn to_elements(&self) -> Vec<BaseElement> {
+        vec![self.result]
+    }
+}
+
+/// AIR for a computation which builds a sequence of Fibonacci pairs in columns 0 and 1, and
+/// proves that pairs in columns 2 and 3 are a permutation of this sequence.
+///
+/// The permutation argument is split into two rounds, each with its own auxiliary segment:
+/// * After the main trace is committed, a random element α is drawn, and each pair (u, v) is
+///   compressed into a single fingerprint u + α * v.
+/// * After the fingerprints are committed, a random element γ is drawn, and the grand product
+///   of (γ + fingerprint) ratios is accumulated into a single column.
+///
+/// The last row of the trace does not participate in the permutation.
+pub struct PermutationAir {
+    context: AirContext<BaseElement>,
+    result: BaseElement,
+}
+
+impl Air for PermutationAir {
+    type BaseField = BaseElement;
+    type PublicInputs = PublicInputs;
+
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+    fn new(trace_info: TraceInfo, pub_inputs: PublicInputs, options: ProofOptions) -> Self {
+        let main_degrees =
+            vec![TransitionConstraintDegree::new(1), TransitionConstraintDegree::new(1)];
+        let aux_degrees = vec![
+            TransitionConstraintDegree::new(1),
+            TransitionConstraintDegree::new(1),
+            TransitionConstraintDegree::new(2),
+        ];
+        assert_eq!(TRACE_WIDTH, trace_info.main_trace_width());
+        assert_eq!(2, trace_info.num_aux_segments());
+
+        PermutationAir {
+            context: AirContext::new_multi_segment(
+                trace_info,
+                main_degrees,
+                aux_degrees,
+                3,
+                2,
+                options,
+            ),
+            result: pub_inputs.result,
+        }
+    }
+
+    fn context(&self) -> &AirContext<Self::BaseField> {
+        &self.context
+    }
+
+    fn evaluate_transition<E: FieldElement + From<Self::BaseField>>(
+        &self,
+        frame: &EvaluationFrame<E>,
+        _periodic_values: &[E],
+        result: &mut [E],
+    ) {
+        let current = frame.current();
+        let next = frame.next();
+        debug_assert_eq!(TRACE_WIDTH, current.len());
+        debug_assert_eq!(TRACE_WIDTH, next.len());
+
+        // columns 0 and 1 hold a sequence of Fibonacci pairs
+        result[0] = are_equal(next[0], current[1]);
+        result[1] = are_equal(next[1], current[0] + current[1]);
+    }
+
+    fn evaluate_aux_transition<F, E>(
+        &self,
+        main_frame: &EvaluationFrame<F>,
+        aux_frame: &EvaluationFrame<E>,
+        _periodic_values: &[F],
+        aux_rand_elements: &AuxRandElements<E>,
+        result: &mut [E],
+    ) where
+        F: FieldElement<BaseField = Self::BaseField>,
+        E: FieldElement<BaseField = Self::BaseField> + ExtensionOf<F>,
+    {
+        let main_current = main_frame.current();
+        let aux_current = aux_frame.current();
+        let aux_next = aux_frame.next();
+
+        // random elements of both auxiliary segments: α was drawn for the first segment, and γ
+        // was drawn for the second segment
+        let alpha = aux_rand_elements.rand_elements()[0];
+        let gamma = aux_rand_elements.rand_elements()[1];
+
+        // the first auxiliary segment holds fingerprints of the original and permuted pairs
+        let original = E::from(main_current[0]) + alpha * E::from(main_current[1]);
+        let permuted = E::from(main_current[2]) + alpha * E::from(main_current[3]);
+        result[0] = are_equal(aux_current[0], original);
+        result[1] = are_equal(aux_current[1], permuted);
+
+        // the second auxiliary segment holds the grand product which scales at each step by
+        // (γ + original) / (γ + permuted)
+        result[2] = are_equal(
+            aux_next[2] * (gamma + aux_current[1]),
+            aux_current[2] * (gamma + aux_current[0]),
+        );
+    }
+
+    fn get_assertions(&self) -> Vec<Assertion<Self::BaseField>> {
+        // the sequence of pairs starts with (1, 1) and ends with the public result
+        let last_step = self.trace_length() - 1;
+        vec![
+            Assertion::single(0, 0, BaseElement::ONE),
+            Assertion::single(1, 0, BaseElement::ONE),
+            Assertion::single(1, last_step, self.result),
+        ]
+    }
+
+    fn get_aux_assertions<E>(&self, _aux_rand_elements: &AuxRandElements<E>) -> Vec<Assertion<E>>
+    where
+        E: FieldElement<BaseField = Self::BaseField>,
+    {
+        // the grand product must start and end at one
+        let last_step = self.trace_length() - 1;
+        let product_column = FINGERPRINT_SEGMENT_WIDTH;
+        vec![
+            Assertion::single(product_column, 0, E::ONE),
+            Assertion::single(product_column, last_step, E::ONE),
+        ]
+    }
+}
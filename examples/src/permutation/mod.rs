@@ -0,0 +1,153 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use core::marker::PhantomData;
+use std::time::Instant;
+
+use tracing::{field, info_span};
+use winterfell::{
+    crypto::{DefaultRandomCoin, ElementHasher, MerkleTree},
+    math::{fields::f128::BaseElement, FieldElement},
+    Proof, ProofOptions, Prover, Trace, VerifierError,
+};
+
+use crate::{Blake3_192, Blake3_256, Example, ExampleOptions, HashFunction, Sha2_256, Sha3_256};
+
+mod air;
+use air::{PermutationAir, PublicInputs};
+
+mod prover;
+use prover::PermutationProver;
+
+#[cfg(test)]
+mod tests;
+
+// CONSTANTS
+// ================================================================================================
+
+const TRACE_WIDTH: usize = 4;
+
+// PERMUTATION EXAMPLE
+// ================================================================================================
+
+pub fn get_example(
+    options: &ExampleOptions,
+    sequence_length: usize,
+) -> Result<Box<dyn Example>, String> {
+    let (options, hash_fn) = options.to_proof_options(42, 8);
+
+    match hash_fn {
+        HashFunction::Blake3_192 => {
+            Ok(Box::new(PermutationExample::<Blake3_192>::new(sequence_length, options)))
+        },
+        HashFunction::Blake3_256 => {
+            Ok(Box::new(PermutationExample::<Blake3_256>::new(sequence_length, options)))
+        },
+        HashFunction::Sha3_256 => {
+            Ok(Box::new(PermutationExample::<Sha3_256>::new(sequence_length, options)))
+        },
+        HashFunction::Sha2_256 => {
+            Ok(Box::new(PermutationExample::<Sha2_256>::new(sequence_length, options)))
+        },
+        _ => Err("The specified hash function cannot be used with this example.".to_string()),
+    }
+}
+
+pub struct PermutationExample<H: ElementHasher> {
+    options: ProofOptions,
+    sequence_length: usize,
+    result: BaseElement,
+    _hasher: PhantomData<H>,
+}
+
+impl<H: ElementHasher> PermutationExample<H> {
+    pub fn new(sequence_length: usize, options: ProofOptions) -> Self {
+        assert!(sequence_length.is_power_of_two(), "sequence length must be a power of 2");
+
+        // compute the last term of the Fibonacci sequence of pairs
+        let now = Instant::now();
+        let result = compute_fib_pair_term(sequence_length);
+        println!(
+            "Computed Fibonacci pair sequence of length {} in {} ms",
+            sequence_length,
+            now.elapsed().as_millis()
+        );
+
+        PermutationExample {
+            options,
+            sequence_length,
+            result,
+            _hasher: PhantomData,
+        }
+    }
+}
+
+// EXAMPLE IMPLEMENTATION
+// ================================================================================================
+
+impl<H: ElementHasher> Example for PermutationExample<H>
+where
+    H: ElementHasher<BaseField = BaseElement> + Sync,
+{
+    fn prove(&self) -> Proof {
+        println!(
+            "Generating proof for permuting a Fibonacci pair sequence of length {}",
+            self.sequence_length
+        );
+
+        // create a prover
+        let prover = PermutationProver::<H>::new(self.options.clone());
+
+        // generate execution trace
+        let trace =
+            info_span!("generate_execution_trace", num_cols = TRACE_WIDTH, steps = field::Empty)
+                .in_scope(|| {
+                    let trace = prover.build_trace(self.sequence_length);
+                    tracing::Span::current().record("steps", trace.length());
+                    trace
+                });
+
+        // generate the proof
+        prover.prove(trace).unwrap()
+    }
+
+    fn verify(&self, proof: Proof) -> Result<(), VerifierError> {
+        let acceptable_options =
+            winterfell::AcceptableOptions::OptionSet(vec![proof.options().clone()]);
+
+        winterfell::verify::<PermutationAir, H, DefaultRandomCoin<H>, MerkleTree<H>>(
+            proof,
+            PublicInputs { result: self.result },
+            &acceptable_options,
+        )
+    }
+
+    fn verify_with_wrong_inputs(&self, proof: Proof) -> Result<(), VerifierError> {
+        let acceptable_options =
+            winterfell::AcceptableOptions::OptionSet(vec![proof.options().clone()]);
+
+        winterfell::verify::<PermutationAir, H, DefaultRandomCoin<H>, MerkleTree<H>>(
+            proof,
+            PublicInputs { result: self.result + BaseElement::ONE },
+            &acceptable_options,
+        )
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the second element of the last pair in a sequence of Fibonacci pairs of the specified
+/// length.
+fn compute_fib_pair_term(sequence_length: usize) -> BaseElement {
+    let mut a = BaseElement::ONE;
+    let mut b = BaseElement::ONE;
+    for _ in 0..sequence_length - 1 {
+        let c = a + b;
+        a = b;
+        b = c;
+    }
+    b
+}
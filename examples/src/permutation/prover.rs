@@ -0,0 +1,205 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use winterfell::{
+    crypto::MerkleTree, math::batch_inversion, matrix::ColMatrix, AuxRandElements, CompositionPoly,
+    CompositionPolyTrace, ConstraintCompositionCoefficients, DefaultConstraintCommitment,
+    DefaultConstraintEvaluator, DefaultTraceLde, EvaluationFrame, PartitionOptions, StarkDomain,
+    Trace, TraceInfo, TracePolyTable,
+};
+
+use super::{
+    air::{FINGERPRINT_SEGMENT_WIDTH, PRODUCT_SEGMENT_WIDTH},
+    BaseElement, DefaultRandomCoin, ElementHasher, FieldElement, PermutationAir, PhantomData,
+    ProofOptions, Prover, PublicInputs, TRACE_WIDTH,
+};
+
+// PERMUTATION TRACE
+// ================================================================================================
+
+/// Execution trace of the permutation example.
+///
+/// The main trace segment consists of two columns holding a sequence of Fibonacci pairs and two
+/// columns holding the same pairs in reverse order. The trace has two auxiliary segments: the
+/// first one holds fingerprints of the pairs, and the second one holds the grand product of the
+/// permutation argument.
+pub struct PermutationTrace {
+    info: TraceInfo,
+    trace: ColMatrix<BaseElement>,
+}
+
+impl Trace for PermutationTrace {
+    type BaseField = BaseElement;
+
+    fn info(&self) -> &TraceInfo {
+        &self.info
+    }
+
+    fn read_main_frame(&self, row_idx: usize, frame: &mut EvaluationFrame<Self::BaseField>) {
+        for i in 0..frame.num_rows() {
+            let frame_row_idx =
+                (row_idx as isize + frame.offsets()[i]).rem_euclid(self.info.length() as isize);
+            self.trace.read_row_into(frame_row_idx as usize, frame.row_mut(i));
+        }
+    }
+
+    fn main_segment(&self) -> &ColMatrix<BaseElement> {
+        &self.trace
+    }
+}
+
+// PERMUTATION PROVER
+// ================================================================================================
+
+pub struct PermutationProver<H: ElementHasher> {
+    options: ProofOptions,
+    _hasher: PhantomData<H>,
+}
+
+impl<H: ElementHasher> PermutationProver<H> {
+    pub fn new(options: ProofOptions) -> Self {
+        Self { options, _hasher: PhantomData }
+    }
+
+    /// Builds an execution trace for a sequence of Fibonacci pairs of the specified length.
+    pub fn build_trace(&self, sequence_length: usize) -> PermutationTrace {
+        let mut original = [vec![BaseElement::ONE], vec![BaseElement::ONE]];
+        for i in 1..sequence_length {
+            let (a, b) = (original[0][i - 1], original[1][i - 1]);
+            original[0].push(b);
+            original[1].push(a + b);
+        }
+
+        // the last row of the trace does not participate in the permutation; so, we permute
+        // the remaining pairs by reversing their order, and set the last permuted pair to zeros
+        let permuted = [0, 1].map(|col| {
+            let mut column: Vec<BaseElement> =
+                original[col][..sequence_length - 1].iter().rev().copied().collect();
+            column.push(BaseElement::ZERO);
+            column
+        });
+
+        let [a, b] = original;
+        let [x, y] = permuted;
+        PermutationTrace {
+            info: TraceInfo::new_with_aux_segments(
+                TRACE_WIDTH,
+                &[FINGERPRINT_SEGMENT_WIDTH, PRODUCT_SEGMENT_WIDTH],
+                &[1, 1],
+                sequence_length,
+                vec![],
+            ),
+            trace: ColMatrix::new(vec![a, b, x, y]),
+        }
+    }
+}
+
+impl<H: ElementHasher> Prover for PermutationProver<H>
+where
+    H: ElementHasher<BaseField = BaseElement> + Sync,
+{
+    type BaseField = BaseElement;
+    type Air = PermutationAir;
+    type Trace = PermutationTrace;
+    type HashFn = H;
+    type VC = MerkleTree<H>;
+    type RandomCoin = DefaultRandomCoin<Self::HashFn>;
+    type TraceLde<E: FieldElement<BaseField = Self::BaseField>> =
+        DefaultTraceLde<E, Self::HashFn, Self::VC>;
+    type ConstraintCommitment<E: FieldElement<BaseField = Self::BaseField>> =
+        DefaultConstraintCommitment<E, H, Self::VC>;
+    type ConstraintEvaluator<'a, E: FieldElement<BaseField = Self::BaseField>> =
+        DefaultConstraintEvaluator<'a, Self::Air, E>;
+
+    fn get_pub_inputs(&self, trace: &Self::Trace) -> PublicInputs {
+        let last_step = trace.length() - 1;
+        PublicInputs {
+            result: trace.main_segment().get(1, last_step),
+        }
+    }
+
+    fn options(&self) -> &ProofOptions {
+        &self.options
+    }
+
+    fn new_trace_lde<E: FieldElement<BaseField = Self::BaseField>>(
+        &self,
+        trace_info: &TraceInfo,
+        main_trace: &ColMatrix<Self::BaseField>,
+        domain: &StarkDomain<Self::BaseField>,
+        partition_options: PartitionOptions,
+    ) -> (Self::TraceLde<E>, TracePolyTable<E>) {
+        DefaultTraceLde::new(trace_info, main_trace, domain, partition_options)
+    }
+
+    fn new_evaluator<'a, E: FieldElement<BaseField = Self::BaseField>>(
+        &self,
+        air: &'a Self::Air,
+        aux_rand_elements: Option<AuxRandElements<E>>,
+        composition_coefficients: ConstraintCompositionCoefficients<E>,
+    ) -> Self::ConstraintEvaluator<'a, E> {
+        DefaultConstraintEvaluator::new(air, aux_rand_elements, composition_coefficients)
+    }
+
+    fn build_constraint_commitment<E: FieldElement<BaseField = Self::BaseField>>(
+        &self,
+        composition_poly_trace: CompositionPolyTrace<E>,
+        num_constraint_composition_columns: usize,
+        domain: &StarkDomain<Self::BaseField>,
+        partition_options: PartitionOptions,
+    ) -> (Self::ConstraintCommitment<E>, CompositionPoly<E>) {
+        DefaultConstraintCommitment::new(
+            composition_poly_trace,
+            num_constraint_composition_columns,
+            domain,
+            partition_options,
+        )
+    }
+
+    fn build_aux_segment<E>(
+        &self,
+        trace: &Self::Trace,
+        aux_segments: &[ColMatrix<E>],
+        aux_rand_elements: &AuxRandElements<E>,
+    ) -> ColMatrix<E>
+    where
+        E: FieldElement<BaseField = Self::BaseField>,
+    {
+        let main_trace = trace.main_segment();
+        let rand_elements = aux_rand_elements.rand_elements();
+
+        match aux_segments {
+            // the first segment holds fingerprints u + α * v of the original and permuted pairs
+            [] => {
+                let alpha = rand_elements[0];
+                let fingerprints = [0, 2].map(|col| {
+                    main_trace
+                        .get_column(col)
+                        .iter()
+                        .zip(main_trace.get_column(col + 1))
+                        .map(|(&u, &v)| alpha.mul_base(v) + E::from(u))
+                        .collect()
+                });
+                ColMatrix::new(fingerprints.to_vec())
+            },
+            // the second segment holds the grand product of (γ + original) / (γ + permuted)
+            [fingerprints] => {
+                let gamma = rand_elements[1];
+                let original = fingerprints.get_column(0);
+                let permuted = fingerprints.get_column(1);
+
+                let denominators: Vec<E> = permuted.iter().map(|&p| gamma + p).collect();
+                let inv_denominators = batch_inversion(&denominators);
+
+                let mut product = vec![E::ONE; main_trace.num_rows()];
+                for i in 0..main_trace.num_rows() - 1 {
+                    product[i + 1] = product[i] * (gamma + original[i]) * inv_denominators[i];
+                }
+                ColMatrix::new(vec![product])
+            },
+            _ => unreachable!("the trace has only two auxiliary segments"),
+        }
+    }
+}
@@ -11,6 +11,9 @@ pub struct BenchmarkResult {
     pub parameter_name: String,
     pub parameter_value: String,
     pub proof_size_bytes: usize,
+    pub conjectured_security_bits: u32,
+    pub hash_fn: String,
+    pub digest_bits: usize,
     pub blowup_factor: usize,
     pub grinding_factor: u32,
     pub field_extension: u32,
@@ -19,6 +22,7 @@ pub struct BenchmarkResult {
 }
 
 pub struct ProofSizeBenchmark {
+    pub default_hash_fn: String,
     pub default_blowup: usize,
     pub default_grinding: u32,
     pub default_field_extension: u32,
@@ -29,6 +33,7 @@ pub struct ProofSizeBenchmark {
 impl Default for ProofSizeBenchmark {
     fn default() -> Self {
         Self {
+            default_hash_fn: "blake3_256".to_string(),
             default_blowup: 8,
             default_grinding: 16,
             default_field_extension: 1,
@@ -74,6 +79,16 @@ impl ProofSizeBenchmark {
         let string_length_results = self.benchmark_string_length()?;
         all_results.extend(string_length_results);
 
+        // Бенчмарк длины хеша
+        println!("Тестирование digest length...");
+        let digest_length_results = self.benchmark_digest_length()?;
+        all_results.extend(digest_length_results);
+
+        // Бенчмарк векторного коммитмента
+        println!("Тестирование vector commitment...");
+        let vector_commitment_results = self.benchmark_vector_commitment()?;
+        all_results.extend(vector_commitment_results);
+
         Ok(all_results)
     }
 
@@ -84,7 +99,8 @@ impl ProofSizeBenchmark {
 
         for &blowup in &blowup_values {
             println!("  Тестирование blowup = {}", blowup);
-            let proof_size = self.measure_proof_size(
+            let (proof_size, security) = self.measure_proof(
+                &self.default_hash_fn,
                 blowup,
                 self.default_grinding,
                 self.default_field_extension,
@@ -96,6 +112,9 @@ impl ProofSizeBenchmark {
                 parameter_name: "blowup_factor".to_string(),
                 parameter_value: blowup.to_string(),
                 proof_size_bytes: proof_size,
+                conjectured_security_bits: security,
+                hash_fn: self.default_hash_fn.clone(),
+                digest_bits: digest_bits(&self.default_hash_fn),
                 blowup_factor: blowup,
                 grinding_factor: self.default_grinding,
                 field_extension: self.default_field_extension,
@@ -114,7 +133,8 @@ impl ProofSizeBenchmark {
 
         for &grinding in &grinding_values {
             println!("  Тестирование grinding = {}", grinding);
-            let proof_size = self.measure_proof_size(
+            let (proof_size, security) = self.measure_proof(
+                &self.default_hash_fn,
                 self.default_blowup,
                 grinding,
                 self.default_field_extension,
@@ -126,6 +146,9 @@ impl ProofSizeBenchmark {
                 parameter_name: "grinding_factor".to_string(),
                 parameter_value: grinding.to_string(),
                 proof_size_bytes: proof_size,
+                conjectured_security_bits: security,
+                hash_fn: self.default_hash_fn.clone(),
+                digest_bits: digest_bits(&self.default_hash_fn),
                 blowup_factor: self.default_blowup,
                 grinding_factor: grinding,
                 field_extension: self.default_field_extension,
@@ -144,7 +167,8 @@ impl ProofSizeBenchmark {
 
         for &field_ext in &field_ext_values {
             println!("  Тестирование field_extension = {}", field_ext);
-            let proof_size = self.measure_proof_size(
+            let (proof_size, security) = self.measure_proof(
+                &self.default_hash_fn,
                 self.default_blowup,
                 self.default_grinding,
                 field_ext,
@@ -156,6 +180,9 @@ impl ProofSizeBenchmark {
                 parameter_name: "field_extension".to_string(),
                 parameter_value: field_ext.to_string(),
                 proof_size_bytes: proof_size,
+                conjectured_security_bits: security,
+                hash_fn: self.default_hash_fn.clone(),
+                digest_bits: digest_bits(&self.default_hash_fn),
                 blowup_factor: self.default_blowup,
                 grinding_factor: self.default_grinding,
                 field_extension: field_ext,
@@ -174,7 +201,8 @@ impl ProofSizeBenchmark {
 
         for &folding in &folding_values {
             println!("  Тестирование folding = {}", folding);
-            let proof_size = self.measure_proof_size(
+            let (proof_size, security) = self.measure_proof(
+                &self.default_hash_fn,
                 self.default_blowup,
                 self.default_grinding,
                 self.default_field_extension,
@@ -186,6 +214,9 @@ impl ProofSizeBenchmark {
                 parameter_name: "folding_factor".to_string(),
                 parameter_value: folding.to_string(),
                 proof_size_bytes: proof_size,
+                conjectured_security_bits: security,
+                hash_fn: self.default_hash_fn.clone(),
+                digest_bits: digest_bits(&self.default_hash_fn),
                 blowup_factor: self.default_blowup,
                 grinding_factor: self.default_grinding,
                 field_extension: self.default_field_extension,
@@ -204,7 +235,8 @@ impl ProofSizeBenchmark {
 
         for &string_length in &string_length_values {
             println!("  Тестирование string_length = {}", string_length);
-            let proof_size = self.measure_proof_size(
+            let (proof_size, security) = self.measure_proof(
+                &self.default_hash_fn,
                 self.default_blowup,
                 self.default_grinding,
                 self.default_field_extension,
@@ -216,6 +248,9 @@ impl ProofSizeBenchmark {
                 parameter_name: "string_length".to_string(),
                 parameter_value: string_length.to_string(),
                 proof_size_bytes: proof_size,
+                conjectured_security_bits: security,
+                hash_fn: self.default_hash_fn.clone(),
+                digest_bits: digest_bits(&self.default_hash_fn),
                 blowup_factor: self.default_blowup,
                 grinding_factor: self.default_grinding,
                 field_extension: self.default_field_extension,
@@ -227,36 +262,139 @@ impl ProofSizeBenchmark {
         Ok(results)
     }
 
-    /// Измеряет размер доказательства для заданных параметров
-    fn measure_proof_size(
+    /// Бенчмарк для длины хеша
+    fn benchmark_digest_length(&self) -> Result<Vec<BenchmarkResult>, Box<dyn std::error::Error>> {
+        let hash_fn_values = vec!["blake3_160", "blake3_192", "blake3_224", "blake3_256"];
+        let mut results = Vec::new();
+
+        for &hash_fn in &hash_fn_values {
+            println!("  Тестирование hash_fn = {}", hash_fn);
+            let (proof_size, security) = self.measure_proof(
+                hash_fn,
+                self.default_blowup,
+                self.default_grinding,
+                self.default_field_extension,
+                self.default_folding,
+                self.default_string_length,
+            )?;
+
+            results.push(BenchmarkResult {
+                parameter_name: "digest_length".to_string(),
+                parameter_value: hash_fn.to_string(),
+                proof_size_bytes: proof_size,
+                conjectured_security_bits: security,
+                hash_fn: hash_fn.to_string(),
+                digest_bits: digest_bits(hash_fn),
+                blowup_factor: self.default_blowup,
+                grinding_factor: self.default_grinding,
+                field_extension: self.default_field_extension,
+                folding_factor: self.default_folding,
+                string_length: self.default_string_length,
+            });
+        }
+
+        Ok(results)
+    }
+
+    /// Бенчмарк для векторного коммитмента: обычное дерево Меркла и дерево Меркла с капом высоты
+    /// по умолчанию
+    fn benchmark_vector_commitment(&self) -> Result<Vec<BenchmarkResult>, Box<dyn std::error::Error>> {
+        let vector_commitment_values = vec!["merkle_tree", "merkle_cap"];
+        let mut results = Vec::new();
+
+        for &vector_commitment in &vector_commitment_values {
+            println!("  Тестирование vector_commitment = {}", vector_commitment);
+            let (proof_size, security) = self.measure_proof_with_commitment(
+                vector_commitment,
+                &self.default_hash_fn,
+                self.default_blowup,
+                self.default_grinding,
+                self.default_field_extension,
+                self.default_folding,
+                self.default_string_length,
+            )?;
+
+            results.push(BenchmarkResult {
+                parameter_name: "vector_commitment".to_string(),
+                parameter_value: vector_commitment.to_string(),
+                proof_size_bytes: proof_size,
+                conjectured_security_bits: security,
+                hash_fn: self.default_hash_fn.clone(),
+                digest_bits: digest_bits(&self.default_hash_fn),
+                blowup_factor: self.default_blowup,
+                grinding_factor: self.default_grinding,
+                field_extension: self.default_field_extension,
+                folding_factor: self.default_folding,
+                string_length: self.default_string_length,
+            });
+        }
+
+        Ok(results)
+    }
+
+    /// Измеряет размер доказательства и его предполагаемый уровень безопасности для заданных
+    /// параметров
+    fn measure_proof(
         &self,
+        hash_fn: &str,
         blowup_factor: usize,
         grinding_factor: u32,
         field_extension: u32,
         folding_factor: usize,
         string_length: usize,
-    ) -> Result<usize, Box<dyn std::error::Error>> {
+    ) -> Result<(usize, u32), Box<dyn std::error::Error>> {
+        self.measure_proof_with_commitment(
+            "merkle_tree",
+            hash_fn,
+            blowup_factor,
+            grinding_factor,
+            field_extension,
+            folding_factor,
+            string_length,
+        )
+    }
+
+    /// Измеряет размер доказательства и его предполагаемый уровень безопасности для заданных
+    /// параметров и векторного коммитмента ("merkle_tree" или "merkle_cap")
+    #[allow(clippy::too_many_arguments)]
+    fn measure_proof_with_commitment(
+        &self,
+        vector_commitment: &str,
+        hash_fn: &str,
+        blowup_factor: usize,
+        grinding_factor: u32,
+        field_extension: u32,
+        folding_factor: usize,
+        string_length: usize,
+    ) -> Result<(usize, u32), Box<dyn std::error::Error>> {
         // Создаем опции для примера
         let options = ExampleOptions {
             example: crate::ExampleType::ExperimentSha { string_length },
-            hash_fn: "blake3_256".to_string(),
+            hash_fn: hash_fn.to_string(),
             num_queries: None,
             blowup_factor: Some(blowup_factor),
             grinding_factor,
             field_extension,
             folding_factor,
+            field: None,
         };
 
         // Получаем пример
-        let example = experiment_sha::get_example(&options, string_length)?;
+        let example = match vector_commitment {
+            "merkle_cap" => experiment_sha::get_merkle_cap_example(&options, string_length)?,
+            _ => experiment_sha::get_example(&options, string_length)?,
+        };
 
         // Генерируем доказательство
         let proof = example.prove();
 
         // Получаем размер доказательства в байтах
         let proof_bytes = proof.to_bytes();
-        
-        Ok(proof_bytes.len())
+
+        // Получаем предполагаемый уровень безопасности доказательства
+        let security = options.get_proof_security_level_conjectured(&proof);
+
+        Ok((proof_bytes.len(), security))
     }
 
     /// Сохраняет результаты в CSV файл
@@ -332,6 +470,7 @@ impl ProofSizeBenchmark {
                     "grinding_factor" => r.grinding_factor as f64,
                     "field_extension" => r.field_extension as f64,
                     "folding_factor" => r.folding_factor as f64,
+                    "digest_length" => r.digest_bits as f64,
                     _ => 0.0,
                 };
                 (x_val, r.proof_size_bytes as f64)
@@ -394,3 +533,8 @@ impl ProofSizeBenchmark {
         Ok(())
     }
 }
+
+/// Возвращает длину хеша в битах по имени хеш-функции (например, 160 для "blake3_160")
+fn digest_bits(hash_fn: &str) -> usize {
+    hash_fn.rsplit('_').next().and_then(|bits| bits.parse().ok()).unwrap_or(0)
+}
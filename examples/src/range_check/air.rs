@@ -0,0 +1,81 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use winterfell::{
+    math::ToElements, Air, AirContext, Assertion, EvaluationFrame, LogUp, TraceInfo,
+    TransitionConstraintDegree,
+};
+
+use super::{BaseElement, FieldElement, ProofOptions, TRACE_WIDTH};
+use crate::utils::are_equal;
+
+// RANGE CHECK AIR
+// ================================================================================================
+
+#[derive(Clone)]
+pub struct PublicInputs {
+    pub first_values: [BaseElement; 2],
+}
+
+impl ToElements<BaseElement> for PublicInputs {
+    fn to_elements(&self) -> Vec<BaseElement> {
+        self.first_values.to_vec()
+    }
+}
+
+pub struct RangeCheckAir {
+    context: AirContext<BaseElement>,
+    first_values: [BaseElement; 2],
+}
+
+impl Air for RangeCheckAir {
+    type BaseField = BaseElement;
+    type PublicInputs = PublicInputs;
+
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+    fn new(trace_info: TraceInfo, pub_inputs: PublicInputs, options: ProofOptions) -> Self {
+        let degrees = vec![TransitionConstraintDegree::new(1)];
+        assert_eq!(TRACE_WIDTH, trace_info.main_trace_width());
+
+        // values in columns 0 and 1 are looked up in the table defined by column 2; column 3
+        // holds the number of times each table row is looked up. The LogUp argument is the only
+        // component of the auxiliary trace segment, and thus we don't need to specify any other
+        // auxiliary constraints or assertions.
+        let logup = LogUp::new(vec![vec![0], vec![1]], vec![2], 3);
+        RangeCheckAir {
+            context: AirContext::new_with_logup(trace_info, degrees, vec![], 3, 0, logup, options),
+            first_values: pub_inputs.first_values,
+        }
+    }
+
+    fn context(&self) -> &AirContext<Self::BaseField> {
+        &self.context
+    }
+
+    fn evaluate_transition<E: FieldElement + From<Self::BaseField>>(
+        &self,
+        frame: &EvaluationFrame<E>,
+        _periodic_values: &[E],
+        result: &mut [E],
+    ) {
+        let current = frame.current();
+        let next = frame.next();
+        debug_assert_eq!(TRACE_WIDTH, current.len());
+        debug_assert_eq!(TRACE_WIDTH, next.len());
+
+        // the table column must be incremented by one at every step
+        result[0] = are_equal(next[2], current[2] + E::ONE);
+    }
+
+    fn get_assertions(&self) -> Vec<Assertion<Self::BaseField>> {
+        // the table must start at zero, and the first looked-up values must match public inputs
+        vec![
+            Assertion::single(0, 0, self.first_values[0]),
+            Assertion::single(1, 0, self.first_values[1]),
+            Assertion::single(2, 0, BaseElement::ZERO),
+        ]
+    }
+}
@@ -0,0 +1,145 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use core::marker::PhantomData;
+use std::time::Instant;
+
+use rand_utils::rand_value;
+use tracing::{field, info_span};
+use winterfell::{
+    crypto::{DefaultRandomCoin, ElementHasher, MerkleTree},
+    math::{fields::f128::BaseElement, FieldElement},
+    Proof, ProofOptions, Prover, Trace, VerifierError,
+};
+
+use crate::{Blake3_192, Blake3_256, Example, ExampleOptions, HashFunction, Sha2_256, Sha3_256};
+
+mod air;
+use air::{PublicInputs, RangeCheckAir};
+
+mod prover;
+use prover::RangeCheckProver;
+
+#[cfg(test)]
+mod tests;
+
+// CONSTANTS
+// ================================================================================================
+
+const TRACE_WIDTH: usize = 4;
+
+// RANGE CHECK EXAMPLE
+// ================================================================================================
+
+pub fn get_example(
+    options: &ExampleOptions,
+    num_values: usize,
+) -> Result<Box<dyn Example>, String> {
+    let (options, hash_fn) = options.to_proof_options(42, 8);
+
+    match hash_fn {
+        HashFunction::Blake3_192 => {
+            Ok(Box::new(RangeCheckExample::<Blake3_192>::new(num_values, options)))
+        },
+        HashFunction::Blake3_256 => {
+            Ok(Box::new(RangeCheckExample::<Blake3_256>::new(num_values, options)))
+        },
+        HashFunction::Sha3_256 => {
+            Ok(Box::new(RangeCheckExample::<Sha3_256>::new(num_values, options)))
+        },
+        HashFunction::Sha2_256 => {
+            Ok(Box::new(RangeCheckExample::<Sha2_256>::new(num_values, options)))
+        },
+        _ => Err("The specified hash function cannot be used with this example.".to_string()),
+    }
+}
+
+pub struct RangeCheckExample<H: ElementHasher> {
+    options: ProofOptions,
+    values: [Vec<u64>; 2],
+    _hasher: PhantomData<H>,
+}
+
+impl<H: ElementHasher> RangeCheckExample<H> {
+    pub fn new(num_values: usize, options: ProofOptions) -> Self {
+        assert!(num_values.is_power_of_two(), "number of values must be a power of 2");
+
+        // generate two sequences of random values in range [0, num_values - 1); the last row of
+        // the trace does not participate in the lookup, and thus the last value in the table is
+        // num_values - 2
+        let now = Instant::now();
+        let range = num_values as u64 - 1;
+        let values = [0, 1].map(|_| (0..num_values).map(|_| rand_value::<u64>() % range).collect());
+        println!(
+            "Generated {} values in range [0, {}) in {} ms",
+            2 * num_values,
+            range,
+            now.elapsed().as_millis()
+        );
+
+        RangeCheckExample { options, values, _hasher: PhantomData }
+    }
+
+    fn get_pub_inputs(&self) -> PublicInputs {
+        PublicInputs {
+            first_values: [
+                BaseElement::from(self.values[0][0]),
+                BaseElement::from(self.values[1][0]),
+            ],
+        }
+    }
+}
+
+// EXAMPLE IMPLEMENTATION
+// ================================================================================================
+
+impl<H: ElementHasher> Example for RangeCheckExample<H>
+where
+    H: ElementHasher<BaseField = BaseElement> + Sync,
+{
+    fn prove(&self) -> Proof {
+        let num_values = self.values[0].len();
+        println!("Generating proof for range checking {} values", 2 * num_values);
+
+        // create a prover
+        let prover = RangeCheckProver::<H>::new(self.options.clone());
+
+        // generate execution trace
+        let trace =
+            info_span!("generate_execution_trace", num_cols = TRACE_WIDTH, steps = field::Empty)
+                .in_scope(|| {
+                    let trace = prover.build_trace(&self.values);
+                    tracing::Span::current().record("steps", trace.length());
+                    trace
+                });
+
+        // generate the proof
+        prover.prove(trace).unwrap()
+    }
+
+    fn verify(&self, proof: Proof) -> Result<(), VerifierError> {
+        let acceptable_options =
+            winterfell::AcceptableOptions::OptionSet(vec![proof.options().clone()]);
+
+        winterfell::verify::<RangeCheckAir, H, DefaultRandomCoin<H>, MerkleTree<H>>(
+            proof,
+            self.get_pub_inputs(),
+            &acceptable_options,
+        )
+    }
+
+    fn verify_with_wrong_inputs(&self, proof: Proof) -> Result<(), VerifierError> {
+        let mut pub_inputs = self.get_pub_inputs();
+        pub_inputs.first_values[0] += BaseElement::ONE;
+
+        let acceptable_options =
+            winterfell::AcceptableOptions::OptionSet(vec![proof.options().clone()]);
+        winterfell::verify::<RangeCheckAir, H, DefaultRandomCoin<H>, MerkleTree<H>>(
+            proof,
+            pub_inputs,
+            &acceptable_options,
+        )
+    }
+}
@@ -0,0 +1,159 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use winterfell::{
+    crypto::MerkleTree, matrix::ColMatrix, AuxRandElements, CompositionPoly, CompositionPolyTrace,
+    ConstraintCompositionCoefficients, DefaultConstraintCommitment, DefaultConstraintEvaluator,
+    DefaultTraceLde, EvaluationFrame, LogUp, PartitionOptions, StarkDomain, Trace, TraceInfo,
+    TracePolyTable,
+};
+
+use super::{
+    BaseElement, DefaultRandomCoin, ElementHasher, FieldElement, PhantomData, ProofOptions, Prover,
+    PublicInputs, RangeCheckAir, TRACE_WIDTH,
+};
+
+// RANGE CHECK TRACE
+// ================================================================================================
+
+/// Execution trace of the range check example.
+///
+/// The main trace segment consists of two columns of looked-up values, a table column, and a
+/// multiplicity column. The auxiliary trace segment consists of a single running-sum column of
+/// the LogUp argument, which is built by the prover automatically.
+pub struct RangeCheckTrace {
+    info: TraceInfo,
+    trace: ColMatrix<BaseElement>,
+}
+
+impl Trace for RangeCheckTrace {
+    type BaseField = BaseElement;
+
+    fn info(&self) -> &TraceInfo {
+        &self.info
+    }
+
+    fn read_main_frame(&self, row_idx: usize, frame: &mut EvaluationFrame<Self::BaseField>) {
+        for i in 0..frame.num_rows() {
+            let frame_row_idx =
+                (row_idx as isize + frame.offsets()[i]).rem_euclid(self.info.length() as isize);
+            self.trace.read_row_into(frame_row_idx as usize, frame.row_mut(i));
+        }
+    }
+
+    fn main_segment(&self) -> &ColMatrix<BaseElement> {
+        &self.trace
+    }
+}
+
+// RANGE CHECK PROVER
+// ================================================================================================
+
+pub struct RangeCheckProver<H: ElementHasher> {
+    options: ProofOptions,
+    _hasher: PhantomData<H>,
+}
+
+impl<H: ElementHasher> RangeCheckProver<H> {
+    pub fn new(options: ProofOptions) -> Self {
+        Self { options, _hasher: PhantomData }
+    }
+
+    /// Builds an execution trace which range checks the provided values against the table of
+    /// values [0, n - 1), where n is the number of values in each sequence.
+    pub fn build_trace(&self, values: &[Vec<u64>; 2]) -> RangeCheckTrace {
+        let trace_length = values[0].len();
+
+        // the last row of the trace does not participate in the lookup, and thus we don't count
+        // values located in it
+        let mut multiplicities = vec![0u64; trace_length];
+        for column in values.iter() {
+            for &value in column.iter().take(trace_length - 1) {
+                multiplicities[value as usize] += 1;
+            }
+        }
+
+        let columns = vec![
+            values[0].iter().map(|&v| BaseElement::from(v)).collect(),
+            values[1].iter().map(|&v| BaseElement::from(v)).collect(),
+            (0..trace_length as u64).map(BaseElement::from).collect(),
+            multiplicities.into_iter().map(BaseElement::from).collect(),
+        ];
+
+        let logup = LogUp::new(vec![vec![0], vec![1]], vec![2], 3);
+        RangeCheckTrace {
+            info: TraceInfo::new_multi_segment(
+                TRACE_WIDTH,
+                logup.aux_trace_width(),
+                logup.num_rand_elements(),
+                trace_length,
+                vec![],
+            ),
+            trace: ColMatrix::new(columns),
+        }
+    }
+}
+
+impl<H: ElementHasher> Prover for RangeCheckProver<H>
+where
+    H: ElementHasher<BaseField = BaseElement> + Sync,
+{
+    type BaseField = BaseElement;
+    type Air = RangeCheckAir;
+    type Trace = RangeCheckTrace;
+    type HashFn = H;
+    type VC = MerkleTree<H>;
+    type RandomCoin = DefaultRandomCoin<Self::HashFn>;
+    type TraceLde<E: FieldElement<BaseField = Self::BaseField>> =
+        DefaultTraceLde<E, Self::HashFn, Self::VC>;
+    type ConstraintCommitment<E: FieldElement<BaseField = Self::BaseField>> =
+        DefaultConstraintCommitment<E, H, Self::VC>;
+    type ConstraintEvaluator<'a, E: FieldElement<BaseField = Self::BaseField>> =
+        DefaultConstraintEvaluator<'a, Self::Air, E>;
+
+    fn get_pub_inputs(&self, trace: &Self::Trace) -> PublicInputs {
+        PublicInputs {
+            first_values: [trace.main_segment().get(0, 0), trace.main_segment().get(1, 0)],
+        }
+    }
+
+    fn options(&self) -> &ProofOptions {
+        &self.options
+    }
+
+    fn new_trace_lde<E: FieldElement<BaseField = Self::BaseField>>(
+        &self,
+        trace_info: &TraceInfo,
+        main_trace: &ColMatrix<Self::BaseField>,
+        domain: &StarkDomain<Self::BaseField>,
+        partition_options: PartitionOptions,
+    ) -> (Self::TraceLde<E>, TracePolyTable<E>) {
+        DefaultTraceLde::new(trace_info, main_trace, domain, partition_options)
+    }
+
+    fn new_evaluator<'a, E: FieldElement<BaseField = Self::BaseField>>(
+        &self,
+        air: &'a Self::Air,
+        aux_rand_elements: Option<AuxRandElements<E>>,
+        composition_coefficients: ConstraintCompositionCoefficients<E>,
+    ) -> Self::ConstraintEvaluator<'a, E> {
+        DefaultConstraintEvaluator::new(air, aux_rand_elements, composition_coefficients)
+    }
+
+    fn build_constraint_commitment<E: FieldElement<BaseField = Self::BaseField>>(
+        &self,
+        composition_poly_trace: CompositionPolyTrace<E>,
+        num_constraint_composition_columns: usize,
+        domain: &StarkDomain<Self::BaseField>,
+        partition_options: PartitionOptions,
+    ) -> (Self::ConstraintCommitment<E>, CompositionPoly<E>) {
+        DefaultConstraintCommitment::new(
+            composition_poly_trace,
+            num_constraint_composition_columns,
+            domain,
+            partition_options,
+        )
+    }
+}
@@ -8,61 +8,40 @@ use winterfell::{
     TransitionConstraintDegree,
 };
 
-use super::{rescue, BaseElement, FieldElement, ProofOptions, CYCLE_LENGTH, TRACE_WIDTH};
+use super::{
+    rescue, FieldElement, ProofOptions, RescueField, CYCLE_LENGTH, NUM_HASH_ROUNDS, TRACE_WIDTH,
+};
 use crate::utils::{are_equal, is_zero, not, EvaluationResult};
 
-// CONSTANTS
-// ================================================================================================
-
-/// Specifies steps on which Rescue transition function is applied.
-const CYCLE_MASK: [BaseElement; CYCLE_LENGTH] = [
-    BaseElement::ONE,
-    BaseElement::ONE,
-    BaseElement::ONE,
-    BaseElement::ONE,
-    BaseElement::ONE,
-    BaseElement::ONE,
-    BaseElement::ONE,
-    BaseElement::ONE,
-    BaseElement::ONE,
-    BaseElement::ONE,
-    BaseElement::ONE,
-    BaseElement::ONE,
-    BaseElement::ONE,
-    BaseElement::ONE,
-    BaseElement::ZERO,
-    BaseElement::ZERO,
-];
-
 // RESCUE AIR
 // ================================================================================================
 
-pub struct PublicInputs {
-    pub seed: [BaseElement; 2],
-    pub result: [BaseElement; 2],
+pub struct PublicInputs<B: RescueField> {
+    pub seed: [B; 2],
+    pub result: [B; 2],
 }
 
-impl ToElements<BaseElement> for PublicInputs {
-    fn to_elements(&self) -> Vec<BaseElement> {
+impl<B: RescueField> ToElements<B> for PublicInputs<B> {
+    fn to_elements(&self) -> Vec<B> {
         let mut result = self.seed.to_vec();
         result.extend_from_slice(&self.result);
         result
     }
 }
 
-pub struct RescueAir {
-    context: AirContext<BaseElement>,
-    seed: [BaseElement; 2],
-    result: [BaseElement; 2],
+pub struct RescueAir<B: RescueField> {
+    context: AirContext<B>,
+    seed: [B; 2],
+    result: [B; 2],
 }
 
-impl Air for RescueAir {
-    type BaseField = BaseElement;
-    type PublicInputs = PublicInputs;
+impl<B: RescueField> Air for RescueAir<B> {
+    type BaseField = B;
+    type PublicInputs = PublicInputs<B>;
 
     // CONSTRUCTOR
     // --------------------------------------------------------------------------------------------
-    fn new(trace_info: TraceInfo, pub_inputs: PublicInputs, options: ProofOptions) -> Self {
+    fn new(trace_info: TraceInfo, pub_inputs: PublicInputs<B>, options: ProofOptions) -> Self {
         let degrees = vec![
             TransitionConstraintDegree::with_cycles(1, vec![CYCLE_LENGTH]),
             TransitionConstraintDegree::with_cycles(3, vec![CYCLE_LENGTH]),
@@ -98,7 +77,7 @@ impl Air for RescueAir {
         let ark = &periodic_values[1..];
 
         // when hash_flag = 1, constraints for Rescue round are enforced
-        rescue::enforce_round(result, current, next, ark, hash_flag);
+        rescue::enforce_round::<B, E>(result, current, next, ark, hash_flag);
 
         // when hash_flag = 0, constraints for copying hash values to the next
         // step are enforced.
@@ -118,7 +97,11 @@ impl Air for RescueAir {
     }
 
     fn get_periodic_column_values(&self) -> Vec<Vec<Self::BaseField>> {
-        let mut result = vec![CYCLE_MASK.to_vec()];
+        // hash_flag is set to 1 on the steps on which Rescue transition function is applied
+        let mut hash_flag = vec![B::ONE; NUM_HASH_ROUNDS];
+        hash_flag.resize(CYCLE_LENGTH, B::ZERO);
+
+        let mut result = vec![hash_flag];
         result.append(&mut rescue::get_round_constants());
         result
     }
@@ -8,15 +8,22 @@ use std::time::Instant;
 
 use tracing::{field, info_span};
 use winterfell::{
-    crypto::{DefaultRandomCoin, ElementHasher, MerkleTree},
-    math::{fields::f128::BaseElement, FieldElement},
+    crypto::{
+        hashers::{Blake3_192, Blake3_256, Sha2_256, Sha3_256},
+        DefaultRandomCoin, ElementHasher, MerkleTree,
+    },
+    math::{
+        fields::{f128::BaseElement as F128BaseElement, f62::BaseElement as F62BaseElement},
+        FieldElement,
+    },
     Proof, ProofOptions, Prover, Trace, VerifierError,
 };
 
-use crate::{Blake3_192, Blake3_256, Example, ExampleOptions, HashFunction, Sha3_256};
+use crate::{Example, ExampleOptions, FieldType, HashFunction};
 
 #[allow(clippy::module_inception)]
 pub(crate) mod rescue;
+use rescue::RescueField;
 
 mod air;
 use air::{PublicInputs, RescueAir};
@@ -41,17 +48,36 @@ pub fn get_example(
     options: &ExampleOptions,
     chain_length: usize,
 ) -> Result<Box<dyn Example>, String> {
+    let field = options.field(FieldType::F128);
     let (options, hash_fn) = options.to_proof_options(42, 4);
 
+    match field {
+        FieldType::F62 => build_example::<F62BaseElement>(chain_length, options, hash_fn),
+        FieldType::F64 => Err("The rescue example cannot be used with the f64 field.".to_string()),
+        FieldType::F128 => build_example::<F128BaseElement>(chain_length, options, hash_fn),
+    }
+}
+
+fn build_example<B: RescueField>(
+    chain_length: usize,
+    options: ProofOptions,
+    hash_fn: HashFunction,
+) -> Result<Box<dyn Example>, String> {
     match hash_fn {
         HashFunction::Blake3_192 => {
-            Ok(Box::new(RescueExample::<Blake3_192>::new(chain_length, options)))
+            Ok(Box::new(RescueExample::<Blake3_192<B>>::new(chain_length, options)))
         },
         HashFunction::Blake3_256 => {
-            Ok(Box::new(RescueExample::<Blake3_256>::new(chain_length, options)))
+            Ok(Box::new(RescueExample::<Blake3_256<B>>::new(chain_length, options)))
         },
         HashFunction::Sha3_256 => {
-            Ok(Box::new(RescueExample::<Sha3_256>::new(chain_length, options)))
+            Ok(Box::new(RescueExample::<Sha3_256<B>>::new(chain_length, options)))
+        },
+        HashFunction::Sha2_256 => {
+            Ok(Box::new(RescueExample::<Sha2_256<B>>::new(chain_length, options)))
+        },
+        hash_fn if hash_fn == B::RESCUE_PRIME => {
+            Ok(Box::new(RescueExample::<B::RescuePrime>::new(chain_length, options)))
         },
         _ => Err("The specified hash function cannot be used with this example.".to_string()),
     }
@@ -60,15 +86,18 @@ pub fn get_example(
 pub struct RescueExample<H: ElementHasher> {
     options: ProofOptions,
     chain_length: usize,
-    seed: [BaseElement; 2],
-    result: [BaseElement; 2],
+    seed: [H::BaseField; 2],
+    result: [H::BaseField; 2],
     _hasher: PhantomData<H>,
 }
 
-impl<H: ElementHasher> RescueExample<H> {
+impl<H: ElementHasher> RescueExample<H>
+where
+    H::BaseField: RescueField,
+{
     pub fn new(chain_length: usize, options: ProofOptions) -> Self {
         assert!(chain_length.is_power_of_two(), "chain length must a power of 2");
-        let seed = [BaseElement::from(42u8), BaseElement::from(43u8)];
+        let seed = [H::BaseField::from(42u8), H::BaseField::from(43u8)];
 
         // compute the sequence of hashes using external implementation of Rescue hash
         let now = Instant::now();
@@ -94,7 +123,8 @@ impl<H: ElementHasher> RescueExample<H> {
 
 impl<H: ElementHasher> Example for RescueExample<H>
 where
-    H: ElementHasher<BaseField = BaseElement> + Sync,
+    H: ElementHasher + Sync,
+    H::BaseField: RescueField,
 {
     fn prove(&self) -> Proof {
         // generate the execution trace
@@ -120,7 +150,7 @@ where
         let pub_inputs = PublicInputs { seed: self.seed, result: self.result };
         let acceptable_options =
             winterfell::AcceptableOptions::OptionSet(vec![proof.options().clone()]);
-        winterfell::verify::<RescueAir, H, DefaultRandomCoin<H>, MerkleTree<H>>(
+        winterfell::verify::<RescueAir<H::BaseField>, H, DefaultRandomCoin<H>, MerkleTree<H>>(
             proof,
             pub_inputs,
             &acceptable_options,
@@ -130,11 +160,11 @@ where
     fn verify_with_wrong_inputs(&self, proof: Proof) -> Result<(), VerifierError> {
         let pub_inputs = PublicInputs {
             seed: self.seed,
-            result: [self.result[0], self.result[1] + BaseElement::ONE],
+            result: [self.result[0], self.result[1] + H::BaseField::ONE],
         };
         let acceptable_options =
             winterfell::AcceptableOptions::OptionSet(vec![proof.options().clone()]);
-        winterfell::verify::<RescueAir, H, DefaultRandomCoin<H>, MerkleTree<H>>(
+        winterfell::verify::<RescueAir<H::BaseField>, H, DefaultRandomCoin<H>, MerkleTree<H>>(
             proof,
             pub_inputs,
             &acceptable_options,
@@ -144,9 +174,9 @@ where
 
 // HELPER FUNCTIONS
 // ================================================================================================
-fn compute_hash_chain(seed: [BaseElement; 2], length: usize) -> [BaseElement; 2] {
+fn compute_hash_chain<B: RescueField>(seed: [B; 2], length: usize) -> [B; 2] {
     let mut values = seed;
-    let mut result = [BaseElement::ZERO; 2];
+    let mut result = [B::ZERO; 2];
     for _ in 0..length {
         rescue::hash(values, &mut result);
         values.copy_from_slice(&result);
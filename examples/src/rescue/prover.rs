@@ -10,8 +10,8 @@ use winterfell::{
 };
 
 use super::{
-    rescue, BaseElement, DefaultRandomCoin, ElementHasher, FieldElement, PhantomData, ProofOptions,
-    Prover, PublicInputs, RescueAir, CYCLE_LENGTH, NUM_HASH_ROUNDS,
+    rescue, DefaultRandomCoin, ElementHasher, FieldElement, PhantomData, ProofOptions, Prover,
+    PublicInputs, RescueAir, RescueField, CYCLE_LENGTH, NUM_HASH_ROUNDS,
 };
 
 // RESCUE PROVER
@@ -22,16 +22,19 @@ pub struct RescueProver<H: ElementHasher> {
     _hasher: PhantomData<H>,
 }
 
-impl<H: ElementHasher> RescueProver<H> {
+impl<H: ElementHasher> RescueProver<H>
+where
+    H::BaseField: RescueField,
+{
     pub fn new(options: ProofOptions) -> Self {
         Self { options, _hasher: PhantomData }
     }
 
     pub fn build_trace(
         &self,
-        seed: [BaseElement; 2],
+        seed: [H::BaseField; 2],
         iterations: usize,
-    ) -> TraceTable<BaseElement> {
+    ) -> TraceTable<H::BaseField> {
         // allocate memory to hold the trace table
         let trace_length = iterations * CYCLE_LENGTH;
         let mut trace = TraceTable::new(4, trace_length);
@@ -41,8 +44,8 @@ impl<H: ElementHasher> RescueProver<H> {
                 // initialize first state of the computation
                 state[0] = seed[0];
                 state[1] = seed[1];
-                state[2] = BaseElement::ZERO;
-                state[3] = BaseElement::ZERO;
+                state[2] = H::BaseField::ZERO;
+                state[3] = H::BaseField::ZERO;
             },
             |step, state| {
                 // execute the transition function for all steps
@@ -53,8 +56,8 @@ impl<H: ElementHasher> RescueProver<H> {
                 if (step % CYCLE_LENGTH) < NUM_HASH_ROUNDS {
                     rescue::apply_round(state, step);
                 } else {
-                    state[2] = BaseElement::ZERO;
-                    state[3] = BaseElement::ZERO;
+                    state[2] = H::BaseField::ZERO;
+                    state[3] = H::BaseField::ZERO;
                 }
             },
         );
@@ -65,11 +68,12 @@ impl<H: ElementHasher> RescueProver<H> {
 
 impl<H: ElementHasher> Prover for RescueProver<H>
 where
-    H: ElementHasher<BaseField = BaseElement> + Sync,
+    H: ElementHasher + Sync,
+    H::BaseField: RescueField,
 {
-    type BaseField = BaseElement;
-    type Air = RescueAir;
-    type Trace = TraceTable<BaseElement>;
+    type BaseField = H::BaseField;
+    type Air = RescueAir<H::BaseField>;
+    type Trace = TraceTable<H::BaseField>;
     type HashFn = H;
     type VC = MerkleTree<H>;
     type RandomCoin = DefaultRandomCoin<Self::HashFn>;
@@ -80,7 +84,7 @@ where
     type ConstraintEvaluator<'a, E: FieldElement<BaseField = Self::BaseField>> =
         DefaultConstraintEvaluator<'a, Self::Air, E>;
 
-    fn get_pub_inputs(&self, trace: &Self::Trace) -> PublicInputs {
+    fn get_pub_inputs(&self, trace: &Self::Trace) -> PublicInputs<H::BaseField> {
         let last_step = trace.length() - 1;
         PublicInputs {
             seed: [trace.get(0, 0), trace.get(1, 0)],
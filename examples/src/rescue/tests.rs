@@ -3,9 +3,14 @@
 // This source code is licensed under the MIT license found in the
 // LICENSE file in the root directory of this source tree.
 
-use winterfell::{BatchingMethod, FieldExtension, ProofOptions};
+use winterfell::{
+    crypto::hashers::Rp62_248,
+    math::fields::{f128, f62},
+    BatchingMethod, FieldExtension, ProofOptions,
+};
 
-use super::Blake3_256;
+use super::rescue::{RescueField, STATE_WIDTH};
+use crate::Blake3_256;
 
 #[test]
 fn rescue_test_basic_proof_verification() {
@@ -25,6 +30,40 @@ fn rescue_test_basic_proof_verification_fail() {
     crate::tests::test_basic_proof_verification_fail(rescue_eg);
 }
 
+#[test]
+fn rescue_test_f62_proof_verification() {
+    let rescue_eg = Box::new(super::RescueExample::<Rp62_248>::new(128, build_options(true)));
+    crate::tests::test_basic_proof_verification(rescue_eg);
+}
+
+#[test]
+fn rescue_test_f62_proof_verification_fail() {
+    let rescue_eg = Box::new(super::RescueExample::<Rp62_248>::new(128, build_options(false)));
+    crate::tests::test_basic_proof_verification_fail(rescue_eg);
+}
+
+#[test]
+fn rescue_constants() {
+    check_constants::<f62::BaseElement>();
+    check_constants::<f128::BaseElement>();
+}
+
+fn check_constants<B: RescueField>() {
+    // inverse S-box is the inverse of x^3
+    let x = B::from(123456789u32);
+    assert_eq!(x, x.cube().exp(B::INV_ALPHA));
+
+    // inverse MDS matrix is the inverse of the MDS matrix
+    for i in 0..STATE_WIDTH {
+        for j in 0..STATE_WIDTH {
+            let value = (0..STATE_WIDTH).fold(B::ZERO, |acc, k| {
+                acc + B::MDS[i * STATE_WIDTH + k] * B::INV_MDS[k * STATE_WIDTH + j]
+            });
+            assert_eq!(if i == j { B::ONE } else { B::ZERO }, value);
+        }
+    }
+}
+
 fn build_options(use_extension_field: bool) -> ProofOptions {
     let extension = if use_extension_field {
         FieldExtension::Quadratic
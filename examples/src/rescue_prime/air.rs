@@ -0,0 +1,193 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use winterfell::{
+    crypto::hashers::Rp128_256, math::ToElements, Air, AirContext, Assertion, EvaluationFrame,
+    TraceInfo, TransitionConstraintDegree,
+};
+
+use super::{
+    init_state, BaseElement, FieldElement, ProofOptions, CYCLE_LENGTH, NUM_HASH_ROUNDS,
+    STATE_WIDTH, TRACE_WIDTH,
+};
+use crate::utils::{are_equal, not, EvaluationResult};
+
+// RESCUE PRIME AIR
+// ================================================================================================
+
+pub struct PublicInputs {
+    pub seed: [BaseElement; 2],
+    pub result: [BaseElement; 2],
+}
+
+impl ToElements<BaseElement> for PublicInputs {
+    fn to_elements(&self) -> Vec<BaseElement> {
+        let mut result = self.seed.to_vec();
+        result.extend_from_slice(&self.result);
+        result
+    }
+}
+
+/// AIR for a chain of Rp128_256 hashes.
+///
+/// Every hash in the chain takes up a cycle of 16 steps. On the first 11 steps of a cycle, a
+/// single round of the Rescue Prime permutation is applied to the state; on the remaining steps,
+/// the digest is copied into the rate portion of the state and the rest of the state is reset to
+/// the initial state of [Rp128_256::hash_elements()] for two elements.
+///
+/// Each row of the trace contains the hasher state `s` in the first 6 columns, and the output of
+/// the inverse S-box for the current round `w` in the last 6 columns. This way, a round can be
+/// described by the constraints w^3 = MDS * s^3 + ARK1 and s' = MDS * w + ARK2, both of which have
+/// degree at most 3.
+pub struct RescuePrimeAir {
+    context: AirContext<BaseElement>,
+    seed: [BaseElement; 2],
+    result: [BaseElement; 2],
+}
+
+impl Air for RescuePrimeAir {
+    type BaseField = BaseElement;
+    type PublicInputs = PublicInputs;
+
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+    fn new(trace_info: TraceInfo, pub_inputs: PublicInputs, options: ProofOptions) -> Self {
+        let mut degrees =
+            vec![TransitionConstraintDegree::with_cycles(3, vec![CYCLE_LENGTH]); STATE_WIDTH];
+        degrees.append(&mut vec![
+            TransitionConstraintDegree::with_cycles(1, vec![CYCLE_LENGTH]);
+            STATE_WIDTH
+        ]);
+        assert_eq!(TRACE_WIDTH, trace_info.width());
+        RescuePrimeAir {
+            context: AirContext::new(trace_info, degrees, STATE_WIDTH + 2, options),
+            seed: pub_inputs.seed,
+            result: pub_inputs.result,
+        }
+    }
+
+    fn context(&self) -> &AirContext<Self::BaseField> {
+        &self.context
+    }
+
+    fn evaluate_transition<E: FieldElement + From<Self::BaseField>>(
+        &self,
+        frame: &EvaluationFrame<E>,
+        periodic_values: &[E],
+        result: &mut [E],
+    ) {
+        let current = frame.current();
+        let next = frame.next();
+        debug_assert_eq!(TRACE_WIDTH, current.len());
+        debug_assert_eq!(TRACE_WIDTH, next.len());
+
+        let state = &current[..STATE_WIDTH];
+        let sbox_inv = &current[STATE_WIDTH..];
+        let next_state = &next[..STATE_WIDTH];
+
+        // split periodic values into hash_flag and Rescue Prime round constants; round constants
+        // are set to zeros on steps where hash_flag = 0
+        let hash_flag = periodic_values[0];
+        let ark1 = &periodic_values[1..STATE_WIDTH + 1];
+        let ark2 = &periodic_values[STATE_WIDTH + 1..];
+
+        // when hash_flag = 1, enforce w^3 = MDS * s^3 + ARK1; since ARK1 is zero when
+        // hash_flag = 0, this constraint places no restrictions on other steps
+        let mut step1 = [E::ZERO; STATE_WIDTH];
+        step1.copy_from_slice(state);
+        step1.iter_mut().for_each(|v| *v = v.cube());
+        apply_mds(&mut step1);
+        for i in 0..STATE_WIDTH {
+            result[i] = hash_flag * (sbox_inv[i].cube() - step1[i]) - ark1[i];
+        }
+
+        // when hash_flag = 1, enforce s' = MDS * w + ARK2
+        let mut step2 = [E::ZERO; STATE_WIDTH];
+        step2.copy_from_slice(sbox_inv);
+        apply_mds(&mut step2);
+        for i in 0..STATE_WIDTH {
+            result[STATE_WIDTH + i] = hash_flag * (next_state[i] - step2[i]) - ark2[i];
+        }
+
+        // when hash_flag = 0, enforce that the digest is copied to the next step, and that the
+        // rest of the state is reset
+        let copy_flag = not(hash_flag);
+        enforce_hash_copy(&mut result[STATE_WIDTH..], state, next_state, copy_flag);
+    }
+
+    fn get_assertions(&self) -> Vec<Assertion<Self::BaseField>> {
+        // assert the initial state and the ending values of the hash chain
+        let last_step = self.trace_length() - 1;
+        let mut assertions: Vec<_> = init_state(self.seed)
+            .into_iter()
+            .enumerate()
+            .map(|(i, value)| Assertion::single(i, 0, value))
+            .collect();
+        for (i, &value) in Rp128_256::DIGEST_RANGE.zip(self.result.iter()) {
+            assertions.push(Assertion::single(i, last_step, value));
+        }
+        assertions
+    }
+
+    fn get_periodic_column_values(&self) -> Vec<Vec<Self::BaseField>> {
+        let mut hash_flag = vec![BaseElement::ZERO; CYCLE_LENGTH];
+        hash_flag[..NUM_HASH_ROUNDS].fill(BaseElement::ONE);
+
+        let mut result = vec![hash_flag];
+        result.append(&mut get_round_constants(&Rp128_256::ARK1));
+        result.append(&mut get_round_constants(&Rp128_256::ARK2));
+        result
+    }
+}
+
+// HELPER FUNCTIONS
+// ------------------------------------------------------------------------------------------------
+
+/// when flag = 1, enforces that the next state of the computation is defined like so:
+/// - the digest elements are equal to the values from the previous step
+/// - the first capacity element is equal to 2 (the number of hashed elements)
+/// - the remaining elements are equal to 0
+fn enforce_hash_copy<E: FieldElement + From<BaseElement>>(
+    result: &mut [E],
+    current: &[E],
+    next: &[E],
+    flag: E,
+) {
+    let mut expected = [E::ZERO; STATE_WIDTH];
+    expected[Rp128_256::CAPACITY_RANGE.start] = E::from(2u32);
+    for i in Rp128_256::DIGEST_RANGE {
+        expected[i] = current[i];
+    }
+
+    for i in 0..STATE_WIDTH {
+        result.agg_constraint(i, flag, are_equal(next[i], expected[i]));
+    }
+}
+
+fn apply_mds<E: FieldElement + From<BaseElement>>(state: &mut [E; STATE_WIDTH]) {
+    let mut result = [E::ZERO; STATE_WIDTH];
+    for (r, mds_row) in result.iter_mut().zip(Rp128_256::MDS) {
+        for (&s, m) in state.iter().zip(mds_row) {
+            *r += E::from(m) * s;
+        }
+    }
+    *state = result;
+}
+
+/// Returns the provided round constants arranged in column-major form and padded with zeros to
+/// the cycle length.
+fn get_round_constants(
+    ark: &[[BaseElement; STATE_WIDTH]; NUM_HASH_ROUNDS],
+) -> Vec<Vec<BaseElement>> {
+    (0..STATE_WIDTH)
+        .map(|i| {
+            let mut column = vec![BaseElement::ZERO; CYCLE_LENGTH];
+            for (value, round) in column.iter_mut().zip(ark) {
+                *value = round[i];
+            }
+            column
+        })
+        .collect()
+}
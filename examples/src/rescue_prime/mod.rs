@@ -0,0 +1,172 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use core::marker::PhantomData;
+use std::time::Instant;
+
+use tracing::{field, info_span};
+use winterfell::{
+    crypto::{hashers::Rp128_256, DefaultRandomCoin, ElementHasher, MerkleTree},
+    math::{fields::f128::BaseElement, FieldElement},
+    Proof, ProofOptions, Prover, Trace, VerifierError,
+};
+
+use crate::{Blake3_192, Blake3_256, Example, ExampleOptions, HashFunction, Sha2_256, Sha3_256};
+
+mod air;
+use air::{PublicInputs, RescuePrimeAir};
+
+mod prover;
+use prover::RescuePrimeProver;
+
+#[cfg(test)]
+mod tests;
+
+// CONSTANTS
+// ================================================================================================
+
+const CYCLE_LENGTH: usize = 16;
+const NUM_HASH_ROUNDS: usize = Rp128_256::NUM_ROUNDS;
+const STATE_WIDTH: usize = Rp128_256::STATE_WIDTH;
+
+/// The trace holds the hasher state as well as the result of the inverse S-box for every round.
+const TRACE_WIDTH: usize = 2 * STATE_WIDTH;
+
+// RESCUE PRIME HASH CHAIN EXAMPLE
+// ================================================================================================
+
+pub fn get_example(
+    options: &ExampleOptions,
+    chain_length: usize,
+) -> Result<Box<dyn Example>, String> {
+    let (options, hash_fn) = options.to_proof_options(42, 4);
+
+    match hash_fn {
+        HashFunction::Blake3_192 => {
+            Ok(Box::new(RescuePrimeExample::<Blake3_192>::new(chain_length, options)))
+        },
+        HashFunction::Blake3_256 => {
+            Ok(Box::new(RescuePrimeExample::<Blake3_256>::new(chain_length, options)))
+        },
+        HashFunction::Sha3_256 => {
+            Ok(Box::new(RescuePrimeExample::<Sha3_256>::new(chain_length, options)))
+        },
+        HashFunction::Sha2_256 => {
+            Ok(Box::new(RescuePrimeExample::<Sha2_256>::new(chain_length, options)))
+        },
+        HashFunction::Rp128_256 => {
+            Ok(Box::new(RescuePrimeExample::<Rp128_256>::new(chain_length, options)))
+        },
+        _ => Err("The specified hash function cannot be used with this example.".to_string()),
+    }
+}
+
+pub struct RescuePrimeExample<H: ElementHasher> {
+    options: ProofOptions,
+    chain_length: usize,
+    seed: [BaseElement; 2],
+    result: [BaseElement; 2],
+    _hasher: PhantomData<H>,
+}
+
+impl<H: ElementHasher> RescuePrimeExample<H> {
+    pub fn new(chain_length: usize, options: ProofOptions) -> Self {
+        assert!(chain_length.is_power_of_two(), "chain length must a power of 2");
+        let seed = [BaseElement::from(42u8), BaseElement::from(43u8)];
+
+        // compute the sequence of hashes using Rp128_256 hash function
+        let now = Instant::now();
+        let result = compute_hash_chain(seed, chain_length);
+        println!(
+            "Computed a chain of {} Rescue Prime hashes in {} ms",
+            chain_length,
+            now.elapsed().as_millis(),
+        );
+
+        RescuePrimeExample {
+            options,
+            chain_length,
+            seed,
+            result,
+            _hasher: PhantomData,
+        }
+    }
+}
+
+// EXAMPLE IMPLEMENTATION
+// ================================================================================================
+
+impl<H: ElementHasher> Example for RescuePrimeExample<H>
+where
+    H: ElementHasher<BaseField = BaseElement> + Sync,
+{
+    fn prove(&self) -> Proof {
+        // generate the execution trace
+        println!(
+            "Generating proof for computing a chain of {} Rescue Prime hashes",
+            self.chain_length
+        );
+
+        // create a prover
+        let prover = RescuePrimeProver::<H>::new(self.options.clone());
+
+        // generate execution trace
+        let trace =
+            info_span!("generate_execution_trace", num_cols = TRACE_WIDTH, steps = field::Empty)
+                .in_scope(|| {
+                    let trace = prover.build_trace(self.seed, self.chain_length);
+                    tracing::Span::current().record("steps", trace.length());
+                    trace
+                });
+
+        // generate the proof
+        prover.prove(trace).unwrap()
+    }
+
+    fn verify(&self, proof: Proof) -> Result<(), VerifierError> {
+        let pub_inputs = PublicInputs { seed: self.seed, result: self.result };
+        let acceptable_options =
+            winterfell::AcceptableOptions::OptionSet(vec![proof.options().clone()]);
+        winterfell::verify::<RescuePrimeAir, H, DefaultRandomCoin<H>, MerkleTree<H>>(
+            proof,
+            pub_inputs,
+            &acceptable_options,
+        )
+    }
+
+    fn verify_with_wrong_inputs(&self, proof: Proof) -> Result<(), VerifierError> {
+        let pub_inputs = PublicInputs {
+            seed: self.seed,
+            result: [self.result[0], self.result[1] + BaseElement::ONE],
+        };
+        let acceptable_options =
+            winterfell::AcceptableOptions::OptionSet(vec![proof.options().clone()]);
+        winterfell::verify::<RescuePrimeAir, H, DefaultRandomCoin<H>, MerkleTree<H>>(
+            proof,
+            pub_inputs,
+            &acceptable_options,
+        )
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the initial hasher state for hashing the two provided elements with
+/// [Rp128_256::hash_elements()].
+fn init_state(values: [BaseElement; 2]) -> [BaseElement; STATE_WIDTH] {
+    let mut state = [BaseElement::ZERO; STATE_WIDTH];
+    state[Rp128_256::CAPACITY_RANGE.start] = BaseElement::new(2);
+    state[Rp128_256::DIGEST_RANGE].copy_from_slice(&values);
+    state
+}
+
+fn compute_hash_chain(seed: [BaseElement; 2], length: usize) -> [BaseElement; 2] {
+    let mut values = seed;
+    for _ in 0..length {
+        values = Rp128_256::hash_elements(&values).into();
+    }
+    values
+}
@@ -0,0 +1,167 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use winterfell::{
+    crypto::{hashers::Rp128_256, MerkleTree},
+    matrix::ColMatrix,
+    AuxRandElements, CompositionPoly, CompositionPolyTrace, ConstraintCompositionCoefficients,
+    DefaultConstraintCommitment, DefaultConstraintEvaluator, DefaultTraceLde, PartitionOptions,
+    StarkDomain, Trace, TraceInfo, TracePolyTable, TraceTable,
+};
+
+use super::{
+    init_state, BaseElement, DefaultRandomCoin, ElementHasher, FieldElement, PhantomData,
+    ProofOptions, Prover, PublicInputs, RescuePrimeAir, CYCLE_LENGTH, NUM_HASH_ROUNDS, STATE_WIDTH,
+    TRACE_WIDTH,
+};
+
+// RESCUE PRIME PROVER
+// ================================================================================================
+
+pub struct RescuePrimeProver<H: ElementHasher> {
+    options: ProofOptions,
+    _hasher: PhantomData<H>,
+}
+
+impl<H: ElementHasher> RescuePrimeProver<H> {
+    pub fn new(options: ProofOptions) -> Self {
+        Self { options, _hasher: PhantomData }
+    }
+
+    pub fn build_trace(
+        &self,
+        seed: [BaseElement; 2],
+        iterations: usize,
+    ) -> TraceTable<BaseElement> {
+        // allocate memory to hold the trace table
+        let trace_length = iterations * CYCLE_LENGTH;
+        let mut trace = TraceTable::new(TRACE_WIDTH, trace_length);
+
+        trace.fill(
+            |row| {
+                // initialize first state of the computation, and compute the output of the inverse
+                // S-box for the first round
+                row[..STATE_WIDTH].copy_from_slice(&init_state(seed));
+                fill_sbox_inv(row, 0);
+            },
+            |step, row| {
+                // execute the transition function for all steps
+                //
+                // for the first 11 steps in every cycle, compute a single round of Rescue Prime
+                // permutation; for the remaining 5 steps, carry over the digest to the next step
+                // and reset the rest of the state
+                let (state, sbox_inv) = row.split_at_mut(STATE_WIDTH);
+                let state: &mut [BaseElement; STATE_WIDTH] = state.try_into().unwrap();
+                if (step % CYCLE_LENGTH) < NUM_HASH_ROUNDS {
+                    Rp128_256::apply_round(state, step % CYCLE_LENGTH);
+                } else {
+                    let digest = state[Rp128_256::DIGEST_RANGE].try_into().unwrap();
+                    *state = init_state(digest);
+                }
+                sbox_inv.fill(BaseElement::ZERO);
+
+                fill_sbox_inv(row, (step + 1) % CYCLE_LENGTH);
+            },
+        );
+
+        trace
+    }
+}
+
+impl<H: ElementHasher> Prover for RescuePrimeProver<H>
+where
+    H: ElementHasher<BaseField = BaseElement> + Sync,
+{
+    type BaseField = BaseElement;
+    type Air = RescuePrimeAir;
+    type Trace = TraceTable<BaseElement>;
+    type HashFn = H;
+    type VC = MerkleTree<H>;
+    type RandomCoin = DefaultRandomCoin<Self::HashFn>;
+    type TraceLde<E: FieldElement<BaseField = Self::BaseField>> =
+        DefaultTraceLde<E, Self::HashFn, Self::VC>;
+    type ConstraintCommitment<E: FieldElement<BaseField = Self::BaseField>> =
+        DefaultConstraintCommitment<E, H, Self::VC>;
+    type ConstraintEvaluator<'a, E: FieldElement<BaseField = Self::BaseField>> =
+        DefaultConstraintEvaluator<'a, Self::Air, E>;
+
+    fn get_pub_inputs(&self, trace: &Self::Trace) -> PublicInputs {
+        let last_step = trace.length() - 1;
+        let digest_start = Rp128_256::DIGEST_RANGE.start;
+        PublicInputs {
+            seed: [trace.get(digest_start, 0), trace.get(digest_start + 1, 0)],
+            result: [trace.get(digest_start, last_step), trace.get(digest_start + 1, last_step)],
+        }
+    }
+
+    fn options(&self) -> &ProofOptions {
+        &self.options
+    }
+
+    fn new_trace_lde<E: FieldElement<BaseField = Self::BaseField>>(
+        &self,
+        trace_info: &TraceInfo,
+        main_trace: &ColMatrix<Self::BaseField>,
+        domain: &StarkDomain<Self::BaseField>,
+        partition_option: PartitionOptions,
+    ) -> (Self::TraceLde<E>, TracePolyTable<E>) {
+        DefaultTraceLde::new(trace_info, main_trace, domain, partition_option)
+    }
+
+    fn new_evaluator<'a, E: FieldElement<BaseField = Self::BaseField>>(
+        &self,
+        air: &'a Self::Air,
+        aux_rand_elements: Option<AuxRandElements<E>>,
+        composition_coefficients: ConstraintCompositionCoefficients<E>,
+    ) -> Self::ConstraintEvaluator<'a, E> {
+        DefaultConstraintEvaluator::new(air, aux_rand_elements, composition_coefficients)
+    }
+
+    fn build_constraint_commitment<E: FieldElement<BaseField = Self::BaseField>>(
+        &self,
+        composition_poly_trace: CompositionPolyTrace<E>,
+        num_constraint_composition_columns: usize,
+        domain: &StarkDomain<Self::BaseField>,
+        partition_options: PartitionOptions,
+    ) -> (Self::ConstraintCommitment<E>, CompositionPoly<E>) {
+        DefaultConstraintCommitment::new(
+            composition_poly_trace,
+            num_constraint_composition_columns,
+            domain,
+            partition_options,
+        )
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// If a Rescue Prime round is applied on the specified step of a cycle, fills the last columns of
+/// the row with the output of the inverse S-box for this round.
+fn fill_sbox_inv(row: &mut [BaseElement], cycle_step: usize) {
+    if cycle_step >= NUM_HASH_ROUNDS {
+        return;
+    }
+
+    // the output of the inverse S-box is the state after the first half of the round, and before
+    // the linear layer of the second half of the round
+    let (state, sbox_inv) = row.split_at_mut(STATE_WIDTH);
+    let mut next: [BaseElement; STATE_WIDTH] = state.try_into().unwrap();
+    Rp128_256::apply_round(&mut next, cycle_step);
+    for (i, value) in sbox_inv.iter_mut().enumerate() {
+        *value = next[i] - Rp128_256::ARK2[cycle_step][i];
+    }
+    apply_inv_mds(sbox_inv);
+}
+
+fn apply_inv_mds(state: &mut [BaseElement]) {
+    let mut result = [BaseElement::ZERO; STATE_WIDTH];
+    for (r, inv_mds_row) in result.iter_mut().zip(Rp128_256::INV_MDS) {
+        for (&s, m) in state.iter().zip(inv_mds_row) {
+            *r += m * s;
+        }
+    }
+    state.copy_from_slice(&result);
+}
@@ -107,7 +107,7 @@ impl Air for RescueRapsAir {
 
         // when hash_flag = 1, constraints for Rescue round are enforced (steps 0 to 14)
         // Enforcing the round for the first hash chain
-        rescue::enforce_round(
+        rescue::enforce_round::<BaseElement, E>(
             &mut result[..STATE_WIDTH],
             &current[..STATE_WIDTH],
             &next[..STATE_WIDTH],
@@ -116,7 +116,7 @@ impl Air for RescueRapsAir {
         );
 
         // Enforcing the round for the second hash chain
-        rescue::enforce_round(
+        rescue::enforce_round::<BaseElement, E>(
             &mut result[STATE_WIDTH..],
             &current[STATE_WIDTH..],
             &next[STATE_WIDTH..],
@@ -14,7 +14,7 @@ use winterfell::{
     Proof, ProofOptions, Prover, Trace, VerifierError,
 };
 
-use crate::{Blake3_192, Blake3_256, Example, ExampleOptions, HashFunction, Sha3_256};
+use crate::{Blake3_192, Blake3_256, Example, ExampleOptions, HashFunction, Sha2_256, Sha3_256};
 
 mod custom_trace_table;
 pub use custom_trace_table::RapTraceTable;
@@ -56,6 +56,9 @@ pub fn get_example(
         HashFunction::Sha3_256 => {
             Ok(Box::new(RescueRapsExample::<Sha3_256>::new(chain_length, options)))
         },
+        HashFunction::Sha2_256 => {
+            Ok(Box::new(RescueRapsExample::<Sha2_256>::new(chain_length, options)))
+        },
         _ => Err("The specified hash function cannot be used with this example.".to_string()),
     }
 }
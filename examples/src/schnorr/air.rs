@@ -0,0 +1,353 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use num_bigint::BigUint;
+use winterfell::{
+    math::{curves::ecgfp5, fields::f64::BaseElement, FieldElement, ToElements},
+    Air, AirContext, Assertion, EvaluationFrame, ProofOptions, TraceInfo,
+    TransitionConstraintDegree,
+};
+
+use super::{
+    challenge, offset_point, point_to_elements, scalar_bit, AffinePoint, CurveField,
+    NUM_SCALAR_BITS, SIG_CYCLE_LENGTH as SIG_CYCLE_LEN, TRACE_WIDTH,
+};
+use crate::utils::{are_equal, is_binary, EvaluationResult};
+
+// CONSTANTS
+// ================================================================================================
+
+/// Index of the column holding the bits of s.
+pub const S_BIT_COL: usize = 10;
+
+/// Index of the first column of the slope of the tangent at the accumulator point.
+pub const LAMBDA1_COL: usize = 11;
+
+/// Index of the first column of the slope of the line through the doubled accumulator point and
+/// the point added to it.
+pub const LAMBDA2_COL: usize = 16;
+
+/// Index of the first column holding coordinates of Z - P.
+pub const Z_MINUS_P_COL: usize = 21;
+
+/// Index of the first column holding coordinates of Z + G - P.
+pub const Z_PLUS_G_MINUS_P_COL: usize = 31;
+
+// SCHNORR SIGNATURE AIR
+// ================================================================================================
+
+#[derive(Clone)]
+pub struct PublicInputs {
+    pub pub_keys: Vec<AffinePoint>,
+    pub messages: Vec<[BaseElement; 4]>,
+    pub nonces: Vec<AffinePoint>,
+}
+
+impl ToElements<BaseElement> for PublicInputs {
+    fn to_elements(&self) -> Vec<BaseElement> {
+        let mut result = Vec::new();
+        for pub_key in self.pub_keys.iter() {
+            result.extend_from_slice(&point_to_elements(pub_key));
+        }
+        for message in self.messages.iter() {
+            result.extend_from_slice(message);
+        }
+        for nonce in self.nonces.iter() {
+            result.extend_from_slice(&point_to_elements(nonce));
+        }
+        result
+    }
+}
+
+/// AIR for verifying Schnorr signatures over the EcGFp5 curve.
+///
+/// Every signature is verified in a segment of [SIG_CYCLE_LEN] rows. The trace consists of the
+/// following columns, where each point coordinate occupies 5 columns:
+/// * 0..10: the accumulator point acc.
+/// * 10: bits of s, from the most significant one.
+/// * 11..16: λ1, the slope of the tangent at acc.
+/// * 16..21: λ2, the slope of the line through 2 * acc and the added point T.
+/// * 21..31: coordinates of Z - P, where Z is the offset point and P is the public key.
+/// * 31..41: coordinates of Z + G - P.
+///
+/// Bits of the challenge e are provided in a periodic column. At every step, the accumulator is
+/// updated as acc' = 2 * acc + T, where T is one of Z, Z + G, Z - P and Z + G - P selected by the
+/// current bits of s and e. The accumulator starts at Z, and thus after [NUM_SCALAR_BITS] steps
+/// it is equal to (2^512 - 1) * Z + s * G - e * P, which the verifier checks against
+/// (2^512 - 1) * Z + R.
+///
+/// Exceptional cases of the addition formulas (e.g., 2 * acc = T) are not handled; hitting them
+/// would require knowing the discrete logarithm of Z, and thus they occur with negligible
+/// probability.
+pub struct SchnorrAir {
+    context: AirContext<BaseElement>,
+    offset: [BaseElement; 10],
+    offset_plus_g: [BaseElement; 10],
+    challenges: Vec<BigUint>,
+    offset_minus_pub_keys: Vec<[BaseElement; 10]>,
+    offset_plus_g_minus_pub_keys: Vec<[BaseElement; 10]>,
+    results: Vec<[BaseElement; 10]>,
+}
+
+impl Air for SchnorrAir {
+    type BaseField = BaseElement;
+    type PublicInputs = PublicInputs;
+
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+    fn new(trace_info: TraceInfo, pub_inputs: PublicInputs, options: ProofOptions) -> Self {
+        let trace_len = trace_info.length();
+        let mut degrees = Vec::with_capacity(TRACE_WIDTH);
+        // tangent slope
+        degrees.extend(
+            (0..5).map(|_| TransitionConstraintDegree::with_cycles(2, vec![SIG_CYCLE_LEN])),
+        );
+        // addition slope
+        degrees.extend(
+            (0..5).map(|_| {
+                TransitionConstraintDegree::with_cycles(3, vec![SIG_CYCLE_LEN, trace_len])
+            }),
+        );
+        // next accumulator x coordinate
+        degrees.extend(
+            (0..5).map(|_| {
+                TransitionConstraintDegree::with_cycles(2, vec![SIG_CYCLE_LEN, trace_len])
+            }),
+        );
+        // next accumulator y coordinate
+        degrees.extend(
+            (0..5).map(|_| TransitionConstraintDegree::with_cycles(3, vec![SIG_CYCLE_LEN])),
+        );
+        // bits of s are binary
+        degrees.push(TransitionConstraintDegree::with_cycles(2, vec![SIG_CYCLE_LEN]));
+        // coordinates of Z - P and Z + G - P are copied over
+        degrees.extend(
+            (0..20).map(|_| TransitionConstraintDegree::with_cycles(1, vec![SIG_CYCLE_LEN])),
+        );
+        assert_eq!(TRACE_WIDTH, trace_info.width());
+
+        let offset = offset_point();
+        let offset_plus_g = offset + AffinePoint::GENERATOR;
+        // (2^512 - 1) * Z
+        let result_offset = offset.scalar_mul(&[u64::MAX; 8]);
+
+        let num_signatures = pub_inputs.pub_keys.len();
+        let mut challenges = Vec::with_capacity(num_signatures);
+        let mut offset_minus_pub_keys = Vec::with_capacity(num_signatures);
+        let mut offset_plus_g_minus_pub_keys = Vec::with_capacity(num_signatures);
+        let mut results = Vec::with_capacity(num_signatures);
+        for (i, &pub_key) in pub_inputs.pub_keys.iter().enumerate() {
+            let nonce = pub_inputs.nonces[i];
+            challenges.push(challenge(&nonce, &pub_key, &pub_inputs.messages[i]));
+            offset_minus_pub_keys.push(point_to_elements(&(offset - pub_key)));
+            offset_plus_g_minus_pub_keys.push(point_to_elements(&(offset_plus_g - pub_key)));
+            results.push(point_to_elements(&(result_offset + nonce)));
+        }
+
+        SchnorrAir {
+            context: AirContext::new(trace_info, degrees, 40, options),
+            offset: point_to_elements(&offset),
+            offset_plus_g: point_to_elements(&offset_plus_g),
+            challenges,
+            offset_minus_pub_keys,
+            offset_plus_g_minus_pub_keys,
+            results,
+        }
+    }
+
+    fn context(&self) -> &AirContext<Self::BaseField> {
+        &self.context
+    }
+
+    fn evaluate_transition<E: FieldElement + From<Self::BaseField>>(
+        &self,
+        frame: &EvaluationFrame<E>,
+        periodic_values: &[E],
+        result: &mut [E],
+    ) {
+        let current = frame.current();
+        let next = frame.next();
+        debug_assert_eq!(TRACE_WIDTH, current.len());
+        debug_assert_eq!(TRACE_WIDTH, next.len());
+
+        // transitions are enforced on all steps except for the last step of a signature cycle
+        let flag = periodic_values[0];
+        let e_bit = periodic_values[1];
+        let s_bit = current[S_BIT_COL];
+
+        let x = read(current, 0);
+        let y = read(current, 5);
+        let next_x = read(next, 0);
+        let next_y = read(next, 5);
+        let lambda1 = read(current, LAMBDA1_COL);
+        let lambda2 = read(current, LAMBDA2_COL);
+
+        // the added point T is selected by the current bits of s and e
+        let offset = self.offset.map(E::from);
+        let offset_plus_g = self.offset_plus_g.map(E::from);
+        let t_x = select(
+            s_bit,
+            e_bit,
+            [
+                read(&offset, 0),
+                read(&offset_plus_g, 0),
+                read(current, Z_MINUS_P_COL),
+                read(current, Z_PLUS_G_MINUS_P_COL),
+            ],
+        );
+        let t_y = select(
+            s_bit,
+            e_bit,
+            [
+                read(&offset, 5),
+                read(&offset_plus_g, 5),
+                read(current, Z_MINUS_P_COL + 5),
+                read(current, Z_PLUS_G_MINUS_P_COL + 5),
+            ],
+        );
+
+        let a = constant::<E>(ecgfp5::A);
+        let b = constant::<E>(ecgfp5::B);
+
+        // doubling: λ1 * 2 * y = 3 * x^2 + 2 * a * x + b
+        let x_sq = mul(x, x);
+        let tangent = add(add(add(double(x_sq), x_sq), double(mul(a, x))), b);
+        let doubled_x = sub(sub(mul(lambda1, lambda1), a), double(x));
+        let doubled_y = sub(mul(lambda1, sub(x, doubled_x)), y);
+        enforce_equal(result, 0, flag, mul(lambda1, double(y)), tangent);
+
+        // addition: λ2 * (x_T - x_d) = y_T - y_d
+        enforce_equal(result, 5, flag, mul(lambda2, sub(t_x, doubled_x)), sub(t_y, doubled_y));
+        let sum_x = sub(sub(sub(mul(lambda2, lambda2), a), doubled_x), t_x);
+        enforce_equal(result, 10, flag, next_x, sum_x);
+        let sum_y = sub(mul(lambda2, sub(doubled_x, next_x)), doubled_y);
+        enforce_equal(result, 15, flag, next_y, sum_y);
+
+        // bits of s must be binary
+        result.agg_constraint(20, flag, is_binary(s_bit));
+
+        // coordinates of Z - P and Z + G - P stay the same within a signature cycle
+        for i in 0..20 {
+            result.agg_constraint(
+                21 + i,
+                flag,
+                are_equal(current[Z_MINUS_P_COL + i], next[Z_MINUS_P_COL + i]),
+            );
+        }
+    }
+
+    fn get_assertions(&self) -> Vec<Assertion<Self::BaseField>> {
+        let last_cycle_step = SIG_CYCLE_LEN - 1;
+        let mut assertions = Vec::new();
+        for i in 0..10 {
+            // the accumulator starts at Z for every signature
+            assertions.push(Assertion::periodic(i, 0, SIG_CYCLE_LEN, self.offset[i]));
+            // coordinates of Z - P and Z + G - P match public keys
+            assertions.push(Assertion::sequence(
+                Z_MINUS_P_COL + i,
+                0,
+                SIG_CYCLE_LEN,
+                self.offset_minus_pub_keys.iter().map(|p| p[i]).collect(),
+            ));
+            assertions.push(Assertion::sequence(
+                Z_PLUS_G_MINUS_P_COL + i,
+                0,
+                SIG_CYCLE_LEN,
+                self.offset_plus_g_minus_pub_keys.iter().map(|p| p[i]).collect(),
+            ));
+            // the accumulator ends at (2^512 - 1) * Z + R for every signature
+            assertions.push(Assertion::sequence(
+                i,
+                last_cycle_step,
+                SIG_CYCLE_LEN,
+                self.results.iter().map(|p| p[i]).collect(),
+            ));
+        }
+        assertions
+    }
+
+    fn get_periodic_column_values(&self) -> Vec<Vec<Self::BaseField>> {
+        // signature cycle mask: 511 ones followed by a zero
+        let mut sig_cycle_mask = vec![BaseElement::ONE; SIG_CYCLE_LEN];
+        sig_cycle_mask[SIG_CYCLE_LEN - 1] = BaseElement::ZERO;
+
+        // bits of challenges for all signatures, from the most significant one
+        let mut e_bits = Vec::with_capacity(SIG_CYCLE_LEN * self.challenges.len());
+        for e in self.challenges.iter() {
+            e_bits.extend((0..NUM_SCALAR_BITS).map(|step| BaseElement::from(scalar_bit(e, step))));
+            e_bits.push(BaseElement::ZERO);
+        }
+
+        vec![sig_cycle_mask, e_bits]
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Selects one of the values [v_00, v_10, v_01, v_11] using binary flags s and e, i.e., returns
+/// v_00 + s * (v_10 - v_00) + e * (v_01 - v_00) + s * e * (v_11 - v_10 - v_01 + v_00).
+fn select<E: FieldElement>(s: E, e: E, values: [[E; 5]; 4]) -> [E; 5] {
+    let [v00, v10, v01, v11] = values;
+    core::array::from_fn(|i| {
+        v00[i]
+            + s * (v10[i] - v00[i])
+            + e * (v01[i] - v00[i])
+            + s * e * (v11[i] - v10[i] - v01[i] + v00[i])
+    })
+}
+
+/// Enforces that two elements of the extension field are equal.
+fn enforce_equal<E: FieldElement>(result: &mut [E], offset: usize, flag: E, a: [E; 5], b: [E; 5]) {
+    for i in 0..5 {
+        result.agg_constraint(offset + i, flag, are_equal(a[i], b[i]));
+    }
+}
+
+/// Reads an element of the extension field from 5 consecutive columns.
+fn read<E: FieldElement>(state: &[E], offset: usize) -> [E; 5] {
+    core::array::from_fn(|i| state[offset + i])
+}
+
+/// Converts an element of the extension field into its 5 coefficients.
+fn constant<E: FieldElement + From<BaseElement>>(value: CurveField) -> [E; 5] {
+    value.to_base_elements().map(E::from)
+}
+
+// EXTENSION FIELD ARITHMETIC
+// ================================================================================================
+// Elements of the field over which the curve is defined are represented by 5 coefficients over
+// the base field; the field is defined by the irreducible polynomial z^5 - 3.
+
+fn add<E: FieldElement>(a: [E; 5], b: [E; 5]) -> [E; 5] {
+    core::array::from_fn(|i| a[i] + b[i])
+}
+
+fn sub<E: FieldElement>(a: [E; 5], b: [E; 5]) -> [E; 5] {
+    core::array::from_fn(|i| a[i] - b[i])
+}
+
+fn double<E: FieldElement>(a: [E; 5]) -> [E; 5] {
+    a.map(|v| v.double())
+}
+
+fn mul<E: FieldElement>(a: [E; 5], b: [E; 5]) -> [E; 5] {
+    let mut product = [E::ZERO; 9];
+    for i in 0..5 {
+        for j in 0..5 {
+            product[i + j] += a[i] * b[j];
+        }
+    }
+
+    // reduce using z^5 = 3
+    core::array::from_fn(|i| {
+        if i < 4 {
+            let high = product[i + 5];
+            product[i] + high.double() + high
+        } else {
+            product[i]
+        }
+    })
+}
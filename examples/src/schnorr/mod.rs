@@ -0,0 +1,229 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use core::marker::PhantomData;
+use std::time::Instant;
+
+use num_bigint::BigUint;
+use tracing::{field, info_span};
+use winterfell::{
+    crypto::{DefaultRandomCoin, ElementHasher, MerkleTree},
+    math::{
+        curves::ecgfp5::{self, AffinePoint, BaseField as CurveField},
+        fields::f64::BaseElement,
+        FieldElement,
+    },
+    Proof, ProofOptions, Prover, Trace, VerifierError,
+};
+
+use crate::{Example, ExampleOptions, HashFunction};
+
+mod signature;
+use signature::{challenge, message_to_elements, point_to_elements, PrivateKey, Signature};
+
+mod air;
+use air::{PublicInputs, SchnorrAir};
+
+mod prover;
+use prover::SchnorrProver;
+
+#[cfg(test)]
+mod tests;
+
+// CONSTANTS AND TYPES
+// ================================================================================================
+
+const TRACE_WIDTH: usize = 41;
+
+/// Number of trace rows used to verify a single signature.
+const SIG_CYCLE_LENGTH: usize = 512;
+
+/// Number of scalar bits processed per signature; one bit is processed per transition.
+const NUM_SCALAR_BITS: usize = SIG_CYCLE_LENGTH - 1;
+
+type Blake3_192 = winterfell::crypto::hashers::Blake3_192<BaseElement>;
+type Blake3_256 = winterfell::crypto::hashers::Blake3_256<BaseElement>;
+type Sha2_256 = winterfell::crypto::hashers::Sha2_256<BaseElement>;
+type Sha3_256 = winterfell::crypto::hashers::Sha3_256<BaseElement>;
+type Rp64_256 = winterfell::crypto::hashers::Rp64_256;
+type RpJive64_256 = winterfell::crypto::hashers::RpJive64_256;
+
+// SCHNORR MULTI-MESSAGE, MULTI-KEY, SIGNATURE EXAMPLE
+// ================================================================================================
+
+pub fn get_example(
+    options: &ExampleOptions,
+    num_signatures: usize,
+) -> Result<Box<dyn Example>, String> {
+    let (options, hash_fn) = options.to_proof_options(28, 8);
+
+    match hash_fn {
+        HashFunction::Blake3_192 => {
+            Ok(Box::new(SchnorrExample::<Blake3_192>::new(num_signatures, options)))
+        },
+        HashFunction::Blake3_256 => {
+            Ok(Box::new(SchnorrExample::<Blake3_256>::new(num_signatures, options)))
+        },
+        HashFunction::Sha3_256 => {
+            Ok(Box::new(SchnorrExample::<Sha3_256>::new(num_signatures, options)))
+        },
+        HashFunction::Sha2_256 => {
+            Ok(Box::new(SchnorrExample::<Sha2_256>::new(num_signatures, options)))
+        },
+        HashFunction::Rp64_256 => {
+            Ok(Box::new(SchnorrExample::<Rp64_256>::new(num_signatures, options)))
+        },
+        HashFunction::RpJive64_256 => {
+            Ok(Box::new(SchnorrExample::<RpJive64_256>::new(num_signatures, options)))
+        },
+        _ => Err("The specified hash function cannot be used with this example.".to_string()),
+    }
+}
+
+/// Verifies a set of Schnorr signatures over the EcGFp5 curve.
+///
+/// For each signature (R, s) of message m under public key P, the challenge e = H(R, P, m) is
+/// computed by the verifier, and the proof attests to the knowledge of s such that
+/// s * G - e * P = R. Thus, the public keys, the messages, and the R components of signatures are
+/// public inputs, while the s components are not revealed.
+pub struct SchnorrExample<H: ElementHasher> {
+    options: ProofOptions,
+    pub_keys: Vec<AffinePoint>,
+    messages: Vec<[BaseElement; 4]>,
+    signatures: Vec<Signature>,
+    _hasher: PhantomData<H>,
+}
+
+impl<H: ElementHasher> SchnorrExample<H> {
+    pub fn new(num_signatures: usize, options: ProofOptions) -> Self {
+        assert!(
+            num_signatures.is_power_of_two() && num_signatures > 1,
+            "number of signatures must be a power of 2 greater than 1"
+        );
+        // generate private/public key pairs for the specified number of signatures
+        let now = Instant::now();
+        let private_keys: Vec<PrivateKey> =
+            (0..num_signatures).map(|i| PrivateKey::from_seed([i as u8; 32])).collect();
+        println!(
+            "Generated {} private-public key pairs in {} ms",
+            num_signatures,
+            now.elapsed().as_millis()
+        );
+
+        // sign messages
+        let now = Instant::now();
+        let mut signatures = Vec::new();
+        let mut messages = Vec::new();
+        for (i, private_key) in private_keys.iter().enumerate() {
+            let msg = format!("test message {i}");
+            signatures.push(private_key.sign(msg.as_bytes()));
+            messages.push(message_to_elements(msg.as_bytes()));
+        }
+        println!("Signed {} messages in {} ms", num_signatures, now.elapsed().as_millis());
+
+        // verify signatures
+        let now = Instant::now();
+        let mut pub_keys = Vec::new();
+        for (i, signature) in signatures.iter().enumerate() {
+            let pk = private_keys[i].pub_key();
+            pub_keys.push(pk.to_point());
+            let msg = format!("test message {i}");
+            assert!(pk.verify(msg.as_bytes(), signature));
+        }
+        println!("Verified {} signatures in {} ms", num_signatures, now.elapsed().as_millis());
+
+        SchnorrExample {
+            options,
+            pub_keys,
+            messages,
+            signatures,
+            _hasher: PhantomData,
+        }
+    }
+
+    fn pub_inputs(&self) -> PublicInputs {
+        PublicInputs {
+            pub_keys: self.pub_keys.clone(),
+            messages: self.messages.clone(),
+            nonces: self.signatures.iter().map(|sig| sig.r).collect(),
+        }
+    }
+}
+
+// EXAMPLE IMPLEMENTATION
+// ================================================================================================
+
+impl<H: ElementHasher> Example for SchnorrExample<H>
+where
+    H: ElementHasher<BaseField = BaseElement> + Sync,
+{
+    fn prove(&self) -> Proof {
+        println!("Generating proof for verifying {} Schnorr signatures", self.signatures.len());
+
+        // create a prover
+        let prover = SchnorrProver::<H>::new(self.pub_inputs(), self.options.clone());
+
+        // generate execution trace
+        let trace =
+            info_span!("generate_execution_trace", num_cols = TRACE_WIDTH, steps = field::Empty)
+                .in_scope(|| {
+                    let trace = prover.build_trace(&self.signatures);
+                    tracing::Span::current().record("steps", trace.length());
+                    trace
+                });
+
+        // generate the proof
+        prover.prove(trace).unwrap()
+    }
+
+    fn verify(&self, proof: Proof) -> Result<(), VerifierError> {
+        let acceptable_options =
+            winterfell::AcceptableOptions::OptionSet(vec![proof.options().clone()]);
+        winterfell::verify::<SchnorrAir, H, DefaultRandomCoin<H>, MerkleTree<H>>(
+            proof,
+            self.pub_inputs(),
+            &acceptable_options,
+        )
+    }
+
+    fn verify_with_wrong_inputs(&self, proof: Proof) -> Result<(), VerifierError> {
+        let mut pub_inputs = self.pub_inputs();
+        pub_inputs.messages[0][0] += BaseElement::ONE;
+        let acceptable_options =
+            winterfell::AcceptableOptions::OptionSet(vec![proof.options().clone()]);
+        winterfell::verify::<SchnorrAir, H, DefaultRandomCoin<H>, MerkleTree<H>>(
+            proof,
+            pub_inputs,
+            &acceptable_options,
+        )
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the offset point Z, which is the point with x coordinate equal to φ (the root of the
+/// polynomial defining the extension field).
+///
+/// Scalar multiplication in the trace starts from Z and adds Z at every step, which keeps the
+/// accumulator away from the point at infinity. The discrete logarithm of Z with respect to the
+/// generator and public keys is unknown, and thus a prover cannot steer the computation into the
+/// exceptional cases of the addition formulas (i.e., adding a point to itself or to its negation).
+fn offset_point() -> AffinePoint {
+    let x = CurveField::new(
+        BaseElement::ZERO,
+        BaseElement::ONE,
+        BaseElement::ZERO,
+        BaseElement::ZERO,
+        BaseElement::ZERO,
+    );
+    AffinePoint::from_x(x).expect("offset point must exist")
+}
+
+/// Returns the bit of the scalar processed at the specified step; bits are processed from the most
+/// significant one, and scalars are padded to [NUM_SCALAR_BITS] bits.
+fn scalar_bit(scalar: &BigUint, step: usize) -> bool {
+    scalar.bit((NUM_SCALAR_BITS - 1 - step) as u64)
+}
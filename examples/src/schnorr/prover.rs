@@ -0,0 +1,201 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use num_bigint::BigUint;
+#[cfg(feature = "concurrent")]
+use winterfell::iterators::*;
+use winterfell::{
+    crypto::MerkleTree, matrix::ColMatrix, AuxRandElements, CompositionPoly, CompositionPolyTrace,
+    ConstraintCompositionCoefficients, DefaultConstraintCommitment, DefaultConstraintEvaluator,
+    DefaultTraceLde, PartitionOptions, StarkDomain, TraceInfo, TracePolyTable, TraceTable,
+};
+
+use super::{
+    air::{LAMBDA1_COL, LAMBDA2_COL, S_BIT_COL, Z_MINUS_P_COL, Z_PLUS_G_MINUS_P_COL},
+    challenge, ecgfp5, offset_point, point_to_elements, scalar_bit, AffinePoint, BaseElement,
+    CurveField, DefaultRandomCoin, ElementHasher, FieldElement, PhantomData, ProofOptions, Prover,
+    PublicInputs, SchnorrAir, Signature, NUM_SCALAR_BITS, SIG_CYCLE_LENGTH, TRACE_WIDTH,
+};
+
+// TYPES AND INTERFACES
+// ================================================================================================
+
+struct SignatureInfo {
+    s: BigUint,
+    e: BigUint,
+    /// Points added to the accumulator, indexed by 2 * e_bit + s_bit: Z, Z + G, Z - P, Z + G - P.
+    addends: [AffinePoint; 4],
+}
+
+// SCHNORR PROVER
+// ================================================================================================
+
+pub struct SchnorrProver<H: ElementHasher> {
+    pub_inputs: PublicInputs,
+    options: ProofOptions,
+    _hasher: PhantomData<H>,
+}
+
+impl<H: ElementHasher> SchnorrProver<H> {
+    pub fn new(pub_inputs: PublicInputs, options: ProofOptions) -> Self {
+        Self {
+            pub_inputs,
+            options,
+            _hasher: PhantomData,
+        }
+    }
+
+    /// Builds an execution trace verifying the specified signatures.
+    ///
+    /// Every signature is verified in a segment of [SIG_CYCLE_LENGTH] rows, which computes
+    /// s * G - e * P using double-and-add with bits processed from the most significant one; see
+    /// [SchnorrAir] for the layout of the trace.
+    pub fn build_trace(&self, signatures: &[Signature]) -> TraceTable<BaseElement> {
+        let trace_length = SIG_CYCLE_LENGTH * signatures.len();
+        let mut trace = TraceTable::new(TRACE_WIDTH, trace_length);
+
+        let offset = offset_point();
+        let offset_plus_g = offset + AffinePoint::GENERATOR;
+        let sig_infos: Vec<SignatureInfo> = signatures
+            .iter()
+            .enumerate()
+            .map(|(i, sig)| {
+                let pub_key = self.pub_inputs.pub_keys[i];
+                let e = challenge(&sig.r, &pub_key, &self.pub_inputs.messages[i]);
+                SignatureInfo {
+                    s: sig.s.clone(),
+                    e,
+                    addends: [offset, offset_plus_g, offset - pub_key, offset_plus_g - pub_key],
+                }
+            })
+            .collect();
+
+        trace.fragments(SIG_CYCLE_LENGTH).for_each(|mut sig_trace| {
+            let sig_info = &sig_infos[sig_trace.index()];
+            sig_trace.fill(
+                |state| {
+                    state[..10].copy_from_slice(&point_to_elements(&offset));
+                    state[Z_MINUS_P_COL..Z_MINUS_P_COL + 10]
+                        .copy_from_slice(&point_to_elements(&sig_info.addends[2]));
+                    state[Z_PLUS_G_MINUS_P_COL..Z_PLUS_G_MINUS_P_COL + 10]
+                        .copy_from_slice(&point_to_elements(&sig_info.addends[3]));
+                    fill_step(0, sig_info, state);
+                },
+                |step, state| {
+                    let acc = read_point(state);
+                    let addend = sig_info.addends[addend_index(sig_info, step)];
+                    state[..10].copy_from_slice(&point_to_elements(&(acc.double() + addend)));
+                    fill_step(step + 1, sig_info, state);
+                },
+            );
+        });
+
+        trace
+    }
+}
+
+impl<H: ElementHasher> Prover for SchnorrProver<H>
+where
+    H: ElementHasher<BaseField = BaseElement> + Sync,
+{
+    type BaseField = BaseElement;
+    type Air = SchnorrAir;
+    type Trace = TraceTable<BaseElement>;
+    type HashFn = H;
+    type VC = MerkleTree<H>;
+    type RandomCoin = DefaultRandomCoin<Self::HashFn>;
+    type TraceLde<E: FieldElement<BaseField = Self::BaseField>> =
+        DefaultTraceLde<E, Self::HashFn, Self::VC>;
+    type ConstraintCommitment<E: FieldElement<BaseField = Self::BaseField>> =
+        DefaultConstraintCommitment<E, H, Self::VC>;
+    type ConstraintEvaluator<'a, E: FieldElement<BaseField = Self::BaseField>> =
+        DefaultConstraintEvaluator<'a, Self::Air, E>;
+
+    fn get_pub_inputs(&self, _trace: &Self::Trace) -> PublicInputs {
+        self.pub_inputs.clone()
+    }
+
+    fn options(&self) -> &ProofOptions {
+        &self.options
+    }
+
+    fn new_trace_lde<E: FieldElement<BaseField = Self::BaseField>>(
+        &self,
+        trace_info: &TraceInfo,
+        main_trace: &ColMatrix<Self::BaseField>,
+        domain: &StarkDomain<Self::BaseField>,
+        partition_option: PartitionOptions,
+    ) -> (Self::TraceLde<E>, TracePolyTable<E>) {
+        DefaultTraceLde::new(trace_info, main_trace, domain, partition_option)
+    }
+
+    fn new_evaluator<'a, E: FieldElement<BaseField = Self::BaseField>>(
+        &self,
+        air: &'a Self::Air,
+        aux_rand_elements: Option<AuxRandElements<E>>,
+        composition_coefficients: ConstraintCompositionCoefficients<E>,
+    ) -> Self::ConstraintEvaluator<'a, E> {
+        DefaultConstraintEvaluator::new(air, aux_rand_elements, composition_coefficients)
+    }
+
+    fn build_constraint_commitment<E: FieldElement<BaseField = Self::BaseField>>(
+        &self,
+        composition_poly_trace: CompositionPolyTrace<E>,
+        num_constraint_composition_columns: usize,
+        domain: &StarkDomain<Self::BaseField>,
+        partition_options: PartitionOptions,
+    ) -> (Self::ConstraintCommitment<E>, CompositionPoly<E>) {
+        DefaultConstraintCommitment::new(
+            composition_poly_trace,
+            num_constraint_composition_columns,
+            domain,
+            partition_options,
+        )
+    }
+}
+
+// TRANSITION FUNCTION
+// ================================================================================================
+
+/// Sets the bit of s and the slopes used at the specified step; the accumulator must already be
+/// set. On the last step of a segment no point operations are performed, and thus these columns
+/// are set to zeros.
+fn fill_step(step: usize, sig_info: &SignatureInfo, state: &mut [BaseElement]) {
+    if step == NUM_SCALAR_BITS {
+        state[S_BIT_COL] = BaseElement::ZERO;
+        state[LAMBDA1_COL..LAMBDA1_COL + 5].fill(BaseElement::ZERO);
+        state[LAMBDA2_COL..LAMBDA2_COL + 5].fill(BaseElement::ZERO);
+        return;
+    }
+
+    let acc = read_point(state);
+    let doubled = acc.double();
+    let addend = sig_info.addends[addend_index(sig_info, step)];
+
+    // slope of the tangent at the accumulator, and slope of the line through 2 * acc and addend
+    let x_sq = acc.x().square();
+    let tangent = x_sq.double() + x_sq + (ecgfp5::A * acc.x()).double() + ecgfp5::B;
+    let lambda1 = tangent / acc.y().double();
+    let lambda2 = (addend.y() - doubled.y()) / (addend.x() - doubled.x());
+
+    state[S_BIT_COL] = BaseElement::from(scalar_bit(&sig_info.s, step));
+    state[LAMBDA1_COL..LAMBDA1_COL + 5].copy_from_slice(&lambda1.to_base_elements());
+    state[LAMBDA2_COL..LAMBDA2_COL + 5].copy_from_slice(&lambda2.to_base_elements());
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the index of the point added to the accumulator at the specified step.
+fn addend_index(sig_info: &SignatureInfo, step: usize) -> usize {
+    scalar_bit(&sig_info.s, step) as usize + 2 * scalar_bit(&sig_info.e, step) as usize
+}
+
+/// Reads the accumulator point from the first 10 columns of the state.
+fn read_point(state: &[BaseElement]) -> AffinePoint {
+    let x = CurveField::new(state[0], state[1], state[2], state[3], state[4]);
+    let y = CurveField::new(state[5], state[6], state[7], state[8], state[9]);
+    AffinePoint::new(x, y).expect("accumulator must be on the curve")
+}
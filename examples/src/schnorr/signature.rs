@@ -0,0 +1,132 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use num_bigint::BigUint;
+use rand_utils::prng_vector;
+use winterfell::{
+    crypto::{hashers::Rp64_256, Digest, ElementHasher, Hasher},
+    math::{
+        curves::ecgfp5::{AffinePoint, ORDER},
+        fields::f64::BaseElement,
+        FieldElement,
+    },
+};
+
+// TYPES AND INTERFACES
+// ================================================================================================
+
+pub struct PrivateKey {
+    secret: BigUint,
+    pub_key: PublicKey,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PublicKey(AffinePoint);
+
+/// A Schnorr signature (R, s) such that s * G = R + e * P, where e is the challenge computed from
+/// R, the public key P, and the message.
+pub struct Signature {
+    pub r: AffinePoint,
+    pub s: BigUint,
+}
+
+// PRIVATE KEY IMPLEMENTATION
+// ================================================================================================
+
+impl PrivateKey {
+    /// Returns a private key generated from the specified `seed`.
+    pub fn from_seed(seed: [u8; 32]) -> Self {
+        let secret = to_scalar(&prng_vector::<u64>(seed, 5));
+        let pub_key = PublicKey(AffinePoint::GENERATOR.scalar_mul(&secret.to_u64_digits()));
+        PrivateKey { secret, pub_key }
+    }
+
+    /// Returns a public key corresponding to this private key.
+    pub fn pub_key(&self) -> PublicKey {
+        self.pub_key
+    }
+
+    /// Signs the specified `message` with this private key.
+    ///
+    /// The nonce is derived deterministically from the private key and the message.
+    pub fn sign(&self, message: &[u8]) -> Signature {
+        let mut nonce_seed = self.secret.to_bytes_le();
+        nonce_seed.extend_from_slice(message);
+        let nonce_seed = Rp64_256::hash(&nonce_seed).as_bytes();
+        let k = to_scalar(&prng_vector::<u64>(nonce_seed, 5));
+
+        let r = AffinePoint::GENERATOR.scalar_mul(&k.to_u64_digits());
+        let e = challenge(&r, &self.pub_key.0, &message_to_elements(message));
+        let s = (k + e * &self.secret) % order();
+        Signature { r, s }
+    }
+}
+
+// PUBLIC KEY IMPLEMENTATION
+// ================================================================================================
+
+impl PublicKey {
+    /// Returns true if the specified signature was signed by this public key for the specified
+    /// message.
+    pub fn verify(&self, message: &[u8], sig: &Signature) -> bool {
+        let e = challenge(&sig.r, &self.0, &message_to_elements(message));
+        let lhs = AffinePoint::GENERATOR.scalar_mul(&sig.s.to_u64_digits());
+        let rhs = sig.r + self.0.scalar_mul(&e.to_u64_digits());
+        lhs == rhs
+    }
+
+    /// Returns the point on the curve corresponding to this public key.
+    pub fn to_point(self) -> AffinePoint {
+        self.0
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Hashes the message into 4 field elements.
+pub fn message_to_elements(message: &[u8]) -> [BaseElement; 4] {
+    let digest = Rp64_256::hash(message);
+    digest.as_elements().try_into().expect("digest must consist of 4 elements")
+}
+
+/// Computes the challenge e = H(R, P, m), where H is Rescue Prime hash function.
+///
+/// The challenge is the 256-bit integer formed by the 4 elements of the digest; since the order of
+/// the curve subgroup is greater than 2^256, this integer is already a valid scalar.
+pub fn challenge(r: &AffinePoint, pub_key: &AffinePoint, message: &[BaseElement; 4]) -> BigUint {
+    let mut elements = Vec::with_capacity(24);
+    elements.extend_from_slice(&point_to_elements(r));
+    elements.extend_from_slice(&point_to_elements(pub_key));
+    elements.extend_from_slice(message);
+
+    let digest = Rp64_256::hash_elements(&elements);
+    let limbs: Vec<u64> = digest.as_elements().iter().map(|e| e.as_int()).collect();
+    to_scalar(&limbs)
+}
+
+/// Returns the coordinates of the point as 10 field elements.
+pub fn point_to_elements(point: &AffinePoint) -> [BaseElement; 10] {
+    let mut result = [BaseElement::ZERO; 10];
+    result[..5].copy_from_slice(&point.x().to_base_elements());
+    result[5..].copy_from_slice(&point.y().to_base_elements());
+    result
+}
+
+/// Returns the order of the curve subgroup.
+fn order() -> BigUint {
+    from_limbs(&ORDER)
+}
+
+/// Converts little-endian 64-bit limbs into an integer reduced modulo the order of the curve
+/// subgroup.
+fn to_scalar(limbs: &[u64]) -> BigUint {
+    from_limbs(limbs) % order()
+}
+
+/// Converts little-endian 64-bit limbs into an integer.
+fn from_limbs(limbs: &[u64]) -> BigUint {
+    limbs.iter().rev().fold(BigUint::ZERO, |acc, &limb| (acc << 64u32) + limb)
+}
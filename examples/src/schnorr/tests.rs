@@ -0,0 +1,44 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use winterfell::{BatchingMethod, FieldExtension, ProofOptions};
+
+use super::{Rp64_256, SchnorrExample};
+
+#[test]
+fn schnorr_test_basic_proof_verification() {
+    let schnorr = Box::new(SchnorrExample::<Rp64_256>::new(2, build_options(false)));
+    crate::tests::test_basic_proof_verification(schnorr);
+}
+
+#[test]
+fn schnorr_test_basic_proof_verification_extension() {
+    let schnorr = Box::new(SchnorrExample::<Rp64_256>::new(2, build_options(true)));
+    crate::tests::test_basic_proof_verification(schnorr);
+}
+
+#[test]
+fn schnorr_test_basic_proof_verification_fail() {
+    let schnorr = Box::new(SchnorrExample::<Rp64_256>::new(2, build_options(false)));
+    crate::tests::test_basic_proof_verification_fail(schnorr);
+}
+
+fn build_options(use_extension_field: bool) -> ProofOptions {
+    let extension = if use_extension_field {
+        FieldExtension::Quadratic
+    } else {
+        FieldExtension::None
+    };
+    ProofOptions::new(
+        28,
+        8,
+        0,
+        extension,
+        4,
+        31,
+        BatchingMethod::Algebraic,
+        BatchingMethod::Algebraic,
+    )
+}
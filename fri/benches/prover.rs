@@ -7,36 +7,41 @@ use std::time::Duration;
 
 use criterion::{criterion_group, criterion_main, BatchSize, BenchmarkId, Criterion};
 use crypto::{hashers::Blake3_256, DefaultRandomCoin, MerkleTree};
-use math::{fft, fields::f128::BaseElement, FieldElement};
+use math::{
+    fft,
+    fields::{f128, f62, f64},
+    StarkField,
+};
 use rand_utils::rand_vector;
 use winter_fri::{DefaultProverChannel, FriOptions, FriProver};
 
 static BATCH_SIZES: [usize; 3] = [65536, 131072, 262144];
 static BLOWUP_FACTOR: usize = 8;
 
-pub fn build_layers(c: &mut Criterion) {
-    let mut fri_group = c.benchmark_group("FRI prover");
+fn build_layers<B: StarkField>(c: &mut Criterion, field_name: &str) {
+    let mut fri_group = c.benchmark_group(format!("{field_name}/FRI prover"));
     fri_group.sample_size(10);
     fri_group.measurement_time(Duration::from_secs(10));
 
     let options = FriOptions::new(BLOWUP_FACTOR, 4, 255);
 
     for &domain_size in &BATCH_SIZES {
-        let evaluations = build_evaluations(domain_size);
+        let evaluations = build_evaluations::<B>(domain_size);
 
         fri_group.bench_with_input(
             BenchmarkId::new("build_layers", domain_size),
             &evaluations,
             |b, e| {
                 let mut prover =
-                    FriProver::<_, _, _, MerkleTree<Blake3_256<BaseElement>>>::new(options.clone());
+                    FriProver::<_, _, _, MerkleTree<Blake3_256<B>>>::new(options.clone());
                 b.iter_batched(
                     || e.clone(),
                     |evaluations| {
                         let mut channel = DefaultProverChannel::<
-                            BaseElement,
-                            Blake3_256<BaseElement>,
-                            DefaultRandomCoin<Blake3_256<BaseElement>>,
+                            B,
+                            Blake3_256<B>,
+                            DefaultRandomCoin<Blake3_256<B>>,
+                            MerkleTree<Blake3_256<B>>,
                         >::new(domain_size, 32);
                         prover.build_layers(&mut channel, evaluations);
                         prover.reset();
@@ -48,16 +53,22 @@ pub fn build_layers(c: &mut Criterion) {
     }
 }
 
-criterion_group!(fri_prover_group, build_layers);
+fn bench_build_layers(c: &mut Criterion) {
+    build_layers::<f62::BaseElement>(c, "f62");
+    build_layers::<f64::BaseElement>(c, "f64");
+    build_layers::<f128::BaseElement>(c, "f128");
+}
+
+criterion_group!(fri_prover_group, bench_build_layers);
 criterion_main!(fri_prover_group);
 
 // HELPER FUNCTIONS
 // ================================================================================================
 
-fn build_evaluations(domain_size: usize) -> Vec<BaseElement> {
-    let mut p: Vec<BaseElement> = rand_vector(domain_size / BLOWUP_FACTOR);
-    p.resize(domain_size, BaseElement::ZERO);
-    let twiddles = fft::get_twiddles::<BaseElement>(domain_size);
+fn build_evaluations<B: StarkField>(domain_size: usize) -> Vec<B> {
+    let mut p: Vec<B> = rand_vector(domain_size / BLOWUP_FACTOR);
+    p.resize(domain_size, B::ZERO);
+    let twiddles = fft::get_twiddles::<B>(domain_size);
     fft::evaluate_poly(&mut p, &twiddles);
     p
 }
@@ -35,6 +35,8 @@ pub enum VerifierError {
     /// Polynomial degree at one of the FRI layers could not be divided evenly by the folding
     /// factor.
     DegreeTruncation(usize, usize, usize),
+    /// Domain size of one of the batched inputs does not match any of the folded FRI domains.
+    InvalidInputDomainSize(usize),
 }
 
 impl fmt::Display for VerifierError {
@@ -71,6 +73,9 @@ impl fmt::Display for VerifierError {
             Self::DegreeTruncation(degree, folding, layer) => {
                 write!(f, "degree reduction from {degree} by {folding} at layer {layer} results in degree truncation")
             }
+            Self::InvalidInputDomainSize(size) => {
+                write!(f, "batched input domain size {size} does not match any of the folded FRI domains")
+            }
         }
     }
 }
@@ -91,4 +91,24 @@ impl FriOptions {
         }
         result
     }
+
+    /// Returns the number of FRI layers after which a domain of the specified `domain_size` is
+    /// folded into a domain of `folded_domain_size`, or `None` if no such layer exists.
+    ///
+    /// A domain is folded into itself after 0 layers, and into the remainder domain after
+    /// [num_fri_layers()](FriOptions::num_fri_layers) layers.
+    pub fn get_folding_depth(
+        &self,
+        domain_size: usize,
+        folded_domain_size: usize,
+    ) -> Option<usize> {
+        let mut size = domain_size;
+        for depth in 0..=self.num_fri_layers(domain_size) {
+            if size == folded_domain_size {
+                return Some(depth);
+            }
+            size /= self.folding_factor;
+        }
+        None
+    }
 }
@@ -6,7 +6,7 @@
 use alloc::vec::Vec;
 use core::marker::PhantomData;
 
-use crypto::{ElementHasher, Hasher, RandomCoin};
+use crypto::{ElementHasher, Hasher, RandomCoin, VectorCommitment};
 use math::FieldElement;
 
 // PROVER CHANNEL TRAIT
@@ -24,6 +24,8 @@ use math::FieldElement;
 pub trait ProverChannel<E: FieldElement> {
     /// Hash function used by the prover to commit to polynomial evaluations.
     type Hasher: ElementHasher<BaseField = E::BaseField>;
+    /// Vector commitment used to commit to polynomial evaluations.
+    type VectorCommitment: VectorCommitment<Self::Hasher>;
 
     /// Sends a layer commitment to the verifier.
     ///
@@ -34,7 +36,15 @@ pub trait ProverChannel<E: FieldElement> {
     /// the hash of each row to get one entry of the vector being committed to. Thus, the number
     /// of elements grouped into a single leaf is equal to the `folding_factor` used for FRI layer
     /// construction.
-    fn commit_fri_layer(&mut self, layer_root: <Self::Hasher as Hasher>::Digest);
+    fn commit_fri_layer(
+        &mut self,
+        layer_commitment: <Self::VectorCommitment as VectorCommitment<Self::Hasher>>::Commitment,
+    );
+
+    /// Sends a commitment to the FRI remainder polynomial to the verifier.
+    ///
+    /// The commitment is the hash of the coefficients of the remainder polynomial.
+    fn commit_fri_remainder(&mut self, remainder_commitment: <Self::Hasher as Hasher>::Digest);
 
     /// Returns a random α drawn uniformly at random from the entire field.
     ///
@@ -53,24 +63,27 @@ pub trait ProverChannel<E: FieldElement> {
 ///
 /// Though this implementation is intended primarily for testing purposes, it can be used in
 /// production use cases as well.
-pub struct DefaultProverChannel<E, H, R>
+pub struct DefaultProverChannel<E, H, R, V>
 where
     E: FieldElement,
     H: ElementHasher<BaseField = E::BaseField>,
     R: RandomCoin<BaseField = E::BaseField, Hasher = H>,
+    V: VectorCommitment<H>,
 {
     public_coin: R,
-    commitments: Vec<H::Digest>,
+    commitments: Vec<V::Commitment>,
+    remainder_commitment: H::Digest,
     domain_size: usize,
     num_queries: usize,
     _field_element: PhantomData<E>,
 }
 
-impl<E, H, R> DefaultProverChannel<E, H, R>
+impl<E, H, R, V> DefaultProverChannel<E, H, R, V>
 where
     E: FieldElement,
     H: ElementHasher<BaseField = E::BaseField>,
     R: RandomCoin<BaseField = E::BaseField, Hasher = H>,
+    V: VectorCommitment<H>,
 {
     /// Returns a new prover channel instantiated from the specified parameters.
     ///
@@ -88,6 +101,7 @@ where
         DefaultProverChannel {
             public_coin: RandomCoin::new(&[]),
             commitments: Vec::new(),
+            remainder_commitment: H::Digest::default(),
             domain_size,
             num_queries,
             _field_element: PhantomData,
@@ -111,22 +125,34 @@ where
     }
 
     /// Returns a list of FRI layer commitments written by the prover into this channel.
-    pub fn layer_commitments(&self) -> &[H::Digest] {
+    pub fn layer_commitments(&self) -> &[V::Commitment] {
         &self.commitments
     }
+
+    /// Returns the FRI remainder polynomial commitment written by the prover into this channel.
+    pub fn remainder_commitment(&self) -> H::Digest {
+        self.remainder_commitment
+    }
 }
 
-impl<E, H, R> ProverChannel<E> for DefaultProverChannel<E, H, R>
+impl<E, H, R, V> ProverChannel<E> for DefaultProverChannel<E, H, R, V>
 where
     E: FieldElement,
     H: ElementHasher<BaseField = E::BaseField>,
     R: RandomCoin<BaseField = E::BaseField, Hasher = H>,
+    V: VectorCommitment<H>,
 {
     type Hasher = H;
+    type VectorCommitment = V;
+
+    fn commit_fri_layer(&mut self, layer_commitment: V::Commitment) {
+        self.public_coin.reseed(V::commitment_digest(&layer_commitment));
+        self.commitments.push(layer_commitment);
+    }
 
-    fn commit_fri_layer(&mut self, layer_root: H::Digest) {
-        self.commitments.push(layer_root);
-        self.public_coin.reseed(layer_root);
+    fn commit_fri_remainder(&mut self, remainder_commitment: H::Digest) {
+        self.remainder_commitment = remainder_commitment;
+        self.public_coin.reseed(remainder_commitment);
     }
 
     fn draw_fri_alpha(&mut self) -> E {
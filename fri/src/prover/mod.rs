@@ -95,7 +95,7 @@ mod tests;
 pub struct FriProver<E, C, H, V>
 where
     E: FieldElement,
-    C: ProverChannel<E, Hasher = H>,
+    C: ProverChannel<E, Hasher = H, VectorCommitment = V>,
     H: ElementHasher<BaseField = E::BaseField>,
     V: VectorCommitment<H>,
 {
@@ -119,7 +119,7 @@ struct FriRemainder<E: FieldElement>(Vec<E>);
 impl<E, C, H, V> FriProver<E, C, H, V>
 where
     E: FieldElement,
-    C: ProverChannel<E, Hasher = H>,
+    C: ProverChannel<E, Hasher = H, VectorCommitment = V>,
     H: ElementHasher<BaseField = E::BaseField>,
     V: VectorCommitment<H>,
 {
@@ -176,30 +176,79 @@ where
     ///
     /// # Panics
     /// Panics if the prover state is dirty (the vector of layers is not empty).
-    pub fn build_layers(&mut self, channel: &mut C, mut evaluations: Vec<E>) {
+    pub fn build_layers(&mut self, channel: &mut C, evaluations: Vec<E>) {
+        self.build_layers_batched(channel, evaluations, Vec::new());
+    }
+
+    /// Executes the commit phase of the FRI protocol for a batch of functions evaluated over
+    /// domains of different sizes.
+    ///
+    /// This works the same way as [build_layers()](FriProver::build_layers()), but in addition to
+    /// `evaluations` over the largest domain *D*, the prover also accepts a list of `inputs`
+    /// evaluated over smaller domains. An input evaluated over a domain of size |*D*| / N^k,
+    /// where N is the folding factor, is mixed into the k-th folded layer: after the DRP with
+    /// random α is applied, the folded evaluations are updated as f'(x) + α^N * g(x). Thus, a
+    /// single FRI proof attests to low degree of all functions in the batch.
+    ///
+    /// All domains must share the same offset, and each input must have degree smaller than the
+    /// size of its domain divided by the blowup factor.
+    ///
+    /// # Panics
+    /// Panics if:
+    /// * The prover state is dirty (the vector of layers is not empty).
+    /// * `inputs` are not sorted by domain size in strictly decreasing order.
+    /// * Domain size of any of the inputs is not equal to |*D*| / N^k for some k such that
+    ///   0 < k <= number of FRI layers.
+    pub fn build_layers_batched(
+        &mut self,
+        channel: &mut C,
+        mut evaluations: Vec<E>,
+        inputs: Vec<Vec<E>>,
+    ) {
         assert!(
             self.layers.is_empty(),
             "a prior proof generation request has not been completed yet"
         );
+        let num_layers = self.options.num_fri_layers(evaluations.len());
+        let mut inputs = inputs.into_iter().peekable();
 
         // reduce the degree by folding_factor at each iteration until the remaining polynomial
         // has small enough degree
-        for _ in 0..self.options.num_fri_layers(evaluations.len()) {
-            match self.folding_factor() {
+        for _ in 0..num_layers {
+            let alpha = match self.folding_factor() {
                 2 => self.build_layer::<2>(channel, &mut evaluations),
                 4 => self.build_layer::<4>(channel, &mut evaluations),
                 8 => self.build_layer::<8>(channel, &mut evaluations),
                 16 => self.build_layer::<16>(channel, &mut evaluations),
                 _ => unimplemented!("folding factor {} is not supported", self.folding_factor()),
+            };
+
+            // mix in the input evaluated over the folded domain, if there is one
+            if let Some(input) = inputs.next_if(|input| input.len() == evaluations.len()) {
+                let coeff = alpha.exp((self.folding_factor() as u32).into());
+                iter_mut!(evaluations, 1024)
+                    .zip(input)
+                    .for_each(|(value, input)| *value += coeff * input);
             }
         }
 
+        if let Some(input) = inputs.next() {
+            panic!(
+                "input domain size {} does not match any of the folded FRI domains; the smallest \
+                folded domain size is {}",
+                input.len(),
+                evaluations.len()
+            );
+        }
+
         self.set_remainder(channel, &mut evaluations);
     }
 
     /// Builds a single FRI layer by first committing to the `evaluations`, then drawing a random
     /// alpha from the channel and use it to perform degree-respecting projection.
-    fn build_layer<const N: usize>(&mut self, channel: &mut C, evaluations: &mut Vec<E>) {
+    ///
+    /// Returns the random alpha used in the projection.
+    fn build_layer<const N: usize>(&mut self, channel: &mut C, evaluations: &mut Vec<E>) -> E {
         // commit to the evaluations at the current layer; we do this by first transposing the
         // evaluations into a matrix of N columns, then hashing each row into a digest, and finally
         // commiting to vector of these digests; we do this so that we could de-commit to N values
@@ -219,6 +268,7 @@ where
             evaluations: flatten_vector_elements(transposed_evaluations),
             _h: PhantomData,
         });
+        alpha
     }
 
     /// Creates remainder polynomial in coefficient form from a vector of `evaluations` over a
@@ -234,7 +284,7 @@ where
         let remainder_poly: Vec<_> =
             evaluations[..remainder_poly_size].iter().copied().rev().collect();
         let commitment = <H as ElementHasher>::hash_elements(&remainder_poly);
-        channel.commit_fri_layer(commitment);
+        channel.commit_fri_remainder(commitment);
         self.remainder_poly = FriRemainder(remainder_poly);
     }
 
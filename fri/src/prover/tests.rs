@@ -11,6 +11,7 @@ use utils::{Deserializable, Serializable, SliceReader};
 
 use super::{DefaultProverChannel, FriProver};
 use crate::{
+    folding::fold_positions,
     verifier::{DefaultVerifierChannel, FriVerifier},
     FriOptions, FriProof, VerifierError,
 };
@@ -38,13 +39,39 @@ fn fri_folding_4() {
     fri_prove_verify(trace_length_e, lde_blowup_e, folding_factor_e, max_remainder_degree)
 }
 
+#[test]
+fn fri_batched_inputs() {
+    let options = FriOptions::new(8, 4, 7);
+    let trace_lengths = [1 << 12, 1 << 10, 1 << 8];
+    // all inputs are valid low-degree polynomials
+    let result = fri_prove_verify_batched(&trace_lengths, &trace_lengths, &options);
+    assert!(result.is_ok(), "{:}", result.err().unwrap());
+
+    // one of the inputs has degree which is too high for its domain
+    let degrees = [1 << 12, 1 << 11, 1 << 8];
+    let result = fri_prove_verify_batched(&trace_lengths, &degrees, &options);
+    assert!(result.is_err());
+}
+
+#[test]
+#[should_panic(expected = "does not match any of the folded FRI domains")]
+fn fri_batched_inputs_invalid_domain() {
+    let options = FriOptions::new(8, 4, 7);
+    let mut channel = build_prover_channel(1 << 12, &options);
+
+    // domain of size 2^12 cannot be reached by folding a domain of size 2^15 by a factor of 4
+    let inputs = vec![build_evaluations(1 << 10, 4)];
+    let mut prover = FriProver::<_, _, _, MerkleTree<Blake3>>::new(options);
+    prover.build_layers_batched(&mut channel, build_evaluations(1 << 12, 8), inputs);
+}
+
 // TEST UTILS
 // ================================================================================================
 
 pub fn build_prover_channel(
     trace_length: usize,
     options: &FriOptions,
-) -> DefaultProverChannel<BaseElement, Blake3, DefaultRandomCoin<Blake3>> {
+) -> DefaultProverChannel<BaseElement, Blake3, DefaultRandomCoin<Blake3>, MerkleTree<Blake3>> {
     DefaultProverChannel::new(trace_length * options.blowup_factor(), 32)
 }
 
@@ -62,6 +89,7 @@ pub fn build_evaluations(trace_length: usize, lde_blowup: usize) -> Vec<BaseElem
 pub fn verify_proof(
     proof: FriProof,
     commitments: Vec<<Blake3 as Hasher>::Digest>,
+    remainder_commitment: <Blake3 as Hasher>::Digest,
     evaluations: &[BaseElement],
     max_degree: usize,
     domain_size: usize,
@@ -79,6 +107,7 @@ pub fn verify_proof(
     let mut channel = DefaultVerifierChannel::<BaseElement, Blake3, MerkleTree<Blake3>>::new(
         proof,
         commitments,
+        remainder_commitment,
         domain_size,
         options.folding_factor(),
     )
@@ -111,10 +140,12 @@ fn fri_prove_verify(
 
     // make sure the proof can be verified
     let commitments = channel.layer_commitments().to_vec();
+    let remainder_commitment = channel.remainder_commitment();
     let max_degree = trace_length - 1;
     let result = verify_proof(
         proof.clone(),
         commitments.clone(),
+        remainder_commitment,
         &evaluations,
         max_degree,
         trace_length * lde_blowup,
@@ -127,6 +158,7 @@ fn fri_prove_verify(
     let result = verify_proof(
         proof,
         commitments,
+        remainder_commitment,
         &evaluations,
         max_degree - 8,
         trace_length * lde_blowup,
@@ -135,3 +167,53 @@ fn fri_prove_verify(
     );
     assert!(result.is_err());
 }
+
+/// Builds a batched FRI proof for polynomials with the specified number of coefficients evaluated
+/// over domains implied by `trace_lengths`, and verifies it against the evaluations.
+fn fri_prove_verify_batched(
+    trace_lengths: &[usize],
+    num_coeffs: &[usize],
+    options: &FriOptions,
+) -> Result<(), VerifierError> {
+    let blowup = options.blowup_factor();
+    let evaluations = trace_lengths
+        .iter()
+        .zip(num_coeffs)
+        .map(|(&trace_length, &num_coeffs)| {
+            build_evaluations(num_coeffs, trace_length * blowup / num_coeffs)
+        })
+        .collect::<Vec<_>>();
+
+    // instantiate the prover and generate the proof
+    let mut channel = build_prover_channel(trace_lengths[0], options);
+    let mut prover = FriProver::<_, _, _, MerkleTree<Blake3>>::new(options.clone());
+    prover.build_layers_batched(&mut channel, evaluations[0].clone(), evaluations[1..].to_vec());
+    let positions = channel.draw_query_positions(0);
+    let proof = prover.build_proof(&positions);
+
+    // verify the proof
+    let domain_size = trace_lengths[0] * blowup;
+    let mut verifier_channel =
+        DefaultVerifierChannel::<BaseElement, Blake3, MerkleTree<Blake3>>::new(
+            proof,
+            channel.layer_commitments().to_vec(),
+            channel.remainder_commitment(),
+            domain_size,
+            options.folding_factor(),
+        )
+        .unwrap();
+    let mut coin = crypto::DefaultRandomCoin::<Blake3>::new(&[]);
+    let verifier =
+        FriVerifier::new(&mut verifier_channel, &mut coin, options.clone(), trace_lengths[0] - 1)?;
+
+    let queried_evaluations = positions.iter().map(|&p| evaluations[0][p]).collect::<Vec<_>>();
+    let inputs = evaluations[1..]
+        .iter()
+        .map(|input| {
+            let input_positions =
+                fold_positions(&positions, domain_size, domain_size / input.len());
+            (input.len(), input_positions.iter().map(|&p| input[p]).collect())
+        })
+        .collect::<Vec<_>>();
+    verifier.verify_batched(&mut verifier_channel, &queried_evaluations, &positions, &inputs)
+}
@@ -42,7 +42,13 @@ pub trait VerifierChannel<E: FieldElement> {
     /// from the entire field after each layer commitment is received. In the non-interactive
     /// version, the verifier can read all layer commitments at once, and then generate α values
     /// locally.
-    fn read_fri_layer_commitments(&mut self) -> Vec<<Self::Hasher as Hasher>::Digest>;
+    fn read_fri_layer_commitments(
+        &mut self,
+    ) -> Vec<<Self::VectorCommitment as VectorCommitment<Self::Hasher>>::Commitment>;
+
+    /// Reads and removes from the channel the commitment to the FRI remainder polynomial sent by
+    /// the prover.
+    fn read_fri_remainder_commitment(&mut self) -> <Self::Hasher as Hasher>::Digest;
 
     /// Reads and removes from the channel evaluations of the polynomial at the queried positions
     /// for the next FRI layer.
@@ -83,7 +89,7 @@ pub trait VerifierChannel<E: FieldElement> {
     fn read_layer_queries<const N: usize>(
         &mut self,
         positions: &[usize],
-        commitment: &<Self::Hasher as Hasher>::Digest,
+        commitment: &<Self::VectorCommitment as VectorCommitment<Self::Hasher>>::Commitment,
     ) -> Result<Vec<[E; N]>, VerifierError> {
         let layer_proof = self.take_next_fri_layer_proof();
         let layer_queries = self.take_next_fri_layer_queries();
@@ -98,7 +104,7 @@ pub trait VerifierChannel<E: FieldElement> {
             .collect();
 
         <<Self as VerifierChannel<E>>::VectorCommitment as VectorCommitment<Self::Hasher>>::verify_many(
-            *commitment,
+            commitment.clone(),
             positions,
             &hashed_values,
             &layer_proof,
@@ -130,7 +136,8 @@ pub struct DefaultVerifierChannel<
     H: ElementHasher<BaseField = E::BaseField>,
     V: VectorCommitment<H>,
 > {
-    layer_commitments: Vec<H::Digest>,
+    layer_commitments: Vec<V::Commitment>,
+    remainder_commitment: H::Digest,
     layer_proofs: Vec<V::MultiProof>,
     layer_queries: Vec<Vec<E>>,
     remainder: Vec<E>,
@@ -150,7 +157,8 @@ where
     /// Returns an error if the specified `proof` could not be parsed correctly.
     pub fn new(
         proof: FriProof,
-        layer_commitments: Vec<H::Digest>,
+        layer_commitments: Vec<V::Commitment>,
+        remainder_commitment: H::Digest,
         domain_size: usize,
         folding_factor: usize,
     ) -> Result<Self, DeserializationError> {
@@ -162,6 +170,7 @@ where
 
         Ok(DefaultVerifierChannel {
             layer_commitments,
+            remainder_commitment,
             layer_proofs,
             layer_queries,
             remainder,
@@ -184,10 +193,14 @@ where
         self.num_partitions
     }
 
-    fn read_fri_layer_commitments(&mut self) -> Vec<H::Digest> {
+    fn read_fri_layer_commitments(&mut self) -> Vec<V::Commitment> {
         self.layer_commitments.drain(..).collect()
     }
 
+    fn read_fri_remainder_commitment(&mut self) -> H::Digest {
+        self.remainder_commitment
+    }
+
     fn take_next_fri_layer_proof(&mut self) -> V::MultiProof {
         self.layer_proofs.remove(0)
     }
@@ -68,7 +68,7 @@ where
     max_poly_degree: usize,
     domain_size: usize,
     domain_generator: E::BaseField,
-    layer_commitments: Vec<H::Digest>,
+    layer_commitments: Vec<V::Commitment>,
     layer_alphas: Vec<E>,
     options: FriOptions,
     num_partitions: usize,
@@ -116,18 +116,22 @@ where
 
         let num_partitions = channel.read_fri_num_partitions();
 
-        // read layer commitments from the channel and use them to build a list of alphas
+        // read layer commitments (followed by the remainder commitment) from the channel and use
+        // them to build a list of alphas
         let layer_commitments = channel.read_fri_layer_commitments();
-        let mut layer_alphas = Vec::with_capacity(layer_commitments.len());
+        let remainder_commitment = channel.read_fri_remainder_commitment();
+        let commitment_digests =
+            layer_commitments.iter().map(V::commitment_digest).chain([remainder_commitment]);
+        let mut layer_alphas = Vec::with_capacity(layer_commitments.len() + 1);
         let mut max_degree_plus_1 = max_poly_degree + 1;
-        for (depth, commitment) in layer_commitments.iter().enumerate() {
-            public_coin.reseed(*commitment);
+        for (depth, commitment) in commitment_digests.enumerate() {
+            public_coin.reseed(commitment);
             let alpha = public_coin.draw().map_err(VerifierError::RandomCoinError)?;
             layer_alphas.push(alpha);
 
             // make sure the degree can be reduced by the folding factor at all layers
             // but the remainder layer
-            if depth != layer_commitments.len() - 1
+            if depth != layer_commitments.len()
                 && !max_degree_plus_1.is_multiple_of(options.folding_factor())
             {
                 return Err(VerifierError::DegreeTruncation(
@@ -212,6 +216,32 @@ where
         channel: &mut C,
         evaluations: &[E],
         positions: &[usize],
+    ) -> Result<(), VerifierError> {
+        self.verify_batched(channel, evaluations, positions, &[])
+    }
+
+    /// Executes the query phase of the FRI protocol for a batch of functions evaluated over
+    /// domains of different sizes.
+    ///
+    /// This works the same way as [verify()](FriVerifier::verify()), but also accounts for
+    /// additional `inputs` which were mixed into the folded FRI layers by the prover (see
+    /// [FriProver::build_layers_batched()](crate::FriProver::build_layers_batched())).
+    ///
+    /// Each input is described by a tuple `(domain_size, evaluations)`, where `evaluations` are
+    /// the values of the input function at positions `fold_positions(positions, self.domain_size(),
+    /// self.domain_size() / domain_size)`, in that order. Inputs must be sorted by domain size in
+    /// strictly decreasing order.
+    ///
+    /// # Errors
+    /// In addition to the errors returned by [verify()](FriVerifier::verify()), returns an error
+    /// if domain size of any of the inputs does not match one of the folded FRI domains, or if
+    /// the number of evaluations of an input is inconsistent with its domain size.
+    pub fn verify_batched(
+        &self,
+        channel: &mut C,
+        evaluations: &[E],
+        positions: &[usize],
+        inputs: &[(usize, Vec<E>)],
     ) -> Result<(), VerifierError> {
         if evaluations.len() != positions.len() {
             return Err(VerifierError::NumPositionEvaluationMismatch(
@@ -223,10 +253,10 @@ where
         // static dispatch for folding factor parameter
         let folding_factor = self.options.folding_factor();
         match folding_factor {
-            2 => self.verify_generic::<2>(channel, evaluations, positions),
-            4 => self.verify_generic::<4>(channel, evaluations, positions),
-            8 => self.verify_generic::<8>(channel, evaluations, positions),
-            16 => self.verify_generic::<16>(channel, evaluations, positions),
+            2 => self.verify_generic::<2>(channel, evaluations, positions, inputs),
+            4 => self.verify_generic::<4>(channel, evaluations, positions, inputs),
+            8 => self.verify_generic::<8>(channel, evaluations, positions, inputs),
+            16 => self.verify_generic::<16>(channel, evaluations, positions, inputs),
             _ => Err(VerifierError::UnsupportedFoldingFactor(folding_factor)),
         }
     }
@@ -238,6 +268,7 @@ where
         channel: &mut C,
         evaluations: &[E],
         positions: &[usize],
+        inputs: &[(usize, Vec<E>)],
     ) -> Result<(), VerifierError> {
         // pre-compute roots of unity used in computing x coordinates in the folded domain
         let folding_roots = (0..N)
@@ -250,6 +281,7 @@ where
         let mut max_degree_plus_1 = self.max_poly_degree + 1;
         let mut positions = positions.to_vec();
         let mut evaluations = evaluations.to_vec();
+        let mut inputs = inputs.iter().peekable();
 
         for depth in 0..self.options.num_fri_layers(self.domain_size) {
             // determine which evaluations were queried in the folded layer
@@ -263,9 +295,9 @@ where
                 self.num_partitions,
             );
             // read query values from the specified indexes
-            let layer_commitment = self.layer_commitments[depth];
+            let layer_commitment = &self.layer_commitments[depth];
             // TODO: add layer depth to the potential error message
-            let layer_values = channel.read_layer_queries(&position_indexes, &layer_commitment)?;
+            let layer_values = channel.read_layer_queries(&position_indexes, layer_commitment)?;
             let query_values =
                 get_query_values::<E, N>(&layer_values, &positions, &folded_positions, domain_size);
             if evaluations != query_values {
@@ -292,6 +324,20 @@ where
             // the corresponding column value
             evaluations = row_polys.iter().map(|p| polynom::eval(p, alpha)).collect();
 
+            // mix in the input evaluated over the folded domain, if there is one
+            if let Some((_, input)) = inputs.next_if(|(size, _)| *size == domain_size / N) {
+                if input.len() != folded_positions.len() {
+                    return Err(VerifierError::NumPositionEvaluationMismatch(
+                        folded_positions.len(),
+                        input.len(),
+                    ));
+                }
+                let coeff = alpha.exp((N as u32).into());
+                for (value, &input) in evaluations.iter_mut().zip(input) {
+                    *value += coeff * input;
+                }
+            }
+
             // make sure next degree reduction does not result in degree truncation
             if !max_degree_plus_1.is_multiple_of(N) {
                 return Err(VerifierError::DegreeTruncation(max_degree_plus_1 - 1, N, depth));
@@ -304,6 +350,11 @@ where
             mem::swap(&mut positions, &mut folded_positions);
         }
 
+        // make sure all inputs were mixed into one of the layers
+        if let Some((size, _)) = inputs.next() {
+            return Err(VerifierError::InvalidInputDomainSize(*size));
+        }
+
         // 2 ----- verify the remainder polynomial of the FRI proof -------------------------------
 
         // read the remainder polynomial from the channel and make sure it agrees with the
@@ -13,7 +13,7 @@ use criterion::{
 use rand_utils::{rand_array, rand_value, rand_vector};
 use winter_math::{
     batch_inversion,
-    fields::{f128, f62, f64, CubeExtension, QuadExtension},
+    fields::{f128, f31, f62, f64, CubeExtension, QuadExtension, QuartExtension},
     ExtensibleField, FieldElement, StarkField,
 };
 
@@ -42,7 +42,7 @@ pub fn batch_inv(c: &mut Criterion) {
 // ================================================================================================
 pub fn field_ops<B>(c: &mut Criterion, field_name: &str)
 where
-    B: StarkField + ExtensibleField<2> + ExtensibleField<3>,
+    B: StarkField + ExtensibleField<2> + ExtensibleField<3> + ExtensibleField<4>,
 {
     let mut group = c.benchmark_group(format!("field/{field_name}"));
 
@@ -141,6 +141,27 @@ where
             bench.iter(|| black_box(x) * black_box(y))
         });
     }
+
+    // --- quartic extension ----------------------------------------------------------------------
+
+    if QuartExtension::<B>::is_supported() {
+        group.bench_function("quart/add", |bench| {
+            let x = rand_value::<QuartExtension<B>>();
+            let y = rand_value::<QuartExtension<B>>();
+            bench.iter(|| black_box(x) + black_box(y))
+        });
+
+        group.bench_function("quart/mul", |bench| {
+            let x = rand_value::<QuartExtension<B>>();
+            let y = rand_value::<QuartExtension<B>>();
+            bench.iter(|| black_box(x) * black_box(y))
+        });
+
+        group.bench_function("quart/inv", |bench| {
+            let x = rand_value::<QuartExtension<B>>();
+            bench.iter(|| black_box(x).inv())
+        });
+    }
 }
 
 // ARRAY OPS
@@ -260,6 +281,7 @@ fn bench_field_ops(c: &mut Criterion) {
     field_ops::<f62::BaseElement>(c, "f62");
     field_ops::<f64::BaseElement>(c, "f64");
     field_ops::<f128::BaseElement>(c, "f128");
+    field_ops::<f31::BaseElement>(c, "f31");
 }
 
 // CRITERION BOILERPLATE
@@ -10,6 +10,8 @@ use rand_utils::rand_vector;
 use winter_math::{fft, fields::f128::BaseElement, polynom, FieldElement};
 
 const SIZES: [usize; 3] = [262_144, 524_288, 1_048_576];
+const MUL_DIV_SIZES: [usize; 3] = [1_024, 16_384, 262_144];
+const MULTIPOINT_SIZES: [usize; 2] = [1_024, 4_096];
 
 fn syn_div(c: &mut Criterion) {
     let mut group = c.benchmark_group("syn_div");
@@ -39,5 +41,71 @@ fn syn_div(c: &mut Criterion) {
     group.finish();
 }
 
-criterion_group!(polynom_group, syn_div);
+fn mul(c: &mut Criterion) {
+    let mut group = c.benchmark_group("mul");
+    group.sample_size(10);
+
+    for &size in MUL_DIV_SIZES.iter() {
+        let p1: Vec<BaseElement> = rand_vector(size);
+        let p2: Vec<BaseElement> = rand_vector(size);
+        group.bench_function(BenchmarkId::from_parameter(size), |bench| {
+            bench.iter(|| polynom::mul(&p1, &p2));
+        });
+    }
+
+    group.finish();
+}
+
+fn div(c: &mut Criterion) {
+    let mut group = c.benchmark_group("div");
+    group.sample_size(10);
+
+    for &size in MUL_DIV_SIZES.iter() {
+        let p1: Vec<BaseElement> = rand_vector(2 * size);
+        let p2: Vec<BaseElement> = rand_vector(size);
+        group.bench_function(BenchmarkId::from_parameter(size), |bench| {
+            bench.iter(|| polynom::div(&p1, &p2));
+        });
+    }
+
+    group.finish();
+}
+
+fn eval_many(c: &mut Criterion) {
+    let mut group = c.benchmark_group("eval_many");
+    group.sample_size(10);
+
+    for &size in MULTIPOINT_SIZES.iter() {
+        let p: Vec<BaseElement> = rand_vector(size);
+        let xs: Vec<BaseElement> = rand_vector(size);
+        group.bench_function(BenchmarkId::new("horner", size), |bench| {
+            bench.iter(|| polynom::eval_many(&p, &xs));
+        });
+        group.bench_function(BenchmarkId::new("subproduct_tree", size), |bench| {
+            bench.iter(|| polynom::eval_many_fast(&p, &xs));
+        });
+    }
+
+    group.finish();
+}
+
+fn interpolate(c: &mut Criterion) {
+    let mut group = c.benchmark_group("interpolate");
+    group.sample_size(10);
+
+    for &size in MULTIPOINT_SIZES.iter() {
+        let xs: Vec<BaseElement> = rand_vector(size);
+        let ys: Vec<BaseElement> = rand_vector(size);
+        group.bench_function(BenchmarkId::new("lagrange", size), |bench| {
+            bench.iter(|| polynom::interpolate(&xs, &ys, false));
+        });
+        group.bench_function(BenchmarkId::new("subproduct_tree", size), |bench| {
+            bench.iter(|| polynom::interpolate_fast(&xs, &ys, false));
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(polynom_group, syn_div, mul, div, eval_many, interpolate);
 criterion_main!(polynom_group);
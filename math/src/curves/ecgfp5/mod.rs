@@ -0,0 +1,231 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+//! An implementation of the EcGFp5 elliptic curve.
+//!
+//! The curve is defined over GF(p^5), where p = 2^64 - 2^32 + 1 is the modulus of the
+//! [f64](crate::fields::f64) field, and the extension is defined by the irreducible polynomial
+//! z^5 - 3 (see [QuintExtension]). The curve equation is:
+//!
+//! y^2 = x^3 + a * x^2 + b * x, where a = 2 and b = 263 * z.
+//!
+//! The order of the curve is 2 * n, where n is a 319-bit prime; this module works with the
+//! subgroup of order n, which is generated by [AffinePoint::GENERATOR]. The parameters follow
+//! <https://eprint.iacr.org/2022/274.pdf>; unlike the encoding proposed there, points are
+//! represented in plain affine coordinates, which makes the group law straightforward to express
+//! as AIR constraints.
+//!
+//! Operations in this module are not constant-time, and thus should not be used with secret
+//! scalars outside of tests and examples.
+
+use core::ops::{Add, Neg, Sub};
+
+use crate::field::{f64::BaseElement, FieldElement, QuintExtension};
+
+#[cfg(test)]
+mod tests;
+
+// CONSTANTS
+// ================================================================================================
+
+/// Field over which the curve is defined.
+pub type BaseField = QuintExtension<BaseElement>;
+
+/// Coefficient a of the curve equation.
+pub const A: BaseField = QuintExtension::new(
+    BaseElement::new(2),
+    BaseElement::new(0),
+    BaseElement::new(0),
+    BaseElement::new(0),
+    BaseElement::new(0),
+);
+
+/// Coefficient b of the curve equation.
+pub const B: BaseField = QuintExtension::new(
+    BaseElement::new(0),
+    BaseElement::new(263),
+    BaseElement::new(0),
+    BaseElement::new(0),
+    BaseElement::new(0),
+);
+
+/// Order n of the subgroup generated by [AffinePoint::GENERATOR], as little-endian 64-bit limbs.
+pub const ORDER: [u64; 5] = [
+    0xe80fd996948bffe1,
+    0xe8885c39d724a09c,
+    0x7fffffe6cfb80639,
+    0x7ffffff100000016,
+    0x7ffffffd80000007,
+];
+
+// AFFINE POINT
+// ================================================================================================
+
+/// Represents a point on the curve in affine coordinates.
+///
+/// The point at infinity has no affine coordinates, and thus it is identified by a separate flag;
+/// its coordinates are set to ZERO.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct AffinePoint {
+    x: BaseField,
+    y: BaseField,
+    is_infinity: bool,
+}
+
+impl AffinePoint {
+    /// The point at infinity (the neutral element of the group).
+    pub const INFINITY: Self = Self {
+        x: BaseField::ZERO,
+        y: BaseField::ZERO,
+        is_infinity: true,
+    };
+
+    /// The generator of the subgroup of order n.
+    pub const GENERATOR: Self = Self {
+        x: QuintExtension::new(
+            BaseElement::new(13751537904594739872),
+            BaseElement::new(8859161600321272310),
+            BaseElement::new(2721769971267987483),
+            BaseElement::new(1199172087896026830),
+            BaseElement::new(5553044710935820496),
+        ),
+        y: QuintExtension::new(
+            BaseElement::new(18146711375748607373),
+            BaseElement::new(11035877302916390812),
+            BaseElement::new(12326466918881407707),
+            BaseElement::new(17988590196751087656),
+            BaseElement::new(10165058723446039306),
+        ),
+        is_infinity: false,
+    };
+
+    /// Returns a point with the specified coordinates, or None if the point does not lie on the
+    /// curve.
+    pub fn new(x: BaseField, y: BaseField) -> Option<Self> {
+        let point = Self { x, y, is_infinity: false };
+        point.is_on_curve().then_some(point)
+    }
+
+    /// Returns a point with the specified x coordinate, or None if no such point exists.
+    ///
+    /// Of the two possible points, the one returned is determined by [FieldElement::sqrt()]; the
+    /// other one is its negation.
+    pub fn from_x(x: BaseField) -> Option<Self> {
+        let y = curve_rhs(x).sqrt()?;
+        Some(Self { x, y, is_infinity: false })
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the x coordinate of this point; this is ZERO for the point at infinity.
+    pub fn x(&self) -> BaseField {
+        self.x
+    }
+
+    /// Returns the y coordinate of this point; this is ZERO for the point at infinity.
+    pub fn y(&self) -> BaseField {
+        self.y
+    }
+
+    /// Returns true if this is the point at infinity.
+    pub fn is_infinity(&self) -> bool {
+        self.is_infinity
+    }
+
+    /// Returns true if this point lies on the curve.
+    pub fn is_on_curve(&self) -> bool {
+        self.is_infinity || self.y.square() == curve_rhs(self.x)
+    }
+
+    // GROUP OPERATIONS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns 2 * self.
+    pub fn double(&self) -> Self {
+        if self.is_infinity || self.y == BaseField::ZERO {
+            return Self::INFINITY;
+        }
+
+        // λ = (3 * x^2 + 2 * a * x + b) / (2 * y)
+        let x_sq = self.x.square();
+        let numerator = x_sq.double() + x_sq + (A * self.x).double() + B;
+        let lambda = numerator / self.y.double();
+
+        let x = lambda.square() - A - self.x.double();
+        let y = lambda * (self.x - x) - self.y;
+        Self { x, y, is_infinity: false }
+    }
+
+    /// Returns scalar * self, where the scalar is specified as little-endian 64-bit limbs.
+    ///
+    /// This uses the double-and-add method, and thus runs in time which depends on the scalar.
+    pub fn scalar_mul(&self, scalar: &[u64]) -> Self {
+        let mut result = Self::INFINITY;
+        for &limb in scalar.iter().rev() {
+            for i in (0..64).rev() {
+                result = result.double();
+                if (limb >> i) & 1 == 1 {
+                    result = result + *self;
+                }
+            }
+        }
+        result
+    }
+}
+
+// OVERLOADED OPERATORS
+// ================================================================================================
+
+impl Add for AffinePoint {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        if self.is_infinity {
+            return rhs;
+        }
+        if rhs.is_infinity {
+            return self;
+        }
+        if self.x == rhs.x {
+            return if self.y == rhs.y { self.double() } else { Self::INFINITY };
+        }
+
+        // λ = (y2 - y1) / (x2 - x1)
+        let lambda = (rhs.y - self.y) / (rhs.x - self.x);
+
+        let x = lambda.square() - A - self.x - rhs.x;
+        let y = lambda * (self.x - x) - self.y;
+        Self { x, y, is_infinity: false }
+    }
+}
+
+impl Sub for AffinePoint {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        self + (-rhs)
+    }
+}
+
+impl Neg for AffinePoint {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self {
+            x: self.x,
+            y: -self.y,
+            is_infinity: self.is_infinity,
+        }
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns x^3 + a * x^2 + b * x.
+fn curve_rhs(x: BaseField) -> BaseField {
+    ((x + A) * x + B) * x
+}
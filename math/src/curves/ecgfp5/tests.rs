@@ -0,0 +1,160 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use proptest::prelude::*;
+use rand_utils::rand_value;
+
+use super::{AffinePoint, BaseField, ORDER};
+use crate::field::{f64::BaseElement, FieldElement, QuintExtension};
+
+// MANUAL TESTS
+// ================================================================================================
+
+#[test]
+fn generator() {
+    let g = AffinePoint::GENERATOR;
+    assert!(g.is_on_curve());
+    assert!(!g.is_infinity());
+    assert_eq!(Some(g), AffinePoint::new(g.x(), g.y()));
+
+    // the generator has order n
+    assert_eq!(AffinePoint::INFINITY, g.scalar_mul(&ORDER));
+}
+
+#[test]
+fn new() {
+    let g = AffinePoint::GENERATOR;
+    assert_eq!(None, AffinePoint::new(g.x(), g.y() + BaseField::ONE));
+}
+
+#[test]
+fn from_x() {
+    let g = AffinePoint::GENERATOR;
+    let p = AffinePoint::from_x(g.x()).unwrap();
+    assert!(p == g || p == -g);
+
+    // points with random x coordinates exist for about half of the x values, and all such points
+    // are in the group of order 2 * n
+    let mut order_2n = ORDER;
+    let mut carry = 0;
+    for limb in order_2n.iter_mut() {
+        let next_carry = *limb >> 63;
+        *limb = (*limb << 1) | carry;
+        carry = next_carry;
+    }
+    for _ in 0..8 {
+        if let Some(p) = AffinePoint::from_x(rand_value()) {
+            assert!(p.is_on_curve());
+            assert_eq!(AffinePoint::INFINITY, p.scalar_mul(&order_2n));
+        }
+    }
+}
+
+#[test]
+fn add() {
+    let g = AffinePoint::GENERATOR;
+
+    // identity
+    assert_eq!(g, g + AffinePoint::INFINITY);
+    assert_eq!(g, AffinePoint::INFINITY + g);
+    assert_eq!(AffinePoint::INFINITY, g + (-g));
+    assert_eq!(AffinePoint::INFINITY, g - g);
+
+    // 3 * G computed independently
+    let expected = point(
+        [
+            5271473280190966675,
+            14083225131496811169,
+            6598939841002259115,
+            3573693251445362024,
+            10923206106642722147,
+        ],
+        [
+            4565474202066625916,
+            11724335693981147798,
+            15588373005032514675,
+            16287299419426949611,
+            8064069082005562883,
+        ],
+    );
+    assert_eq!(expected, g + g + g);
+    assert_eq!(expected, g.double() + g);
+    assert_eq!(expected, g + g.double());
+    assert_eq!(expected, g.scalar_mul(&[3]));
+}
+
+#[test]
+fn double() {
+    let g = AffinePoint::GENERATOR;
+    assert_eq!(AffinePoint::INFINITY, AffinePoint::INFINITY.double());
+    assert_eq!(g + g, g.double());
+
+    // (0, 0) is the point of order 2
+    let p = AffinePoint::new(BaseField::ZERO, BaseField::ZERO).unwrap();
+    assert_eq!(AffinePoint::INFINITY, p.double());
+}
+
+#[test]
+fn scalar_mul() {
+    let g = AffinePoint::GENERATOR;
+    assert_eq!(AffinePoint::INFINITY, g.scalar_mul(&[0]));
+    assert_eq!(AffinePoint::INFINITY, g.scalar_mul(&[]));
+    assert_eq!(g, g.scalar_mul(&[1]));
+
+    // scalar = 0x0123456789abcdef_fedcba9876543210_0f1e2d3c4b5a6978_8796a5b4c3d2e1f0
+    let scalar = [0x8796a5b4c3d2e1f0, 0x0f1e2d3c4b5a6978, 0xfedcba9876543210, 0x0123456789abcdef];
+    let expected = point(
+        [
+            465058665885961799,
+            12583358193099729673,
+            2330061914728248325,
+            10436181698617806318,
+            17088289209451907060,
+        ],
+        [
+            1763738739101001017,
+            11230278182046786447,
+            13396056788335802656,
+            13403814415624667630,
+            864161856061635959,
+        ],
+    );
+    assert_eq!(expected, g.scalar_mul(&scalar));
+}
+
+// RANDOMIZED TESTS
+// ================================================================================================
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(16))]
+
+    #[test]
+    fn scalar_mul_proptest(a in any::<u64>(), b in any::<u64>()) {
+        let g = AffinePoint::GENERATOR;
+        let ga = g.scalar_mul(&[a]);
+        let gb = g.scalar_mul(&[b]);
+        prop_assert!(ga.is_on_curve());
+
+        // a * G + b * G = (a + b) * G
+        let (sum, carry) = a.overflowing_add(b);
+        prop_assert_eq!(ga + gb, g.scalar_mul(&[sum, carry as u64]));
+
+        // b * (a * G) = (a * b) * G
+        let product = (a as u128) * (b as u128);
+        prop_assert_eq!(ga.scalar_mul(&[b]), g.scalar_mul(&[product as u64, (product >> 64) as u64]));
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn point(x: [u64; 5], y: [u64; 5]) -> AffinePoint {
+    AffinePoint::new(element(x), element(y)).expect("point must be on the curve")
+}
+
+fn element(value: [u64; 5]) -> BaseField {
+    let [a, b, c, d, e] = value.map(BaseElement::new);
+    QuintExtension::new(a, b, c, d, e)
+}
@@ -0,0 +1,11 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+//! Elliptic curves defined over extensions of STARK fields.
+//!
+//! Arithmetic on these curves can be expressed efficiently in AIR constraints, and thus they can
+//! be used to prove knowledge of discrete logarithms or to verify signatures inside a STARK.
+
+pub mod ecgfp5;
@@ -25,6 +25,33 @@ pub trait FftInputs<E: FieldElement> {
     /// twiddle factor into a larger NTT.
     fn butterfly_twiddle(&mut self, twiddle: E::BaseField, offset: usize, stride: usize);
 
+    /// Applies [FftInputs::butterfly()] to `count` consecutive offsets starting at `offset`.
+    ///
+    /// The default implementation processes one offset at a time; implementations backed by
+    /// contiguous memory may override it to take advantage of batch field operations.
+    fn butterfly_batch(&mut self, offset: usize, stride: usize, count: usize) {
+        for offset in offset..(offset + count) {
+            self.butterfly(offset, stride);
+        }
+    }
+
+    /// Applies [FftInputs::butterfly_twiddle()] to `count` consecutive offsets starting at
+    /// `offset`.
+    ///
+    /// The default implementation processes one offset at a time; implementations backed by
+    /// contiguous memory may override it to take advantage of batch field operations.
+    fn butterfly_twiddle_batch(
+        &mut self,
+        twiddle: E::BaseField,
+        offset: usize,
+        stride: usize,
+        count: usize,
+    ) {
+        for offset in offset..(offset + count) {
+            self.butterfly_twiddle(twiddle, offset, stride);
+        }
+    }
+
     /// Swaps the element at index i with the element at index j. Specifically:
     ///
     /// elem_i <-> elem_j
@@ -122,6 +149,24 @@ impl<E: FieldElement> FftInputs<E> for [E] {
         self[j] = temp - self[j];
     }
 
+    fn butterfly_batch(&mut self, offset: usize, stride: usize, count: usize) {
+        debug_assert!(count <= stride);
+        let (left, right) = self.split_at_mut(offset + stride);
+        E::batch_butterfly(&mut left[offset..offset + count], &mut right[..count]);
+    }
+
+    fn butterfly_twiddle_batch(
+        &mut self,
+        twiddle: E::BaseField,
+        offset: usize,
+        stride: usize,
+        count: usize,
+    ) {
+        debug_assert!(count <= stride);
+        let (left, right) = self.split_at_mut(offset + stride);
+        E::batch_butterfly_twiddle(&mut left[offset..offset + count], &mut right[..count], twiddle);
+    }
+
     fn swap(&mut self, i: usize, j: usize) {
         self.swap(i, j)
     }
@@ -180,6 +225,31 @@ impl<E: FieldElement, const N: usize> FftInputs<E> for [[E; N]] {
         }
     }
 
+    fn butterfly_batch(&mut self, offset: usize, stride: usize, count: usize) {
+        debug_assert!(count <= stride);
+        let (left, right) = self.split_at_mut(offset + stride);
+        E::batch_butterfly(
+            left[offset..offset + count].as_flattened_mut(),
+            right[..count].as_flattened_mut(),
+        );
+    }
+
+    fn butterfly_twiddle_batch(
+        &mut self,
+        twiddle: E::BaseField,
+        offset: usize,
+        stride: usize,
+        count: usize,
+    ) {
+        debug_assert!(count <= stride);
+        let (left, right) = self.split_at_mut(offset + stride);
+        E::batch_butterfly_twiddle(
+            left[offset..offset + count].as_flattened_mut(),
+            right[..count].as_flattened_mut(),
+            twiddle,
+        );
+    }
+
     fn swap(&mut self, i: usize, j: usize) {
         self.swap(i, j)
     }
@@ -238,15 +308,11 @@ fn fft_in_place<E, I>(
     }
 
     // Apply butterfly operations.
-    for offset in offset..(offset + count) {
-        I::butterfly(values, offset, stride);
-    }
+    I::butterfly_batch(values, offset, stride, count);
 
     // Apply butterfly operations with twiddle factors.
     let last_offset = offset + size * stride;
     for (i, offset) in (offset..last_offset).step_by(2 * stride).enumerate().skip(1) {
-        for j in offset..(offset + count) {
-            I::butterfly_twiddle(values, twiddles[i], j, stride);
-        }
+        I::butterfly_twiddle_batch(values, twiddles[i], offset, stride, count);
     }
 }
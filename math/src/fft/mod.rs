@@ -210,6 +210,91 @@ where
     result
 }
 
+/// Evaluates a polynomial on all points of the specified coset of a (shifted) domain using the
+/// FFT algorithm.
+///
+/// The (shifted) domain of size `domain_size` is defined as a multiplicative subgroup of size
+/// `domain_size` with every element multiplied by the `domain_offset`. Let `m` be the size of the
+/// coset implied by `twiddles` (i.e., `twiddles.len()` * 2). Then, the domain is a union of
+/// `domain_size` / `m` cosets of a multiplicative subgroup of size `m`, and the coset with index
+/// `coset_idx` consists of the points at positions `coset_idx`, `coset_idx` + `domain_size` / `m`,
+/// `coset_idx` + 2 * `domain_size` / `m` etc. of the domain. Thus, evaluating a polynomial on all
+/// cosets of a domain yields the same values as evaluating it over the entire domain, but only one
+/// coset needs to be held in memory at a time.
+///
+/// The polynomial `p` is expected to be in coefficient form, and can be of any length; if
+/// `p.len()` is greater than `m`, the coefficients are first folded into `m` coefficients (this
+/// is equivalent to a single radix-(`domain_size` / `m`) step of the FFT over the entire domain).
+///
+/// The complexity of evaluation is O(`n` + `m` log(`m`)), where `n` is the length of `p`.
+///
+/// The `twiddles` needed for evaluation can be obtained via `fft::get_twiddles()` function using
+/// `m` as the domain size parameter; the same twiddles can be used for all cosets of the domain.
+///
+/// When `concurrent` feature is enabled, the FFT over the coset is done in multiple threads;
+/// folding of the coefficients of `p` into `m` coefficients is always done in a single thread.
+///
+/// # Panics
+/// Panics if:
+/// * `domain_size` is not a power of two.
+/// * Length of `twiddles` is zero, or `twiddles.len()` * 2 is not a power of two or is greater
+///   than `domain_size`.
+/// * `coset_idx` is not smaller than `domain_size` / `m`.
+/// * Field specified by `B` does not contain a multiplicative subgroup of size `domain_size`.
+/// * `domain_offset` is ZERO.
+///
+/// # Examples
+/// ```
+/// # use winter_math::{polynom, fft::*, get_power_series};
+/// # use winter_math::{fields::{f128::BaseElement}, FieldElement, StarkField};
+/// # use rand_utils::rand_vector;
+/// let n = 2048;
+/// let offset = BaseElement::GENERATOR;
+/// let coset_size = 256;
+///
+/// // build a random polynomial
+/// let p: Vec<BaseElement> = rand_vector(n / 4);
+///
+/// // evaluate the polynomial over the entire domain using FFT-based evaluation
+/// let twiddles = get_twiddles::<BaseElement>(p.len());
+/// let expected = evaluate_poly_with_offset(&p, &twiddles, offset, 4);
+///
+/// // evaluate the polynomial over each coset of the domain
+/// let twiddles = get_twiddles::<BaseElement>(coset_size);
+/// for i in 0..n / coset_size {
+///     let actual = evaluate_poly_on_coset(&p, &twiddles, offset, n, i);
+///     for (j, &value) in actual.iter().enumerate() {
+///         assert_eq!(expected[i + j * (n / coset_size)], value);
+///     }
+/// }
+/// ```
+pub fn evaluate_poly_on_coset<B, E>(
+    p: &[E],
+    twiddles: &[B],
+    domain_offset: B,
+    domain_size: usize,
+    coset_idx: usize,
+) -> Vec<E>
+where
+    B: StarkField,
+    E: FieldElement<BaseField = B>,
+{
+    let coset_offset = get_coset_offset(twiddles.len(), domain_offset, domain_size, coset_idx);
+    let coset_size = twiddles.len() * 2;
+
+    // fold the polynomial into a polynomial q(x) of degree smaller than the coset size such that
+    // q(x) = p(coset_offset * x) for all x in the subgroup of the coset size
+    let mut result = vec![E::ZERO; coset_size];
+    let mut factor = B::ONE;
+    for (i, &c) in p.iter().enumerate() {
+        result[i & (coset_size - 1)] += c.mul_base(factor);
+        factor *= coset_offset;
+    }
+
+    evaluate_poly(&mut result, twiddles);
+    result
+}
+
 // POLYNOMIAL INTERPOLATION
 // ================================================================================================
 
@@ -385,6 +470,74 @@ pub fn interpolate_poly_with_offset<B, E>(
     }
 }
 
+/// Interpolates evaluations of a polynomial over the specified coset of a (shifted) domain into a
+/// polynomial in coefficient form using the FFT algorithm.
+///
+/// This is the inverse of [evaluate_poly_on_coset()]: the coset with index `coset_idx` of a
+/// (shifted) domain of size `domain_size` consists of the points at positions `coset_idx`,
+/// `coset_idx` + `domain_size` / `evaluations.len()`, etc. of the domain, and the evaluations are
+/// expected to be in this order. The interpolation is done in-place, and the evaluations contained
+/// in `evaluations` are replaced with coefficients of the unique polynomial of degree smaller than
+/// `evaluations.len()` which takes the specified values on the coset.
+///
+/// The complexity of interpolation is O(`m` log(`m`)), where `m` is the length of `evaluations`.
+///
+/// The `inv_twiddles` needed for interpolation can be obtained via `fft::get_inv_twiddles()`
+/// function using `evaluations.len()` as the domain size parameter; the same twiddles can be used
+/// for all cosets of the domain.
+///
+/// When `concurrent` feature is enabled, the interpolation is done in multiple threads.
+///
+/// # Panics
+/// Panics if:
+/// * `domain_size` is not a power of two.
+/// * Length of `evaluations` is not a power of two or is greater than `domain_size`.
+/// * Length of `inv_twiddles` is not `evaluations.len()` / 2.
+/// * `coset_idx` is not smaller than `domain_size` / `evaluations.len()`.
+/// * Field specified by `B` does not contain a multiplicative subgroup of size `domain_size`.
+/// * `domain_offset` is ZERO.
+///
+/// # Examples
+/// ```
+/// # use winter_math::{polynom, fft::*, get_power_series};
+/// # use winter_math::{fields::{f128::BaseElement}, FieldElement, StarkField};
+/// # use rand_utils::rand_vector;
+/// let n = 2048;
+/// let offset = BaseElement::GENERATOR;
+/// let coset_size = 256;
+///
+/// // build a random polynomial and evaluate it over the 3rd coset of the domain
+/// let p: Vec<BaseElement> = rand_vector(coset_size);
+/// let twiddles = get_twiddles::<BaseElement>(coset_size);
+/// let mut ys = evaluate_poly_on_coset(&p, &twiddles, offset, n, 3);
+///
+/// // interpolate the evaluations into a polynomial
+/// let inv_twiddles = get_inv_twiddles::<BaseElement>(coset_size);
+/// interpolate_poly_on_coset(&mut ys, &inv_twiddles, offset, n, 3);
+///
+/// assert_eq!(p, ys);
+/// ```
+pub fn interpolate_poly_on_coset<B, E>(
+    evaluations: &mut [E],
+    inv_twiddles: &[B],
+    domain_offset: B,
+    domain_size: usize,
+    coset_idx: usize,
+) where
+    B: StarkField,
+    E: FieldElement<BaseField = B>,
+{
+    assert_eq!(
+        evaluations.len(),
+        inv_twiddles.len() * 2,
+        "invalid number of twiddles: expected {} but received {}",
+        evaluations.len() / 2,
+        inv_twiddles.len()
+    );
+    let coset_offset = get_coset_offset(inv_twiddles.len(), domain_offset, domain_size, coset_idx);
+    interpolate_poly_with_offset(evaluations, inv_twiddles, coset_offset);
+}
+
 // RAW FFT ALGORITHM
 // ================================================================================================
 
@@ -585,3 +738,40 @@ fn permute<E: FieldElement>(v: &mut [E]) {
         FftInputs::permute(v);
     }
 }
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the offset of the coset with index `coset_idx` of a domain of size `domain_size`
+/// shifted by `domain_offset`, where the coset size is twice the number of twiddles.
+fn get_coset_offset<B: StarkField>(
+    num_twiddles: usize,
+    domain_offset: B,
+    domain_size: usize,
+    coset_idx: usize,
+) -> B {
+    let coset_size = num_twiddles * 2;
+    assert!(domain_size.is_power_of_two(), "domain size must be a power of 2");
+    assert!(
+        num_twiddles > 0 && coset_size.is_power_of_two(),
+        "coset size must be a power of 2 greater than 1"
+    );
+    assert!(
+        coset_size <= domain_size,
+        "coset size {coset_size} cannot be greater than domain size {domain_size}"
+    );
+    assert!(
+        domain_size.ilog2() <= B::TWO_ADICITY,
+        "multiplicative subgroup of size {domain_size} does not exist in the specified base field"
+    );
+    assert!(
+        coset_idx < domain_size / coset_size,
+        "coset index must be smaller than {}, but was {}",
+        domain_size / coset_size,
+        coset_idx
+    );
+    assert_ne!(domain_offset, B::ZERO, "domain offset cannot be zero");
+
+    let g = B::get_root_of_unity(domain_size.ilog2());
+    g.exp_vartime((coset_idx as u64).into()) * domain_offset
+}
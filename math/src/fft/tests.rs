@@ -72,6 +72,43 @@ fn fft_get_twiddles() {
     assert_eq!(expected, twiddles);
 }
 
+#[test]
+fn fft_evaluate_poly_on_coset() {
+    let n = 256;
+    let offset = BaseElement::GENERATOR;
+    let domain: Vec<BaseElement> = build_domain(n).into_iter().map(|x| x * offset).collect();
+
+    // polynomials shorter than, equal to, and longer than the coset
+    for (poly_size, coset_size) in [(4, 16), (16, 16), (64, 16), (256, 2), (8, 256)] {
+        let p: Vec<BaseElement> = rand_vector(poly_size);
+        let expected = polynom::eval_many(&p, &domain);
+        let twiddles = super::get_twiddles::<BaseElement>(coset_size);
+        let num_cosets = n / coset_size;
+        for i in 0..num_cosets {
+            let actual = super::evaluate_poly_on_coset(&p, &twiddles, offset, n, i);
+            let expected: Vec<BaseElement> =
+                expected.iter().skip(i).step_by(num_cosets).copied().collect();
+            assert_eq!(expected, actual);
+        }
+    }
+}
+
+#[test]
+fn fft_interpolate_poly_on_coset() {
+    let n = 1024;
+    let coset_size = 64;
+    let offset = BaseElement::GENERATOR;
+    let twiddles = super::get_twiddles::<BaseElement>(coset_size);
+    let inv_twiddles = super::get_inv_twiddles::<BaseElement>(coset_size);
+
+    let p: Vec<BaseElement> = rand_vector(coset_size);
+    for i in [0, 1, 7, n / coset_size - 1] {
+        let mut ys = super::evaluate_poly_on_coset(&p, &twiddles, offset, n, i);
+        super::interpolate_poly_on_coset(&mut ys, &inv_twiddles, offset, n, i);
+        assert_eq!(p, ys);
+    }
+}
+
 // HELPER FUNCTIONS
 // ================================================================================================
 
@@ -9,4 +9,10 @@ pub use quadratic::QuadExtension;
 mod cubic;
 pub use cubic::CubeExtension;
 
+mod quartic;
+pub use quartic::QuartExtension;
+
+mod quintic;
+pub use quintic::QuintExtension;
+
 use super::{ExtensibleField, ExtensionOf, FieldElement};
@@ -28,7 +28,8 @@ use super::{ExtensibleField, ExtensionOf, FieldElement};
 /// defined by the implementation of the [ExtensibleField] trait, and α and β are base field
 /// elements.
 #[repr(C)]
-#[derive(Copy, Clone, Debug, PartialEq, Eq, Default)]
+#[derive(Copy, Clone, Debug, Eq, Default)]
+#[cfg_attr(not(feature = "ct"), derive(PartialEq))]
 #[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
 pub struct QuadExtension<B: ExtensibleField<2>>(B, B);
 
@@ -50,6 +51,14 @@ impl<B: ExtensibleField<2>> QuadExtension<B> {
     pub const fn to_base_elements(self) -> [B; 2] {
         [self.0, self.1]
     }
+
+    /// Converts a mutable slice of extension elements into a mutable slice of the underlying base
+    /// field elements.
+    fn slice_as_base_elements_mut(elements: &mut [Self]) -> &mut [B] {
+        let ptr = elements.as_mut_ptr();
+        let len = elements.len() * 2;
+        unsafe { slice::from_raw_parts_mut(ptr as *mut B, len) }
+    }
 }
 
 impl<B: ExtensibleField<2>> FieldElement for QuadExtension<B> {
@@ -79,6 +88,9 @@ impl<B: ExtensibleField<2>> FieldElement for QuadExtension<B> {
 
     #[inline]
     fn inv(self) -> Self {
+        // the computation below maps ZERO to ZERO as well; the early return is skipped when the
+        // `ct` feature is enabled to avoid leaking whether this element is ZERO
+        #[cfg(not(feature = "ct"))]
         if self == Self::ZERO {
             return self;
         }
@@ -99,6 +111,23 @@ impl<B: ExtensibleField<2>> FieldElement for QuadExtension<B> {
         Self(result[0], result[1])
     }
 
+    // BATCH OPERATIONS
+    // --------------------------------------------------------------------------------------------
+
+    fn batch_butterfly(a: &mut [Self], b: &mut [Self]) {
+        // butterflies act on each base element coordinate independently
+        B::batch_butterfly(Self::slice_as_base_elements_mut(a), Self::slice_as_base_elements_mut(b))
+    }
+
+    fn batch_butterfly_twiddle(a: &mut [Self], b: &mut [Self], twiddle: B) {
+        // multiplication by a base field element also acts on each coordinate independently
+        B::batch_butterfly_twiddle(
+            Self::slice_as_base_elements_mut(a),
+            Self::slice_as_base_elements_mut(b),
+            twiddle,
+        )
+    }
+
     // BASE ELEMENT CONVERSIONS
     // --------------------------------------------------------------------------------------------
 
@@ -184,6 +213,19 @@ impl<B: ExtensibleField<2>> fmt::Display for QuadExtension<B> {
     }
 }
 
+// EQUALITY CHECKS
+// ------------------------------------------------------------------------------------------------
+
+/// Compares all coefficients without short-circuiting, so that the comparison does not leak which
+/// coefficient differs.
+#[cfg(feature = "ct")]
+impl<B: ExtensibleField<2>> PartialEq for QuadExtension<B> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        (self.0 == other.0) & (self.1 == other.1)
+    }
+}
+
 // OVERLOADED OPERATORS
 // ------------------------------------------------------------------------------------------------
 
@@ -9,6 +9,10 @@
 //! canonical form using `u128` as the backing type. However, this field was not chosen with any
 //! significant thought given to performance, and the implementations of most operations are
 //! sub-optimal as well.
+//!
+//! By default, arithmetic operations, equality checks, exponentiation and inversion in this field
+//! are variable-time. When the `ct` feature is enabled, these operations run in constant time;
+//! in this case, inversion is computed via Fermat's little theorem.
 
 use alloc::{
     string::{String, ToString},
@@ -52,7 +56,8 @@ const ELEMENT_BYTES: usize = core::mem::size_of::<u128>();
 ///
 /// Internal values are stored in their canonical form in the range [0, M). The backing type is
 /// `u128`.
-#[derive(Copy, Clone, PartialEq, Eq, Default)]
+#[derive(Copy, Clone, Eq, Default)]
+#[cfg_attr(not(feature = "ct"), derive(PartialEq))]
 #[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
 #[cfg_attr(feature = "serde", serde(transparent))]
 pub struct BaseElement(u128);
@@ -191,6 +196,18 @@ impl Display for BaseElement {
     }
 }
 
+// EQUALITY CHECKS
+// ================================================================================================
+
+#[cfg(feature = "ct")]
+impl PartialEq for BaseElement {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        let t = self.0 ^ other.0;
+        ((t | t.wrapping_neg()) >> 127) == 0
+    }
+}
+
 // OVERLOADED OPERATORS
 // ================================================================================================
 
@@ -307,6 +324,31 @@ impl ExtensibleField<3> for BaseElement {
     }
 }
 
+// QUARTIC EXTENSION
+// ================================================================================================
+
+/// Quartic extension for this field is not implemented as quadratic extension already provides
+/// sufficient security level.
+impl ExtensibleField<4> for BaseElement {
+    fn mul(_a: [Self; 4], _b: [Self; 4]) -> [Self; 4] {
+        unimplemented!()
+    }
+
+    #[inline(always)]
+    fn mul_base(_a: [Self; 4], _b: Self) -> [Self; 4] {
+        unimplemented!()
+    }
+
+    #[inline(always)]
+    fn frobenius(_x: [Self; 4]) -> [Self; 4] {
+        unimplemented!()
+    }
+
+    fn is_supported() -> bool {
+        false
+    }
+}
+
 // TYPE CONVERSIONS
 // ================================================================================================
 
@@ -407,6 +449,7 @@ impl Deserializable for BaseElement {
 // ================================================================================================
 
 /// Computes (a + b) % m; a and b are assumed to be valid field elements.
+#[cfg(not(feature = "ct"))]
 fn add(a: u128, b: u128) -> u128 {
     let z = M - b;
     if a < z {
@@ -416,7 +459,15 @@ fn add(a: u128, b: u128) -> u128 {
     }
 }
 
+/// Computes (a + b) % m; a and b are assumed to be valid field elements. Runs in constant time.
+#[cfg(feature = "ct")]
+fn add(a: u128, b: u128) -> u128 {
+    // we compute a + b = a - (m - b)
+    sub(a, M - b)
+}
+
 /// Computes (a - b) % m; a and b are assumed to be valid field elements.
+#[cfg(not(feature = "ct"))]
 fn sub(a: u128, b: u128) -> u128 {
     if a < b {
         M - b + a
@@ -425,48 +476,42 @@ fn sub(a: u128, b: u128) -> u128 {
     }
 }
 
+/// Computes (a - b) % m; a and b are assumed to be valid field elements. Runs in constant time.
+#[cfg(feature = "ct")]
+fn sub(a: u128, b: u128) -> u128 {
+    let (z, borrow) = a.overflowing_sub(b);
+    // the mask is sign-extended to cover all 128 bits
+    z.wrapping_add(M & (ct_mask(borrow as u64) as i64 as u128))
+}
+
 /// Computes (a * b) % m; a and b are assumed to be valid field elements.
 fn mul(a: u128, b: u128) -> u128 {
     let (x0, x1, x2) = mul_128x64(a, (b >> 64) as u64); // x = a * b_hi
-    let (mut x0, mut x1, x2) = mul_reduce(x0, x1, x2); // x = x - (x >> 128) * m
-    if x2 == 1 {
-        // if there was an overflow beyond 128 bits, subtract
-        // modulus from the result to make sure it fits into
-        // 128 bits; this can potentially be removed in favor
-        // of checking overflow later
-        let (t0, t1) = sub_modulus(x0, x1); // x = x - m
-        x0 = t0;
-        x1 = t1;
-    }
+    let (x0, x1, x2) = mul_reduce(x0, x1, x2); // x = x - (x >> 128) * m
+
+    // if there was an overflow beyond 128 bits, subtract modulus from the result to make sure it
+    // fits into 128 bits; this can potentially be removed in favor of checking overflow later
+    let (x0, x1) = sub_modulus_if(x0, x1, x2); // x = x - m
 
     let (y0, y1, y2) = mul_128x64(a, b as u64); // y = a * b_lo
 
-    let (mut y1, carry) = add64_with_carry(y1, x0, 0); // y = y + (x << 64)
-    let (mut y2, y3) = add64_with_carry(y2, x1, carry);
-    if y3 == 1 {
-        // if there was an overflow beyond 192 bits, subtract
-        // modulus * 2^64 from the result to make sure it fits
-        // into 192 bits; this can potentially replace the
-        // previous overflow check (but needs to be proven)
-        let (t0, t1) = sub_modulus(y1, y2); // y = y - (m << 64)
-        y1 = t0;
-        y2 = t1;
-    }
+    let (y1, carry) = add64_with_carry(y1, x0, 0); // y = y + (x << 64)
+    let (y2, y3) = add64_with_carry(y2, x1, carry);
 
-    let (mut z0, mut z1, z2) = mul_reduce(y0, y1, y2); // z = y - (y >> 128) * m
+    // if there was an overflow beyond 192 bits, subtract modulus * 2^64 from the result to make
+    // sure it fits into 192 bits; this can potentially replace the previous overflow check (but
+    // needs to be proven)
+    let (y1, y2) = sub_modulus_if(y1, y2, y3); // y = y - (m << 64)
 
-    // make sure z is smaller than m
-    if z2 == 1 || (z1 == (M >> 64) as u64 && z0 >= (M as u64)) {
-        let (t0, t1) = sub_modulus(z0, z1); // z = z - m
-        z0 = t0;
-        z1 = t1;
-    }
+    let (z0, z1, z2) = mul_reduce(y0, y1, y2); // z = y - (y >> 128) * m
 
-    ((z1 as u128) << 64) + (z0 as u128)
+    // make sure z is smaller than m
+    reduce_192(z0, z1, z2)
 }
 
 /// Computes y such that (x * y) % m = 1 except for when when x = 0; in such a case,
 /// 0 is returned; x is assumed to be a valid field element.
+#[cfg(not(feature = "ct"))]
 fn inv(x: u128) -> u128 {
     if x == 0 {
         return 0;
@@ -562,6 +607,26 @@ fn inv(x: u128) -> u128 {
     a
 }
 
+/// Computes y such that (x * y) % m = 1 except for when when x = 0; in such a case,
+/// 0 is returned; x is assumed to be a valid field element.
+///
+/// The inverse is computed as x^(m - 2) using a fixed sequence of squarings and multiplications,
+/// and thus runs in constant time.
+#[cfg(feature = "ct")]
+fn inv(x: u128) -> u128 {
+    const POWER: u128 = M - 2;
+
+    let mut result = 1;
+    for i in (0..128 - POWER.leading_zeros()).rev() {
+        result = mul(result, result);
+        if (POWER >> i) & 1 == 1 {
+            result = mul(result, x);
+        }
+    }
+
+    result
+}
+
 // HELPER FUNCTIONS
 // ================================================================================================
 
@@ -583,7 +648,10 @@ fn mul_reduce(z0: u64, z1: u64, z2: u64) -> (u64, u64, u64) {
 #[inline]
 fn mul_by_modulus(a: u64) -> (u64, u64, u64) {
     let a_lo = (a as u128).wrapping_mul(M);
+    #[cfg(not(feature = "ct"))]
     let a_hi = if a == 0 { 0 } else { a - 1 };
+    #[cfg(feature = "ct")]
+    let a_hi = a - ((a | a.wrapping_neg()) >> 63);
     (a_lo as u64, (a_lo >> 64) as u64, a_hi)
 }
 
@@ -595,6 +663,52 @@ fn sub_modulus(a_lo: u64, a_hi: u64) -> (u64, u64) {
     (z as u64, (z >> 64) as u64)
 }
 
+/// Subtracts the modulus from a 128-bit value if `flag` is 1; `flag` is assumed to be 0 or 1.
+#[cfg(not(feature = "ct"))]
+#[inline]
+fn sub_modulus_if(a_lo: u64, a_hi: u64, flag: u64) -> (u64, u64) {
+    if flag == 1 {
+        sub_modulus(a_lo, a_hi)
+    } else {
+        (a_lo, a_hi)
+    }
+}
+
+/// Subtracts the modulus from a 128-bit value if `flag` is 1; `flag` is assumed to be 0 or 1.
+/// Runs in constant time.
+#[cfg(feature = "ct")]
+#[inline]
+fn sub_modulus_if(a_lo: u64, a_hi: u64, flag: u64) -> (u64, u64) {
+    let (t_lo, t_hi) = sub_modulus(a_lo, a_hi);
+    let mask = ct_mask(flag);
+    (a_lo ^ (mask & (a_lo ^ t_lo)), a_hi ^ (mask & (a_hi ^ t_hi)))
+}
+
+/// Reduces a value in [0, 2m) range, represented by three 64-bit limbs, to [0, m) range.
+#[cfg(not(feature = "ct"))]
+#[inline]
+fn reduce_192(z0: u64, z1: u64, z2: u64) -> u128 {
+    let (z0, z1) = if z2 == 1 || (z1 == (M >> 64) as u64 && z0 >= (M as u64)) {
+        sub_modulus(z0, z1) // z = z - m
+    } else {
+        (z0, z1)
+    };
+
+    ((z1 as u128) << 64) + (z0 as u128)
+}
+
+/// Reduces a value in [0, 2m) range, represented by three 64-bit limbs, to [0, m) range. Runs in
+/// constant time.
+#[cfg(feature = "ct")]
+#[inline]
+fn reduce_192(z0: u64, z1: u64, z2: u64) -> u128 {
+    // z >= m if and only if z - m does not underflow
+    let (_, _, t2) = sub_192x192(z0, z1, z2, M as u64, (M >> 64) as u64, 0);
+    let (z0, z1) = sub_modulus_if(z0, z1, (t2 >> 63) ^ 1);
+
+    ((z1 as u128) << 64) + (z0 as u128)
+}
+
 #[inline]
 fn sub_192x192(a0: u64, a1: u64, a2: u64, b0: u64, b1: u64, b2: u64) -> (u64, u64, u64) {
     let z0 = (a0 as u128).wrapping_sub(b0 as u128);
@@ -603,6 +717,16 @@ fn sub_192x192(a0: u64, a1: u64, a2: u64, b0: u64, b1: u64, b2: u64) -> (u64, u6
     (z0 as u64, z1 as u64, z2 as u64)
 }
 
+/// Expands a 0/1 flag into a 64-bit mask. The flag goes through [core::hint::black_box()], which
+/// discourages, but does not prevent, the compiler from rewriting selections based on the mask
+/// into branches.
+#[cfg(feature = "ct")]
+#[inline(always)]
+fn ct_mask(flag: u64) -> u64 {
+    0u64.wrapping_sub(core::hint::black_box(flag))
+}
+
+#[cfg(not(feature = "ct"))]
 #[inline]
 fn add_192x192(a0: u64, a1: u64, a2: u64, b0: u64, b1: u64, b2: u64) -> (u64, u64, u64) {
     let z0 = (a0 as u128) + (b0 as u128);
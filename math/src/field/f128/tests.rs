@@ -144,6 +144,26 @@ fn quad_mul_base() {
     assert_eq!(expected, a.mul_base(b0));
 }
 
+// SQUARE ROOTS
+// ================================================================================================
+
+#[test]
+fn sqrt() {
+    assert_eq!(Some(BaseElement::ZERO), BaseElement::ZERO.sqrt());
+    assert_eq!(0, BaseElement::ZERO.legendre());
+    assert_eq!(1, BaseElement::ONE.legendre());
+
+    // a primitive root of unity of order 2^TWO_ADICITY is not a square
+    let g = BaseElement::TWO_ADIC_ROOT_OF_UNITY;
+    assert_eq!(-1, g.legendre());
+    assert_eq!(None, g.sqrt());
+
+    for _ in 0..100 {
+        check_sqrt::<BaseElement>(rand_value());
+        check_sqrt::<QuadExtension<BaseElement>>(rand_value());
+    }
+}
+
 // SERIALIZATION / DESERIALIZATION
 // ================================================================================================
 
@@ -244,3 +264,20 @@ impl BaseElement {
         BaseElement::new(value)
     }
 }
+
+/// Checks that the square root and the Legendre symbol of `a` agree with each other, and that the
+/// square of `a` has a root.
+fn check_sqrt<E: FieldElement>(a: E) {
+    let square = a.square();
+    let root = square.sqrt().expect("square must have a root");
+    assert_eq!(square, root.square());
+    assert_eq!(if a == E::ZERO { 0 } else { 1 }, square.legendre());
+
+    match a.sqrt() {
+        Some(root) => {
+            assert_eq!(a, root.square());
+            assert_ne!(-1, a.legendre());
+        },
+        None => assert_eq!(-1, a.legendre()),
+    }
+}
@@ -0,0 +1,455 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use num_bigint::BigUint;
+use proptest::prelude::*;
+use rand_utils::rand_value;
+
+use super::{
+    AsBytes, BaseElement, DeserializationError, FieldElement, Serializable, StarkField, M,
+};
+use crate::field::{CubeExtension, ExtensionOf, QuadExtension, QuartExtension};
+
+// MANUAL TESTS
+// ================================================================================================
+
+#[test]
+fn add() {
+    // identity
+    let r: BaseElement = rand_value();
+    assert_eq!(r, r + BaseElement::ZERO);
+
+    // test addition within bounds
+    assert_eq!(BaseElement::from(5u8), BaseElement::from(2u8) + BaseElement::from(3u8));
+
+    // test overflow
+    let t = BaseElement::new(M - 1);
+    assert_eq!(BaseElement::ZERO, t + BaseElement::ONE);
+    assert_eq!(BaseElement::ONE, t + BaseElement::from(2u8));
+}
+
+#[test]
+fn sub() {
+    // identity
+    let r: BaseElement = rand_value();
+    assert_eq!(r, r - BaseElement::ZERO);
+
+    // test subtraction within bounds
+    assert_eq!(BaseElement::from(2u8), BaseElement::from(5u8) - BaseElement::from(3u8));
+
+    // test underflow
+    let expected = BaseElement::new(M - 2);
+    assert_eq!(expected, BaseElement::from(3u8) - BaseElement::from(5u8));
+}
+
+#[test]
+fn mul() {
+    // identity
+    let r: BaseElement = rand_value();
+    assert_eq!(BaseElement::ZERO, r * BaseElement::ZERO);
+    assert_eq!(r, r * BaseElement::ONE);
+
+    // test multiplication within bounds
+    assert_eq!(BaseElement::from(15u8), BaseElement::from(5u8) * BaseElement::from(3u8));
+
+    // test overflow
+    let t = BaseElement::new(M - 1);
+    assert_eq!(BaseElement::ONE, t * t);
+    assert_eq!(BaseElement::new(M - 2), t * BaseElement::from(2u8));
+    assert_eq!(BaseElement::new(M - 4), t * BaseElement::from(4u8));
+
+    let t = M.div_ceil(2);
+    assert_eq!(BaseElement::ONE, BaseElement::new(t) * BaseElement::from(2u8));
+}
+
+#[test]
+fn exp() {
+    let a = BaseElement::ZERO;
+    assert_eq!(a.exp(0), BaseElement::ONE);
+    assert_eq!(a.exp(1), BaseElement::ZERO);
+
+    let a = BaseElement::ONE;
+    assert_eq!(a.exp(0), BaseElement::ONE);
+    assert_eq!(a.exp(1), BaseElement::ONE);
+    assert_eq!(a.exp(3), BaseElement::ONE);
+
+    let a: BaseElement = rand_value();
+    assert_eq!(a.exp(3), a * a * a);
+}
+
+#[test]
+fn inv() {
+    // identity
+    assert_eq!(BaseElement::ONE, BaseElement::inv(BaseElement::ONE));
+    assert_eq!(BaseElement::ZERO, BaseElement::inv(BaseElement::ZERO));
+}
+
+#[test]
+fn element_as_int() {
+    let v = u32::MAX;
+    let e = BaseElement::new(v);
+    assert_eq!((v % M) as u64, e.as_int());
+}
+
+#[test]
+fn equals() {
+    let a = BaseElement::ONE;
+    let b = BaseElement::new(M - 1) * BaseElement::new(M - 1);
+
+    // elements are equal
+    assert_eq!(a, b);
+    assert_eq!(a.as_int(), b.as_int());
+    assert_eq!(a.to_bytes(), b.to_bytes());
+
+    // and since internal values are always reduced, so is their internal representation
+    assert_eq!(a.0, b.0);
+    assert_eq!(a.as_bytes(), b.as_bytes());
+}
+
+// QUADRATIC EXTENSION
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn quad_mul_base() {
+    let a = <QuadExtension<BaseElement>>::new(rand_value(), rand_value());
+    let b0 = rand_value();
+    let b = <QuadExtension<BaseElement>>::new(b0, BaseElement::ZERO);
+
+    let expected = a * b;
+    assert_eq!(expected, a.mul_base(b0));
+}
+
+// CUBIC EXTENSION
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn cube_mul_base() {
+    let a = <CubeExtension<BaseElement>>::new(rand_value(), rand_value(), rand_value());
+    let b0 = rand_value();
+    let b = <CubeExtension<BaseElement>>::new(b0, BaseElement::ZERO, BaseElement::ZERO);
+
+    let expected = a * b;
+    assert_eq!(expected, a.mul_base(b0));
+}
+
+// QUARTIC EXTENSION
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn quart_mul() {
+    // identity
+    let r: QuartExtension<BaseElement> = rand_value();
+    assert_eq!(<QuartExtension<BaseElement>>::ZERO, r * <QuartExtension<BaseElement>>::ZERO);
+    assert_eq!(r, r * <QuartExtension<BaseElement>>::ONE);
+
+    // φ^4 = 11
+    let phi = <QuartExtension<BaseElement>>::new(
+        BaseElement::ZERO,
+        BaseElement::ONE,
+        BaseElement::ZERO,
+        BaseElement::ZERO,
+    );
+    assert_eq!(<QuartExtension<BaseElement>>::from(11u8), phi.exp(4));
+
+    // test multiplication with overflow
+    let a = <QuartExtension<BaseElement>>::new(
+        BaseElement::new(M - 1),
+        BaseElement::new(2),
+        BaseElement::new(M - 3),
+        BaseElement::new(4),
+    );
+    let b = <QuartExtension<BaseElement>>::new(
+        BaseElement::new(5),
+        BaseElement::new(M - 6),
+        BaseElement::new(7),
+        BaseElement::new(M - 8),
+    );
+    let expected = <QuartExtension<BaseElement>>::new(
+        BaseElement::new(M - 676),
+        BaseElement::new(588),
+        BaseElement::new(M - 386),
+        BaseElement::new(60),
+    );
+    assert_eq!(expected, a * b);
+}
+
+#[test]
+fn quart_mul_base() {
+    let a =
+        <QuartExtension<BaseElement>>::new(rand_value(), rand_value(), rand_value(), rand_value());
+    let b0: BaseElement = rand_value();
+    let b = <QuartExtension<BaseElement>>::from(b0);
+
+    let expected = a * b;
+    assert_eq!(expected, a.mul_base(b0));
+}
+
+#[test]
+fn quart_frobenius() {
+    // conjugate of an element must be equal to the element raised to the power of the modulus
+    let a: QuartExtension<BaseElement> = rand_value();
+    assert_eq!(a.exp(BaseElement::MODULUS), a.conjugate());
+}
+
+// ROOTS OF UNITY
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn get_root_of_unity() {
+    let root_27 = BaseElement::get_root_of_unity(27);
+    assert_eq!(BaseElement::TWO_ADIC_ROOT_OF_UNITY, root_27);
+    assert_eq!(BaseElement::ONE, root_27.exp(1u64 << 27));
+
+    let root_26 = BaseElement::get_root_of_unity(26);
+    let expected = root_27.exp(2);
+    assert_eq!(expected, root_26);
+    assert_eq!(BaseElement::ONE, root_26.exp(1u64 << 26));
+}
+
+// SERIALIZATION AND DESERIALIZATION
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn try_from_slice() {
+    let bytes = vec![1, 0, 0, 0];
+    let result = BaseElement::try_from(bytes.as_slice());
+    assert!(result.is_ok());
+    assert_eq!(1, result.unwrap().as_int());
+
+    let bytes = vec![1, 0, 0];
+    let result = BaseElement::try_from(bytes.as_slice());
+    assert!(result.is_err());
+
+    let bytes = vec![1, 0, 0, 0, 0];
+    let result = BaseElement::try_from(bytes.as_slice());
+    assert!(result.is_err());
+
+    let bytes = vec![255, 255, 255, 255];
+    let result = BaseElement::try_from(bytes.as_slice());
+    assert!(result.is_err());
+}
+
+#[test]
+fn elements_as_bytes() {
+    let source = vec![
+        BaseElement::new(1),
+        BaseElement::new(2),
+        BaseElement::new(3),
+        BaseElement::new(4),
+    ];
+
+    let mut expected = vec![];
+    expected.extend_from_slice(&source[0].0.to_le_bytes());
+    expected.extend_from_slice(&source[1].0.to_le_bytes());
+    expected.extend_from_slice(&source[2].0.to_le_bytes());
+    expected.extend_from_slice(&source[3].0.to_le_bytes());
+
+    assert_eq!(expected, BaseElement::elements_as_bytes(&source));
+}
+
+#[test]
+fn bytes_as_elements() {
+    let elements = vec![
+        BaseElement::new(1),
+        BaseElement::new(2),
+        BaseElement::new(3),
+        BaseElement::new(4),
+    ];
+
+    let mut bytes = vec![];
+    bytes.extend_from_slice(&elements[0].0.to_le_bytes());
+    bytes.extend_from_slice(&elements[1].0.to_le_bytes());
+    bytes.extend_from_slice(&elements[2].0.to_le_bytes());
+    bytes.extend_from_slice(&elements[3].0.to_le_bytes());
+    bytes.extend_from_slice(&BaseElement::new(5).0.to_le_bytes());
+
+    let result = unsafe { BaseElement::bytes_as_elements(&bytes[..16]) };
+    assert!(result.is_ok());
+    assert_eq!(elements, result.unwrap());
+
+    let result = unsafe { BaseElement::bytes_as_elements(&bytes[..17]) };
+    assert!(matches!(result, Err(DeserializationError::InvalidValue(_))));
+
+    let result = unsafe { BaseElement::bytes_as_elements(&bytes[1..17]) };
+    assert!(matches!(result, Err(DeserializationError::InvalidValue(_))));
+}
+
+// SQUARE ROOTS
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn sqrt() {
+    assert_eq!(Some(BaseElement::ZERO), BaseElement::ZERO.sqrt());
+    assert_eq!(0, BaseElement::ZERO.legendre());
+    assert_eq!(1, BaseElement::ONE.legendre());
+
+    // a primitive root of unity of order 2^TWO_ADICITY is not a square
+    let g = BaseElement::TWO_ADIC_ROOT_OF_UNITY;
+    assert_eq!(-1, g.legendre());
+    assert_eq!(None, g.sqrt());
+    let root = g.square().sqrt().unwrap();
+    assert!(root == g || root == -g);
+}
+
+// RANDOMIZED TESTS
+// ================================================================================================
+
+proptest! {
+
+    #[test]
+    fn add_proptest(a in any::<u32>(), b in any::<u32>()) {
+        let v1 = BaseElement::new(a);
+        let v2 = BaseElement::new(b);
+        let result = v1 + v2;
+
+        let expected = ((a % M) as u64 + (b % M) as u64) % M as u64;
+        prop_assert_eq!(expected, result.as_int());
+    }
+
+    #[test]
+    fn sub_proptest(a in any::<u32>(), b in any::<u32>()) {
+        let v1 = BaseElement::new(a);
+        let v2 = BaseElement::new(b);
+        let result = v1 - v2;
+
+        let a = a % M;
+        let b = b % M;
+        let expected = if a < b { M - b + a } else { a - b };
+
+        prop_assert_eq!(expected as u64, result.as_int());
+    }
+
+    #[test]
+    fn mul_proptest(a in any::<u32>(), b in any::<u32>()) {
+        let v1 = BaseElement::new(a);
+        let v2 = BaseElement::new(b);
+        let result = v1 * v2;
+
+        let expected = ((a as u64) * (b as u64)) % M as u64;
+        prop_assert_eq!(expected, result.as_int());
+    }
+
+    #[test]
+    fn exp_proptest(a in any::<u32>(), b in any::<u64>()) {
+        let result = BaseElement::new(a).exp(b);
+
+        let b = BigUint::from(b);
+        let m = BigUint::from(M);
+        let expected = BigUint::from(a).modpow(&b, &m).to_u64_digits().first().copied().unwrap_or(0);
+        prop_assert_eq!(expected, result.as_int());
+    }
+
+    #[test]
+    fn inv_proptest(a in any::<u32>()) {
+        let a = BaseElement::new(a);
+        let b = a.inv();
+
+        let expected = if a == BaseElement::ZERO { BaseElement::ZERO } else { BaseElement::ONE };
+        prop_assert_eq!(expected, a * b);
+    }
+
+    #[test]
+    fn element_as_int_proptest(a in any::<u32>()) {
+        let e = BaseElement::new(a);
+        prop_assert_eq!((a % M) as u64, e.as_int());
+    }
+
+    // QUADRATIC EXTENSION
+    // --------------------------------------------------------------------------------------------
+    #[test]
+    fn quad_mul_inv_proptest(a0 in any::<u32>(), a1 in any::<u32>()) {
+        let a = QuadExtension::<BaseElement>::new(BaseElement::new(a0), BaseElement::new(a1));
+        let b = a.inv();
+
+        let expected = if a == QuadExtension::<BaseElement>::ZERO {
+            QuadExtension::<BaseElement>::ZERO
+        } else {
+            QuadExtension::<BaseElement>::ONE
+        };
+        prop_assert_eq!(expected, a * b);
+    }
+
+    // CUBIC EXTENSION
+    // --------------------------------------------------------------------------------------------
+    #[test]
+    fn cube_mul_inv_proptest(a0 in any::<u32>(), a1 in any::<u32>(), a2 in any::<u32>()) {
+        let a = CubeExtension::<BaseElement>::new(BaseElement::new(a0), BaseElement::new(a1), BaseElement::new(a2));
+        let b = a.inv();
+
+        let expected = if a == CubeExtension::<BaseElement>::ZERO {
+            CubeExtension::<BaseElement>::ZERO
+        } else {
+            CubeExtension::<BaseElement>::ONE
+        };
+        prop_assert_eq!(expected, a * b);
+    }
+
+    // QUARTIC EXTENSION
+    // --------------------------------------------------------------------------------------------
+    #[test]
+    fn quart_mul_inv_proptest(a0 in any::<u32>(), a1 in any::<u32>(), a2 in any::<u32>(), a3 in any::<u32>()) {
+        let a = QuartExtension::<BaseElement>::new(
+            BaseElement::new(a0),
+            BaseElement::new(a1),
+            BaseElement::new(a2),
+            BaseElement::new(a3),
+        );
+        let b = a.inv();
+
+        let expected = if a == QuartExtension::<BaseElement>::ZERO {
+            QuartExtension::<BaseElement>::ZERO
+        } else {
+            QuartExtension::<BaseElement>::ONE
+        };
+        prop_assert_eq!(expected, a * b);
+    }
+
+    // SQUARE ROOTS
+    // --------------------------------------------------------------------------------------------
+    #[test]
+    fn sqrt_proptest(a in any::<u32>()) {
+        check_sqrt(BaseElement::new(a));
+    }
+
+    #[test]
+    fn quad_sqrt_proptest(a0 in any::<u32>(), a1 in any::<u32>()) {
+        check_sqrt(QuadExtension::<BaseElement>::new(BaseElement::new(a0), BaseElement::new(a1)));
+    }
+
+    #[test]
+    fn cube_sqrt_proptest(a0 in any::<u32>(), a1 in any::<u32>(), a2 in any::<u32>()) {
+        check_sqrt(CubeExtension::<BaseElement>::new(BaseElement::new(a0), BaseElement::new(a1), BaseElement::new(a2)));
+    }
+
+    #[test]
+    fn quart_sqrt_proptest(a0 in any::<u32>(), a1 in any::<u32>(), a2 in any::<u32>(), a3 in any::<u32>()) {
+        check_sqrt(QuartExtension::<BaseElement>::new(
+            BaseElement::new(a0),
+            BaseElement::new(a1),
+            BaseElement::new(a2),
+            BaseElement::new(a3),
+        ));
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Checks that the square root and the Legendre symbol of `a` agree with each other, and that the
+/// square of `a` has a root.
+fn check_sqrt<E: FieldElement>(a: E) {
+    let square = a.square();
+    let root = square.sqrt().expect("square must have a root");
+    assert_eq!(square, root.square());
+    assert_eq!(if a == E::ZERO { 0 } else { 1 }, square.legendre());
+
+    match a.sqrt() {
+        Some(root) => {
+            assert_eq!(a, root.square());
+            assert_ne!(-1, a.legendre());
+        },
+        None => assert_eq!(-1, a.legendre()),
+    }
+}
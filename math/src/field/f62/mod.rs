@@ -8,6 +8,11 @@
 //! All operations in this field are implemented using Montgomery arithmetic. It supports very
 //! fast modular arithmetic including branchless multiplication and addition. Base elements are
 //! stored in the Montgomery form using `u64` as the backing type.
+//!
+//! Multiplication, addition and doubling are implemented without branches. Subtraction, equality
+//! checks, [as_int()](StarkField::as_int), exponentiation and inversion are variable-time unless
+//! the `ct` feature is enabled; with `ct`, inversion is computed via Fermat's little theorem, and
+//! all of these operations run in constant time.
 
 use alloc::{
     string::{String, ToString},
@@ -42,6 +47,7 @@ const M: u64 = 4611624995532046337;
 const R2: u64 = 630444561284293700;
 
 /// 2^192 mod M; this is used during element inversion.
+#[cfg(not(feature = "ct"))]
 const R3: u64 = 732984146687909319;
 
 /// -M^{-1} mod 2^64; this is used during element multiplication.
@@ -99,6 +105,7 @@ impl FieldElement for BaseElement {
         Self(z - q)
     }
 
+    #[cfg(not(feature = "ct"))]
     fn exp(self, power: Self::PositiveInteger) -> Self {
         let mut b = self;
 
@@ -119,6 +126,22 @@ impl FieldElement for BaseElement {
         r
     }
 
+    #[cfg(feature = "ct")]
+    fn exp(self, power: Self::PositiveInteger) -> Self {
+        let mut b: Self;
+        let mut r = Self::ONE;
+        for i in (0..64).rev() {
+            r = r.square();
+            b = r;
+            b *= self;
+            // Constant-time branching
+            let mask = ct_mask((power >> i) & 1);
+            r.0 ^= mask & (r.0 ^ b.0);
+        }
+
+        r
+    }
+
     fn inv(self) -> Self {
         BaseElement(inv(self.0))
     }
@@ -239,7 +262,7 @@ impl PartialEq for BaseElement {
     fn eq(&self, other: &Self) -> bool {
         // since either of the elements can be in [0, 2M) range, we normalize them first to be
         // in [0, M) range and then compare them.
-        normalize(self.0) == normalize(other.0)
+        equals(normalize(self.0), normalize(other.0))
     }
 }
 
@@ -389,6 +412,31 @@ impl ExtensibleField<3> for BaseElement {
     }
 }
 
+// QUARTIC EXTENSION
+// ================================================================================================
+
+/// Quartic extension for this field is not implemented as cubic extension already provides sufficient
+/// security level.
+impl ExtensibleField<4> for BaseElement {
+    fn mul(_a: [Self; 4], _b: [Self; 4]) -> [Self; 4] {
+        unimplemented!()
+    }
+
+    #[inline(always)]
+    fn mul_base(_a: [Self; 4], _b: Self) -> [Self; 4] {
+        unimplemented!()
+    }
+
+    #[inline(always)]
+    fn frobenius(_x: [Self; 4]) -> [Self; 4] {
+        unimplemented!()
+    }
+
+    fn is_supported() -> bool {
+        false
+    }
+}
+
 // TYPE CONVERSIONS
 // ================================================================================================
 
@@ -544,6 +592,7 @@ fn add(a: u64, b: u64) -> u64 {
 
 /// Computes (a - b) reduced by M such that the output is in [0, 2M) range; a and b are assumed to
 /// be in [0, 2M).
+#[cfg(not(feature = "ct"))]
 #[inline(always)]
 fn sub(a: u64, b: u64) -> u64 {
     if a < b {
@@ -553,6 +602,15 @@ fn sub(a: u64, b: u64) -> u64 {
     }
 }
 
+/// Computes (a - b) reduced by M such that the output is in [0, 2M) range; a and b are assumed to
+/// be in [0, 2M). Runs in constant time.
+#[cfg(feature = "ct")]
+#[inline(always)]
+fn sub(a: u64, b: u64) -> u64 {
+    let (z, borrow) = a.overflowing_sub(b);
+    z.wrapping_add((2 * M) & ct_mask(borrow as u64))
+}
+
 /// Computes (a * b) reduced by M such that the output is in [0, 2M) range; a and b are assumed to
 /// be in [0, 2M).
 #[inline(always)]
@@ -565,6 +623,7 @@ const fn mul(a: u64, b: u64) -> u64 {
 
 /// Computes y such that (x * y) % M = 1 except for when when x = 0; in such a case, 0 is returned;
 /// x is assumed to in [0, 2M) range, and the output will also be in [0, 2M) range.
+#[cfg(not(feature = "ct"))]
 #[inline(always)]
 #[allow(clippy::many_single_char_names)]
 fn inv(x: u64) -> u64 {
@@ -613,10 +672,32 @@ fn inv(x: u64) -> u64 {
     mul(a as u64, R3)
 }
 
+/// Computes y such that (x * y) % M = 1 except for when when x = 0; in such a case, 0 is returned;
+/// x is assumed to in [0, 2M) range, and the output will also be in [0, 2M) range.
+///
+/// The inverse is computed as x^(M - 2) using a fixed sequence of squarings and multiplications,
+/// and thus runs in constant time.
+#[cfg(feature = "ct")]
+#[inline(always)]
+fn inv(x: u64) -> u64 {
+    const POWER: u64 = M - 2;
+
+    let mut result = BaseElement::ONE.0;
+    for i in (0..64 - POWER.leading_zeros()).rev() {
+        result = mul(result, result);
+        if (POWER >> i) & 1 == 1 {
+            result = mul(result, x);
+        }
+    }
+
+    result
+}
+
 // HELPER FUNCTIONS
 // ================================================================================================
 
 /// Reduces any value in [0, 2M) range to [0, M) range
+#[cfg(not(feature = "ct"))]
 #[inline(always)]
 fn normalize(value: u64) -> u64 {
     if value >= M {
@@ -625,3 +706,35 @@ fn normalize(value: u64) -> u64 {
         value
     }
 }
+
+/// Reduces any value in [0, 2M) range to [0, M) range. Runs in constant time.
+#[cfg(feature = "ct")]
+#[inline(always)]
+fn normalize(value: u64) -> u64 {
+    let (z, borrow) = value.overflowing_sub(M);
+    z.wrapping_add(M & ct_mask(borrow as u64))
+}
+
+/// Test of equality between two values in [0, M) range.
+#[cfg(not(feature = "ct"))]
+#[inline(always)]
+fn equals(lhs: u64, rhs: u64) -> bool {
+    lhs == rhs
+}
+
+/// Test of equality between two values in [0, M) range. Runs in constant time.
+#[cfg(feature = "ct")]
+#[inline(always)]
+fn equals(lhs: u64, rhs: u64) -> bool {
+    let t = lhs ^ rhs;
+    ((t | t.wrapping_neg()) >> 63) == 0
+}
+
+/// Returns 2^64 - 1 if `flag` is 1 and 0 otherwise. The flag is passed through
+/// [core::hint::black_box()] so that LLVM is less likely to turn masked selections back into
+/// conditional jumps; `black_box()` is only a hint and not a guaranteed barrier.
+#[cfg(feature = "ct")]
+#[inline(always)]
+fn ct_mask(flag: u64) -> u64 {
+    0u64.wrapping_sub(core::hint::black_box(flag))
+}
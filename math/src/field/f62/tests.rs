@@ -278,6 +278,23 @@ fn bytes_as_elements() {
     assert!(matches!(result, Err(DeserializationError::InvalidValue(_))));
 }
 
+// SQUARE ROOTS
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn sqrt() {
+    assert_eq!(Some(BaseElement::ZERO), BaseElement::ZERO.sqrt());
+    assert_eq!(0, BaseElement::ZERO.legendre());
+    assert_eq!(1, BaseElement::ONE.legendre());
+
+    // a primitive root of unity of order 2^TWO_ADICITY is not a square
+    let g = BaseElement::TWO_ADIC_ROOT_OF_UNITY;
+    assert_eq!(-1, g.legendre());
+    assert_eq!(None, g.sqrt());
+    let root = g.square().sqrt().unwrap();
+    assert!(root == g || root == -g);
+}
+
 // RANDOMIZED TESTS
 // ================================================================================================
 
@@ -370,4 +387,45 @@ proptest! {
         };
         prop_assert_eq!(expected, a * b);
     }
+
+    // SQUARE ROOTS
+    // --------------------------------------------------------------------------------------------
+    #[test]
+    fn sqrt_proptest(a in any::<u64>()) {
+        check_sqrt(BaseElement::new(a));
+    }
+
+    #[test]
+    fn quad_sqrt_proptest(a0 in any::<u64>(), a1 in any::<u64>()) {
+        check_sqrt(QuadExtension::<BaseElement>::new(BaseElement::new(a0), BaseElement::new(a1)));
+    }
+
+    #[test]
+    fn cube_sqrt_proptest(a0 in any::<u64>(), a1 in any::<u64>(), a2 in any::<u64>()) {
+        check_sqrt(CubeExtension::<BaseElement>::new(
+            BaseElement::new(a0),
+            BaseElement::new(a1),
+            BaseElement::new(a2),
+        ));
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Checks that the square root and the Legendre symbol of `a` agree with each other, and that the
+/// square of `a` has a root.
+fn check_sqrt<E: FieldElement>(a: E) {
+    let square = a.square();
+    let root = square.sqrt().expect("square must have a root");
+    assert_eq!(square, root.square());
+    assert_eq!(if a == E::ZERO { 0 } else { 1 }, square.legendre());
+
+    match a.sqrt() {
+        Some(root) => {
+            assert_eq!(a, root.square());
+            assert_ne!(-1, a.legendre());
+        },
+        None => assert_eq!(-1, a.legendre()),
+    }
 }
@@ -6,7 +6,12 @@
 //! An implementation of a 64-bit STARK-friendly prime field with modulus $2^{64} - 2^{32} + 1$
 //! using Montgomery representation.
 //!
-//! Our implementation follows <https://eprint.iacr.org/2022/274.pdf> and is constant-time.
+//! Our implementation follows <https://eprint.iacr.org/2022/274.pdf> and is constant-time at the
+//! source level. When the `ct` feature is enabled, the flags of the final corrections in addition,
+//! subtraction, doubling, multiplication and [as_int()](StarkField::as_int) are additionally
+//! passed through [core::hint::black_box()], which makes it less likely that the compiler turns
+//! these corrections into branches when the operations are inlined into loops. This is a
+//! best-effort measure: `black_box()` does not guarantee anything about the generated code.
 //!
 //! This field supports very fast modular arithmetic and has a number of other attractive
 //! properties, including:
@@ -36,6 +41,9 @@ use utils::{
 
 use super::{ExtensibleField, FieldElement, StarkField};
 
+mod packed;
+pub use packed::PackedBaseElement;
+
 #[cfg(test)]
 mod tests;
 
@@ -61,6 +69,7 @@ const ELEMENT_BYTES: usize = core::mem::size_of::<u64>();
 #[derive(Copy, Clone, Default)]
 #[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
 #[cfg_attr(feature = "serde", serde(try_from = "u64", into = "u64"))]
+#[repr(transparent)]
 pub struct BaseElement(u64);
 
 impl BaseElement {
@@ -133,7 +142,7 @@ impl FieldElement for BaseElement {
     fn double(self) -> Self {
         let ret = (self.0 as u128) << 1;
         let (result, over) = (ret as u64, (ret >> 64) as u64);
-        Self(result.wrapping_sub(M * over))
+        Self(result.wrapping_sub(M & ct_mask(over)))
     }
 
     #[inline]
@@ -145,7 +154,7 @@ impl FieldElement for BaseElement {
             b = r;
             b *= self;
             // Constant-time branching
-            let mask = -(((power >> i) & 1 == 1) as i64) as u64;
+            let mask = ct_mask((power >> i) & 1);
             r.0 ^= mask & (r.0 ^ b.0);
         }
 
@@ -188,6 +197,21 @@ impl FieldElement for BaseElement {
         Self(self.0)
     }
 
+    // BATCH OPERATIONS
+    // --------------------------------------------------------------------------------------------
+
+    fn batch_butterfly(a: &mut [Self], b: &mut [Self]) {
+        packed::butterfly(a, b)
+    }
+
+    fn batch_butterfly_twiddle(a: &mut [Self], b: &mut [Self], twiddle: Self) {
+        packed::butterfly_twiddle(a, b, twiddle)
+    }
+
+    fn batch_mul_acc(a: &mut [Self], b: &[Self], c: Self) {
+        packed::mul_acc(a, b, c)
+    }
+
     // BASE ELEMENT CONVERSIONS
     // --------------------------------------------------------------------------------------------
 
@@ -272,7 +296,8 @@ impl StarkField for BaseElement {
 
     #[inline]
     fn as_int(&self) -> Self::PositiveInteger {
-        mont_to_int(self.0)
+        let (r, c) = mont_to_int_unadjusted(self.0);
+        r.wrapping_sub(ct_mask(c as u64) >> 32)
     }
 }
 
@@ -319,8 +344,8 @@ impl Add for BaseElement {
     fn add(self, rhs: Self) -> Self {
         // We compute a + b = a - (p - b).
         let (x1, c1) = self.0.overflowing_sub(M - rhs.0);
-        let adj = 0u32.wrapping_sub(c1 as u32);
-        Self(x1.wrapping_sub(adj as u64))
+        let adj = ct_mask(c1 as u64) >> 32;
+        Self(x1.wrapping_sub(adj))
     }
 }
 
@@ -338,8 +363,8 @@ impl Sub for BaseElement {
     #[allow(clippy::suspicious_arithmetic_impl)]
     fn sub(self, rhs: Self) -> Self {
         let (x1, c1) = self.0.overflowing_sub(rhs.0);
-        let adj = 0u32.wrapping_sub(c1 as u32);
-        Self(x1.wrapping_sub(adj as u64))
+        let adj = ct_mask(c1 as u64) >> 32;
+        Self(x1.wrapping_sub(adj))
     }
 }
 
@@ -355,7 +380,8 @@ impl Mul for BaseElement {
 
     #[inline]
     fn mul(self, rhs: Self) -> Self {
-        Self(mont_red_cst((self.0 as u128) * (rhs.0 as u128)))
+        let (r, c) = mont_red_unadjusted((self.0 as u128) * (rhs.0 as u128));
+        Self(r.wrapping_sub(ct_mask(c as u64) >> 32))
     }
 }
 
@@ -498,6 +524,78 @@ impl ExtensibleField<3> for BaseElement {
     }
 }
 
+// QUARTIC EXTENSION
+// ================================================================================================
+
+/// Quartic extension for this field is not implemented as cubic extension already provides sufficient
+/// security level.
+impl ExtensibleField<4> for BaseElement {
+    fn mul(_a: [Self; 4], _b: [Self; 4]) -> [Self; 4] {
+        unimplemented!()
+    }
+
+    #[inline(always)]
+    fn mul_base(_a: [Self; 4], _b: Self) -> [Self; 4] {
+        unimplemented!()
+    }
+
+    #[inline(always)]
+    fn frobenius(_x: [Self; 4]) -> [Self; 4] {
+        unimplemented!()
+    }
+
+    fn is_supported() -> bool {
+        false
+    }
+}
+
+// QUINTIC EXTENSION
+// ================================================================================================
+
+/// Defines a quintic extension of the base field over an irreducible polynomial x<sup>5</sup> - 3.
+/// Thus, an extension element is defined as α + β * φ + γ * φ^2 + δ * φ^3 + ε * φ^4, where φ is a
+/// root of this polynomial, and α, β, γ, δ and ε are base field elements.
+///
+/// This is the field over which the [EcGFp5](crate::curves::ecgfp5) curve is defined.
+impl ExtensibleField<5> for BaseElement {
+    #[inline(always)]
+    fn mul(a: [Self; 5], b: [Self; 5]) -> [Self; 5] {
+        // performs schoolbook multiplication and then reduces the result using φ^5 = 3; this
+        // requires 25 multiplications in the base field.
+        let c0 = a[0] * b[0];
+        let c1 = a[0] * b[1] + a[1] * b[0];
+        let c2 = a[0] * b[2] + a[1] * b[1] + a[2] * b[0];
+        let c3 = a[0] * b[3] + a[1] * b[2] + a[2] * b[1] + a[3] * b[0];
+        let c4 = a[0] * b[4] + a[1] * b[3] + a[2] * b[2] + a[3] * b[1] + a[4] * b[0];
+        let c5 = a[1] * b[4] + a[2] * b[3] + a[3] * b[2] + a[4] * b[1];
+        let c6 = a[2] * b[4] + a[3] * b[3] + a[4] * b[2];
+        let c7 = a[3] * b[4] + a[4] * b[3];
+        let c8 = a[4] * b[4];
+
+        [c0 + mul_by_3(c5), c1 + mul_by_3(c6), c2 + mul_by_3(c7), c3 + mul_by_3(c8), c4]
+    }
+
+    #[inline(always)]
+    fn mul_base(a: [Self; 5], b: Self) -> [Self; 5] {
+        // multiplying an extension field element by a base field element requires just 5
+        // multiplications in the base field.
+        [a[0] * b, a[1] * b, a[2] * b, a[3] * b, a[4] * b]
+    }
+
+    #[inline(always)]
+    fn frobenius(x: [Self; 5]) -> [Self; 5] {
+        // φ^p = 3^((p - 1) / 5) * φ, and thus the i-th coefficient is multiplied by the i-th power
+        // of the primitive 5th root of unity 3^((p - 1) / 5)
+        [
+            x[0],
+            Self::new(1041288259238279555) * x[1],
+            Self::new(15820824984080659046) * x[2],
+            Self::new(211587555138949697) * x[3],
+            Self::new(1373043270956696022) * x[4],
+        ]
+    }
+}
+
 // TYPE CONVERSIONS
 // ================================================================================================
 
@@ -684,6 +782,13 @@ impl Deserializable for BaseElement {
 // HELPER FUNCTIONS
 // ================================================================================================
 
+/// Multiplies the provided value by 3, which is the non-residue defining the quintic extension of
+/// this field.
+#[inline(always)]
+fn mul_by_3(x: BaseElement) -> BaseElement {
+    x.double() + x
+}
+
 /// Squares the base N number of times and multiplies the result by the tail value.
 #[inline(always)]
 fn exp_acc<const N: usize>(base: BaseElement, tail: BaseElement) -> BaseElement {
@@ -712,6 +817,14 @@ const fn mont_red_var(x: u128) -> u64 {
 /// Montgomery reduction (constant time)
 #[inline(always)]
 const fn mont_red_cst(x: u128) -> u64 {
+    let (r, c) = mont_red_unadjusted(x);
+    r.wrapping_sub(0u32.wrapping_sub(c as u32) as u64)
+}
+
+/// Montgomery reduction without the final adjustment; returns the reduced value together with a
+/// flag indicating whether 2^32 - 1 needs to be subtracted from it.
+#[inline(always)]
+const fn mont_red_unadjusted(x: u128) -> (u64, bool) {
     // See reference above for a description of the following implementation.
     let xl = x as u64;
     let xh = (x >> 64) as u64;
@@ -719,8 +832,7 @@ const fn mont_red_cst(x: u128) -> u64 {
 
     let b = a.wrapping_sub(a >> 32).wrapping_sub(e as u64);
 
-    let (r, c) = xh.overflowing_sub(b);
-    r.wrapping_sub(0u32.wrapping_sub(c as u32) as u64)
+    xh.overflowing_sub(b)
 }
 
 // Converts a field element in Montgomery form to canonical form. That is, given x, it computes
@@ -729,11 +841,18 @@ const fn mont_red_cst(x: u128) -> u64 {
 // use this to simplify mont_red_cst in this case.
 #[inline(always)]
 const fn mont_to_int(x: u64) -> u64 {
+    let (r, c) = mont_to_int_unadjusted(x);
+    r.wrapping_sub(0u32.wrapping_sub(c as u32) as u64)
+}
+
+/// Same as [mont_to_int()] but without the final adjustment; returns the converted value together
+/// with a flag indicating whether 2^32 - 1 needs to be subtracted from it.
+#[inline(always)]
+const fn mont_to_int_unadjusted(x: u64) -> (u64, bool) {
     let (a, e) = x.overflowing_add(x << 32);
     let b = a.wrapping_sub(a >> 32).wrapping_sub(e as u64);
 
-    let (r, c) = 0u64.overflowing_sub(b);
-    r.wrapping_sub(0u32.wrapping_sub(c as u32) as u64)
+    0u64.overflowing_sub(b)
 }
 
 /// Test of equality between two BaseField elements; return value is
@@ -743,3 +862,13 @@ fn equals(lhs: u64, rhs: u64) -> u64 {
     let t = lhs ^ rhs;
     !((((t | t.wrapping_neg()) as i64) >> 63) as u64)
 }
+
+/// Returns a mask with all bits set if `flag` is 1, or 0 if `flag` is 0. When the `ct` feature is
+/// enabled, the flag is passed through [core::hint::black_box()] to discourage the optimizer from
+/// compiling selections based on the mask into branches; this is not guaranteed to work.
+#[inline(always)]
+fn ct_mask(flag: u64) -> u64 {
+    #[cfg(feature = "ct")]
+    let flag = core::hint::black_box(flag);
+    0u64.wrapping_sub(flag)
+}
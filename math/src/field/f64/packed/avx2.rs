@@ -0,0 +1,174 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+//! AVX2 implementation of packed arithmetic for the 64-bit field.
+//!
+//! Each 256-bit register holds four field elements in Montgomery form. The arithmetic below
+//! mirrors the scalar implementation lane-wise: AVX2 has no unsigned 64-bit comparison, so
+//! comparisons are done by flipping the sign bits of both operands and comparing them as signed
+//! integers, and 64x64-bit multiplications are assembled from four 32x32-bit products.
+
+use core::arch::x86_64::*;
+
+use super::{BaseElement, PackedBaseElement};
+
+// CONSTANTS
+// ================================================================================================
+
+/// Field modulus = 2^64 - 2^32 + 1
+const M: i64 = 0xffffffff00000001u64 as i64;
+
+/// 2^64 mod M = 2^32 - 1
+const EPSILON: i64 = 0xffffffff;
+
+/// Mask for the most significant bit of a 64-bit lane.
+const SIGN_BIT: i64 = i64::MIN;
+
+// PUBLIC FUNCTIONS
+// ================================================================================================
+
+/// Returns true if the current CPU supports AVX2 instructions.
+#[inline]
+pub fn is_available() -> bool {
+    std::is_x86_feature_detected!("avx2")
+}
+
+/// Sets (a_i, b_i) = (a_i + b_i, a_i - b_i) for all i.
+///
+/// # Safety
+/// The caller must ensure that the current CPU supports AVX2 instructions.
+#[target_feature(enable = "avx2")]
+pub unsafe fn butterfly(a: &mut [PackedBaseElement], b: &mut [PackedBaseElement]) {
+    for (a, b) in a.iter_mut().zip(b.iter_mut()) {
+        let x = load(a);
+        let y = load(b);
+        store(a, add(x, y));
+        store(b, sub(x, y));
+    }
+}
+
+/// Sets (a_i, b_i) = (a_i + b_i * twiddle, a_i - b_i * twiddle) for all i.
+///
+/// # Safety
+/// The caller must ensure that the current CPU supports AVX2 instructions.
+#[target_feature(enable = "avx2")]
+pub unsafe fn butterfly_twiddle(
+    a: &mut [PackedBaseElement],
+    b: &mut [PackedBaseElement],
+    twiddle: BaseElement,
+) {
+    let twiddle = _mm256_set1_epi64x(twiddle.inner() as i64);
+    for (a, b) in a.iter_mut().zip(b.iter_mut()) {
+        let x = load(a);
+        let y = mul(load(b), twiddle);
+        store(a, add(x, y));
+        store(b, sub(x, y));
+    }
+}
+
+/// Sets a_i = a_i + b_i * c for all i.
+///
+/// # Safety
+/// The caller must ensure that the current CPU supports AVX2 instructions.
+#[target_feature(enable = "avx2")]
+pub unsafe fn mul_acc(a: &mut [PackedBaseElement], b: &[PackedBaseElement], c: BaseElement) {
+    let c = _mm256_set1_epi64x(c.inner() as i64);
+    for (a, b) in a.iter_mut().zip(b) {
+        let x = load(a);
+        let y = mul(load(b), c);
+        store(a, add(x, y));
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+#[inline]
+#[target_feature(enable = "avx2")]
+unsafe fn load(value: &PackedBaseElement) -> __m256i {
+    _mm256_loadu_si256(value as *const PackedBaseElement as *const __m256i)
+}
+
+#[inline]
+#[target_feature(enable = "avx2")]
+unsafe fn store(dest: &mut PackedBaseElement, value: __m256i) {
+    _mm256_storeu_si256(dest as *mut PackedBaseElement as *mut __m256i, value)
+}
+
+/// Returns a lane mask which is all ones where x < y (as unsigned integers) and zero otherwise.
+#[inline]
+#[target_feature(enable = "avx2")]
+unsafe fn lt_u64(x: __m256i, y: __m256i) -> __m256i {
+    let sign_bit = _mm256_set1_epi64x(SIGN_BIT);
+    _mm256_cmpgt_epi64(_mm256_xor_si256(y, sign_bit), _mm256_xor_si256(x, sign_bit))
+}
+
+/// Computes a + b mod M lane-wise; both inputs must be in [0, M).
+#[inline]
+#[target_feature(enable = "avx2")]
+unsafe fn add(a: __m256i, b: __m256i) -> __m256i {
+    // same as the scalar implementation: compute a - (M - b) and add M back on underflow
+    let m_minus_b = _mm256_sub_epi64(_mm256_set1_epi64x(M), b);
+    let x = _mm256_sub_epi64(a, m_minus_b);
+    let underflow = lt_u64(a, m_minus_b);
+    _mm256_sub_epi64(x, _mm256_and_si256(underflow, _mm256_set1_epi64x(EPSILON)))
+}
+
+/// Computes a - b mod M lane-wise; both inputs must be in [0, M).
+#[inline]
+#[target_feature(enable = "avx2")]
+unsafe fn sub(a: __m256i, b: __m256i) -> __m256i {
+    let x = _mm256_sub_epi64(a, b);
+    let underflow = lt_u64(a, b);
+    _mm256_sub_epi64(x, _mm256_and_si256(underflow, _mm256_set1_epi64x(EPSILON)))
+}
+
+/// Computes the Montgomery product a * b / 2^64 mod M lane-wise.
+#[inline]
+#[target_feature(enable = "avx2")]
+unsafe fn mul(a: __m256i, b: __m256i) -> __m256i {
+    let (lo, hi) = mul_wide(a, b);
+    mont_red(lo, hi)
+}
+
+/// Computes the full 128-bit products of 64-bit lanes, returning (low, high) halves.
+#[inline]
+#[target_feature(enable = "avx2")]
+unsafe fn mul_wide(a: __m256i, b: __m256i) -> (__m256i, __m256i) {
+    let a_hi = _mm256_srli_epi64::<32>(a);
+    let b_hi = _mm256_srli_epi64::<32>(b);
+
+    // _mm256_mul_epu32 multiplies the low 32 bits of each lane
+    let ll = _mm256_mul_epu32(a, b);
+    let lh = _mm256_mul_epu32(a, b_hi);
+    let hl = _mm256_mul_epu32(a_hi, b);
+    let hh = _mm256_mul_epu32(a_hi, b_hi);
+
+    // none of the sums below can overflow 64 bits
+    let mask_lo = _mm256_set1_epi64x(EPSILON);
+    let t = _mm256_add_epi64(hl, _mm256_srli_epi64::<32>(ll));
+    let u = _mm256_add_epi64(lh, _mm256_and_si256(t, mask_lo));
+
+    let lo = _mm256_or_si256(_mm256_slli_epi64::<32>(u), _mm256_and_si256(ll, mask_lo));
+    let hi = _mm256_add_epi64(
+        hh,
+        _mm256_add_epi64(_mm256_srli_epi64::<32>(t), _mm256_srli_epi64::<32>(u)),
+    );
+    (lo, hi)
+}
+
+/// Montgomery reduction; a lane-wise version of `mont_red_cst()` from the parent module.
+#[inline]
+#[target_feature(enable = "avx2")]
+unsafe fn mont_red(xl: __m256i, xh: __m256i) -> __m256i {
+    let a = _mm256_add_epi64(xl, _mm256_slli_epi64::<32>(xl));
+    // the overflow mask is -1 on overflow, so adding it subtracts the carry
+    let overflow = lt_u64(a, xl);
+    let b = _mm256_add_epi64(_mm256_sub_epi64(a, _mm256_srli_epi64::<32>(a)), overflow);
+
+    let r = _mm256_sub_epi64(xh, b);
+    let underflow = lt_u64(xh, b);
+    _mm256_sub_epi64(r, _mm256_and_si256(underflow, _mm256_set1_epi64x(EPSILON)))
+}
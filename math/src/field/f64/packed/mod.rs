@@ -0,0 +1,241 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+//! Packed arithmetic for the 64-bit field.
+//!
+//! [PackedBaseElement] groups several field elements together so that field operations can be
+//! applied to all of them at once. The batch operations in this module back the batch methods of
+//! [FieldElement](crate::FieldElement) for [BaseElement]. On x86_64 targets with the `std`
+//! feature enabled, AVX2 support is detected at runtime; when it is not available (or on other
+//! targets) a portable implementation is used instead.
+//!
+//! AVX-512 intrinsics are not available on the minimum supported Rust version of this crate, and
+//! thus only AVX2 is supported for now.
+
+use core::{
+    ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+    slice,
+};
+
+use super::BaseElement;
+
+#[cfg(all(target_arch = "x86_64", feature = "std"))]
+mod avx2;
+
+// PACKED FIELD ELEMENT
+// ================================================================================================
+
+/// Represents a vector of [PackedBaseElement::WIDTH] base field elements.
+///
+/// All arithmetic operations are applied lane-wise.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct PackedBaseElement([BaseElement; PackedBaseElement::WIDTH]);
+
+impl PackedBaseElement {
+    /// Number of field elements in a packed element.
+    pub const WIDTH: usize = 4;
+
+    /// Returns a new packed element containing the provided field elements.
+    pub const fn new(values: [BaseElement; Self::WIDTH]) -> Self {
+        Self(values)
+    }
+
+    /// Returns a new packed element with all lanes set to the provided field element.
+    pub const fn broadcast(value: BaseElement) -> Self {
+        Self([value; Self::WIDTH])
+    }
+
+    /// Returns the field elements contained in this packed element.
+    pub const fn to_array(self) -> [BaseElement; Self::WIDTH] {
+        self.0
+    }
+
+    /// Splits the provided slice into a slice of packed elements and a slice containing the
+    /// remaining (fewer than [PackedBaseElement::WIDTH]) field elements.
+    pub fn pack_slice(values: &[BaseElement]) -> (&[Self], &[BaseElement]) {
+        let num_packed = values.len() / Self::WIDTH;
+        let (packed, tail) = values.split_at(num_packed * Self::WIDTH);
+        // SAFETY: PackedBaseElement is a transparent wrapper around an array of WIDTH elements,
+        // and thus has the same layout and alignment as WIDTH consecutive base elements.
+        let packed = unsafe { slice::from_raw_parts(packed.as_ptr() as *const Self, num_packed) };
+        (packed, tail)
+    }
+
+    /// Splits the provided mutable slice into a slice of packed elements and a slice containing
+    /// the remaining (fewer than [PackedBaseElement::WIDTH]) field elements.
+    pub fn pack_slice_mut(values: &mut [BaseElement]) -> (&mut [Self], &mut [BaseElement]) {
+        let num_packed = values.len() / Self::WIDTH;
+        let (packed, tail) = values.split_at_mut(num_packed * Self::WIDTH);
+        // SAFETY: see pack_slice() above.
+        let packed =
+            unsafe { slice::from_raw_parts_mut(packed.as_mut_ptr() as *mut Self, num_packed) };
+        (packed, tail)
+    }
+}
+
+// OVERLOADED OPERATORS
+// ================================================================================================
+
+impl Add for PackedBaseElement {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        Self(core::array::from_fn(|i| self.0[i] + rhs.0[i]))
+    }
+}
+
+impl AddAssign for PackedBaseElement {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs
+    }
+}
+
+impl Sub for PackedBaseElement {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        Self(core::array::from_fn(|i| self.0[i] - rhs.0[i]))
+    }
+}
+
+impl SubAssign for PackedBaseElement {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl Mul for PackedBaseElement {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        Self(core::array::from_fn(|i| self.0[i] * rhs.0[i]))
+    }
+}
+
+impl MulAssign for PackedBaseElement {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs
+    }
+}
+
+impl Neg for PackedBaseElement {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self {
+        Self(self.0.map(|x| -x))
+    }
+}
+
+// BATCH OPERATIONS
+// ================================================================================================
+
+/// Sets (a_i, b_i) = (a_i + b_i, a_i - b_i) for all i.
+pub(super) fn butterfly(a: &mut [BaseElement], b: &mut [BaseElement]) {
+    assert_eq!(a.len(), b.len(), "slices must have the same length");
+    let (a_packed, a_tail) = PackedBaseElement::pack_slice_mut(a);
+    let (b_packed, b_tail) = PackedBaseElement::pack_slice_mut(b);
+    if !a_packed.is_empty() {
+        butterfly_packed(a_packed, b_packed);
+    }
+
+    for (a, b) in a_tail.iter_mut().zip(b_tail.iter_mut()) {
+        let temp = *a;
+        *a = temp + *b;
+        *b = temp - *b;
+    }
+}
+
+/// Sets (a_i, b_i) = (a_i + b_i * twiddle, a_i - b_i * twiddle) for all i.
+pub(super) fn butterfly_twiddle(
+    a: &mut [BaseElement],
+    b: &mut [BaseElement],
+    twiddle: BaseElement,
+) {
+    assert_eq!(a.len(), b.len(), "slices must have the same length");
+    let (a_packed, a_tail) = PackedBaseElement::pack_slice_mut(a);
+    let (b_packed, b_tail) = PackedBaseElement::pack_slice_mut(b);
+    if !a_packed.is_empty() {
+        butterfly_twiddle_packed(a_packed, b_packed, twiddle);
+    }
+
+    for (a, b) in a_tail.iter_mut().zip(b_tail.iter_mut()) {
+        let temp = *a;
+        *b *= twiddle;
+        *a = temp + *b;
+        *b = temp - *b;
+    }
+}
+
+/// Sets a_i = a_i + b_i * c for all i.
+pub(super) fn mul_acc(a: &mut [BaseElement], b: &[BaseElement], c: BaseElement) {
+    assert_eq!(a.len(), b.len(), "slices must have the same length");
+    let (a_packed, a_tail) = PackedBaseElement::pack_slice_mut(a);
+    let (b_packed, b_tail) = PackedBaseElement::pack_slice(b);
+    if !a_packed.is_empty() {
+        mul_acc_packed(a_packed, b_packed, c);
+    }
+
+    for (a, &b) in a_tail.iter_mut().zip(b_tail) {
+        *a += b * c;
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn butterfly_packed(a: &mut [PackedBaseElement], b: &mut [PackedBaseElement]) {
+    #[cfg(all(target_arch = "x86_64", feature = "std"))]
+    if avx2::is_available() {
+        // SAFETY: AVX2 support was detected at runtime.
+        return unsafe { avx2::butterfly(a, b) };
+    }
+
+    for (a, b) in a.iter_mut().zip(b.iter_mut()) {
+        let temp = *a;
+        *a = temp + *b;
+        *b = temp - *b;
+    }
+}
+
+fn butterfly_twiddle_packed(
+    a: &mut [PackedBaseElement],
+    b: &mut [PackedBaseElement],
+    twiddle: BaseElement,
+) {
+    #[cfg(all(target_arch = "x86_64", feature = "std"))]
+    if avx2::is_available() {
+        // SAFETY: AVX2 support was detected at runtime.
+        return unsafe { avx2::butterfly_twiddle(a, b, twiddle) };
+    }
+
+    let twiddle = PackedBaseElement::broadcast(twiddle);
+    for (a, b) in a.iter_mut().zip(b.iter_mut()) {
+        let temp = *a;
+        *b *= twiddle;
+        *a = temp + *b;
+        *b = temp - *b;
+    }
+}
+
+fn mul_acc_packed(a: &mut [PackedBaseElement], b: &[PackedBaseElement], c: BaseElement) {
+    #[cfg(all(target_arch = "x86_64", feature = "std"))]
+    if avx2::is_available() {
+        // SAFETY: AVX2 support was detected at runtime.
+        return unsafe { avx2::mul_acc(a, b, c) };
+    }
+
+    let c = PackedBaseElement::broadcast(c);
+    for (a, &b) in a.iter_mut().zip(b) {
+        *a += b * c;
+    }
+}
@@ -3,12 +3,17 @@
 // This source code is licensed under the MIT license found in the
 // LICENSE file in the root directory of this source tree.
 
+use alloc::vec::Vec;
+
 use num_bigint::BigUint;
 use proptest::prelude::*;
 use rand_utils::rand_value;
 
-use super::{BaseElement, DeserializationError, FieldElement, Serializable, StarkField, M};
-use crate::field::{CubeExtension, ExtensionOf, QuadExtension};
+use super::{
+    packed, BaseElement, DeserializationError, FieldElement, PackedBaseElement, Serializable,
+    StarkField, M,
+};
+use crate::field::{CubeExtension, ExtensionOf, QuadExtension, QuintExtension};
 
 // MANUAL TESTS
 // ================================================================================================
@@ -355,6 +360,125 @@ fn cube_mul_base() {
     assert_eq!(expected, a.mul_base(b0));
 }
 
+// QUINTIC EXTENSION
+// ------------------------------------------------------------------------------------------------
+#[test]
+fn quint_mul() {
+    // identity
+    let r: QuintExtension<BaseElement> = rand_value();
+    assert_eq!(<QuintExtension<BaseElement>>::ZERO, r * <QuintExtension<BaseElement>>::ZERO);
+    assert_eq!(r, r * <QuintExtension<BaseElement>>::ONE);
+
+    // test multiplication with overflow
+    let a = <QuintExtension<BaseElement>>::new(
+        BaseElement::new(M - 1),
+        BaseElement::new(M - 2),
+        BaseElement::new(M - 3),
+        BaseElement::new(M - 4),
+        BaseElement::new(M - 5),
+    );
+    let b = <QuintExtension<BaseElement>>::new(
+        BaseElement::new(M - 6),
+        BaseElement::new(M - 7),
+        BaseElement::new(M - 8),
+        BaseElement::new(M - 9),
+        BaseElement::new(M - 10),
+    );
+    let expected = <QuintExtension<BaseElement>>::new(
+        BaseElement::new(348),
+        BaseElement::new(337),
+        BaseElement::new(295),
+        BaseElement::new(220),
+        BaseElement::new(110),
+    );
+    assert_eq!(expected, a * b);
+}
+
+#[test]
+fn quint_mul_base() {
+    let a: QuintExtension<BaseElement> = rand_value();
+    let b0: BaseElement = rand_value();
+    let b = <QuintExtension<BaseElement>>::from(b0);
+
+    let expected = a * b;
+    assert_eq!(expected, a.mul_base(b0));
+}
+
+#[test]
+fn quint_frobenius() {
+    let a: QuintExtension<BaseElement> = rand_value();
+    assert_eq!(a.exp(M), a.conjugate());
+}
+
+// PACKED ARITHMETIC
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn packed_ops() {
+    let a = PackedBaseElement::new([
+        BaseElement::from_mont(M - 1),
+        BaseElement::from_mont(0),
+        BaseElement::new(M - 1),
+        rand_value(),
+    ]);
+    let b = PackedBaseElement::new([
+        BaseElement::from_mont(M - 1),
+        BaseElement::from_mont(1),
+        BaseElement::new(2),
+        rand_value(),
+    ]);
+
+    let (x, y) = (a.to_array(), b.to_array());
+    assert_eq!((a + b).to_array(), core::array::from_fn(|i| x[i] + y[i]));
+    assert_eq!((a - b).to_array(), core::array::from_fn(|i| x[i] - y[i]));
+    assert_eq!((a * b).to_array(), core::array::from_fn(|i| x[i] * y[i]));
+    assert_eq!((-a).to_array(), core::array::from_fn(|i| -x[i]));
+}
+
+#[test]
+fn pack_slice() {
+    let values: Vec<BaseElement> = (0..10u32).map(BaseElement::from).collect();
+    let (packed, tail) = PackedBaseElement::pack_slice(&values);
+    assert_eq!(2, packed.len());
+    assert_eq!(&values[8..], tail);
+    assert_eq!(values[4..8], packed[1].to_array());
+}
+
+#[test]
+fn batch_ops_edge_values() {
+    // values close to the modulus and to the 2^32 boundaries exercise all carry paths
+    let edge = [0, 1, 2, 0xffffffff, 0x100000000, M - 0xffffffff, M - 2, M - 1];
+    let a: Vec<BaseElement> = edge
+        .iter()
+        .flat_map(|&x| edge.iter().map(move |_| BaseElement::from_mont(x)))
+        .collect();
+    let b: Vec<BaseElement> = edge
+        .iter()
+        .flat_map(|_| edge.iter().map(|&y| BaseElement::from_mont(y)))
+        .collect();
+
+    for &t in edge.iter() {
+        check_batch_ops(&a, &b, BaseElement::from_mont(t));
+    }
+}
+
+// SQUARE ROOTS
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn sqrt() {
+    assert_eq!(Some(BaseElement::ZERO), BaseElement::ZERO.sqrt());
+    assert_eq!(0, BaseElement::ZERO.legendre());
+    assert_eq!(1, BaseElement::ONE.legendre());
+
+    // a primitive root of unity of order 2^TWO_ADICITY is not a square
+    let g = BaseElement::TWO_ADIC_ROOT_OF_UNITY;
+    assert_eq!(-1, g.legendre());
+    assert_eq!(None, g.sqrt());
+    let root = g.square().sqrt().unwrap();
+    assert!(root == g || root == -g);
+}
+
 // RANDOMIZED TESTS
 // ================================================================================================
 
@@ -490,4 +614,112 @@ proptest! {
 
         prop_assert_eq!(expected, a.square());
     }
+
+    // QUINTIC EXTENSION
+    // --------------------------------------------------------------------------------------------
+    #[test]
+    fn quint_mul_inv_proptest(a in prop::array::uniform5(any::<u64>())) {
+        let a = QuintExtension::<BaseElement>::new(
+            BaseElement::new(a[0]),
+            BaseElement::new(a[1]),
+            BaseElement::new(a[2]),
+            BaseElement::new(a[3]),
+            BaseElement::new(a[4]),
+        );
+        let b = a.inv();
+
+        let expected = if a == QuintExtension::<BaseElement>::ZERO {
+            QuintExtension::<BaseElement>::ZERO
+        } else {
+            QuintExtension::<BaseElement>::ONE
+        };
+        prop_assert_eq!(expected, a * b);
+    }
+
+    // PACKED ARITHMETIC
+    // --------------------------------------------------------------------------------------------
+    #[test]
+    fn batch_ops_proptest(
+        values in prop::collection::vec((any::<u64>(), any::<u64>()), 0..70),
+        twiddle in any::<u64>(),
+    ) {
+        let a: Vec<BaseElement> = values.iter().map(|&(a, _)| BaseElement::new(a)).collect();
+        let b: Vec<BaseElement> = values.iter().map(|&(_, b)| BaseElement::new(b)).collect();
+        check_batch_ops(&a, &b, BaseElement::new(twiddle));
+    }
+
+    // SQUARE ROOTS
+    // --------------------------------------------------------------------------------------------
+    #[test]
+    fn sqrt_proptest(a in any::<u64>()) {
+        check_sqrt(BaseElement::new(a));
+    }
+
+    #[test]
+    fn quad_sqrt_proptest(a0 in any::<u64>(), a1 in any::<u64>()) {
+        check_sqrt(QuadExtension::<BaseElement>::new(BaseElement::new(a0), BaseElement::new(a1)));
+    }
+
+    #[test]
+    fn cube_sqrt_proptest(a0 in any::<u64>(), a1 in any::<u64>(), a2 in any::<u64>()) {
+        check_sqrt(CubeExtension::<BaseElement>::new(
+            BaseElement::new(a0),
+            BaseElement::new(a1),
+            BaseElement::new(a2),
+        ));
+    }
+
+    #[test]
+    fn quint_sqrt_proptest(a in prop::array::uniform5(any::<u64>())) {
+        check_sqrt(QuintExtension::<BaseElement>::new(
+            BaseElement::new(a[0]),
+            BaseElement::new(a[1]),
+            BaseElement::new(a[2]),
+            BaseElement::new(a[3]),
+            BaseElement::new(a[4]),
+        ));
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Checks that packed batch operations produce the same results as scalar arithmetic.
+fn check_batch_ops(a: &[BaseElement], b: &[BaseElement], t: BaseElement) {
+    let (mut x, mut y) = (a.to_vec(), b.to_vec());
+    packed::butterfly(&mut x, &mut y);
+    for i in 0..a.len() {
+        assert_eq!(a[i] + b[i], x[i]);
+        assert_eq!(a[i] - b[i], y[i]);
+    }
+
+    let (mut x, mut y) = (a.to_vec(), b.to_vec());
+    packed::butterfly_twiddle(&mut x, &mut y, t);
+    for i in 0..a.len() {
+        assert_eq!(a[i] + b[i] * t, x[i]);
+        assert_eq!(a[i] - b[i] * t, y[i]);
+    }
+
+    let mut x = a.to_vec();
+    packed::mul_acc(&mut x, b, t);
+    for i in 0..a.len() {
+        assert_eq!(a[i] + b[i] * t, x[i]);
+    }
+}
+
+/// Checks that the square root and the Legendre symbol of `a` agree with each other, and that the
+/// square of `a` has a root.
+fn check_sqrt<E: FieldElement>(a: E) {
+    let square = a.square();
+    let root = square.sqrt().expect("square must have a root");
+    assert_eq!(square, root.square());
+    assert_eq!(if a == E::ZERO { 0 } else { 1 }, square.legendre());
+
+    match a.sqrt() {
+        Some(root) => {
+            assert_eq!(a, root.square());
+            assert_ne!(-1, a.legendre());
+        },
+        None => assert_eq!(-1, a.legendre()),
+    }
 }
@@ -7,8 +7,13 @@ mod traits;
 pub use traits::{ExtensibleField, ExtensionOf, FieldElement, StarkField, ToElements};
 
 pub mod f128;
+pub mod f31;
 pub mod f62;
 pub mod f64;
 
 mod extensions;
-pub use extensions::{CubeExtension, QuadExtension};
+mod sqrt;
+pub use extensions::{CubeExtension, QuadExtension, QuartExtension, QuintExtension};
+
+#[cfg(all(test, feature = "ct", feature = "std"))]
+mod timing_tests;
@@ -0,0 +1,197 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+//! Quadratic residuosity and square roots in STARK fields and their extensions.
+//!
+//! For a field of order q = p^k, where p is the modulus of the base field and k is the extension
+//! degree, these functions rely on the following:
+//! * An element is a square if and only if its norm (the product of its Frobenius conjugates) is
+//!   a square in the base field; thus, the Legendre symbol can be computed with an exponent of
+//!   the base field size.
+//! * Square roots are computed using the Tonelli-Shanks algorithm with q - 1 = 2^s * t for odd t.
+//!   The generator of the subgroup of order 2^s is derived from a quadratic non-residue; when k is
+//!   odd, the two-adic root of unity of the base field usually is one, otherwise a non-residue is
+//!   found by a deterministic search.
+//!
+//! Since q does not generally fit into `PositiveInteger` type of a field, exponents are
+//! represented as little-endian vectors of 64-bit limbs.
+
+use alloc::vec::Vec;
+
+use super::{FieldElement, StarkField};
+
+// PUBLIC FUNCTIONS
+// ================================================================================================
+
+/// Returns the Legendre symbol of `a`: 0 if `a` is ZERO, 1 if `a` is a non-zero square, and -1
+/// otherwise.
+pub fn legendre<E: FieldElement>(a: E) -> i8 {
+    // the norm of an element lies in the base field
+    let mut norm = a;
+    let mut conjugate = a;
+    for _ in 1..E::EXTENSION_DEGREE {
+        conjugate = conjugate.conjugate();
+        norm *= conjugate;
+    }
+    let norm = norm.base_element(0);
+
+    let p_minus_one = sub_one(modulus_limbs::<E::BaseField>());
+    let symbol = exp_limbs(norm, &shr(&p_minus_one, 1));
+    if symbol == E::BaseField::ZERO {
+        0
+    } else if symbol == E::BaseField::ONE {
+        1
+    } else {
+        -1
+    }
+}
+
+/// Returns a square root of `a`, or None if `a` is not a square.
+pub fn sqrt<E: FieldElement>(a: E) -> Option<E> {
+    match legendre(a) {
+        0 => return Some(E::ZERO),
+        -1 => return None,
+        _ => (),
+    }
+
+    // q - 1 = 2^s * t, where q is the size of the field
+    let mut q = modulus_limbs::<E::BaseField>();
+    let p = q.clone();
+    for _ in 1..E::EXTENSION_DEGREE {
+        q = mul(&q, &p);
+    }
+    let q_minus_one = sub_one(q);
+    let s = trailing_zeros(&q_minus_one);
+    let t = shr(&q_minus_one, s);
+
+    // c is a generator of the subgroup of order 2^s
+    let mut c = exp_limbs(non_residue::<E>(), &t);
+
+    // x = a^((t + 1) / 2) and b = a^t; b is in the subgroup of order 2^(s - 1) and a = x^2 / b
+    let w = exp_limbs(a, &shr(&t, 1));
+    let mut x = a * w;
+    let mut b = x * w;
+    let mut m = s;
+    while b != E::ONE {
+        // find the smallest i such that b^(2^i) = 1
+        let mut i = 0;
+        let mut b2 = b;
+        while b2 != E::ONE {
+            b2 = b2.square();
+            i += 1;
+        }
+
+        let mut g = c;
+        for _ in 0..(m - i - 1) {
+            g = g.square();
+        }
+        x *= g;
+        c = g.square();
+        b *= c;
+        m = i;
+    }
+
+    Some(x)
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns a quadratic non-residue in the field.
+fn non_residue<E: FieldElement>() -> E {
+    // when the extension degree is odd, the two-adic root of unity of the base field is usually
+    // a non-residue in the extension field as well
+    let root = E::from(E::BaseField::TWO_ADIC_ROOT_OF_UNITY);
+    if legendre(root) == -1 {
+        return root;
+    }
+
+    // otherwise, try elements φ^(k - 1) + i for i = 0, 1, 2, ...
+    let mut coefficients = vec![E::BaseField::ZERO; E::EXTENSION_DEGREE];
+    coefficients[E::EXTENSION_DEGREE - 1] = E::BaseField::ONE;
+    loop {
+        let candidate = E::slice_from_base_elements(&coefficients)[0];
+        if legendre(candidate) == -1 {
+            return candidate;
+        }
+        coefficients[0] += E::BaseField::ONE;
+    }
+}
+
+/// Returns the modulus of the field as little-endian 64-bit limbs.
+fn modulus_limbs<B: StarkField>() -> Vec<u64> {
+    B::get_modulus_le_bytes()
+        .chunks(8)
+        .map(|chunk| {
+            let mut bytes = [0u8; 8];
+            bytes[..chunk.len()].copy_from_slice(chunk);
+            u64::from_le_bytes(bytes)
+        })
+        .collect()
+}
+
+/// Raises `base` to the power specified by little-endian 64-bit limbs.
+fn exp_limbs<E: FieldElement>(base: E, power: &[u64]) -> E {
+    let mut result = E::ONE;
+    for &limb in power.iter().rev() {
+        for i in (0..64).rev() {
+            result = result.square();
+            if (limb >> i) & 1 == 1 {
+                result *= base;
+            }
+        }
+    }
+    result
+}
+
+/// Returns the product of two integers represented as little-endian 64-bit limbs.
+fn mul(a: &[u64], b: &[u64]) -> Vec<u64> {
+    let mut result = vec![0u64; a.len() + b.len()];
+    for (i, &x) in a.iter().enumerate() {
+        let mut carry = 0u128;
+        for (j, &y) in b.iter().enumerate() {
+            let t = (x as u128) * (y as u128) + (result[i + j] as u128) + carry;
+            result[i + j] = t as u64;
+            carry = t >> 64;
+        }
+        result[i + b.len()] = carry as u64;
+    }
+    result
+}
+
+/// Subtracts one from an odd integer represented as little-endian 64-bit limbs.
+fn sub_one(mut a: Vec<u64>) -> Vec<u64> {
+    debug_assert!(a[0] & 1 == 1, "value must be odd");
+    a[0] -= 1;
+    a
+}
+
+/// Returns the number of trailing zero bits of a non-zero integer.
+fn trailing_zeros(a: &[u64]) -> u32 {
+    let mut result = 0;
+    for &limb in a {
+        if limb != 0 {
+            return result + limb.trailing_zeros();
+        }
+        result += 64;
+    }
+    unreachable!("value must not be zero")
+}
+
+/// Shifts an integer represented as little-endian 64-bit limbs right by `n` bits.
+fn shr(a: &[u64], n: u32) -> Vec<u64> {
+    let limb_shift = (n / 64) as usize;
+    let bit_shift = n % 64;
+    (limb_shift..a.len())
+        .map(|i| {
+            let lo = a[i] >> bit_shift;
+            let hi = match a.get(i + 1) {
+                Some(&next) if bit_shift > 0 => next << (64 - bit_shift),
+                _ => 0,
+            };
+            lo | hi
+        })
+        .collect()
+}
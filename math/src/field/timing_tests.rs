@@ -0,0 +1,222 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+//! Statistical timing tests in the style of [dudect](https://eprint.iacr.org/2016/1123.pdf).
+//!
+//! Each test times an operation on two classes of inputs: a fixed input chosen to hit a fast path
+//! of a variable-time implementation (e.g., ZERO for inversion), and uniformly random inputs. The
+//! classes are interleaved at random, and Welch's t-test is applied to the two timing
+//! distributions. An operation is flagged as variable-time if the t statistic exceeds
+//! [T_THRESHOLD].
+//!
+//! Timing measurements are noisy and depend on the machine, and thus these tests are ignored by
+//! default. They are meant to be run locally, in release mode, with the `ct` feature enabled:
+//!
+//! ```text
+//! cargo test -p winter-math --release --features ct timing_tests -- --ignored --test-threads=1
+//! ```
+
+use std::{hint::black_box, time::Instant, vec::Vec};
+
+use rand_utils::rand_value;
+
+use super::{
+    f128::BaseElement as F128, f62::BaseElement as F62, f64::BaseElement as F64, FieldElement,
+    QuadExtension, StarkField,
+};
+
+// CONSTANTS
+// ================================================================================================
+
+/// Number of timing measurements taken for each operation.
+const NUM_MEASUREMENTS: usize = 100_000;
+
+/// Number of operations executed per measurement; this is needed to get above timer resolution.
+const BATCH_SIZE: usize = 16;
+
+/// Fraction of the slowest measurements which are discarded before applying the t-test; these
+/// are usually caused by interrupts and context switches.
+const CROP_FRACTION: f64 = 0.05;
+
+/// The t statistic above which an operation is considered variable-time. This is the threshold
+/// dudect uses for "definitely not constant time"; lower thresholds are too sensitive for
+/// operations which take only a few nanoseconds, as micro-architectural effects of repeating the
+/// same input can shift their timings slightly.
+const T_THRESHOLD: f64 = 500.0;
+
+// BASE FIELD TESTS
+// ================================================================================================
+
+#[test]
+#[ignore = "timing test; run locally in release mode"]
+fn f62_ops() {
+    check_base_field_ops::<F62>();
+}
+
+#[test]
+#[ignore = "timing test; run locally in release mode"]
+fn f64_ops() {
+    check_base_field_ops::<F64>();
+}
+
+#[test]
+#[ignore = "timing test; run locally in release mode"]
+fn f128_ops() {
+    check_base_field_ops::<F128>();
+}
+
+// EXTENSION FIELD TESTS
+// ================================================================================================
+
+#[test]
+#[ignore = "timing test; run locally in release mode"]
+fn f62_quad_ops() {
+    check_field_ops::<QuadExtension<F62>>();
+}
+
+#[test]
+#[ignore = "timing test; run locally in release mode"]
+fn f64_quad_ops() {
+    check_field_ops::<QuadExtension<F64>>();
+}
+
+#[test]
+#[ignore = "timing test; run locally in release mode"]
+fn f128_quad_ops() {
+    check_field_ops::<QuadExtension<F128>>();
+}
+
+// HARNESS SELF-CHECK
+// ================================================================================================
+
+/// Makes sure the harness is able to detect an operation which is expressly variable-time.
+#[test]
+#[ignore = "timing test; run locally in release mode"]
+fn detects_exp_vartime() {
+    let t = measure_t(
+        || (F128::ONE, 0),
+        || (rand_value::<F128>(), rand_value::<u128>()),
+        |(x, power)| x.exp_vartime(power),
+    );
+    assert!(t.abs() > T_THRESHOLD, "exp_vartime() was not detected: t = {t:.2}");
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn check_base_field_ops<B: StarkField>() {
+    check_field_ops::<B>();
+
+    assert_constant_time("as_int", || B::ZERO, rand_value::<B>, |x| x.as_int());
+}
+
+fn check_field_ops<E: FieldElement>() {
+    assert_constant_time(
+        "add",
+        || (E::ZERO, E::ZERO),
+        || (rand_value::<E>(), rand_value::<E>()),
+        |(a, b)| a + b,
+    );
+    assert_constant_time(
+        "sub",
+        || (E::ZERO, E::ZERO),
+        || (rand_value::<E>(), rand_value::<E>()),
+        |(a, b)| a - b,
+    );
+    assert_constant_time(
+        "mul",
+        || (E::ZERO, E::ZERO),
+        || (rand_value::<E>(), rand_value::<E>()),
+        |(a, b)| a * b,
+    );
+    assert_constant_time("neg", || E::ZERO, rand_value::<E>, |x| -x);
+    assert_constant_time("inv", || E::ZERO, rand_value::<E>, |x| x.inv());
+    assert_constant_time("inv(ONE)", || E::ONE, rand_value::<E>, |x| x.inv());
+    assert_constant_time(
+        "exp",
+        || (E::ONE, E::PositiveInteger::from(0u32)),
+        || (rand_value::<E>(), E::PositiveInteger::from(rand_value::<u64>())),
+        |(x, power)| x.exp(power),
+    );
+
+    // equal elements are compared against elements which differ in a random position
+    let x: E = rand_value();
+    assert_constant_time("eq", || (x, x), || (x, rand_value::<E>()), |(a, b)| a == b);
+}
+
+/// Panics if the t statistic for timings of `op` over the two input classes exceeds the
+/// threshold.
+fn assert_constant_time<T, R>(
+    name: &str,
+    fixed: impl Fn() -> T,
+    random: impl Fn() -> T,
+    op: impl Fn(T) -> R,
+) where
+    T: Copy,
+{
+    let t = measure_t(fixed, random, op);
+    assert!(
+        t.abs() < T_THRESHOLD,
+        "{name} is not constant-time for {}: t = {t:.2}",
+        core::any::type_name::<T>()
+    );
+}
+
+/// Times `op` over interleaved batches of fixed and random inputs and returns Welch's t statistic
+/// for the two timing distributions.
+fn measure_t<T, R>(fixed: impl Fn() -> T, random: impl Fn() -> T, op: impl Fn(T) -> R) -> f64
+where
+    T: Copy,
+{
+    let mut timings = [Vec::with_capacity(NUM_MEASUREMENTS), Vec::with_capacity(NUM_MEASUREMENTS)];
+    let mut inputs = [Vec::with_capacity(BATCH_SIZE), Vec::with_capacity(BATCH_SIZE)];
+
+    for _ in 0..NUM_MEASUREMENTS {
+        // prepare inputs outside of the timed region; inputs for both classes are generated for
+        // every measurement so that the state of caches and branch predictors before the timed
+        // region does not depend on the class
+        for class_inputs in inputs.iter_mut() {
+            class_inputs.clear();
+        }
+        for _ in 0..BATCH_SIZE {
+            inputs[0].push(fixed());
+            inputs[1].push(random());
+        }
+        let class = (rand_value::<u64>() & 1) as usize;
+
+        let start = Instant::now();
+        for &input in inputs[class].iter() {
+            black_box(op(black_box(input)));
+        }
+        timings[class].push(start.elapsed().as_nanos() as f64);
+    }
+
+    // discard the slowest measurements across both classes
+    let mut all = timings.concat();
+    all.sort_by(|a, b| a.total_cmp(b));
+    let cutoff = all[((all.len() as f64) * (1.0 - CROP_FRACTION)) as usize];
+    let [fixed_timings, random_timings] =
+        timings.map(|t| t.into_iter().filter(|&x| x <= cutoff).collect::<Vec<_>>());
+
+    welch_t(&fixed_timings, &random_timings)
+}
+
+/// Computes Welch's t statistic for two samples.
+fn welch_t(a: &[f64], b: &[f64]) -> f64 {
+    let (mean_a, var_a) = mean_and_variance(a);
+    let (mean_b, var_b) = mean_and_variance(b);
+    let se = (var_a / a.len() as f64 + var_b / b.len() as f64).sqrt();
+    if se == 0.0 {
+        return 0.0;
+    }
+    (mean_a - mean_b) / se
+}
+
+fn mean_and_variance(values: &[f64]) -> (f64, f64) {
+    let n = values.len() as f64;
+    let mean = values.iter().sum::<f64>() / n;
+    let variance = values.iter().map(|x| (x - mean) * (x - mean)).sum::<f64>() / (n - 1.0);
+    (mean, variance)
+}
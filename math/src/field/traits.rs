@@ -4,6 +4,8 @@
 // LICENSE file in the root directory of this source tree.
 
 use alloc::vec::Vec;
+#[cfg(feature = "ct")]
+use core::mem;
 use core::{
     fmt::{Debug, Display},
     ops::{
@@ -115,9 +117,30 @@ pub trait FieldElement:
     }
 
     /// Exponentiates this field element by `power` parameter.
+    ///
+    /// When the `ct` feature is enabled, the default implementation runs in time which does not
+    /// depend on the values of this element and `power`, as long as multiplication in this field
+    /// runs in constant time.
     #[must_use]
     fn exp(self, power: Self::PositiveInteger) -> Self {
-        self.exp_vartime(power)
+        #[cfg(not(feature = "ct"))]
+        return self.exp_vartime(power);
+
+        #[cfg(feature = "ct")]
+        {
+            let int_one = Self::PositiveInteger::from(1u32);
+            let num_bits = (mem::size_of::<Self::PositiveInteger>() * 8) as u32;
+
+            let mut r = Self::ONE;
+            for i in (0..num_bits).rev() {
+                r = r.square();
+                // multiply by either self or ONE without branching on the bit of the power
+                let bit = Self::from(((power >> i) & int_one == int_one) as u8);
+                r *= Self::ONE + bit * (self - Self::ONE);
+            }
+
+            r
+        }
     }
 
     /// Exponentiates this field element by `power` parameter.
@@ -157,6 +180,83 @@ pub trait FieldElement:
     #[must_use]
     fn conjugate(&self) -> Self;
 
+    /// Returns the Legendre symbol of this field element: 0 if this element is ZERO, 1 if it is
+    /// a square of some non-zero element, and -1 otherwise.
+    ///
+    /// For extension fields, quadratic residuosity is determined by the norm of the element in
+    /// the base field. This function is variable time.
+    fn legendre(&self) -> i8 {
+        super::sqrt::legendre(*self)
+    }
+
+    /// Returns a square root of this field element, or None if this element is not a square.
+    ///
+    /// The root is computed using the Tonelli-Shanks algorithm, which relies on the two-adic
+    /// structure of the multiplicative group of the field. Which of the two roots is returned is
+    /// unspecified. This function is variable time.
+    #[must_use]
+    fn sqrt(&self) -> Option<Self> {
+        super::sqrt::sqrt(*self)
+    }
+
+    // BATCH OPERATIONS
+    // --------------------------------------------------------------------------------------------
+
+    /// Applies radix-2 butterflies to the provided slices. Specifically, for all `i`:
+    ///
+    /// (a_i, b_i) = (a_i + b_i, a_i - b_i)
+    ///
+    /// The default implementation processes one pair of elements at a time; fields which support
+    /// vectorized arithmetic may override it.
+    ///
+    /// # Panics
+    /// Panics if the slices have different lengths.
+    fn batch_butterfly(a: &mut [Self], b: &mut [Self]) {
+        assert_eq!(a.len(), b.len(), "slices must have the same length");
+        for (a, b) in a.iter_mut().zip(b.iter_mut()) {
+            let temp = *a;
+            *a = temp + *b;
+            *b = temp - *b;
+        }
+    }
+
+    /// Applies radix-2 butterflies with a twiddle factor to the provided slices. Specifically,
+    /// for all `i`:
+    ///
+    /// (a_i, b_i) = (a_i + b_i * twiddle, a_i - b_i * twiddle)
+    ///
+    /// The default implementation processes one pair of elements at a time; fields which support
+    /// vectorized arithmetic may override it.
+    ///
+    /// # Panics
+    /// Panics if the slices have different lengths.
+    fn batch_butterfly_twiddle(a: &mut [Self], b: &mut [Self], twiddle: Self::BaseField) {
+        assert_eq!(a.len(), b.len(), "slices must have the same length");
+        for (a, b) in a.iter_mut().zip(b.iter_mut()) {
+            let temp = *a;
+            *b = b.mul_base(twiddle);
+            *a = temp + *b;
+            *b = temp - *b;
+        }
+    }
+
+    /// Multiplies each element of `b` by `c` and adds the result to the corresponding element
+    /// of `a`. Specifically, for all `i`:
+    ///
+    /// a_i = a_i + b_i * c
+    ///
+    /// The default implementation processes one element at a time; fields which support
+    /// vectorized arithmetic may override it.
+    ///
+    /// # Panics
+    /// Panics if the slices have different lengths.
+    fn batch_mul_acc(a: &mut [Self], b: &[Self::BaseField], c: Self) {
+        assert_eq!(a.len(), b.len(), "slices must have the same length");
+        for (a, &b) in a.iter_mut().zip(b) {
+            *a += c.mul_base(b);
+        }
+    }
+
     // BASE ELEMENT CONVERSIONS
     // --------------------------------------------------------------------------------------------
 
@@ -16,7 +16,7 @@
 //! * Drawing random and pseudo-random elements from the field.
 //! * Computing roots of unity of a given order.
 //!
-//! Currently, there are two implementations of finite fields:
+//! Currently, there are four implementations of finite fields:
 //!
 //! * A 128-bit field with modulus 2<sup>128</sup> - 45 * 2<sup>40</sup> + 1. This field was not
 //!   chosen with any significant thought given to performance, and the implementation of most
@@ -32,22 +32,42 @@
 //!   properties. To achieve adequate security (i.e. ~100 bits), proofs must be generated in a
 //!   quadratic extension of this field. For higher levels of security, a cubic extension field
 //!   should be used.
+//! * A 31-bit field (BabyBear) with modulus 15 * 2<sup>27</sup> + 1. Elements of this field fit
+//!   into a single 32-bit word which makes arithmetic in it very fast. To achieve adequate
+//!   security (i.e. ~100 bits), proofs must be generated in a quartic extension of this field.
 //!
 //! ## Extension fields
 //!
-//! Currently, the library provides a generic way to create quadratic and cubic extensions of
-//! supported STARK fields. This can be done by implementing [ExtensibleField] trait for
-//! degrees 2 and 3.
+//! Currently, the library provides a generic way to create quadratic, cubic, quartic, and quintic
+//! extensions of supported STARK fields. This can be done by implementing [ExtensibleField] trait
+//! for degrees 2, 3, 4, and 5.
 //!
 //! Quadratic extension fields are defined using the following irreducible polynomials:
 //! * For [f62](crate::fields::f62) field, the polynomial is x<sup>2</sup> - x - 1.
 //! * For [f64](crate::fields::f64) field, the polynomial is x<sup>2</sup> - x + 2.
 //! * For [f128](crate::fields::f128) field, the polynomial is x<sup>2</sup> - x - 1.
+//! * For [f31](crate::fields::f31) field, the polynomial is x<sup>2</sup> - 11.
 //!
 //! Cubic extension fields are defined using the following irreducible polynomials:
 //! * For [f62](crate::fields::f62) field, the polynomial is x<sup>3</sup> + 2x + 2.
 //! * For [f64](crate::fields::f64) field, the polynomial is x<sup>3</sup> - x - 1.
 //! * For [f128](crate::fields::f128) field, cubic extensions are not supported.
+//! * For [f31](crate::fields::f31) field, the polynomial is x<sup>3</sup> - 2.
+//!
+//! Quartic extension fields are defined using the following irreducible polynomials:
+//! * For [f31](crate::fields::f31) field, the polynomial is x<sup>4</sup> - 11.
+//! * For [f62](crate::fields::f62), [f64](crate::fields::f64), and [f128](crate::fields::f128)
+//!   fields, quartic extensions are not supported.
+//!
+//! Quintic extension fields are defined using the following irreducible polynomials:
+//! * For [f64](crate::fields::f64) field, the polynomial is x<sup>5</sup> - 3.
+//! * For [f31](crate::fields::f31), [f62](crate::fields::f62), and [f128](crate::fields::f128)
+//!   fields, quintic extensions are not implemented.
+//!
+//! # Elliptic curves
+//! [Curves](curves) module contains elliptic curves defined over extensions of STARK fields.
+//! Currently, it contains the [EcGFp5](curves::ecgfp5) curve defined over the quintic extension
+//! of the [f64](crate::fields::f64) field.
 //!
 //! # Polynomials
 //! [Polynomials](polynom) module implements basic polynomial operations such as:
@@ -64,6 +84,51 @@
 //! field (also called [Number-theoretic transform](https://en.wikipedia.org/wiki/Discrete_Fourier_transform_(general)#Number-theoretic_transform)).
 //! This can be used to interpolate and evaluate polynomials in *O(n log n)* time as long as
 //! the domain of the polynomial is a multiplicative subgroup with size which is a power of 2.
+//! Polynomials can also be evaluated and interpolated on a single coset of a larger domain via
+//! [evaluate_poly_on_coset()](fft::evaluate_poly_on_coset()) and
+//! [interpolate_poly_on_coset()](fft::interpolate_poly_on_coset()).
+//!
+//! # Multilinear polynomials
+//! [Multilinear](multilinear) module contains dense multilinear polynomials represented by their
+//! evaluations over the boolean hypercube, evaluations of the equality function, and a prover and
+//! a verifier for the sum-check protocol which process the protocol one round at a time.
+//!
+//! # Constant-time arithmetic
+//! Arithmetic in the 64-bit field ([f64](crate::fields::f64)) is implemented without branching on
+//! the values of the operands: additions, subtractions, multiplications, inversions,
+//! exponentiations, equality checks and [as_int()](StarkField::as_int) all run in constant time
+//! at the source level. In the 62-bit field ([f62](crate::fields::f62)), only additions,
+//! multiplications and doublings are branchless by default, while in the 128-bit field
+//! ([f128](crate::fields::f128)) all of the above operations are variable-time.
+//!
+//! When the crate is compiled with `ct` feature enabled, the variable-time paths in
+//! [f62](crate::fields::f62) and [f128](crate::fields::f128) are replaced with constant-time ones:
+//! inversions are computed via Fermat's little theorem, conditional reductions are replaced with
+//! masking, and equality checks no longer short-circuit. The same applies to inversions and
+//! equality checks in extension fields, and to the default implementation of
+//! [FieldElement::exp()]. Additionally, the flags of conditional corrections in all three fields
+//! are passed through [core::hint::black_box()], which makes it less likely that the compiler
+//! turns the corrections back into branches when arithmetic operations are inlined into loops.
+//! The price for this is slower inversions in the 62-bit and 128-bit fields, and slightly slower
+//! arithmetic overall.
+//!
+//! All of the above is best-effort: `black_box()` is documented as a hint which gives no
+//! guarantees, and neither the compiler nor the CPU is required to preserve constant-time
+//! behavior of the source code.
+//!
+//! The following operations are variable-time regardless of whether `ct` feature is enabled:
+//! * [FieldElement::exp_vartime()], [FieldElement::sqrt()] and [FieldElement::legendre()].
+//! * [batch_inversion()], which skips ZERO elements.
+//! * Arithmetic in the 31-bit field ([f31](crate::fields::f31)) and on [curves].
+//! * Conversions and deserialization of field elements, which are assumed to operate on public
+//!   data.
+//!
+//! The generated code is checked only by statistical timing tests, which are ignored by default
+//! and can be run locally as follows:
+//!
+//! ```text
+//! cargo test -p winter-math --release --features ct timing_tests -- --ignored --test-threads=1
+//! ```
 //!
 //! # Concurrent execution
 //!
@@ -82,6 +147,8 @@
 //!   - [evaluate_poly_with_offset()](fft::evaluate_poly_with_offset())
 //!   - [interpolate_poly()](fft::interpolate_poly())
 //!   - [interpolate_poly_with_offset()][fft::interpolate_poly_with_offset()]
+//!   - [evaluate_poly_on_coset()](fft::evaluate_poly_on_coset())
+//!   - [interpolate_poly_on_coset()](fft::interpolate_poly_on_coset())
 //!   - [get_twiddles()](fft::get_twiddles())
 //!   - [get_inv_twiddles()](fft::get_twiddles())
 //!
@@ -92,7 +159,12 @@
 #[macro_use]
 extern crate alloc;
 
+#[cfg(feature = "std")]
+extern crate std;
+
+pub mod curves;
 pub mod fft;
+pub mod multilinear;
 pub mod polynom;
 
 mod field;
@@ -103,7 +175,9 @@ pub mod fields {
     //! This module contains concrete implementations of base STARK fields as well as extensions
     //! of these field.
 
-    pub use super::field::{f128, f62, f64, CubeExtension, QuadExtension};
+    pub use super::field::{
+        f128, f31, f62, f64, CubeExtension, QuadExtension, QuartExtension, QuintExtension,
+    };
 }
 
 mod utils;
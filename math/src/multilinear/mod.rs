@@ -0,0 +1,178 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+//! Multilinear polynomials and the sum-check protocol.
+//!
+//! A multilinear polynomial in `n` variables is represented by its evaluations over the boolean
+//! hypercube {0, 1}^`n`. The evaluation at a point (b<sub>0</sub>, ..., b<sub>n-1</sub>) of the
+//! hypercube is stored at index b<sub>0</sub> + 2 * b<sub>1</sub> + ... + 2<sup>n-1</sup> *
+//! b<sub>n-1</sub>; that is, the first variable corresponds to the least significant bit of the
+//! index.
+//!
+//! The sum-check protocol is implemented by [SumCheckProver] and [SumCheckVerifier] which process
+//! the protocol one round at a time, and thus, can be used with any source of round challenges.
+//! Non-interactive versions of the protocol which draw round challenges from a random coin are
+//! available in the `winter-crypto` crate.
+
+use alloc::vec::Vec;
+
+use crate::FieldElement;
+
+mod sumcheck;
+pub use sumcheck::{
+    CompositionPolynomial, CompressedUnivariatePoly, SumCheckError, SumCheckProof, SumCheckProver,
+    SumCheckVerifier,
+};
+
+#[cfg(test)]
+mod tests;
+
+// MULTILINEAR POLYNOMIAL
+// ================================================================================================
+
+/// A multilinear polynomial represented by its evaluations over the boolean hypercube.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MultiLinearPoly<E: FieldElement> {
+    num_variables: usize,
+    evaluations: Vec<E>,
+}
+
+impl<E: FieldElement> MultiLinearPoly<E> {
+    /// Returns a multilinear polynomial defined by the provided evaluations over the boolean
+    /// hypercube.
+    ///
+    /// # Panics
+    /// Panics if the number of evaluations is not a power of two.
+    pub fn from_evaluations(evaluations: Vec<E>) -> Self {
+        assert!(
+            evaluations.len().is_power_of_two(),
+            "number of evaluations must be a power of 2, but was {}",
+            evaluations.len()
+        );
+        Self {
+            num_variables: evaluations.len().ilog2() as usize,
+            evaluations,
+        }
+    }
+
+    /// Returns the number of variables of this polynomial.
+    pub fn num_variables(&self) -> usize {
+        self.num_variables
+    }
+
+    /// Returns the number of evaluations of this polynomial over the boolean hypercube.
+    pub fn num_evaluations(&self) -> usize {
+        self.evaluations.len()
+    }
+
+    /// Returns evaluations of this polynomial over the boolean hypercube.
+    pub fn evaluations(&self) -> &[E] {
+        &self.evaluations
+    }
+
+    /// Evaluates this polynomial at the specified point.
+    ///
+    /// # Panics
+    /// Panics if the number of coordinates in `query` is not equal to the number of variables of
+    /// this polynomial.
+    pub fn evaluate(&self, query: &[E]) -> E {
+        assert_eq!(
+            self.num_variables,
+            query.len(),
+            "expected a query with {} coordinates, but received {}",
+            self.num_variables,
+            query.len()
+        );
+        let eq = EqFunction::new(query.to_vec()).evaluations();
+        self.evaluations.iter().zip(eq).fold(E::ZERO, |acc, (&v, e)| acc + v * e)
+    }
+
+    /// Fixes the first variable of this polynomial to the specified value, reducing the number
+    /// of variables by one.
+    ///
+    /// # Panics
+    /// Panics if this polynomial has no variables.
+    pub fn bind_least_significant_variable(&mut self, round_challenge: E) {
+        assert!(self.num_variables > 0, "polynomial must have at least one variable");
+        let half = self.evaluations.len() / 2;
+        for i in 0..half {
+            let lo = self.evaluations[2 * i];
+            let hi = self.evaluations[2 * i + 1];
+            self.evaluations[i] = lo + round_challenge * (hi - lo);
+        }
+        self.evaluations.truncate(half);
+        self.num_variables -= 1;
+    }
+
+    /// Returns a polynomial resulting from fixing the first variable of this polynomial to the
+    /// specified value.
+    ///
+    /// # Panics
+    /// Panics if this polynomial has no variables.
+    pub fn project_least_significant_variable(&self, round_challenge: E) -> Self {
+        let mut result = self.clone();
+        result.bind_least_significant_variable(round_challenge);
+        result
+    }
+}
+
+// EQ FUNCTION
+// ================================================================================================
+
+/// The multilinear extension of the equality function at a fixed point r.
+///
+/// For a point r = (r<sub>0</sub>, ..., r<sub>n-1</sub>), this is the polynomial
+/// eq(r, x) = &prod;(r<sub>i</sub> * x<sub>i</sub> + (1 - r<sub>i</sub>) * (1 - x<sub>i</sub>))
+/// which, for x in the boolean hypercube, is equal to 1 when x = r and to 0 otherwise.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EqFunction<E: FieldElement> {
+    r: Vec<E>,
+}
+
+impl<E: FieldElement> EqFunction<E> {
+    /// Returns the equality function at the specified point.
+    pub fn new(r: Vec<E>) -> Self {
+        Self { r }
+    }
+
+    /// Evaluates the equality function at the specified point.
+    ///
+    /// # Panics
+    /// Panics if the number of coordinates in `query` is not equal to the number of coordinates
+    /// of the point defining this function.
+    pub fn evaluate(&self, query: &[E]) -> E {
+        assert_eq!(
+            self.r.len(),
+            query.len(),
+            "expected a query with {} coordinates, but received {}",
+            self.r.len(),
+            query.len()
+        );
+        self.r
+            .iter()
+            .zip(query)
+            .fold(E::ONE, |acc, (&r, &x)| acc * (r * x + (E::ONE - r) * (E::ONE - x)))
+    }
+
+    /// Returns evaluations of the equality function over the boolean hypercube.
+    ///
+    /// The evaluations are ordered in the same way as evaluations of a [MultiLinearPoly], and
+    /// thus, the evaluation of a multilinear polynomial at r is equal to the inner product of its
+    /// evaluations and the returned vector.
+    pub fn evaluations(&self) -> Vec<E> {
+        let mut result = Vec::with_capacity(1 << self.r.len());
+        result.push(E::ONE);
+        for &r in self.r.iter() {
+            let len = result.len();
+            result.extend_from_within(..);
+            for i in 0..len {
+                let v = result[i];
+                result[i + len] = v * r;
+                result[i] = v - result[i + len];
+            }
+        }
+        result
+    }
+}
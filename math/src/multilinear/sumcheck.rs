@@ -0,0 +1,389 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use alloc::vec::Vec;
+use core::fmt;
+
+use utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable};
+
+use super::MultiLinearPoly;
+use crate::FieldElement;
+
+// COMPOSITION POLYNOMIAL
+// ================================================================================================
+
+/// A polynomial which combines evaluations of several multilinear polynomials into a single value.
+///
+/// The sum-check protocol proves the sum of a composition polynomial applied to a set of
+/// multilinear polynomials over the boolean hypercube. For example, a composition polynomial
+/// f(a, b) = a * b can be used to prove an inner product of two vectors.
+pub trait CompositionPolynomial<E: FieldElement> {
+    /// Returns the total degree of the polynomial (e.g., f(a, b) = a * b has degree 2).
+    ///
+    /// This is also the degree of the round polynomials in the sum-check protocol, since every
+    /// multilinear polynomial has degree at most 1 in each variable.
+    fn max_degree(&self) -> usize;
+
+    /// Evaluates the polynomial at the specified values of its inputs.
+    fn evaluate(&self, query: &[E]) -> E;
+}
+
+// ROUND POLYNOMIAL
+// ================================================================================================
+
+/// A univariate polynomial sent by the prover in a single round of the sum-check protocol.
+///
+/// A polynomial p(x) of degree d is represented by its evaluations at 0, 2, 3, ..., d. The
+/// evaluation at 1 is omitted because the verifier can derive it from the claim of the round as
+/// claim - p(0).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CompressedUnivariatePoly<E: FieldElement>(Vec<E>);
+
+impl<E: FieldElement> CompressedUnivariatePoly<E> {
+    /// Returns a polynomial defined by its evaluations at 0, 2, 3, ..., d.
+    pub fn new(evaluations: Vec<E>) -> Self {
+        Self(evaluations)
+    }
+
+    /// Returns the degree of this polynomial.
+    pub fn degree(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns evaluations of this polynomial at 0, 2, 3, ..., d.
+    pub fn evaluations(&self) -> &[E] {
+        &self.0
+    }
+
+    /// Evaluates this polynomial at `x` given that p(0) + p(1) = `claim`.
+    ///
+    /// # Panics
+    /// Panics if this polynomial has no evaluations.
+    pub fn evaluate_using_claim(&self, claim: E, x: E) -> E {
+        assert!(!self.0.is_empty(), "round polynomial must have at least one evaluation");
+
+        // recover evaluations at 0, 1, ..., d and interpolate them at x
+        let mut ys = Vec::with_capacity(self.0.len() + 1);
+        ys.push(self.0[0]);
+        ys.push(claim - self.0[0]);
+        ys.extend_from_slice(&self.0[1..]);
+
+        let mut result = E::ZERO;
+        for (i, &y) in ys.iter().enumerate() {
+            let mut num = E::ONE;
+            let mut den = E::ONE;
+            for j in (0..ys.len()).filter(|&j| j != i) {
+                num *= x - E::from(j as u32);
+                den *= E::from(i as u32) - E::from(j as u32);
+            }
+            result += y * num / den;
+        }
+        result
+    }
+}
+
+impl<E: FieldElement> Serializable for CompressedUnivariatePoly<E> {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.0.write_into(target);
+    }
+}
+
+impl<E: FieldElement> Deserializable for CompressedUnivariatePoly<E> {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        Ok(Self(Vec::read_from(source)?))
+    }
+}
+
+// SUM-CHECK PROOF
+// ================================================================================================
+
+/// A non-interactive proof for the sum-check protocol.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SumCheckProof<E: FieldElement> {
+    /// Polynomials sent by the prover in each round of the protocol.
+    pub round_polys: Vec<CompressedUnivariatePoly<E>>,
+    /// Evaluations of the multilinear polynomials at the point defined by round challenges.
+    pub final_evaluations: Vec<E>,
+}
+
+impl<E: FieldElement> Serializable for SumCheckProof<E> {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.round_polys.write_into(target);
+        self.final_evaluations.write_into(target);
+    }
+}
+
+impl<E: FieldElement> Deserializable for SumCheckProof<E> {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        Ok(Self {
+            round_polys: Vec::read_from(source)?,
+            final_evaluations: Vec::read_from(source)?,
+        })
+    }
+}
+
+// SUM-CHECK PROVER
+// ================================================================================================
+
+/// Prover for the sum-check protocol.
+///
+/// The prover proves that the sum of a composition polynomial applied to a set of multilinear
+/// polynomials over the boolean hypercube is equal to [SumCheckProver::claim()]. The protocol is
+/// executed one round at a time: in every round, the prover sends the polynomial returned by
+/// [SumCheckProver::round_poly()], and then binds the first remaining variable of the multilinear
+/// polynomials to the challenge of the round via [SumCheckProver::bind()]. After all rounds have
+/// been executed, [SumCheckProver::final_evaluations()] returns the evaluations of the
+/// multilinear polynomials at the point defined by the round challenges.
+pub struct SumCheckProver<E: FieldElement, P: CompositionPolynomial<E>> {
+    composition: P,
+    mles: Vec<MultiLinearPoly<E>>,
+}
+
+impl<E: FieldElement, P: CompositionPolynomial<E>> SumCheckProver<E, P> {
+    /// Returns a new prover for the sum of the specified composition polynomial applied to the
+    /// specified multilinear polynomials.
+    ///
+    /// # Panics
+    /// Panics if:
+    /// * `mles` is empty or the polynomials have different numbers of variables.
+    /// * Maximum degree of the composition polynomial is zero.
+    pub fn new(composition: P, mles: Vec<MultiLinearPoly<E>>) -> Self {
+        assert!(!mles.is_empty(), "at least one multilinear polynomial must be provided");
+        let num_variables = mles[0].num_variables();
+        assert!(
+            mles.iter().all(|mle| mle.num_variables() == num_variables),
+            "all multilinear polynomials must have the same number of variables"
+        );
+        assert!(composition.max_degree() > 0, "composition polynomial degree must be at least 1");
+        Self { composition, mles }
+    }
+
+    /// Returns the sum of the composition polynomial over the remaining variables of the
+    /// multilinear polynomials.
+    ///
+    /// Before the first round, this is the sum over the entire boolean hypercube.
+    pub fn claim(&self) -> E {
+        let mut query = Vec::with_capacity(self.mles.len());
+        (0..self.mles[0].num_evaluations()).fold(E::ZERO, |acc, i| {
+            query.clear();
+            query.extend(self.mles.iter().map(|mle| mle.evaluations()[i]));
+            acc + self.composition.evaluate(&query)
+        })
+    }
+
+    /// Returns the number of rounds remaining in the protocol.
+    pub fn num_rounds(&self) -> usize {
+        self.mles[0].num_variables()
+    }
+
+    /// Returns the polynomial for the current round of the protocol.
+    ///
+    /// This is the sum of the composition polynomial over all remaining variables except for the
+    /// first one, which is left free.
+    ///
+    /// # Panics
+    /// Panics if all rounds of the protocol have already been executed.
+    pub fn round_poly(&self) -> CompressedUnivariatePoly<E> {
+        assert!(self.num_rounds() > 0, "all rounds have already been executed");
+        let degree = self.composition.max_degree();
+        let num_mles = self.mles.len();
+
+        let mut evaluations = vec![E::ZERO; degree];
+        let mut query = vec![E::ZERO; num_mles];
+        let mut deltas = vec![E::ZERO; num_mles];
+        for i in 0..self.mles[0].num_evaluations() / 2 {
+            // evaluation at 0
+            for (j, mle) in self.mles.iter().enumerate() {
+                let lo = mle.evaluations()[2 * i];
+                let hi = mle.evaluations()[2 * i + 1];
+                query[j] = lo;
+                deltas[j] = hi - lo;
+            }
+            evaluations[0] += self.composition.evaluate(&query);
+
+            // evaluations at 2, 3, ..., degree; the query is first advanced to 1
+            query.iter_mut().zip(deltas.iter()).for_each(|(q, &d)| *q += d);
+            for evaluation in evaluations.iter_mut().skip(1) {
+                query.iter_mut().zip(deltas.iter()).for_each(|(q, &d)| *q += d);
+                *evaluation += self.composition.evaluate(&query);
+            }
+        }
+
+        CompressedUnivariatePoly(evaluations)
+    }
+
+    /// Binds the first remaining variable of the multilinear polynomials to the specified
+    /// challenge, completing the current round of the protocol.
+    ///
+    /// # Panics
+    /// Panics if all rounds of the protocol have already been executed.
+    pub fn bind(&mut self, round_challenge: E) {
+        assert!(self.num_rounds() > 0, "all rounds have already been executed");
+        self.mles
+            .iter_mut()
+            .for_each(|mle| mle.bind_least_significant_variable(round_challenge));
+    }
+
+    /// Returns evaluations of the multilinear polynomials at the point defined by the round
+    /// challenges.
+    ///
+    /// # Panics
+    /// Panics if not all rounds of the protocol have been executed.
+    pub fn final_evaluations(&self) -> Vec<E> {
+        assert_eq!(0, self.num_rounds(), "not all rounds have been executed");
+        self.mles.iter().map(|mle| mle.evaluations()[0]).collect()
+    }
+}
+
+// SUM-CHECK VERIFIER
+// ================================================================================================
+
+/// Verifier for the sum-check protocol.
+///
+/// The protocol is executed one round at a time via [SumCheckVerifier::process_round()], and is
+/// completed via [SumCheckVerifier::finalize()], which checks the final claim against the
+/// evaluations of multilinear polynomials provided by the prover.
+///
+/// Note that the verifier does not check that the final evaluations are correct: this must be
+/// done by the caller (e.g., by opening commitments to the multilinear polynomials at the point
+/// returned by [SumCheckVerifier::finalize()]).
+pub struct SumCheckVerifier<E: FieldElement> {
+    num_rounds: usize,
+    num_polys: usize,
+    max_degree: usize,
+    claim: E,
+    round_challenges: Vec<E>,
+}
+
+impl<E: FieldElement> SumCheckVerifier<E> {
+    /// Returns a new verifier for a sum-check protocol with the specified number of rounds (i.e.,
+    /// number of variables), number of multilinear polynomials, maximum degree of the composition
+    /// polynomial, and claimed sum.
+    ///
+    /// # Panics
+    /// Panics if the maximum degree of the composition polynomial is zero.
+    pub fn new(num_rounds: usize, num_polys: usize, max_degree: usize, claim: E) -> Self {
+        assert!(max_degree > 0, "composition polynomial degree must be at least 1");
+        Self {
+            num_rounds,
+            num_polys,
+            max_degree,
+            claim,
+            round_challenges: Vec::with_capacity(num_rounds),
+        }
+    }
+
+    /// Returns the claim for the current round of the protocol.
+    pub fn claim(&self) -> E {
+        self.claim
+    }
+
+    /// Processes the polynomial sent by the prover in the current round, and reduces the claim
+    /// of the round to the evaluation of the polynomial at the specified challenge.
+    ///
+    /// # Errors
+    /// Returns an error if all rounds of the protocol have already been processed, or if the
+    /// degree of the round polynomial is not equal to the maximum degree of the composition
+    /// polynomial.
+    pub fn process_round(
+        &mut self,
+        round_poly: &CompressedUnivariatePoly<E>,
+        round_challenge: E,
+    ) -> Result<(), SumCheckError> {
+        let round = self.round_challenges.len();
+        if round == self.num_rounds {
+            return Err(SumCheckError::TooManyRounds(self.num_rounds));
+        }
+        if round_poly.degree() != self.max_degree {
+            return Err(SumCheckError::InvalidRoundPolyDegree(
+                round,
+                self.max_degree,
+                round_poly.degree(),
+            ));
+        }
+
+        self.claim = round_poly.evaluate_using_claim(self.claim, round_challenge);
+        self.round_challenges.push(round_challenge);
+        Ok(())
+    }
+
+    /// Checks the final claim of the protocol against the composition polynomial applied to the
+    /// specified evaluations of multilinear polynomials, and returns the point defined by the
+    /// round challenges.
+    ///
+    /// # Errors
+    /// Returns an error if not all rounds of the protocol have been processed, if the number of
+    /// `final_evaluations` is not equal to the number of multilinear polynomials, or if the final
+    /// claim does not match the composition polynomial applied to `final_evaluations`.
+    pub fn finalize<P: CompositionPolynomial<E>>(
+        self,
+        composition: &P,
+        final_evaluations: &[E],
+    ) -> Result<Vec<E>, SumCheckError> {
+        if self.round_challenges.len() != self.num_rounds {
+            return Err(SumCheckError::TooFewRounds(self.num_rounds, self.round_challenges.len()));
+        }
+        if final_evaluations.len() != self.num_polys {
+            return Err(SumCheckError::InvalidNumFinalEvaluations(
+                self.num_polys,
+                final_evaluations.len(),
+            ));
+        }
+        if composition.evaluate(final_evaluations) != self.claim {
+            return Err(SumCheckError::FinalEvaluationMismatch);
+        }
+        Ok(self.round_challenges)
+    }
+}
+
+// SUM-CHECK ERROR
+// ================================================================================================
+
+/// Defines errors which can occur during verification of the sum-check protocol.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SumCheckError {
+    /// More rounds than the specified number were processed.
+    TooManyRounds(usize),
+    /// Fewer rounds than the specified number were processed.
+    TooFewRounds(usize, usize),
+    /// Degree of a round polynomial did not match the degree of the composition polynomial.
+    InvalidRoundPolyDegree(usize, usize, usize),
+    /// A round challenge could not be drawn.
+    FailedToDrawChallenge(usize),
+    /// Number of final evaluations did not match the number of multilinear polynomials.
+    InvalidNumFinalEvaluations(usize, usize),
+    /// The final claim did not match the composition polynomial applied to final evaluations.
+    FinalEvaluationMismatch,
+}
+
+impl fmt::Display for SumCheckError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::TooManyRounds(expected) => {
+                write!(f, "sum-check protocol cannot have more than {expected} rounds")
+            },
+            Self::TooFewRounds(expected, actual) => {
+                write!(f, "sum-check protocol must have {expected} rounds, but had {actual}")
+            },
+            Self::InvalidRoundPolyDegree(round, expected, actual) => {
+                write!(
+                    f,
+                    "round polynomial in round {round} must have degree {expected}, but had degree {actual}"
+                )
+            },
+            Self::FailedToDrawChallenge(round) => {
+                write!(f, "failed to draw a random challenge for round {round}")
+            },
+            Self::InvalidNumFinalEvaluations(expected, actual) => {
+                write!(f, "expected {expected} final evaluations, but received {actual}")
+            },
+            Self::FinalEvaluationMismatch => {
+                write!(f, "final claim does not match the final evaluations")
+            },
+        }
+    }
+}
+
+impl core::error::Error for SumCheckError {}
@@ -0,0 +1,236 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use alloc::vec::Vec;
+
+use rand_utils::{rand_value, rand_vector};
+use utils::{Deserializable, Serializable, SliceReader};
+
+use super::{
+    CompositionPolynomial, CompressedUnivariatePoly, EqFunction, MultiLinearPoly, SumCheckError,
+    SumCheckProof, SumCheckProver, SumCheckVerifier,
+};
+use crate::{
+    field::{f64::BaseElement, FieldElement, QuadExtension},
+    polynom,
+};
+
+type E = QuadExtension<BaseElement>;
+
+// MULTILINEAR POLYNOMIAL
+// ================================================================================================
+
+#[test]
+fn mle_evaluate() {
+    // f(x0, x1) = 1 + 2 * x0 + 3 * x1 + 4 * x0 * x1
+    let mle = MultiLinearPoly::from_evaluations(
+        [1u32, 3, 4, 10].into_iter().map(BaseElement::from).collect(),
+    );
+    assert_eq!(2, mle.num_variables());
+    assert_eq!(4, mle.num_evaluations());
+
+    let (x0, x1) = (rand_value::<BaseElement>(), rand_value::<BaseElement>());
+    let expected = BaseElement::ONE
+        + BaseElement::from(2u32) * x0
+        + BaseElement::from(3u32) * x1
+        + BaseElement::from(4u32) * x0 * x1;
+    assert_eq!(expected, mle.evaluate(&[x0, x1]));
+
+    // evaluations over the hypercube
+    let mle = MultiLinearPoly::<E>::from_evaluations(rand_vector(16));
+    for (i, &value) in mle.evaluations().iter().enumerate() {
+        let query: Vec<E> = (0..4).map(|j| E::from(((i >> j) & 1) as u32)).collect();
+        assert_eq!(value, mle.evaluate(&query));
+    }
+}
+
+#[test]
+fn mle_bind() {
+    let mle = MultiLinearPoly::<E>::from_evaluations(rand_vector(32));
+    let query: Vec<E> = rand_vector(5);
+    let expected = mle.evaluate(&query);
+
+    let mut bound = mle.clone();
+    for (i, &r) in query.iter().enumerate() {
+        let projected = bound.project_least_significant_variable(r);
+        bound.bind_least_significant_variable(r);
+        assert_eq!(projected, bound);
+        assert_eq!(4 - i, bound.num_variables());
+        assert_eq!(expected, bound.evaluate(&query[i + 1..]));
+    }
+    assert_eq!(expected, bound.evaluations()[0]);
+}
+
+// EQ FUNCTION
+// ================================================================================================
+
+#[test]
+fn eq_function() {
+    let r: Vec<E> = rand_vector(4);
+    let eq = EqFunction::new(r.clone());
+    let evaluations = eq.evaluations();
+    assert_eq!(16, evaluations.len());
+    assert_eq!(E::ONE, evaluations.iter().fold(E::ZERO, |acc, &v| acc + v));
+
+    for (i, &value) in evaluations.iter().enumerate() {
+        let query: Vec<E> = (0..4).map(|j| E::from(((i >> j) & 1) as u32)).collect();
+        assert_eq!(value, eq.evaluate(&query));
+    }
+
+    // eq(r, x) is equal to 1 at x = r only for boolean r
+    let boolean: Vec<E> = [1u32, 0, 0, 1].into_iter().map(E::from).collect();
+    let eq = EqFunction::new(boolean.clone());
+    assert_eq!(E::ONE, eq.evaluate(&boolean));
+    assert_eq!(E::ONE, eq.evaluations()[0b1001]);
+    assert_eq!(1, eq.evaluations().iter().filter(|&&v| v != E::ZERO).count());
+}
+
+// SUM-CHECK PROTOCOL
+// ================================================================================================
+
+#[test]
+fn sumcheck_round_poly() {
+    let mles = build_mles(3);
+    let prover = SumCheckProver::new(TripleProduct, mles.clone());
+    let round_poly = prover.round_poly();
+    assert_eq!(3, round_poly.degree());
+
+    // round polynomial evaluations are sums over the remaining variables
+    let claim = prover.claim();
+    for x in 0..6u32 {
+        let x = E::from(x);
+        let projected: Vec<_> =
+            mles.iter().map(|mle| mle.project_least_significant_variable(x)).collect();
+        let expected = SumCheckProver::new(TripleProduct, projected).claim();
+        assert_eq!(expected, round_poly.evaluate_using_claim(claim, x));
+    }
+
+    // the round polynomial is a polynomial of degree 3 in the challenge
+    let xs: Vec<E> = (1..5u32).map(E::from).collect();
+    let ys: Vec<E> = xs.iter().map(|&x| round_poly.evaluate_using_claim(claim, x)).collect();
+    let poly = polynom::interpolate(&xs, &ys, false);
+    let r = rand_value::<E>();
+    assert_eq!(polynom::eval(&poly, r), round_poly.evaluate_using_claim(claim, r));
+}
+
+#[test]
+fn sumcheck_prove_verify() {
+    let num_variables = 6;
+    let mles = build_mles(num_variables);
+    let challenges: Vec<E> = rand_vector(num_variables);
+
+    let proof = prove(mles.clone(), &challenges);
+    let claim = SumCheckProver::new(TripleProduct, mles.clone()).claim();
+
+    // the final evaluations are evaluations of the multilinear polynomials at the challenges
+    let point = verify(claim, &proof, &challenges).unwrap();
+    assert_eq!(challenges, point);
+    for (mle, &value) in mles.iter().zip(proof.final_evaluations.iter()) {
+        assert_eq!(mle.evaluate(&point), value);
+    }
+
+    // serialization round trip
+    let bytes = proof.to_bytes();
+    let mut reader = SliceReader::new(&bytes);
+    assert_eq!(proof, SumCheckProof::read_from(&mut reader).unwrap());
+
+    // wrong claim
+    assert_eq!(
+        Err(SumCheckError::FinalEvaluationMismatch),
+        verify(claim + E::ONE, &proof, &challenges)
+    );
+
+    // tampered round polynomial
+    let mut bad_proof = proof.clone();
+    let mut evaluations = bad_proof.round_polys[2].evaluations().to_vec();
+    evaluations[1] += E::ONE;
+    bad_proof.round_polys[2] = CompressedUnivariatePoly::new(evaluations);
+    assert_eq!(
+        Err(SumCheckError::FinalEvaluationMismatch),
+        verify(claim, &bad_proof, &challenges)
+    );
+
+    // tampered final evaluations
+    let mut bad_proof = proof.clone();
+    bad_proof.final_evaluations[0] += E::ONE;
+    assert_eq!(
+        Err(SumCheckError::FinalEvaluationMismatch),
+        verify(claim, &bad_proof, &challenges)
+    );
+
+    // missing final evaluation
+    let mut bad_proof = proof.clone();
+    bad_proof.final_evaluations.pop();
+    assert_eq!(
+        Err(SumCheckError::InvalidNumFinalEvaluations(3, 2)),
+        verify(claim, &bad_proof, &challenges)
+    );
+
+    // round polynomial of wrong degree
+    let mut bad_proof = proof.clone();
+    let evaluations = bad_proof.round_polys[1].evaluations()[..2].to_vec();
+    bad_proof.round_polys[1] = CompressedUnivariatePoly::new(evaluations);
+    assert_eq!(
+        Err(SumCheckError::InvalidRoundPolyDegree(1, 3, 2)),
+        verify(claim, &bad_proof, &challenges)
+    );
+
+    // missing round
+    let mut bad_proof = proof;
+    bad_proof.round_polys.pop();
+    assert_eq!(
+        Err(SumCheckError::TooFewRounds(num_variables, num_variables - 1)),
+        verify(claim, &bad_proof, &challenges)
+    );
+}
+
+#[test]
+#[should_panic(expected = "composition polynomial degree must be at least 1")]
+fn sumcheck_verifier_zero_degree() {
+    let _ = SumCheckVerifier::new(4, 3, 0, E::ZERO);
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// f(a, b, c) = a * b * c + a
+struct TripleProduct;
+
+impl CompositionPolynomial<E> for TripleProduct {
+    fn max_degree(&self) -> usize {
+        3
+    }
+
+    fn evaluate(&self, query: &[E]) -> E {
+        query[0] * query[1] * query[2] + query[0]
+    }
+}
+
+fn build_mles(num_variables: usize) -> Vec<MultiLinearPoly<E>> {
+    (0..3)
+        .map(|_| MultiLinearPoly::from_evaluations(rand_vector(1 << num_variables)))
+        .collect()
+}
+
+fn prove(mles: Vec<MultiLinearPoly<E>>, challenges: &[E]) -> SumCheckProof<E> {
+    let mut prover = SumCheckProver::new(TripleProduct, mles);
+    let mut round_polys = Vec::new();
+    for &r in challenges {
+        round_polys.push(prover.round_poly());
+        prover.bind(r);
+    }
+    SumCheckProof {
+        round_polys,
+        final_evaluations: prover.final_evaluations(),
+    }
+}
+
+fn verify(claim: E, proof: &SumCheckProof<E>, challenges: &[E]) -> Result<Vec<E>, SumCheckError> {
+    let mut verifier = SumCheckVerifier::new(challenges.len(), 3, 3, claim);
+    for (round_poly, &r) in proof.round_polys.iter().zip(challenges) {
+        verifier.process_round(round_poly, r)?;
+    }
+    verifier.finalize(&TripleProduct, &proof.final_evaluations)
+}
@@ -8,7 +8,9 @@
 //! This module provides a set of function for basic polynomial operations, including:
 //! - Polynomial evaluation using Horner method.
 //! - Polynomial interpolation using Lagrange method.
-//! - Polynomial addition, subtraction, multiplication, and division.
+//! - Fast multipoint evaluation and interpolation using subproduct trees.
+//! - Polynomial addition, subtraction, multiplication, and division; for large polynomials,
+//!   multiplication is performed via FFT and division via Newton iteration.
 //! - Synthetic polynomial division for efficient division by polynomials of the form `x`^`a` - `b`.
 //!
 //! In the context of this module any slice of field elements is considered to be a polynomial
@@ -28,11 +30,28 @@ use core::mem;
 
 use utils::group_slice_elements;
 
-use crate::{field::FieldElement, utils::batch_inversion};
+use crate::{
+    fft,
+    field::{FieldElement, StarkField},
+    utils::batch_inversion,
+};
+
+mod subproduct_tree;
+use subproduct_tree::SubproductTree;
 
 #[cfg(test)]
 mod tests;
 
+// CONSTANTS
+// ================================================================================================
+
+/// Minimum length of both operands for which polynomial multiplication is performed via FFT.
+const FFT_MUL_THRESHOLD: usize = 64;
+
+/// Minimum length of both the divisor and the quotient for which polynomial division is
+/// performed via Newton iteration.
+const FFT_DIV_THRESHOLD: usize = 64;
+
 // POLYNOMIAL EVALUATION
 // ================================================================================================
 
@@ -85,6 +104,37 @@ where
     xs.iter().map(|x| eval(p, *x)).collect()
 }
 
+/// Evaluates a polynomial at multiple points and returns a vector of results.
+///
+/// Builds a subproduct tree for `xs`, reduces polynomial `p` modulo the polynomial vanishing on
+/// all of `xs`, and then recursively reduces the remainders modulo the polynomials vanishing on
+/// halves of the points.
+/// With FFT-based multiplication and division, this takes *O(n log^2 n)* time, compared to
+/// *O(n * m)* for `polynom::eval_many()`; for small inputs, `polynom::eval_many()` is faster.
+///
+/// # Examples
+/// ```
+/// # use winter_math::polynom::*;
+/// # use winter_math::{fields::{f128::BaseElement}, FieldElement};
+/// # use rand_utils::rand_vector;
+/// let p: Vec<BaseElement> = rand_vector(256);
+/// let xs: Vec<BaseElement> = rand_vector(300);
+///
+/// assert_eq!(eval_many(&p, &xs), eval_many_fast(&p, &xs));
+/// ```
+pub fn eval_many_fast<B, E>(p: &[B], xs: &[E]) -> Vec<E>
+where
+    B: FieldElement,
+    E: FieldElement + From<B>,
+{
+    if xs.is_empty() {
+        return Vec::new();
+    }
+
+    let p = p.iter().map(|&coeff| E::from(coeff)).collect::<Vec<_>>();
+    SubproductTree::new(xs).evaluate(&p)
+}
+
 // POLYNOMIAL INTERPOLATION
 // ================================================================================================
 
@@ -136,6 +186,48 @@ where
     }
 }
 
+/// Returns a polynomial in coefficient form interpolated from a set of X and Y coordinates.
+///
+/// Builds a subproduct tree for the X coordinates and uses it to compute the Lagrange form of the
+/// polynomial in *O(n log^2 n)* time, compared to *O(n^2)* for `polynom::interpolate()`; for small
+/// inputs, `polynom::interpolate()` is faster. If `remove_leading_zeros = true`, all leading
+/// coefficients which are ZEROs will be truncated; otherwise, the length of result will be equal
+/// to the number of X coordinates.
+///
+/// The X coordinates must be distinct; otherwise, the result is undefined.
+///
+/// # Panics
+/// Panics if number of X and Y coordinates is not the same.
+///
+/// # Example
+/// ```
+/// # use winter_math::polynom::*;
+/// # use winter_math::{fields::{f128::BaseElement}, FieldElement};
+/// # use rand_utils::rand_vector;
+/// let xs: Vec<BaseElement> = rand_vector(300);
+/// let ys: Vec<BaseElement> = rand_vector(300);
+///
+/// let p = interpolate_fast(&xs, &ys, false);
+/// assert_eq!(interpolate(&xs, &ys, false), p);
+/// assert_eq!(ys, eval_many_fast(&p, &xs));
+/// ```
+pub fn interpolate_fast<E>(xs: &[E], ys: &[E], remove_leading_zeros: bool) -> Vec<E>
+where
+    E: FieldElement,
+{
+    assert!(xs.len() == ys.len(), "number of X and Y coordinates must be the same");
+    if xs.is_empty() {
+        return Vec::new();
+    }
+
+    let result = SubproductTree::new(xs).interpolate(ys);
+    if remove_leading_zeros {
+        crate::polynom::remove_leading_zeros(&result)
+    } else {
+        result
+    }
+}
+
 /// Returns a vector of polynomials interpolated from the provided X and Y coordinate batches.
 ///
 /// Uses [Lagrange interpolation](https://en.wikipedia.org/wiki/Lagrange_polynomial) to build a
@@ -284,6 +376,10 @@ where
 /// polynomial will be in the coefficient form as well. The length of the returned vector
 /// will be a.len() + b.len() - 1.
 ///
+/// When both polynomials have at least 64 coefficients and the base field contains a
+/// multiplicative subgroup large enough to hold the product, the multiplication is performed via
+/// FFT in *O(n log n)* time; otherwise, schoolbook multiplication is used.
+///
 /// # Examples
 /// ```
 /// # use winter_math::polynom::*;
@@ -307,6 +403,10 @@ where
     E: FieldElement,
 {
     let result_len = a.len() + b.len() - 1;
+    if a.len().min(b.len()) >= FFT_MUL_THRESHOLD && fft_supports::<E>(result_len) {
+        return mul_fft(a, b);
+    }
+
     let mut result = vec![E::ZERO; result_len];
     for i in 0..a.len() {
         for j in 0..b.len() {
@@ -350,6 +450,11 @@ where
 /// be in the coefficient form, and the returned polynomial will be in the coefficient form as
 /// well. The length of the returned vector will be a.len() - b.len() + 1.
 ///
+/// When both the divisor and the quotient have at least 64 coefficients and the base field
+/// supports FFT of the required size, the quotient is computed by multiplying the reversed
+/// dividend by the inverse of the reversed divisor, obtained via Newton iteration; otherwise,
+/// long division is used.
+///
 /// # Panics
 /// Panics if:
 /// * Polynomial `b` is empty.
@@ -388,7 +493,13 @@ where
         assert!(b[0] != E::ZERO, "cannot divide polynomial by zero");
     }
 
-    let mut result = vec![E::ZERO; apos - bpos + 1];
+    let quot_len = apos - bpos + 1;
+    if bpos >= FFT_DIV_THRESHOLD && quot_len >= FFT_DIV_THRESHOLD && fft_supports::<E>(2 * quot_len)
+    {
+        return div_newton(&a[..=apos], &b[..=bpos]);
+    }
+
+    let mut result = vec![E::ZERO; quot_len];
     for i in (0..result.len()).rev() {
         let quot = a[apos] / b[bpos];
         result[i] = quot;
@@ -670,6 +781,76 @@ pub fn poly_from_roots<E: FieldElement>(xs: &[E]) -> Vec<E> {
 // HELPER FUNCTIONS
 // ================================================================================================
 
+/// Returns true if the base field of `E` contains a multiplicative subgroup which can be used to
+/// evaluate polynomials with `num_coeffs` coefficients via FFT.
+fn fft_supports<E: FieldElement>(num_coeffs: usize) -> bool {
+    num_coeffs.next_power_of_two().ilog2() <= E::BaseField::TWO_ADICITY
+}
+
+/// Multiplies two polynomials by evaluating them over a domain large enough to hold the product,
+/// multiplying the evaluations point-wise, and interpolating the result.
+fn mul_fft<E: FieldElement>(a: &[E], b: &[E]) -> Vec<E> {
+    let result_len = a.len() + b.len() - 1;
+    let domain_size = result_len.next_power_of_two();
+
+    let twiddles = fft::get_twiddles::<E::BaseField>(domain_size);
+    let mut a_evals = a.to_vec();
+    a_evals.resize(domain_size, E::ZERO);
+    fft::evaluate_poly(&mut a_evals, &twiddles);
+    let mut b_evals = b.to_vec();
+    b_evals.resize(domain_size, E::ZERO);
+    fft::evaluate_poly(&mut b_evals, &twiddles);
+
+    for (a, b) in a_evals.iter_mut().zip(b_evals) {
+        *a *= b;
+    }
+
+    let inv_twiddles = fft::get_inv_twiddles::<E::BaseField>(domain_size);
+    fft::interpolate_poly(&mut a_evals, &inv_twiddles);
+    a_evals.truncate(result_len);
+    a_evals
+}
+
+/// Divides polynomial `a` by polynomial `b`, ignoring the remainder; the leading coefficients of
+/// both polynomials must be non-zero.
+///
+/// For a = q * b + r, reversing coefficients gives rev(a) = rev(q) * rev(b) mod x^k, where k is
+/// the length of the quotient. Thus, the quotient can be obtained from the first k coefficients
+/// of rev(a) * rev(b)^-1.
+fn div_newton<E: FieldElement>(a: &[E], b: &[E]) -> Vec<E> {
+    let quot_len = a.len() - b.len() + 1;
+    let a_rev = a.iter().rev().take(quot_len).copied().collect::<Vec<_>>();
+    let b_rev = b.iter().rev().copied().collect::<Vec<_>>();
+
+    let b_rev_inv = inv_power_series(&b_rev, quot_len);
+    let mut result = mul(&a_rev, &b_rev_inv);
+    result.truncate(quot_len);
+    result.reverse();
+    result
+}
+
+/// Returns the first `n` coefficients of the power series inverse of polynomial `p`; the constant
+/// coefficient of `p` must be non-zero.
+///
+/// Each Newton iteration g' = g * (2 - p * g) doubles the number of correct coefficients of g.
+fn inv_power_series<E: FieldElement>(p: &[E], n: usize) -> Vec<E> {
+    let mut result = vec![p[0].inv()];
+    let mut k = 1;
+    while k < n {
+        k = (2 * k).min(n);
+
+        // compute 2 - p * g mod x^k
+        let mut e = mul(&p[..k.min(p.len())], &result);
+        e.resize(k, E::ZERO);
+        e.iter_mut().for_each(|c| *c = -*c);
+        e[0] += E::from(2u8);
+
+        result = mul(&result, &e);
+        result.truncate(k);
+    }
+    result
+}
+
 fn fill_zero_roots<E: FieldElement>(xs: &[E], result: &mut [E]) {
     let mut n = result.len();
     n -= 1;
@@ -0,0 +1,119 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use alloc::vec::Vec;
+
+use super::{degree_of, div, eval, mul, sub};
+use crate::{field::FieldElement, utils::batch_inversion};
+
+// SUBPRODUCT TREE
+// ================================================================================================
+
+/// A binary tree of polynomials vanishing on subsets of a set of points.
+///
+/// Leaves of the tree are polynomials (x - x_i), and every internal node is the product of its
+/// children; thus, the root is the polynomial vanishing on all points. When the number of nodes
+/// at some level is odd, the last node is carried to the next level unchanged.
+pub(super) struct SubproductTree<E: FieldElement> {
+    levels: Vec<Vec<Vec<E>>>,
+}
+
+impl<E: FieldElement> SubproductTree<E> {
+    /// Builds a subproduct tree for the provided points; `xs` must not be empty.
+    pub fn new(xs: &[E]) -> Self {
+        debug_assert!(!xs.is_empty(), "subproduct tree requires at least one point");
+        let mut levels = vec![xs.iter().map(|&x| vec![-x, E::ONE]).collect::<Vec<_>>()];
+        while levels[levels.len() - 1].len() > 1 {
+            let level = levels[levels.len() - 1]
+                .chunks(2)
+                .map(|pair| match pair {
+                    [left, right] => mul(left, right),
+                    [node] => node.clone(),
+                    _ => unreachable!(),
+                })
+                .collect();
+            levels.push(level);
+        }
+        Self { levels }
+    }
+
+    /// Returns the polynomial vanishing on all points of this tree.
+    pub fn root(&self) -> &[E] {
+        &self.levels[self.levels.len() - 1][0]
+    }
+
+    /// Evaluates polynomial `p` at all points of this tree.
+    ///
+    /// The polynomial is reduced modulo the root, and the remainders are then recursively reduced
+    /// modulo the children of each node; at the leaves, remainders are constants equal to the
+    /// evaluations of `p`.
+    pub fn evaluate(&self, p: &[E]) -> Vec<E> {
+        let mut remainders = vec![rem(p, self.root())];
+        for level in self.levels.iter().rev().skip(1) {
+            remainders = level
+                .iter()
+                .enumerate()
+                .map(|(i, node)| rem(&remainders[i / 2], node))
+                .collect();
+        }
+
+        remainders.into_iter().map(|r| eval(&r, E::ZERO)).collect()
+    }
+
+    /// Returns the polynomial of degree smaller than the number of points of this tree which
+    /// evaluates to `ys` at these points; the points must be distinct.
+    ///
+    /// Writing the Lagrange form of the polynomial as sum_i c_i * m(x) / (x - x_i), where m is
+    /// the root of the tree, the weights c_i = y_i / m'(x_i) are computed via a single multipoint
+    /// evaluation, and the sum is then accumulated from the leaves up to the root.
+    pub fn interpolate(&self, ys: &[E]) -> Vec<E> {
+        let root = self.root();
+        let derivative = root
+            .iter()
+            .enumerate()
+            .skip(1)
+            .map(|(i, &c)| c * E::from(i as u32))
+            .collect::<Vec<_>>();
+        let weights = batch_inversion(&self.evaluate(&derivative));
+
+        let mut result = ys.iter().zip(weights).map(|(&y, w)| vec![y * w]).collect::<Vec<_>>();
+        for level in self.levels.iter().take(self.levels.len() - 1) {
+            result = result
+                .chunks(2)
+                .zip(level.chunks(2))
+                .map(|(values, nodes)| match (values, nodes) {
+                    ([left, right], [left_node, right_node]) => {
+                        let mut sum = mul(left, right_node);
+                        for (s, v) in sum.iter_mut().zip(mul(right, left_node)) {
+                            *s += v;
+                        }
+                        sum
+                    },
+                    ([value], [_]) => value.clone(),
+                    _ => unreachable!(),
+                })
+                .collect();
+        }
+
+        let mut result = result.remove(0);
+        result.resize(ys.len(), E::ZERO);
+        result
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the remainder of dividing polynomial `a` by a monic polynomial `b`; the length of the
+/// returned vector is `b.len() - 1`.
+fn rem<E: FieldElement>(a: &[E], b: &[E]) -> Vec<E> {
+    let mut result = if degree_of(a) < degree_of(b) {
+        a.to_vec()
+    } else {
+        sub(a, &mul(&div(a, b), b))
+    };
+    result.resize(b.len() - 1, E::ZERO);
+    result
+}
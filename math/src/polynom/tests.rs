@@ -5,9 +5,11 @@
 
 use alloc::vec::Vec;
 
+use rand_utils::rand_vector;
+
 use super::remove_leading_zeros;
 use crate::{
-    field::{f128::BaseElement, FieldElement, StarkField},
+    field::{f128::BaseElement, f64, FieldElement, QuadExtension, StarkField},
     utils::get_power_series,
 };
 
@@ -38,6 +40,23 @@ fn eval() {
     assert_eq!(poly[0] + poly[1] * x + poly[2] * x2 + poly[3] * x3, super::eval(&poly, x));
 }
 
+#[test]
+fn eval_many_fast() {
+    for (num_coeffs, num_points) in [(0, 5), (1, 1), (3, 2), (17, 33), (300, 100), (100, 300)] {
+        let poly: Vec<BaseElement> = rand_vector(num_coeffs);
+        let xs: Vec<BaseElement> = rand_vector(num_points);
+        assert_eq!(super::eval_many(&poly, &xs), super::eval_many_fast(&poly, &xs));
+    }
+
+    // base field polynomial evaluated at extension field points
+    let poly: Vec<f64::BaseElement> = rand_vector(200);
+    let xs: Vec<QuadExtension<f64::BaseElement>> = rand_vector(150);
+    assert_eq!(super::eval_many(&poly, &xs), super::eval_many_fast(&poly, &xs));
+
+    let xs: Vec<BaseElement> = Vec::new();
+    assert!(super::eval_many_fast(&[BaseElement::ONE], &xs).is_empty());
+}
+
 #[test]
 fn add() {
     let poly1: [BaseElement; 3] = [
@@ -90,6 +109,24 @@ fn sub() {
     assert_eq!(pr, super::sub(&poly1, &poly2[..2]));
 }
 
+#[test]
+fn interpolate_fast() {
+    for n in [1, 2, 5, 64, 257] {
+        let xs: Vec<BaseElement> = rand_vector(n);
+        let ys: Vec<BaseElement> = rand_vector(n);
+        let poly = super::interpolate_fast(&xs, &ys, false);
+        assert_eq!(super::interpolate(&xs, &ys, false), poly);
+        assert_eq!(ys, super::eval_many(&poly, &xs));
+    }
+
+    // points on a line produce a polynomial of degree 1
+    let xs: Vec<QuadExtension<f64::BaseElement>> = rand_vector(100);
+    let ys = xs.iter().map(|&x| x.double() + QuadExtension::ONE).collect::<Vec<_>>();
+    let poly = super::interpolate_fast(&xs, &ys, true);
+    assert_eq!(vec![QuadExtension::ONE, QuadExtension::from(2u8)], poly);
+    assert_eq!(100, super::interpolate_fast(&xs, &ys, false).len());
+}
+
 #[test]
 fn mul() {
     let poly1: [BaseElement; 3] = [
@@ -158,6 +195,37 @@ fn div() {
     assert_eq!(vec![BaseElement::new(11269864713250585702u128)], super::div(&poly3, &poly1));
 }
 
+#[test]
+fn mul_fft() {
+    // operands above the threshold are multiplied via FFT
+    let poly1: Vec<BaseElement> = rand_vector(100);
+    let poly2: Vec<BaseElement> = rand_vector(300);
+    assert_eq!(mul_schoolbook(&poly1, &poly2), super::mul(&poly1, &poly2));
+
+    // extension field elements
+    let poly1: Vec<QuadExtension<f64::BaseElement>> = rand_vector(128);
+    let poly2: Vec<QuadExtension<f64::BaseElement>> = rand_vector(129);
+    assert_eq!(mul_schoolbook(&poly1, &poly2), super::mul(&poly1, &poly2));
+}
+
+#[test]
+fn div_newton() {
+    let quotient: Vec<BaseElement> = rand_vector(150);
+    let divisor: Vec<BaseElement> = rand_vector(120);
+    let remainder: Vec<BaseElement> = rand_vector(119);
+
+    // a = q * b + r, with leading zeros appended to a
+    let mut poly = super::add(&super::mul(&quotient, &divisor), &remainder);
+    poly.resize(poly.len() + 5, BaseElement::ZERO);
+    assert_eq!(quotient, super::div(&poly, &divisor));
+
+    // extension field elements
+    let quotient: Vec<QuadExtension<f64::BaseElement>> = rand_vector(200);
+    let divisor: Vec<QuadExtension<f64::BaseElement>> = rand_vector(80);
+    let poly = super::mul(&quotient, &divisor);
+    assert_eq!(quotient, super::div(&poly, &divisor));
+}
+
 #[test]
 fn syn_div() {
     // ----- division by degree 1 polynomial ------------------------------------------------------
@@ -236,3 +304,16 @@ fn syn_div() {
     let result = super::syn_div(&poly, 4, root.exp(4));
     assert_eq!(poly, remove_leading_zeros(&super::mul(&result, &z_poly)));
 }
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn mul_schoolbook<E: FieldElement>(a: &[E], b: &[E]) -> Vec<E> {
+    let mut result = vec![E::ZERO; a.len() + b.len() - 1];
+    for (i, &a) in a.iter().enumerate() {
+        for (j, &b) in b.iter().enumerate() {
+            result[i + j] += a * b;
+        }
+    }
+    result
+}
@@ -141,7 +141,15 @@ where
     E: FieldElement<BaseField = F::BaseField> + ExtensionOf<F>,
 {
     assert!(a.len() == b.len(), "number of values must be the same for both slices");
-    iter_mut!(a).zip(b).for_each(|(a, &b)| *a += c.mul_base(b));
+    if F::EXTENSION_DEGREE == 1 {
+        // when F is a base field, delegate to batch multiply-accumulate which may be vectorized
+        let b = F::slice_as_base_elements(b);
+        batch_iter_mut!(a, 1024, |batch: &mut [E], batch_offset: usize| {
+            E::batch_mul_acc(batch, &b[batch_offset..batch_offset + batch.len()], c);
+        });
+    } else {
+        iter_mut!(a).zip(b).for_each(|(a, &b)| *a += c.mul_base(b));
+    }
 }
 
 /// Computes a multiplicative inverse of a sequence of elements using batch inversion method.
@@ -85,15 +85,15 @@ where
     // --------------------------------------------------------------------------------------------
 
     /// Commits the prover the extended execution trace.
-    pub fn commit_trace(&mut self, trace_root: H::Digest) {
-        self.commitments.add::<H>(&trace_root);
-        self.public_coin.reseed(trace_root);
+    pub fn commit_trace(&mut self, trace_commitment: V::Commitment) {
+        self.commitments.add(&trace_commitment);
+        self.public_coin.reseed(V::commitment_digest(&trace_commitment));
     }
 
     /// Commits the prover to the evaluations of the constraint composition polynomial.
-    pub fn commit_constraints(&mut self, constraint_root: H::Digest) {
-        self.commitments.add::<H>(&constraint_root);
-        self.public_coin.reseed(constraint_root);
+    pub fn commit_constraints(&mut self, constraint_commitment: V::Commitment) {
+        self.commitments.add(&constraint_commitment);
+        self.public_coin.reseed(V::commitment_digest(&constraint_commitment));
     }
 
     /// Saves the evaluations of the trace and constraint composition polynomials over
@@ -150,7 +150,7 @@ where
     /// are removed from the returned vector.
     pub fn get_query_positions(&mut self) -> Vec<usize> {
         let num_queries = self.context.options().num_queries();
-        let lde_domain_size = self.context.lde_domain_size();
+        let lde_domain_size = self.air.lde_domain_size();
         let mut positions = self
             .public_coin
             .draw_integers(num_queries, lde_domain_size, self.pow_nonce)
@@ -221,11 +221,18 @@ where
     V: VectorCommitment<H>,
 {
     type Hasher = H;
+    type VectorCommitment = V;
 
     /// Commits the prover to a FRI layer.
-    fn commit_fri_layer(&mut self, layer_root: H::Digest) {
-        self.commitments.add::<H>(&layer_root);
-        self.public_coin.reseed(layer_root);
+    fn commit_fri_layer(&mut self, layer_commitment: V::Commitment) {
+        self.commitments.add(&layer_commitment);
+        self.public_coin.reseed(V::commitment_digest(&layer_commitment));
+    }
+
+    /// Commits the prover to the FRI remainder polynomial.
+    fn commit_fri_remainder(&mut self, remainder_commitment: H::Digest) {
+        self.commitments.add(&remainder_commitment);
+        self.public_coin.reseed(remainder_commitment);
     }
 
     /// Returns a new alpha drawn from the public coin.
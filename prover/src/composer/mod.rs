@@ -58,11 +58,14 @@ impl<E: FieldElement> DeepCompositionPoly<E> {
     /// - Compute polynomials T'_i(x) = (T_i(x) - T_i(z)) / (x - z) and
     ///   T''_i(x) = (T_i(x) - T_i(z * g)) / (x - z * g) for all i, where T_i(x) is a trace
     ///   or quotient polynomial for column i.
-    /// - Then, combine together all T'_i(x) and T''_i(x) polynomials using a random linear
-    ///   combination as T(x) = sum((T'_i(x) + T''_i(x)) * cc_i) for all i, where cc_i is
-    ///   the coefficient for the random linear combination drawn from the public coin.
+    /// - If the evaluation frame contains additional rows, also compute polynomials
+    ///   (T_i(x) - T_i(z * g^k)) / (x - z * g^k) for all trace polynomials T_i(x) and all
+    ///   additional frame offsets k.
+    /// - Then, combine together all of these polynomials using a random linear combination as
+    ///   T(x) = sum((T'_i(x) + T''_i(x) + ...) * cc_i) for all i, where cc_i is the coefficient
+    ///   for the random linear combination drawn from the public coin.
     ///
-    /// Note that evaluations of T_i(z) and T_i(z * g) are passed in via the `ood_trace_state`
+    /// Note that evaluations of T_i(z), T_i(z * g) etc. are passed in via the `ood_trace_state`
     /// and `ood_quotient_states` parameter.
     pub fn add_trace_polys(
         &mut self,
@@ -73,15 +76,23 @@ impl<E: FieldElement> DeepCompositionPoly<E> {
     ) {
         assert!(self.coefficients.is_empty());
 
-        // compute a second out-of-domain point offset from z by exactly trace generator; this
-        // point defines the "next" computation state in relation to point z
-        let trace_length = trace_polys.poly_size();
-        let g = E::from(E::BaseField::get_root_of_unity(trace_length.ilog2()));
-        let next_z = self.z * g;
-
-        // combine trace polynomials into 2 composition polynomials T'(x) and T''(x)
-        let mut composition_z = vec![E::ZERO; trace_length];
-        let mut composition_gz = vec![E::ZERO; trace_length];
+        // compute out-of-domain points offset from z by powers of the trace generator; the point
+        // offset by exactly trace generator defines the "next" computation state in relation to
+        // point z
+        let trace_length = trace_polys.trace_length();
+        let g = E::BaseField::get_root_of_unity(trace_length.ilog2());
+        let ood_points: Vec<E> = ood_trace_states
+            .offsets()
+            .iter()
+            .map(|&offset| {
+                let power = offset.rem_euclid(trace_length as isize) as u64;
+                self.z.mul_base(g.exp_vartime(power.into()))
+            })
+            .collect();
+
+        // combine trace polynomials into one composition polynomial per out-of-domain point; the
+        // first two of these are T'(x) and T''(x)
+        let mut compositions = vec![vec![E::ZERO; trace_polys.poly_size()]; ood_points.len()];
 
         // index of a trace polynomial; we declare it here so that we can maintain index continuity
         // across all trace segments
@@ -89,46 +100,32 @@ impl<E: FieldElement> DeepCompositionPoly<E> {
 
         // --- merge polynomials of the main trace segment ----------------------------------------
         for poly in trace_polys.main_trace_polys() {
-            // compute T'(x) = T(x) - T(z), multiply it by a pseudo-random coefficient,
-            // and add the result into composition polynomial
-            acc_trace_poly::<E::BaseField, E>(
-                &mut composition_z,
-                poly,
-                ood_trace_states.current_row()[i],
-                self.cc.trace[i],
-            );
-
-            // compute T''(x) = T(x) - T(z * g), multiply it by a pseudo-random coefficient,
-            // and add the result into composition polynomial
-            acc_trace_poly::<E::BaseField, E>(
-                &mut composition_gz,
-                poly,
-                ood_trace_states.next_row()[i],
-                self.cc.trace[i],
-            );
+            // compute T(x) - T(z * g^k) for each frame offset k, multiply it by a pseudo-random
+            // coefficient, and add the result into the corresponding composition polynomial
+            for (k, composition) in compositions.iter_mut().enumerate() {
+                acc_trace_poly::<E::BaseField, E>(
+                    composition,
+                    poly,
+                    ood_trace_states.row(k)[i],
+                    self.cc.trace[i],
+                );
+            }
 
             i += 1;
         }
 
         // --- merge polynomials of the auxiliary trace segment ----------------------------------
         for poly in trace_polys.aux_trace_polys() {
-            // compute T'(x) = T(x) - T(z), multiply it by a pseudo-random coefficient,
-            // and add the result into composition polynomial
-            acc_trace_poly::<E, E>(
-                &mut composition_z,
-                poly,
-                ood_trace_states.current_row()[i],
-                self.cc.trace[i],
-            );
-
-            // compute T''(x) = T(x) - T(z * g), multiply it by a pseudo-random coefficient,
-            // and add the result into composition polynomial
-            acc_trace_poly::<E, E>(
-                &mut composition_gz,
-                poly,
-                ood_trace_states.next_row()[i],
-                self.cc.trace[i],
-            );
+            // compute T(x) - T(z * g^k) for each frame offset k, multiply it by a pseudo-random
+            // coefficient, and add the result into the corresponding composition polynomial
+            for (k, composition) in compositions.iter_mut().enumerate() {
+                acc_trace_poly::<E, E>(
+                    composition,
+                    poly,
+                    ood_trace_states.row(k)[i],
+                    self.cc.trace[i],
+                );
+            }
 
             i += 1;
         }
@@ -138,7 +135,7 @@ impl<E: FieldElement> DeepCompositionPoly<E> {
             // compute T'(x) = T(x) - T(z), multiply it by a pseudo-random coefficient,
             // and add the result into composition polynomial
             acc_trace_poly::<E, E>(
-                &mut composition_z,
+                &mut compositions[0],
                 poly,
                 ood_quotient_states.current_row()[i],
                 self.cc.constraints[i],
@@ -147,18 +144,17 @@ impl<E: FieldElement> DeepCompositionPoly<E> {
             // compute T''(x) = T(x) - T(z * g), multiply it by a pseudo-random coefficient,
             // and add the result into composition polynomial
             acc_trace_poly::<E, E>(
-                &mut composition_gz,
+                &mut compositions[1],
                 poly,
                 ood_quotient_states.next_row()[i],
                 self.cc.constraints[i],
             );
         }
 
-        // divide the composition polynomials by (x - z) and (x - z * g), respectively,
+        // divide the composition polynomials by (x - z), (x - z * g) etc., respectively,
         // and add the resulting polynomials together; the output of this step
         // is a single trace polynomial T(x) and deg(T(x)) = trace_length - 2.
-        let trace_poly =
-            merge_compositions(vec![composition_z, composition_gz], vec![self.z, next_z]);
+        let trace_poly = merge_compositions(compositions, ood_points);
 
         // set the coefficients of the DEEP composition polynomial
         self.coefficients = trace_poly;
@@ -173,7 +169,7 @@ impl<E: FieldElement> DeepCompositionPoly<E> {
             &self.coefficients,
             domain.trace_twiddles(),
             domain.offset(),
-            domain.trace_to_lde_blowup(),
+            domain.trace_poly_to_lde_blowup(),
         )
     }
 }
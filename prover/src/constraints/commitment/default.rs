@@ -23,6 +23,9 @@ use crate::{CompositionPoly, CompositionPolyTrace, StarkDomain, DEFAULT_SEGMENT_
 /// * Evaluations of composition polynomial columns over the LDE domain.
 /// * Vector commitment where each vector element corresponds to the digest of a row in the
 ///   composition polynomial evaluation matrix.
+///
+/// In zero-knowledge mode, each row is salted before it is committed to, and salts of the queried
+/// leaves are appended to the queried rows.
 pub struct DefaultConstraintCommitment<
     E: FieldElement,
     H: ElementHasher<BaseField = E::BaseField>,
@@ -30,6 +33,7 @@ pub struct DefaultConstraintCommitment<
 > {
     evaluations: RowMatrix<E>,
     vector_commitment: V,
+    salts: Vec<E>,
     _h: PhantomData<H>,
 }
 
@@ -48,12 +52,13 @@ where
         partition_options: PartitionOptions,
     ) -> (Self, CompositionPoly<E>) {
         // extend the main execution trace and build a commitment to the extended trace
-        let (evaluations, commitment, composition_poly) = build_constraint_commitment::<E, H, V>(
-            composition_poly_trace,
-            num_constraint_composition_columns,
-            domain,
-            partition_options,
-        );
+        let (evaluations, commitment, salts, composition_poly) =
+            build_constraint_commitment::<E, H, V>(
+                composition_poly_trace,
+                num_constraint_composition_columns,
+                domain,
+                partition_options,
+            );
 
         assert_eq!(
             evaluations.num_rows(),
@@ -65,6 +70,7 @@ where
         let commitment = Self {
             evaluations,
             vector_commitment: commitment,
+            salts,
             _h: PhantomData,
         };
 
@@ -82,7 +88,7 @@ where
     type VC = V;
 
     /// Returns the commitment.
-    fn commitment(&self) -> H::Digest {
+    fn commitment(&self) -> V::Commitment {
         self.vector_commitment.commitment()
     }
 
@@ -95,10 +101,13 @@ where
             .open_many(positions)
             .expect("failed to generate a batch opening proof for constraint queries");
 
-        // determine a set of evaluations corresponding to each position
+        // determine a set of evaluations corresponding to each position; in zero-knowledge mode
+        // the salt of each leaf is appended to its row
+        let salt_len = self.salts.len() / self.evaluations.num_rows();
         let mut evaluations = Vec::new();
         for &position in positions {
-            let row = self.evaluations.row(position).to_vec();
+            let mut row = self.evaluations.row(position).to_vec();
+            row.extend_from_slice(&self.salts[position * salt_len..(position + 1) * salt_len]);
             evaluations.push(row);
         }
 
@@ -111,7 +120,7 @@ fn build_constraint_commitment<E, H, V>(
     num_constraint_composition_columns: usize,
     domain: &StarkDomain<E::BaseField>,
     partition_options: PartitionOptions,
-) -> (RowMatrix<E>, V, CompositionPoly<E>)
+) -> (RowMatrix<E>, V, Vec<E>, CompositionPoly<E>)
 where
     E: FieldElement,
     H: ElementHasher<BaseField = E::BaseField>,
@@ -129,7 +138,7 @@ where
         CompositionPoly::new(composition_poly_trace, domain, num_constraint_composition_columns)
     });
     assert_eq!(composition_poly.num_columns(), num_constraint_composition_columns);
-    assert_eq!(composition_poly.column_degree(), domain.trace_length() - 1);
+    assert_eq!(composition_poly.column_degree(), domain.trace_poly_size() - 1);
 
     // then, evaluate composition polynomial columns over the LDE domain
     let domain_size = domain.lde_domain_size();
@@ -139,12 +148,20 @@ where
     assert_eq!(composed_evaluations.num_cols(), num_constraint_composition_columns);
     assert_eq!(composed_evaluations.num_rows(), domain_size);
 
-    // finally, build constraint evaluation commitment
-    let commitment = info_span!(
+    // finally, build constraint evaluation commitment; in zero-knowledge mode, rows are salted
+    let (commitment, salts) = info_span!(
         "compute_constraint_evaluation_commitment",
         log_domain_size = domain_size.ilog2()
     )
-    .in_scope(|| composed_evaluations.commit_to_rows::<H, V>(partition_options));
+    .in_scope(|| match domain.zk() {
+        Some(zk) => {
+            let salts = zk.draw_composition_salts(domain_size);
+            let commitment =
+                composed_evaluations.commit_to_salted_rows::<H, V>(&salts, partition_options);
+            (commitment, salts)
+        },
+        None => (composed_evaluations.commit_to_rows::<H, V>(partition_options), Vec::new()),
+    });
 
-    (composed_evaluations, commitment, composition_poly)
+    (composed_evaluations, commitment, salts, composition_poly)
 }
@@ -4,7 +4,7 @@
 // LICENSE file in the root directory of this source tree.
 
 use air::proof::Queries;
-use crypto::{ElementHasher, Hasher, VectorCommitment};
+use crypto::{ElementHasher, VectorCommitment};
 use math::FieldElement;
 
 use super::RowMatrix;
@@ -29,7 +29,7 @@ pub trait ConstraintCommitment<E: FieldElement> {
     type VC: VectorCommitment<Self::HashFn>;
 
     /// Returns the commitment.
-    fn commitment(&self) -> <Self::HashFn as Hasher>::Digest;
+    fn commitment(&self) -> <Self::VC as VectorCommitment<Self::HashFn>>::Commitment;
 
     /// Returns constraint evaluations at the specified positions along with a batch opening proof
     /// against the vector commitment.
@@ -49,8 +49,13 @@ impl<E: FieldElement> CompositionPolyTrace<E> {
 ///
 /// For example, if the composition polynomial has degree 2N - 1, where N is the trace length,
 /// it will be stored as two columns of size N (each of degree N - 1).
+///
+/// In zero-knowledge mode, columns are of the same length as randomized trace polynomials, the
+/// composition polynomial is split into shorter segments which are then masked, and the last
+/// column is a random polynomial (see [ZkRandomizer](crate::ZkRandomizer)).
 pub struct CompositionPoly<E: FieldElement> {
     data: ColMatrix<E>,
+    trace_length: usize,
 }
 
 impl<E: FieldElement> CompositionPoly<E> {
@@ -72,9 +77,15 @@ impl<E: FieldElement> CompositionPoly<E> {
         let inv_twiddles = fft::get_inv_twiddles::<E::BaseField>(trace.len());
         fft::interpolate_poly_with_offset(&mut trace, &inv_twiddles, domain.offset());
 
-        let polys = segment(trace, domain.trace_length(), num_cols);
+        let polys = match domain.zk() {
+            Some(zk) => zk.randomize_composition_poly(trace, num_cols),
+            None => segment(trace, domain.trace_length(), num_cols),
+        };
 
-        CompositionPoly { data: ColMatrix::new(polys) }
+        CompositionPoly {
+            data: ColMatrix::new(polys),
+            trace_length: domain.trace_length(),
+        }
     }
 
     // PUBLIC ACCESSORS
@@ -97,9 +108,10 @@ impl<E: FieldElement> CompositionPoly<E> {
         self.column_len() - 1
     }
 
-    /// Returns evaluations of all composition polynomial columns at points `z` and `g * z`.
+    /// Returns evaluations of all composition polynomial columns at points `z` and `g * z`, where
+    /// `g` is the generator of the trace domain.
     pub fn get_ood_frame(&self, z: E) -> QuotientOodFrame<E> {
-        let log_trace_len = self.column_len().ilog2();
+        let log_trace_len = self.trace_length.ilog2();
         let g = E::from(E::BaseField::get_root_of_unity(log_trace_len));
         let current_row = self.data.evaluate_columns_at(z);
         let next_row = self.data.evaluate_columns_at(z * g);
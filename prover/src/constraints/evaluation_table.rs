@@ -35,6 +35,8 @@ pub struct ConstraintEvaluationTable<'a, E: FieldElement> {
     aux_transition_evaluations: Vec<Vec<E>>,
     #[cfg(debug_assertions)]
     expected_transition_degrees: Vec<usize>,
+    #[cfg(debug_assertions)]
+    transition_constraint_divisors: Vec<usize>,
 }
 
 impl<'a, E: FieldElement> ConstraintEvaluationTable<'a, E> {
@@ -72,8 +74,13 @@ impl<'a, E: FieldElement> ConstraintEvaluationTable<'a, E> {
 
         // collect expected degrees for all transition constraints to compare them against actual
         // degrees; we do this in debug mode only because this comparison is expensive
-        let expected_transition_degrees =
-            build_transition_constraint_degrees(transition_constraints, domain.trace_length());
+        let num_randomizer_coeffs =
+            domain.zk().map_or(0, |zk| zk.num_trace_randomizer_coefficients());
+        let expected_transition_degrees = build_transition_constraint_degrees(
+            transition_constraints,
+            domain.trace_length(),
+            num_randomizer_coeffs,
+        );
 
         ConstraintEvaluationTable {
             evaluations: uninit_matrix(num_columns, num_rows),
@@ -82,6 +89,12 @@ impl<'a, E: FieldElement> ConstraintEvaluationTable<'a, E> {
             main_transition_evaluations: uninit_matrix(num_tm_columns, num_rows),
             aux_transition_evaluations: uninit_matrix(num_ta_columns, num_rows),
             expected_transition_degrees,
+            transition_constraint_divisors: transition_constraints
+                .main_constraint_divisors()
+                .iter()
+                .chain(transition_constraints.aux_constraint_divisors())
+                .copied()
+                .collect(),
         }
     }
 
@@ -96,9 +109,9 @@ impl<'a, E: FieldElement> ConstraintEvaluationTable<'a, E> {
 
     /// Returns number of columns in this table.
     ///
-    /// The first column always contains the value of combined transition constraint evaluations;
-    /// the remaining columns contain values of assertion constraint evaluations combined based on
-    /// common divisors.
+    /// The first columns contain values of transition constraint evaluations combined based on
+    /// common divisors (usually, there is just one such column); the remaining columns contain
+    /// values of assertion constraint evaluations combined based on common divisors.
     #[allow(dead_code)]
     pub fn num_columns(&self) -> usize {
         self.evaluations.len()
@@ -180,14 +193,17 @@ impl<'a, E: FieldElement> ConstraintEvaluationTable<'a, E> {
 
     #[cfg(debug_assertions)]
     pub fn validate_transition_degrees(&mut self) {
-        // evaluate transition constraint divisor (which is assumed to be the first one in the
+        // evaluate transition constraint divisors (which are assumed to be at the front of the
         // divisor list) over the constraint evaluation domain. this is used later to compute
         // actual degrees of transition constraint evaluations.
-        let div_values = evaluate_divisor::<E::BaseField>(
-            &self.divisors[0],
-            self.num_rows(),
-            self.domain.offset(),
-        );
+        let num_transition_divisors =
+            self.transition_constraint_divisors.iter().max().map_or(1, |&idx| idx + 1);
+        let div_values = self.divisors[..num_transition_divisors]
+            .iter()
+            .map(|divisor| {
+                evaluate_divisor::<E::BaseField>(divisor, self.num_rows(), self.domain.offset())
+            })
+            .collect::<Vec<_>>();
 
         // collect actual degrees for all transition constraints by interpolating saved
         // constraint evaluations into polynomials and checking their degree; also
@@ -195,17 +211,20 @@ impl<'a, E: FieldElement> ConstraintEvaluationTable<'a, E> {
         let mut actual_degrees = Vec::with_capacity(self.expected_transition_degrees.len());
         let mut max_degree = 0;
         let inv_twiddles = fft::get_inv_twiddles::<E::BaseField>(self.num_rows());
+        let mut divisor_indexes = self.transition_constraint_divisors.iter();
 
         // first process transition constraint evaluations for the main trace segment
         for evaluations in self.main_transition_evaluations.iter() {
-            let degree = get_transition_poly_degree(evaluations, &inv_twiddles, &div_values);
+            let div_values = &div_values[*divisor_indexes.next().unwrap()];
+            let degree = get_transition_poly_degree(evaluations, &inv_twiddles, div_values);
             actual_degrees.push(degree);
             max_degree = core::cmp::max(max_degree, degree);
         }
 
         // then process transition constraint evaluations for the auxiliary trace segment
         for evaluations in self.aux_transition_evaluations.iter() {
-            let degree = get_transition_poly_degree(evaluations, &inv_twiddles, &div_values);
+            let div_values = &div_values[*divisor_indexes.next().unwrap()];
+            let degree = get_transition_poly_degree(evaluations, &inv_twiddles, div_values);
             actual_degrees.push(degree);
             max_degree = core::cmp::max(max_degree, degree);
         }
@@ -217,9 +236,13 @@ impl<'a, E: FieldElement> ConstraintEvaluationTable<'a, E> {
             self.expected_transition_degrees, actual_degrees
         );
 
-        // make sure evaluation domain size does not exceed the size required by max degree
-        let expected_domain_size =
-            core::cmp::max(max_degree, self.domain.trace_length() + 1).next_power_of_two();
+        // make sure evaluation domain size does not exceed the size required by max degree; in
+        // zero-knowledge mode, the domain must also be larger than randomized trace polynomials
+        let expected_domain_size = if self.domain.zk().is_some() {
+            core::cmp::max(max_degree + 1, self.domain.trace_poly_size() + 1).next_power_of_two()
+        } else {
+            core::cmp::max(max_degree, self.domain.trace_length() + 1).next_power_of_two()
+        };
         assert_eq!(
             expected_domain_size,
             self.num_rows(),
@@ -422,15 +445,32 @@ fn get_inv_evaluation<B: StarkField>(
 fn build_transition_constraint_degrees<E: FieldElement>(
     constraints: &TransitionConstraints<E>,
     trace_length: usize,
+    num_randomizer_coeffs: usize,
 ) -> Vec<usize> {
     let mut result = Vec::new();
 
-    for degree in constraints.main_constraint_degrees() {
-        result.push(degree.get_evaluation_degree(trace_length) - constraints.divisor().degree())
+    for (degree, &divisor_idx) in constraints
+        .main_constraint_degrees()
+        .iter()
+        .zip(constraints.main_constraint_divisors())
+    {
+        let divisor = &constraints.divisors()[divisor_idx];
+        result.push(
+            degree.get_randomized_evaluation_degree(trace_length, num_randomizer_coeffs)
+                - divisor.degree(),
+        )
     }
 
-    for degree in constraints.aux_constraint_degrees() {
-        result.push(degree.get_evaluation_degree(trace_length) - constraints.divisor().degree())
+    for (degree, &divisor_idx) in constraints
+        .aux_constraint_degrees()
+        .iter()
+        .zip(constraints.aux_constraint_divisors())
+    {
+        let divisor = &constraints.divisors()[divisor_idx];
+        result.push(
+            degree.get_randomized_evaluation_degree(trace_length, num_randomizer_coeffs)
+                - divisor.degree(),
+        )
     }
 
     result
@@ -68,10 +68,10 @@ where
             "extended trace length is not consistent with evaluation domain"
         );
 
-        // build a list of constraint divisors; currently, all transition constraints have the same
-        // divisor which we put at the front of the list; boundary constraint divisors are appended
-        // after that
-        let mut divisors = vec![self.transition_constraints.divisor().clone()];
+        // build a list of constraint divisors; transition constraint divisors are put at the front
+        // of the list (usually, all transition constraints have the same divisor); boundary
+        // constraint divisors are appended after that
+        let mut divisors = self.transition_constraints.divisors().to_vec();
         divisors.append(&mut self.boundary_constraints.get_divisors());
 
         // allocate space for constraint evaluations; when we are in debug mode, we also allocate
@@ -169,9 +169,12 @@ where
         fragment: &mut EvaluationTableFragment<E>,
     ) {
         // initialize buffers to hold trace values and evaluation results at each step;
-        let mut main_frame = EvaluationFrame::new(trace.trace_info().main_trace_width());
+        let frame_offsets = self.air.context().frame_offsets();
+        let mut main_frame =
+            EvaluationFrame::with_offsets(trace.trace_info().main_trace_width(), frame_offsets);
         let mut evaluations = vec![E::ZERO; fragment.num_columns()];
         let mut t_evaluations = vec![E::BaseField::ZERO; self.num_main_transition_constraints()];
+        let num_divisors = self.num_transition_divisors();
 
         // this will be used to convert steps in constraint evaluation domain to steps in
         // LDE domain
@@ -181,14 +184,16 @@ where
             let step = i + fragment.offset();
 
             // update evaluation frame buffer with data from the execution trace; this will
-            // read current and next rows from the trace into the buffer; data in the trace
+            // read current and next rows (and any other rows of the frame) into the buffer; data in the trace
             // table is extended over the LDE domain, so, we need to convert step in constraint
             // evaluation domain, into a step in LDE domain, in case these domains are different
             trace.read_main_trace_frame_into(step << lde_shift, &mut main_frame);
 
-            // evaluate transition constraints and save the merged result the first slot of the
-            // evaluations buffer
-            evaluations[0] = self.evaluate_main_transition(&main_frame, step, &mut t_evaluations);
+            // evaluate transition constraints and save the merged results into the first slots
+            // of the evaluations buffer (one slot per transition constraint divisor)
+            let (t_merged, b_evaluations) = evaluations.split_at_mut(num_divisors);
+            t_merged.fill(E::ZERO);
+            self.evaluate_main_transition(&main_frame, step, &mut t_evaluations, t_merged);
 
             // when in debug mode, save transition constraint evaluations
             #[cfg(debug_assertions)]
@@ -197,12 +202,7 @@ where
             // evaluate boundary constraints; the results go into remaining slots of the
             // evaluations buffer
             let main_state = main_frame.current();
-            self.boundary_constraints.evaluate_main(
-                main_state,
-                domain,
-                step,
-                &mut evaluations[1..],
-            );
+            self.boundary_constraints.evaluate_main(main_state, domain, step, b_evaluations);
 
             // record the result in the evaluation table
             fragment.update_row(i, &evaluations);
@@ -220,11 +220,15 @@ where
         fragment: &mut EvaluationTableFragment<E>,
     ) {
         // initialize buffers to hold trace values and evaluation results at each step
-        let mut main_frame = EvaluationFrame::new(trace.trace_info().main_trace_width());
-        let mut aux_frame = EvaluationFrame::new(trace.trace_info().aux_segment_width());
+        let frame_offsets = self.air.context().frame_offsets();
+        let mut main_frame =
+            EvaluationFrame::with_offsets(trace.trace_info().main_trace_width(), frame_offsets);
+        let mut aux_frame =
+            EvaluationFrame::with_offsets(trace.trace_info().aux_segment_width(), frame_offsets);
         let mut tm_evaluations = vec![E::BaseField::ZERO; self.num_main_transition_constraints()];
         let mut ta_evaluations = vec![E::ZERO; self.num_aux_transition_constraints()];
         let mut evaluations = vec![E::ZERO; fragment.num_columns()];
+        let num_divisors = self.num_transition_divisors();
 
         // this will be used to convert steps in constraint evaluation domain to steps in
         // LDE domain
@@ -237,13 +241,20 @@ where
             trace.read_main_trace_frame_into(step << lde_shift, &mut main_frame);
             trace.read_aux_trace_frame_into(step << lde_shift, &mut aux_frame);
 
-            // evaluate transition constraints and save the merged result the first slot of the
-            // evaluations buffer; we evaluate and compose constraints in the same function, we
-            // can just add up the results of evaluating main and auxiliary constraints.
-            evaluations[0] = self.evaluate_main_transition(&main_frame, step, &mut tm_evaluations);
-
-            evaluations[0] +=
-                self.evaluate_aux_transition(&main_frame, &aux_frame, step, &mut ta_evaluations);
+            // evaluate transition constraints and save the merged results into the first slots
+            // of the evaluations buffer (one slot per transition constraint divisor); we evaluate
+            // and compose constraints in the same function, so we can just add up the results of
+            // evaluating main and auxiliary constraints.
+            let (t_merged, b_evaluations) = evaluations.split_at_mut(num_divisors);
+            t_merged.fill(E::ZERO);
+            self.evaluate_main_transition(&main_frame, step, &mut tm_evaluations, t_merged);
+            self.evaluate_aux_transition(
+                &main_frame,
+                &aux_frame,
+                step,
+                &mut ta_evaluations,
+                t_merged,
+            );
 
             // when in debug mode, save transition constraint evaluations
             #[cfg(debug_assertions)]
@@ -258,7 +269,7 @@ where
                 aux_state,
                 domain,
                 step,
-                &mut evaluations[1..],
+                b_evaluations,
             );
 
             // record the result in the evaluation table
@@ -274,12 +285,16 @@ where
     ///
     /// `x` is the corresponding domain value at the specified step. That is, x = s * g^step,
     /// where g is the generator of the constraint evaluation domain, and s is the domain offset.
+    ///
+    /// Random linear combinations of the evaluations are added to `result`, which must contain a
+    /// slot for each distinct transition constraint divisor.
     fn evaluate_main_transition(
         &self,
         main_frame: &EvaluationFrame<E::BaseField>,
         step: usize,
         evaluations: &mut [E::BaseField],
-    ) -> E {
+        result: &mut [E],
+    ) {
         // TODO: use a more efficient way to zero out memory
         evaluations.fill(E::BaseField::ZERO);
 
@@ -290,12 +305,8 @@ where
         // the results into evaluations buffer
         self.air.evaluate_transition(main_frame, periodic_values, evaluations);
 
-        // merge transition constraint evaluations into a single value and return it;
-        // we can do this here because all transition constraints have the same divisor.
-        evaluations
-            .iter()
-            .zip(self.transition_constraints.main_constraint_coef().iter())
-            .fold(E::ZERO, |acc, (&const_eval, &coef)| acc + coef.mul_base(const_eval))
+        // merge evaluations of transition constraints which share the same divisor
+        self.transition_constraints.merge_main_evaluations(evaluations, result);
     }
 
     /// Evaluates all transition constraints (i.e., for main and the auxiliary trace segment) at the
@@ -303,13 +314,17 @@ where
     ///
     /// `x` is the corresponding domain value at the specified step. That is, x = s * g^step,
     /// where g is the generator of the constraint evaluation domain, and s is the domain offset.
+    ///
+    /// Random linear combinations of the evaluations are added to `result`, which must contain a
+    /// slot for each distinct transition constraint divisor.
     fn evaluate_aux_transition(
         &self,
         main_frame: &EvaluationFrame<E::BaseField>,
         aux_frame: &EvaluationFrame<E>,
         step: usize,
         evaluations: &mut [E],
-    ) -> E {
+        result: &mut [E],
+    ) {
         // TODO: use a more efficient way to zero out memory
         evaluations.fill(E::ZERO);
 
@@ -318,7 +333,7 @@ where
 
         // evaluate transition constraints over the auxiliary trace segment and save the results
         // into evaluations buffer
-        self.air.evaluate_aux_transition(
+        self.air.evaluate_all_aux_transitions(
             main_frame,
             aux_frame,
             periodic_values,
@@ -328,12 +343,8 @@ where
             evaluations,
         );
 
-        // merge transition constraint evaluations into a single value and return it;
-        // we can do this here because all transition constraints have the same divisor.
-        evaluations
-            .iter()
-            .zip(self.transition_constraints.aux_constraint_coef().iter())
-            .fold(E::ZERO, |acc, (&const_eval, &coef)| acc + coef * const_eval)
+        // merge evaluations of transition constraints which share the same divisor
+        self.transition_constraints.merge_aux_evaluations(evaluations, result);
     }
 
     // ACCESSORS
@@ -349,4 +360,9 @@ where
     fn num_aux_transition_constraints(&self) -> usize {
         self.transition_constraints.num_aux_constraints()
     }
+
+    /// Returns the number of distinct divisors of transition constraints.
+    fn num_transition_divisors(&self) -> usize {
+        self.transition_constraints.divisors().len()
+    }
 }
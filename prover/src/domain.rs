@@ -8,13 +8,19 @@ use alloc::vec::Vec;
 use air::Air;
 use math::{fft, get_power_series, StarkField};
 
+use crate::ZkRandomizer;
+
 // TYPES AND INTERFACES
 // ================================================================================================
 
 /// Info about domains related to specific instance of proof generation.
 pub struct StarkDomain<B: StarkField> {
-    /// Twiddles which can be used to evaluate polynomials in the trace domain. Length of this
-    /// vector is half the length of the trace domain size.
+    /// Length of the execution trace.
+    trace_length: usize,
+
+    /// Twiddles which can be used to evaluate trace polynomials. Length of this vector is half
+    /// the size of trace polynomials; unless zero-knowledge mode is enabled, this is half the
+    /// length of the trace domain size.
     trace_twiddles: Vec<B>,
 
     /// [g^i for i in (0..ce_domain_size)] where g is the constraint evaluation domain generator.
@@ -30,6 +36,9 @@ pub struct StarkDomain<B: StarkField> {
 
     /// Offset of the low-degree extension domain.
     domain_offset: B,
+
+    /// Source of randomness for proofs generated in zero-knowledge mode.
+    zk: Option<ZkRandomizer>,
 }
 
 // STARK DOMAIN IMPLEMENTATION
@@ -38,18 +47,20 @@ pub struct StarkDomain<B: StarkField> {
 impl<B: StarkField> StarkDomain<B> {
     /// Returns a new STARK domain initialized with the provided `context`.
     pub fn new<A: Air<BaseField = B>>(air: &A) -> Self {
-        let trace_twiddles = fft::get_twiddles(air.trace_length());
+        let trace_twiddles = fft::get_twiddles(air.context().trace_poly_size());
 
         // build constraint evaluation domain
         let domain_gen = B::get_root_of_unity(air.ce_domain_size().ilog2());
         let ce_domain = get_power_series(domain_gen, air.ce_domain_size());
 
         StarkDomain {
+            trace_length: air.trace_length(),
             trace_twiddles,
             ce_domain,
             ce_to_lde_blowup: air.lde_domain_size() / air.ce_domain_size(),
             ce_domain_mod_mask: air.ce_domain_size() - 1,
             domain_offset: air.domain_offset(),
+            zk: None,
         }
     }
 
@@ -67,20 +78,35 @@ impl<B: StarkField> StarkDomain<B> {
         let ce_domain = get_power_series(domain_gen, ce_domain_size);
 
         StarkDomain {
+            trace_length: trace_twiddles.len() * 2,
             trace_twiddles,
             ce_domain,
             ce_to_lde_blowup: 1,
             ce_domain_mod_mask: ce_domain_size - 1,
             domain_offset,
+            zk: None,
         }
     }
 
+    /// Sets the source of randomness used to generate proofs in zero-knowledge mode.
+    pub fn with_zk(mut self, zk: ZkRandomizer) -> Self {
+        self.zk = Some(zk);
+        self
+    }
+
     // EXECUTION TRACE
     // --------------------------------------------------------------------------------------------
 
     /// Returns length of the execution trace for this computation.
     pub fn trace_length(&self) -> usize {
-        &self.trace_twiddles.len() * 2
+        self.trace_length
+    }
+
+    /// Returns the number of coefficients in trace polynomials for this computation.
+    ///
+    /// Unless zero-knowledge mode is enabled, this is the same as the trace length.
+    pub fn trace_poly_size(&self) -> usize {
+        self.trace_twiddles.len() * 2
     }
 
     /// Returns twiddles which can be used to evaluate trace polynomials.
@@ -98,6 +124,14 @@ impl<B: StarkField> StarkDomain<B> {
         self.lde_domain_size() / self.trace_length()
     }
 
+    /// Returns blowup factor from the size of trace polynomials to LDE domain.
+    ///
+    /// Unless zero-knowledge mode is enabled, this is the same as
+    /// [trace_to_lde_blowup()](Self::trace_to_lde_blowup).
+    pub fn trace_poly_to_lde_blowup(&self) -> usize {
+        self.lde_domain_size() / self.trace_poly_size()
+    }
+
     // CONSTRAINT EVALUATION DOMAIN
     // --------------------------------------------------------------------------------------------
 
@@ -152,4 +186,13 @@ impl<B: StarkField> StarkDomain<B> {
     pub fn offset(&self) -> B {
         self.domain_offset
     }
+
+    // ZERO-KNOWLEDGE
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the source of randomness for proofs generated in zero-knowledge mode, or None if
+    /// zero-knowledge mode is not enabled.
+    pub fn zk(&self) -> Option<&ZkRandomizer> {
+        self.zk.as_ref()
+    }
 }
@@ -21,6 +21,20 @@ pub enum ProverError {
     /// This error occurs when the base field specified by the AIR does not support field extension
     /// of degree specified by proof options.
     UnsupportedFieldExtension(usize),
+    /// This error occurs when the length of a table of a multi-table proof is greater than the
+    /// length of the preceding table, or when the LDE domain of the table cannot be reached by
+    /// folding the LDE domain of the first table during FRI.
+    InvalidTableLength(usize),
+    /// This error occurs when a table of a multi-table proof has a different number of auxiliary
+    /// trace segments than the first table, or requires a different number of random elements
+    /// for any of these segments.
+    InconsistentAuxSegments(usize),
+    /// This error occurs when a multi-table proof is requested in zero-knowledge mode, which is
+    /// not supported for multi-table proofs.
+    UnsupportedZkMode,
+    /// This error occurs when a proof is requested in zero-knowledge mode, but the prover could
+    /// not obtain a random seed for the proof.
+    ZkSeedUnavailable,
 }
 
 impl fmt::Display for ProverError {
@@ -36,6 +50,18 @@ impl fmt::Display for ProverError {
             Self::UnsupportedFieldExtension(degree) => {
                 write!(f, "field extension of degree {degree} is not supported for the specified base field")
             }
+            Self::InvalidTableLength(table_idx) => {
+                write!(f, "length of table {table_idx} is not compatible with lengths of the preceding tables")
+            }
+            Self::InconsistentAuxSegments(table_idx) => {
+                write!(f, "auxiliary trace segments of table {table_idx} are inconsistent with auxiliary trace segments of the first table")
+            }
+            Self::UnsupportedZkMode => {
+                write!(f, "zero-knowledge mode is not supported for multi-table proofs")
+            }
+            Self::ZkSeedUnavailable => {
+                write!(f, "failed to obtain a random seed for a zero-knowledge proof")
+            }
         }
     }
 }
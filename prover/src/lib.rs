@@ -42,19 +42,23 @@
 #[macro_use]
 extern crate alloc;
 
+use alloc::vec::Vec;
+
 pub use air::{
-    proof, proof::Proof, Air, AirContext, Assertion, BoundaryConstraint, BoundaryConstraintGroup,
+    proof,
+    proof::{MultiProof, Proof},
+    Air, AirContext, Assertion, BoundaryConstraint, BoundaryConstraintGroup,
     ConstraintCompositionCoefficients, ConstraintDivisor, DeepCompositionCoefficients,
     EvaluationFrame, FieldExtension, ProofOptions, TraceInfo, TransitionConstraintDegree,
 };
 use air::{AuxRandElements, PartitionOptions};
 pub use crypto;
 use crypto::{ElementHasher, RandomCoin, VectorCommitment};
-use fri::FriProver;
+use fri::{folding::fold_positions, FriProver};
 pub use math;
 use math::{
     fft::infer_degree,
-    fields::{CubeExtension, QuadExtension},
+    fields::{CubeExtension, QuadExtension, QuartExtension},
     ExtensibleField, FieldElement, StarkField, ToElements,
 };
 use tracing::{event, info_span, instrument, Level};
@@ -66,6 +70,9 @@ pub use utils::{
 mod domain;
 pub use domain::StarkDomain;
 
+mod zk;
+pub use zk::ZkRandomizer;
+
 pub mod matrix;
 use matrix::{ColMatrix, RowMatrix};
 
@@ -80,6 +87,7 @@ use composer::DeepCompositionPoly;
 
 mod trace;
 use maybe_async::{maybe_async, maybe_await};
+use trace::build_logup_column;
 pub use trace::{
     AuxTraceWithMetadata, DefaultTraceLde, Trace, TraceLde, TracePolyTable, TraceTable,
     TraceTableFragment,
@@ -88,6 +96,9 @@ pub use trace::{
 mod channel;
 use channel::ProverChannel;
 
+mod multi_channel;
+use multi_channel::MultiProverChannel;
+
 mod errors;
 pub use errors::ProverError;
 
@@ -124,7 +135,7 @@ const DEFAULT_SEGMENT_WIDTH: usize = 8;
 /// generation can be delegated to non-CPU hardware (e.g., GPUs).
 pub trait Prover {
     /// Base field for the computation described by this prover.
-    type BaseField: StarkField + ExtensibleField<2> + ExtensibleField<3>;
+    type BaseField: StarkField + ExtensibleField<2> + ExtensibleField<3> + ExtensibleField<4>;
 
     /// Algebraic intermediate representation (AIR) for the computation described by this prover.
     type Air: Air<BaseField = Self::BaseField>;
@@ -225,7 +236,28 @@ pub trait Prover {
     // PROVIDED METHODS
     // --------------------------------------------------------------------------------------------
 
+    /// Returns a secret random seed from which all randomness needed to generate a proof in
+    /// zero-knowledge mode is derived (see [ProofOptions::with_zk()]).
+    ///
+    /// This method is called once per proof, and only when zero-knowledge mode is enabled. It
+    /// must return fresh randomness (e.g., drawn from a cryptographically secure random number
+    /// generator) every time it is called: re-using the seed across proofs of different
+    /// witnesses breaks the zero-knowledge property, and a predictable seed allows the verifier to
+    /// remove the randomization altogether.
+    ///
+    /// When `std` feature is enabled, the default implementation draws the seed from the
+    /// operating system's source of randomness; otherwise, the default implementation always
+    /// returns [ProverError::ZkSeedUnavailable], and thus, provers which need to generate proofs
+    /// in zero-knowledge mode in `no_std` environments must override this method.
+    fn zk_seed(&self) -> Result<[u8; 32], ProverError> {
+        draw_zk_seed()
+    }
+
     /// Builds and returns the auxiliary trace.
+    ///
+    /// If the AIR declares a [LogUp](air::LogUp) argument, the running-sum column of the argument
+    /// is built automatically and should not be included in the returned trace. In this case,
+    /// this method is called only if the auxiliary trace segment contains other columns.
     #[allow(unused_variables)]
     #[maybe_async]
     #[instrument(skip_all)]
@@ -240,6 +272,37 @@ pub trait Prover {
         unimplemented!("`Prover::build_aux_trace` needs to be implemented when the trace has an auxiliary segment.")
     }
 
+    /// Builds and returns the next auxiliary trace segment.
+    ///
+    /// `aux_segments` contains all previously built auxiliary segments, and `aux_rand_elements`
+    /// contains random elements drawn for all auxiliary segments up to and including the one
+    /// being built. Random elements for a segment are drawn only after the prover has committed
+    /// to all previous segments.
+    ///
+    /// The default implementation delegates building of the first auxiliary segment to
+    /// [build_aux_trace()](Prover::build_aux_trace) and thus needs to be overridden only when
+    /// the trace has more than one auxiliary segment. The same rules as for
+    /// [build_aux_trace()](Prover::build_aux_trace) apply to the running-sum column of a
+    /// [LogUp](air::LogUp) argument, which is placed into the last auxiliary segment.
+    #[allow(unused_variables)]
+    #[maybe_async]
+    #[instrument(skip_all)]
+    fn build_aux_segment<E>(
+        &self,
+        main_trace: &Self::Trace,
+        aux_segments: &[ColMatrix<E>],
+        aux_rand_elements: &AuxRandElements<E>,
+    ) -> ColMatrix<E>
+    where
+        E: FieldElement<BaseField = Self::BaseField>,
+    {
+        if aux_segments.is_empty() {
+            maybe_await!(self.build_aux_trace(main_trace, aux_rand_elements))
+        } else {
+            unimplemented!("`Prover::build_aux_segment` needs to be implemented when the trace has more than one auxiliary segment.")
+        }
+    }
+
     /// Returns a STARK proof attesting to a correct execution of a computation defined by the
     /// provided trace.
     ///
@@ -268,6 +331,69 @@ pub trait Prover {
                 }
                 maybe_await!(self.generate_proof::<CubeExtension<Self::BaseField>>(trace))
             },
+            FieldExtension::Quartic => {
+                if !<QuartExtension<Self::BaseField>>::is_supported() {
+                    return Err(ProverError::UnsupportedFieldExtension(4));
+                }
+                maybe_await!(self.generate_proof::<QuartExtension<Self::BaseField>>(trace))
+            },
+        }
+    }
+
+    /// Returns a STARK proof attesting to a correct execution of a computation described by
+    /// several tables of different lengths.
+    ///
+    /// Each of the provided `traces` is a table of the computation; an instance of
+    /// [Self::Air](Prover::Air) is created for every table from the table's [TraceInfo] (thus,
+    /// the AIR can tell tables apart by their trace metadata or width). Public inputs are
+    /// retrieved via [get_pub_inputs()](Prover::get_pub_inputs) from the first table and are
+    /// shared by all tables.
+    ///
+    /// Every table gets its own low-degree extension and commitments, while random elements for
+    /// auxiliary trace segments are shared by all tables. Thus, consistency between tables can be
+    /// enforced via a bus: e.g., one table may include a [LogUp](air::LogUp) argument which
+    /// consists only of lookups, while another table includes a LogUp argument which consists
+    /// only of the table side. The final values of the running sums of all tables are sent to
+    /// the verifier, which checks that they add up to zero. Low degree of the DEEP composition
+    /// polynomials of all tables is proven via a single batched FRI proof.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// * The tables are not sorted by length in non-increasing order, or the LDE domain of a table
+    ///   is not reachable by folding the LDE domain of the first table during FRI.
+    /// * The tables have different numbers of auxiliary trace segments, or require different
+    ///   numbers of random elements for these segments.
+    /// * Zero-knowledge mode is enabled in the proof options.
+    ///
+    /// # Panics
+    /// Panics if `traces` is empty.
+    #[maybe_async]
+    fn prove_multi(&self, traces: Vec<Self::Trace>) -> Result<MultiProof, ProverError>
+    where
+        <Self::Air as Air>::PublicInputs: Send + Clone,
+    {
+        match self.options().field_extension() {
+            FieldExtension::None => {
+                maybe_await!(self.generate_multi_proof::<Self::BaseField>(traces))
+            },
+            FieldExtension::Quadratic => {
+                if !<QuadExtension<Self::BaseField>>::is_supported() {
+                    return Err(ProverError::UnsupportedFieldExtension(2));
+                }
+                maybe_await!(self.generate_multi_proof::<QuadExtension<Self::BaseField>>(traces))
+            },
+            FieldExtension::Cubic => {
+                if !<CubeExtension<Self::BaseField>>::is_supported() {
+                    return Err(ProverError::UnsupportedFieldExtension(3));
+                }
+                maybe_await!(self.generate_multi_proof::<CubeExtension<Self::BaseField>>(traces))
+            },
+            FieldExtension::Quartic => {
+                if !<QuartExtension<Self::BaseField>>::is_supported() {
+                    return Err(ProverError::UnsupportedFieldExtension(4));
+                }
+                maybe_await!(self.generate_multi_proof::<QuartExtension<Self::BaseField>>(traces))
+            },
         }
     }
 
@@ -306,45 +432,81 @@ pub trait Prover {
 
         // 1 ----- Commit to the execution trace --------------------------------------------------
 
-        // build computation domain; this is used later for polynomial evaluations
+        // build computation domain; this is used later for polynomial evaluations. In
+        // zero-knowledge mode, the domain also carries the randomness used to randomize the proof
+        let zk_seed = air.options().is_zk().then(|| self.zk_seed()).transpose()?;
         let lde_domain_size = air.lde_domain_size();
         let trace_length = air.trace_length();
-        let domain = info_span!("build_domain", trace_length, lde_domain_size)
-            .in_scope(|| StarkDomain::new(&air));
+        let domain = info_span!("build_domain", trace_length, lde_domain_size).in_scope(|| {
+            let domain = StarkDomain::new(&air);
+            match zk_seed {
+                Some(seed) => domain.with_zk(ZkRandomizer::new(&air, seed)),
+                None => domain,
+            }
+        });
         assert_eq!(domain.lde_domain_size(), lde_domain_size);
         assert_eq!(domain.trace_length(), trace_length);
+        let trace_poly_size = domain.trace_poly_size();
 
         // commit to the main trace segment
         let (mut trace_lde, mut trace_polys) =
             maybe_await!(self.commit_to_main_trace_segment(&trace, &domain, &mut channel));
 
-        // build the auxiliary trace segment, and append the resulting segments to trace commitment
-        // and trace polynomial table structs
+        // build the auxiliary trace segments one after another, and append the resulting segments
+        // to trace commitment and trace polynomial table structs
         let aux_trace_with_metadata = if air.trace_info().is_multi_segment() {
-            let aux_rand_elements = air
-                .get_aux_rand_elements(channel.public_coin())
-                .expect("failed to draw random elements for the auxiliary trace segment");
-
-            let aux_trace = maybe_await!(self.build_aux_trace(&trace, &aux_rand_elements));
-
-            // commit to the auxiliary trace segment
-            let aux_segment_polys = {
-                // extend the auxiliary trace segment and commit to the extended trace
-                let span = info_span!("commit_to_aux_trace_segment").entered();
-                let (aux_segment_polys, aux_segment_commitment) =
-                    trace_lde.set_aux_trace(&aux_trace, &domain);
-
-                // commit to the LDE of the extended auxiliary trace segment by writing its
-                // commitment into the channel
-                channel.commit_trace(aux_segment_commitment);
-
-                drop(span);
-                aux_segment_polys
-            };
-
-            trace_polys.add_aux_segment(aux_segment_polys);
+            let num_aux_segments = air.trace_info().num_aux_segments();
+            let mut rand_elements = Vec::new();
+            let mut aux_segments: Vec<ColMatrix<E>> = Vec::with_capacity(num_aux_segments);
+
+            for segment_idx in 0..num_aux_segments {
+                // random elements for this segment are drawn after the commitments to all previous
+                // segments have been written into the channel
+                rand_elements.extend(
+                    air.get_aux_segment_rand_elements::<E, _>(segment_idx, channel.public_coin())
+                        .expect("failed to draw random elements for the auxiliary trace segment"),
+                );
+                let aux_rand_elements = AuxRandElements::new(rand_elements.clone());
+
+                let aux_segment = maybe_await!(self.build_next_aux_segment(
+                    &air,
+                    &trace,
+                    &aux_segments,
+                    &aux_rand_elements
+                ));
+
+                // commit to the auxiliary trace segment
+                let aux_segment_polys = {
+                    // extend the auxiliary trace segment and commit to the extended trace
+                    let span = info_span!("commit_to_aux_trace_segment", segment_idx).entered();
+                    let (aux_segment_polys, aux_segment_commitment) =
+                        trace_lde.set_aux_trace(&aux_segment, &domain);
+
+                    // commit to the LDE of the extended auxiliary trace segment by writing its
+                    // commitment into the channel
+                    channel.commit_trace(aux_segment_commitment);
+
+                    drop(span);
+                    aux_segment_polys
+                };
+
+                trace_polys.add_aux_segment(aux_segment_polys);
+                aux_segments.push(aux_segment);
+            }
+
+            // combine all auxiliary segments into a single auxiliary trace
+            let mut aux_segments = aux_segments.into_iter();
+            let mut aux_trace = aux_segments.next().expect("no auxiliary segments");
+            for aux_segment in aux_segments {
+                for column in aux_segment.into_columns() {
+                    aux_trace.merge_column(column);
+                }
+            }
 
-            Some(AuxTraceWithMetadata { aux_trace, aux_rand_elements })
+            Some(AuxTraceWithMetadata {
+                aux_trace,
+                aux_rand_elements: AuxRandElements::new(rand_elements),
+            })
         } else {
             None
         };
@@ -395,8 +557,9 @@ pub trait Prover {
             let z = channel.get_ood_point();
 
             // evaluate trace and constraint polynomials at the OOD point z and gz, where g is
-            // the generator of the trace domain. and send the results to the verifier
-            let ood_trace_states = trace_polys.get_ood_frame(z);
+            // the generator of the trace domain (trace polynomials are also evaluated at z * g^k
+            // for any additional frame offsets k), and send the results to the verifier
+            let ood_trace_states = trace_polys.get_ood_frame(z, air.context().frame_offsets());
             let ood_evaluations = composition_poly.get_ood_frame(z);
             channel.send_ood_evaluations(&ood_trace_states, &ood_evaluations);
 
@@ -422,7 +585,7 @@ pub trait Prover {
 
         // make sure the degree of the DEEP composition polynomial is equal to trace polynomial
         // degree minus 1.
-        assert_eq!(trace_length - 2, deep_composition_poly.degree());
+        assert_eq!(trace_poly_size - 2, deep_composition_poly.degree());
 
         // 5 ----- evaluate DEEP composition polynomial over LDE domain ---------------------------
         let deep_evaluations = {
@@ -430,7 +593,7 @@ pub trait Prover {
             let deep_evaluations = deep_composition_poly.evaluate(&domain);
             // we check the following condition in debug mode only because infer_degree is an
             // expensive operation
-            debug_assert_eq!(trace_length - 2, infer_degree(&deep_evaluations, domain.offset()));
+            debug_assert_eq!(trace_poly_size - 2, infer_degree(&deep_evaluations, domain.offset()));
 
             drop(span);
             deep_evaluations
@@ -491,6 +654,330 @@ pub trait Prover {
         Ok(proof)
     }
 
+    /// Performs the actual multi-table proof generation procedure, generating the proof that the
+    /// provided `traces` are valid against this prover's AIR.
+    #[doc(hidden)]
+    #[maybe_async]
+    fn generate_multi_proof<E>(&self, traces: Vec<Self::Trace>) -> Result<MultiProof, ProverError>
+    where
+        E: FieldElement<BaseField = Self::BaseField>,
+        <Self::Air as Air>::PublicInputs: Send + Clone,
+    {
+        assert!(!traces.is_empty(), "at least one table must be provided");
+        if self.options().is_zk() {
+            return Err(ProverError::UnsupportedZkMode);
+        }
+
+        // 0 ----- instantiate AIRs and prover channel --------------------------------------------
+
+        // public inputs are retrieved from the first table and are shared by all tables
+        let pub_inputs = self.get_pub_inputs(&traces[0]);
+        let pub_inputs_elements = pub_inputs.to_elements();
+
+        let airs: Vec<Self::Air> = traces
+            .iter()
+            .map(|trace| {
+                Self::Air::new(trace.info().clone(), pub_inputs.clone(), self.options().clone())
+            })
+            .collect();
+
+        // make sure the tables can be combined into a single proof: LDE domains of all tables
+        // must be reachable by folding the LDE domain of the first table, and all tables must
+        // draw the same random elements for their auxiliary trace segments
+        let fri_options = self.options().to_fri_options();
+        let lde_domain_size = airs[0].lde_domain_size();
+        for (table_idx, air) in airs.iter().enumerate().skip(1) {
+            if air.trace_length() > airs[table_idx - 1].trace_length()
+                || fri_options.get_folding_depth(lde_domain_size, air.lde_domain_size()).is_none()
+            {
+                return Err(ProverError::InvalidTableLength(table_idx));
+            }
+
+            let trace_info = air.trace_info();
+            let first_trace_info = airs[0].trace_info();
+            if trace_info.num_aux_segments() != first_trace_info.num_aux_segments()
+                || (0..trace_info.num_aux_segments()).any(|idx| {
+                    trace_info.get_num_aux_segment_rand_elements_at(idx)
+                        != first_trace_info.get_num_aux_segment_rand_elements_at(idx)
+                })
+            {
+                return Err(ProverError::InconsistentAuxSegments(table_idx));
+            }
+        }
+
+        let mut channel =
+            MultiProverChannel::<Self::Air, E, Self::HashFn, Self::RandomCoin, Self::VC>::new(
+                &airs,
+                pub_inputs_elements,
+            );
+
+        // 1 ----- commit to the main trace segments of all tables --------------------------------
+        let domains: Vec<StarkDomain<Self::BaseField>> = airs
+            .iter()
+            .map(|air| {
+                info_span!("build_domain", trace_length = air.trace_length())
+                    .in_scope(|| StarkDomain::new(air))
+            })
+            .collect();
+
+        let mut trace_ldes = Vec::with_capacity(airs.len());
+        let mut trace_polys = Vec::with_capacity(airs.len());
+        for (table_idx, (trace, domain)) in traces.iter().zip(domains.iter()).enumerate() {
+            let (trace_lde, table_polys) = maybe_await!(self.new_trace_lde::<E>(
+                trace.info(),
+                trace.main_segment(),
+                domain,
+                self.options().partition_options(),
+            ));
+            channel.commit_trace(table_idx, trace_lde.get_main_trace_commitment());
+            trace_ldes.push(trace_lde);
+            trace_polys.push(table_polys);
+        }
+
+        // 2 ----- build auxiliary trace segments of all tables -----------------------------------
+
+        // random elements for each segment are drawn once and are shared by all tables; a
+        // segment is built for every table before the random elements for the next segment are
+        // drawn
+        let num_aux_segments = airs[0].trace_info().num_aux_segments();
+        let mut rand_elements = Vec::new();
+        let mut aux_segments: Vec<Vec<ColMatrix<E>>> = vec![Vec::new(); airs.len()];
+        for segment_idx in 0..num_aux_segments {
+            rand_elements.extend(
+                airs[0]
+                    .get_aux_segment_rand_elements::<E, _>(segment_idx, channel.public_coin())
+                    .expect("failed to draw random elements for the auxiliary trace segment"),
+            );
+            let aux_rand_elements = AuxRandElements::new(rand_elements.clone());
+
+            for table_idx in 0..airs.len() {
+                let aux_segment = maybe_await!(self.build_next_aux_segment(
+                    &airs[table_idx],
+                    &traces[table_idx],
+                    &aux_segments[table_idx],
+                    &aux_rand_elements
+                ));
+
+                let span =
+                    info_span!("commit_to_aux_trace_segment", table_idx, segment_idx).entered();
+                let (aux_segment_polys, aux_segment_commitment) =
+                    trace_ldes[table_idx].set_aux_trace(&aux_segment, &domains[table_idx]);
+                channel.commit_trace(table_idx, aux_segment_commitment);
+                drop(span);
+
+                trace_polys[table_idx].add_aux_segment(aux_segment_polys);
+                aux_segments[table_idx].push(aux_segment);
+            }
+        }
+
+        // read the final values of LogUp running sums of all tables, and send them to the
+        // verifier
+        let logup_sums: Vec<Option<E>> = airs
+            .iter()
+            .zip(aux_segments.iter())
+            .map(|(air, segments)| {
+                air.context().logup().map(|_| {
+                    let last_segment = segments.last().expect("no auxiliary segments");
+                    let step = air.trace_length() - air.context().num_logup_transition_exemptions();
+                    last_segment.get(last_segment.num_cols() - 1, step)
+                })
+            })
+            .collect();
+        channel.send_logup_sums(&logup_sums);
+
+        let aux_rand_elements: Vec<Option<AuxRandElements<E>>> = logup_sums
+            .iter()
+            .map(|&logup_sum| {
+                (num_aux_segments > 0).then(|| {
+                    let aux_rand_elements = AuxRandElements::new(rand_elements.clone());
+                    match logup_sum {
+                        Some(logup_sum) => aux_rand_elements.with_logup_sum(logup_sum),
+                        None => aux_rand_elements,
+                    }
+                })
+            })
+            .collect();
+
+        // make sure the specified traces (including auxiliary segments) are valid against their
+        // AIRs. We do this in debug mode only because this is a very expensive operation.
+        #[cfg(debug_assertions)]
+        for ((air, trace), (segments, aux_rand_elements)) in airs
+            .iter()
+            .zip(traces.iter())
+            .zip(aux_segments.iter().zip(aux_rand_elements.iter()))
+        {
+            let aux_trace_with_metadata = aux_rand_elements.as_ref().map(|aux_rand_elements| {
+                let mut aux_trace = segments[0].clone();
+                for segment in segments[1..].iter() {
+                    for column in segment.columns() {
+                        aux_trace.merge_column(column.to_vec());
+                    }
+                }
+                AuxTraceWithMetadata {
+                    aux_trace,
+                    aux_rand_elements: aux_rand_elements.clone(),
+                }
+            });
+            trace.validate(air, aux_trace_with_metadata.as_ref());
+        }
+
+        // drop the traces as they are no longer needed
+        drop(traces);
+        drop(aux_segments);
+
+        // 3 ----- evaluate and commit to constraints of all tables -------------------------------
+        let mut constraint_commitments = Vec::with_capacity(airs.len());
+        let mut composition_polys = Vec::with_capacity(airs.len());
+        for (table_idx, aux_rand_elements) in aux_rand_elements.into_iter().enumerate() {
+            let air = &airs[table_idx];
+            let domain = &domains[table_idx];
+            let composition_coeffs = channel.get_constraint_composition_coeffs(table_idx);
+            let composition_poly_trace =
+                maybe_await!(self.new_evaluator(air, aux_rand_elements, composition_coeffs))
+                    .evaluate(&trace_ldes[table_idx], domain);
+            assert_eq!(composition_poly_trace.num_rows(), air.ce_domain_size());
+
+            let (constraint_commitment, composition_poly) = maybe_await!(self
+                .build_constraint_commitment::<E>(
+                    composition_poly_trace,
+                    air.context().num_constraint_composition_columns(),
+                    domain,
+                    self.options().partition_options()
+                ));
+            channel.commit_constraints(table_idx, constraint_commitment.commitment());
+            constraint_commitments.push(constraint_commitment);
+            composition_polys.push(composition_poly);
+        }
+
+        // 4 ----- build DEEP composition polynomials of all tables -------------------------------
+
+        // a single out-of-domain point z is used for all tables
+        let z = channel.get_ood_point();
+        let mut ood_frames = Vec::with_capacity(airs.len());
+        for (table_idx, (table_polys, composition_poly)) in
+            trace_polys.iter().zip(composition_polys.iter()).enumerate()
+        {
+            let air = &airs[table_idx];
+            let ood_trace_states = table_polys.get_ood_frame(z, air.context().frame_offsets());
+            let ood_evaluations = composition_poly.get_ood_frame(z);
+            channel.send_ood_evaluations(table_idx, &ood_trace_states, &ood_evaluations);
+            ood_frames.push((ood_trace_states, ood_evaluations));
+        }
+
+        let mut deep_evaluations: Vec<Vec<E>> = Vec::new();
+        for (table_idx, ((table_polys, composition_poly), (ood_trace_states, ood_evaluations))) in
+            trace_polys.into_iter().zip(composition_polys).zip(ood_frames).enumerate()
+        {
+            let span = info_span!("build_deep_composition_poly", table_idx).entered();
+            let deep_coefficients = channel.get_deep_composition_coeffs(table_idx);
+            let mut deep_composition_poly = DeepCompositionPoly::new(z, deep_coefficients);
+            deep_composition_poly.add_trace_polys(
+                table_polys,
+                composition_poly,
+                ood_trace_states,
+                ood_evaluations,
+            );
+            assert_eq!(airs[table_idx].trace_length() - 2, deep_composition_poly.degree());
+
+            // evaluate the DEEP composition polynomial over the LDE domain of the table; tables
+            // with LDE domains of the same size are combined into a single FRI input
+            let table_evaluations = deep_composition_poly.evaluate(&domains[table_idx]);
+            match deep_evaluations.last_mut() {
+                Some(evaluations) if evaluations.len() == table_evaluations.len() => {
+                    for (value, table_value) in evaluations.iter_mut().zip(table_evaluations) {
+                        *value += table_value;
+                    }
+                },
+                _ => deep_evaluations.push(table_evaluations),
+            }
+            drop(span);
+        }
+
+        // 5 ----- compute FRI layers for all DEEP composition polynomials ------------------------
+        let num_layers = fri_options.num_fri_layers(lde_domain_size);
+        let mut fri_prover = FriProver::<_, _, _, Self::VC>::new(fri_options);
+        let mut deep_evaluations = deep_evaluations.into_iter();
+        let first_evaluations = deep_evaluations.next().expect("no DEEP evaluations");
+        info_span!("compute_fri_layers", num_layers).in_scope(|| {
+            fri_prover.build_layers_batched(
+                &mut channel,
+                first_evaluations,
+                deep_evaluations.collect(),
+            )
+        });
+
+        // 6 ----- determine query positions ------------------------------------------------------
+        channel.grind_query_seed();
+        let query_positions = channel.get_query_positions();
+
+        // 7 ----- build proof object -------------------------------------------------------------
+        let fri_proof = fri_prover.build_proof(&query_positions);
+
+        // query positions are drawn over the LDE domain of the first table and are folded into
+        // the LDE domain of each of the other tables
+        let table_queries = trace_ldes
+            .into_iter()
+            .zip(constraint_commitments)
+            .zip(airs.iter())
+            .map(|((trace_lde, constraint_commitment), air)| {
+                let positions = fold_positions(
+                    &query_positions,
+                    lde_domain_size,
+                    lde_domain_size / air.lde_domain_size(),
+                );
+                let trace_queries = trace_lde.query(&positions);
+                let constraint_queries = constraint_commitment.query(&positions);
+                (trace_queries, constraint_queries, positions.len())
+            })
+            .collect();
+
+        Ok(channel.build_proof(table_queries, fri_proof))
+    }
+
+    /// Builds the next auxiliary trace segment of the specified trace, including the running-sum
+    /// column of the [LogUp](air::LogUp) argument if the segment is the last one.
+    #[doc(hidden)]
+    #[maybe_async]
+    fn build_next_aux_segment<E>(
+        &self,
+        air: &Self::Air,
+        trace: &Self::Trace,
+        aux_segments: &[ColMatrix<E>],
+        aux_rand_elements: &AuxRandElements<E>,
+    ) -> ColMatrix<E>
+    where
+        E: FieldElement<BaseField = Self::BaseField>,
+    {
+        let segment_idx = aux_segments.len();
+        let is_last_segment = segment_idx == air.trace_info().num_aux_segments() - 1;
+        match air.context().logup() {
+            Some(logup) if is_last_segment => {
+                // the running-sum column of the LogUp argument is always the last column of the
+                // last auxiliary trace segment; other columns (if any) are built by the prover
+                let rand_elements = aux_rand_elements.rand_elements();
+                let logup_column = build_logup_column(
+                    logup,
+                    trace.main_segment(),
+                    &rand_elements[rand_elements.len() - logup.num_rand_elements()..],
+                );
+
+                let segment_width = air.trace_info().get_aux_segment_width_at(segment_idx);
+                if segment_width > logup.aux_trace_width() {
+                    let mut aux_segment = maybe_await!(self.build_aux_segment(
+                        trace,
+                        aux_segments,
+                        aux_rand_elements
+                    ));
+                    aux_segment.merge_column(logup_column);
+                    aux_segment
+                } else {
+                    ColMatrix::new(vec![logup_column])
+                }
+            },
+            _ => maybe_await!(self.build_aux_segment(trace, aux_segments, aux_rand_elements)),
+        }
+    }
+
     #[doc(hidden)]
     #[instrument(skip_all)]
     #[maybe_async]
@@ -551,3 +1038,22 @@ pub trait Prover {
         (constraint_commitment, composition_poly)
     }
 }
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Draws a fresh seed for a zero-knowledge proof from the operating system's source of
+/// randomness.
+#[cfg(feature = "std")]
+fn draw_zk_seed() -> Result<[u8; 32], ProverError> {
+    let mut seed = [0u8; 32];
+    getrandom::fill(&mut seed).map_err(|_| ProverError::ZkSeedUnavailable)?;
+    Ok(seed)
+}
+
+/// Without the standard library there is no source of randomness, and thus, the seed must be
+/// provided by the prover.
+#[cfg(not(feature = "std"))]
+fn draw_zk_seed() -> Result<[u8; 32], ProverError> {
+    Err(ProverError::ZkSeedUnavailable)
+}
@@ -234,7 +234,7 @@ impl<E: FieldElement> ColMatrix<E> {
                     poly,
                     domain.trace_twiddles(),
                     domain.offset(),
-                    domain.trace_to_lde_blowup(),
+                    domain.trace_poly_to_lde_blowup(),
                 )
             })
             .collect();
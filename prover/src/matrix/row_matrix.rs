@@ -89,8 +89,11 @@ impl<E: FieldElement> RowMatrix<E> {
 
         // pre-compute offsets for each row
         let poly_size = polys.num_rows();
-        let offsets =
-            get_evaluation_offsets::<E>(poly_size, domain.trace_to_lde_blowup(), domain.offset());
+        let offsets = get_evaluation_offsets::<E>(
+            poly_size,
+            domain.trace_poly_to_lde_blowup(),
+            domain.offset(),
+        );
 
         // build matrix segments by evaluating all polynomials
         let segments = build_segments::<E, N>(polys, domain.trace_twiddles(), &offsets);
@@ -185,6 +188,59 @@ impl<E: FieldElement> RowMatrix<E> {
     where
         H: ElementHasher<BaseField = E::BaseField>,
         V: VectorCommitment<H>,
+    {
+        let row_hashes = self.hash_rows::<H>(partition_options);
+
+        // build the vector commitment to the hashed rows
+        V::new(row_hashes).expect("failed to construct trace vector commitment")
+    }
+
+    /// Returns a commitment to this matrix in which each row is salted with a random salt.
+    ///
+    /// The commitment is built in the same way as in [commit_to_rows()](Self::commit_to_rows),
+    /// except that each leaf of the vector commitment is computed as hash(row_hash || hash(salt)).
+    /// `salts` contains a salt for each row of the matrix, with all salts being of the same length
+    /// and placed one after another.
+    ///
+    /// # Panics
+    /// Panics if the length of `salts` is not a multiple of the number of rows in this matrix.
+    pub fn commit_to_salted_rows<H, V>(&self, salts: &[E], partition_options: PartitionOptions) -> V
+    where
+        H: ElementHasher<BaseField = E::BaseField>,
+        V: VectorCommitment<H>,
+    {
+        assert_eq!(
+            salts.len() % self.num_rows(),
+            0,
+            "number of salt elements must be a multiple of the number of rows"
+        );
+        let salt_len = salts.len() / self.num_rows();
+
+        let mut leaves = self.hash_rows::<H>(partition_options);
+        batch_iter_mut!(
+            &mut leaves,
+            128, // min batch size
+            |batch: &mut [H::Digest], batch_offset: usize| {
+                for (i, leaf) in batch.iter_mut().enumerate() {
+                    let row_idx = batch_offset + i;
+                    let salt = &salts[row_idx * salt_len..(row_idx + 1) * salt_len];
+                    *leaf = H::merge(&[*leaf, H::hash_elements(salt)]);
+                }
+            }
+        );
+
+        // build the vector commitment to the salted row hashes
+        V::new(leaves).expect("failed to construct trace vector commitment")
+    }
+
+    // HELPER METHODS
+    // --------------------------------------------------------------------------------------------
+
+    /// Hashes each row of this matrix into a single digest, hashing wide rows in partitions
+    /// defined by the specified partition options.
+    fn hash_rows<H>(&self, partition_options: PartitionOptions) -> Vec<H::Digest>
+    where
+        H: ElementHasher<BaseField = E::BaseField>,
     {
         // allocate vector to store row hashes
         let mut row_hashes = unsafe { uninit_vector::<H::Digest>(self.num_rows()) };
@@ -223,8 +279,7 @@ impl<E: FieldElement> RowMatrix<E> {
             );
         }
 
-        // build the vector commitment to the hashed rows
-        V::new(row_hashes).expect("failed to construct trace vector commitment")
+        row_hashes
     }
 }
 
@@ -0,0 +1,306 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+
+use air::{
+    proof::{
+        merge_ood_evaluations, Commitments, Context, MultiProof, OodFrame, Queries,
+        QuotientOodFrame, TableProof, TraceOodFrame,
+    },
+    Air, ConstraintCompositionCoefficients, DeepCompositionCoefficients,
+};
+use crypto::{ElementHasher, RandomCoin, VectorCommitment};
+use fri::FriProof;
+use math::{FieldElement, ToElements};
+#[cfg(feature = "concurrent")]
+use utils::iterators::*;
+
+// TYPES AND INTERFACES
+// ================================================================================================
+
+/// Prover channel for multi-table proofs.
+///
+/// This works similarly to the [ProverChannel](crate::channel::ProverChannel), but keeps track
+/// of commitments and out-of-domain evaluations for each table separately. A single public coin
+/// is shared by all tables.
+pub struct MultiProverChannel<'a, A, E, H, R, V>
+where
+    A: Air,
+    E: FieldElement<BaseField = A::BaseField>,
+    H: ElementHasher<BaseField = A::BaseField>,
+    R: RandomCoin<BaseField = E::BaseField, Hasher = H>,
+    V: VectorCommitment<H>,
+{
+    airs: &'a [A],
+    public_coin: R,
+    contexts: Vec<Context>,
+    commitments: Vec<Commitments>,
+    fri_commitments: Commitments,
+    ood_frames: Vec<OodFrame>,
+    logup_sums: Vec<Vec<u8>>,
+    pow_nonce: u64,
+    _field_element: PhantomData<E>,
+    _vector_commitment: PhantomData<V>,
+}
+
+// MULTI PROVER CHANNEL IMPLEMENTATION
+// ================================================================================================
+
+impl<'a, A, E, H, R, V> MultiProverChannel<'a, A, E, H, R, V>
+where
+    A: Air,
+    E: FieldElement<BaseField = A::BaseField>,
+    H: ElementHasher<BaseField = A::BaseField>,
+    R: RandomCoin<BaseField = A::BaseField, Hasher = H>,
+    V: VectorCommitment<H>,
+{
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+    /// Creates a new prover channel for the specified table `airs` and public inputs.
+    pub fn new(airs: &'a [A], mut pub_inputs_elements: Vec<A::BaseField>) -> Self {
+        let contexts: Vec<Context> = airs
+            .iter()
+            .map(|air| {
+                let num_constraints =
+                    air.context().num_assertions() + air.context().num_transition_constraints();
+                Context::new::<A::BaseField>(
+                    air.trace_info().clone(),
+                    air.options().clone(),
+                    num_constraints,
+                )
+            })
+            .collect();
+
+        // build a seed for the public coin; the initial seed is a hash of the contexts of all
+        // tables and the public inputs
+        let mut coin_seed_elements: Vec<A::BaseField> =
+            contexts.iter().flat_map(|context| context.to_elements()).collect();
+        coin_seed_elements.append(&mut pub_inputs_elements);
+
+        MultiProverChannel {
+            airs,
+            public_coin: RandomCoin::new(&coin_seed_elements),
+            contexts,
+            commitments: vec![Commitments::default(); airs.len()],
+            fri_commitments: Commitments::default(),
+            ood_frames: vec![OodFrame::default(); airs.len()],
+            logup_sums: vec![Vec::new(); airs.len()],
+            pow_nonce: 0,
+            _field_element: PhantomData,
+            _vector_commitment: PhantomData,
+        }
+    }
+
+    // COMMITMENT METHODS
+    // --------------------------------------------------------------------------------------------
+
+    /// Commits the prover to the extended execution trace segment of the specified table.
+    pub fn commit_trace(&mut self, table_idx: usize, trace_commitment: V::Commitment) {
+        self.commitments[table_idx].add(&trace_commitment);
+        self.public_coin.reseed(V::commitment_digest(&trace_commitment));
+    }
+
+    /// Commits the prover to the evaluations of the constraint composition polynomial of the
+    /// specified table.
+    pub fn commit_constraints(&mut self, table_idx: usize, constraint_commitment: V::Commitment) {
+        self.commitments[table_idx].add(&constraint_commitment);
+        self.public_coin.reseed(V::commitment_digest(&constraint_commitment));
+    }
+
+    /// Saves the final values of the LogUp running sums of all tables. This also reseeds the
+    /// public coin with the hash of these values.
+    ///
+    /// `logup_sums` must contain an entry for each table; the entry is `None` if the table does
+    /// not have a LogUp argument.
+    pub fn send_logup_sums(&mut self, logup_sums: &[Option<E>]) {
+        let mut sums = Vec::new();
+        for (table_sum, &logup_sum) in self.logup_sums.iter_mut().zip(logup_sums) {
+            if let Some(logup_sum) = logup_sum {
+                *table_sum = logup_sum.to_bytes();
+                sums.push(logup_sum);
+            }
+        }
+
+        if !sums.is_empty() {
+            self.public_coin.reseed(H::hash_elements(&sums));
+        }
+    }
+
+    /// Saves the evaluations of the trace and constraint composition polynomials of the specified
+    /// table over the out-of-domain evaluation frame. This also reseeds the public coin with the
+    /// hash of all OOD evaluations.
+    pub fn send_ood_evaluations(
+        &mut self,
+        table_idx: usize,
+        trace_ood_frame: &TraceOodFrame<E>,
+        constraints_ood_frame: &QuotientOodFrame<E>,
+    ) {
+        self.ood_frames[table_idx].set_trace_states::<E>(trace_ood_frame);
+        self.ood_frames[table_idx].set_quotient_states::<E>(constraints_ood_frame);
+        let ood_evals = merge_ood_evaluations(trace_ood_frame, constraints_ood_frame);
+        let digest = H::hash_elements(&ood_evals);
+
+        self.public_coin.reseed(digest);
+    }
+
+    // PUBLIC COIN METHODS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the inner public coin
+    pub fn public_coin(&mut self) -> &mut R {
+        &mut self.public_coin
+    }
+
+    /// Returns a set of coefficients for constructing a constraint composition polynomial of the
+    /// specified table.
+    ///
+    /// The coefficients are drawn from the public coin uniformly at random.
+    pub fn get_constraint_composition_coeffs(
+        &mut self,
+        table_idx: usize,
+    ) -> ConstraintCompositionCoefficients<E> {
+        self.airs[table_idx]
+            .get_constraint_composition_coefficients(&mut self.public_coin)
+            .expect("failed to draw composition coefficients")
+    }
+
+    /// Returns an out-of-domain point drawn uniformly at random from the public coin.
+    pub fn get_ood_point(&mut self) -> E {
+        self.public_coin.draw().expect("failed to draw OOD point")
+    }
+
+    /// Returns a set of coefficients for constructing a DEEP composition polynomial of the
+    /// specified table.
+    ///
+    /// The coefficients are drawn from the public coin uniformly at random.
+    pub fn get_deep_composition_coeffs(
+        &mut self,
+        table_idx: usize,
+    ) -> DeepCompositionCoefficients<E> {
+        self.airs[table_idx]
+            .get_deep_composition_coefficients(&mut self.public_coin)
+            .expect("failed to draw DEEP composition coefficients")
+    }
+
+    /// Returns a set of positions in the LDE domain of the first (largest) table against which
+    /// the evaluations of trace and constraint composition polynomials should be queried.
+    ///
+    /// The positions are drawn from the public coin uniformly at random. Duplicate positions
+    /// are removed from the returned vector.
+    pub fn get_query_positions(&mut self) -> Vec<usize> {
+        let num_queries = self.contexts[0].options().num_queries();
+        let lde_domain_size = self.contexts[0].lde_domain_size();
+        let mut positions = self
+            .public_coin
+            .draw_integers(num_queries, lde_domain_size, self.pow_nonce)
+            .expect("failed to draw query position");
+
+        // remove any duplicate positions from the list
+        positions.sort_unstable();
+        positions.dedup();
+
+        positions
+    }
+
+    /// Determines a nonce, which when hashed with the current seed of the public coin results
+    /// in a new seed with the number of leading zeros equal to the grinding_factor specified
+    /// in the proof options.
+    pub fn grind_query_seed(&mut self) {
+        let grinding_factor = self.contexts[0].options().grinding_factor();
+
+        #[cfg(not(feature = "concurrent"))]
+        let nonce = (1..u64::MAX)
+            .find(|&nonce| self.public_coin.check_leading_zeros(nonce) >= grinding_factor)
+            .expect("nonce not found");
+
+        #[cfg(feature = "concurrent")]
+        let nonce = (1..u64::MAX)
+            .into_par_iter()
+            .find_any(|&nonce| self.public_coin.check_leading_zeros(nonce) >= grinding_factor)
+            .expect("nonce not found");
+
+        self.pow_nonce = nonce;
+    }
+
+    // PROOF BUILDER
+    // --------------------------------------------------------------------------------------------
+    /// Builds a multi-table proof from the previously committed values as well as values passed
+    /// into this method.
+    ///
+    /// `table_queries` must contain trace queries, constraint queries, and the number of unique
+    /// query positions for each table.
+    pub fn build_proof(
+        self,
+        table_queries: Vec<(Vec<Queries>, Queries, usize)>,
+        fri_proof: FriProof,
+    ) -> MultiProof {
+        let tables = self
+            .contexts
+            .into_iter()
+            .zip(self.commitments)
+            .zip(self.ood_frames)
+            .zip(self.logup_sums)
+            .zip(table_queries)
+            .map(
+                |(
+                    (((context, commitments), ood_frame), logup_sum),
+                    (trace_queries, constraint_queries, num_query_positions),
+                )| {
+                    assert!(num_query_positions <= u8::MAX as usize, "num_query_positions too big");
+                    TableProof {
+                        context,
+                        num_unique_queries: num_query_positions as u8,
+                        commitments,
+                        trace_queries,
+                        constraint_queries,
+                        ood_frame,
+                        logup_sum,
+                    }
+                },
+            )
+            .collect();
+
+        MultiProof {
+            tables,
+            fri_commitments: self.fri_commitments,
+            fri_proof,
+            pow_nonce: self.pow_nonce,
+        }
+    }
+}
+
+// FRI PROVER CHANNEL IMPLEMENTATION
+// ================================================================================================
+
+impl<A, E, H, R, V> fri::ProverChannel<E> for MultiProverChannel<'_, A, E, H, R, V>
+where
+    A: Air,
+    E: FieldElement<BaseField = A::BaseField>,
+    H: ElementHasher<BaseField = A::BaseField>,
+    R: RandomCoin<BaseField = A::BaseField, Hasher = H>,
+    V: VectorCommitment<H>,
+{
+    type Hasher = H;
+    type VectorCommitment = V;
+
+    /// Commits the prover to a FRI layer.
+    fn commit_fri_layer(&mut self, layer_commitment: V::Commitment) {
+        self.fri_commitments.add(&layer_commitment);
+        self.public_coin.reseed(V::commitment_digest(&layer_commitment));
+    }
+
+    /// Commits the prover to the FRI remainder polynomial.
+    fn commit_fri_remainder(&mut self, remainder_commitment: H::Digest) {
+        self.fri_commitments.add(&remainder_commitment);
+        self.public_coin.reseed(remainder_commitment);
+    }
+
+    /// Returns a new alpha drawn from the public coin.
+    fn draw_fri_alpha(&mut self) -> E {
+        self.public_coin.draw().expect("failed to draw FRI alpha")
+    }
+}
@@ -9,22 +9,22 @@ use air::{
     Air, AirContext, Assertion, BatchingMethod, EvaluationFrame, FieldExtension, ProofOptions,
     TraceInfo, TransitionConstraintDegree,
 };
-use math::{fields::f128::BaseElement, FieldElement, StarkField};
+use math::{ExtensibleField, FieldElement, StarkField};
 
 use crate::TraceTable;
 
 // FIBONACCI TRACE BUILDER
 // ================================================================================================
 
-pub fn build_fib_trace(length: usize) -> TraceTable<BaseElement> {
+pub fn build_fib_trace<B: StarkField>(length: usize) -> TraceTable<B> {
     assert!(length.is_power_of_two(), "length must be a power of 2");
 
-    let mut reg1 = vec![BaseElement::ONE];
-    let mut reg2 = vec![BaseElement::ONE];
+    let mut reg1 = vec![B::ONE];
+    let mut reg2 = vec![B::ONE];
 
     for i in 0..(length / 2 - 1) {
         reg1.push(reg1[i] + reg2[i]);
-        reg2.push(reg1[i] + BaseElement::from(2u8) * reg2[i]);
+        reg2.push(reg1[i] + B::from(2u8) * reg2[i]);
     }
 
     TraceTable::init(vec![reg1, reg2])
@@ -33,13 +33,16 @@ pub fn build_fib_trace(length: usize) -> TraceTable<BaseElement> {
 // MOCK AIR
 // ================================================================================================
 
-pub struct MockAir {
-    context: AirContext<BaseElement>,
-    assertions: Vec<Assertion<BaseElement>>,
-    periodic_columns: Vec<Vec<BaseElement>>,
+pub struct MockAir<B: StarkField> {
+    context: AirContext<B>,
+    assertions: Vec<Assertion<B>>,
+    periodic_columns: Vec<Vec<B>>,
 }
 
-impl MockAir {
+impl<B> MockAir<B>
+where
+    B: StarkField + ExtensibleField<2> + ExtensibleField<3> + ExtensibleField<4>,
+{
     pub fn with_trace_length(trace_length: usize) -> Self {
         Self::new(
             TraceInfo::new(4, trace_length),
@@ -57,10 +60,7 @@ impl MockAir {
         )
     }
 
-    pub fn with_periodic_columns(
-        column_values: Vec<Vec<BaseElement>>,
-        trace_length: usize,
-    ) -> Self {
+    pub fn with_periodic_columns(column_values: Vec<Vec<B>>, trace_length: usize) -> Self {
         let mut result = Self::new(
             TraceInfo::new(4, trace_length),
             (),
@@ -79,7 +79,7 @@ impl MockAir {
         result
     }
 
-    pub fn with_assertions(assertions: Vec<Assertion<BaseElement>>, trace_length: usize) -> Self {
+    pub fn with_assertions(assertions: Vec<Assertion<B>>, trace_length: usize) -> Self {
         let mut result = Self::new(
             TraceInfo::new(4, trace_length),
             (),
@@ -99,8 +99,11 @@ impl MockAir {
     }
 }
 
-impl Air for MockAir {
-    type BaseField = BaseElement;
+impl<B> Air for MockAir<B>
+where
+    B: StarkField + ExtensibleField<2> + ExtensibleField<3> + ExtensibleField<4>,
+{
+    type BaseField = B;
     type PublicInputs = ();
 
     fn new(trace_info: TraceInfo, _pub_inputs: (), _options: ProofOptions) -> Self {
@@ -0,0 +1,64 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use alloc::vec::Vec;
+
+use air::LogUp;
+use math::{batch_inversion, FieldElement};
+
+use super::ColMatrix;
+
+// LOGUP COLUMN BUILDER
+// ================================================================================================
+
+/// Builds the running-sum column of the specified LogUp argument over the provided main trace
+/// segment.
+///
+/// The value of the column at step 0 is zero, and the value at step `i + 1` is equal to the value
+/// at step `i` plus `sum(1 / d(l_k(i))) - m(i) / d(t(i))`. Thus, if the lookup argument is valid,
+/// the column is zero again at the first step which is exempt from transition constraints. For
+/// one-sided arguments, the missing side contributes nothing to the running sum.
+///
+/// `rand_elements` must contain exactly [LogUp::num_rand_elements()] elements.
+pub(crate) fn build_logup_column<E: FieldElement>(
+    logup: &LogUp,
+    main_trace: &ColMatrix<E::BaseField>,
+    rand_elements: &[E],
+) -> Vec<E> {
+    let num_rows = main_trace.num_rows();
+    let num_dens = logup.num_denominators();
+
+    // compute denominators for the table tuple (if any) and all looked-up tuples at every row,
+    // and invert them all at once
+    let mut row = vec![E::BaseField::ZERO; main_trace.num_cols()];
+    let mut denominators = Vec::with_capacity(num_rows * num_dens);
+    for i in 0..num_rows {
+        main_trace.read_row_into(i, &mut row);
+        if logup.multiplicity().is_some() {
+            denominators.push(logup.get_denominator(&row, logup.table(), rand_elements));
+        }
+        for lookup in logup.lookups() {
+            denominators.push(logup.get_denominator(&row, lookup, rand_elements));
+        }
+    }
+    let inv_denominators = batch_inversion(&denominators);
+
+    // accumulate the running sum
+    let multiplicities = logup.multiplicity().map(|column| main_trace.get_column(column));
+    let mut result = Vec::with_capacity(num_rows);
+    result.push(E::ZERO);
+    for (i, inv_dens) in inv_denominators.chunks(num_dens).take(num_rows - 1).enumerate() {
+        let delta = match multiplicities {
+            Some(multiplicities) => {
+                let lookups_sum = inv_dens[1..].iter().fold(E::ZERO, |acc, &inv_den| acc + inv_den);
+                lookups_sum - inv_dens[0].mul_base(multiplicities[i])
+            },
+            None => inv_dens.iter().fold(E::ZERO, |acc, &inv_den| acc + inv_den),
+        };
+        result.push(result[i] + delta);
+    }
+
+    result
+}
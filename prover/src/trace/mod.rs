@@ -17,6 +17,9 @@ pub use poly_table::TracePolyTable;
 mod trace_table;
 pub use trace_table::{TraceTable, TraceTableFragment};
 
+mod logup;
+pub(crate) use logup::build_logup_column;
+
 #[cfg(test)]
 mod tests;
 
@@ -60,6 +63,10 @@ pub trait Trace: Sized {
     fn main_segment(&self) -> &ColMatrix<Self::BaseField>;
 
     /// Reads an evaluation frame from the main trace segment at the specified row.
+    ///
+    /// The frame must be populated with one row for each of its offsets, i.e., the row at index
+    /// `i` of the frame must be set to the trace row at `row_idx + frame.offsets()[i]` (wrapping
+    /// around at the end and at the start of the trace).
     fn read_main_frame(&self, row_idx: usize, frame: &mut EvaluationFrame<Self::BaseField>);
 
     // PROVIDED METHODS
@@ -117,7 +124,7 @@ pub trait Trace: Sized {
             let aux_trace = &aux_trace_with_metadata.aux_trace;
             let aux_rand_elements = &aux_trace_with_metadata.aux_rand_elements;
 
-            for assertion in air.get_aux_assertions(aux_rand_elements) {
+            for assertion in air.get_all_aux_assertions(aux_rand_elements) {
                 // get the matrix and verify the assertion against it
                 assertion.apply(self.length(), |step, value| {
                     assert!(
@@ -140,9 +147,10 @@ pub trait Trace: Sized {
 
         // initialize buffers to hold evaluation frames and results of constraint evaluations
         let mut x = Self::BaseField::ONE;
-        let mut main_frame = EvaluationFrame::new(self.main_trace_width());
+        let frame_offsets = air.context().frame_offsets();
+        let mut main_frame = EvaluationFrame::with_offsets(self.main_trace_width(), frame_offsets);
         let mut aux_frame = if air.trace_info().is_multi_segment() {
-            Some(EvaluationFrame::<E>::new(self.aux_trace_width()))
+            Some(EvaluationFrame::<E>::with_offsets(self.aux_trace_width(), frame_offsets))
         } else {
             None
         };
@@ -150,9 +158,26 @@ pub trait Trace: Sized {
             vec![Self::BaseField::ZERO; air.context().num_main_transition_constraints()];
         let mut aux_evaluations = vec![E::ZERO; air.context().num_aux_transition_constraints()];
 
-        // we check transition constraints on all steps except the last k steps, where k is the
-        // number of steps exempt from transition constraints (guaranteed to be at least 1)
-        for step in 0..self.length() - air.context().num_transition_exemptions() {
+        // by default, transition constraints are checked on all steps except the last k steps,
+        // where k is the number of steps exempt from transition constraints (guaranteed to be at
+        // least 1); constraints with custom divisors are checked only on the steps at which their
+        // divisors are enforced
+        let divisors = air.context().transition_divisors();
+        let constraint_divisors = air.context().transition_constraint_divisors();
+        let (main_divisors, aux_divisors) =
+            constraint_divisors.split_at(air.context().num_main_transition_constraints());
+        let mut is_enforced = vec![false; divisors.len()];
+
+        for step in 0..self.length() {
+            // determine which constraint divisors are enforced at this step
+            for (divisor, is_enforced) in divisors.iter().zip(is_enforced.iter_mut()) {
+                *is_enforced = divisor.is_enforced_at(x);
+            }
+            if !is_enforced.contains(&true) {
+                x *= g;
+                continue;
+            }
+
             // build periodic values
             for (p, v) in periodic_values_polys.iter().zip(periodic_values.iter_mut()) {
                 let num_cycles = air.trace_length() / p.len();
@@ -166,7 +191,7 @@ pub trait Trace: Sized {
             air.evaluate_transition(&main_frame, &periodic_values, &mut main_evaluations);
             for (i, &evaluation) in main_evaluations.iter().enumerate() {
                 assert!(
-                    evaluation == Self::BaseField::ZERO,
+                    !is_enforced[main_divisors[i]] || evaluation == Self::BaseField::ZERO,
                     "main transition constraint {i} did not evaluate to ZERO at step {step}"
                 );
             }
@@ -180,7 +205,7 @@ pub trait Trace: Sized {
                 let aux_rand_elements = &aux_trace_with_metadata.aux_rand_elements;
 
                 read_aux_frame(aux_trace, step, aux_frame);
-                air.evaluate_aux_transition(
+                air.evaluate_all_aux_transitions(
                     &main_frame,
                     aux_frame,
                     &periodic_values,
@@ -189,7 +214,7 @@ pub trait Trace: Sized {
                 );
                 for (i, &evaluation) in aux_evaluations.iter().enumerate() {
                     assert!(
-                        evaluation == E::ZERO,
+                        !is_enforced[aux_divisors[i]] || evaluation == E::ZERO,
                         "auxiliary transition constraint {i} did not evaluate to ZERO at step {step}"
                     );
                 }
@@ -213,15 +238,13 @@ fn read_aux_frame<E>(aux_segment: &ColMatrix<E>, row_idx: usize, frame: &mut Eva
 where
     E: FieldElement,
 {
-    for (current_frame_cell, aux_segment_col) in
-        frame.current_mut().iter_mut().zip(aux_segment.columns())
-    {
-        *current_frame_cell = aux_segment_col[row_idx];
-    }
-
-    let next_row_idx = (row_idx + 1) % aux_segment.num_rows();
-    for (next_frame_cell, aux_segment_col) in frame.next_mut().iter_mut().zip(aux_segment.columns())
-    {
-        *next_frame_cell = aux_segment_col[next_row_idx];
+    for i in 0..frame.num_rows() {
+        let frame_row_idx =
+            (row_idx as isize + frame.offsets()[i]).rem_euclid(aux_segment.num_rows() as isize);
+        let frame_row_idx = frame_row_idx as usize;
+        for (frame_cell, aux_segment_col) in frame.row_mut(i).iter_mut().zip(aux_segment.columns())
+        {
+            *frame_cell = aux_segment_col[frame_row_idx];
+        }
     }
 }
@@ -16,34 +16,72 @@ use crate::{matrix::ColumnIter, ColMatrix};
 /// Trace polynomials in coefficient from for all segments of the execution trace.
 ///
 /// Coefficients of the polynomials for the main trace segment are always in the base field.
-/// However, coefficients of the polynomials for the auxiliary trace segment may be either in
+/// However, coefficients of the polynomials for the auxiliary trace segments may be either in
 /// the base field, or in the extension field, depending on whether extension field is being used.
+///
+/// In zero-knowledge mode, trace polynomials are randomized, and thus, the size of the polynomials
+/// is greater than the length of the execution trace.
 pub struct TracePolyTable<E: FieldElement> {
     main_trace_polys: ColMatrix<E::BaseField>,
     aux_trace_polys: Option<ColMatrix<E>>,
+    trace_length: usize,
 }
 
 impl<E: FieldElement> TracePolyTable<E> {
     // CONSTRUCTOR
     // --------------------------------------------------------------------------------------------
     /// Creates a new table of trace polynomials from the provided main trace segment polynomials.
+    ///
+    /// The length of the execution trace is assumed to be equal to the size of the polynomials.
     pub fn new(main_trace_polys: ColMatrix<E::BaseField>) -> Self {
-        Self { main_trace_polys, aux_trace_polys: None }
+        let trace_length = main_trace_polys.num_rows();
+        Self {
+            main_trace_polys,
+            aux_trace_polys: None,
+            trace_length,
+        }
+    }
+
+    /// Sets the length of the execution trace described by the polynomials of this table.
+    ///
+    /// This is needed only when trace polynomials are randomized (i.e., in zero-knowledge mode),
+    /// and thus, the size of the polynomials is greater than the trace length.
+    ///
+    /// # Panics
+    /// Panics if `trace_length` is not a power of two or is greater than the size of the
+    /// polynomials.
+    pub fn with_trace_length(mut self, trace_length: usize) -> Self {
+        assert!(trace_length.is_power_of_two(), "trace length must be a power of two");
+        assert!(
+            trace_length <= self.poly_size(),
+            "trace length cannot exceed the size of trace polynomials"
+        );
+        self.trace_length = trace_length;
+        self
     }
 
     // STATE MUTATORS
     // --------------------------------------------------------------------------------------------
 
     /// Adds the provided auxiliary segment polynomials to this polynomial table.
+    ///
+    /// If polynomials of other auxiliary segments have already been added to this table, the
+    /// provided polynomials are appended after them.
     pub fn add_aux_segment(&mut self, aux_trace_polys: ColMatrix<E>) {
-        assert!(self.aux_trace_polys.is_none());
         assert_eq!(
             self.main_trace_polys.num_rows(),
             aux_trace_polys.num_rows(),
             "polynomials in auxiliary segment must be of the same size as in the main segment"
         );
 
-        self.aux_trace_polys = Some(aux_trace_polys);
+        match self.aux_trace_polys {
+            Some(ref mut polys) => {
+                for column in aux_trace_polys.into_columns() {
+                    polys.merge_column(column);
+                }
+            },
+            None => self.aux_trace_polys = Some(aux_trace_polys),
+        }
     }
 
     // PUBLIC ACCESSORS
@@ -54,6 +92,11 @@ impl<E: FieldElement> TracePolyTable<E> {
         self.main_trace_polys.num_rows()
     }
 
+    /// Returns the length of the execution trace described by the polynomials of this table.
+    pub fn trace_length(&self) -> usize {
+        self.trace_length
+    }
+
     /// Evaluates all trace polynomials (across all trace segments) at the specified point `x`.
     pub fn evaluate_at(&self, x: E) -> Vec<E> {
         let mut result = self.main_trace_polys.evaluate_columns_at(x);
@@ -64,15 +107,24 @@ impl<E: FieldElement> TracePolyTable<E> {
     }
 
     /// Returns an out-of-domain evaluation frame constructed by evaluating trace polynomials for
-    /// all columns at points z and z * g, where g is the generator of the trace domain.
-    pub fn get_ood_frame(&self, z: E) -> TraceOodFrame<E> {
-        let log_trace_len = self.poly_size().ilog2();
-        let g = E::from(E::BaseField::get_root_of_unity(log_trace_len));
-        let current_row = self.evaluate_at(z);
-        let next_row = self.evaluate_at(z * g);
+    /// all columns at points z * g^k for each of the specified frame offsets k, where g is the
+    /// generator of the trace domain.
+    ///
+    /// For the default frame offsets `[0, 1]`, this evaluates the polynomials at z and z * g.
+    pub fn get_ood_frame(&self, z: E, frame_offsets: &[isize]) -> TraceOodFrame<E> {
+        let trace_length = self.trace_length();
+        let g = E::BaseField::get_root_of_unity(trace_length.ilog2());
+        let rows = frame_offsets
+            .iter()
+            .map(|&offset| {
+                // negative offsets wrap around the trace domain: g^{-k} = g^{n - k}
+                let power = offset.rem_euclid(trace_length as isize) as u64;
+                self.evaluate_at(z.mul_base(g.exp_vartime(power.into())))
+            })
+            .collect();
         let main_trace_width = self.main_trace_polys.num_cols();
 
-        TraceOodFrame::new(current_row, next_row, main_trace_width)
+        TraceOodFrame::from_rows(rows, frame_offsets, main_trace_width)
     }
 
     /// Returns an iterator over the polynomials of the main trace segment.
@@ -80,7 +132,7 @@ impl<E: FieldElement> TracePolyTable<E> {
         self.main_trace_polys.columns()
     }
 
-    /// Returns an iterator over the polynomials of the auxiliary trace segment.
+    /// Returns an iterator over the polynomials of all auxiliary trace segments.
     pub fn aux_trace_polys(&self) -> impl Iterator<Item = &[E]> {
         match self.aux_trace_polys {
             Some(ref aux_segment_polys) => aux_segment_polys.columns(),
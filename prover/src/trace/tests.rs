@@ -5,9 +5,11 @@
 
 use alloc::vec::Vec;
 
-use math::fields::f128::BaseElement;
+use air::LogUp;
+use math::{fields::f128::BaseElement, FieldElement};
 
-use crate::{tests::build_fib_trace, Trace};
+use super::build_logup_column;
+use crate::{tests::build_fib_trace, ColMatrix, Trace};
 
 #[test]
 fn new_trace_table() {
@@ -29,3 +31,52 @@ fn new_trace_table() {
         .collect();
     assert_eq!(expected, trace.get_column(1));
 }
+
+#[test]
+fn build_logup_column_valid_lookup() {
+    // column 0 looks up values 3, 1, 1, 0, 6, 2, 5 in the table [0, 7) defined by column 1; the
+    // value in the last row is not looked up
+    let looked_up = [3u32, 1, 1, 0, 6, 2, 5, 42];
+    let multiplicities = [1u32, 2, 1, 1, 0, 1, 1, 0];
+    let main_trace = ColMatrix::new(vec![
+        looked_up.into_iter().map(BaseElement::from).collect(),
+        (0..8u32).map(BaseElement::from).collect(),
+        multiplicities.into_iter().map(BaseElement::from).collect(),
+    ]);
+
+    let logup = LogUp::new(vec![vec![0]], vec![1], 2);
+    let column = build_logup_column(&logup, &main_trace, &[BaseElement::from(97u32)]);
+    assert_eq!(8, column.len());
+    assert_eq!(BaseElement::ZERO, column[0]);
+    assert_eq!(BaseElement::ZERO, column[7]);
+
+    // changing a looked-up value breaks the argument
+    let mut main_trace = main_trace;
+    main_trace.set(0, 2, BaseElement::from(4u32));
+    let column = build_logup_column(&logup, &main_trace, &[BaseElement::from(97u32)]);
+    assert_ne!(BaseElement::ZERO, column[7]);
+}
+
+#[test]
+fn build_logup_column_one_sided() {
+    // the same lookup as above, but with the looked-up side and the table side split into two
+    // separate arguments; the running sums of the two arguments must cancel out
+    let looked_up = [3u32, 1, 1, 0, 6, 2, 5, 42];
+    let multiplicities = [1u32, 2, 1, 1, 0, 1, 1, 0];
+    let main_trace = ColMatrix::new(vec![
+        looked_up.into_iter().map(BaseElement::from).collect(),
+        (0..8u32).map(BaseElement::from).collect(),
+        multiplicities.into_iter().map(BaseElement::from).collect(),
+    ]);
+    let rand_elements = [BaseElement::from(97u32)];
+
+    let lookups = LogUp::new_lookups(vec![vec![0]]);
+    let lookups_column = build_logup_column(&lookups, &main_trace, &rand_elements);
+    assert_eq!(BaseElement::ZERO, lookups_column[0]);
+    assert_ne!(BaseElement::ZERO, lookups_column[7]);
+
+    let table = LogUp::new_table(vec![1], 2);
+    let table_column = build_logup_column(&table, &main_trace, &rand_elements);
+    assert_eq!(BaseElement::ZERO, table_column[0]);
+    assert_eq!(BaseElement::ZERO, lookups_column[7] + table_column[7]);
+}
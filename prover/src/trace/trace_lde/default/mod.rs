@@ -27,7 +27,11 @@ mod tests;
 /// - Main segment: this is the first trace segment generated by the prover. Values in this segment
 ///   will always be elements in the base field (even when an extension field is used).
 /// - Auxiliary segments: a list of 0 or more segments for traces generated after the prover commits
-///   to the first trace segment. Currently, at most 1 auxiliary segment is possible.
+///   to the first trace segment. Each auxiliary segment is committed to separately.
+///
+/// In zero-knowledge mode, trace polynomials are randomized before they are extended, and the
+/// leaves of segment commitments are salted; salts of the queried leaves are appended to the
+/// queried rows.
 pub struct DefaultTraceLde<
     E: FieldElement,
     H: ElementHasher<BaseField = E::BaseField>,
@@ -37,10 +41,15 @@ pub struct DefaultTraceLde<
     main_segment_lde: RowMatrix<E::BaseField>,
     // commitment to the main segment of the trace
     main_segment_oracles: V,
-    // low-degree extensions of the auxiliary segment of the trace
-    aux_segment_lde: Option<RowMatrix<E>>,
-    // commitment to the auxiliary segment of the trace
-    aux_segment_oracles: Option<V>,
+    // salts of the leaves of the main segment commitment; empty unless zero-knowledge mode is
+    // enabled
+    main_segment_salts: Vec<E::BaseField>,
+    // low-degree extensions of the auxiliary segments of the trace
+    aux_segment_ldes: Vec<RowMatrix<E>>,
+    // commitments to the auxiliary segments of the trace
+    aux_segment_oracles: Vec<V>,
+    // salts of the leaves of the auxiliary segment commitments
+    aux_segment_salts: Vec<Vec<E>>,
     blowup: usize,
     trace_info: TraceInfo,
     partition_options: PartitionOptions,
@@ -67,15 +76,23 @@ where
         partition_options: PartitionOptions,
     ) -> (Self, TracePolyTable<E>) {
         // extend the main execution trace and build a commitment to the extended trace
-        let (main_segment_lde, main_segment_vector_com, main_segment_polys) =
-            build_trace_commitment::<E, E::BaseField, H, V>(main_trace, domain, partition_options);
-
-        let trace_poly_table = TracePolyTable::new(main_segment_polys);
+        let (main_segment_lde, main_segment_vector_com, main_segment_polys, main_segment_salts) =
+            build_trace_commitment::<E, E::BaseField, H, V>(
+                main_trace,
+                domain,
+                partition_options,
+                0,
+            );
+
+        let trace_poly_table =
+            TracePolyTable::new(main_segment_polys).with_trace_length(domain.trace_length());
         let trace_lde = DefaultTraceLde {
             main_segment_lde,
             main_segment_oracles: main_segment_vector_com,
-            aux_segment_lde: None,
-            aux_segment_oracles: None,
+            main_segment_salts,
+            aux_segment_ldes: Vec::new(),
+            aux_segment_oracles: Vec::new(),
+            aux_segment_salts: Vec::new(),
             blowup: domain.trace_to_lde_blowup(),
             trace_info: trace_info.clone(),
             partition_options,
@@ -119,7 +136,7 @@ where
     type VC = V;
 
     /// Returns the commitment to the low-degree extension of the main trace segment.
-    fn get_main_trace_commitment(&self) -> H::Digest {
+    fn get_main_trace_commitment(&self) -> V::Commitment {
         self.main_segment_oracles.commitment()
     }
 
@@ -136,20 +153,26 @@ where
     ///
     /// This function will panic if any of the following are true:
     /// - the number of rows in the provided `aux_trace` does not match the main trace.
-    /// - the auxiliary trace has been previously set already.
+    /// - all auxiliary trace segments have been previously set already.
     fn set_aux_trace(
         &mut self,
         aux_trace: &ColMatrix<E>,
         domain: &StarkDomain<E::BaseField>,
-    ) -> (ColMatrix<E>, H::Digest) {
+    ) -> (ColMatrix<E>, V::Commitment) {
         // extend the auxiliary trace segment and build a commitment to the extended trace
-        let (aux_segment_lde, aux_segment_oracles, aux_segment_polys) =
-            build_trace_commitment::<E, E, H, Self::VC>(aux_trace, domain, self.partition_options);
+        let segment_idx = 1 + self.aux_segment_ldes.len();
+        let (aux_segment_lde, aux_segment_oracles, aux_segment_polys, aux_segment_salts) =
+            build_trace_commitment::<E, E, H, Self::VC>(
+                aux_trace,
+                domain,
+                self.partition_options,
+                segment_idx,
+            );
 
         // check errors
         assert!(
-            usize::from(self.aux_segment_lde.is_some()) < self.trace_info.num_aux_segments(),
-            "the auxiliary trace has already been added"
+            self.aux_segment_ldes.len() < self.trace_info.num_aux_segments(),
+            "all auxiliary trace segments have already been added"
         );
         assert_eq!(
             self.main_segment_lde.num_rows(),
@@ -158,40 +181,55 @@ where
         );
 
         // save the lde and commitment
-        self.aux_segment_lde = Some(aux_segment_lde);
+        self.aux_segment_ldes.push(aux_segment_lde);
         let commitment_string = aux_segment_oracles.commitment();
-        self.aux_segment_oracles = Some(aux_segment_oracles);
+        self.aux_segment_oracles.push(aux_segment_oracles);
+        self.aux_segment_salts.push(aux_segment_salts);
 
         (aux_segment_polys, commitment_string)
     }
 
     /// Reads current and next rows from the main trace segment into the specified frame.
+    ///
+    /// If the frame contains more than two rows, a row is read for each of the frame's offsets.
     fn read_main_trace_frame_into(
         &self,
         lde_step: usize,
         frame: &mut EvaluationFrame<E::BaseField>,
     ) {
-        // at the end of the trace, next state wraps around and we read the first step again
-        let next_lde_step = (lde_step + self.blowup()) % self.trace_len();
-
-        // copy main trace segment values into the frame
-        frame.current_mut().copy_from_slice(self.main_segment_lde.row(lde_step));
-        frame.next_mut().copy_from_slice(self.main_segment_lde.row(next_lde_step));
+        read_frame_into(&self.main_segment_lde, lde_step, self.blowup(), frame);
     }
 
-    /// Reads current and next rows from the auxiliary trace segment into the specified frame.
+    /// Reads current and next rows from all auxiliary trace segments into the specified frame.
+    ///
+    /// If the frame contains more than two rows, a row is read for each of the frame's offsets.
+    /// Columns of the auxiliary segments are placed into the frame one segment after another.
     ///
     /// # Panics
-    /// This currently assumes that there is exactly one auxiliary trace segment, and will panic
-    /// otherwise.
+    /// Panics if not all auxiliary trace segments have been set.
     fn read_aux_trace_frame_into(&self, lde_step: usize, frame: &mut EvaluationFrame<E>) {
-        // at the end of the trace, next state wraps around and we read the first step again
-        let next_lde_step = (lde_step + self.blowup()) % self.trace_len();
+        assert_eq!(
+            self.aux_segment_ldes.len(),
+            self.trace_info.num_aux_segments(),
+            "expected all auxiliary segments to be present"
+        );
 
-        // copy auxiliary trace segment values into the frame
-        let segment = self.aux_segment_lde.as_ref().expect("expected aux segment to be present");
-        frame.current_mut().copy_from_slice(segment.row(lde_step));
-        frame.next_mut().copy_from_slice(segment.row(next_lde_step));
+        if let [segment] = self.aux_segment_ldes.as_slice() {
+            read_frame_into(segment, lde_step, self.blowup(), frame);
+            return;
+        }
+
+        let num_rows = self.main_segment_lde.num_rows();
+        for i in 0..frame.num_rows() {
+            let row_step =
+                get_frame_row_step(lde_step, frame.offsets()[i], self.blowup(), num_rows);
+            let mut col_offset = 0;
+            for segment in self.aux_segment_ldes.iter() {
+                let row = segment.row(row_step);
+                frame.row_mut(i)[col_offset..col_offset + row.len()].copy_from_slice(row);
+                col_offset += row.len();
+            }
+        }
     }
 
     /// Returns trace table rows at the specified positions along with an opening proof to these
@@ -201,14 +239,23 @@ where
         let mut result = vec![build_segment_queries::<E::BaseField, H, V>(
             &self.main_segment_lde,
             &self.main_segment_oracles,
+            &self.main_segment_salts,
             positions,
         )];
 
-        // build queries for the auxiliary trace segment
-        if let Some(ref segment_oracles) = self.aux_segment_oracles {
-            let segment_lde =
-                self.aux_segment_lde.as_ref().expect("expected aux segment to be present");
-            result.push(build_segment_queries::<E, H, V>(segment_lde, segment_oracles, positions));
+        // build queries for the auxiliary trace segments
+        for ((segment_lde, segment_oracles), segment_salts) in self
+            .aux_segment_ldes
+            .iter()
+            .zip(self.aux_segment_oracles.iter())
+            .zip(self.aux_segment_salts.iter())
+        {
+            result.push(build_segment_queries::<E, H, V>(
+                segment_lde,
+                segment_oracles,
+                segment_salts,
+                positions,
+            ));
         }
 
         result
@@ -238,15 +285,19 @@ where
 ///
 /// The extension is performed by interpolating each column of the execution trace into a
 /// polynomial of degree = trace_length - 1, and then evaluating the polynomial over the LDE
-/// domain.
+/// domain. In zero-knowledge mode, the polynomials are randomized before they are evaluated
+/// (the randomness depends on `segment_idx`).
 ///
 /// The trace commitment is computed by building a vector containing the hashes of each row of
-/// the extended execution trace, then building a vector commitment to the resulting vector.
+/// the extended execution trace, then building a vector commitment to the resulting vector. In
+/// zero-knowledge mode, each row is salted with a random salt; the salts are returned as the last
+/// element of the tuple (otherwise, the returned vector of salts is empty).
 fn build_trace_commitment<E, F, H, V>(
     trace: &ColMatrix<F>,
     domain: &StarkDomain<E::BaseField>,
     partition_options: PartitionOptions,
-) -> (RowMatrix<F>, V, ColMatrix<F>)
+    segment_idx: usize,
+) -> (RowMatrix<F>, V, ColMatrix<F>, Vec<F>)
 where
     E: FieldElement,
     F: FieldElement<BaseField = E::BaseField>,
@@ -261,7 +312,10 @@ where
             blowup = domain.trace_to_lde_blowup()
         )
         .entered();
-        let trace_polys = trace.interpolate_columns();
+        let mut trace_polys = trace.interpolate_columns();
+        if let Some(zk) = domain.zk() {
+            trace_polys = zk.randomize_trace_polys(trace_polys, segment_idx);
+        }
         let trace_lde =
             RowMatrix::evaluate_polys_over::<DEFAULT_SEGMENT_WIDTH>(&trace_polys, domain);
         drop(span);
@@ -269,21 +323,59 @@ where
         (trace_lde, trace_polys)
     };
     assert_eq!(trace_lde.num_cols(), trace.num_cols());
-    assert_eq!(trace_polys.num_rows(), trace.num_rows());
+    assert_eq!(trace_polys.num_rows(), domain.trace_poly_size());
     assert_eq!(trace_lde.num_rows(), domain.lde_domain_size());
 
     // build trace commitment
     let commitment_domain_size = trace_lde.num_rows();
-    let trace_vector_com = info_span!("compute_execution_trace_commitment", commitment_domain_size)
-        .in_scope(|| trace_lde.commit_to_rows::<H, V>(partition_options));
+    let (trace_vector_com, salts) =
+        info_span!("compute_execution_trace_commitment", commitment_domain_size).in_scope(|| {
+            match domain.zk() {
+                Some(zk) => {
+                    let salts = zk.draw_trace_salts(segment_idx, commitment_domain_size);
+                    let vector_com =
+                        trace_lde.commit_to_salted_rows::<H, V>(&salts, partition_options);
+                    (vector_com, salts)
+                },
+                None => (trace_lde.commit_to_rows::<H, V>(partition_options), Vec::new()),
+            }
+        });
     assert_eq!(trace_vector_com.domain_len(), commitment_domain_size);
 
-    (trace_lde, trace_vector_com, trace_polys)
+    (trace_lde, trace_vector_com, trace_polys, salts)
+}
+
+/// Reads the rows of the provided trace segment LDE into the specified evaluation frame.
+///
+/// For a frame offset `k`, the row is located `k * blowup` steps after `lde_step` in the LDE
+/// domain; at the end of the trace, rows wrap around and we read the first steps again (and
+/// similarly, for negative offsets at the start of the trace we read the last steps).
+fn read_frame_into<E: FieldElement>(
+    segment_lde: &RowMatrix<E>,
+    lde_step: usize,
+    blowup: usize,
+    frame: &mut EvaluationFrame<E>,
+) {
+    let num_rows = segment_lde.num_rows();
+    for i in 0..frame.num_rows() {
+        let row_step = get_frame_row_step(lde_step, frame.offsets()[i], blowup, num_rows);
+        frame.row_mut(i).copy_from_slice(segment_lde.row(row_step));
+    }
+}
+
+/// Returns the LDE step of the row located `offset` trace steps from `lde_step`, wrapping around
+/// the LDE domain of `num_rows` steps.
+fn get_frame_row_step(lde_step: usize, offset: isize, blowup: usize, num_rows: usize) -> usize {
+    (lde_step as isize + offset * blowup as isize).rem_euclid(num_rows as isize) as usize
 }
 
+/// Returns the rows of the provided trace segment LDE at the specified positions together with
+/// a batch opening proof against the segment commitment; if `salts` are not empty, the salt of
+/// each queried leaf is appended to the corresponding row.
 fn build_segment_queries<E, H, V>(
     segment_lde: &RowMatrix<E>,
     segment_vector_com: &V,
+    salts: &[E],
     positions: &[usize],
 ) -> Queries
 where
@@ -293,8 +385,15 @@ where
 {
     // for each position, get the corresponding row from the trace segment LDE and put all these
     // rows into a single vector
-    let trace_states =
-        positions.iter().map(|&pos| segment_lde.row(pos).to_vec()).collect::<Vec<_>>();
+    let salt_len = salts.len() / segment_lde.num_rows();
+    let trace_states = positions
+        .iter()
+        .map(|&pos| {
+            let mut row = segment_lde.row(pos).to_vec();
+            row.extend_from_slice(&salts[pos * salt_len..(pos + 1) * salt_len]);
+            row
+        })
+        .collect::<Vec<_>>();
 
     // build a batch opening proof to the leaves specified by positions
     let trace_proof = segment_vector_com
@@ -6,7 +6,7 @@
 use alloc::vec::Vec;
 
 use air::{proof::Queries, TraceInfo};
-use crypto::{ElementHasher, Hasher, VectorCommitment};
+use crypto::{ElementHasher, VectorCommitment};
 
 use super::{ColMatrix, EvaluationFrame, FieldElement, TracePolyTable};
 use crate::StarkDomain;
@@ -22,7 +22,8 @@ pub use default::DefaultTraceLde;
 /// - Main segment: this is the first trace segment generated by the prover. Values in this segment
 ///   will always be elements in the base field (even when an extension field is used).
 /// - Auxiliary segments: a list of 0 or more segments for traces generated after the prover commits
-///   to the first trace segment. Currently, at most 1 auxiliary segment is possible.
+///   to the first trace segment. Each auxiliary segment is committed to separately, in the order
+///   in which the segments are built.
 pub trait TraceLde<E: FieldElement>: Sync {
     /// The hash function used for hashing the rows of trace segment LDEs.
     type HashFn: ElementHasher<BaseField = E::BaseField>;
@@ -31,12 +32,16 @@ pub trait TraceLde<E: FieldElement>: Sync {
     type VC: VectorCommitment<Self::HashFn>;
 
     /// Returns the commitment to the low-degree extension of the main trace segment.
-    fn get_main_trace_commitment(&self) -> <Self::HashFn as Hasher>::Digest;
+    fn get_main_trace_commitment(&self)
+        -> <Self::VC as VectorCommitment<Self::HashFn>>::Commitment;
 
     /// Takes auxiliary trace segment columns as input, interpolates them into polynomials in
     /// coefficient form, evaluates the polynomials over the LDE domain, and commits to the
     /// polynomial evaluations.
     ///
+    /// This method is invoked once for each auxiliary trace segment, in the order in which the
+    /// segments are built.
+    ///
     /// Returns a tuple containing the column polynomials in coefficient form and the commitment
     /// to the polynomial evaluations over the LDE domain.
     ///
@@ -49,20 +54,25 @@ pub trait TraceLde<E: FieldElement>: Sync {
         &mut self,
         aux_trace: &ColMatrix<E>,
         domain: &StarkDomain<E::BaseField>,
-    ) -> (ColMatrix<E>, <Self::HashFn as Hasher>::Digest);
+    ) -> (ColMatrix<E>, <Self::VC as VectorCommitment<Self::HashFn>>::Commitment);
 
     /// Reads current and next rows from the main trace segment into the specified frame.
+    ///
+    /// If the frame contains more than two rows, a row is read for each of the frame's offsets.
     fn read_main_trace_frame_into(
         &self,
         lde_step: usize,
         frame: &mut EvaluationFrame<E::BaseField>,
     );
 
-    /// Reads current and next rows from the auxiliary trace segment into the specified frame.
+    /// Reads current and next rows from all auxiliary trace segments into the specified frame.
+    ///
+    /// If the frame contains more than two rows, a row is read for each of the frame's offsets.
+    /// Columns of the auxiliary segments are placed into the frame one segment after another.
     fn read_aux_trace_frame_into(&self, lde_step: usize, frame: &mut EvaluationFrame<E>);
 
     /// Returns trace table rows at the specified positions along with an opening proof to these
-    /// rows.
+    /// rows; the result contains one [Queries] for each of the trace segments.
     fn query(&self, positions: &[usize]) -> Vec<Queries>;
 
     /// Returns the number of rows in the execution trace.
@@ -285,9 +285,11 @@ impl<B: StarkField> Trace for TraceTable<B> {
     }
 
     fn read_main_frame(&self, row_idx: usize, frame: &mut EvaluationFrame<Self::BaseField>) {
-        let next_row_idx = (row_idx + 1) % self.info.length();
-        self.trace.read_row_into(row_idx, frame.current_mut());
-        self.trace.read_row_into(next_row_idx, frame.next_mut());
+        for i in 0..frame.num_rows() {
+            let frame_row_idx =
+                (row_idx as isize + frame.offsets()[i]).rem_euclid(self.info.length() as isize);
+            self.trace.read_row_into(frame_row_idx as usize, frame.row_mut(i));
+        }
     }
 
     fn main_segment(&self) -> &ColMatrix<B> {
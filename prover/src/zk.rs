@@ -0,0 +1,210 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use alloc::vec::Vec;
+
+use air::Air;
+use crypto::{hashers::Blake3_256, DefaultRandomCoin, Hasher, RandomCoin};
+use math::FieldElement;
+
+use crate::ColMatrix;
+
+// CONSTANTS
+// ================================================================================================
+
+// labels of the PRNG streams used to randomize different parts of the proof; trace labels are
+// combined with the index of the trace segment
+const TRACE_POLYS_LABEL: u64 = 0;
+const TRACE_SALTS_LABEL: u64 = 1;
+const COMPOSITION_POLYS_LABEL: u64 = 2;
+const COMPOSITION_SALTS_LABEL: u64 = 3;
+
+// ZERO-KNOWLEDGE RANDOMIZER
+// ================================================================================================
+
+/// Source of randomness for proofs generated in zero-knowledge mode.
+///
+/// A randomizer is instantiated from a secret 32-byte seed and deterministically derives from it
+/// all random values needed to make a proof zero-knowledge:
+/// * Random polynomials which are used to randomize trace polynomials as
+///   $t'(x) = t(x) + (x^n - 1) \cdot r(x)$, where $n$ is the trace length. Randomized trace
+///   polynomials agree with the original trace polynomials over the trace domain.
+/// * Random polynomials which mask the columns of the constraint composition polynomial, and an
+///   additional random column which is committed to together with the composition polynomial
+///   columns.
+/// * Salts for the leaves of trace and constraint commitments.
+///
+/// The seed must be freshly sampled for each proof (see [Prover::zk_seed()](crate::Prover)).
+pub struct ZkRandomizer {
+    seed: [u8; 32],
+    trace_length: usize,
+    trace_poly_size: usize,
+    num_trace_randomizer_coeffs: usize,
+    num_composition_randomizer_coeffs: usize,
+    composition_segment_len: usize,
+    num_salt_elements: usize,
+}
+
+impl ZkRandomizer {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+    /// Returns a new randomizer for the computation described by the specified `air`.
+    ///
+    /// # Panics
+    /// Panics if zero-knowledge mode is not enabled in the proof options of the `air`.
+    pub fn new<A: Air>(air: &A, seed: [u8; 32]) -> Self {
+        assert!(air.options().is_zk(), "zero-knowledge mode is not enabled");
+        let context = air.context();
+
+        Self {
+            seed,
+            trace_length: air.trace_length(),
+            trace_poly_size: context.trace_poly_size(),
+            num_trace_randomizer_coeffs: context.num_trace_randomizer_coefficients(),
+            num_composition_randomizer_coeffs: air
+                .options()
+                .num_composition_randomizer_coefficients(),
+            composition_segment_len: context.composition_segment_len(),
+            num_salt_elements: context.num_salt_elements(),
+        }
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the number of coefficients of the random polynomial by which each trace polynomial
+    /// is randomized.
+    pub fn num_trace_randomizer_coefficients(&self) -> usize {
+        self.num_trace_randomizer_coeffs
+    }
+
+    /// Returns the number of random elements by which each leaf of trace and constraint
+    /// commitments is salted.
+    pub fn num_salt_elements(&self) -> usize {
+        self.num_salt_elements
+    }
+
+    // TRACE RANDOMIZATION
+    // --------------------------------------------------------------------------------------------
+
+    /// Randomizes the provided trace polynomials of the trace segment at the specified index.
+    ///
+    /// Each polynomial $t(x)$ is replaced by $t(x) + (x^n - 1) \cdot r(x)$, where $n$ is the trace
+    /// length and $r(x)$ is a random polynomial. The returned polynomials are padded to
+    /// [trace_poly_size()](air::AirContext::trace_poly_size) coefficients.
+    pub fn randomize_trace_polys<E: FieldElement>(
+        &self,
+        polys: ColMatrix<E>,
+        segment_idx: usize,
+    ) -> ColMatrix<E> {
+        assert_eq!(polys.num_rows(), self.trace_length);
+
+        let n = self.trace_length;
+        let num_coeffs = self.num_trace_randomizer_coeffs;
+        let label = TRACE_POLYS_LABEL | (segment_idx as u64) << 8;
+        let randomizers = self.draw_elements::<E>(label, polys.num_cols() * num_coeffs);
+
+        let columns = polys
+            .into_columns()
+            .into_iter()
+            .zip(randomizers.chunks(num_coeffs))
+            .map(|(mut column, randomizer)| {
+                column.resize(self.trace_poly_size, E::ZERO);
+                for (j, &r) in randomizer.iter().enumerate() {
+                    column[j] -= r;
+                    column[n + j] += r;
+                }
+                column
+            })
+            .collect();
+
+        ColMatrix::new(columns)
+    }
+
+    /// Returns salts for `num_rows` leaves of the commitment to the trace segment at the
+    /// specified index.
+    ///
+    /// Salts are returned as a single vector in which the salt of each leaf occupies
+    /// [num_salt_elements()](Self::num_salt_elements) consecutive elements.
+    pub fn draw_trace_salts<E: FieldElement>(&self, segment_idx: usize, num_rows: usize) -> Vec<E> {
+        let label = TRACE_SALTS_LABEL | (segment_idx as u64) << 8;
+        self.draw_elements(label, num_rows * self.num_salt_elements)
+    }
+
+    // COMPOSITION POLYNOMIAL RANDOMIZATION
+    // --------------------------------------------------------------------------------------------
+
+    /// Splits the provided constraint composition polynomial into `num_cols` columns, where the
+    /// last column is a random polynomial, and masks the remaining columns.
+    ///
+    /// The polynomial $H(x)$ is split into segments $h_i(x)$ such that
+    /// $H(x) = \sum_{i} x^{i \cdot l} \cdot h_i(x)$, where $l$ is the
+    /// [composition segment length](air::AirContext::composition_segment_len). The segments are
+    /// then masked with random polynomials $r_i(x)$ as $h_i'(x) = h_i(x) + x^l \cdot r_i(x) -
+    /// r_{i-1}(x)$ such that the masks cancel out in the above sum. All returned columns are
+    /// padded to [trace_poly_size()](air::AirContext::trace_poly_size) coefficients.
+    pub fn randomize_composition_poly<E: FieldElement>(
+        &self,
+        coefficients: Vec<E>,
+        num_cols: usize,
+    ) -> Vec<Vec<E>> {
+        let l = self.composition_segment_len;
+        let num_segments = num_cols - 1;
+        debug_assert!(math::polynom::degree_of(&coefficients) < l * num_segments);
+
+        let mut columns: Vec<Vec<E>> = (0..num_segments)
+            .map(|i| {
+                let mut column = vec![E::ZERO; self.trace_poly_size];
+                let start = core::cmp::min(i * l, coefficients.len());
+                let end = core::cmp::min(start + l, coefficients.len());
+                column[..end - start].copy_from_slice(&coefficients[start..end]);
+                column
+            })
+            .collect();
+
+        // mask the segments; the last segment only needs to cancel out the preceding mask
+        let num_coeffs = self.num_composition_randomizer_coeffs;
+        let mut randomness = self.draw_elements::<E>(
+            COMPOSITION_POLYS_LABEL,
+            (num_segments - 1) * num_coeffs + self.trace_poly_size,
+        );
+        let random_column = randomness.split_off((num_segments - 1) * num_coeffs);
+        for (i, mask) in randomness.chunks(num_coeffs).enumerate() {
+            for (j, &r) in mask.iter().enumerate() {
+                columns[i][l + j] += r;
+                columns[i + 1][j] -= r;
+            }
+        }
+
+        columns.push(random_column);
+        columns
+    }
+
+    /// Returns salts for `num_rows` leaves of the commitment to the constraint composition
+    /// polynomial.
+    ///
+    /// Salts are returned as a single vector in which the salt of each leaf occupies
+    /// [num_salt_elements()](Self::num_salt_elements) consecutive elements.
+    pub fn draw_composition_salts<E: FieldElement>(&self, num_rows: usize) -> Vec<E> {
+        self.draw_elements(COMPOSITION_SALTS_LABEL, num_rows * self.num_salt_elements)
+    }
+
+    // HELPER METHODS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns `num_elements` pseudo-random elements derived from the seed of this randomizer and
+    /// the specified `label`.
+    fn draw_elements<E: FieldElement>(&self, label: u64, num_elements: usize) -> Vec<E> {
+        let mut bytes = self.seed.to_vec();
+        bytes.extend_from_slice(&label.to_le_bytes());
+
+        let mut prng = DefaultRandomCoin::<Blake3_256<E::BaseField>>::new(&[]);
+        prng.reseed(Blake3_256::<E::BaseField>::hash(&bytes));
+
+        (0..num_elements)
+            .map(|_| prng.draw().expect("failed to draw a random element"))
+            .collect()
+    }
+}
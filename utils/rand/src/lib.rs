@@ -24,11 +24,11 @@ mod internal {
     ///
     /// # Panics
     /// Panics if:
-    /// * A valid value requires over 32 bytes.
+    /// * A valid value requires over 64 bytes.
     /// * A valid value could not be generated after 1000 tries.
     pub fn rand_value<R: Randomizable>() -> R {
         for _ in 0..1000 {
-            let bytes = rand::rng().random::<[u8; 32]>();
+            let bytes = rand::rng().random::<[u8; 64]>();
             if let Some(value) = R::from_random_bytes(&bytes[..R::VALUE_SIZE]) {
                 return value;
             }
@@ -41,7 +41,7 @@ mod internal {
     ///
     /// # Panics
     /// Panics if:
-    /// * A valid value requires at over 32 bytes.
+    /// * A valid value requires at over 64 bytes.
     /// * A valid value could not be generated after 1000 tries.
     pub fn rand_vector<R: Randomizable>(n: usize) -> Vec<R> {
         if n == 0 {
@@ -51,7 +51,7 @@ mod internal {
         let seed = rand::rng().random::<[u8; 32]>();
         let mut g = StdRng::from_seed(seed);
         for _ in 0..1000 * n {
-            let bytes = g.random::<[u8; 32]>();
+            let bytes = g.random::<[u8; 64]>();
             if let Some(element) = R::from_random_bytes(&bytes[..R::VALUE_SIZE]) {
                 result.push(element);
                 if result.len() == n {
@@ -67,7 +67,7 @@ mod internal {
     ///
     /// # Panics
     /// Panics if:
-    /// * A valid value requires at over 32 bytes.
+    /// * A valid value requires at over 64 bytes.
     /// * A valid value could not be generated after 1000 tries.
     pub fn rand_array<R: Randomizable + Debug, const N: usize>() -> [R; N] {
         let elements = rand_vector(N);
@@ -7,7 +7,7 @@ use alloc::{string::ToString, vec::Vec};
 use core::marker::PhantomData;
 
 use air::{
-    proof::{Proof, Queries, QuotientOodFrame, Table, TraceOodFrame},
+    proof::{OodFrame, Proof, Queries, QuotientOodFrame, Table, TableProof, TraceOodFrame},
     Air,
 };
 use crypto::{ElementHasher, VectorCommitment};
@@ -30,17 +30,19 @@ pub struct VerifierChannel<
     V: VectorCommitment<H>,
 > {
     // trace queries
-    trace_commitments: Vec<H::Digest>,
+    trace_commitments: Vec<V::Commitment>,
     trace_queries: Option<TraceQueries<E, H, V>>,
     // constraint queries
-    constraint_commitment: H::Digest,
+    constraint_commitment: V::Commitment,
     constraint_queries: Option<ConstraintQueries<E, H, V>>,
-    // partition sizes for the rows of main, auxiliary and constraint traces rows
+    // partition sizes for the rows of main, auxiliary and constraint traces rows; there is one
+    // auxiliary partition size for each of the auxiliary trace segments
     partition_size_main: usize,
-    partition_size_aux: usize,
+    partition_size_aux: Vec<usize>,
     partition_size_constraint: usize,
     // FRI proof
-    fri_commitments: Option<Vec<H::Digest>>,
+    fri_commitments: Option<Vec<V::Commitment>>,
+    fri_remainder_commitment: H::Digest,
     fri_layer_proofs: Vec<V::MultiProof>,
     fri_layer_queries: Vec<Vec<E>>,
     fri_remainder: Option<Vec<E>>,
@@ -80,27 +82,26 @@ where
         if E::BaseField::get_modulus_le_bytes() != context.field_modulus_bytes() {
             return Err(VerifierError::InconsistentBaseField);
         }
-        let constraint_frame_width = air.context().num_constraint_composition_columns();
 
         let num_trace_segments = air.trace_info().num_segments();
-        let main_trace_width = air.trace_info().main_trace_width();
-        let aux_trace_width = air.trace_info().aux_segment_width();
         let lde_domain_size = air.lde_domain_size();
         let fri_options = air.options().to_fri_options();
-        let partition_options = air.options().partition_options();
 
         // --- parse commitments ------------------------------------------------------------------
-        let (trace_commitments, constraint_commitment, fri_commitments) = commitments
-            .parse::<H>(num_trace_segments, fri_options.num_fri_layers(lde_domain_size))
-            .map_err(|err| VerifierError::ProofDeserializationError(err.to_string()))?;
+        let (trace_commitments, constraint_commitment, fri_commitments, fri_remainder_commitment) =
+            commitments
+                .parse::<H, V>(num_trace_segments, fri_options.num_fri_layers(lde_domain_size))
+                .map_err(|err| VerifierError::ProofDeserializationError(err.to_string()))?;
 
-        // --- parse trace and constraint queries -------------------------------------------------
-        let trace_queries =
-            TraceQueries::<E, H, V>::new(trace_queries, air, num_unique_queries as usize)?;
-        let constraint_queries = ConstraintQueries::<E, H, V>::new(
-            constraint_queries,
+        // --- parse trace and constraint queries and out-of-domain evaluation frame --------------
+        let mut channel = Self::from_table_parts(
             air,
-            num_unique_queries as usize,
+            num_unique_queries,
+            trace_commitments,
+            constraint_commitment,
+            trace_queries,
+            constraint_queries,
+            ood_frame,
         )?;
 
         // --- parse FRI proofs -------------------------------------------------------------------
@@ -112,16 +113,101 @@ where
             .parse_layers::<E, H, V>(lde_domain_size, fri_options.folding_factor())
             .map_err(|err| VerifierError::ProofDeserializationError(err.to_string()))?;
 
+        channel.fri_commitments = Some(fri_commitments);
+        channel.fri_remainder_commitment = fri_remainder_commitment;
+        channel.fri_layer_proofs = fri_layer_proofs;
+        channel.fri_layer_queries = fri_layer_queries;
+        channel.fri_remainder = Some(fri_remainder);
+        channel.fri_num_partitions = fri_num_partitions;
+        channel.pow_nonce = pow_nonce;
+
+        Ok(channel)
+    }
+
+    /// Creates and returns a new [VerifierChannel] initialized from the specified `table` of a
+    /// multi-table proof.
+    ///
+    /// The returned channel does not contain FRI proof data as the FRI proof is shared by all
+    /// tables of a multi-table proof; the final value of the LogUp running sum of the table is
+    /// not parsed either.
+    pub fn new_table<A: Air<BaseField = E::BaseField>>(
+        air: &A,
+        table: TableProof,
+    ) -> Result<Self, VerifierError> {
+        let TableProof {
+            context,
+            num_unique_queries,
+            commitments,
+            trace_queries,
+            constraint_queries,
+            ood_frame,
+            logup_sum: _,
+        } = table;
+
+        // make sure AIR and proof base fields are the same
+        if E::BaseField::get_modulus_le_bytes() != context.field_modulus_bytes() {
+            return Err(VerifierError::InconsistentBaseField);
+        }
+
+        let (trace_commitments, constraint_commitment) = commitments
+            .parse_table::<H, V>(air.trace_info().num_segments())
+            .map_err(|err| VerifierError::ProofDeserializationError(err.to_string()))?;
+
+        Self::from_table_parts(
+            air,
+            num_unique_queries,
+            trace_commitments,
+            constraint_commitment,
+            trace_queries,
+            constraint_queries,
+            ood_frame,
+        )
+    }
+
+    /// Creates a channel from the parts of a proof which are specific to a single execution
+    /// trace; FRI proof data of the returned channel is empty.
+    fn from_table_parts<A: Air<BaseField = E::BaseField>>(
+        air: &A,
+        num_unique_queries: u8,
+        trace_commitments: Vec<V::Commitment>,
+        constraint_commitment: V::Commitment,
+        trace_queries: Vec<Queries>,
+        constraint_queries: Queries,
+        ood_frame: OodFrame,
+    ) -> Result<Self, VerifierError> {
+        let constraint_frame_width = air.context().num_constraint_composition_columns();
+        let main_trace_width = air.trace_info().main_trace_width();
+        let aux_trace_width = air.trace_info().aux_segment_width();
+        let partition_options = air.options().partition_options();
+
+        // --- parse trace and constraint queries -------------------------------------------------
+        let trace_queries =
+            TraceQueries::<E, H, V>::new(trace_queries, air, num_unique_queries as usize)?;
+        let constraint_queries = ConstraintQueries::<E, H, V>::new(
+            constraint_queries,
+            air,
+            num_unique_queries as usize,
+        )?;
+
         // --- parse out-of-domain evaluation frame -----------------------------------------------
         let (ood_trace_frame, ood_constraint_evaluations) = ood_frame
-            .parse(main_trace_width, aux_trace_width, constraint_frame_width)
+            .parse(
+                main_trace_width,
+                aux_trace_width,
+                constraint_frame_width,
+                air.context().frame_offsets(),
+            )
             .map_err(|err| VerifierError::ProofDeserializationError(err.to_string()))?;
 
         // --- compute the partition size for each trace ------------------------------------------
         let partition_size_main = partition_options
             .partition_size::<E::BaseField>(air.context().trace_info().main_trace_width());
-        let partition_size_aux =
-            partition_options.partition_size::<E>(air.context().trace_info().aux_segment_width());
+        let partition_size_aux = (0..air.trace_info().num_aux_segments())
+            .map(|idx| {
+                partition_options
+                    .partition_size::<E>(air.trace_info().get_aux_segment_width_at(idx))
+            })
+            .collect();
         let partition_size_constraint = partition_options
             .partition_size::<E>(air.context().num_constraint_composition_columns());
 
@@ -137,16 +223,17 @@ where
             partition_size_aux,
             partition_size_constraint,
             // FRI proof
-            fri_commitments: Some(fri_commitments),
-            fri_layer_proofs,
-            fri_layer_queries,
-            fri_remainder: Some(fri_remainder),
-            fri_num_partitions,
+            fri_commitments: None,
+            fri_remainder_commitment: H::Digest::default(),
+            fri_layer_proofs: Vec::new(),
+            fri_layer_queries: Vec::new(),
+            fri_remainder: None,
+            fri_num_partitions: 1,
             // out-of-domain evaluation
             ood_trace_frame: Some(ood_trace_frame),
             ood_constraint_evaluations: Some(ood_constraint_evaluations),
             // query seed
-            pow_nonce,
+            pow_nonce: 0,
         })
     }
 
@@ -157,17 +244,18 @@ where
     ///
     /// For computations requiring multiple trace segment, the returned slice will contain a
     /// commitment for each trace segment.
-    pub fn read_trace_commitments(&self) -> &[H::Digest] {
+    pub fn read_trace_commitments(&self) -> &[V::Commitment] {
         &self.trace_commitments
     }
 
     /// Returns constraint evaluation commitment sent by the prover.
-    pub fn read_constraint_commitment(&self) -> H::Digest {
-        self.constraint_commitment
+    pub fn read_constraint_commitment(&self) -> &V::Commitment {
+        &self.constraint_commitment
     }
 
     /// Returns trace polynomial evaluations at out-of-domain points z and z * g, where g is the
-    /// generator of the trace domain.
+    /// generator of the trace domain. If the AIR declares additional frame offsets, evaluations
+    /// at z * g^k for each additional offset k are also included.
     ///
     /// For computations requiring multiple trace segments, evaluations of auxiliary trace
     /// polynomials are also included.
@@ -200,36 +288,44 @@ where
         let queries = self.trace_queries.take().expect("already read");
 
         // make sure the states included in the proof correspond to the trace commitment
-        let items: Vec<H::Digest> = queries
-            .main_states
-            .rows()
-            .map(|row| hash_row::<H, E::BaseField>(row, self.partition_size_main))
-            .collect();
+        let items = hash_rows::<H, E::BaseField>(
+            &queries.main_states,
+            queries.main_salts.as_ref(),
+            self.partition_size_main,
+        );
 
         <V as VectorCommitment<H>>::verify_many(
-            self.trace_commitments[0],
+            self.trace_commitments[0].clone(),
             positions,
             &items,
             &queries.query_proofs[0],
         )
         .map_err(|_| VerifierError::TraceQueryDoesNotMatchCommitment)?;
 
-        if let Some(ref aux_states) = queries.aux_states {
-            let items: Vec<H::Digest> = aux_states
-                .rows()
-                .map(|row| hash_row::<H, E>(row, self.partition_size_aux))
-                .collect();
+        // make sure the states of each auxiliary segment correspond to the segment commitment
+        for (idx, (aux_states, aux_salts)) in
+            queries.aux_states.iter().zip(queries.aux_salts.iter()).enumerate()
+        {
+            let items =
+                hash_rows::<H, E>(aux_states, aux_salts.as_ref(), self.partition_size_aux[idx]);
 
             <V as VectorCommitment<H>>::verify_many(
-                self.trace_commitments[1],
+                self.trace_commitments[1 + idx].clone(),
                 positions,
                 &items,
-                &queries.query_proofs[1],
+                &queries.query_proofs[1 + idx],
             )
             .map_err(|_| VerifierError::TraceQueryDoesNotMatchCommitment)?;
         }
 
-        Ok((queries.main_states, queries.aux_states))
+        // merge tables for each auxiliary segment into a single table
+        let aux_states = if queries.aux_states.is_empty() {
+            None
+        } else {
+            Some(Table::merge(queries.aux_states))
+        };
+
+        Ok((queries.main_states, aux_states))
     }
 
     /// Returns constraint evaluations at the specified positions of the LDE domain. This also
@@ -241,14 +337,14 @@ where
     ) -> Result<Table<E>, VerifierError> {
         let queries = self.constraint_queries.take().expect("already read");
 
-        let items: Vec<H::Digest> = queries
-            .evaluations
-            .rows()
-            .map(|row| hash_row::<H, E>(row, self.partition_size_constraint))
-            .collect();
+        let items = hash_rows::<H, E>(
+            &queries.evaluations,
+            queries.salts.as_ref(),
+            self.partition_size_constraint,
+        );
 
         <V as VectorCommitment<H>>::verify_many(
-            self.constraint_commitment,
+            self.constraint_commitment.clone(),
             positions,
             &items,
             &queries.query_proofs,
@@ -275,10 +371,14 @@ where
         self.fri_num_partitions
     }
 
-    fn read_fri_layer_commitments(&mut self) -> Vec<H::Digest> {
+    fn read_fri_layer_commitments(&mut self) -> Vec<V::Commitment> {
         self.fri_commitments.take().expect("already read")
     }
 
+    fn read_fri_remainder_commitment(&mut self) -> H::Digest {
+        self.fri_remainder_commitment
+    }
+
     fn take_next_fri_layer_proof(&mut self) -> V::MultiProof {
         self.fri_layer_proofs.remove(0)
     }
@@ -299,7 +399,8 @@ where
 /// * Queried states for all trace segments.
 /// * Batch opening proof for all queries.
 ///
-/// Trace states for all auxiliary segments are stored in a single table.
+/// Trace states for each of the auxiliary segments are stored in a separate table. In
+/// zero-knowledge mode, salts of the queried leaves are stored separately from the trace states.
 struct TraceQueries<
     E: FieldElement,
     H: ElementHasher<BaseField = E::BaseField>,
@@ -307,7 +408,9 @@ struct TraceQueries<
 > {
     query_proofs: Vec<V::MultiProof>,
     main_states: Table<E::BaseField>,
-    aux_states: Option<Table<E>>,
+    main_salts: Option<Table<E::BaseField>>,
+    aux_states: Vec<Table<E>>,
+    aux_salts: Vec<Option<Table<E>>>,
     _h: PhantomData<H>,
 }
 
@@ -332,47 +435,59 @@ where
             queries.len()
         );
 
+        // in zero-knowledge mode, each queried row is followed by the salt of its leaf
+        let num_salt_elements = air.context().num_salt_elements();
+
         // parse main trace segment queries
         let main_segment_width = air.trace_info().main_trace_width();
         let main_segment_queries = queries.remove(0);
         let (main_segment_query_proofs, main_segment_states) = main_segment_queries
-            .parse::<E::BaseField, H, V>(air.lde_domain_size(), num_queries, main_segment_width)
+            .parse::<E::BaseField, H, V>(
+                air.lde_domain_size(),
+                num_queries,
+                main_segment_width + num_salt_elements,
+            )
             .map_err(|err| {
                 VerifierError::ProofDeserializationError(format!(
                     "main trace segment query deserialization failed: {err}"
                 ))
             })?;
+        let (main_segment_states, main_segment_salts) =
+            split_salts(main_segment_states, main_segment_width, num_salt_elements);
 
         // all query proofs will be aggregated into a single vector
         let mut query_proofs = vec![main_segment_query_proofs];
 
-        // parse auxiliary trace segment queries (if any), and merge resulting tables into a
-        // single table
-        let aux_trace_states = if air.trace_info().is_multi_segment() {
-            let mut aux_trace_states = Vec::new();
-            let segment_queries = queries.remove(0);
-            let segment_width = air.trace_info().get_aux_segment_width();
+        // parse auxiliary trace segment queries (if any)
+        let mut aux_trace_states = Vec::with_capacity(air.trace_info().num_aux_segments());
+        let mut aux_trace_salts = Vec::with_capacity(air.trace_info().num_aux_segments());
+        for (idx, segment_queries) in queries.into_iter().enumerate() {
+            let segment_width = air.trace_info().get_aux_segment_width_at(idx);
             let (segment_query_proof, segment_trace_states) = segment_queries
-                .parse::<E, H, V>(air.lde_domain_size(), num_queries, segment_width)
+                .parse::<E, H, V>(
+                    air.lde_domain_size(),
+                    num_queries,
+                    segment_width + num_salt_elements,
+                )
                 .map_err(|err| {
                     VerifierError::ProofDeserializationError(format!(
                         "auxiliary trace segment query deserialization failed: {err}"
                     ))
                 })?;
+            let (segment_trace_states, segment_salts) =
+                split_salts(segment_trace_states, segment_width, num_salt_elements);
 
             query_proofs.push(segment_query_proof);
             aux_trace_states.push(segment_trace_states);
-
-            // merge tables for each auxiliary segment into a single table
-            Some(Table::merge(aux_trace_states))
-        } else {
-            None
-        };
+            aux_trace_salts.push(segment_salts);
+        }
 
         Ok(Self {
             query_proofs,
             main_states: main_segment_states,
+            main_salts: main_segment_salts,
             aux_states: aux_trace_states,
+            aux_salts: aux_trace_salts,
             _h: PhantomData,
         })
     }
@@ -383,6 +498,7 @@ where
 
 /// Container of constraint evaluation query data, including:
 /// * Queried constraint evaluation values.
+/// * Salts of the queried leaves (in zero-knowledge mode only).
 /// * Batch opening proof for all queries.
 struct ConstraintQueries<
     E: FieldElement,
@@ -391,6 +507,7 @@ struct ConstraintQueries<
 > {
     query_proofs: V::MultiProof,
     evaluations: Table<E>,
+    salts: Option<Table<E>>,
     _h: PhantomData<H>,
 }
 
@@ -408,18 +525,26 @@ where
         num_queries: usize,
     ) -> Result<Self, VerifierError> {
         let constraint_frame_width = air.context().num_constraint_composition_columns();
+        let num_salt_elements = air.context().num_salt_elements();
 
         let (query_proofs, evaluations) = queries
-            .parse::<E, H, V>(air.lde_domain_size(), num_queries, constraint_frame_width)
+            .parse::<E, H, V>(
+                air.lde_domain_size(),
+                num_queries,
+                constraint_frame_width + num_salt_elements,
+            )
             .map_err(|err| {
                 VerifierError::ProofDeserializationError(format!(
                     "constraint evaluation query deserialization failed: {err}"
                 ))
             })?;
+        let (evaluations, salts) =
+            split_salts(evaluations, constraint_frame_width, num_salt_elements);
 
         Ok(Self {
             query_proofs,
             evaluations,
+            salts,
             _h: PhantomData,
         })
     }
@@ -428,6 +553,46 @@ where
 // HELPER
 // ================================================================================================
 
+/// Splits salts of the queried leaves (if any) from the queried rows; the salt of each leaf is
+/// placed at the end of its row.
+fn split_salts<E: FieldElement>(
+    rows: Table<E>,
+    row_width: usize,
+    num_salt_elements: usize,
+) -> (Table<E>, Option<Table<E>>) {
+    if num_salt_elements == 0 {
+        (rows, None)
+    } else {
+        let (rows, salts) = rows.split(row_width);
+        (rows, Some(salts))
+    }
+}
+
+/// Hashes each row of the provided table into a leaf of a vector commitment.
+///
+/// If `salts` are provided, each leaf is computed as hash(row_hash || hash(salt)), where salts
+/// are read from the corresponding row of the `salts` table.
+fn hash_rows<H, E>(
+    rows: &Table<E>,
+    salts: Option<&Table<E>>,
+    partition_size: usize,
+) -> Vec<H::Digest>
+where
+    E: FieldElement,
+    H: ElementHasher<BaseField = E::BaseField>,
+{
+    match salts {
+        Some(salts) => rows
+            .rows()
+            .zip(salts.rows())
+            .map(|(row, salt)| {
+                H::merge(&[hash_row::<H, E>(row, partition_size), H::hash_elements(salt)])
+            })
+            .collect(),
+        None => rows.rows().map(|row| hash_row::<H, E>(row, partition_size)).collect(),
+    }
+}
+
 /// Hashes a row of a trace in batches where each batch is of size at most `partition_size`.
 fn hash_row<H, E>(row: &[E], partition_size: usize) -> H::Digest
 where
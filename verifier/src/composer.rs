@@ -17,7 +17,7 @@ use math::{batch_inversion, FieldElement};
 pub struct DeepComposer<E: FieldElement> {
     cc: DeepCompositionCoefficients<E>,
     x_coordinates: Vec<E>,
-    z: [E; 2],
+    z: Vec<E>,
 }
 
 impl<E: FieldElement> DeepComposer<E> {
@@ -35,13 +35,23 @@ impl<E: FieldElement> DeepComposer<E> {
             .iter()
             .map(|&p| E::from(g_lde.exp_vartime((p as u64).into()) * domain_offset))
             .collect();
+
+        // compute out-of-domain points z * g^k for all frame offsets k; the first two of these
+        // points are always z and z * g
         let g_trace = air.trace_domain_generator();
+        let trace_length = air.trace_length() as isize;
+        let z = air
+            .context()
+            .frame_offsets()
+            .iter()
+            .map(|&offset| {
+                // negative offsets wrap around the trace domain: g^{-k} = g^{n - k}
+                let power = offset.rem_euclid(trace_length) as u64;
+                z * E::from(g_trace.exp_vartime(power.into()))
+            })
+            .collect();
 
-        DeepComposer {
-            cc,
-            x_coordinates,
-            z: [z, z * E::from(g_trace)],
-        }
+        DeepComposer { cc, x_coordinates, z }
     }
 
     /// For each queried trace state, combines column values into a single value by computing
@@ -53,8 +63,11 @@ impl<E: FieldElement> DeepComposer<E> {
     ///   T''_i = (T_i(x) - T_i(z * g)) / (x - z * g), where z is the out-of-domain point and
     ///   g is the the LDE domain generator and cc_i is the coefficient for the random
     ///   linear combination drawn from the public coin.
+    /// - If the evaluation frame contains additional rows, also compute
+    ///   (T_i(x) - T_i(z * g^k)) / (x - z * g^k) for all trace polynomials T_i(x) and all
+    ///   additional frame offsets k.
     ///
-    /// Note that values of T_i(z) and T_i(z * g) are received from the prover and passed into
+    /// Note that values of T_i(z), T_i(z * g) etc. are received from the prover and passed into
     /// this function via the `ood_main_frame`, `ood_aux_frame` and `ood_quotient_frame`
     /// parameters.
     pub fn compose_columns(
@@ -66,96 +79,71 @@ impl<E: FieldElement> DeepComposer<E> {
         ood_aux_frame: Option<EvaluationFrame<E>>,
         ood_quotient_frame: QuotientOodFrame<E>,
     ) -> Vec<E> {
-        let ood_main_trace_states = [ood_main_frame.current(), ood_main_frame.next()];
-
-        // compose columns of of the main trace segment; we do this separately for numerators of
-        // each query; we also track common denominator for each query separately; this way we can
-        // use a batch inversion in the end.
+        // for each query we compute a separate numerator for each of the out-of-domain points;
+        // numerators for all queries are stored in a single vector, with numerators for query j
+        // located at [j * num_points, (j + 1) * num_points)
+        let num_points = self.z.len();
         let n = queried_main_trace_states.num_rows();
-        let mut result_num = Vec::<E>::with_capacity(n);
-        let mut result_den = Vec::<E>::with_capacity(n);
-
-        for ((_, row), &x) in (0..n).zip(queried_main_trace_states.rows()).zip(&self.x_coordinates)
-        {
-            let mut t1_num = E::ZERO;
-            let mut t2_num = E::ZERO;
+        let mut result_num = vec![E::ZERO; n * num_points];
 
+        // compose columns of of the main trace segment
+        for (row, num) in queried_main_trace_states.rows().zip(result_num.chunks_mut(num_points)) {
             for (i, &value) in row.iter().enumerate() {
                 let value = E::from(value);
-                // compute the numerator of T'_i(x) as (T_i(x) - T_i(z)), multiply it by a
-                // composition coefficient, and add the result to the numerator aggregator
-                t1_num += (value - ood_main_trace_states[0][i]) * self.cc.trace[i];
-
-                // compute the numerator of T''_i(x) as (T_i(x) - T_i(z * g)), multiply it by a
-                // composition coefficient, and add the result to the numerator aggregator
-                t2_num += (value - ood_main_trace_states[1][i]) * self.cc.trace[i];
+                // compute the numerator of (T_i(x) - T_i(z * g^k)) for each frame offset k,
+                // multiply it by a composition coefficient, and add the result to the numerator
+                // aggregator for the corresponding out-of-domain point
+                for (k, num) in num.iter_mut().enumerate() {
+                    *num += (value - ood_main_frame.row(k)[i]) * self.cc.trace[i];
+                }
             }
-            // compute the common denominator as (x - z) * (x - z * g)
-            let t1_den = x - self.z[0];
-            let t2_den = x - self.z[1];
-            result_den.push(t1_den * t2_den);
-
-            // add the numerators of T'_i(x) and T''_i(x) together; we can do this because later on
-            // we'll use the common denominator computed above.
-            result_num.push(t1_num * t2_den + t2_num * t1_den);
         }
 
-        // if the trace has auxiliary segments, compose columns from these segments as well; we
-        // also do this separately for numerators and denominators.
+        // if the trace has auxiliary segments, compose columns from these segments as well
         if let Some(queried_aux_trace_states) = queried_aux_trace_states {
             let ood_aux_frame = ood_aux_frame.expect("missing auxiliary OOD frame");
-            let ood_aux_trace_states = [ood_aux_frame.current(), ood_aux_frame.next()];
 
             // we define this offset here because composition of the main trace columns has
             // consumed some number of composition coefficients already.
             let cc_offset = queried_main_trace_states.num_columns();
 
-            for ((j, row), &x) in
-                (0..n).zip(queried_aux_trace_states.rows()).zip(&self.x_coordinates)
+            for (row, num) in queried_aux_trace_states.rows().zip(result_num.chunks_mut(num_points))
             {
-                let mut t1_num = E::ZERO;
-                let mut t2_num = E::ZERO;
-
                 for (i, &value) in row.iter().enumerate() {
-                    // compute the numerator of T'_i(x) as (T_i(x) - T_i(z)), multiply it by a
-                    // composition coefficient, and add the result to the numerator aggregator
-                    t1_num += (value - ood_aux_trace_states[0][i]) * self.cc.trace[cc_offset + i];
-
-                    // compute the numerator of T''_i(x) as (T_i(x) - T_i(z * g)), multiply it by a
-                    // composition coefficient, and add the result to the numerator aggregator
-                    t2_num += (value - ood_aux_trace_states[1][i]) * self.cc.trace[cc_offset + i];
+                    for (k, num) in num.iter_mut().enumerate() {
+                        *num += (value - ood_aux_frame.row(k)[i]) * self.cc.trace[cc_offset + i];
+                    }
                 }
-
-                // compute the common denominators (x - z) and (x - z * g), and use the to aggregate
-                // numerators into the common numerator computed for the main trace of this query
-                let t1_den = x - self.z[0];
-                let t2_den = x - self.z[1];
-                result_num[j] += t1_num * t2_den + t2_num * t1_den;
             }
         }
 
-        for ((j, row), &x) in (0..n).zip(queried_evaluations.rows()).zip(&self.x_coordinates) {
-            let mut t1_num = E::ZERO;
-            let mut t2_num = E::ZERO;
-
+        // compose columns of the constraint composition polynomial; these are opened only at z
+        // and z * g
+        for (row, num) in queried_evaluations.rows().zip(result_num.chunks_mut(num_points)) {
             for (i, &value) in row.iter().enumerate() {
                 // compute the numerator of T'_i(x) as (T_i(x) - T_i(z)), multiply it by a
                 // composition coefficient, and add the result to the numerator aggregator
-                t1_num += (value - ood_quotient_frame.current_row()[i]) * self.cc.constraints[i];
+                num[0] += (value - ood_quotient_frame.current_row()[i]) * self.cc.constraints[i];
 
                 // compute the numerator of T''_i(x) as (T_i(x) - T_i(z * g)), multiply it by a
                 // composition coefficient, and add the result to the numerator aggregator
-                t2_num += (value - ood_quotient_frame.next_row()[i]) * self.cc.constraints[i];
+                num[1] += (value - ood_quotient_frame.next_row()[i]) * self.cc.constraints[i];
             }
-
-            // compute the common denominators (x - z) and (x - z * g), and use the to aggregate
-            // numerators into the common numerator computed for the main trace of this query
-            let t1_den = x - self.z[0];
-            let t2_den = x - self.z[1];
-            result_num[j] += t1_num * t2_den + t2_num * t1_den;
         }
 
-        result_den = batch_inversion(&result_den);
-        result_num.iter().zip(result_den).map(|(n, d)| *n * d).collect()
+        // compute the denominators (x - z * g^k) for all queries and out-of-domain points, and
+        // invert them all using a single batch inversion
+        let result_den = self
+            .x_coordinates
+            .iter()
+            .flat_map(|&x| self.z.iter().map(move |&z| x - z))
+            .collect::<Vec<_>>();
+        let result_den = batch_inversion(&result_den);
+
+        result_num
+            .chunks(num_points)
+            .zip(result_den.chunks(num_points))
+            .map(|(num, den)| num.iter().zip(den).fold(E::ZERO, |acc, (&n, &d)| acc + n * d))
+            .collect()
     }
 }
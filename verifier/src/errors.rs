@@ -51,6 +51,23 @@ pub enum VerifierError {
     /// This error occurs when the parameters, that were used to generate the proof, do not match
     /// any of the set of parameters expected by the verifier.
     UnacceptableProofOptions,
+    /// This error occurs when a table of a multi-table proof is longer than the preceding table,
+    /// when its LDE domain cannot be reached by folding the LDE domain of the first table during
+    /// FRI, or when it was generated with different parameters than the first table.
+    InvalidTable(usize),
+    /// This error occurs when a table of a multi-table proof has a different number of auxiliary
+    /// trace segments than the first table, or requires a different number of random elements
+    /// for any of these segments.
+    InconsistentAuxSegments(usize),
+    /// This error occurs when the number, the order, or the shapes of the tables of a multi-table
+    /// proof do not match the tables expected by the AIR of the computation.
+    UnexpectedTables(String),
+    /// This error occurs when the final values of LogUp running sums of all tables of a
+    /// multi-table proof do not add up to zero.
+    UnbalancedLogUpSums,
+    /// This error occurs when a multi-table proof was generated in zero-knowledge mode, which is
+    /// not supported for multi-table proofs.
+    UnsupportedZkMode,
 }
 
 impl fmt::Display for VerifierError {
@@ -91,6 +108,21 @@ impl fmt::Display for VerifierError {
                 write!(f, "insufficient proof security level: expected at least {minimal_security} bits of proven security, but was {proof_security} bits")
             }
             Self::UnacceptableProofOptions => {write!(f, "invalid proof options: security parameters do not match the acceptable parameter set")}
+            Self::InvalidTable(table_idx) => {
+                write!(f, "table {table_idx} is not compatible with the preceding tables of the proof")
+            }
+            Self::InconsistentAuxSegments(table_idx) => {
+                write!(f, "auxiliary trace segments of table {table_idx} are inconsistent with auxiliary trace segments of the first table")
+            }
+            Self::UnexpectedTables(err) => {
+                write!(f, "tables of the proof do not match the expected tables: {err}")
+            }
+            Self::UnbalancedLogUpSums => {
+                write!(f, "LogUp running sums of all tables do not add up to zero")
+            }
+            Self::UnsupportedZkMode => {
+                write!(f, "zero-knowledge mode is not supported for multi-table proofs")
+            }
         }
     }
 }
@@ -46,7 +46,7 @@ pub fn evaluate_constraints<A: Air, E: FieldElement<BaseField = A::BaseField>>(
         let aux_rand_elements =
             aux_rand_elements.expect("expected aux rand elements to be present");
 
-        air.evaluate_aux_transition(
+        air.evaluate_all_aux_transitions(
             main_trace_frame,
             aux_trace_frame,
             &periodic_values,
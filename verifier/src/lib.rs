@@ -34,9 +34,10 @@ extern crate alloc;
 use alloc::vec::Vec;
 use core::cmp;
 
-use air::proof::merge_ood_evaluations;
+use air::{proof::merge_ood_evaluations, AuxRandElements};
 pub use air::{
-    proof::Proof, Air, AirContext, Assertion, BoundaryConstraint, BoundaryConstraintGroup,
+    proof::{MultiProof, Proof},
+    Air, AirContext, Assertion, BoundaryConstraint, BoundaryConstraintGroup,
     ConstraintCompositionCoefficients, ConstraintDivisor, DeepCompositionCoefficients,
     EvaluationFrame, FieldExtension, ProofOptions, TraceInfo, TransitionConstraintDegree,
 };
@@ -45,7 +46,7 @@ use crypto::{ElementHasher, Hasher, RandomCoin, VectorCommitment};
 use fri::FriVerifier;
 pub use math;
 use math::{
-    fields::{CubeExtension, QuadExtension},
+    fields::{CubeExtension, QuadExtension, QuartExtension},
     FieldElement, ToElements,
 };
 pub use utils::{
@@ -64,6 +65,9 @@ use composer::DeepComposer;
 mod errors;
 pub use errors::VerifierError;
 
+mod multi;
+pub use multi::verify_multi;
+
 // VERIFIER
 // ================================================================================================
 
@@ -139,6 +143,18 @@ where
                 public_coin,
             )
         },
+        FieldExtension::Quartic => {
+            if !<QuartExtension<AIR::BaseField>>::is_supported() {
+                return Err(VerifierError::UnsupportedFieldExtension(4));
+            }
+            let public_coin = RandCoin::new(&public_coin_seed);
+            let channel = VerifierChannel::new(&air, proof)?;
+            perform_verification::<AIR, QuartExtension<AIR::BaseField>, HashFn, RandCoin, VC>(
+                air,
+                channel,
+                public_coin,
+            )
+        },
     }
 }
 
@@ -168,21 +184,27 @@ where
     // commitment is used to draw a set of random coefficients which the prover uses to compute
     // constraint composition polynomial.
     const MAIN_TRACE_IDX: usize = 0;
-    const AUX_TRACE_IDX: usize = 1;
     let trace_commitments = channel.read_trace_commitments();
 
     // reseed the coin with the commitment to the main trace segment
-    public_coin.reseed(trace_commitments[MAIN_TRACE_IDX]);
+    public_coin.reseed(V::commitment_digest(&trace_commitments[MAIN_TRACE_IDX]));
 
-    // process auxiliary trace segments (if any), to build a set of random elements for each segment
+    // process auxiliary trace segments (if any), to build a set of random elements for each
+    // segment; random elements of all segments are concatenated in the order of segments
     let aux_trace_rand_elements = if air.trace_info().is_multi_segment() {
-        let aux_rand_elements = air
-            .get_aux_rand_elements(&mut public_coin)
-            .expect("failed to generate the random elements needed to build the auxiliary trace");
-
-        public_coin.reseed(trace_commitments[AUX_TRACE_IDX]);
+        let mut rand_elements = Vec::new();
+        for segment_idx in 0..air.trace_info().num_aux_segments() {
+            rand_elements.extend(
+                air.get_aux_segment_rand_elements::<E, _>(segment_idx, &mut public_coin).expect(
+                    "failed to generate the random elements needed to build the auxiliary trace",
+                ),
+            );
+
+            public_coin
+                .reseed(V::commitment_digest(&trace_commitments[MAIN_TRACE_IDX + 1 + segment_idx]));
+        }
 
-        Some(aux_rand_elements)
+        Some(AuxRandElements::new(rand_elements))
     } else {
         None
     };
@@ -199,7 +221,7 @@ where
     // to the prover, and the prover evaluates trace and constraint composition polynomials at z,
     // and sends the results back to the verifier.
     let constraint_commitment = channel.read_constraint_commitment();
-    public_coin.reseed(constraint_commitment);
+    public_coin.reseed(V::commitment_digest(constraint_commitment));
     let z = public_coin.draw::<E>().map_err(|_| VerifierError::RandomCoinError)?;
 
     // 3 ----- OOD consistency check --------------------------------------------------------------
@@ -223,17 +245,22 @@ where
     // read evaluations of composition polynomial columns sent by the prover, and reduce
     // the evaluations at z into a single value by computing
     // \sum_{i=0}^{m-1}(z^(i * l) * value_i), where value_i is the
-    // evaluation of the ith column polynomial H_i(X) at z, l is the trace length and m is
-    // the number of composition column polynomials. This computes H(z) (i.e.
-    // the evaluation of the composition polynomial at z) using the fact that
-    // H(X) = \sum_{i=0}^{m-1} X^{i * l} H_i(X).
+    // evaluation of the ith column polynomial H_i(X) at z, l is the composition segment length
+    // (the trace length, unless zero-knowledge mode is enabled) and m is the number of
+    // composition column polynomials. This computes H(z) (i.e. the evaluation of the composition
+    // polynomial at z) using the fact that H(X) = \sum_{i=0}^{m-1} X^{i * l} H_i(X). In
+    // zero-knowledge mode, the last column is a random polynomial which is not a part of H(X).
     let ood_constraint_evaluations = channel.read_ood_constraint_frame();
+    let segment_len = air.context().composition_segment_len();
+    let num_segments =
+        air.context().num_constraint_composition_columns() - air.options().is_zk() as usize;
     let ood_constraint_evaluation_2 = ood_constraint_evaluations
         .current_row()
         .iter()
+        .take(num_segments)
         .enumerate()
         .fold(E::ZERO, |result, (i, &value)| {
-            result + z.exp_vartime(((i * (air.trace_length())) as u32).into()) * value
+            result + z.exp_vartime(((i * segment_len) as u32).into()) * value
         });
 
     // finally, make sure the values are the same
@@ -360,4 +387,34 @@ impl AcceptableOptions {
         }
         Ok(())
     }
+
+    /// Checks that a multi-table proof was generated using an acceptable set of parameters.
+    pub fn validate_multi<H: Hasher>(&self, proof: &MultiProof) -> Result<(), VerifierError> {
+        match self {
+            AcceptableOptions::MinConjecturedSecurity(minimal_security) => {
+                let conjectured_security = proof.conjectured_security::<H>();
+                if !conjectured_security.is_at_least(*minimal_security) {
+                    return Err(VerifierError::InsufficientConjecturedSecurity(
+                        *minimal_security,
+                        conjectured_security.bits(),
+                    ));
+                }
+            },
+            AcceptableOptions::MinProvenSecurity(minimal_security) => {
+                let proven_security = proof.proven_security::<H>();
+                if !proven_security.is_at_least(*minimal_security) {
+                    return Err(VerifierError::InsufficientProvenSecurity(
+                        *minimal_security,
+                        cmp::max(proven_security.ldr_bits(), proven_security.udr_bits()),
+                    ));
+                }
+            },
+            AcceptableOptions::OptionSet(options) => {
+                if !options.iter().any(|opt| opt == proof.options()) {
+                    return Err(VerifierError::UnacceptableProofOptions);
+                }
+            },
+        }
+        Ok(())
+    }
 }
@@ -0,0 +1,392 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use alloc::{string::ToString, vec::Vec};
+use core::mem;
+
+use air::{
+    proof::{merge_ood_evaluations, MultiProof},
+    Air, AuxRandElements, TraceInfo,
+};
+use crypto::{ElementHasher, RandomCoin, VectorCommitment};
+use fri::{folding::fold_positions, DefaultVerifierChannel, FriVerifier};
+use math::{
+    fields::{CubeExtension, QuadExtension, QuartExtension},
+    FieldElement, ToElements,
+};
+
+use crate::{
+    evaluate_constraints, AcceptableOptions, DeepComposer, FieldExtension, VerifierChannel,
+    VerifierError,
+};
+
+// MULTI-TABLE VERIFIER
+// ================================================================================================
+
+/// Verifies that the computation described by several tables was executed correctly against the
+/// specified inputs.
+///
+/// This is the multi-table counterpart of [verify()](crate::verify()): an instance of `AIR` is
+/// created for every table of the `proof` from the table's trace info, and all instances share
+/// the same `pub_inputs`. If the verification is successful, `Ok(())` is returned.
+///
+/// # Errors
+/// In addition to the errors returned by [verify()](crate::verify()), returns an error if:
+/// - The tables of the proof are rejected by [Air::validate_tables()] (e.g., the proof does not
+///   contain all of the tables expected for the computation).
+/// - The tables of the proof are not sorted by length in non-increasing order, were generated
+///   with different parameters, or cannot be combined into a single FRI proof.
+/// - The tables draw different random elements for their auxiliary trace segments.
+/// - The final values of LogUp running sums of all tables do not add up to zero.
+/// - The proof was generated in zero-knowledge mode.
+pub fn verify_multi<AIR, HashFn, RandCoin, VC>(
+    proof: MultiProof,
+    pub_inputs: AIR::PublicInputs,
+    acceptable_options: &AcceptableOptions,
+) -> Result<(), VerifierError>
+where
+    AIR: Air,
+    AIR::PublicInputs: Clone,
+    HashFn: ElementHasher<BaseField = AIR::BaseField>,
+    RandCoin: RandomCoin<BaseField = AIR::BaseField, Hasher = HashFn>,
+    VC: VectorCommitment<HashFn>,
+{
+    if proof.tables.is_empty() {
+        return Err(VerifierError::ProofDeserializationError(
+            "multi-table proof must contain at least one table".to_string(),
+        ));
+    }
+
+    // check that `proof` was generated with an acceptable set of parameters from the point of view
+    // of the verifier
+    acceptable_options.validate_multi::<HashFn>(&proof)?;
+
+    // build a seed for the public coin; the initial seed is a hash of the contexts of all tables
+    // and the public inputs
+    let mut public_coin_seed: Vec<AIR::BaseField> =
+        proof.tables.iter().flat_map(|table| table.context.to_elements()).collect();
+    public_coin_seed.append(&mut pub_inputs.to_elements());
+
+    // make sure the proof contains exactly the tables expected by the AIR; this must be done
+    // before AIR instances are created, as AIR constructors may rely on the shapes of the tables
+    let trace_infos: Vec<TraceInfo> =
+        proof.tables.iter().map(|table| table.context.trace_info().clone()).collect();
+    AIR::validate_tables(&trace_infos, &pub_inputs).map_err(VerifierError::UnexpectedTables)?;
+
+    // zero-knowledge mode is not supported for multi-table proofs
+    let options = proof.options().clone();
+    if options.is_zk() {
+        return Err(VerifierError::UnsupportedZkMode);
+    }
+
+    // create AIR instances for all tables specified in the proof
+    let mut airs = Vec::with_capacity(proof.tables.len());
+    for (table_idx, (table, trace_info)) in proof.tables.iter().zip(trace_infos).enumerate() {
+        if table.context.options() != &options {
+            return Err(VerifierError::InvalidTable(table_idx));
+        }
+        airs.push(AIR::new(trace_info, pub_inputs.clone(), options.clone()));
+    }
+
+    // figure out which version of the generic proof verification procedure to run. this is a sort
+    // of static dispatch for selecting two generic parameter: extension field and hash function.
+    let public_coin = RandCoin::new(&public_coin_seed);
+    match options.field_extension() {
+        FieldExtension::None => {
+            perform_multi_verification::<AIR, AIR::BaseField, HashFn, RandCoin, VC>(
+                airs,
+                proof,
+                public_coin,
+            )
+        },
+        FieldExtension::Quadratic => {
+            if !<QuadExtension<AIR::BaseField>>::is_supported() {
+                return Err(VerifierError::UnsupportedFieldExtension(2));
+            }
+            perform_multi_verification::<AIR, QuadExtension<AIR::BaseField>, HashFn, RandCoin, VC>(
+                airs,
+                proof,
+                public_coin,
+            )
+        },
+        FieldExtension::Cubic => {
+            if !<CubeExtension<AIR::BaseField>>::is_supported() {
+                return Err(VerifierError::UnsupportedFieldExtension(3));
+            }
+            perform_multi_verification::<AIR, CubeExtension<AIR::BaseField>, HashFn, RandCoin, VC>(
+                airs,
+                proof,
+                public_coin,
+            )
+        },
+        FieldExtension::Quartic => {
+            if !<QuartExtension<AIR::BaseField>>::is_supported() {
+                return Err(VerifierError::UnsupportedFieldExtension(4));
+            }
+            perform_multi_verification::<AIR, QuartExtension<AIR::BaseField>, HashFn, RandCoin, VC>(
+                airs,
+                proof,
+                public_coin,
+            )
+        },
+    }
+}
+
+// VERIFICATION PROCEDURE
+// ================================================================================================
+/// Performs the actual verification of a multi-table proof against the AIRs of all tables.
+///
+/// The procedure mirrors the single-table verification procedure, except that trace and
+/// constraint commitments, out-of-domain evaluations, and queries are processed for each table,
+/// and a single batched FRI proof is verified for the DEEP composition polynomials of all tables.
+fn perform_multi_verification<A, E, H, R, V>(
+    airs: Vec<A>,
+    proof: MultiProof,
+    mut public_coin: R,
+) -> Result<(), VerifierError>
+where
+    E: FieldElement<BaseField = A::BaseField>,
+    A: Air,
+    H: ElementHasher<BaseField = A::BaseField>,
+    R: RandomCoin<BaseField = A::BaseField, Hasher = H>,
+    V: VectorCommitment<H>,
+{
+    let MultiProof {
+        tables,
+        fri_commitments,
+        fri_proof,
+        pow_nonce,
+    } = proof;
+
+    // make sure the tables can be combined into a single proof
+    let fri_options = airs[0].options().to_fri_options();
+    let lde_domain_size = airs[0].lde_domain_size();
+    for (table_idx, air) in airs.iter().enumerate().skip(1) {
+        if air.trace_length() > airs[table_idx - 1].trace_length()
+            || fri_options.get_folding_depth(lde_domain_size, air.lde_domain_size()).is_none()
+        {
+            return Err(VerifierError::InvalidTable(table_idx));
+        }
+
+        let trace_info = air.trace_info();
+        let first_trace_info = airs[0].trace_info();
+        if trace_info.num_aux_segments() != first_trace_info.num_aux_segments()
+            || (0..trace_info.num_aux_segments()).any(|idx| {
+                trace_info.get_num_aux_segment_rand_elements_at(idx)
+                    != first_trace_info.get_num_aux_segment_rand_elements_at(idx)
+            })
+        {
+            return Err(VerifierError::InconsistentAuxSegments(table_idx));
+        }
+    }
+
+    // parse the final values of LogUp running sums and instantiate a channel for each table
+    let mut logup_sums: Vec<Option<E>> = Vec::with_capacity(tables.len());
+    let mut channels: Vec<VerifierChannel<E, H, V>> = Vec::with_capacity(tables.len());
+    for (air, mut table) in airs.iter().zip(tables) {
+        let logup_sum_bytes = mem::take(&mut table.logup_sum);
+        let logup_sum = match air.context().logup() {
+            Some(_) => Some(
+                E::read_from_bytes(&logup_sum_bytes)
+                    .map_err(|err| VerifierError::ProofDeserializationError(err.to_string()))?,
+            ),
+            None if logup_sum_bytes.is_empty() => None,
+            None => {
+                return Err(VerifierError::ProofDeserializationError(
+                    "LogUp sum provided for a table without a LogUp argument".to_string(),
+                ))
+            },
+        };
+        logup_sums.push(logup_sum);
+        channels.push(VerifierChannel::new_table(air, table)?);
+    }
+
+    // 1 ----- trace commitments ------------------------------------------------------------------
+    // reseed the coin with the commitments to the main trace segments of all tables
+    const MAIN_TRACE_IDX: usize = 0;
+    for channel in channels.iter() {
+        public_coin.reseed(V::commitment_digest(&channel.read_trace_commitments()[MAIN_TRACE_IDX]));
+    }
+
+    // process auxiliary trace segments; random elements for each segment are shared by all tables
+    let num_aux_segments = airs[0].trace_info().num_aux_segments();
+    let mut rand_elements = Vec::new();
+    for segment_idx in 0..num_aux_segments {
+        rand_elements.extend(
+            airs[0]
+                .get_aux_segment_rand_elements::<E, _>(segment_idx, &mut public_coin)
+                .expect(
+                    "failed to generate the random elements needed to build the auxiliary trace",
+                ),
+        );
+
+        for channel in channels.iter() {
+            let commitment = &channel.read_trace_commitments()[MAIN_TRACE_IDX + 1 + segment_idx];
+            public_coin.reseed(V::commitment_digest(commitment));
+        }
+    }
+
+    // reseed the coin with the final values of LogUp running sums, and make sure the sums of all
+    // tables add up to zero
+    let sums: Vec<E> = logup_sums.iter().flatten().copied().collect();
+    if !sums.is_empty() {
+        public_coin.reseed(H::hash_elements(&sums));
+        if sums.iter().fold(E::ZERO, |acc, &sum| acc + sum) != E::ZERO {
+            return Err(VerifierError::UnbalancedLogUpSums);
+        }
+    }
+
+    let aux_trace_rand_elements: Vec<Option<AuxRandElements<E>>> = logup_sums
+        .iter()
+        .map(|&logup_sum| {
+            (num_aux_segments > 0).then(|| {
+                let aux_rand_elements = AuxRandElements::new(rand_elements.clone());
+                match logup_sum {
+                    Some(logup_sum) => aux_rand_elements.with_logup_sum(logup_sum),
+                    None => aux_rand_elements,
+                }
+            })
+        })
+        .collect();
+
+    // 2 ----- constraint commitments -------------------------------------------------------------
+    // draw constraint composition coefficients for each table, and reseed the coin with the
+    // table's constraint commitment
+    let mut constraint_coeffs = Vec::with_capacity(airs.len());
+    for (air, channel) in airs.iter().zip(channels.iter()) {
+        constraint_coeffs.push(
+            air.get_constraint_composition_coefficients(&mut public_coin)
+                .map_err(|_| VerifierError::RandomCoinError)?,
+        );
+        public_coin.reseed(V::commitment_digest(channel.read_constraint_commitment()));
+    }
+    let z = public_coin.draw::<E>().map_err(|_| VerifierError::RandomCoinError)?;
+
+    // 3 ----- OOD consistency check --------------------------------------------------------------
+    let mut ood_frames = Vec::with_capacity(airs.len());
+    for (((air, channel), constraint_coeffs), aux_rand_elements) in airs
+        .iter()
+        .zip(channels.iter_mut())
+        .zip(constraint_coeffs)
+        .zip(aux_trace_rand_elements.iter())
+    {
+        let ood_trace_frame = channel.read_ood_trace_frame();
+        let ood_main_trace_frame = ood_trace_frame.main_frame();
+        let ood_aux_trace_frame = ood_trace_frame.aux_frame();
+        let ood_constraint_evaluation_1 = evaluate_constraints(
+            air,
+            constraint_coeffs,
+            &ood_main_trace_frame,
+            &ood_aux_trace_frame,
+            aux_rand_elements.as_ref(),
+            z,
+        );
+
+        let ood_constraint_evaluations = channel.read_ood_constraint_frame();
+        let ood_constraint_evaluation_2 = ood_constraint_evaluations
+            .current_row()
+            .iter()
+            .enumerate()
+            .fold(E::ZERO, |result, (i, &value)| {
+                result + z.exp_vartime(((i * (air.trace_length())) as u32).into()) * value
+            });
+
+        if ood_constraint_evaluation_1 != ood_constraint_evaluation_2 {
+            return Err(VerifierError::InconsistentOodConstraintEvaluations);
+        }
+
+        // reseed the public coin with OOD evaluations of the table
+        let ood_evals = merge_ood_evaluations(&ood_trace_frame, &ood_constraint_evaluations);
+        public_coin.reseed(H::hash_elements(&ood_evals));
+
+        ood_frames.push((ood_main_trace_frame, ood_aux_trace_frame, ood_constraint_evaluations));
+    }
+
+    // 4 ----- FRI commitments --------------------------------------------------------------------
+    let mut deep_coefficients = Vec::with_capacity(airs.len());
+    for air in airs.iter() {
+        deep_coefficients.push(
+            air.get_deep_composition_coefficients::<E, R>(&mut public_coin)
+                .map_err(|_| VerifierError::RandomCoinError)?,
+        );
+    }
+
+    let num_fri_layers = fri_options.num_fri_layers(lde_domain_size);
+    let (fri_commitments, fri_remainder_commitment) = fri_commitments
+        .parse_fri_layers::<H, V>(num_fri_layers)
+        .map_err(|err| VerifierError::ProofDeserializationError(err.to_string()))?;
+    let mut fri_channel = DefaultVerifierChannel::<E, H, V>::new(
+        fri_proof,
+        fri_commitments,
+        fri_remainder_commitment,
+        lde_domain_size,
+        fri_options.folding_factor(),
+    )
+    .map_err(|err| VerifierError::ProofDeserializationError(err.to_string()))?;
+    let fri_verifier = FriVerifier::new(
+        &mut fri_channel,
+        &mut public_coin,
+        fri_options,
+        airs[0].trace_poly_degree(),
+    )
+    .map_err(VerifierError::FriVerificationFailed)?;
+
+    // 5 ----- trace and constraint queries -------------------------------------------------------
+    if public_coin.check_leading_zeros(pow_nonce) < airs[0].options().grinding_factor() {
+        return Err(VerifierError::QuerySeedProofOfWorkVerificationFailed);
+    }
+
+    // draw query positions over the LDE domain of the first table; these positions are folded
+    // into the LDE domain of each of the other tables
+    let mut query_positions = public_coin
+        .draw_integers(airs[0].options().num_queries(), lde_domain_size, pow_nonce)
+        .map_err(|_| VerifierError::RandomCoinError)?;
+    query_positions.sort_unstable();
+    query_positions.dedup();
+
+    // 6 ----- DEEP composition -------------------------------------------------------------------
+    // compute evaluations of the DEEP composition polynomial of each table at the queried
+    // positions; evaluations of tables with LDE domains of the same size are combined
+    let mut deep_evaluations: Vec<(usize, Vec<E>)> = Vec::new();
+    for (((air, mut channel), deep_coefficients), ood_frame) in
+        airs.iter().zip(channels).zip(deep_coefficients).zip(ood_frames)
+    {
+        let (ood_main_trace_frame, ood_aux_trace_frame, ood_constraint_evaluations) = ood_frame;
+        let table_domain_size = air.lde_domain_size();
+        let positions =
+            fold_positions(&query_positions, lde_domain_size, lde_domain_size / table_domain_size);
+
+        let (queried_main_trace_states, queried_aux_trace_states) =
+            channel.read_queried_trace_states(&positions)?;
+        let queried_constraint_evaluations = channel.read_constraint_evaluations(&positions)?;
+
+        let composer = DeepComposer::new(air, &positions, z, deep_coefficients);
+        let table_evaluations = composer.compose_columns(
+            queried_main_trace_states,
+            queried_aux_trace_states,
+            queried_constraint_evaluations,
+            ood_main_trace_frame,
+            ood_aux_trace_frame,
+            ood_constraint_evaluations,
+        );
+
+        match deep_evaluations.last_mut() {
+            Some((domain_size, evaluations)) if *domain_size == table_domain_size => {
+                for (value, table_value) in evaluations.iter_mut().zip(table_evaluations) {
+                    *value += table_value;
+                }
+            },
+            _ => deep_evaluations.push((table_domain_size, table_evaluations)),
+        }
+    }
+
+    // 7 ----- Verify low-degree proof -------------------------------------------------------------
+    // evaluations of the DEEP composition polynomials of the largest tables are checked directly,
+    // while evaluations for smaller tables are mixed into the folded FRI layers
+    let (_, first_evaluations) = deep_evaluations.remove(0);
+    fri_verifier
+        .verify_batched(&mut fri_channel, &first_evaluations, &query_positions, &deep_evaluations)
+        .map_err(VerifierError::FriVerificationFailed)
+}
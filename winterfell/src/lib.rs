@@ -628,14 +628,14 @@
 #[cfg(test)]
 extern crate std;
 
-pub use air::{AuxRandElements, BatchingMethod, PartitionOptions};
+pub use air::{AuxRandElements, BatchingMethod, LogUp, PartitionOptions};
 pub use prover::{
     crypto, iterators, math, matrix, Air, AirContext, Assertion, AuxTraceWithMetadata,
     BoundaryConstraint, BoundaryConstraintGroup, CompositionPoly, CompositionPolyTrace,
     ConstraintCompositionCoefficients, ConstraintDivisor, ConstraintEvaluator,
     DeepCompositionCoefficients, DefaultConstraintCommitment, DefaultConstraintEvaluator,
-    DefaultTraceLde, EvaluationFrame, FieldExtension, Proof, ProofOptions, Prover, ProverError,
-    StarkDomain, Trace, TraceInfo, TraceLde, TracePolyTable, TraceTable, TraceTableFragment,
-    TransitionConstraintDegree,
+    DefaultTraceLde, EvaluationFrame, FieldExtension, MultiProof, Proof, ProofOptions, Prover,
+    ProverError, StarkDomain, Trace, TraceInfo, TraceLde, TracePolyTable, TraceTable,
+    TraceTableFragment, TransitionConstraintDegree, ZkRandomizer,
 };
-pub use verifier::{verify, AcceptableOptions, ByteWriter, VerifierError};
+pub use verifier::{verify, verify_multi, AcceptableOptions, ByteWriter, VerifierError};